@@ -44,7 +44,15 @@ tables=$(sqlite3 ./swade.db "SELECT name FROM sqlite_master WHERE type='table' A
 table_count=0
 for table in $tables; do
     echo "Dumping table: $table"
-    sqlite3 ./swade.db ".dump $table" > "$version_dir/${table}.sql"
+    {
+        echo "PRAGMA foreign_keys=OFF;"
+        echo "BEGIN TRANSACTION;"
+        sqlite3 ./swade.db ".schema $table"
+        # Name the columns, since columns added by migrations sit at the end of
+        # the table here but may be in the middle of it in schema.sql
+        sqlite3 -header -cmd ".mode insert $table" ./swade.db "SELECT * FROM $table;"
+        echo "COMMIT;"
+    } > "$version_dir/${table}.sql"
     table_count=$((table_count + 1))
 done
 
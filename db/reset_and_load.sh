@@ -89,7 +89,8 @@ fi
 echo ""
 
 # Define table order (respecting foreign key dependencies)
-# Only include tables with seed data (exclude character tables)
+# Only include tables with seed data (exclude character tables). schema_migrations
+# is loaded too, so the rebuilt database is recognised as fully migrated.
 seed_tables=(
     "attributes"
     "skills"
@@ -123,6 +124,9 @@ seed_tables=(
     "pack_contents"
     "gear_modifiers"
     "gear_requirements"
+    "archetypes"
+    "archetype_suggestions"
+    "schema_migrations"
 )
 
 # Extract only INSERT statements from each dump file
@@ -26,11 +26,12 @@ CREATE TABLE modifiers (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
     target_type VARCHAR(20) CHECK (target_type IN ('attribute', 'skill', 'derived_stat', 'edge_choice', 'hindrance_choice', 'heritage_choice', 'skill_points', 'attribute_points', 'power_slots', 'power_points', 'wealth')),
     target_identifier VARCHAR(30), -- nullable for general abilities
-    value_type VARCHAR(20) NOT NULL CHECK (value_type IN ('die_increment', 'roll_bonus', 'flat_bonus', 'description', 'bonus_selection', 'mandatory_selection')),
+    value_type VARCHAR(20) NOT NULL CHECK (value_type IN ('die_increment', 'roll_bonus', 'flat_bonus', 'description', 'bonus_selection', 'mandatory_selection', 'grant_skill', 'max_die_increase', 'parry_skill')),
     value INTEGER, -- can be NULL, especially for description type
     description TEXT NOT NULL, -- always present, concise human-readable text
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
-    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    condition TEXT -- NULL for always-on modifiers; otherwise when the bonus applies (e.g. 'unarmed')
 );
 
 CREATE TABLE requirements (
@@ -40,6 +41,7 @@ CREATE TABLE requirements (
         'edge', 'arcane_background', 'wild_card', 'description'
     )),
     target_id INTEGER, -- nullable for types like rank_min, power_points_min
+    target_name VARCHAR(50), -- target by name when target_id is NULL, e.g. a rank in imported content
     value INTEGER, -- can be NULL for description type
     description TEXT NOT NULL, -- always present, human-readable requirement text
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
@@ -114,6 +116,7 @@ CREATE TABLE hindrances (
     severity VARCHAR(10) NOT NULL CHECK (severity IN ('minor', 'major')),
     point_value INTEGER NOT NULL, -- 1 for minor, 2 for major
     companion_hindrance_id INTEGER, -- FK to the other variant, NULL if no companion
+    can_take_multiple_times BOOLEAN NOT NULL DEFAULT 0, -- Whether this hindrance can be taken more than once
     source VARCHAR(20) NOT NULL DEFAULT 'core',
     description TEXT NOT NULL,
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
@@ -125,6 +128,7 @@ CREATE TABLE powers (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
     name VARCHAR(50) NOT NULL,
     power_points INTEGER NOT NULL,
+    maintenance_power_points INTEGER, -- Cost to extend the duration (NULL when not recorded for the power)
     range VARCHAR(30) NOT NULL,
     duration VARCHAR(30) NOT NULL,
     source VARCHAR(20) NOT NULL DEFAULT 'core',
@@ -161,6 +165,7 @@ CREATE TABLE arcane_backgrounds (
     description TEXT NOT NULL,
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    edge_id INTEGER, -- Edge that grants this arcane background when taken as an advance
     FOREIGN KEY (arcane_skill_id) REFERENCES skills(id)
 );
 
@@ -204,7 +209,7 @@ CREATE TABLE arcane_background_requirements (
 CREATE TABLE ancestry_choices (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
     ancestry_id INTEGER NOT NULL,
-    choice_type VARCHAR(50) NOT NULL, -- 'free_edge', 'mandatory_hindrance', 'heritage_trait', etc.
+    choice_type VARCHAR(50) NOT NULL, -- 'free_edge', 'mandatory_hindrance', 'attribute_increase', etc.
     choice_category VARCHAR(50), -- 'background_edge', 'combat_edge', etc. (for filtering)
     min_selections INTEGER NOT NULL DEFAULT 1,
     max_selections INTEGER NOT NULL DEFAULT 1,
@@ -217,7 +222,7 @@ CREATE TABLE ancestry_choices (
 CREATE TABLE ancestry_choice_options (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
     choice_id INTEGER NOT NULL,
-    option_type VARCHAR(50) NOT NULL, -- 'edge', 'hindrance', 'modifier'
+    option_type VARCHAR(50) NOT NULL, -- 'edge', 'hindrance', 'ancestry', 'attribute', 'skill'
     option_id INTEGER, -- specific ID or NULL for "any"
     option_description TEXT,
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
@@ -414,6 +419,27 @@ CREATE TABLE gear_requirements (
     UNIQUE(gear_id, requirement_expression_id)
 );
 
+CREATE TABLE archetypes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    key VARCHAR(50) NOT NULL UNIQUE, -- stable identifier, e.g. 'brawler'
+    name VARCHAR(100) NOT NULL,
+    description TEXT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE archetype_suggestions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    archetype_id INTEGER NOT NULL,
+    suggestion_type VARCHAR(20) NOT NULL CHECK (suggestion_type IN ('attribute', 'skill', 'edge', 'hindrance')),
+    target_id INTEGER NOT NULL, -- attribute, skill, edge, or hindrance id
+    die_size INTEGER, -- target die for attributes and skills
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (archetype_id) REFERENCES archetypes(id) ON DELETE CASCADE
+);
+
 CREATE TABLE characters (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
     is_wild_card BOOLEAN NOT NULL DEFAULT 1,
@@ -442,6 +468,20 @@ CREATE TABLE characters (
     wealth INTEGER NOT NULL DEFAULT 500, -- Starting wealth amount
     background TEXT, -- Character background/history
     description TEXT, -- Physical description, personality, etc.
+    campaign VARCHAR(100), -- Campaign or table the character belongs to
+    archived BOOLEAN NOT NULL DEFAULT 0, -- Archived characters are hidden from the character list
+
+    -- House Rule Configuration
+    max_attribute_die_size INTEGER, -- Attribute cap override (NULL uses the d12 default)
+    max_attribute_die_modifier INTEGER NOT NULL DEFAULT 0, -- Modifier for d12+N caps
+    allow_hindrances_past_cap BOOLEAN NOT NULL DEFAULT 0, -- Hindrances past the point cap earn nothing instead of being blocked
+    load_limit_multiplier INTEGER, -- Pounds of load limit per Strength die step (NULL uses the core 20)
+    parry_skill VARCHAR(30), -- Skill Parry is derived from (NULL uses Fighting)
+    max_wounds INTEGER, -- Wounds a Wild Card can take before Incapacitation (NULL uses the core 3)
+    track_experience BOOLEAN NOT NULL DEFAULT 0, -- Advances come from experience_points instead of advances taken
+    experience_points INTEGER NOT NULL DEFAULT 0, -- Total XP earned, used when track_experience is set
+    sell_back_percent INTEGER, -- Percent of an item's cost refunded when selling it (NULL uses the core 50)
+    weight_unit VARCHAR(2) NOT NULL DEFAULT 'lb', -- Unit gear weights and encumbrance are shown in ('lb' or 'kg')
 
     -- Portrait (stored as resized image, max 1024x1024)
     portrait BLOB, -- Raw image bytes (PNG/JPEG)
@@ -449,6 +489,7 @@ CREATE TABLE characters (
 
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    revision INTEGER NOT NULL DEFAULT 0, -- Incremented by every update, so saves can detect a stale draft
     FOREIGN KEY (ancestry_id) REFERENCES ancestries(id)
 );
 
@@ -459,6 +500,7 @@ CREATE TABLE character_advances (
     advance_type VARCHAR(30) NOT NULL,  -- 'edge', 'attribute', 'skill_expensive', 'skill_cheap', 'hindrance'
     -- For edge advances
     edge_id INTEGER,
+    arcane_background_id INTEGER,  -- Arcane background the edge granted, revoked on undo
     -- For attribute advances
     attribute_id INTEGER,
     -- For skill advances (expensive = 1 skill, cheap = 2 skills)
@@ -466,13 +508,15 @@ CREATE TABLE character_advances (
     skill_id_2 INTEGER,  -- Only used for cheap skill advances
     -- For hindrance advances
     hindrance_id INTEGER,
-    hindrance_action VARCHAR(20),  -- 'remove_minor', 'reduce_major', 'remove_major_half'
+    hindrance_action VARCHAR(30),  -- 'remove_minor', 'reduce_major', 'remove_major_half', 'complete_major_removal'
+    hindrance_source VARCHAR(20),  -- Source of the removed hindrance, restored on undo
     -- Metadata
     notes TEXT,
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     FOREIGN KEY (character_id) REFERENCES characters(id) ON DELETE CASCADE,
     FOREIGN KEY (edge_id) REFERENCES edges(id),
+    FOREIGN KEY (arcane_background_id) REFERENCES arcane_backgrounds(id),
     FOREIGN KEY (attribute_id) REFERENCES attributes(id),
     FOREIGN KEY (skill_id_1) REFERENCES skills(id),
     FOREIGN KEY (skill_id_2) REFERENCES skills(id),
@@ -498,6 +542,7 @@ CREATE TABLE character_arcane_backgrounds (
     character_id INTEGER NOT NULL,
     arcane_background_id INTEGER NOT NULL,
     advance_taken INTEGER DEFAULT 0, -- Which advance was used to take this Arcane Background edge
+    power_points_used INTEGER NOT NULL DEFAULT 0, -- Power points spent from this background's pool
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     FOREIGN KEY (character_id) REFERENCES characters(id),
@@ -611,11 +656,45 @@ CREATE TABLE character_notes (
     character_id INTEGER NOT NULL,
     title VARCHAR(100) NOT NULL,
     body TEXT NOT NULL DEFAULT '',
+    pinned BOOLEAN NOT NULL DEFAULT 0, -- Pinned notes are listed first
+    category VARCHAR(50), -- Optional grouping, e.g. 'Session Log' or 'Contacts'
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
     FOREIGN KEY (character_id) REFERENCES characters(id) ON DELETE CASCADE
 );
 
+CREATE TABLE companion_links (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    parent_character_id INTEGER NOT NULL,
+    child_character_id INTEGER NOT NULL, -- The companion's own character record
+    relationship VARCHAR(50) NOT NULL, -- 'Sidekick', 'Animal Companion', 'Familiar', etc.
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (parent_character_id) REFERENCES characters(id) ON DELETE CASCADE,
+    FOREIGN KEY (child_character_id) REFERENCES characters(id) ON DELETE CASCADE,
+    UNIQUE(parent_character_id, child_character_id)
+);
+
+CREATE TABLE character_snapshots (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    character_id INTEGER NOT NULL, -- No foreign key: snapshots outlive the character so restoring can recreate it
+    label VARCHAR(100) NOT NULL,
+    data TEXT NOT NULL, -- JSON of the CharacterView and its advance history
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+-- ============================================================================
+-- SCHEMA VERSIONING
+-- ============================================================================
+
+-- Migrations applied to this database (see swade-core/src/db/migrations.rs)
+CREATE TABLE schema_migrations (
+    version INTEGER PRIMARY KEY,
+    description TEXT NOT NULL,
+    applied_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
 -- ============================================================================
 -- INDEXES
 -- ============================================================================
@@ -0,0 +1,213 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE ancestry_modifiers (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    ancestry_id INTEGER NOT NULL,
+    modifier_id INTEGER NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (ancestry_id) REFERENCES ancestries(id),
+    FOREIGN KEY (modifier_id) REFERENCES modifiers(id),
+    UNIQUE(ancestry_id, modifier_id)
+);
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(1,2,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(2,2,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(3,2,3,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(4,2,4,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(5,3,5,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(6,3,6,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(7,3,7,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(8,4,8,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(9,4,9,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(10,4,10,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(11,4,11,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(12,5,12,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(13,5,13,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(14,5,14,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(15,5,15,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(16,5,16,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(17,5,17,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(18,6,18,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(19,6,19,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(20,6,20,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(21,6,21,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(22,7,22,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(23,7,23,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(24,7,24,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(25,7,25,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(26,7,26,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(27,8,8,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(28,8,584,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(29,8,1,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(30,8,585,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(31,9,586,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(32,9,587,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(33,9,588,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(34,9,589,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(35,9,590,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(36,9,591,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(37,10,592,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(38,11,593,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(39,11,594,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(40,11,595,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(41,11,596,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(42,11,597,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(43,11,598,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(44,11,599,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(45,12,600,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(46,12,601,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(47,12,602,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(48,12,603,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(49,12,604,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(50,12,605,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(51,13,1,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(52,13,606,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(53,13,591,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(54,13,607,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(55,14,608,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(56,14,609,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(57,15,613,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(58,15,614,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(59,15,1,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(60,16,615,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(61,16,616,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(62,16,617,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(63,16,618,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(64,16,619,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(65,16,620,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(66,16,621,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(67,16,622,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(68,17,623,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(69,17,1,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(70,17,589,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(71,17,624,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(72,17,625,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(73,17,626,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(74,17,591,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(75,18,627,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(76,18,628,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(77,18,629,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(78,18,630,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(79,18,631,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(80,19,632,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(81,19,633,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(82,19,634,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(83,19,635,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(84,19,636,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(85,19,637,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(86,19,638,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(87,19,639,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(88,19,640,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(89,19,641,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(90,19,642,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(91,19,643,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(92,19,644,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(93,20,645,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(94,20,646,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(95,20,647,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(96,20,1,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(97,20,648,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(98,20,649,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(99,20,650,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(100,20,651,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(101,21,1,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(102,21,652,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(103,22,654,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(104,22,626,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(105,22,591,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(106,22,655,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(107,22,625,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(108,22,656,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(109,23,657,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(110,23,658,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(111,23,634,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(112,23,635,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(113,23,659,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(114,23,660,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(115,23,661,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(116,23,662,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(117,23,663,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(118,23,664,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(119,24,665,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(120,24,666,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(121,26,667,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(122,26,668,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(123,26,604,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(124,26,605,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(125,26,669,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(126,26,670,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(127,27,671,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(128,27,672,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(129,27,673,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(130,27,674,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(131,27,675,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(132,27,676,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(133,27,677,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(134,28,678,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(135,28,596,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(136,28,663,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(137,28,679,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(138,28,680,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(139,28,607,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(140,28,657,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(141,29,681,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(142,29,621,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(143,29,622,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(144,29,1,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(145,29,682,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(146,29,683,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(147,29,626,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(148,29,642,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(149,29,684,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(150,30,685,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(151,30,657,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(152,30,634,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(153,30,635,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(154,30,636,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(155,30,637,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(156,30,686,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(157,30,687,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(158,30,688,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(159,30,596,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(160,30,663,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(161,30,664,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(162,31,689,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(163,31,665,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(164,31,690,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(165,31,691,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(166,31,596,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(167,31,692,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(168,31,607,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(169,32,613,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(170,32,693,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(171,32,694,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(172,32,695,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(173,32,696,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(174,32,1,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(175,33,693,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(176,33,697,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(177,33,698,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(178,33,1,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(179,33,699,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(180,33,655,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(181,33,625,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(182,33,700,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(183,33,701,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(184,33,702,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(185,34,703,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(186,34,693,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(187,34,704,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(188,34,705,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(189,34,706,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(190,35,707,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(191,35,603,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(192,35,704,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(193,35,708,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(194,35,709,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(195,35,710,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(196,35,711,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(197,35,712,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(198,36,713,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(199,36,714,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+INSERT INTO ancestry_modifiers(id,ancestry_id,modifier_id,created_at,updated_at) VALUES(200,36,715,'2026-01-03 21:24:41','2026-01-03 21:24:41');
+COMMIT;
@@ -0,0 +1,54 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE archetype_suggestions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    archetype_id INTEGER NOT NULL,
+    suggestion_type VARCHAR(20) NOT NULL CHECK (suggestion_type IN ('attribute', 'skill', 'edge', 'hindrance')),
+    target_id INTEGER NOT NULL, -- attribute, skill, edge, or hindrance id
+    die_size INTEGER, -- target die for attributes and skills
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (archetype_id) REFERENCES archetypes(id) ON DELETE CASCADE
+);
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(1,1,'attribute',1,6,1,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(2,1,'attribute',4,8,2,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(3,1,'attribute',5,8,3,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(4,1,'skill',12,8,4,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(5,1,'skill',1,6,5,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(6,1,'skill',17,6,6,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(7,1,'skill',3,6,7,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(8,1,'skill',29,4,8,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(9,1,'skill',5,6,9,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(10,1,'edge',11,NULL,10,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(11,1,'hindrance',7,NULL,11,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(12,1,'hindrance',2,NULL,12,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(13,2,'attribute',2,8,1,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(14,2,'attribute',3,6,2,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(15,2,'attribute',1,6,3,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(16,2,'attribute',5,6,4,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(17,2,'skill',24,8,5,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(18,2,'skill',6,8,6,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(19,2,'skill',3,6,7,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(20,2,'skill',2,8,8,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(21,2,'skill',26,6,9,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(22,2,'skill',16,4,10,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(23,2,'edge',100,NULL,11,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(24,2,'hindrance',5,NULL,12,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(25,2,'hindrance',4,NULL,13,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(26,3,'attribute',3,8,1,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(27,3,'attribute',2,6,2,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(28,3,'attribute',1,6,3,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(29,3,'attribute',5,6,4,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(30,3,'skill',4,8,5,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(31,3,'skill',20,6,6,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(32,3,'skill',17,6,7,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(33,3,'skill',3,6,8,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(34,3,'skill',2,6,9,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(35,3,'skill',30,6,10,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(36,3,'skill',14,4,11,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(37,3,'skill',5,6,12,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(38,3,'edge',13,NULL,13,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(39,3,'hindrance',1,NULL,14,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetype_suggestions(id,archetype_id,suggestion_type,target_id,die_size,sort_order,created_at,updated_at) VALUES(40,3,'hindrance',15,NULL,15,'2026-10-16 11:52:10','2026-10-16 11:52:10');
+COMMIT;
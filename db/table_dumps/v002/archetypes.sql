@@ -0,0 +1,14 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE archetypes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    key VARCHAR(50) NOT NULL UNIQUE, -- stable identifier, e.g. 'brawler'
+    name VARCHAR(100) NOT NULL,
+    description TEXT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+INSERT INTO archetypes(id,"key",name,description,created_at,updated_at) VALUES(1,'brawler','Brawler','A tough fighter who settles things up close.','2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetypes(id,"key",name,description,created_at,updated_at) VALUES(2,'scholar','Scholar','A learned researcher who knows a little about everything.','2026-10-16 11:52:10','2026-10-16 11:52:10');
+INSERT INTO archetypes(id,"key",name,description,created_at,updated_at) VALUES(3,'face','Face','A smooth talker who gets the party through doors and out of trouble.','2026-10-16 11:52:10','2026-10-16 11:52:10');
+COMMIT;
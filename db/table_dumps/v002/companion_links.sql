@@ -0,0 +1,14 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE companion_links (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    parent_character_id INTEGER NOT NULL,
+    child_character_id INTEGER NOT NULL, -- The companion's own character record
+    relationship VARCHAR(50) NOT NULL, -- 'Sidekick', 'Animal Companion', 'Familiar', etc.
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (parent_character_id) REFERENCES characters(id) ON DELETE CASCADE,
+    FOREIGN KEY (child_character_id) REFERENCES characters(id) ON DELETE CASCADE,
+    UNIQUE(parent_character_id, child_character_id)
+);
+COMMIT;
@@ -0,0 +1,366 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE edge_modifiers (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    edge_id INTEGER NOT NULL,
+    modifier_id INTEGER NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (edge_id) REFERENCES edges(id),
+    FOREIGN KEY (modifier_id) REFERENCES modifiers(id),
+    UNIQUE(edge_id, modifier_id)
+);
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(1,1,27,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(2,2,28,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(3,2,29,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(4,3,30,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(5,4,31,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(6,4,32,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(7,5,33,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(8,5,34,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(9,6,35,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(10,6,36,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(11,7,37,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(12,8,38,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(13,9,39,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(14,10,40,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(15,10,41,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(16,11,42,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(17,11,43,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(18,11,44,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(19,12,45,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(20,12,46,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(21,12,47,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(22,13,48,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(23,14,49,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(24,15,50,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(25,15,51,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(26,16,52,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(27,16,53,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(28,17,54,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(29,17,55,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(30,18,56,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(31,18,57,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(32,19,58,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(33,19,59,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(34,20,60,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(35,21,61,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(36,22,62,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(37,23,63,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(38,23,64,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(39,24,65,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(40,24,66,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(41,25,67,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(42,26,68,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(43,26,69,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(44,27,70,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(45,27,71,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(46,28,72,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(47,28,73,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(48,29,74,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(49,29,75,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(50,30,76,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(51,30,77,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(52,31,78,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(53,31,79,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(54,32,80,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(55,33,81,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(56,34,82,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(57,35,83,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(58,36,84,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(59,37,85,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(60,38,86,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(61,38,87,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(62,39,88,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(63,40,89,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(64,41,90,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(65,42,91,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(66,43,92,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(67,44,93,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(68,44,94,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(69,45,95,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(70,46,96,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(71,47,97,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(72,48,98,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(73,49,99,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(74,50,100,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(75,51,101,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(76,52,102,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(77,53,103,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(78,54,104,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(79,55,105,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(80,55,106,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(81,55,107,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(82,56,108,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(83,56,109,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(84,57,110,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(85,58,111,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(86,59,112,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(87,60,113,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(88,61,114,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(89,62,115,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(90,63,116,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(91,64,117,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(92,65,118,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(93,66,119,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(94,66,120,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(95,67,121,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(96,67,122,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(97,68,123,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(98,69,124,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(99,70,125,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(100,70,126,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(101,70,127,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(102,71,128,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(103,72,129,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(104,73,130,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(105,74,131,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(106,74,132,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(107,75,133,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(108,76,134,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(109,76,135,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(110,76,136,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(111,77,137,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(112,78,138,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(113,78,139,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(114,79,140,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(115,79,141,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(116,80,142,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(117,80,143,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(118,81,144,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(119,81,145,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(120,81,146,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(121,82,147,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(122,82,148,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(123,82,149,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(124,82,150,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(125,83,151,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(126,83,152,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(127,83,153,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(128,84,154,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(129,84,155,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(130,85,156,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(131,85,157,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(132,85,158,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(133,85,159,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(134,86,160,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(135,86,161,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(136,86,162,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(137,87,163,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(138,87,164,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(139,88,165,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(140,89,166,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(141,90,167,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(142,90,168,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(143,90,169,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(144,90,170,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(145,91,171,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(146,91,172,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(147,92,173,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(148,92,174,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(149,93,175,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(150,94,176,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(151,95,177,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(152,96,178,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(153,96,179,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(154,97,180,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(155,98,181,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(156,99,182,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(157,99,183,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(158,100,184,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(159,101,185,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(160,101,186,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(161,102,187,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(162,102,188,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(163,102,189,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(164,103,190,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(165,103,191,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(166,104,192,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(167,105,193,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(168,106,194,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(169,107,195,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(170,108,196,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(171,109,197,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(172,110,198,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(173,111,199,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(174,112,200,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(175,113,201,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(176,113,202,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(177,114,203,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(178,115,204,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(179,116,205,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(180,117,206,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(181,118,207,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(182,119,208,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(183,119,209,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(184,119,210,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(185,120,211,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(186,121,212,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(187,122,213,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(188,122,214,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(189,123,215,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(190,124,216,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(191,125,217,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(192,126,218,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(193,127,219,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(194,128,220,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(195,129,221,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(196,130,222,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(197,131,223,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(198,132,224,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(199,133,225,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(200,133,226,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(201,134,227,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(202,134,228,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(203,85,524,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(204,86,525,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(205,23,526,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(206,24,527,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(207,135,716,'2026-01-03 21:46:52','2026-01-03 21:46:52');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(208,135,717,'2026-01-03 21:46:52','2026-01-03 21:46:52');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(209,136,718,'2026-01-03 21:47:07','2026-01-03 21:47:07');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(210,136,719,'2026-01-03 21:47:07','2026-01-03 21:47:07');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(211,136,720,'2026-01-03 21:47:07','2026-01-03 21:47:07');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(212,137,721,'2026-01-03 21:47:19','2026-01-03 21:47:19');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(213,137,722,'2026-01-03 21:47:19','2026-01-03 21:47:19');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(214,138,723,'2026-01-03 21:47:33','2026-01-03 21:47:33');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(215,138,724,'2026-01-03 21:47:33','2026-01-03 21:47:33');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(216,138,725,'2026-01-03 21:47:33','2026-01-03 21:47:33');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(217,139,726,'2026-01-03 21:47:42','2026-01-03 21:47:42');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(218,140,727,'2026-01-03 21:47:58','2026-01-03 21:47:58');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(219,140,728,'2026-01-03 21:47:58','2026-01-03 21:47:58');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(220,141,729,'2026-01-03 21:48:09','2026-01-03 21:48:09');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(221,141,730,'2026-01-03 21:48:09','2026-01-03 21:48:09');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(222,142,731,'2026-01-03 21:50:24','2026-01-03 21:50:24');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(223,142,732,'2026-01-03 21:50:24','2026-01-03 21:50:24');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(224,143,733,'2026-01-03 21:50:26','2026-01-03 21:50:26');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(225,143,734,'2026-01-03 21:50:26','2026-01-03 21:50:26');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(226,144,735,'2026-01-03 21:50:27','2026-01-03 21:50:27');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(227,144,736,'2026-01-03 21:50:27','2026-01-03 21:50:27');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(228,145,737,'2026-01-03 21:50:28','2026-01-03 21:50:28');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(229,146,738,'2026-01-03 21:50:29','2026-01-03 21:50:29');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(230,146,739,'2026-01-03 21:50:29','2026-01-03 21:50:29');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(231,147,740,'2026-01-03 21:50:30','2026-01-03 21:50:30');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(232,147,741,'2026-01-03 21:50:30','2026-01-03 21:50:30');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(233,148,742,'2026-01-03 21:50:39','2026-01-03 21:50:39');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(234,149,743,'2026-01-03 21:50:40','2026-01-03 21:50:40');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(235,150,744,'2026-01-03 21:50:42','2026-01-03 21:50:42');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(236,151,745,'2026-01-03 21:50:43','2026-01-03 21:50:43');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(237,152,746,'2026-01-03 21:50:45','2026-01-03 21:50:45');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(238,153,747,'2026-01-03 21:50:46','2026-01-03 21:50:46');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(239,154,748,'2026-01-03 21:50:57','2026-01-03 21:50:57');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(240,155,749,'2026-01-03 21:50:58','2026-01-03 21:50:58');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(241,155,750,'2026-01-03 21:50:58','2026-01-03 21:50:58');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(242,156,751,'2026-01-03 21:50:59','2026-01-03 21:50:59');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(243,157,752,'2026-01-03 21:51:00','2026-01-03 21:51:00');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(244,157,753,'2026-01-03 21:51:00','2026-01-03 21:51:00');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(245,158,754,'2026-01-03 21:51:01','2026-01-03 21:51:01');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(246,158,755,'2026-01-03 21:51:01','2026-01-03 21:51:01');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(247,159,756,'2026-01-03 21:51:02','2026-01-03 21:51:02');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(248,160,757,'2026-01-03 21:51:13','2026-01-03 21:51:13');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(249,161,758,'2026-01-03 21:51:14','2026-01-03 21:51:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(250,162,759,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(251,162,760,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(252,163,761,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(253,164,762,'2026-01-03 21:51:20','2026-01-03 21:51:20');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(254,164,763,'2026-01-03 21:51:20','2026-01-03 21:51:20');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(255,165,764,'2026-01-03 21:51:21','2026-01-03 21:51:21');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(256,165,765,'2026-01-03 21:51:21','2026-01-03 21:51:21');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(257,165,766,'2026-01-03 21:51:21','2026-01-03 21:51:21');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(258,165,767,'2026-01-03 21:51:21','2026-01-03 21:51:21');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(259,166,768,'2026-01-03 21:52:33','2026-01-03 21:52:33');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(260,166,769,'2026-01-03 21:52:33','2026-01-03 21:52:33');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(261,167,770,'2026-01-03 21:52:36','2026-01-03 21:52:36');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(262,167,771,'2026-01-03 21:52:36','2026-01-03 21:52:36');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(263,168,772,'2026-01-03 21:52:38','2026-01-03 21:52:38');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(264,169,773,'2026-01-03 21:52:39','2026-01-03 21:52:39');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(265,170,774,'2026-01-03 21:54:09','2026-01-03 21:54:09');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(266,170,775,'2026-01-03 21:54:09','2026-01-03 21:54:09');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(267,171,776,'2026-01-03 21:54:17','2026-01-03 21:54:17');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(268,172,777,'2026-01-03 21:54:18','2026-01-03 21:54:18');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(269,172,778,'2026-01-03 21:54:18','2026-01-03 21:54:18');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(270,172,779,'2026-01-03 21:54:18','2026-01-03 21:54:18');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(271,173,780,'2026-01-03 21:54:20','2026-01-03 21:54:20');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(272,173,781,'2026-01-03 21:54:20','2026-01-03 21:54:20');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(273,174,782,'2026-01-03 21:54:21','2026-01-03 21:54:21');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(274,174,783,'2026-01-03 21:54:21','2026-01-03 21:54:21');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(275,174,784,'2026-01-03 21:54:21','2026-01-03 21:54:21');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(276,174,785,'2026-01-03 21:54:21','2026-01-03 21:54:21');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(277,174,786,'2026-01-03 21:54:21','2026-01-03 21:54:21');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(278,174,787,'2026-01-03 21:54:21','2026-01-03 21:54:21');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(279,175,788,'2026-01-03 21:54:22','2026-01-03 21:54:22');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(280,175,789,'2026-01-03 21:54:22','2026-01-03 21:54:22');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(281,176,790,'2026-01-03 21:54:23','2026-01-03 21:54:23');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(282,176,791,'2026-01-03 21:54:23','2026-01-03 21:54:23');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(283,177,792,'2026-01-03 21:54:24','2026-01-03 21:54:24');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(284,177,793,'2026-01-03 21:54:24','2026-01-03 21:54:24');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(285,177,794,'2026-01-03 21:54:24','2026-01-03 21:54:24');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(286,177,795,'2026-01-03 21:54:24','2026-01-03 21:54:24');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(287,177,796,'2026-01-03 21:54:24','2026-01-03 21:54:24');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(288,178,797,'2026-01-03 21:54:25','2026-01-03 21:54:25');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(289,178,798,'2026-01-03 21:54:25','2026-01-03 21:54:25');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(290,179,799,'2026-01-03 21:54:26','2026-01-03 21:54:26');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(291,179,800,'2026-01-03 21:54:26','2026-01-03 21:54:26');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(292,180,801,'2026-01-03 21:55:40','2026-01-03 21:55:40');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(293,180,802,'2026-01-03 21:55:40','2026-01-03 21:55:40');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(294,181,803,'2026-01-03 21:55:41','2026-01-03 21:55:41');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(295,181,804,'2026-01-03 21:55:41','2026-01-03 21:55:41');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(296,182,805,'2026-01-03 21:55:42','2026-01-03 21:55:42');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(297,182,806,'2026-01-03 21:55:42','2026-01-03 21:55:42');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(298,183,807,'2026-01-03 21:55:43','2026-01-03 21:55:43');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(299,183,808,'2026-01-03 21:55:43','2026-01-03 21:55:43');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(300,183,809,'2026-01-03 21:55:43','2026-01-03 21:55:43');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(301,183,810,'2026-01-03 21:55:43','2026-01-03 21:55:43');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(302,184,811,'2026-01-03 21:55:44','2026-01-03 21:55:44');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(303,184,812,'2026-01-03 21:55:44','2026-01-03 21:55:44');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(304,185,813,'2026-01-03 21:55:45','2026-01-03 21:55:45');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(305,185,814,'2026-01-03 21:55:45','2026-01-03 21:55:45');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(306,185,815,'2026-01-03 21:55:45','2026-01-03 21:55:45');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(307,186,816,'2026-01-03 21:55:45','2026-01-03 21:55:45');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(308,186,817,'2026-01-03 21:55:45','2026-01-03 21:55:45');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(309,187,818,'2026-01-03 21:55:46','2026-01-03 21:55:46');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(310,187,819,'2026-01-03 21:55:46','2026-01-03 21:55:46');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(311,187,820,'2026-01-03 21:55:46','2026-01-03 21:55:46');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(312,188,821,'2026-01-03 21:55:47','2026-01-03 21:55:47');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(313,188,822,'2026-01-03 21:55:47','2026-01-03 21:55:47');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(314,188,823,'2026-01-03 21:55:47','2026-01-03 21:55:47');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(315,189,824,'2026-01-03 21:55:48','2026-01-03 21:55:48');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(316,189,825,'2026-01-03 21:55:48','2026-01-03 21:55:48');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(317,190,826,'2026-01-03 21:57:00','2026-01-03 21:57:00');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(318,191,827,'2026-01-03 21:58:04','2026-01-03 21:58:04');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(319,191,828,'2026-01-03 21:58:04','2026-01-03 21:58:04');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(320,192,829,'2026-01-03 21:58:08','2026-01-03 21:58:08');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(321,192,830,'2026-01-03 21:58:08','2026-01-03 21:58:08');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(322,193,831,'2026-01-03 21:58:11','2026-01-03 21:58:11');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(323,194,997,'2026-01-04 20:18:20','2026-01-04 20:18:20');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(324,195,998,'2026-01-04 20:18:20','2026-01-04 20:18:20');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(325,195,999,'2026-01-04 20:18:20','2026-01-04 20:18:20');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(329,198,1000,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(330,199,1001,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(331,200,1002,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(332,201,1003,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(333,202,1004,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(334,203,1005,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(335,204,1006,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(336,205,1007,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(337,206,1008,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(338,207,1009,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(339,208,1010,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(340,209,1011,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(341,210,1012,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(342,211,1013,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(343,212,1014,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(344,213,1015,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(345,214,1016,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(346,215,1017,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(347,216,1018,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(348,217,1019,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(349,218,1020,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(350,219,1021,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(351,220,1022,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(352,221,1023,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(353,222,1024,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(354,223,1025,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(355,224,1026,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+INSERT INTO edge_modifiers(id,edge_id,modifier_id,created_at,updated_at) VALUES(356,225,1027,'2026-01-04 20:56:14','2026-01-04 20:56:14');
+COMMIT;
@@ -0,0 +1,209 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE edge_requirements (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    edge_id INTEGER NOT NULL,
+    requirement_expression_id INTEGER NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (edge_id) REFERENCES edges(id),
+    FOREIGN KEY (requirement_expression_id) REFERENCES requirement_expressions(id),
+    UNIQUE(edge_id, requirement_expression_id)
+);
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(1,1,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(2,2,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(3,3,5,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(4,4,6,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(5,5,9,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(6,6,12,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(7,7,13,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(8,8,16,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(9,9,19,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(10,10,20,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(11,11,23,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(12,12,27,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(13,13,31,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(14,14,34,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(15,15,37,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(16,16,38,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(17,17,41,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(18,18,44,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(19,19,47,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(20,20,50,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(21,21,51,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(22,22,54,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(23,23,57,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(24,24,58,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(25,25,61,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(26,26,62,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(27,27,65,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(28,28,68,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(29,29,71,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(30,30,74,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(31,31,78,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(32,32,81,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(33,33,84,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(34,34,87,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(35,35,90,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(36,36,96,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(37,37,99,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(38,38,102,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(39,39,105,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(40,40,108,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(41,41,111,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(42,42,114,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(43,43,117,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(44,44,120,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(45,45,124,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(46,46,127,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(47,47,130,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(48,48,131,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(49,49,134,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(50,50,137,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(51,51,140,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(52,52,141,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(53,53,144,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(54,54,147,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(55,55,152,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(56,56,155,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(57,57,158,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(58,58,162,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(59,59,165,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(60,60,168,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(61,61,169,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(62,62,172,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(63,63,175,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(64,64,178,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(65,65,182,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(66,66,185,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(67,67,188,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(68,68,191,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(69,69,194,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(70,70,197,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(71,71,200,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(72,72,203,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(73,73,207,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(74,74,211,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(75,75,214,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(76,76,218,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(77,77,223,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(78,78,226,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(79,79,229,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(80,80,232,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(81,81,235,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(82,82,239,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(83,83,243,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(84,84,247,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(85,85,251,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(86,86,254,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(87,87,257,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(88,88,262,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(89,89,266,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(90,90,269,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(91,91,273,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(92,92,277,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(93,93,280,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(94,94,284,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(95,95,287,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(96,96,292,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(97,97,296,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(98,98,299,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(99,99,304,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(100,100,307,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(101,101,310,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(102,102,314,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(103,103,319,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(104,104,323,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(105,105,326,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(106,106,330,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(107,107,331,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(108,108,334,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(109,109,337,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(110,110,340,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(111,111,343,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(112,112,346,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(113,113,349,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(114,114,352,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(115,115,355,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(116,116,359,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(117,117,362,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(118,118,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(119,119,366,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(120,120,369,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(121,121,373,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(122,122,376,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(123,123,377,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(124,124,380,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(125,125,383,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(126,126,386,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(127,127,389,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(128,128,392,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(129,129,395,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(130,130,399,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(131,131,402,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(132,132,405,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(133,133,409,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(134,134,412,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(135,135,480,'2026-01-03 21:46:52','2026-01-03 21:46:52');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(136,136,483,'2026-01-03 21:47:07','2026-01-03 21:47:07');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(137,137,486,'2026-01-03 21:47:19','2026-01-03 21:47:19');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(138,138,492,'2026-01-03 21:47:33','2026-01-03 21:47:33');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(139,139,365,'2026-01-03 21:47:42','2026-01-03 21:47:42');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(140,139,495,'2026-01-03 21:47:42','2026-01-03 21:47:42');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(141,140,496,'2026-01-03 21:47:58','2026-01-03 21:47:58');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(142,141,497,'2026-01-03 21:48:09','2026-01-03 21:48:09');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(143,142,500,'2026-01-03 21:50:24','2026-01-03 21:50:24');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(144,143,503,'2026-01-03 21:50:26','2026-01-03 21:50:26');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(145,144,507,'2026-01-03 21:50:27','2026-01-03 21:50:27');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(146,145,510,'2026-01-03 21:50:28','2026-01-03 21:50:28');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(147,146,511,'2026-01-03 21:50:29','2026-01-03 21:50:29');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(148,147,516,'2026-01-03 21:50:30','2026-01-03 21:50:30');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(149,148,519,'2026-01-03 21:50:39','2026-01-03 21:50:39');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(150,149,522,'2026-01-03 21:50:40','2026-01-03 21:50:40');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(151,150,528,'2026-01-03 21:50:42','2026-01-03 21:50:42');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(152,151,530,'2026-01-03 21:50:43','2026-01-03 21:50:43');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(153,152,533,'2026-01-03 21:50:45','2026-01-03 21:50:45');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(154,153,536,'2026-01-03 21:50:46','2026-01-03 21:50:46');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(155,154,538,'2026-01-03 21:50:57','2026-01-03 21:50:57');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(156,155,541,'2026-01-03 21:50:58','2026-01-03 21:50:58');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(157,156,544,'2026-01-03 21:50:59','2026-01-03 21:50:59');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(158,157,547,'2026-01-03 21:51:00','2026-01-03 21:51:00');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(159,158,551,'2026-01-03 21:51:01','2026-01-03 21:51:01');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(160,159,555,'2026-01-03 21:51:02','2026-01-03 21:51:02');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(161,160,558,'2026-01-03 21:51:13','2026-01-03 21:51:13');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(162,161,561,'2026-01-03 21:51:14','2026-01-03 21:51:14');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(163,162,564,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(164,163,568,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(165,164,573,'2026-01-03 21:51:20','2026-01-03 21:51:20');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(166,165,577,'2026-01-03 21:51:21','2026-01-03 21:51:21');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(167,166,580,'2026-01-03 21:52:33','2026-01-03 21:52:33');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(168,167,585,'2026-01-03 21:52:36','2026-01-03 21:52:36');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(169,168,588,'2026-01-03 21:52:38','2026-01-03 21:52:38');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(170,169,594,'2026-01-03 21:52:39','2026-01-03 21:52:39');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(171,170,600,'2026-01-03 21:54:09','2026-01-03 21:54:09');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(172,171,603,'2026-01-03 21:54:17','2026-01-03 21:54:17');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(173,172,607,'2026-01-03 21:54:18','2026-01-03 21:54:18');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(174,173,611,'2026-01-03 21:54:20','2026-01-03 21:54:20');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(175,174,615,'2026-01-03 21:54:21','2026-01-03 21:54:21');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(176,175,618,'2026-01-03 21:54:22','2026-01-03 21:54:22');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(177,176,622,'2026-01-03 21:54:23','2026-01-03 21:54:23');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(178,177,626,'2026-01-03 21:54:24','2026-01-03 21:54:24');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(179,178,629,'2026-01-03 21:54:25','2026-01-03 21:54:25');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(180,179,633,'2026-01-03 21:54:26','2026-01-03 21:54:26');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(181,180,636,'2026-01-03 21:55:40','2026-01-03 21:55:40');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(182,181,640,'2026-01-03 21:55:41','2026-01-03 21:55:41');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(183,182,644,'2026-01-03 21:55:42','2026-01-03 21:55:42');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(184,183,652,'2026-01-03 21:55:43','2026-01-03 21:55:43');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(185,184,655,'2026-01-03 21:55:44','2026-01-03 21:55:44');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(186,185,661,'2026-01-03 21:55:45','2026-01-03 21:55:45');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(187,186,664,'2026-01-03 21:55:45','2026-01-03 21:55:45');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(188,187,667,'2026-01-03 21:55:46','2026-01-03 21:55:46');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(189,188,670,'2026-01-03 21:55:47','2026-01-03 21:55:47');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(190,189,674,'2026-01-03 21:55:48','2026-01-03 21:55:48');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(191,190,678,'2026-01-03 21:57:00','2026-01-03 21:57:00');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(192,191,681,'2026-01-03 21:58:04','2026-01-03 21:58:04');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(193,192,684,'2026-01-03 21:58:08','2026-01-03 21:58:08');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(194,193,686,'2026-01-03 21:58:11','2026-01-03 21:58:11');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(195,194,758,'2026-01-04 20:18:20','2026-01-04 20:18:20');
+INSERT INTO edge_requirements(id,edge_id,requirement_expression_id,created_at,updated_at) VALUES(196,195,761,'2026-01-04 20:18:20','2026-01-04 20:18:20');
+COMMIT;
@@ -0,0 +1,237 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE edges (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name VARCHAR(50) NOT NULL,
+    background VARCHAR(20) NOT NULL, -- Edge category: Background, Combat, Leadership, Power, Professional, Social, Weird
+    source VARCHAR(20) NOT NULL DEFAULT 'core',
+    description TEXT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    can_take_multiple_times BOOLEAN NOT NULL DEFAULT 0, -- Whether this edge can be taken more than once
+    UNIQUE(name, source)
+);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(1,'Alertness','Background','core','Not much gets by this hero. He''s very observant and perceptive, and adds +2 to his Notice rolls to hear, see, or otherwise sense the world around him.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(2,'Ambidextrous','Background','core','Your warrior is as deft with his left hand as he is with his right. He ignores the Off-Hand penalty. If holding a weapon in each hand, Ambidextrous characters may stack Parry bonuses (if any) from both weapons.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(3,'Arcane Background','Background','core','Exposure to strange energies, studies at a magical school, or gifts from divine or spiritual entities sometimes grant champions their own powers and abilities. Such events lead to the Arcane Background Edge and the development of supernatural abilities. See Chapter Five for a complete description of Arcane Backgrounds, powers, and how to use them.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(4,'Arcane Resistance','Background','core','Magic and the supernatural hold little sway with this individual. Whether by nature, heritage, or training he''s particularly resistant to magic, psionics, weird science, and other supernatural energies. Enemy arcane abilities targeting the hero suffer a −2 penalty and magical damage is reduced by 2 as well. If a hostile power fails to affect the hero due to this penalty, it still activates and consumes Power Points (and may still affect other targets). This includes magical bonuses granted to weapons, such as the smite power or the damage bonus of magical weapons.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(5,'Improved Arcane Resistance','Background','core','As above, but the penalty to the arcane skill roll and damage are increased to 4.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(6,'Aristocrat','Background','core','This individual was born into privilege or came into it later in life. She may or may not have money (the Rich or Filthy Rich Edge), but she still walks in the elite social circles of the setting. Aristocrats add +2 to Persuasion when Networking with the local elite, captains of industry, nobles, or other aristocrats. They also add +2 to Common Knowledge rolls made to know the etiquette of the upper class, recognize family trees or heraldry, or recall gossip concerning others of their station.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(7,'Attractive','Background','core','It''s no secret people are more willing to help those they find physically attractive. Your character adds +1 to Performance and Persuasion rolls if the target is attracted to his general type (gender, sex, species, etc.).','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(8,'Very Attractive','Background','core','Your hero is drop-dead gorgeous. He increases his Performance and Persuasion bonus to +2.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(9,'Berserk','Background','core','Berserkers become wild and nearly uncontrollable when the "red rage" takes them, but they are deadly killing machines as well! Immediately after suffering a Wound or a Shaken result (from physical damage only), your hero must make a Smarts roll or go Berserk. She can voluntarily fail this check if she likes. Going Berserk: FURY - Strength increases a die type, every melee attack must be a Wild Attack, cannot use skills requiring wit or concentration. ENRAGED - +2 to Toughness, ignore one level of Wound penalties. RECKLESS ABANDON - Critical Failure on Fighting hits random target in range. After five consecutive rounds, take a level of Fatigue. At ten rounds, take another level of Fatigue and rage ends. May end rage with Smarts −2 roll as free action.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(10,'Brave','Background','core','Those with this Edge have learned to master their fear, or have dealt with so many horrors they''ve become jaded. These valiant explorers add +2 to Fear checks and subtract 2 from any result on the Fear Table.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(11,'Brawny','Background','core','Your bruiser is very large or perhaps just built like a solid rock. His Size increases by +1 (and therefore Toughness by 1). This Edge may not be taken by characters with a Size of +3 or more. Brawny characters treat their Strength as one die type higher for Encumbrance and Minimum Strength to use weapons, armor, or equipment without penalty. This Edge doesn''t stack with the Armor Interference rule for very small characters.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(12,'Brute','Background','core','Brutes are powerful individuals who rely on raw strength more than finesse. Athletics is linked to Strength instead of Agility for this character. He may also resist Athletics Tests with Strength instead of Agility. Finally, the character increases the Short Range of any thrown weapon by +1.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(13,'Charismatic','Background','core','This individual has a way with words and a magnetic personality. He knows how to work a crowd, calm a mob, and convince others of his point of view. Charismatic characters may reroll Persuasion rolls once per attempt.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(14,'Elan','Background','core','Elan is a French word meaning style or flair. Those with this Edge have an inner reserve of confidence and energy that pushes them to greatness. When a character with Elan spends a Benny to reroll a Trait roll, he adds +2 to the final total.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(15,'Fame','Background','core','Whether your hero is a gunslinger, a sorcerer, or a scientist, he is known far and wide for his skills or reputation. When performing in their area of expertise, the character makes double their normal fee. The character also adds +1 to Persuasion rolls when dealing with those who are friendly and know who he is (Game Master''s call).','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(16,'Famous','Background','core','The character is a true celebrity. When performing in their area of expertise, they make five times their normal fee. The character also adds +2 to Persuasion rolls when dealing with those who are friendly and know who they are (Game Master''s call).','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(17,'Fast Healer','Background','core','Those with this Edge heal faster than most. They may make a natural healing roll once per three days instead of the usual five days.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(18,'Fleet-Footed','Background','core','Whether through genetics, training, or magic, this individual is fast. His Pace is increased by +2 and his running die increases one step (from d6 to d8, for example).','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(19,'Linguist','Background','core','This character has an ear for languages. She begins play knowing a number of languages equal to half her Smarts die (round down). A character with a d8 in Smarts, for example, starts with four languages. Each language is a separate Language skill set at d6. The player may raise these skills normally during character creation or by using Advances.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(20,'Luck','Background','core','The adventurer seems to be blessed by fate, karma, the gods, or whatever external forces he believes in (or believe in him!). He draws one extra Benny at the start of each game session, allowing him to succeed at important tasks more often than most, and survive incredible dangers.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(21,'Great Luck','Background','core','The character is blessed with exceptional fortune. He draws two extra Bennies instead of one at the start of each game session.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(22,'Quick','Background','core','Quick characters have lightning-fast reflexes and a cool head. Whenever a Quick character is dealt an Action Card of Five or lower, he may discard and draw again until he gets a card higher than Five.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(23,'Rich','Background','core','Whether the individual was born into wealth or earned it through hard work and success, he has more personal capital than most. Rich heroes start with three times the normal starting funds for the setting. If a regular income is appropriate for this setting, the character earns a $150,000 annual salary.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(24,'Filthy Rich','Background','core','This fortunate individual is extremely wealthy. He has five times the normal starting funds and a $500,000 annual salary if such is appropriate to the setting.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(25,'Combat Reflexes','Combat','core','Your warrior recovers quickly from shock and trauma. He adds +2 to his rolls when attempting to recover from being Shaken or Stunned.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(26,'Steady Hands','Combat','core','Firing from the back of a horse or moving vehicle is tricky business, but your adventurer has figured it out. She ignores the Unstable Platform penalty. This also helps when running, reducing the usual penalty from −2 to −1.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(27,'Iron Jaw','Combat','core','The hero can shrug off even extreme blows. He adds +2 to Soak rolls and Vigor rolls to avoid Knockout Blows.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(28,'Block','Combat','core','Through hard-fought experience your hero has learned to defend himself in vicious hand-to-hand combat. His Parry increases by +1 and any Gang Up bonus against him is reduced by one.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(29,'Improved Block','Combat','core','The hero''s Parry bonus is now +2 and the Gang Up bonus against him is reduced by 2.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(30,'Brawler','Combat','core','His fists hit like hammers or his talons cut like scythes. His body feels like it''s made of stone. Brawlers increase their Toughness by 1 and roll Strength +d4 when hitting with their fists or feet (or claws if they have them). If they already have a damage die from Claws, the Martial Artist Edge, etc., increase the damage die type by one instead. The Brawler Edge doesn''t make the character''s fists Natural Weapons.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(31,'Bruiser','Combat','core','The fighter increases his Toughness an additional +1, and the damage caused with his fists or claws another die type.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(32,'Calculating','Combat','core','A few seconds to study your foe''s actions gives your hero a major advantage. When his Action Card is a Five or less, he ignores up to 2 points of penalties on one action that turn, which can include Multi-Action, cover, Range, and even Wound penalties.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(33,'Counterattack','Combat','core','Fighters with this Edge deal instant punishment for an enemy''s mistakes. Once per round (if not Shaken or Stunned), the character receives a Free Attack against one failed Fighting attack against him. The counterattack takes place immediately (before other hits against the hero on the same Action Card, if any).','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(34,'Improved Counterattack','Combat','core','As above but the hero gets a Free Attack against up to three failed attacks each round.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(35,'Dead Shot','Combat','core','When your Action Card is a Joker, double the total damage of your first successful Athletics (throwing) or Shooting roll this round.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(36,'Dodge','Combat','core','The hero can anticipate attacks or move erratically under fire. Unless the victim of a surprise attack and taken completely unaware, Dodge subtracts 2 from all ranged attacks made against the character. Dodge does not stack with actual cover, however.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(37,'Improved Dodge','Combat','core','The hero adds +2 when Evading area effect attacks.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(38,'Double Tap','Combat','core','Experienced firearms experts fire two shots in rapid succession without spoiling their aim. Double Tap can only be used with weapons that have a Rate of Fire of 1 and can fire two shots without needing to manually reload. It adds +1 to hit and damage at the cost of one extra bullet. This is per action, so a shooter can Double Tap more than once if she performs a Multi-Action. Double Tap cannot be combined with Rapid Fire. If used with a weapon capable of Three-Round Burst, it adds +2 to Shooting and damage instead of +1 and expends six bullets.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(39,'Extraction','Combat','core','When a character withdraws from melee, adjacent attackers get a free Fighting attack against him. This is a very dangerous proposition for most, but not your wily champion. When moving away from adjacent foes, one of them (player''s choice), doesn''t get his free Fighting attack.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(40,'Improved Extraction','Combat','core','Up to three foes'' (player''s choice) don''t get attacks when your warrior moves out of melee with them.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(41,'Feint','Combat','core','When performing a Test with the Fighting skill, you can choose to make the foe resist with Smarts instead of Agility.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(42,'First Strike','Combat','core','Once per round, as long as he''s not Shaken or Stunned, the hero gets a free Fighting attack against a foe immediately after he moves into Reach.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(43,'Improved First Strike','Combat','core','As above but the hero may attack up to three foes each turn.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(44,'Free Runner','Combat','core','Your character practices "parkour" or is just very skilled at running, jumping, swinging, scampering up walls, and over obstacles. As long as there are obstacles she can bound on, bounce off, or swing on, she moves at her full Pace on Difficult Ground when on foot. She also adds +2 to her Athletics rolls when climbing and in foot Chases.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(45,'Frenzy','Combat','core','As a limited action, a character with Frenzy may roll a second Fighting die with any one of his Fighting attacks for the turn. The extra die may be allocated to the same or different targets as he sees fit. Resolve each separately.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(46,'Improved Frenzy','Combat','core','As Frenzy but the fighter adds a third Fighting die to his Fighting attack made with Frenzy this turn.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(47,'Giant Killer','Combat','core','The bigger they are, the harder they are to kill. At least for most. Your hero knows how to find the weak points in the most massive of foes. He adds +1d6 damage when attacking creatures who are three or more Sizes larger than himself. A human (Size 0) gets the bonus against a creature of Size 3 or greater.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(48,'Hard To Kill','Combat','core','This adventurer has more lives than a herd of cats. He may ignore his Wound penalties when making Vigor rolls to avoid Bleeding Out.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(49,'Harder to Kill','Combat','core','Your hero is tougher to kill than Rasputin. If he is ever "killed," roll a die. On an odd result, he''s dead as usual. On an even roll, he''s Incapacitated but somehow escapes death. He may be captured, stripped of all his belongings, or mistakenly left for dead, but he somehow survives.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(50,'Improvisational Fighter','Combat','core','Heroes often find themselves fighting with pieces of equipment or furnishings not designed for combat. A brawler with this Edge has a knack for using such improvised weapons. He ignores the usual −2 penalty when wielding them.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(51,'Killer Instinct','Combat','core','This hero hates losing. He gets a free reroll in any opposed Test he initiates.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(52,'Level Headed','Combat','core','Fighters who can keep their cool when everyone else is running for cover make deadly combatants. A hero with this Edge draws an additional Action Card in combat and chooses which to use.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(53,'Improved Level Headed','Combat','core','As above but the hero draws two additional cards and chooses which to keep.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(54,'Marksman','Combat','core','The hero is a natural with ranged weapons. If she doesn''t move in a turn and fires no more than a Rate of Fire of 1 as her first action, she may add +1 to an Athletics (throwing) or Shooting roll, or ignore up to 2 points of penalties from Called Shots, Cover, Range, Scale, or Speed. This is a lesser version of the Aim maneuver and does not stack with it. Marksman doesn''t apply to additional attacks after the first.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(55,'Martial Artist','Combat','core','The fighter has trained in basic martial arts. Her fists and feet are weapons so she''s always considered armed. She adds +1 when striking with them and causes Strength+d4 damage. If she already has a Strength damage die from the Claws ancestral ability or the Brawler Edge, increase the damage a die type. Martial Artist does not add to damage from other Natural Weapons such as fangs or horns.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(56,'Martial Warrior','Combat','core','Increase the warrior''s Fighting bonus to +2 and her damage die an additional step.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(57,'Mighty Blow','Combat','core','If your Action Card is a Joker, double the damage of your first successful Fighting attack this round.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(58,'Nerves of Steel','Combat','core','Your hero has learned to fight on through the most intense pain. He may ignore 1 point of Wound penalties.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(59,'Improved Nerves of Steel','Combat','core','The hero ignores two points of Wound penalties.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(60,'No Mercy','Combat','core','When this killer spends a Benny to reroll damage, he adds +2 to his final total.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(61,'Rapid Fire','Combat','core','The shooter is practiced at taking quick and accurate shots. As long as she''s armed with a fast-firing ranged weapon of some sort (such as a revolver or semi-automatic) and has enough ammunition to do so, she may increase her weapon''s Rate of Fire by 1 for any one of her Shooting attacks that turn.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(62,'Improved Rapid Fire','Combat','core','The shooter may now increase her weapon''s Rate of Fire by 1 twice in the same turn (via a Multi-Action).','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(63,'Rock and Roll!','Combat','core','Experienced shooters learn to compensate for the recoil of fully automatic weapons. If a character with this Edge doesn''t move on his turn, he ignores the Recoil penalty when firing at a Rate of Fire of 2 or higher.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(64,'Sweep','Combat','core','As a limited action, Sweep allows a character to make a single Fighting attack with a two-handed weapon and apply it against all targets in his Reach (friends and foes alike). Resolve damage separately for each enemy that''s hit. A Sweep made without a two-handed weapon suffers a −2 penalty.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(65,'Improved Sweep','Combat','core','As above but the whirlwind of death is able to avoid allies when using Sweep.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(66,'Trademark Weapon','Combat','core','The hero knows one unique weapon (Excalibur, Old Betsy, Sting) like the back of his hand. When using it, he adds +1 to his Athletics (throwing), Fighting, or Shooting rolls, and +1 to Parry when readied (even if it''s a ranged weapon). A fighter can take this Edge multiple times, applying it to a different weapon each time. If a Trademark Weapon is lost, he can replace it but the benefits don''t kick in for a few days (however long the GM feels is dramatically appropriate).','2025-10-08 06:13:12','2025-10-08 06:13:12',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(67,'Improved Trademark Weapon','Combat','core','As above but the bonuses when using the weapon increase to +2.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(68,'Two-Fisted','Combat','core','If a character makes a Fighting attack with one action and another from a different hand in a later action, the second attack doesn''t inflict a Multi-Action penalty. The Off-Hand penalty still applies unless he''s Ambidextrous, however. If the character has Two-Gun Kid, the second action may also be a ranged attack.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(69,'Two-Gun Kid','Combat','core','Two-Gun Kid works just like Two-Fisted, but with ranged weapons, allowing her to fire or throw a weapon in each hand as two different actions but without triggering a Multi-Action penalty. If the character has Two-Fisted, the second action may also be a melee attack.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(70,'Command','Leadership','core','Command is the basic ability to give clear instructions and support to allies in the thick of battle. Extras in Command Range add +1 to their Spirit rolls when attempting to recover from being Shaken and Vigor when attempting to recover from being Stunned.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(71,'Command Presence','Leadership','core','A booming voice, effective commands, natural charisma, or simple training results in a much more effective combat element. A hero with this Edge has a Command Range of 10″ (20 yards).','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(72,'Fervor','Leadership','core','A simple phrase uttered by a great leader can sometimes have momentous results. A commander with this ability can inspire his troops to bloody fervor by yelling a motto, slogan, or other inspirational words. Extras in range add +1 to their Fighting damage rolls.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(73,'Hold the Line!','Leadership','core','Hold the Line strengthens the will of any Extras under the hero''s command, adding +1 to their Toughness.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(74,'Inspire','Leadership','core','Exceptional leaders inspire those around them to great feats of valor and determination. As a limited action, the hero may roll his Battle skill to Support one type of Trait roll, and apply it to all allied Extras in Command Range. A leader could Support all Shooting attacks in range, for example, or all Spirit rolls to recover from being Shaken. Inspire requires some sort of communication with those it affects.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(75,'Natural Leader','Leadership','core','This leader has proven herself time and time again, gaining the respect of all those who fight by her side. Any Leadership Edge that says it applies only to Extras now applies to Wild Cards as well.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(76,'Tactician','Leadership','core','The commander has a natural grasp of small unit tactics and can take advantage of rapidly changing situations. A Tactician is dealt an extra Action Card each round of combat or a chase—kept separate from his own cards. At the start of the round, he may discard it or give it to any Extra (or group of Extras sharing an Action Card) in Command Range. The player or GM controlling the receiving character or group can decide whether to accept and replace her current Action Card, or discard it.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(77,'Master Tactician','Leadership','core','The Tactician now gets a total of two extra Action Cards to distribute each round.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(78,'Artificer','Power','core','An Artificer can create Arcane Devices, magical or weird science items that can be used by anyone. These devices allow the Artificer to share their arcane powers with allies who may not have arcane abilities of their own.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(79,'Channeling','Power','core','A character with Channeling is particularly efficient at channeling arcane energy. When she gets a raise on her arcane skill roll, the Power Point cost of the power is reduced by 1. This can take the cost to 0.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(80,'Concentration','Power','core','Concentration allows an arcane character to extend the Duration of their powers through sheer force of will. The base Duration of any non-Instant power is doubled, including powers that are maintained.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(81,'Extra Effort','Power','core','Those with this Edge can push their Focus power beyond its normal limits. After making a Focus roll, the character may spend 1 Power Point to increase the total by +1, or 3 Power Points to increase it by +2. This cannot be used to improve a Critical Failure.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(82,'Gadgeteer','Power','core','A Gadgeteer can quickly jury-rig devices from spare parts. The character may spend up to 3 Power Points to activate any power available to Weird Scientists of their Rank or lower with a base cost of 3 or less. The device is created with a Weird Science roll at −2, and is used immediately (no additional roll is required). It falls apart after use.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(83,'Holy/Unholy Warrior','Power','core','Believers with this Edge have learned to channel their faith into protection from harm. When making a Soak roll, the character may spend up to 4 Power Points. For each Power Point spent, add +1 to the final Soak roll total (maximum +4).','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(84,'Mentalist','Power','core','Mentalists are masters of mind-affecting powers and mental combat. The character adds +2 to opposed Psionics rolls, whether using their powers against a foe or defending against a rival mentalist.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(85,'New Powers','Power','core','A character with this Edge learns two new powers. She may choose from any powers of her Rank or lower normally available to her particular Arcane Background. Alternatively, she may add a new Trapping to one existing power instead. This Edge may be taken multiple times, but a character may never have more Trappings on a power than her arcane skill die type (a d8 arcane skill allows a power to have up to four additional Trappings).','2025-10-08 06:13:12','2025-10-08 06:13:12',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(86,'Power Points','Power','core','Experienced arcane characters learn to channel more energy as they grow in power. Each time this Edge is chosen, the character gains 5 additional Power Points. This Edge may be selected once per Rank (Novice, Seasoned, Veteran, Heroic). At Legendary Rank, it may only be taken twice, granting a maximum of 2 additional Power Points total at that Rank.','2025-10-08 06:13:12','2025-10-08 06:13:12',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(87,'Power Surge','Power','core','When a Wild Card with this Edge is dealt a Joker for her Action Card, she recovers 10 Power Points. She may not exceed her usual limit.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(88,'Rapid Recharge','Power','core','A character with Rapid Recharge recovers their Power Points more quickly than usual. Power Points recharge at a rate of 10 per hour instead of the usual 5 per hour.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(89,'Improved Rapid Recharge','Power','core','A character with Improved Rapid Recharge has mastered the art of recovering their Power Points at an exceptional rate. Power Points recharge at a rate of 20 per hour instead of the usual 10 per hour (from Rapid Recharge).','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(90,'Soul Drain','Power','core','A character with Soul Drain can sacrifice their own vitality to recover Power Points. The character may take a level of Fatigue to immediately recover up to 5 Power Points. If the character is already Fatigued, they may take an additional level of Fatigue to become Exhausted and recover 5 more Power Points (10 total). This Fatigue can only be recovered naturally through rest and cannot be removed by powers or other supernatural means.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(91,'Wizard','Power','core','Wizards are masters of manipulating magical energies and can alter the appearance and manifestation of their spells. When casting a power, the Wizard may spend 1 additional Power Point to change the power''s Trapping to any other Trapping available for that power.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(92,'Ace','Professional','core','Aces are pilots and drivers who have a special affinity with their car, boat, plane, or other vehicle. They ignore two points of penalties to any Boating, Driving, or Piloting roll, and may spend Bennies to Soak damage for any vehicle they control or command, using the appropriate Boating, Driving, or Piloting skill instead of Vigor. Each success and raise negates a Wound.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(93,'Acrobat','Professional','core','The Acrobat gets one free reroll on Athletics totals that involve balance, tumbling, or grappling. It doesn''t affect rolls to interrupt actions, climb, swim, or throw.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(94,'Combat Acrobat','Professional','core','The tumbler leaps quickly about so that attacks against her are made at −1 as long as she''s aware of the attack, can reasonably move about, and isn''t suffering any Encumbrance or Minimum Strength penalties.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(95,'Assassin','Professional','core','Assassins are trained killers who know how to kill even the toughest foes. They add +2 to damage rolls when their foe is Vulnerable or they have The Drop.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(96,'Investigator','Professional','core','Investigators spend a great deal of time researching ancient legends, working the streets, or deducing devilish mysteries. Some of these heroes are actual private investigators while others are sleuthing mages in a fantasy world or inquisitive college professors stumbling upon Things Man Was Not Meant to Know. Investigators add +2 to Research rolls and Notice rolls made to search through desks for important papers, sift through stacks of junk mail for something of note, or spy obscured items from piles of junk or debris.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(97,'Jack-Of-All-Trades','Professional','core','Through advanced schooling, book-learning, computer-enhanced skill programs, or just amazing intuitive perception, your hero has a talent for picking up skills on the fly. There''s little he can''t figure out given a little time and a dash of luck. The character makes a Smarts roll as an action after observing or studying some subject. She gains a d4 in the relevant skill with success, or d6 with a raise. She may try again after an hour of study, trial and effort, or immersion if she fails or wants to try for a raise. This lasts until the character attempts to learn a different subject, whether she''s successful or not.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(98,'McGyver','Professional','core','A McGyver can improvise a device from common resources when the need arises. Given a few simple items, he can make a Repair roll to craft improvised weapons, explosives, or tools, that last until used or the end of the encounter (GM''s call). This takes one entire turn, and he can''t move or take any other actions while constructing the device. Failure means the device isn''t ready. A Critical Failure means he doesn''t have the right materials and can''t create the device this encounter. Success creates a minor explosive (2d4 explosive in a Small Blast Template), a one shot projectile weapon like a "zip gun" (Range 5/10/20, Damage 2d6), rickety raft, electrical source, etc. A raise creates a larger explosive (2d6 in a Medium Blast Template or 2d4 in a Large), a better ranged weapon (five shots, 2d8 damage, Range 10/20/40), a more stable raft, a more powerful battery, etc. The quality or power of the creation is completely up to the Game Master, but creativity should be rewarded, particularly in the most dire and dramatic situations.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(99,'Mr. Fix It','Professional','core','The mechanic adds +2 to Repair rolls. With a raise, he halves the time normally required to fix something. This means if a particular Repair job says a raise repairs it in half the time, a Mr. Fix It can finish the job in one-quarter the time with a raise.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(100,'Scholar','Professional','core','Learned professors, devoted students, and amateur enthusiasts spend great amounts of time and energy studying particular subjects. They become experts in these fields, and rarely fail to answer questions in their particular area of expertise. Pick any one of the following skills: Academics, Battle, Occult, Science, or a Smarts-based "knowledge"-type skill allowed in your setting, and add +2 to the total whenever they''re used. This Edge may be taken more than once if applied to different skills.','2025-10-08 06:13:12','2025-10-08 06:13:12',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(101,'Soldier','Professional','core','Professional soldiers get used to carrying heavy loads, and enduring harsh conditions. After a few days getting used to their gear (GM''s call), they treat their Strength as one die type higher when determining Encumbrance (page 61) and Minimum Strength to use armor, weapons, and equipment without a penalty (page 60). (This stacks with the Brawny Edge.) They also get a free reroll on Vigor rolls made to survive environmental hazards (see Hazards, page 115).','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(102,'Thief','Professional','core','Thieves specialize in deceit, treachery, and acrobatics. They can be invaluable where traps must be detected, walls must be climbed, and locks must be picked. Thieves know how to use protrusions on walls and window ledges to climb the tallest of buildings, scampering through streets and back alleys like cats. They add +1 to Athletics rolls made to climb in urban areas. Thieves also know how to use the dark areas between the streetlights of cities to conceal their movements, and add +1 to Stealth rolls when in an urban environment. Finally, these rogues are—not surprisingly—adept at Thievery itself, adding +1 to those rolls in all circumstances.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(103,'Woodsman','Professional','core','Woodsmen are rangers, scouts, and hunters who are more at home in the wilderness than in urban areas. They are skilled trackers and scouts, and know how to live off the land for months at a time. Woodsmen add +2 to Survival rolls, and Stealth rolls made in the wild (not towns, ruins, or underground).','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(104,'Bolster','Social','core','Belittling or humiliating an enemy can also boost your ally''s spirits. When this character successfully Tests a foe, he may also remove the Distracted or Vulnerable state from one of his allies.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(105,'Common Bond','Social','core','Selfless heroes and determined leaders know their greatest strength often comes from their companions, and are willing to give some of their own fortune, fate, or luck to support them. A character with this Edge may freely give her Bennies to any other character she can communicate with. The player should explain what form this takes, from a quick shout of encouragement to a welcome pat on the back.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(106,'Connections','Social','core','Your heroine is connected to people or organizations who can help her when the chips are down. It might be the mob, the Feds, a union, or even other adventurers. Connections may be taken more than once, selecting a new faction or contact each time. Once per session, and assuming she can get in touch with them, the heroine can call on her friends for a favor. The favor depends on the nature of the contact (GM''s call), but might include a loan, gear, a few allied fighters, transportation, information, or even a professional with critical skills the party doesn''t have, like a hacker or scholar.','2025-10-08 06:13:12','2025-10-08 06:13:12',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(107,'Humiliate','Social','core','Those with a cruel wit can destroy a rival''s ego in a single remark or well-timed gesture. Your hero gets a free reroll on Taunt Tests.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(108,'Menacing','Social','core','Being a brutish lout isn''t always a drawback if you know how to use it. Menacing allows a character to put her bad looks or worse attitude to good use. The goon adds +2 to her Intimidation rolls.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(109,'Provoke','Social','core','Clever characters can manipulate their foes, drawing enemy focus on themselves to protect their allies. Once per turn, when your hero uses Taunt for a Test and gets a raise, she may Provoke the foe. In addition to all the usual effects of the success and raise, the enemy suffers a −2 penalty to affect any other target besides the one who provoked her. This stacks with Distracted but not further instances of Provoke. Provoke lasts until a Joker is drawn, someone else Provokes the target, or the encounter ends. Provoke can affect multiple targets, and may be combined with Rabble Rouser.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(110,'Rabble-Rouser','Social','core','This instigator knows how to rile up several enemies at once. As a limited action, a character with this Edge can make a social Test with Intimidation or Taunt against all enemies in a Medium Blast Template. The targets must be able to see and hear the hero clearly. Each defender resists and is affected by the Test separately.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(111,'Reliable','Social','core','People know they can depend on your hero when they need assistance. He gets a free reroll on any Support roll.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(112,'Retort','Social','core','This verbal duelist can turn the slings and arrows of social combat right back at the unwitting fools who hurled them. If a character with Retort gets a raise when resisting an Intimidation or Taunt Test, the foe is Distracted.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(113,'Streetwise','Social','core','Streetwise characters know how to find the local black market, fence stolen goods, avoid the local law (or criminal element!), lay low when the heat''s on, obtain illegal weapons, find out which "boss" is hiring muscle, or similar shady activities. Streetwise characters add +2 to Intimidation or Persuasion rolls made to Network with shady or criminal elements. They also add +2 to Common Knowledge rolls pertaining to the types of disreputable activities listed above.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(114,'Strong Willed','Social','core','Self-confidence is powerful armor against those who attempt to attack this individual''s will. He adds +2 to his total when resisting Tests with Smarts or Spirit.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(115,'Iron Will','Social','core','The hero now adds his Strong Willed bonus to resist powers and negate their effects. Iron Will doesn''t stack with Brave, nor does it apply to subsequent rolls resulting from powers such as being Shaken by a damaging power, fear, or the like.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(116,'Work the Room','Social','core','Your hero''s words don''t just inspire those they''re directed at—they often inspire others as well. As a limited action, you can use Work the Room to roll a second skill die when Supporting with Persuasion or Performance. The additional die Supports another ally normally and can apply to any Trait chosen. It need not be the same Trait for both allies.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(117,'Work the Crowd','Social','core','As Work the Room but the hero now adds a third skill die to his Support roll this turn and Supports an additional ally.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(118,'Beast Bond','Weird','core','Some individuals have an extraordinary bond with their animal companions. These characters may spend their own Bennies for any animals under their control, including mounts, pets, familiars, and so on.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(119,'Beast Master','Weird','core','Animals take to your hero quickly and easily. They won''t attack him unless he attacks them first or they''re enraged for some reason. The Beast Master''s "animal magnetism" is so great he''s attracted a loyal animal of some sort as well. This is typically an animal of Size 0 or smaller, subject to the GM''s approval. The beast is an Extra and doesn''t Advance in Rank or abilities. If a pet is dismissed or killed, the hero gains a replacement in 1d4 days. More Powerful Companions: Beast Master may be taken more than once. Choose one of the effects below each time it''s chosen: • Gain an additional pet. • Increase one of the pet''s Traits one die type (only one Trait per pet). • Increase the maximum Size pet you may have by +1, to a maximum of 3. • Make one pet a Wild Card. (The hero must be of Heroic Rank).','2025-10-08 06:13:12','2025-10-08 06:13:12',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(120,'Champion','Weird','core','Champions are holy (or unholy) men and women chosen to fight for a particular deity or religion. Most are pious souls ready and willing to lay down their lives for a greater cause, but some may have been born into the role and follow their path with some reluctance. Champions fight the forces of darkness (or good), adding +2 damage when attacking supernaturally evil (or good if they''re evil) creatures. The bonus applies to area effect damage, ranged attacks, powers, etc. The GM must decide which foes this bonus applies to, but generally it''s any evil (or good!) creature born of magic or with any supernatural abilities.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(121,'Chi','Weird','core','Your hero''s martial arts training goes beyond the norm and into the realm of the mystical. At the start of each combat encounter, she gains a "Chi Point" that can be spent to: • Reroll one of her failed attacks (even a Critical Failure). • Make an enemy discard an attack against her, then reroll it from scratch. • Add +d6 damage to a successful Fighting attack made with her hands, feet, claws, or other Natural Weapons (this may Ace). Unspent Chi is lost at the end of the combat encounter.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(122,'Danger Sense','Weird','core','Your hero can sense when something bad is about to happen. When rolling for Surprise (page 102), he adds +2 to his Notice roll to act in the first round. With a raise, he starts the encounter on Hold. In other situations not covered by the Surprise rules (a sniper shot, pit trap, poisoned drink, etc.), Danger Sense gives him a Notice roll at −2 (or +2 if a Notice roll is usually allowed) to detect the hazard and take appropriate action. If this was an attack and the hero makes his Notice roll, the foe doesn''t get The Drop against him.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(123,'Healer','Weird','core','A hero with this Edge adds +2 to all Healing rolls, whether natural or magical in nature.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(124,'Liquid Courage','Weird','core','This gregarious individual processes alcohol far differently than most. The round after consuming a stiff drink (about eight ounces of hard liquor or equivalent), her Vigor increases one die type (increasing Toughness as well). The drinker can also ignore one level of Wound penalties (which stacks with other abilities that do the same). Smarts, Agility, and all linked skills suffer a −1 penalty for the duration, however. The effect lasts for one hour after it begins, at which point the boozer suffers a level of Fatigue for the next four hours.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(125,'Scavenger','Weird','core','Once per encounter the hero may find, "suddenly remember," or dig up some much-needed piece of equipment, a handful of ammunition, or some other useful device. The Game Master decides what constitutes an encounter, and has the final word on what can and can''t be found.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(126,'Followers','Weird','core','Each time this Edge is chosen, five followers come to fight by the hero''s side. If any are lost, others eventually take their place (how long is up to the GM and the circumstances). Followers must be cared for and generally want a share of whatever loot, treasure, or other rewards the hero acquires. Otherwise, they are completely dedicated to their task. They won''t throw their lives away but are willing to risk them repeatedly in his service. Use the Soldier profile found in Savage Worlds Adventure Edition for the followers. A hero may outfit her allies as she sees fit. Followers Advance just like player characters (see page 48). Use the Allies rules on page 105 to give them distinctive personalities if desired.','2025-10-08 06:13:12','2025-10-08 06:13:12',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(127,'Professional','Weird','core','The character is an expert at a particular skill or attribute (his choice). This increases the Trait and its limit one step (a d12 + 1 becomes a d12 + 2, for example). This Edge may be selected once per Trait.','2025-10-08 06:13:12','2025-10-08 06:13:12',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(128,'Expert','Weird','core','As the Professional Edge, increasing the Trait and its limit one additional step.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(129,'Master','Weird','core','The character''s Wild Die increases to a d10 when rolling the selected Expert Trait.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(130,'Sidekick','Weird','core','A character who triumphs over evil time and time again becomes an inspiration to others. Eventually, one of these young crusaders may attempt to join the hero in his epic quests. The hero gains a Novice Rank sidekick. The sidekick is a Wild Card, starts each session with two Bennies, may Advance, and has abilities that complement his mentor. The player character controls his sidekick just like any other ally, though he may occasionally cause trouble by getting captured, running into danger when he''s not supposed to, and so on. The player should be prepared for his Edge to occasionally become a Hindrance! If the sidekick dies, he isn''t replaced unless the hero chooses this Edge again. Fortunately, heroes may always spend Bennies for their sidekicks as if she had the Common Bond Edge. Sidekicks must actually take that Edge to do the same for their mentors.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(131,'Tough As Nails','Weird','core','Your character keeps going when others fall. She can take four Wounds before she''s Incapacitated (her maximum Wound penalty is still −3).','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(132,'Tougher Than Nails','Weird','core','The hero can take up to five Wounds before she''s Incapacitated! Her maximum Wound penalty is still −3.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(133,'Weapon Master','Weird','core','The warrior increases his Parry by +1 and the bonus damage die for Fighting rolls is a d8 instead of a d6 (see Bonus Damage, page 88). He must be armed to gain these benefits, but this includes the Martial Artist Edge, claws, or other abilities that count as weapons.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(134,'Master of Arms','Weird','core','Increase the hero''s Parry an additional +1 and his Fighting bonus damage die is now a d10.','2025-10-08 06:13:12','2025-10-08 06:13:12',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(135,'Arcane Resistance','Background','fantasy_companion','Magic and the supernatural hold little sway with this individual. Whether by nature, heritage, or training the character is particularly resistant to magic and other supernatural energies. Enemy arcane abilities targeting the hero suffer a −2 penalty and magical damage is reduced by 2 as well. If a hostile power fails to affect the hero due to this penalty, it still activates and consumes Power Points (and may still affect other targets). This includes magical bonuses granted to weapons, such as the smite power or the damage bonus of magical weapons.','2026-01-03 21:46:52','2026-01-03 21:46:52',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(136,'Chosen','Background','fantasy_companion','Prophecies tell of a being destined for greatness. One who will, with the help of her boon companions, do great things. When the Chosen spends Conviction, it lasts until the end of the encounter without having to maintain it with Bennies! Of course, such a boon comes at a great cost. The Chosen has the Enemy (Major) Hindrance—there''s always some powerful being opposed to her quest! Worse, she has a mark of some sort that can''t be removed no matter how much she tries. It might be a tattoo, birthmark, sixth finger, catlike pupils, or some other visible cosmetic oddity, but those who know what to look for can find it. And by whatever narrative means necessary, the mark shows through occasionally, regardless how hard she attempts to cover it with clothing, wraps, or even illusions. This means the hero''s foe and his minions are always on her trail.','2026-01-03 21:47:07','2026-01-03 21:47:07',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(137,'Favored Enemy','Background','fantasy_companion','Your hero has fought a particular type of enemy for many years. She knows how best to attack and defend against them, and where their weak spots are. Choose a type of enemy native to your character''s homeland or backstory, such as undead, fey, demons, constructs, giants, animals, or a particular ancestry such as elves or dwarves. Your hero gets a free reroll when rolling to track (Survival) or attack (Athletics (throwing), Fighting, Shooting) that type of being. You may take this Edge more than once to reflect experience with multiple types of foes.','2026-01-03 21:47:19','2026-01-03 21:47:19',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(138,'Favored Terrain','Background','fantasy_companion','Rangers, druids, and those who spend great amounts of time in the wilderness know how to use it to their advantage. Choose a terrain type (Arctic, Aquatic, Desert, Forest, Hill/Mountain, Jungle, Plains, Swamp, Urban, or Underground). While in his Favored Terrain, your character gets a free reroll to Survival and Notice rolls. In addition, the character draws an additional Action Card for initiative when in his Favored Terrain. He may then choose from all possible Action Cards as usual. Favored Terrain may be taken multiple times, adding a new Favored Terrain each time.','2026-01-03 21:47:33','2026-01-03 21:47:33',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(139,'Fey Blood','Background','fantasy_companion','Elves, half-elves, and certain fey are often resistant to hostile magic. Those with some amount of it in their blood get a free reroll when resisting enemy powers and spell-like effects.','2026-01-03 21:47:42','2026-01-03 21:47:42',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(140,'Heirloom','Background','fantasy_companion','Occasionally, powerful magic items wind up in the hands of relatively novice adventurers. This Edge grants your hero one magic item (or a themed set, GM''s call) chosen from those listed in Chapter Five with a total value of 10,000 gold pieces each time this Edge is taken. This is a one-time only benefit—if the item is destroyed or is a consumable (like a potion), it isn''t replenished. If it''s lost, it must be recovered through adventure!','2026-01-03 21:47:58','2026-01-03 21:47:58',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(141,'Improved Arcane Resistance','Background','fantasy_companion','As Arcane Resistance, but the penalty to the arcane skill roll and damage are increased to 4.','2026-01-03 21:48:09','2026-01-03 21:48:09',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(142,'Charge','Combat','fantasy_companion','A short burst of speed adds significant impact to this hero''s melee damage. If she runs at least 5" (10 yards), she adds +2 to the damage of her first Fighting attack that turn. This may be combined with a Wild Attack.','2026-01-03 21:50:24','2026-01-03 21:50:24',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(143,'Close Fighting','Combat','fantasy_companion','Skilled knife-fighters exploit a foe''s longer reach by slipping inside and striking with deadly precision. A Close Fighter armed with a knife or similar small weapon (GM''s call) can choose a single adjacent target as a free action each turn. Their attacks and Parry for that foe (only) increase by the target''s Reach +1. No bonus is granted if the foe is unarmed or also armed with a small weapon such as a knife.','2026-01-03 21:50:26','2026-01-03 21:50:26',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(144,'Defender','Combat','fantasy_companion','You can share your shield''s Parry and cover bonus with one adjacent ally as a free action. Shield bonuses don''t stack, so use only the highest if a character is protected by more than one.','2026-01-03 21:50:27','2026-01-03 21:50:27',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(145,'Dirty Fighter','Combat','fantasy_companion','Those with this Edge will do anything to win a fight. The dastardly cur is particularly good at tricks. He adds +2 to rolls when performing a Test with the Fighting skill.','2026-01-03 21:50:28','2026-01-03 21:50:28',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(146,'Double Shot','Combat','fantasy_companion','Double Shot allows an archer to fire two arrows in one attack with a regular bow or hurl two thrown weapons as a single attack. As a limited action, the character rolls two Shooting or Athletics (throwing) dice instead of one (this does not increase the Rate of Fire, does not incur Recoil, and uses two projectiles). Each die hits and causes damage separately. (The Wild Die may replace either shot as desired.) The attacker must have a handy source of projectiles to accomplish the feat (a quiver, knives on a bandoleer, etc.).','2026-01-03 21:50:29','2026-01-03 21:50:29',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(147,'Formation Fighter','Combat','fantasy_companion','The warrior has trained to fight alongside others, overwhelming their common foe with synchronized strikes and blows. She increases the Gang Up bonus by an additional +1 for herself and her allies. The maximum Gang Up bonus is still +4 no matter how many Formation Fighters stand together.','2026-01-03 21:50:30','2026-01-03 21:50:30',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(148,'Improved Close Fighting','Combat','fantasy_companion','The Close Fighter''s bonus increases to his target''s Reach +2.','2026-01-03 21:50:39','2026-01-03 21:50:39',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(149,'Improved Double Shot','Combat','fantasy_companion','The attacker may use her Double Shot Edge twice per turn.','2026-01-03 21:50:40','2026-01-03 21:50:40',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(150,'Improved Sneak Attack','Combat','fantasy_companion','The character''s Sneak Attack bonus also now applies if the foe is Distracted.','2026-01-03 21:50:42','2026-01-03 21:50:42',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(151,'Martial Flexibility','Combat','fantasy_companion','Once per encounter, as a free action, the fighter can choose to gain the benefits of a single Combat Edge. He must meet all of the Edge''s Requirements, and the benefits of the chosen Edge end after five rounds.','2026-01-03 21:50:43','2026-01-03 21:50:43',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(152,'Missile Deflection','Combat','fantasy_companion','The hero''s ability with a melee weapon is unparalleled. As long as he has a melee weapon in hand (or the Martial Artist Edge), physical ranged attacks (arrows, axes, powers with physical Trappings, etc.) directed against him (and assuming he can see the attack coming) use his Parry as the base TN.','2026-01-03 21:50:45','2026-01-03 21:50:45',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(153,'Opportunistic','Combat','fantasy_companion','Opportunity is what one makes of it. When a character with this Edge is dealt a Joker, he adds +4 to his Trait and damage rolls instead of +2.','2026-01-03 21:50:46','2026-01-03 21:50:46',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(154,'Really Dirty Fighter','Combat','fantasy_companion','Your character is extremely skilled in tactical deceit. Should he get a raise on a Test, he gains The Drop against that opponent until the foe is no longer Shaken.','2026-01-03 21:50:57','2026-01-03 21:50:57',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(155,'Roar','Combat','fantasy_companion','Powerful lungs, magical ability, or great size allow the being to issue a great, bone-shaking roar! As a limited action, the character can make an Intimidation Test in a Cone Template. All targets within are affected and must resist separately.','2026-01-03 21:50:58','2026-01-03 21:50:58',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(156,'Savagery','Combat','fantasy_companion','Violence is a way of life for some. Those with this Edge hit their foes hard enough that they often don''t get to reply in kind. A character with this Edge causes +4 damage when making a Wild Attack rather than +2.','2026-01-03 21:50:59','2026-01-03 21:50:59',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(157,'Scorch','Combat','fantasy_companion','This Edge improves the damage dealt by a breath weapon by one die type. A breath attack that causes 2d6 damage, for example, does 2d8 after taking this Edge. It also allows the fire breather to use either the Cone or Stream template as desired.','2026-01-03 21:51:00','2026-01-03 21:51:00',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(158,'Shield Wall','Combat','fantasy_companion','Trained soldiers can stand against overwhelming odds and furious hordes with discipline, training, and a sturdy shield. Characters with the Shield Wall Edge (and at least a medium shield) add +1 to their Parry if they are adjacent to one ally who also has the Shield Wall Edge, and +2 if they have two adjacent allies with that Edge (the maximum bonus).','2026-01-03 21:51:01','2026-01-03 21:51:01',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(159,'Sneak Attack','Combat','fantasy_companion','The +2 bonus from the Assassin Edge is replaced by a d6, which may Ace as usual. This applies to Athletics (throwing), Fighting, or Shooting attacks.','2026-01-03 21:51:02','2026-01-03 21:51:02',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(160,'Stunning Blow','Combat','fantasy_companion','The character''s blows are powerful or precise enough to stun his foes. If he strikes an opponent with a blunt weapon (a hammer, maul, mace, his hands, etc.) and causes at least a Shaken or Wound result, resolve damage and any attempt to Soak. Then the victim must make a Vigor roll or be Stunned.','2026-01-03 21:51:13','2026-01-03 21:51:13',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(161,'Sunder','Combat','fantasy_companion','Those who spend their lives working with stone and other natural materials not only know how to craft tools with them--they also know how to break them. When striking to break an object, the sunderer adds +d6 to his damage total. Damage dice may still not Ace, however (see Breaking Things in Savage Worlds).','2026-01-03 21:51:14','2026-01-03 21:51:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(162,'Take the Hit','Combat','fantasy_companion','Fighters, barbarians, gladiators and the like must learn to endure violent lives filled with merciless brutality. A character with this Edge has learned to survive under the most relentless conditions. He gets a free reroll on Soak rolls made to eliminate Wounds or Vigor rolls to resist Knockout Blows.','2026-01-03 21:51:19','2026-01-03 21:51:19',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(163,'Trick Shot','Combat','fantasy_companion','When performing a Test with the Athletics or Shooting skill, the archer can choose to make the foe resist with Smarts instead of Agility.','2026-01-03 21:51:19','2026-01-03 21:51:19',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(164,'Uncanny Reflexes','Combat','fantasy_companion','The character has an uncanny ability to avoid area attacks and effects. She ignores the usual -2 Agility penalty when making Evasion attempts. She also gets a regular Evasion attempt against area effect attacks.','2026-01-03 21:51:20','2026-01-03 21:51:20',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(165,'Wing Gust','Combat','fantasy_companion','The character''s wings are powerful enough to beat down others of the same Size or less. Gusting is a limited action that requires an Athletics roll. On a Critical Failure, the flyer has over-exerted himself and takes a level of Fatigue. If successful, characters within a Cone Template originating from the hero must make a Vigor roll or be Shaken (at -2 with a raise). If the hero''s Action Card is a Joker or she''s currently under the effects of Conviction, those who fail are Stunned instead!','2026-01-03 21:51:21','2026-01-03 21:51:21',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(166,'Home Ground','Legendary','fantasy_companion','There are few things more dangerous than a druid in her home forest, a wizard in his lair, or a necromancer in the ruined city his undead horde have built for him. Working with the Game Master, designate one area as your hero''s "home." This might be an entire forest, bay, coastline, or lake and its surroundings for a druid, a church and the small town it serves for a cleric, a tower and its environs for a wizard or elementalist, or a keep full of contraptions and chemicals for an alchemist or tinkerer. Once per encounter, while within this area, the character may spend a Benny to recover all her Power Points. The mage also has a sense of her home, no matter how distant she may be. She may concentrate for ten minutes (one minute if on her Home Ground) to ask three questions about the current, observable state of her homeland, revealed through scrying, visions, or other eldritch sense.','2026-01-03 21:52:33','2026-01-03 21:52:33',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(167,'Relic','Legendary','fantasy_companion','Powerful magic items should normally be discovered only through epic quests, dangerous treks into vast dungeons, or exchanging vast quantities of gold and other treasure. If those opportunities don''t present themselves, a hero may take this Edge to choose any one magical item from this book. The Game Master must approve the choice as usual, deciding if such a thing even exists in her world. If so, the hero comes upon it somehow--perhaps via inheritance, at the end of the next quest, as a gift from those he aided. If the item is lost, he eventually recovers or replaces it, but may have to do without for a session or two to recover it.','2026-01-03 21:52:36','2026-01-03 21:52:36',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(168,'Unstoppable','Legendary','fantasy_companion','The hero is an irresistible force of nature, undaunted by hardship and steadfast in the face of the most seemingly insurmountable obstacles. She has the Unstoppable monstrous ability.','2026-01-03 21:52:38','2026-01-03 21:52:38',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(169,'Warband','Legendary','fantasy_companion','Legendary leaders attract dedicated fighters to their side. Each time this Edge is taken, five of the champion''s Followers gain the Resilient Monstrous Ability.','2026-01-03 21:52:39','2026-01-03 21:52:39',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(170,'Artificer','Power','fantasy_companion','Those who tangle with supernatural forces sometimes find ways to imbue powers into items. They may turn an ordinary blade into a magic sword, grant powerful potions to their friends, or even bless holy relics with divine grace. Artificers can create temporary Arcane Devices and craft more permanent magical items. See Arcane Devices on page 109.','2026-01-03 21:54:09','2026-01-03 21:54:09',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(171,'Battle Magic','Power','fantasy_companion','This Edge allows the user to cast her spells on large units of Extras such as soldiers, henchmen, or guards. See Battle Magic on page 110 for details.','2026-01-03 21:54:17','2026-01-03 21:54:17',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(172,'Blood Magic','Power','fantasy_companion','The character channels his arcane power through an evil or malevolent source, or has learned to draw power through the pain and misery of others. Any turn in which the mage causes a Wound to a conscious, sapient being of some sort (and the Wound isn''t Soaked), he recovers a d6 Power Points. The caster must cause the damage directly, using his powers, bare hands, weapons, and so on. He gains no benefit from indirect harm such as that caused by a puppeted foe, ally, zombie, pet, or minion. Those suspected of practicing such tainted magic are often hunted by local authorities. Sometimes there are entire orders dedicated to their eradication. When caught, the sentence is almost always slow, painful, and fatal.','2026-01-03 21:54:18','2026-01-03 21:54:18',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(173,'Epic Mastery','Power','fantasy_companion','The spellcaster is so well-versed in her arcane or divine craft that she has learned new ways to manipulate her powers. She gains access to the Epic Power Modifiers for all her powers. Epic Mastery applies to all a hero''s Arcane Backgrounds if she has more than one, though she must meet the arcane skill requirement for each before she can use Epic Power Modifiers with that background''s powers. See Chapter Four for a complete list of Epic Power Modifiers.','2026-01-03 21:54:20','2026-01-03 21:54:20',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(174,'Familiar','Power','fantasy_companion','"Familiars" are small creatures (Size -2 or -3) that serve as a mage''s loyal pet and magical companion. They are loyal to their masters but aren''t slaves. They are very smart versions of their species and can occasionally be distracted by their own inherent instincts. A familiar is a Wild Card with respect to Wounds and rolling a Wild Die with its Trait rolls. It doesn''t get Bennies, but the mage can spend his own on its behalf. The familiar can understand its master''s speech and vice-versa (others hear only the creature''s natural sounds), though concepts are limited to what a member of its species might understand. The familiar has 5 Power Points of its own. It cannot cast spells, but the mage can draw on them as if they were his own. They recharge separately and at the same rate as the mage''s. The bond between the familiar and the mage makes it immune to the beast friend power cast by others. Familiars don''t advance, but instead gain one of the advantages below for each of its master''s Ranks (including Novice) and for every fourth advance the hero gains past Legendary. SECOND SIGHT: The owner may use the familiar''s senses as if they were her own, with a maximum range of 10 miles. She is Distracted and Vulnerable while doing so. TRAINING: The mage can increase one of the familiar''s Traits one die type (no more than once per Trait). ENHANCEMENT: The familiar gains an Edge. It must make sense for the familiar''s species (GM''s call) and meet the Requirements as usual. If a familiar dies, the hero is automatically Stunned (no matter how far away he is). He can summon another after 10 days and can change the type if she chooses.','2026-01-03 21:54:21','2026-01-03 21:54:21',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(175,'Favored Power','Power','fantasy_companion','The caster has dedicated his time to mastering a particular spell, miracle, or weird science device. Choose one of your character''s powers. She may ignore up to two points of any penalties (Multi-Action, Wounds, Fatigue, etc.) when activating that power.','2026-01-03 21:54:22','2026-01-03 21:54:22',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(176,'Master Artificer','Power','fantasy_companion','The artificer gains 1000 gp progress for each success and raise on his Occult roll (rather than being limited to 2000 gp total progress per roll).','2026-01-03 21:54:23','2026-01-03 21:54:23',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(177,'Mystic Powers','Power','fantasy_companion','In worlds where magic is relatively common, disciplined adventurers may learn a few simple powers to help them in their quests. Choose one of the packages: Barbarian (Strength d8+), Fighter (Fighting d8+), Monk (Athletics d8+), Paladin (Spirit d8+), Ranger (Survival d8+), or Rogue (Thievery d8+). The character has 10 dedicated Power Points that recharge normally. As a limited free action, the hero automatically activates the power with success for its regular cost, or with a raise for an additional 2 Power Points. She may use applicable Power Modifiers if desired (at the usual cost). Mystic Powers doesn''t grant access to Edges that require an Arcane Background, but she may take the Power Points or Soul Drain Edge. If the hero also has an Arcane Background, none of its Edges or abilities can be used with Mystic Powers and vice-versa.','2026-01-03 21:54:24','2026-01-03 21:54:24',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(178,'Silent Caster','Power','fantasy_companion','The character has learned how to cast spells or invoke miracles without speaking. She may cast underwater, in a vacuum, while gagged, within a silence spell, etc.','2026-01-03 21:54:25','2026-01-03 21:54:25',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(179,'Transfer','Power','fantasy_companion','The mage, priest, or other practitioner of the arcane arts can give his magical energy to others. As a limited free action, the character may transfer up to five of his Power Points to anyone else in sight.','2026-01-03 21:54:26','2026-01-03 21:54:26',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(180,'Born in the Saddle','Professional','fantasy_companion','There''s no place this warrior would rather be than on the back of a fast horse or other, more exotic mount. A hero with this Edge gets a free reroll on all Riding rolls and increases her mount''s Pace by 2 and their running die one step.','2026-01-03 21:55:40','2026-01-03 21:55:40',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(181,'Explorer','Professional','fantasy_companion','The world is vast, and this wanderer is called to discover its uncharted reaches. When using the Travel rules, the Explorer may make a Survival roll to draw an additional card from the Action Deck (or two additional cards with a raise) and choose which to use. In addition, the Explorer and his party reduces Travel Times by 10 percent.','2026-01-03 21:55:41','2026-01-03 21:55:41',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(182,'Knight','Professional','fantasy_companion','Knights pledge fealty to their lord, serving as enforcers and most importantly warriors in whatever cause their liege demands. They have whatever authority their lord gives them over common folk in their realm. This is usually limited to food, lodging, and basic services required to fulfill their duties, but with express approval may also include mustering militias, adjudicating disputes, and dispensing justice. Knights represent their master, add +1 to Intimidation or Persuasion rolls with those who respect or fear authority. If taken during character creation, or with the GM''s approval afterward, the hero inherits or is granted a war horse, lance, long sword, a full suit of heavy armor (or medium if preferred), and a medium shield.','2026-01-03 21:55:42','2026-01-03 21:55:42',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(183,'Mount','Professional','fantasy_companion','The hero has a mount that is hardier and more clever than most and utterly loyal to its master (even if it''s cantankerous or otherwise disagreeable). Normal Size heroes may have a riding horse, war horse, or elven horse (with the GM''s permission). Smaller heroes may also choose a boar, large cat, dire wolf, or wolf. Whatever the beast, its Smarts is one die type higher than normal and it''s Resilient. For each of your character''s current Ranks, and each time he gains a new Rank thereafter, the mount gains one Advance: increase a Trait one die type (no more than once per Trait), or gain an Edge or Monstrous ability. At Heroic Rank you may upgrade to a Great Mount (Very Resilient), and at Legendary Rank to a Legendary Mount with access to dragons, rocs, and other powerful creatures.','2026-01-03 21:55:43','2026-01-03 21:55:43',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(184,'Poisoner','Professional','fantasy_companion','The character is an expert with poisons. She creates poisons in half the time. In addition, her contact poisons last 12 hours rather than four.','2026-01-03 21:55:44','2026-01-03 21:55:44',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(185,'Scout','Professional','fantasy_companion','Scouts are masters of navigating the mystic lands of their fantastic worlds. When the Game Master draws a face card while the party is traveling, the Scout may make a Notice roll at -2 to detect the hazard, creature, or event first. Scouts are always considered alert for Notice rolls versus Stealth and add +2 to Common Knowledge rolls to recall information about towns, outposts, landmarks, or lairs along a particular route they may have traveled before.','2026-01-03 21:55:45','2026-01-03 21:55:45',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(186,'Stonecunning','Professional','fantasy_companion','Those who spend their lives around worked stone quickly perceive imperfections or alterations within its design. Assuming they can see or feel the work, they get one free Notice roll at +2 to detect traps, hidden doors, or compartments in stonework within 10 feet of their person. Success means they know some alteration has been made, and a raise gives them insight into its general purpose.','2026-01-03 21:55:45','2026-01-03 21:55:45',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(187,'Trap Sense','Professional','fantasy_companion','Many adventurers become adept at spotting and defeating all but the most clever snares and traps. They get a Notice roll anytime a detectable trap is about to be sprung within 5" (10 yards). They must have line of sight to the trap and can''t be Shaken or Stunned. This works generally like Danger Sense but applies only to mechanical and magical traps. If the trap is detected and may be Evaded, the rogue and anyone she warns ignores 2 points of Evasion penalties to do so. If the trap can''t normally be Evaded, anyone warned may Evade at the usual -2 penalty. A character with Trap Sense ignores up to 2 points of Thievery penalties when attempting to disarm traps.','2026-01-03 21:55:46','2026-01-03 21:55:46',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(188,'Treasure Hunter','Professional','fantasy_companion','Tomb raiders and treasure hunters know what to look for when rifling through piles of gold and goods. They may roll Smarts to gauge the value of goods or treasures or get a basic idea of a magic item''s abilities. One who finds an exploding stone, for example, might know that they''re thrown for explosive effect but doesn''t know the Range increments or damage until he uses it. Finally, when the Game Master rolls for magic items, a Treasure Hunter can spend a Benny to have her roll again.','2026-01-03 21:55:47','2026-01-03 21:55:47',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(189,'Troubadour','Professional','fantasy_companion','Troubadours travel the world, learning culture, etiquette, history, and above all, legends. They add +2 to most Common Knowledge rolls (GM''s call) and may use Performance in place of Battle for Leadership Edges or Edges that require Battle.','2026-01-03 21:55:48','2026-01-03 21:55:48',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(190,'Deceptive','Social','fantasy_companion','Lies, deception, and misdirection are a stock-in-trade for this rogue. When making a Test based on Smarts- or Spirit-linked skills, he can choose whether the target resists with Smarts or Spirit.','2026-01-03 21:57:00','2026-01-03 21:57:00',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(191,'Aura of Courage','Weird','fantasy_companion','A field of holy energy encircles this noble being. Allies within 10" (20 yards) add +1 to Fear checks and subtract 1 from Fear Table results.','2026-01-03 21:58:04','2026-01-03 21:58:04',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(192,'Beast Talker','Weird','fantasy_companion','The character can speak to and understand a general class of animals: birds, marine animals (fish, crustaceans, etc.), insects, mammals, reptiles, etc. She doesn''t control them, but can attempt to persuade them to help or provide whatever information creatures of their type might understand. The GM should impose appropriate modifiers when conversing with pets, hostile or skittish animals, predators, or those animals who have been hunted or preyed upon. The vocabularies, memories, and comprehension of such creatures are limited by their type--a dog knows and understands far more than a fish, for example. The nature of the world may also define how much its animals understand.','2026-01-03 21:58:08','2026-01-03 21:58:08',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(193,'Rapid Change','Weird','fantasy_companion','This Edge is available to any character who has contracted lycanthropy (see page 218). Changing forms normally takes a painful and grotesque 10 minutes, but those with this Edge have learned to embrace the arcane aspect of the curse and may change form as a limited action.','2026-01-03 21:58:11','2026-01-03 21:58:11',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(194,'Eldritch Inspiration','Power','fantasy_companion','Great wizards gather esoteric information and collect it in various scrolls, manuals, and tomes collectively called spellbooks. As long as a wizard has access to his spellbooks, he may spend a Benny to cast any power of his Rank or lower. The power may be maintained normally but afterwards is forgotten (the wizard doesn''t add it to his list of powers). A wizard may not use this ability if his spellbook is taken away.','2026-01-04 20:18:20','2026-01-04 20:18:20',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(195,'Spellbooks','Power','fantasy_companion','A wizard''s spellbooks contain copious notes on magical processes, arcane observations, and thaumaturgical insights. Whenever he takes the New Powers Edge, he gets three new powers instead of two. He also gains an immediate power of his Rank or lower on taking this Edge.','2026-01-04 20:18:20','2026-01-04 20:18:20',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(198,'Chemist','Power','fantasy_companion','The alchemist''s concoctions last one week rather than 48 hours when given to others.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(199,'Master Alchemist','Power','fantasy_companion','Create permanent potions at half cost and as if having Simple Workshop access with alchemist''s bag.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(200,'Dirge','Power','fantasy_companion','The bard''s mournful songs can terrify foes. As an action, spend 2 Power Points to force all enemies in a Large Blast Template to make Spirit rolls or be Shaken.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(201,'Inspire Heroics','Power','fantasy_companion','The bard''s Support bonus using Performance increases to +2 (or +3 with a raise).','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(202,'Instrument','Power','fantasy_companion','The bard has a magical instrument. As long as it''s in hand, get a free reroll on Performance rolls. If lost, requires d6 days and 500 gold to replace.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(203,'Destroy Undead','Power','fantasy_companion','As a limited action, spend 1 PP to deal 2d6 damage (or 3d6 for 2 PP) to all undead in a Large Blast Template centered on self.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(204,'Mercy','Power','fantasy_companion','As an action, spend 1 PP to automatically remove Distracted, Vulnerable, or Shaken from one character within Spirit range.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(205,'Hell''s Wrath','Power','fantasy_companion','The diabolist''s bolt, blast, and burst powers cause +2 damage.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(206,'Infernal Armor','Power','fantasy_companion','As an action, limn clothes/armor with Hellish glow for +2 armor. Lasts as long as desired but makes Stealth virtually impossible.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(207,'Heartwood Staff','Power','fantasy_companion','Gain a magical staff (Str+d8, Parry +1, Reach 1, 2 hands). After hitting, spend 1 PP for extra d6 damage (can Ace). Replace requires 8 hours in old forest + Faith -2.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(208,'True Form','Power','fantasy_companion','While shape changed, can speak and cast powers at -2 penalty even in forms that normally wouldn''t allow it.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(209,'Elemental Absorption','Power','fantasy_companion','Toughness increases by +2 while experiencing elemental synergy (near significant source of chosen element).','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(210,'Elemental Master','Power','fantasy_companion','Gain domain over another element. Can change Trappings between mastered elements at will. May take multiple times.','2026-01-04 20:56:14','2026-01-04 20:56:14',1);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(211,'Master of Illusion','Power','fantasy_companion','Illusion spells have the Mobility and Sound Power Modifiers at no additional Power Point cost.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(212,'Deadly Illusion','Power','fantasy_companion','May take the Deadly Power Modifier for illusions at no additional Power Point cost.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(213,'Soul Jar','Power','fantasy_companion','Hide soul in a vessel to become Undead. If slain, inhabit new corpse in 2d6 days. If vessel destroyed, soul is destroyed.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(214,'Undead Familiar','Power','fantasy_companion','Gain an undead animal familiar with Undead ability (+2 Toughness, +2 Shaken recovery, immune to disease/poison, etc.).','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(215,'Primal Magic','Power','fantasy_companion','Add +2 damage to damaging powers. Critical Failure Stuns everyone (including caster) in LBT centered on self.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(216,'Sacred Fetish','Power','fantasy_companion','With sacred fetish in hand or displayed, get one free reroll whenever failing a Faith roll.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(217,'Great Power','Power','fantasy_companion','The sorcerer''s innate magic grows stronger. Add +2 to damage from any power that causes damage.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(218,'Phenomenal Power','Power','fantasy_companion','Damage bonus from Great Power increases to +4.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(219,'Arcane Barding','Power','fantasy_companion','Summoned animals appear with eldritch barding granting +2 Toughness (cannot be dispelled).','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(220,'Ferocious Summoning','Power','fantasy_companion','Summoned monsters may be granted any one Combat Edge of your Rank or lower.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(221,'Great Summoning','Power','fantasy_companion','May summon barghest (5 PP), mammoth (7 PP), frost mammoth (8 PP), T-rex (8 PP), or young dragon (11 PP).','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(222,'Tinkerer''s Armor','Power','fantasy_companion','Modify armor with springs/straps: reduce Min Str by 1 die, add effect (Arms: +2 melee damage + Str reroll; Torso: +2 Armor; Legs: double jump + +4 Pace). Breaks on odd Wound roll.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(223,'Construct Familiar','Power','fantasy_companion','Gain a mechanical animal familiar with Construct ability (+2 Shaken recovery, ignore 1 Wound penalty, immune to disease/poison, Repair instead of Healing).','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(224,'The Evil Eye','Power','fantasy_companion','Once per encounter as a free action, target within 6" must make Spirit -2 to spend Bennies for rest of encounter (fail = Benny lost).','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+INSERT INTO edges(id,name,background,source,description,created_at,updated_at,can_take_multiple_times) VALUES(225,'The Witching Hour','Power','fantasy_companion','Between midnight and 1 AM: cannot Critically Fail (just normal failure), and get a free Soak roll when taking a Wound.','2026-01-04 20:56:14','2026-01-04 20:56:14',0);
+COMMIT;
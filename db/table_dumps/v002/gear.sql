@@ -0,0 +1,289 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE gear (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name VARCHAR(100) NOT NULL,
+    category_id INTEGER NOT NULL,
+    era VARCHAR(20) NOT NULL DEFAULT 'any' CHECK (era IN ('medieval', 'modern', 'futuristic', 'any')),
+    cost INTEGER NOT NULL DEFAULT 0, -- Cost in dollars
+    weight REAL NOT NULL DEFAULT 0, -- Weight in pounds
+    source VARCHAR(20) NOT NULL DEFAULT 'core',
+    notes TEXT, -- Special notes about the item
+    description TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (category_id) REFERENCES gear_categories(id),
+    UNIQUE(name, source)
+);
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(1,'Backpack',5,'any',50,2.0,'core',NULL,'Holds 30 pounds of gear','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(2,'Bedroll',5,'any',25,4.0,'core',NULL,'Sleeping bag and blanket','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(3,'Blanket',5,'any',10,4.0,'core',NULL,'Wool blanket','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(4,'Candle (10)',5,'any',10,1.0,'core',NULL,'Burns for 2 hours each','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(5,'Canteen',5,'any',5,1.0,'core',NULL,'Holds 1 quart of liquid','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(6,'Crowbar',5,'any',10,2.0,'core',NULL,'+1 to Strength rolls to open doors, pry things','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(7,'Flint and Steel',5,'any',3,1.0,'core',NULL,'Starts fires','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(8,'Grappling Hook',5,'any',100,2.0,'core',NULL,'Can support 200 lbs','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(9,'Hammer',5,'any',10,1.0,'core',NULL,'Tool hammer','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(10,'Lantern',5,'any',25,3.0,'core',NULL,'Burns 4 hours on 1 pint of oil','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(11,'Lockpicks',5,'any',200,1.0,'core',NULL,'Used with Thievery skill','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(12,'Manacles',5,'any',15,2.0,'core',NULL,'Requires Str d10 to break','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(13,'Oil (1 pint)',5,'any',2,1.0,'core',NULL,'Lamp oil','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(14,'Rope (10 yards)',5,'any',10,15.0,'core',NULL,'Hemp rope, holds 300 lbs','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(15,'Shovel',5,'any',5,5.0,'core',NULL,'Digging tool','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(16,'Torch (6)',5,'any',15,1.0,'core',NULL,'Burns 1 hour, 4" radius light','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(17,'Whetstone',5,'any',5,1.0,'core',NULL,'Sharpens blades','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(18,'Medic Kit',5,'modern',100,4.0,'core',NULL,'+1 to Healing skill rolls','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(19,'Flashlight',5,'modern',20,1.0,'core',NULL,'10" beam','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(20,'Binoculars',5,'modern',75,2.0,'core',NULL,'Magnify distant objects','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(21,'Camera',5,'modern',300,1.0,'core',NULL,'Digital camera','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(22,'Cell Phone',5,'modern',250,0.0,'core',NULL,'Modern mobile phone','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(23,'Laptop',5,'modern',800,5.0,'core',NULL,'Portable computer','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(24,'Night Vision Goggles',5,'modern',500,3.0,'core',NULL,'See in darkness','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(25,'GPS',5,'modern',250,1.0,'core',NULL,'Global positioning system','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(26,'Axe, Hand',1,'medieval',100,2.0,'core',NULL,'One-handed chopping weapon','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(27,'Axe, Battle',1,'medieval',300,4.0,'core',NULL,'Heavy two-handed axe','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(28,'Club',1,'any',0,1.0,'core',NULL,'Simple bludgeon','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(29,'Dagger',1,'any',25,1.0,'core',NULL,'Small blade, can be thrown','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(30,'Flail',1,'medieval',200,8.0,'core',NULL,'Ball and chain weapon','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(31,'Great Sword',1,'medieval',400,6.0,'core',NULL,'Large two-handed sword','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(32,'Halberd',1,'medieval',250,8.0,'core',NULL,'Axe blade on a pole','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(33,'Katana',1,'medieval',1000,3.0,'core',NULL,'Curved samurai sword','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(34,'Lance',1,'medieval',300,6.0,'core',NULL,'Cavalry weapon','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(35,'Long Sword',1,'medieval',300,3.0,'core',NULL,'Standard knightly blade','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(36,'Mace',1,'medieval',100,4.0,'core',NULL,'Metal head on wooden shaft','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(37,'Maul',1,'medieval',400,10.0,'core',NULL,'Large two-handed hammer','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(38,'Pike',1,'medieval',400,18.0,'core',NULL,'Long infantry spear','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(39,'Rapier',1,'medieval',150,2.0,'core',NULL,'Fencing blade','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(40,'Short Sword',1,'medieval',100,2.0,'core',NULL,'Smaller blade','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(41,'Spear',1,'any',100,3.0,'core',NULL,'Pointed pole weapon','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(42,'Staff',1,'any',10,4.0,'core',NULL,'Wooden fighting staff','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(43,'Whip',1,'any',50,2.0,'core',NULL,'Leather whip','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(44,'Knife/Survival',1,'modern',25,1.0,'core',NULL,'Modern utility knife','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(45,'Switchblade',1,'modern',10,0.0,'core',NULL,'Concealed spring blade','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(46,'Molecular Knife',1,'futuristic',500,0.0,'core',NULL,'Monomolecular edge','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(47,'Stun Baton',1,'futuristic',200,1.0,'core',NULL,'Electric shock weapon','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(48,'Bow',2,'medieval',250,2.0,'core',NULL,'Standard bow','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(49,'Crossbow',2,'medieval',500,5.0,'core',NULL,'Mechanical bow','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(50,'Longbow',2,'medieval',300,3.0,'core',NULL,'Large powerful bow','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(51,'Sling',2,'any',10,1.0,'core',NULL,'Stone-throwing strap','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(52,'Throwing Knife',2,'any',25,1.0,'core',NULL,'Balanced for throwing','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(53,'Derringer',2,'modern',150,1.0,'core',NULL,'Small concealed pistol','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(54,'Glock (9mm)',2,'modern',350,3.0,'core',NULL,'Semi-automatic pistol','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(55,'Colt 1911 (.45)',2,'modern',400,4.0,'core',NULL,'Classic semi-automatic','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(56,'Desert Eagle (.50)',2,'modern',1000,6.0,'core',NULL,'Heavy pistol','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(57,'Ruger (.22)',2,'modern',200,2.0,'core',NULL,'Small caliber pistol','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(58,'S&W (.357)',2,'modern',350,4.0,'core',NULL,'Magnum revolver','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(59,'Shotgun, Double',2,'modern',300,11.0,'core',NULL,'Double-barrel shotgun','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(60,'Shotgun, Pump',2,'modern',350,8.0,'core',NULL,'Pump-action shotgun','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(61,'M4 Carbine',2,'modern',700,7.0,'core',NULL,'Military carbine','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(62,'AK47',2,'modern',450,10.0,'core',NULL,'Assault rifle','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(63,'Hunting Rifle',2,'modern',500,8.0,'core',NULL,'Bolt-action rifle','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(64,'Sniper Rifle',2,'modern',1500,12.0,'core',NULL,'Long-range precision rifle','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(65,'Submachine Gun',2,'modern',500,8.0,'core',NULL,'Compact automatic','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(66,'Uzi',2,'modern',350,6.0,'core',NULL,'Compact SMG','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(67,'MP5',2,'modern',600,7.0,'core',NULL,'Modern SMG','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(68,'M60',2,'modern',6000,23.0,'core',NULL,'Light machine gun','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(69,'Laser Pistol',2,'futuristic',500,2.0,'core',NULL,'Energy sidearm','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(70,'Laser Rifle',2,'futuristic',1000,8.0,'core',NULL,'Energy rifle','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(71,'Leather Armor',3,'medieval',50,15.0,'core',NULL,'Hardened leather protection','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(72,'Chain Shirt',3,'medieval',300,25.0,'core',NULL,'Interlocking metal rings','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(73,'Chain Hauberk',3,'medieval',500,30.0,'core',NULL,'Full chain mail','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(74,'Plate Corselet',3,'medieval',500,25.0,'core',NULL,'Metal chest plate','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(75,'Plate Arms',3,'medieval',400,10.0,'core',NULL,'Metal arm guards','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(76,'Plate Legs',3,'medieval',400,15.0,'core',NULL,'Metal leg guards','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(77,'Plate Helmet',3,'medieval',200,4.0,'core',NULL,'Full metal helm','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(78,'Kevlar Vest',3,'modern',500,8.0,'core',NULL,'Ballistic-resistant vest','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(79,'Infantry Battle Suit',3,'modern',2500,22.0,'core',NULL,'Full military armor','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(80,'Bomb Squad Suit',3,'modern',3000,40.0,'core',NULL,'Explosive-resistant suit','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(81,'Motorcycle Helmet',3,'modern',75,3.0,'core',NULL,'Hard shell helmet','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(82,'Tactical Helmet',3,'modern',300,3.0,'core',NULL,'Military combat helmet','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(83,'Light Body Armor',3,'futuristic',500,4.0,'core',NULL,'Lightweight composite armor','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(84,'Medium Body Armor',3,'futuristic',1000,10.0,'core',NULL,'Standard combat armor','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(85,'Heavy Body Armor',3,'futuristic',2500,20.0,'core',NULL,'Full battle armor','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(86,'Small Shield',4,'medieval',50,4.0,'core',NULL,'Buckler-sized shield','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(87,'Medium Shield',4,'medieval',100,8.0,'core',NULL,'Standard round shield','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(88,'Large Shield',4,'medieval',200,12.0,'core',NULL,'Tower or kite shield','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(89,'Riot Shield',4,'modern',100,5.0,'core',NULL,'Clear plastic shield','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(90,'Ballistic Shield',4,'modern',500,15.0,'core',NULL,'Bullet-resistant shield','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(91,'Energy Shield',4,'futuristic',1000,2.0,'core',NULL,'Force field generator','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(92,'Arrows (20)',6,'medieval',10,2.0,'core',NULL,'Standard arrows','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(93,'Bolts (20)',6,'medieval',15,2.0,'core',NULL,'Crossbow bolts','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(94,'Bullets, Small (50)',6,'modern',10,1.0,'core',NULL,'.22 caliber','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(95,'Bullets, Medium (50)',6,'modern',20,2.0,'core',NULL,'9mm, .45 ACP','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(96,'Bullets, Large (50)',6,'modern',30,3.0,'core',NULL,'.357, .50 caliber','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(97,'Shotgun Shells (25)',6,'modern',20,2.0,'core',NULL,'12-gauge shells','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(98,'Rifle Rounds (50)',6,'modern',40,3.0,'core',NULL,'Rifle caliber rounds','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(99,'Energy Cell',6,'futuristic',20,1.0,'core',NULL,'Power for energy weapons','2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(100,'Dog',8,'medieval',10,0.0,'fantasy_companion','A trained guard or hunting dog.','A loyal companion animal.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(101,'Horse, Riding',8,'medieval',150,0.0,'fantasy_companion','A standard riding horse.','A basic mount for travel.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(102,'Horse, War',8,'medieval',750,0.0,'fantasy_companion','A horse trained for combat.','A mount trained for battle.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(103,'Mule',8,'medieval',50,0.0,'fantasy_companion','A sturdy pack animal.','A beast of burden.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(104,'Pony',8,'medieval',100,0.0,'fantasy_companion','A small horse suitable for smaller riders.','A mount for smaller characters.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(105,'Ox',8,'medieval',30,0.0,'fantasy_companion','A draft animal for pulling.','Used for pulling carts and plows.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(106,'Donkey',8,'medieval',25,0.0,'fantasy_companion','A small pack animal.','A smaller beast of burden.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(107,'Camel',8,'medieval',100,0.0,'fantasy_companion','A desert mount.','Ideal for desert travel.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(108,'Boots, Heavy',10,'medieval',3,3.0,'fantasy_companion','Sturdy boots for rough terrain.','Heavy-duty footwear.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(109,'Cloak, Light (w/hood)',10,'medieval',2,2.0,'fantasy_companion','A light traveling cloak with hood.','Basic protection from weather.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(110,'Common Clothing',10,'medieval',5,2.0,'fantasy_companion','Basic peasant clothing.','Simple, practical garments.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(111,'Formal Clothing',10,'medieval',50,3.0,'fantasy_companion','Fine clothing for social occasions.','Suitable for court or formal events.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(112,'Fine Clothing',10,'medieval',100,3.0,'fantasy_companion','High-quality garments.','Expensive, well-made attire.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(113,'Winter Clothing',10,'medieval',15,5.0,'fantasy_companion','Heavy clothing for cold weather.','Warm garments for harsh conditions.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(114,'Ale, gallon',11,'medieval',2,8.0,'fantasy_companion','A gallon of common ale.','Basic alcoholic beverage.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(115,'Ale, mug',11,'medieval',1,1.0,'fantasy_companion','A mug of ale.','A single serving of ale.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(116,'Banquet (per person)',11,'medieval',10,0.0,'fantasy_companion','A lavish feast.','Fine dining experience.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(117,'Feed, animal (1 day)',11,'medieval',1,10.0,'fantasy_companion','Feed for one mount for one day.','Basic animal fodder.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(118,'Meal, cheap',11,'medieval',1,0.0,'fantasy_companion','A simple, cheap meal.','Basic sustenance.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(119,'Meal, average',11,'medieval',2,0.0,'fantasy_companion','A decent tavern meal.','Standard fare.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(120,'Meal, fine',11,'medieval',5,0.0,'fantasy_companion','A quality meal.','Well-prepared food.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(121,'Rations, basic (1 day)',11,'medieval',1,1.0,'fantasy_companion','Trail rations for one day.','Dried foods for travel.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(122,'Rations, deluxe (1 day)',11,'medieval',5,2.0,'fantasy_companion','Quality rations for one day.','Better quality travel food.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(123,'Water, quart (1 day)',11,'medieval',1,2.0,'fantasy_companion','A quart of water.','Basic hydration.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(124,'Wine, bottle',11,'medieval',10,2.0,'fantasy_companion','A bottle of wine.','Common wine.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(125,'Wine, glass',11,'medieval',2,0.5,'fantasy_companion','A glass of wine.','Single serving of wine.','2026-01-04 23:28:58','2026-01-04 23:28:58');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(126,'Alchemists'' Bag',5,'medieval',50,10.0,'fantasy_companion','Contains basic alchemical supplies.','Equipment for alchemy.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(127,'Artificer''s Kit',5,'medieval',200,15.0,'fantasy_companion','Tools for crafting magical items.','Specialized crafting equipment.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(128,'Bandoleer',5,'medieval',2,0.5,'fantasy_companion','Holds ammunition or potions.','Worn across the chest.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(129,'Barrel',5,'medieval',2,30.0,'fantasy_companion','Large container for liquids.','Wooden storage container.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(130,'Basket',5,'medieval',1,1.0,'fantasy_companion','Woven carrying container.','Simple basket for carrying goods.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(131,'Bell',5,'medieval',1,0.5,'fantasy_companion','Small bell.','Can be used as alarm or signal.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(132,'Belt Pouch',5,'medieval',1,0.5,'fantasy_companion','Small pouch worn on belt.','Holds small items.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(133,'Book',5,'medieval',25,1.0,'fantasy_companion','A written book.','Blank or containing text.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(134,'Bottle',5,'medieval',1,1.0,'fantasy_companion','Glass or ceramic container.','Holds liquids.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(135,'Caltrops',5,'medieval',5,2.0,'fantasy_companion','Scatters to impede movement.','Sharp metal spikes.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(136,'Canvas (sq. yd.)',5,'medieval',1,1.0,'fantasy_companion','Heavy cloth material.','For tents or covers.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(137,'Carriage/Coach',5,'medieval',250,600.0,'fantasy_companion','Enclosed vehicle.','Luxury transportation.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(138,'Cart (300 lbs. capacity)',5,'medieval',35,200.0,'fantasy_companion','Two-wheeled cart.','For hauling goods.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(139,'Case, map or scroll',5,'medieval',1,0.5,'fantasy_companion','Protective tube.','Protects documents.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(140,'Chain (5"/10 yards)',5,'medieval',30,10.0,'fantasy_companion','Metal chain.','For securing or climbing.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(141,'Chalk (box of 12)',5,'medieval',1,0.5,'fantasy_companion','Writing chalk.','For marking surfaces.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(142,'Chest (small)',5,'medieval',5,25.0,'fantasy_companion','Small storage chest.','Secure storage.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(143,'Chest (large)',5,'medieval',10,50.0,'fantasy_companion','Large storage chest.','Major storage container.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(144,'Crate',5,'medieval',2,20.0,'fantasy_companion','Wooden shipping crate.','For transporting goods.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(145,'Flask (metal)',5,'medieval',2,0.5,'fantasy_companion','Metal drinking flask.','Holds liquids.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(146,'Healer''s Kit',5,'medieval',25,4.0,'fantasy_companion','Medical supplies.','For treating wounds.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(147,'Holy Symbol, wood',5,'medieval',1,0.5,'fantasy_companion','Wooden religious symbol.','Basic holy symbol.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(148,'Holy Symbol, silver',5,'medieval',25,1.0,'fantasy_companion','Silver religious symbol.','Quality holy symbol.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(149,'Holy Symbol, gold',5,'medieval',100,1.0,'fantasy_companion','Gold religious symbol.','Expensive holy symbol.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(150,'Hourglass',5,'medieval',25,1.0,'fantasy_companion','Time measuring device.','Tracks time.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(151,'Ink (vial)',5,'medieval',8,0.1000000000000000055,'fantasy_companion','Writing ink.','For writing.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(152,'Journal, blank',5,'medieval',10,1.0,'fantasy_companion','Empty journal.','For recording notes.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(153,'Magnifying glass',5,'medieval',100,0.5,'fantasy_companion','Optical lens.','For examining details.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(154,'Musical Instrument',5,'medieval',50,3.0,'fantasy_companion','Various types available.','For entertainment.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(155,'Parchment (per sheet)',5,'medieval',1,0.0,'fantasy_companion','Writing surface.','For documents.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(156,'Pick, mining',5,'medieval',3,10.0,'fantasy_companion','Mining tool.','For excavation.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(157,'Pole, 12 foot',5,'medieval',1,8.0,'fantasy_companion','Long wooden pole.','For probing or vaulting.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(158,'Pot, iron',5,'medieval',5,4.0,'fantasy_companion','Cooking pot.','For cooking over fire.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(159,'Quill',5,'medieval',1,0.0,'fantasy_companion','Writing quill.','For writing with ink.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(160,'Rope, hemp (10"/20 yards)',5,'medieval',2,10.0,'fantasy_companion','Hemp rope.','For climbing or binding.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(161,'Rowboat',5,'medieval',50,100.0,'fantasy_companion','Small boat.','Holds 4 people.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(162,'Sack, large',5,'medieval',1,0.5,'fantasy_companion','Large carrying sack.','For carrying goods.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(163,'Sack, small',5,'medieval',1,0.1000000000000000055,'fantasy_companion','Small carrying sack.','For smaller items.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(164,'Saddlebags',5,'medieval',5,8.0,'fantasy_companion','Bags for mount.','Attaches to saddle.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(165,'Scabbard',5,'medieval',2,1.0,'fantasy_companion','Sword sheath.','For carrying swords.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(166,'Sealing Wax, stick',5,'medieval',1,0.1000000000000000055,'fantasy_companion','For sealing documents.','Creates official seals.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(167,'Sewing Kit',5,'medieval',2,0.5,'fantasy_companion','Needles and thread.','For repairs.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(168,'Shovel, small',5,'medieval',2,2.0,'fantasy_companion','Small digging tool.','For light digging.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(169,'Soap',5,'medieval',1,0.5,'fantasy_companion','Bar of soap.','For cleaning.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(170,'Spellbook (blank)',5,'medieval',100,3.0,'fantasy_companion','Empty spellbook.','For recording spells.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(171,'Spikes/Pitons (10)',5,'medieval',1,5.0,'fantasy_companion','Metal spikes.','For climbing.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(172,'Spyglass',5,'medieval',500,1.0,'fantasy_companion','Telescope.','For seeing at distance.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(173,'Tack, riding',5,'medieval',10,15.0,'fantasy_companion','Saddle and gear.','For riding mounts.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(174,'Tankard, ceramic',5,'medieval',1,1.0,'fantasy_companion','Drinking vessel.','For beverages.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(175,'Tent, bivouac (2 person)',5,'medieval',5,5.0,'fantasy_companion','Small tent.','Basic shelter.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(176,'Tent (6 person)',5,'medieval',20,20.0,'fantasy_companion','Medium tent.','Group shelter.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(177,'Tent, pavilion (15 person)',5,'medieval',100,50.0,'fantasy_companion','Large tent.','Large group shelter.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(178,'Thieves'' Tools',5,'medieval',200,1.0,'fantasy_companion','Lockpicks and tools.','For bypassing locks.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(179,'Torch',5,'medieval',1,1.0,'fantasy_companion','Single torch.','Provides light for 1 hour.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(180,'Trapmaking Kit',5,'medieval',200,5.0,'fantasy_companion','Tools for making traps.','Specialized equipment.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(181,'Waterskin (empty)',5,'medieval',1,1.0,'fantasy_companion','Leather water container.','Holds 2 quarts.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(182,'Wheelbarrow',5,'medieval',10,20.0,'fantasy_companion','Single-wheeled cart.','For hauling.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(183,'Whetstone (FC)',5,'medieval',1,1.0,'fantasy_companion','For sharpening blades.','Maintains edge weapons.','2026-01-04 23:30:20','2026-01-04 23:30:20');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(184,'Axe, Battle',1,'medieval',200,10.0,'fantasy_companion','Large two-handed axe.','Heavy cutting weapon.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(185,'Axe, Great',1,'medieval',500,15.0,'fantasy_companion','Massive two-handed axe.','Devastating weapon.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(186,'Axe, Hand',1,'medieval',100,2.0,'fantasy_companion','One-handed axe.','Versatile chopping weapon.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(187,'Axe, Throwing',1,'medieval',75,2.0,'fantasy_companion','Balanced for throwing.','Can be thrown.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(188,'Club, Heavy',1,'medieval',1,5.0,'fantasy_companion','Large wooden club.','Simple bludgeoning weapon.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(189,'Club, Light',1,'medieval',1,2.0,'fantasy_companion','Small wooden club.','Basic weapon.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(190,'Dagger',1,'medieval',25,1.0,'fantasy_companion','Short blade.','Easily concealed.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(191,'Dagger, Parrying',1,'medieval',100,1.0,'fantasy_companion','+1 Parry when wielded with a one-handed weapon.','Off-hand defensive blade.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(192,'Flail',1,'medieval',200,8.0,'fantasy_companion','Ignores shield bonus.','Spiked ball on chain.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(193,'Glaive',1,'medieval',300,15.0,'fantasy_companion','Reach 1.','Pole weapon with blade.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(194,'Halberd',1,'medieval',250,15.0,'fantasy_companion','Reach 1.','Versatile polearm.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(195,'Hammer, War',1,'medieval',250,8.0,'fantasy_companion','AP 1 vs rigid armor.','Military hammer.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(196,'Javelin',1,'medieval',50,3.0,'fantasy_companion','Range 3/6/12.','Throwing spear.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(197,'Katana',1,'medieval',1000,6.0,'fantasy_companion','Fine blade.','Curved sword.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(198,'Knife',1,'medieval',10,1.0,'fantasy_companion','Simple blade.','Basic cutting tool.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(199,'Lance',1,'medieval',300,10.0,'fantasy_companion','Reach 2, requires mount.','Cavalry weapon.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(200,'Mace',1,'medieval',100,4.0,'fantasy_companion','AP 1 vs rigid armor.','Flanged metal head.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(201,'Morningstar',1,'medieval',150,6.0,'fantasy_companion','AP 1 vs rigid armor.','Spiked mace.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(202,'Pike',1,'medieval',200,25.0,'fantasy_companion','Reach 2.','Long spear.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(203,'Quarterstaff',1,'medieval',10,8.0,'fantasy_companion','+1 Parry.','Wooden staff.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(204,'Rapier',1,'medieval',150,3.0,'fantasy_companion','+1 Parry.','Thin thrusting sword.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(205,'Scimitar',1,'medieval',200,4.0,'fantasy_companion','Curved blade.','Slashing sword.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(206,'Scythe',1,'medieval',25,10.0,'fantasy_companion','Improvised weapon, -1 to hit.','Farming tool.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(207,'Sickle',1,'medieval',10,2.0,'fantasy_companion','Curved blade.','Farming tool.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(208,'Spear',1,'medieval',100,5.0,'fantasy_companion','Reach 1, can be thrown.','Versatile pole weapon.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(209,'Spear, Short',1,'medieval',50,3.0,'fantasy_companion','Can be thrown.','Shorter spear.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(210,'Sword, Bastard',1,'medieval',300,6.0,'fantasy_companion','Can be used one or two-handed.','Hand-and-a-half sword.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(211,'Sword, Great',1,'medieval',400,12.0,'fantasy_companion','Two-handed.','Large sword.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(212,'Sword, Long',1,'medieval',200,4.0,'fantasy_companion','Standard sword.','One-handed sword.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(213,'Sword, Short',1,'medieval',100,2.0,'fantasy_companion','Shorter blade.','Light sword.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(214,'Trident',1,'medieval',250,5.0,'fantasy_companion','Reach 1.','Three-pronged spear.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(215,'Warhammer, Great',1,'medieval',400,12.0,'fantasy_companion','AP 2 vs rigid armor, two-handed.','Large war hammer.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(216,'Whip',1,'medieval',50,2.0,'fantasy_companion','Reach 2, can entangle.','Flexible striking weapon.','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(217,'Blowgun',2,'medieval',5,1.0,'fantasy_companion','Uses darts, often poisoned.','Tube weapon.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(218,'Bow, Composite',2,'medieval',250,3.0,'fantasy_companion','Powerful bow.','Laminated bow.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(219,'Bow, Long',2,'medieval',200,3.0,'fantasy_companion','Tall bow.','English longbow style.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(220,'Bow, Short',2,'medieval',75,2.0,'fantasy_companion','Compact bow.','Good for mounted use.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(221,'Crossbow, Heavy',2,'medieval',300,8.0,'fantasy_companion','AP 2, reload 2 actions.','Powerful crossbow.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(222,'Crossbow, Light',2,'medieval',200,5.0,'fantasy_companion','AP 2, reload 1 action.','Standard crossbow.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(223,'Net',2,'medieval',25,8.0,'fantasy_companion','Range 3/6/12, entangles.','Throwing net.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(224,'Sling',2,'medieval',5,0.5,'fantasy_companion','Uses stones.','Simple ranged weapon.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(225,'Staff Sling',2,'medieval',10,2.0,'fantasy_companion','Extended range sling.','Sling on a staff.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(226,'Acid Flask',7,'medieval',10,1.0,'fantasy_companion','SBT, 2d4-2 damage next round unless washed off.','Corrosive acid.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(227,'Alchemist''s Fire',7,'medieval',20,1.0,'fantasy_companion','SBT, Heavy Weapon, may ignite target.','Sticky flammable liquid.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(228,'Antitoxin, vial',7,'medieval',50,0.5,'fantasy_companion','+4 to resist poison for 1 hour.','Protection against poisons.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(229,'Everburning Torch',7,'medieval',110,1.0,'fantasy_companion','Permanent light, no heat.','Magical light source.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(230,'Holy Water',7,'medieval',25,0.5,'fantasy_companion','SBT, affects undead and evil creatures.','Consecrated water.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(231,'Smokestick',7,'medieval',20,0.1000000000000000055,'fantasy_companion','MBT dark illumination for 1 minute.','Creates smoke cloud.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(232,'Sunrod',7,'medieval',2,1.0,'fantasy_companion','Light for 6 hours, counts as sunlight.','Alchemical light.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(233,'Tanglefoot Bag',7,'medieval',50,4.0,'fantasy_companion','Distracted 5 rounds, Athletics or Entangled.','Sticky substance.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(234,'Thunderstone',7,'medieval',30,1.0,'fantasy_companion','MBT, Vigor or Shaken and deafened.','Explosive stone.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(235,'Tindertwig',7,'medieval',1,0.1000000000000000055,'fantasy_companion','Lights fires in single action.','Alchemical match.','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(236,'Cloak with Hood',3,'medieval',5,5.0,'fantasy_companion','Covers torso and head.','Cloth protection.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(237,'Cloth Leggings',3,'medieval',5,5.0,'fantasy_companion','Covers legs.','Light leg protection.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(238,'Cloth Tunic',3,'medieval',10,5.0,'fantasy_companion','Covers torso and arms.','Padded cloth.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(239,'Robe with Hooded Cloak',3,'medieval',10,8.0,'fantasy_companion','Covers torso, arms, head.','Full cloth protection.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(240,'Leather Tunic or Jacket',3,'medieval',20,11.0,'fantasy_companion','Covers torso and arms.','Light leather.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(241,'Leather Leggings',3,'medieval',20,8.0,'fantasy_companion','Covers legs.','Light leather legs.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(242,'Leather or Natural Cap',3,'medieval',10,1.0,'fantasy_companion','Covers head.','Simple helm.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(243,'Chain Shirt',3,'medieval',100,22.0,'fantasy_companion','Covers torso and arms.','Chain mail.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(244,'Chain Leggings',3,'medieval',100,10.0,'fantasy_companion','Covers legs.','Chain mail.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(245,'Chain Hood or Pot Helm',3,'medieval',50,3.0,'fantasy_companion','Covers head.','Metal head protection.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(246,'Breastplate',3,'medieval',500,30.0,'fantasy_companion','Covers torso.','Plate armor.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(247,'Plate Greaves',3,'medieval',500,10.0,'fantasy_companion','Covers legs.','Plate leg armor.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(248,'Plate Vambraces',3,'medieval',250,10.0,'fantasy_companion','Covers arms.','Plate arm armor.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(249,'Heavy Helm',3,'medieval',250,4.0,'fantasy_companion','Covers head.','Full metal helm.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(250,'Heavy Helm, Enclosed',3,'medieval',300,8.0,'fantasy_companion','Covers head, -1 vision Notice.','Enclosed plate helm.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(251,'Assassin''s Brew',9,'medieval',250,0.5,'fantasy_companion','-2 Notice to detect.','Lethal ingested poison.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(252,'Crypt Spores',9,'medieval',75,0.5,'fantasy_companion','Mild inhaled poison.','Fungal spores.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(253,'Ether',9,'medieval',300,0.5,'fantasy_companion','Knockout inhaled.','Chemical knockout agent.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(254,'Faerie Wine',9,'medieval',200,0.5,'fantasy_companion','Disabling (Smarts) ingested.','Mind-dulling wine.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(255,'Giant Snake Venom',9,'medieval',400,0.5,'fantasy_companion','Lethal injury poison.','Deadly venom.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(256,'Goblin Poison',9,'medieval',150,0.5,'fantasy_companion','Disabling (Agility), goblins immune.','Debilitating poison.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(257,'Green Slime Extract',9,'medieval',200,0.5,'fantasy_companion','Paralyzing contact, +1 Notice.','Paralyzing agent.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(258,'Lotus Dust',9,'medieval',225,0.5,'fantasy_companion','Disabling (Strength) inhaled.','Strength-sapping dust.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(259,'Medusa''s Blood',9,'medieval',300,0.5,'fantasy_companion','Disabling (Vigor) contact.','Body-weakening poison.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(260,'Moon Draught',9,'medieval',75,0.5,'fantasy_companion','Disabling (Spirit) ingested.','Will-weakening brew.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(261,'Wyvern Poison',9,'medieval',100,0.5,'fantasy_companion','Paralyzing injury.','Paralyzing venom.','2026-01-04 23:33:12','2026-01-04 23:33:12');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(262,'Mirror, small',5,'medieval',5,0.5,'fantasy_companion','A small hand mirror.','A small hand-held mirror for personal use.','2026-01-04 23:40:53','2026-01-04 23:40:53');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(263,'Adventurer''s Pack',13,'medieval',45,23.0,'fantasy_companion','Backpack, bedroll, candle, chalk, common clothing, flint and steel, grappling hook, lantern, small mirror, hemp rope, oil, shovel, soap, 3 torches, waterskin, whetstone, 1 week''s rations.','A ready-made pack with common adventuring gear at a discount.','2026-01-04 23:40:53','2026-01-04 23:40:53');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(264,'Alchemist''s Pack',13,'medieval',50,31.0,'fantasy_companion','Alchemist''s bag, backpack, flint and steel, bedroll, mirror, belt pouch, 3 pints of oil, rope, 1 week''s rations, 5 quarts of water, 10 bottles, and 3 torches.','A ready-made pack for alchemists with essential supplies.','2026-01-04 23:40:53','2026-01-04 23:40:53');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(265,'Cleric''s Pack',13,'medieval',100,12.0,'fantasy_companion','Holy symbol (silver), bedroll, flint and steel, candle, healer''s kit, lantern, 3 pints of oil, parchment, ink and quill, backpack, waterskin, 1 week''s rations.','A ready-made pack for clerics with religious and healing supplies.','2026-01-04 23:40:53','2026-01-04 23:40:53');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(266,'Entertainer''s Pack',13,'medieval',120,16.0,'fantasy_companion','Musical instrument, backpack, bedroll, mirror, journal, ink and quill, flint and steel, cloak (light), formal clothing, 1 week''s rations, flask, a sewing kit, and a bottle of wine.','A ready-made pack for entertainers with performance supplies.','2026-01-04 23:40:53','2026-01-04 23:40:53');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(267,'Dungeoneer''s Pack',13,'medieval',50,31.0,'fantasy_companion','Rope, grappling hook, backpack, flint and steel, hammer, bedroll, crowbar, lantern, 5 pints of oil, spikes, shovel, 3 waterskins, 1 week''s rations.','A ready-made pack for dungeon exploration with tools and light sources.','2026-01-04 23:40:53','2026-01-04 23:40:53');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(268,'Mage''s Pack',13,'medieval',80,16.0,'fantasy_companion','Spellbook, backpack, bedroll, lamp, flint and steel, ink and quill, 3 pints of oil, cloak (light), one book, waterskin, 1 week''s rations.','A ready-made pack for mages with scholarly supplies.','2026-01-04 23:40:53','2026-01-04 23:40:53');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(269,'Mercenary''s Pack',13,'medieval',45,24.0,'fantasy_companion','Bedroll, backpack, caltrops, lantern, 3 pints of oil, ale (gallon), flint and steel, a tankard, rope, manacles, waterskin, 1 week''s rations.','A ready-made pack for mercenaries with practical combat supplies.','2026-01-04 23:40:53','2026-01-04 23:40:53');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(270,'Thief''s Pack',13,'medieval',65,19.0,'fantasy_companion','Thieves'' tools, backpack, mirror, flint and steel, belt pouch, grappling hook, rope, caltrops, sack (small), 2 pints of oil, lantern (bullseye), waterskin, 1 week''s rations.','A ready-made pack for rogues with stealth and infiltration gear.','2026-01-04 23:40:53','2026-01-04 23:40:53');
+INSERT INTO gear(id,name,category_id,era,cost,weight,source,notes,description,created_at,updated_at) VALUES(271,'Wilderness Pack',13,'medieval',36,24.0,'fantasy_companion','Tent (two person), backpack, rope, bedroll, winter clothing, boots (heavy), flint and steel, shovel (small), cloak, waterskin, 1 week''s rations.','A ready-made pack for wilderness survival.','2026-01-04 23:40:53','2026-01-04 23:40:53');
+COMMIT;
@@ -0,0 +1,53 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE gear_modifiers (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    gear_id INTEGER NOT NULL,
+    modifier_id INTEGER NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (gear_id) REFERENCES gear(id) ON DELETE CASCADE,
+    FOREIGN KEY (modifier_id) REFERENCES modifiers(id),
+    UNIQUE(gear_id, modifier_id)
+);
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(1,86,528,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(2,87,529,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(3,88,530,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(4,89,531,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(5,90,532,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(6,91,533,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(7,39,534,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(8,18,535,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(9,71,1028,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(10,72,1029,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(11,73,1030,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(12,74,1031,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(13,75,1032,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(14,76,1033,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(15,77,1034,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(16,78,1035,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(17,79,1036,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(18,80,1037,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(19,81,1038,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(20,82,1039,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(21,83,1040,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(22,84,1041,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(23,85,1042,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(24,236,1043,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(25,237,1044,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(26,238,1045,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(27,239,1046,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(28,240,1047,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(29,241,1048,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(30,242,1049,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(31,243,1050,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(32,244,1051,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(33,245,1052,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(34,246,1053,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(35,247,1054,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(36,248,1055,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(37,249,1056,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(38,250,1057,'2026-01-06 00:06:22','2026-01-06 00:06:22');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(39,203,1058,'2026-01-06 00:08:20','2026-01-06 00:08:20');
+INSERT INTO gear_modifiers(id,gear_id,modifier_id,created_at,updated_at) VALUES(40,191,1059,'2026-01-06 00:08:20','2026-01-06 00:08:20');
+COMMIT;
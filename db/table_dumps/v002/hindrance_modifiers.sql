@@ -0,0 +1,186 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE hindrance_modifiers (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    hindrance_id INTEGER NOT NULL,
+    modifier_id INTEGER NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (hindrance_id) REFERENCES hindrances(id),
+    FOREIGN KEY (modifier_id) REFERENCES modifiers(id),
+    UNIQUE(hindrance_id, modifier_id)
+);
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(1,1,229,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(2,1,230,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(3,1,231,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(4,2,232,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(5,3,233,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(6,3,234,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(7,3,235,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(8,3,236,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(9,4,237,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(10,5,238,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(11,6,239,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(12,7,240,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(13,7,241,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(14,7,242,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(15,8,243,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(16,8,244,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(17,9,245,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(18,9,246,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(19,9,247,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(20,9,248,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(21,10,249,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(22,11,250,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(23,11,251,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(24,12,252,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(25,13,253,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(26,14,254,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(27,15,255,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(28,16,256,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(29,17,257,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(30,18,258,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(31,19,259,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(32,19,260,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(33,20,261,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(34,21,262,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(35,22,263,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(36,23,264,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(37,24,265,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(38,25,266,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(39,26,267,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(40,27,268,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(41,28,269,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(42,29,270,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(43,30,271,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(44,30,272,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(45,30,273,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(46,30,274,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(47,30,275,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(48,30,276,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(49,31,277,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(50,31,278,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(51,31,273,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(52,31,280,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(53,31,281,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(54,32,282,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(55,32,283,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(56,33,284,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(57,34,285,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(58,35,286,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(59,35,287,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(60,36,288,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(61,36,289,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(62,36,290,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(63,36,291,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(64,37,292,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(65,37,293,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(66,38,294,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(67,39,295,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(68,40,296,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(69,41,297,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(70,41,298,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(71,42,299,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(72,43,300,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(73,43,301,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(74,44,302,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(75,44,303,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(76,44,304,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(77,44,305,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(78,45,306,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(79,46,307,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(80,47,308,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(81,48,309,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(82,48,310,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(83,48,311,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(84,48,312,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(85,48,313,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(86,49,314,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(87,50,315,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(88,51,316,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(89,51,317,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(90,51,318,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(91,52,319,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(92,52,320,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(93,52,321,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(94,52,322,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(95,53,323,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(96,53,324,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(97,54,325,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(98,55,326,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(99,56,327,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(100,57,328,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(101,58,329,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(102,59,330,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(103,60,331,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(104,60,332,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(105,60,333,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(106,61,334,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(107,62,335,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(108,63,336,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(109,64,337,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(110,65,338,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(111,66,339,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(112,66,340,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(113,66,341,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(114,66,342,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(115,67,343,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(116,68,344,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(117,69,345,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(118,70,346,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(119,71,347,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(120,71,348,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(121,72,349,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(122,73,350,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(123,73,351,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(124,74,352,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(125,74,353,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(126,75,354,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(127,76,355,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(128,76,356,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(129,77,357,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(130,78,358,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(131,79,347,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(132,80,360,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(133,81,544,'2026-01-02 18:46:20','2026-01-02 18:46:20');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(134,82,545,'2026-01-02 18:46:46','2026-01-02 18:46:46');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(135,83,546,'2026-01-02 18:46:46','2026-01-02 18:46:46');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(136,84,547,'2026-01-02 18:47:03','2026-01-02 18:47:03');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(137,84,548,'2026-01-02 18:47:03','2026-01-02 18:47:03');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(138,84,549,'2026-01-02 18:47:03','2026-01-02 18:47:03');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(139,85,550,'2026-01-02 18:47:03','2026-01-02 18:47:03');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(140,85,551,'2026-01-02 18:47:03','2026-01-02 18:47:03');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(141,85,552,'2026-01-02 18:47:03','2026-01-02 18:47:03');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(142,86,553,'2026-01-02 18:47:24','2026-01-02 18:47:24');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(143,87,554,'2026-01-02 18:47:38','2026-01-02 18:47:38');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(144,87,555,'2026-01-02 18:47:38','2026-01-02 18:47:38');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(145,87,556,'2026-01-02 18:47:38','2026-01-02 18:47:38');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(146,88,557,'2026-01-02 18:47:54','2026-01-02 18:47:54');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(147,88,558,'2026-01-02 18:47:54','2026-01-02 18:47:54');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(148,88,559,'2026-01-02 18:47:54','2026-01-02 18:47:54');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(149,89,560,'2026-01-02 18:48:08','2026-01-02 18:48:08');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(150,90,561,'2026-01-02 18:48:14','2026-01-02 18:48:14');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(151,90,562,'2026-01-02 18:48:14','2026-01-02 18:48:14');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(152,90,563,'2026-01-02 18:48:14','2026-01-02 18:48:14');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(153,91,564,'2026-01-02 18:48:21','2026-01-02 18:48:21');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(154,92,565,'2026-01-02 18:48:27','2026-01-02 18:48:27');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(155,92,566,'2026-01-02 18:48:27','2026-01-02 18:48:27');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(156,92,567,'2026-01-02 18:48:27','2026-01-02 18:48:27');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(157,93,568,'2026-01-02 18:48:27','2026-01-02 18:48:27');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(158,93,569,'2026-01-02 18:48:27','2026-01-02 18:48:27');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(159,93,570,'2026-01-02 18:48:27','2026-01-02 18:48:27');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(160,94,571,'2026-01-02 18:48:33','2026-01-02 18:48:33');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(161,94,572,'2026-01-02 18:48:33','2026-01-02 18:48:33');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(162,94,573,'2026-01-02 18:48:33','2026-01-02 18:48:33');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(163,94,574,'2026-01-02 18:48:33','2026-01-02 18:48:33');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(164,95,575,'2026-01-02 18:48:39','2026-01-02 18:48:39');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(165,95,576,'2026-01-02 18:48:39','2026-01-02 18:48:39');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(166,96,577,'2026-01-02 18:48:39','2026-01-02 18:48:39');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(167,96,576,'2026-01-02 18:48:39','2026-01-02 18:48:39');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(168,96,578,'2026-01-02 18:48:39','2026-01-02 18:48:39');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(169,96,579,'2026-01-02 18:48:39','2026-01-02 18:48:39');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(170,97,580,'2026-01-02 18:48:45','2026-01-02 18:48:45');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(171,97,581,'2026-01-02 18:48:45','2026-01-02 18:48:45');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(172,98,582,'2026-01-02 18:48:45','2026-01-02 18:48:45');
+INSERT INTO hindrance_modifiers(id,hindrance_id,modifier_id,created_at,updated_at) VALUES(173,98,583,'2026-01-02 18:48:45','2026-01-02 18:48:45');
+COMMIT;
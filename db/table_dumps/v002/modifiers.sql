@@ -0,0 +1,1073 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE IF NOT EXISTS "modifiers" (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    target_type VARCHAR(20) CHECK (target_type IN ('attribute', 'skill', 'derived_stat', 'edge_choice', 'hindrance_choice', 'heritage_choice', 'skill_points', 'attribute_points', 'power_slots', 'power_points', 'wealth')),
+    target_identifier VARCHAR(30),
+    value_type VARCHAR(20) NOT NULL CHECK (value_type IN ('die_increment', 'roll_bonus', 'flat_bonus', 'description', 'bonus_selection', 'mandatory_selection', 'grant_skill', 'max_die_increase', 'parry_skill')),
+    value INTEGER,
+    description TEXT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    condition TEXT
+);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1,NULL,NULL,'description',NULL,'Low Light Vision: Ignore penalties for Dim and Dark Illumination','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(2,'derived_stat','pace','flat_bonus',-1,'Pace -1 due to short legs','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(3,NULL,NULL,'description',NULL,'Running die reduced by one die type','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(4,'attribute','Vigor','die_increment',1,'Vigor starts at d6 instead of d4, maximum Vigor d12+1','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(5,'attribute','Agility','die_increment',1,'Agility starts at d6 instead of d4, maximum Agility d12+1','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(6,NULL,NULL,'description',NULL,'All Thumbs: Inherent dislike of mechanical objects, -2 penalty when using mechanical or electronic devices','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(7,NULL,NULL,'description',NULL,'Low Light Vision: Elven eyes amplify light, ignore penalties for Dim and Dark Illumination','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(8,NULL,NULL,'description',NULL,'Aquatic: Cannot drown in water and move at full Pace when swimming','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(9,NULL,NULL,'description',NULL,'Dependency: Must immerse in water one hour out of every 24 or become Fatigued each day until Incapacitated','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(10,NULL,NULL,'description',NULL,'Low Light Vision: Used to darkness of the depths, ignore penalties for Dim and Dark Illumination','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(11,'derived_stat','toughness','flat_bonus',1,'Toughness +1 due to resilient flesh from life in the depths','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(12,'skill','Athletics','roll_bonus',-2,'Athletics (swimming) -2, each inch moved in water costs 3" of Pace','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(13,NULL,NULL,'description',NULL,'Flight: Fly at Pace 12 per round, use Athletics when maneuvering','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(14,'derived_stat','toughness','flat_bonus',-1,'Toughness -1 due to hollow bones','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(15,'skill','Notice','die_increment',1,'Notice starts at d6 instead of d4, maximum Notice d12+1','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(16,'derived_stat','pace','flat_bonus',-1,'Pace -1 due to dependence on flight and bulky wings','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(17,NULL,NULL,'description',NULL,'Running die reduced by one die type due to bulky wings','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(18,NULL,NULL,'description',NULL,'Construct: +2 to recover from being Shaken, don''t breathe, ignore one level of Wound modifiers, immune to poison and disease','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(19,NULL,NULL,'description',NULL,'Construct Healing: Cannot heal naturally, requires Repair skill (one hour per Wound level, not limited to Golden Hour)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(20,NULL,NULL,'description',NULL,'Outsider (Major): -2 Persuasion when interacting with non-Androids, no legal rights in most areas','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(21,NULL,NULL,'description',NULL,'Vow (Major): Designed with particular purpose, broad directives such as serving corporation or political faction','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(22,'attribute','Agility','die_increment',1,'Agility starts at d6 instead of d4, maximum Agility d12+1','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(23,NULL,NULL,'description',NULL,'Bite/Claws: Natural Weapons that cause Strength+d4 damage','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(24,NULL,NULL,'description',NULL,'Bloodthirsty: Cruel to foes, often toying with them for amusement, rarely take prisoners','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(25,'skill','Athletics','roll_bonus',-2,'Athletics (swimming) -2, each inch moved in water costs 3" of Pace','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(26,NULL,NULL,'description',NULL,'Low Light Vision: Rakashan eyes amplify light, ignore penalties for Dim and Dark Illumination','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(27,'skill','Notice','roll_bonus',2,'+2 to Notice rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(28,NULL,NULL,'description',NULL,'Ignore Off-Hand penalty when making Trait rolls with off-hand','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(29,NULL,NULL,'description',NULL,'May stack Parry bonuses from both weapons when dual-wielding','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(30,NULL,NULL,'description',NULL,'Grants access to one Arcane Background (Gifted, Magic, Miracles, Psionics, or Weird Science) with starting powers and Power Points','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(31,NULL,NULL,'description',NULL,'Enemy arcane abilities targeting this character suffer −2 penalty to arcane skill roll','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(32,NULL,NULL,'description',NULL,'Magical damage reduced by 2 (including magical weapon bonuses)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(33,NULL,NULL,'description',NULL,'Enemy arcane abilities targeting this character suffer −4 penalty to arcane skill roll','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(34,NULL,NULL,'description',NULL,'Magical damage reduced by 4 (including magical weapon bonuses)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(35,NULL,NULL,'description',NULL,'+2 to Persuasion when Networking with elite, nobles, captains of industry','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(36,NULL,NULL,'description',NULL,'+2 to Common Knowledge for upper class etiquette, heraldry, family trees, and gossip','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(37,NULL,NULL,'description',NULL,'+1 to Performance and Persuasion if target is attracted to character''s type','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(38,NULL,NULL,'description',NULL,'+2 to Performance and Persuasion if target is attracted to character''s type','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(39,NULL,NULL,'description',NULL,'After Wound/Shaken from physical damage, make Smarts roll or go Berserk (can voluntarily fail). While Berserk: Strength +1 die type, all melee attacks must be Wild Attacks, +2 Toughness, ignore 1 Wound penalty, cannot use concentration skills. Critical Failure on Fighting hits random target. Fatigue after 5 rounds, another at 10 rounds (ends rage). Can end with Smarts −2 roll.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(40,NULL,NULL,'description',NULL,'+2 to Fear checks','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(41,NULL,NULL,'description',NULL,'Subtract 2 from Fear Table results','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(42,'derived_stat','size','flat_bonus',1,'Size +1 (and Toughness +1)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(43,NULL,NULL,'description',NULL,'Treat Strength as one die type higher for Encumbrance and Minimum Strength requirements','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(44,NULL,NULL,'description',NULL,'Cannot be taken if Size is already +3 or more','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(45,'skill','Athletics','description',NULL,'Athletics is linked to Strength instead of Agility','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(46,NULL,NULL,'description',NULL,'May resist Athletics Tests with Strength instead of Agility','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(47,NULL,NULL,'description',NULL,'Increase Short Range of thrown weapons by +1','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(48,'skill','Persuasion','description',NULL,'May reroll Persuasion rolls once per attempt','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(49,NULL,NULL,'description',NULL,'When spending a Benny to reroll a Trait roll, add +2 to the final total','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(50,NULL,NULL,'description',NULL,'Make double normal fee when performing in area of expertise','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(51,NULL,NULL,'description',NULL,'+1 to Persuasion if target is friendly and knows who character is','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(52,NULL,NULL,'description',NULL,'Make 5× normal fee when performing in area of expertise','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(53,NULL,NULL,'description',NULL,'+2 to Persuasion when influencing friendly individuals who know who character is','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(54,'attribute','Vigor','roll_bonus',2,'+2 to Vigor rolls for natural healing','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(55,NULL,NULL,'description',NULL,'Make natural healing roll every three days instead of five days','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(56,'derived_stat','pace','flat_bonus',2,'Pace +2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(57,NULL,NULL,'description',NULL,'Running die increases one step (d6 to d8, d8 to d10, etc.)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(58,NULL,NULL,'description',NULL,'Begin play knowing a number of languages equal to half Smarts die type (round down)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(59,NULL,NULL,'description',NULL,'Each language is a separate Language skill starting at d6','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(60,NULL,NULL,'description',NULL,'Draw one extra Benny at the beginning of each game session','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(61,NULL,NULL,'description',NULL,'Draw two extra Bennies instead of one at the start of each session','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(62,NULL,NULL,'description',NULL,'When dealt Action Card of Five or lower, may discard and draw again until Six or higher','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(63,NULL,NULL,'description',NULL,'Start with three times normal starting funds for the setting','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(64,NULL,NULL,'description',NULL,'$150,000 annual salary equivalent if regular income is appropriate for setting','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(65,NULL,NULL,'description',NULL,'Start with five times normal starting funds for the setting','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(66,NULL,NULL,'description',NULL,'$500,000 annual salary equivalent if regular income is appropriate for setting','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(67,'derived_stat','shaken_recovery','roll_bonus',2,'+2 to recover from being Shaken or Stunned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(68,NULL,NULL,'description',NULL,'Ignore Unstable Platform penalty when firing from moving vehicle or mount','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(69,NULL,NULL,'description',NULL,'Reduce running penalty from -2 to -1 when making attacks','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(70,NULL,NULL,'description',NULL,'+2 to Soak rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(71,NULL,NULL,'description',NULL,'+2 to Vigor rolls to avoid Knockout Blows','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(72,'derived_stat','parry','flat_bonus',1,'Parry +1','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(73,NULL,NULL,'description',NULL,'Gang Up bonus against character reduced by 1','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(74,'derived_stat','parry','flat_bonus',2,'Parry +2 (replaces Block bonus)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(75,NULL,NULL,'description',NULL,'Gang Up bonus against character reduced by 2 (replaces Block reduction)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(76,'derived_stat','toughness','flat_bonus',1,'Toughness +1','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(77,NULL,NULL,'description',NULL,'Unarmed damage Str+d4, or increase existing unarmed damage die by one step','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(78,'derived_stat','toughness','flat_bonus',1,'Toughness +1 (additional, stacks with Brawler)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(79,NULL,NULL,'description',NULL,'Unarmed damage increased another die type (stacks with Brawler)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(80,NULL,NULL,'description',NULL,'When Action Card is 5 or less, ignore up to 2 points of penalties on one action','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(81,NULL,NULL,'description',NULL,'Once per round, free attack against one failed Fighting attack (if not Shaken or Stunned)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(82,NULL,NULL,'description',NULL,'Free attack against up to three failed attacks each round (replaces Counterattack)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(83,NULL,NULL,'description',NULL,'When Action Card is Joker, double damage of first successful Athletics (throwing) or Shooting roll','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(84,NULL,NULL,'description',NULL,'-2 to ranged attacks against character (unless surprised, does not stack with cover)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(85,NULL,NULL,'description',NULL,'+2 when Evading area effect attacks','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(86,NULL,NULL,'description',NULL,'+1 to hit and damage with RoF 1 weapons, costs one extra bullet (cannot combine with Rapid Fire)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(87,NULL,NULL,'description',NULL,'With Three-Round Burst: +2 to Shooting and damage, expends six bullets','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(88,NULL,NULL,'description',NULL,'One adjacent foe (player''s choice) doesn''t get free attack when withdrawing from melee','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(89,NULL,NULL,'description',NULL,'Up to three foes (player''s choice) don''t get free attacks when withdrawing from melee (replaces Extraction)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(90,NULL,NULL,'description',NULL,'When performing Test with Fighting, foe resists with Smarts instead of Agility','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(91,NULL,NULL,'description',NULL,'Once per round, free Fighting attack when foe moves into Reach (if not Shaken or Stunned)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(92,NULL,NULL,'description',NULL,'Free attack against up to three foes who move into Reach (replaces First Strike)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(93,NULL,NULL,'description',NULL,'Move at full Pace on Difficult Ground when obstacles available','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(94,'skill','Athletics','roll_bonus',2,'+2 to Athletics when climbing and in foot Chases','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(95,NULL,NULL,'description',NULL,'As limited action, roll second Fighting die with one Fighting attack (can allocate to same or different targets)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(96,NULL,NULL,'description',NULL,'Add third Fighting die to Frenzy attack (replaces Frenzy)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(97,NULL,NULL,'description',NULL,'+1d6 damage when attacking creatures 3+ Sizes larger','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(98,NULL,NULL,'description',NULL,'Ignore Wound penalties when making Vigor rolls to avoid Bleeding Out','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(99,NULL,NULL,'description',NULL,'If killed, roll die - even result means Incapacitated but survives','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(100,NULL,NULL,'description',NULL,'Ignore -2 penalty when wielding improvised weapons','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(101,NULL,NULL,'description',NULL,'Free reroll in any opposed Test initiated by character','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(102,NULL,NULL,'description',NULL,'Draw one additional Action Card and choose which to use','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(103,NULL,NULL,'description',NULL,'Draw two additional Action Cards and choose which to use (replaces Level Headed)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(104,NULL,NULL,'description',NULL,'If no movement and RoF 1 on first action: +1 to attack or ignore up to 2 points of penalties (does not stack with Aim)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(105,NULL,NULL,'description',NULL,'Fists and feet are Natural Weapons, always considered armed','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(106,'skill','Fighting','roll_bonus',1,'+1 to unarmed Fighting attacks','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(107,NULL,NULL,'description',NULL,'Unarmed damage Str+d4, or increase existing unarmed damage die by one step','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(108,'skill','Fighting','roll_bonus',1,'+1 to unarmed Fighting (total +2 with Martial Artist, replaces Martial Artist bonus)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(109,NULL,NULL,'description',NULL,'Unarmed damage increased another die step (stacks with Martial Artist)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(110,NULL,NULL,'description',NULL,'When Action Card is Joker, double damage of first successful Fighting attack','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(111,NULL,NULL,'description',NULL,'Ignore 1 point of Wound penalties','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(112,NULL,NULL,'description',NULL,'Ignore 2 points of Wound penalties (replaces Nerves of Steel)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(113,NULL,NULL,'description',NULL,'When spending Benny to reroll damage, add +2 to final total','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(114,NULL,NULL,'description',NULL,'Increase weapon RoF by 1 for one Shooting attack per turn','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(115,NULL,NULL,'description',NULL,'Increase weapon RoF by 1 twice per turn via Multi-Action (replaces Rapid Fire)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(116,NULL,NULL,'description',NULL,'If no movement, ignore Recoil penalty when firing at RoF 2+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(117,NULL,NULL,'description',NULL,'As limited action, make single Fighting attack against all targets in Reach (friends and foes, -2 without two-handed weapon)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(118,NULL,NULL,'description',NULL,'Sweep can avoid allies (replaces Sweep)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(119,NULL,NULL,'description',NULL,'+1 to Athletics (throwing), Fighting, or Shooting with specific weapon','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(120,'derived_stat','parry','flat_bonus',1,'+1 to Parry when weapon readied','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(121,NULL,NULL,'description',NULL,'+2 to Athletics (throwing), Fighting, or Shooting with specific weapon (replaces Trademark Weapon)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(122,'derived_stat','parry','flat_bonus',1,'+1 to Parry when weapon readied (total +2 with Trademark Weapon, replaces Trademark Weapon bonus)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(123,NULL,NULL,'description',NULL,'Second Fighting attack from different hand doesn''t inflict Multi-Action penalty (Off-Hand penalty still applies unless Ambidextrous)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(124,NULL,NULL,'description',NULL,'Second ranged attack from different hand doesn''t inflict Multi-Action penalty (Off-Hand penalty still applies unless Ambidextrous)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(125,NULL,NULL,'description',NULL,'Extras in Command Range add +1 to Spirit rolls to recover from being Shaken','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(126,NULL,NULL,'description',NULL,'Extras in Command Range add +1 to Vigor rolls to recover from being Stunned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(127,NULL,NULL,'description',NULL,'Command Range: 5" (10 yards)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(128,NULL,NULL,'description',NULL,'Command Range increases to 10" (20 yards)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(129,NULL,NULL,'description',NULL,'Extras in Command Range add +1 to Fighting damage rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(130,NULL,NULL,'description',NULL,'Extras in Command Range add +1 to Toughness','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(131,NULL,NULL,'description',NULL,'As limited action, roll Battle skill to Support one type of Trait roll for all allied Extras in Command Range','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(132,NULL,NULL,'description',NULL,'Requires communication with affected allies','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(133,NULL,NULL,'description',NULL,'Leadership Edges that normally apply only to Extras now also apply to Wild Cards','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(134,NULL,NULL,'description',NULL,'Dealt one extra Action Card each round to give to Extras in Command Range','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(135,NULL,NULL,'description',NULL,'At start of round, may give extra card to any Extra or group of Extras in Command Range','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(136,NULL,NULL,'description',NULL,'Recipient can choose to accept and replace current Action Card or discard','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(137,NULL,NULL,'description',NULL,'Dealt two extra Action Cards each round to distribute (replaces Tactician''s one card)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(138,NULL,NULL,'description',NULL,'Can create Arcane Devices that can be used by allies','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(139,NULL,NULL,'description',NULL,'Arcane Devices allow non-arcane characters to use the Artificer''s powers','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(140,NULL,NULL,'description',NULL,'When getting a raise on arcane skill roll, reduce Power Point cost by 1','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(141,NULL,NULL,'description',NULL,'Power Point cost reduction can take the cost to 0','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(142,NULL,NULL,'description',NULL,'Base Duration of any non-Instant power is doubled','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(143,NULL,NULL,'description',NULL,'Duration doubling applies to maintained powers as well','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(144,NULL,NULL,'description',NULL,'After rolling Focus, spend 1 PP to increase total by +1','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(145,NULL,NULL,'description',NULL,'After rolling Focus, spend 3 PP to increase total by +2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(146,NULL,NULL,'description',NULL,'Cannot be used to improve a Critical Failure','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(147,NULL,NULL,'description',NULL,'Spend up to 3 PP to jury-rig device from spare parts','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(148,NULL,NULL,'description',NULL,'Can activate any power available to Weird Scientists of Rank or lower with base PP cost 3 or lower','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(149,NULL,NULL,'description',NULL,'Device created with Weird Science roll at -2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(150,NULL,NULL,'description',NULL,'Device is used immediately (no additional roll required) and falls apart after use','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(151,NULL,NULL,'description',NULL,'When making a Soak roll, may spend up to 4 Power Points','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(152,NULL,NULL,'description',NULL,'Add +1 to final Soak roll for each Power Point spent','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(153,NULL,NULL,'description',NULL,'Maximum bonus: +4 (for 4 Power Points spent)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(154,NULL,NULL,'description',NULL,'Add +2 to opposed Psionics rolls when using powers against a foe','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(155,NULL,NULL,'description',NULL,'Add +2 to opposed Psionics rolls when defending against a rival','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(156,NULL,NULL,'description',NULL,'Learn two new powers of Rank or lower available to your Arcane Background','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(157,NULL,NULL,'description',NULL,'Alternatively, add a new Trapping to one existing power','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(158,NULL,NULL,'description',NULL,'Can be taken multiple times','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(159,NULL,NULL,'description',NULL,'Maximum Trappings per power limited by arcane skill die type','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(160,NULL,NULL,'description',NULL,'Grants +5 Power Points','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(161,NULL,NULL,'description',NULL,'Can be taken once per Rank (Novice through Heroic)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(162,NULL,NULL,'description',NULL,'At Legendary Rank, can only be taken twice (granting 2 Power Points total at that Rank)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(163,NULL,NULL,'description',NULL,'Recover 10 Power Points when Action Card is Joker','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(164,NULL,NULL,'description',NULL,'Cannot exceed usual Power Point limit','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(165,NULL,NULL,'description',NULL,'Power Points recharge at 10 per hour instead of 5 per hour','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(166,NULL,NULL,'description',NULL,'Power Points recharge at 20 per hour instead of 10 per hour','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(167,NULL,NULL,'description',NULL,'Take a level of Fatigue to immediately recover up to 5 Power Points','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(168,NULL,NULL,'description',NULL,'If already Fatigued, can take additional Fatigue to Exhaustion for 5 more Power Points (10 total)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(169,NULL,NULL,'description',NULL,'Fatigue from Soul Drain can only be recovered naturally through rest','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(170,NULL,NULL,'description',NULL,'Fatigue cannot be removed by powers or other supernatural means','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(171,NULL,NULL,'description',NULL,'Spend 1 extra Power Point when casting to change power''s Trapping','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(172,NULL,NULL,'description',NULL,'Can change to any other Trapping available for that power','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(173,NULL,NULL,'description',NULL,'Ignore 2 points of penalties to Boating, Driving, or Piloting rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(174,NULL,NULL,'description',NULL,'May spend Bennies to Soak damage for controlled vehicles using Boating/Driving/Piloting instead of Vigor','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(175,NULL,NULL,'description',NULL,'Free reroll on Athletics totals involving balance, tumbling, or grappling','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(176,NULL,NULL,'description',NULL,'Attacks against character at -1 when aware, mobile, and not encumbered','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(177,NULL,NULL,'description',NULL,'+2 damage when foe is Vulnerable or character has The Drop','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(178,'skill','Research','roll_bonus',2,'+2 to Research rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(179,NULL,NULL,'description',NULL,'+2 to Notice rolls when searching desks, sifting through papers, or finding obscured items in clutter','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(180,NULL,NULL,'description',NULL,'Make Smarts roll as action to gain d4 (or d6 with raise) in any skill temporarily','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(181,NULL,NULL,'description',NULL,'Improvise devices with Repair roll: success=minor device, raise=better device','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(182,'skill','Repair','roll_bonus',2,'+2 to Repair rolls (does not stack with other Professional edges)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(183,NULL,NULL,'description',NULL,'With raise on Repair roll, halve the time normally required','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(184,NULL,NULL,'description',NULL,'+2 to one knowledge skill: Academics, Battle, Occult, Science, or other Smarts-based knowledge skill (may be taken multiple times for different skills, does not stack with other Professional edges)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(185,NULL,NULL,'description',NULL,'Treat Strength as one die type higher for Encumbrance and Minimum Strength (stacks with Brawny)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(186,NULL,NULL,'description',NULL,'Free reroll on Vigor rolls to survive environmental hazards','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(187,NULL,NULL,'description',NULL,'+1 to Athletics rolls for climbing in urban areas (does not stack with other Professional edges)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(188,NULL,NULL,'description',NULL,'+1 to Stealth rolls in urban environments (does not stack with other Professional edges)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(189,NULL,NULL,'description',NULL,'+1 to Thievery rolls in all circumstances (does not stack with other Professional edges)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(190,'skill','Survival','roll_bonus',2,'+2 to Survival rolls (does not stack with other Professional edges)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(191,NULL,NULL,'description',NULL,'+2 to Stealth rolls in wilderness (not towns, ruins, or underground; does not stack with other Professional edges)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(192,NULL,NULL,'description',NULL,'When successfully Testing a foe, may remove Distracted or Vulnerable state from one ally','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(193,NULL,NULL,'description',NULL,'May freely give Bennies to any character you can communicate with','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(194,NULL,NULL,'description',NULL,'Once per session, call on connected faction for favors (loan, gear, allies, transport, info, professionals)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(195,NULL,NULL,'description',NULL,'Free reroll on Taunt Tests','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(196,'skill','Intimidation','roll_bonus',2,'+2 to Intimidation rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(197,NULL,NULL,'description',NULL,'Once per turn, Taunt Test with raise Provokes foe: -2 penalty to affect other targets (stacks with Distracted)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(198,NULL,NULL,'description',NULL,'Limited action: Test with Intimidation or Taunt against all enemies in Medium Blast Template','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(199,NULL,NULL,'description',NULL,'Free reroll on any Support roll','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(200,NULL,NULL,'description',NULL,'If raise when resisting Intimidation or Taunt Test, foe becomes Distracted','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(201,NULL,NULL,'description',NULL,'+2 to Intimidation or Persuasion when Networking with shady or criminal elements','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(202,NULL,NULL,'description',NULL,'+2 to Common Knowledge rolls about black market, fencing, avoiding law, illegal weapons, etc.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(203,NULL,NULL,'description',NULL,'+2 to resist Tests with Smarts or Spirit','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(204,NULL,NULL,'description',NULL,'Strong Willed bonus applies to resist powers and negate effects (doesn''t stack with Brave)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(205,NULL,NULL,'description',NULL,'Limited action: Roll second skill die when Supporting with Persuasion or Performance to Support another ally','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(206,NULL,NULL,'description',NULL,'Add third skill die to Support roll, Support an additional ally (total 3 allies)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(207,NULL,NULL,'description',NULL,'May spend own Bennies for any animals under control (mounts, pets, familiars, etc.)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(208,NULL,NULL,'description',NULL,'Animals won''t attack unless provoked or enraged','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(209,NULL,NULL,'description',NULL,'Gain loyal animal companion (Size 0 or smaller, Extra, doesn''t Advance). Replacement arrives in 1d4 days if lost','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(210,NULL,NULL,'description',NULL,'May take multiple times: Gain additional pet, increase pet Trait by one die type, increase max Size by +1 (max 3), or make pet Wild Card (Heroic Rank required)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(211,NULL,NULL,'description',NULL,'+2 damage when attacking supernaturally evil (or good if evil Champion) creatures. Applies to all damage types including area effects, ranged attacks, and powers','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(212,NULL,NULL,'description',NULL,'Gain 1 Chi Point at start of combat. Spend to: reroll failed attack (even Critical Failure), force enemy to reroll attack against you, or add +d6 damage to unarmed Fighting attack (may Ace). Lost at end of encounter','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(213,'skill','Notice','roll_bonus',2,'+2 to Notice rolls for Surprise. With raise, start on Hold','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(214,NULL,NULL,'description',NULL,'Notice at -2 (or +2 if normally allowed) to detect hazards. If successful against attack, foe doesn''t get The Drop','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(215,'skill','Healing','roll_bonus',2,'+2 to all Healing rolls (natural or magical)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(216,NULL,NULL,'description',NULL,'After drinking (8oz liquor): Vigor +1 die type (increases Toughness), ignore 1 Wound penalty level (stacks). Smarts/Agility/linked skills -1. Lasts 1 hour, then Fatigued for 4 hours','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(217,NULL,NULL,'description',NULL,'Once per encounter, find/remember/dig up needed equipment, ammunition, or useful device (GM approval required)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(218,NULL,NULL,'description',NULL,'Gain 5 followers (Soldier profile). Replacements arrive eventually if lost. Must be cared for and want share of rewards. Can be taken multiple times. Followers Advance like PCs','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(219,NULL,NULL,'description',NULL,'Increase one Trait and its limit by one step (d12+1 becomes d12+2). May be selected once per Trait','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(220,NULL,NULL,'description',NULL,'Increase Trait and its limit one additional step beyond Professional (d12+2 becomes d12+3)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(221,NULL,NULL,'description',NULL,'Wild Die increases to d10 when rolling the selected Expert Trait','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(222,NULL,NULL,'description',NULL,'Gain Novice Rank Wild Card sidekick (2 Bennies/session, may Advance). Hero may spend Bennies for sidekick. Not replaced if killed unless Edge taken again','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(223,NULL,NULL,'description',NULL,'Can take 4 Wounds before Incapacitated (maximum Wound penalty still -3)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(224,NULL,NULL,'description',NULL,'Can take 5 Wounds before Incapacitated (maximum Wound penalty still -3)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(225,'derived_stat','parry','flat_bonus',1,'Parry +1 when armed','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(226,NULL,NULL,'description',NULL,'Bonus damage die for Fighting rolls is d8 instead of d6. Must be armed (includes Martial Artist, claws, etc.)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(227,'derived_stat','parry','flat_bonus',1,'Parry +1 additional (total +2 with Weapon Master) when armed','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(228,NULL,NULL,'description',NULL,'Bonus damage die for Fighting rolls is d10 (replaces Weapon Master d8)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(229,NULL,NULL,'description',NULL,'Character believes they are the best and flaunts their superiority','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(230,NULL,NULL,'description',NULL,'Seeks to dominate opponents rather than simply defeat them','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(231,NULL,NULL,'description',NULL,'Targets the greatest threat in battle, viewing lesser foes as beneath them','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(232,NULL,NULL,'description',NULL,'Cannot keep secrets well, reveals plans and information at the worst possible times','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(233,NULL,NULL,'description',NULL,'Never takes prisoners unless under direct supervision of a superior','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(234,NULL,NULL,'description',NULL,'Cold-blooded ruthlessness causes enemies to respond in kind','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(235,NULL,NULL,'description',NULL,'Often costs vital information by killing potential sources','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(236,NULL,NULL,'description',NULL,'Creates constant enemies and may cause trouble with superiors or authorities','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(237,NULL,NULL,'description',NULL,'Never makes rash decisions, always plots things out in detail before taking action','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(238,NULL,NULL,'description',NULL,'Must check out everything, always wants to know what''s behind mysteries and secrets','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(239,NULL,NULL,'description',NULL,'Almost always leaps before looking, rarely thinks things through before taking action','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(240,NULL,NULL,'description',NULL,'Believes they can defeat anything and do most anything','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(241,NULL,NULL,'description',NULL,'Never wants to retreat from a challenge','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(242,NULL,NULL,'description',NULL,'Not suicidal, but takes on more than common sense dictates','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(243,NULL,NULL,'description',NULL,'Only fights when given no other choice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(244,NULL,NULL,'description',NULL,'Never allows the killing of prisoners or other defenseless victims','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(245,NULL,NULL,'description',NULL,'Won''t fight living characters under any circumstances','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(246,NULL,NULL,'description',NULL,'May defend themselves but won''t permanently harm sapient living creatures','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(247,NULL,NULL,'description',NULL,'May use nonlethal methods only in self-defense or defense of others','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(248,NULL,NULL,'description',NULL,'Undead, demons, and undeniably evil creatures are fair game','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(249,NULL,NULL,'description',NULL,'Has a minor foible that is usually humorous but can occasionally cause real trouble (player should define specific quirk)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(250,NULL,NULL,'description',NULL,'Always wants their way and never admits they''re wrong','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(251,NULL,NULL,'description',NULL,'Justifies mistakes with half-truths and rationalizations even when obviously wrong','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(252,NULL,NULL,'description',NULL,'Receive one less Benny per game session than normal','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(253,NULL,NULL,'description',NULL,'Minor Enemy: lone individual or group that appears rarely','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(254,NULL,NULL,'description',NULL,'Major Enemy: powerful authorities, band of outlaws, or very powerful relentless rival','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(255,NULL,NULL,'description',NULL,'Argues bitterly for more than fair share of loot or rewards','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(256,NULL,NULL,'description',NULL,'Fights over unfair distribution, may kill if slighted or covets something','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(257,NULL,NULL,'description',NULL,'Regular responsibility consuming about 20 hours most weeks','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(258,NULL,NULL,'description',NULL,'Regular responsibility consuming 40+ hours most weeks','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(259,NULL,NULL,'description',NULL,'Start with half the usual starting money for the setting','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(260,NULL,NULL,'description',NULL,'Halve total funds every game week','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(261,NULL,NULL,'description',NULL,'Has a troublesome but not life-threatening secret','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(262,NULL,NULL,'description',NULL,'Has a secret that would cause severe problems if discovered. Trade for Enemy, Shamed, Wanted, or similar if revealed','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(263,NULL,NULL,'description',NULL,'Haunted by shameful past deed not generally known. May go out of way to avoid repeating mistake or give in and hate self','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(264,NULL,NULL,'description',NULL,'Shameful deed is well-known among those character cares about. Should be revealed to party and occasionally used against hero','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(265,NULL,NULL,'description',NULL,'Seeks vengeance, usually through legal means. May plot for months or demand immediate results','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(266,NULL,NULL,'description',NULL,'Won''t let anything prevent reckoning. Actions escalate until total satisfaction achieved','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(267,NULL,NULL,'description',NULL,'Sworn oath with broad mandate that rarely conflicts with party goals','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(268,NULL,NULL,'description',NULL,'Sworn oath making long-term frequent demands and creating great risks to life','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(269,NULL,NULL,'description',NULL,'Wanted for minor crimes or serious crimes away from campaign area. Will be arrested if discovered','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(270,NULL,NULL,'description',NULL,'Wanted for serious crimes like murder. Active pursuit by authorities or those seeking justice/vengeance','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(271,NULL,NULL,'description',NULL,'Age 12-15 years old (adjust for other ancestries)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(272,NULL,NULL,'description',NULL,'Only 4 attribute points instead of 5 during character creation','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(273,NULL,NULL,'description',NULL,'Only 10 skill points instead of 12 during character creation','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(274,NULL,NULL,'description',NULL,'May have legal restrictions (can''t drive, own firearms, etc.)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(275,NULL,NULL,'description',NULL,'Draw one extra Benny at beginning of each game session (stacks with Luck/Great Luck)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(276,NULL,NULL,'description',NULL,'Most Young characters should also take Small Hindrance (not mandatory)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(277,NULL,NULL,'description',NULL,'Very Young: Age 8-11 years old (adjust for other ancestries)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(278,NULL,NULL,'description',NULL,'Only 3 attribute points instead of 5 during character creation','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(279,NULL,NULL,'description',NULL,'Only 10 skill points instead of 12 during character creation','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(280,NULL,NULL,'description',NULL,'Must take Small Hindrance','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(281,NULL,NULL,'description',NULL,'Draw two extra Bennies at beginning of each game session (stacks with Luck/Great Luck)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(282,'skill','Common Knowledge','roll_bonus',-1,'-1 to Common Knowledge rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(283,'skill','Notice','roll_bonus',-1,'-1 to Notice rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(284,NULL,NULL,'description',NULL,'Skeptical of supernatural, rationalizes supernatural events, walks into danger from disbelief, doubts even after confronted with truth','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(285,NULL,NULL,'description',NULL,'Cannot read or write in any language. Limited math skills (basic addition only). Can sign name and recognize simple signs','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(286,NULL,NULL,'description',NULL,'-2 to Fear checks','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(287,NULL,NULL,'description',NULL,'-2 when resisting Intimidation','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(288,'skill','Intimidation','roll_bonus',-1,'-1 to Intimidation rolls involving speech','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(289,'skill','Performance','roll_bonus',-1,'-1 to Performance rolls involving speech','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(290,'skill','Persuasion','roll_bonus',-1,'-1 to Persuasion rolls involving speech','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(291,'skill','Taunt','roll_bonus',-1,'-1 to Taunt rolls involving speech','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(292,NULL,NULL,'description',NULL,'−2 penalty when using mechanical or electrical devices','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(293,NULL,NULL,'description',NULL,'Critical Failure with such devices breaks them (fixable with Repair roll and 1d6 hours)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(294,'attribute','Vigor','roll_bonus',-2,'−2 to Vigor rolls to resist Fatigue','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(295,NULL,NULL,'description',NULL,'−1 to vision-dependent Trait rolls (ranged attacks, Notice, etc.). Glasses negate penalty but 50% chance lost when Wounded/trauma (Distracted until end of next turn)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(296,NULL,NULL,'description',NULL,'−2 to vision-dependent Trait rolls (ranged attacks, Notice, etc.). Glasses negate penalty but 50% chance lost when Wounded/trauma (Distracted and Vulnerable until end of next turn)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(297,NULL,NULL,'description',NULL,'−6 to all physical tasks that require vision','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(298,NULL,NULL,'description',NULL,'Gain one free Edge of choice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(299,NULL,NULL,'description',NULL,'−2 to Athletics when swimming, each inch moved in water costs 3″ of Pace','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(300,'skill','Athletics','roll_bonus',-2,'−2 to Athletics rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(301,'skill','Stealth','roll_bonus',-2,'−2 to Stealth rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(302,'derived_stat','pace','flat_bonus',-1,'Pace −1','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(303,NULL,NULL,'description',NULL,'−1 to running rolls (minimum 1)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(304,NULL,NULL,'description',NULL,'−1 to Agility, Strength (including damage), and Vigor rolls (not linked skills)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(305,'skill_points','Smarts','flat_bonus',5,'+5 skill points for Smarts-linked skills','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(306,NULL,NULL,'description',NULL,'−4 to Notice rolls to hear (including waking). Hearing aids reduce to −2 but 50% chance lost when Wounded/trauma','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(307,NULL,NULL,'description',NULL,'Deaf: Cannot hear, automatically fail all Notice rolls dependent on hearing','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(308,NULL,NULL,'description',NULL,'Cannot speak. Must use writing, sign language, or visual communication (may require Notice roll)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(309,'derived_stat','size','flat_bonus',1,'Size +1 (and Toughness +1)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(310,'derived_stat','pace','flat_bonus',-1,'Pace −1','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(311,NULL,NULL,'description',NULL,'Running die reduced one step (minimum d4)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(312,NULL,NULL,'description',NULL,'Strength considered one die type less (minimum d4) for armor and worn gear (not weapons)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(313,NULL,NULL,'description',NULL,'Cannot be both Brawny and Obese. Cannot increase Size above +3','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(314,NULL,NULL,'description',NULL,'−4 to tasks requiring two hands (some Athletics rolls, two-handed weapons, etc.)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(315,NULL,NULL,'description',NULL,'−2 to vision-dependent Trait rolls beyond 5″ (10 yards)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(316,'derived_stat','pace','flat_bonus',-1,'Pace −1','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(317,NULL,NULL,'description',NULL,'Running die reduced one step (minimum d4−1)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(318,NULL,NULL,'description',NULL,'May not take Fleet-Footed Edge. May have prosthesis (if lost, suffer Major Slow effects)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(319,'derived_stat','pace','flat_bonus',-2,'Pace −2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(320,NULL,NULL,'description',NULL,'Running die reduced one step','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(321,'skill','Athletics','roll_bonus',-2,'−2 to Athletics rolls and rolls to resist Athletics','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(322,NULL,NULL,'description',NULL,'May not take Fleet-Footed Edge. May start with wheelchair (Victorian+)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(323,'derived_stat','size','flat_bonus',-1,'Size −1 (minimum −1)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(324,'derived_stat','toughness','flat_bonus',-1,'Toughness −1 (even if Size already at minimum)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(325,NULL,NULL,'description',NULL,'Must keep word, not abuse or kill prisoners, and operate within world''s notion of proper behavior','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(326,NULL,NULL,'description',NULL,'Will take any risk to achieve noble but deadly goals, though not suicidal without reason','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(327,NULL,NULL,'description',NULL,'Believes something strange but harmless, generally keeps it to self','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(328,NULL,NULL,'description',NULL,'Expresses strange beliefs frequently, can occasionally lead to danger','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(329,NULL,NULL,'description',NULL,'Personal goal shapes decisions but happens rarely or is fairly harmless','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(330,NULL,NULL,'description',NULL,'Overriding desire comes up frequently or causes peril for character and those around her','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(331,NULL,NULL,'description',NULL,'Draw two Action Cards in combat and act on the lowest','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(332,NULL,NULL,'description',NULL,'If Joker drawn, use it normally and ignore Hindrance for the round','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(333,NULL,NULL,'description',NULL,'Cannot take Quick or Level Headed Edges','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(334,NULL,NULL,'description',NULL,'-1 to all Trait rolls when in presence of phobia (GM determines when applicable)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(335,NULL,NULL,'description',NULL,'-2 to all Trait rolls when in presence of phobia (GM determines when applicable). Must be irrational, not obvious fear','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(336,NULL,NULL,'description',NULL,'Will do most anything to accomplish goals, stops short of true harm except to direct opponents','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(337,NULL,NULL,'description',NULL,'Will harm anyone and everyone who gets in the way of goals','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(338,NULL,NULL,'description',NULL,'Has annoying habit that irritates others. Allies tend to avoid character when possible','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(339,NULL,NULL,'description',NULL,'Physical or mental addiction (drugs, alcohol, etc.)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(340,NULL,NULL,'description',NULL,'Without fix for 24 hours: Vigor roll or take Fatigue level','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(341,NULL,NULL,'description',NULL,'Healing roll with appropriate medicine removes Fatigue for 4 hours (once per 24 hours)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(342,NULL,NULL,'description',NULL,'Fatigue can only be permanently recovered with the addicting substance','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(343,NULL,NULL,'description',NULL,'Never says no to person in need. Always helps those who can''t help themselves. First to run into danger, works for little or no pay, pushover for sob stories','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(344,NULL,NULL,'description',NULL,'Jealousy focused on one particular subject (skill, romantic interest, etc.). Complains, pouts, covets, claims credit, disobeys commands','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(345,NULL,NULL,'description',NULL,'Jealous of anyone who outshines character. Bad-mouths rivals, tries to upstage them, actively plots to discredit threats to ego','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(346,NULL,NULL,'description',NULL,'Risks life for friends without hesitation. First to come to friends'' rescue when threatened','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(347,'skill','Persuasion','roll_bonus',-1,'-1 to Persuasion rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(348,NULL,NULL,'description',NULL,'Ill-tempered and disagreeable. Trouble doing kind acts, must be paid, doesn''t accept favors graciously','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(349,'skill','Intimidation','roll_bonus',-2,'-2 to Intimidation rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(350,'skill','Persuasion','roll_bonus',-2,'-2 to Persuasion rolls when influencing those who aren''t character''s own kind','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(351,NULL,NULL,'description',NULL,'Treated as lower class. Locals raise prices, ignore pleas for help','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(352,'skill','Persuasion','roll_bonus',-2,'-2 to Persuasion rolls when influencing those who aren''t character''s own kind','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(353,NULL,NULL,'description',NULL,'Few or no legal rights in main campaign area. May be different species among xenophobes, AI without recognized sapience, etc.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(354,NULL,NULL,'description',NULL,'Suspicious of everyone. Paranoia causes trust issues. Demands full payment upfront, wants agreements in writing, believes friends are out to get him','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(355,NULL,NULL,'description',NULL,'Suspicious of everyone. Paranoia causes trust issues','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(356,NULL,NULL,'description',NULL,'Support rolls to aid this character are made at -2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(357,NULL,NULL,'description',NULL,'-2 when resisting Taunt attacks','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(358,NULL,NULL,'description',NULL,'-4 when resisting Taunt attacks','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(359,'skill','Persuasion','roll_bonus',-1,'-1 to Persuasion rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(360,'skill','Persuasion','roll_bonus',-2,'-2 to Persuasion rolls','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(361,NULL,NULL,'description',NULL,'Hostile powers suffer −2 penalty to affect this character (−4 with a raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(362,NULL,NULL,'description',NULL,'Damage from hostile powers reduced by 2 (or 4 with a raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(363,NULL,NULL,'description',NULL,'Stacks with Arcane Resistance','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(364,NULL,NULL,'description',NULL,'Additional Recipients (+1): Affect one additional target for +1 Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(365,NULL,NULL,'description',NULL,'Medium Blast Template: 2d6 damage (3d6 with a raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(366,NULL,NULL,'description',NULL,'Area Effect (+0/+1): Small Blast Template for no extra cost, or Large Blast Template for +1 Power Point','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(367,NULL,NULL,'description',NULL,'Damage (+2): Blast causes 3d6 damage (4d6 with a raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(368,NULL,NULL,'description',NULL,'No Range penalties','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(369,NULL,NULL,'description',NULL,'Damage: 2d6 (3d6 with a raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(370,NULL,NULL,'description',NULL,'Damage (+2): Bolt causes 3d6 damage (4d6 with a raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(371,NULL,NULL,'description',NULL,'Cone Template: 2d6 damage (3d6 with a raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(372,NULL,NULL,'description',NULL,'Damage (+2): Burst causes 3d6 damage (4d6 with a raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(373,NULL,NULL,'description',NULL,'All adjacent beings take 2d4 damage at end of affected character''s turn','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(374,NULL,NULL,'description',NULL,'Affects allies as well as enemies','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(375,NULL,NULL,'description',NULL,'Damage (+2): Damage field causes 2d6 damage','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(376,NULL,NULL,'description',NULL,'Foes subtract 2 from melee OR ranged attacks (caster''s choice)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(377,NULL,NULL,'description',NULL,'With a raise, penalty applies to both melee and ranged attacks','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(378,NULL,NULL,'description',NULL,'Ranged weapons used in melee suffer penalty either way','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(379,NULL,NULL,'description',NULL,'Additional Recipients (+1): Affect one additional target for +1 Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(380,NULL,NULL,'description',NULL,'+2 Armor (or +2 Toughness with a raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(381,NULL,NULL,'description',NULL,'Stacks with all other armor','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(382,NULL,NULL,'description',NULL,'Negated by AP as usual','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(383,NULL,NULL,'description',NULL,'Additional Recipients (+1): Affect one additional target for +1 Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(384,NULL,NULL,'description',NULL,'Weapon damage increased by +2 (+4 with a raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(385,NULL,NULL,'description',NULL,'For ranged weapons: affects one magazine, 20 bolts/shells/arrows, or one full load','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(386,NULL,NULL,'description',NULL,'Additional Recipients (+1): Affect one additional target for +1 Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(387,NULL,NULL,'description',NULL,'Victim makes Vigor roll or is Stunned (−2 with a raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(388,NULL,NULL,'description',NULL,'Area Effect (+2/+3): Medium Blast Template for +2 Power Points, Large Blast Template for +3 Power Points','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(389,NULL,NULL,'description',NULL,'Opposed roll vs Spirit: Success = Shaken, Raise = 1 Wound. Incapacitation returns entity to native plane','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(390,NULL,NULL,'description',NULL,'Creates wall 5" long, 1" tall, Hardness 10','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(391,NULL,NULL,'description',NULL,'Modifier: Damage (+1 PP) - Barrier causes 2d4 damage on contact','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(392,NULL,NULL,'description',NULL,'Modifier: Hardened (+1 PP) - Wall is Hardness 12','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(393,NULL,NULL,'description',NULL,'Modifier: Shaped (+2 PP) - Barrier forms circle, square, or other basic shape','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(394,NULL,NULL,'description',NULL,'Modifier: Size (+1 PP) - Length and height double','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(395,NULL,NULL,'description',NULL,'Success: -2 to sight actions, Raise: -4 to sight actions','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(396,NULL,NULL,'description',NULL,'Victim shakes off with Vigor roll: Success removes 2 penalty, Raise removes all','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(397,NULL,NULL,'description',NULL,'Modifier: Area Effect (+2/+3 PP) - Medium/Large Blast Template','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(398,NULL,NULL,'description',NULL,'Modifier: Strong (+1 PP) - Vigor roll at -2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(399,NULL,NULL,'description',NULL,'Success: Distracted or Vulnerable, Raise: Both','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(400,NULL,NULL,'description',NULL,'Affects Medium Blast Template','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(401,NULL,NULL,'description',NULL,'Modifier: Area Effect (+0/+1 PP) - Small Blast Template (no cost) or Large Blast Template (+1 PP)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(402,NULL,NULL,'description',NULL,'Success: Entangled, Raise: Bound (Hardness 8)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(403,NULL,NULL,'description',NULL,'Modifier: Area Effect (+2/+3 PP) - Medium/Large Blast Template','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(404,NULL,NULL,'description',NULL,'Modifier: Tough (+1 PP) - Hardness 10','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(405,NULL,NULL,'description',NULL,'Targets make Fear roll: Extras Panicked on fail, Wild Cards roll Fear Table','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(406,NULL,NULL,'description',NULL,'Raise: Fear roll at -2, Wild Cards +2 to Fear Table','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(407,NULL,NULL,'description',NULL,'Modifier: Area Effect (+2/+3 PP) - Medium/Large Blast Template','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(408,NULL,NULL,'description',NULL,'Targets Distracted, make Strength roll or hurled 2d6" away','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(409,NULL,NULL,'description',NULL,'Raise: Strength roll at -2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(410,NULL,NULL,'description',NULL,'Striking hard object: 2d4 damage (nonlethal)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(411,NULL,NULL,'description',NULL,'Airborne targets: -2 to Strength roll','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(412,NULL,NULL,'description',NULL,'Modifier: Area Effect (+1 PP) - Large Blast Template','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(413,NULL,NULL,'description',NULL,'Modifier: Strong (+1 PP) - Strength rolls at -2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(414,NULL,NULL,'description',NULL,'Opposed roll vs Spirit: Success = obeys safe commands, Raise = obeys harmful commands','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(415,NULL,NULL,'description',NULL,'Harmful orders: Spirit roll to resist (Raise breaks control)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(416,NULL,NULL,'description',NULL,'Modifier: Additional Recipients (+2 PP each)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(417,NULL,NULL,'description',NULL,'Sloth: Halves movement, Raise: movement is an action','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(418,NULL,NULL,'description',NULL,'Sloth: Spirit roll to shake off at end of turn','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(419,NULL,NULL,'description',NULL,'Speed: Doubles movement, Raise: ignore -2 running penalty','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(420,NULL,NULL,'description',NULL,'Modifier: Additional Recipients (+1 PP each, Speed only)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(421,NULL,NULL,'description',NULL,'Modifier: Area Effect (+2/+3 PP, Sloth only) - Medium/Large Blast Template','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(422,NULL,NULL,'description',NULL,'Modifier: Quickness (+2 PP, Speed only) - Multi-Action penalty reduced by 2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(423,NULL,NULL,'description',NULL,'Modifier: Strong (+1 PP, Sloth only) - Spirit roll at -2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(424,NULL,NULL,'description',NULL,'Spirit roll to resist (at -2 with raise), fail = sleep for 1 hour','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(425,NULL,NULL,'description',NULL,'Loud noises or physical attempts grant another Spirit roll','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(426,NULL,NULL,'description',NULL,'Modifier: Area Effect (+2/+3 PP) - Medium/Large Blast Template','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(427,NULL,NULL,'description',NULL,'Boost: Increases selected Trait one die type (two with raise) for 5 rounds','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(428,NULL,NULL,'description',NULL,'Lower: Decreases selected Trait one die type (two with raise), victim can shake off with Spirit roll','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(429,NULL,NULL,'description',NULL,'Additional Recipients (+2): Affect additional targets for +1 Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(430,NULL,NULL,'description',NULL,'Strong (+1): Lower Trait only - Spirit roll to shake off at -2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(431,NULL,NULL,'description',NULL,'Ignores up to 4 points of illumination penalties (6 with raise, can see in pitch darkness)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(432,NULL,NULL,'description',NULL,'Additional Recipients (+1): Affect additional targets for +1 Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(433,NULL,NULL,'description',NULL,'Can read lips or fine print up to a mile distant','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(434,NULL,NULL,'description',NULL,'With raise: Halves Range penalties for Shooting, Athletics (throwing), or other abilities','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(435,NULL,NULL,'description',NULL,'Additional Recipients (+1): Affect additional targets for +1 Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(436,NULL,NULL,'description',NULL,'Target gains Flight at Pace 12" (24" with raise), may not Run','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(437,NULL,NULL,'description',NULL,'Additional Recipients (+2): Affect additional targets for +2 Power Points each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(438,NULL,NULL,'description',NULL,'Growth: +1 Size per 2 PP spent, grants +1 Strength die step and +1 Toughness per Size increase','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(439,NULL,NULL,'description',NULL,'Shrink: -1 Size per 2 PP spent (max Size -2), reduces Strength one die step and -1 Toughness per Size decrease','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(440,NULL,NULL,'description',NULL,'Unwilling targets oppose with Spirit roll','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(441,NULL,NULL,'description',NULL,'Recover: Removes one condition (Shaken, Distracted, or Vulnerable), two with raise','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(442,NULL,NULL,'description',NULL,'Numb: Reduces total Wound/Fatigue penalties by 1 (2 with raise) for one hour','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(443,NULL,NULL,'description',NULL,'Additional Recipients (+1): Affect additional targets for +1 Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(444,NULL,NULL,'description',NULL,'Stunned (+1): If using Recover option, may also remove Stunned condition','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(445,NULL,NULL,'description',NULL,'Sound: Mimics any known sound or voice up to a loud shout, can be used as Test (opposed by Smarts)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(446,NULL,NULL,'description',NULL,'Silence: Mutes sound in Large Blast Template, -4 to Notice rolls (-auto fail with raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(447,NULL,NULL,'description',NULL,'Mobile (+1): Move area of effect up to arcane skill die type each round','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(448,NULL,NULL,'description',NULL,'Targeted (+0): Target individuals instead of area for 1 PP each, unwilling targets resist with Spirit (-2 with raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(449,NULL,NULL,'description',NULL,'Walk on vertical or horizontal surfaces at half Pace (full Pace and run with raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(450,NULL,NULL,'description',NULL,'+4 to Athletics rolls to climb or hang on to surfaces','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(451,NULL,NULL,'description',NULL,'Additional Recipients (+1): Affect additional targets for +1 Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(452,NULL,NULL,'description',NULL,'Grants recipient benefits of one Combat Edge (caster must meet Rank requirement)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(453,NULL,NULL,'description',NULL,'With raise: Grants Improved version of Edge (if available)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(454,NULL,NULL,'description',NULL,'Additional Recipients (+1): Affect additional targets for +1 Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(455,NULL,NULL,'description',NULL,'Greater Healing (+10 PP): Can restore any Wound, including those more than an hour old','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(456,NULL,NULL,'description',NULL,'Crippling Injuries (+20 PP): Can heal a permanent Crippling Injury. Requires an hour of preparation and only one casting is permitted per injury. If successful, the subject is Exhausted for 24 hours','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(457,NULL,NULL,'description',NULL,'Neutralize Poison or Disease (+1 PP): A successful healing roll negates any poison or disease. If the poison or disease has a bonus or penalty associated with it, the modifier applies to the arcane skill roll as well','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(458,NULL,NULL,'description',NULL,'Power (+5 PP): The hero can raise a spirit dead up to a decade for +5 Power Points','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(459,NULL,NULL,'description',NULL,'Additional Recipients (+1 PP): The power may affect more than one target for 1 additional Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(460,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one viewer for detect, or item for conceal, for 1 additional Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(461,NULL,NULL,'description',NULL,'Area Effect (+1/+2): Conceal only. The power affects everything in a sphere the size of a Medium Blast Template for +1 points, or a Large Blast Template for +2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(462,NULL,NULL,'description',NULL,'Strong (+1): Conceal only. Detection rolls to see through the concealment are made at −2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(463,NULL,NULL,'description',NULL,'Disenchant (+1): The caster can target a magical item rather than an individual or a power. This is a standard arcane skill roll, not an opposed roll','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(464,NULL,NULL,'description',NULL,'Multiple Powers (+3): If successful, the caster dispels all the target''s currently activated powers','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(465,'skill','Intimidation','roll_bonus',1,'+1 to Intimidation rolls against target (+2 with raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(466,'skill','Persuasion','roll_bonus',1,'+1 to Persuasion rolls against target (+2 with raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(467,'skill','Performance','roll_bonus',1,'+1 to Performance rolls against target (+2 with raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(468,'skill','Taunt','roll_bonus',1,'+1 to Taunt rolls against target (+2 with raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(469,'skill','Riding','roll_bonus',1,'+1 to Riding rolls when used on animals (+2 with raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(470,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(471,NULL,NULL,'description',NULL,'Sound (+1): The power generates sound appropriate to the illusion','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(472,NULL,NULL,'description',NULL,'Strong (+2): Smarts rolls to disbelieve the illusion are made at −2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(473,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect additional individuals for 1 additional Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(474,NULL,NULL,'description',NULL,'Edit (+1): Instead of wiping the memory, the caster edits it as she sees fit','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(475,NULL,NULL,'description',NULL,'Fast Cast (+2): The caster can wipe a memory as an action','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(476,NULL,NULL,'description',NULL,'Special cost: Equal to sum of Size (minimum 1 per creature)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(477,NULL,NULL,'description',NULL,'Success: Creatures obey simple commands like a well-trained dog','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(478,NULL,NULL,'description',NULL,'Raise: Beasts overcome natural fears to follow orders','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(479,NULL,NULL,'description',NULL,'Mind Rider (+1): Caster can communicate and sense through any befriended beasts','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(480,NULL,NULL,'description',NULL,'Power has d6 Strength (d8 with raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(481,NULL,NULL,'description',NULL,'ATTACK: Use activation roll as attack, 2d4 damage (3d4 with raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(482,NULL,NULL,'description',NULL,'MOVE: Move cubic foot of element up to Smarts as limited action','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(483,NULL,NULL,'description',NULL,'PUSH: Use activation roll in place of Strength for Push','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(484,NULL,NULL,'description',NULL,'SPECIAL EFFECTS: Create additional effects with GM approval','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(485,NULL,NULL,'description',NULL,'Opposed by target Spirit, -2 penalty if different Arcane Background','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(486,NULL,NULL,'description',NULL,'Success: Drain 1d6 Power Points from target','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(487,NULL,NULL,'description',NULL,'Raise: Add drained points to caster (may exceed maximum, used first)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(488,NULL,NULL,'description',NULL,'Can target arcane devices (creator resists with arcane skill)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(489,NULL,NULL,'description',NULL,'Variable cost: Attendant (1), Bodyguard (3), Seasoned (5), Mirror Self (7)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(490,NULL,NULL,'description',NULL,'Raise: Ally is Resilient','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(491,NULL,NULL,'description',NULL,'Ally acts on creator Action Card, follows commands, no personality','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(492,NULL,NULL,'description',NULL,'Attendant: Agi/Sma/Spi/Str/Vig d4, Athletics/Fighting/Notice/Shooting d4, Stealth d6, Pace 4, Parry 4, Toughness 4, Claw Str+d4, Construct, Fearless','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(493,NULL,NULL,'description',NULL,'Bodyguard: Agi/Vig d6, Sma d4, Spi d6, Str d6, Athletics/Fighting d6, Intimidation d6, Notice/Shooting/Stealth d4, Pace 6, Parry 5, Toughness 7(2), First Strike, Armor +2, Str+d6 melee, Construct, Fearless','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(494,NULL,NULL,'description',NULL,'Sentinel: Agi d6, Sma d6, Spi d8, Str d12+2, Vig d10, Athletics d6, Fighting d10, Intimidation d10, Notice d8, Pace 6, Parry 7, Toughness 13(4), Arcane Resistance, Sweep(Imp), Armor +4, Str+d8 melee, Construct, Fearless, Size 2','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(495,NULL,NULL,'description',NULL,'Mirror Self: Clone of caster (Extra, same PP after casting, no summon ally, skills -1 die, identical mundane equipment, Construct, Fearless)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(496,NULL,NULL,'description',NULL,'Bite/Claw (+1): Ally can bite or claw at Str+d6','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(497,NULL,NULL,'description',NULL,'Flight (+2): Ally can fly at Pace 12','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(498,NULL,NULL,'description',NULL,'Mind Rider (+1): Caster can communicate and sense through the ally','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(499,NULL,NULL,'description',NULL,'Power has d10 Strength (d12 with raise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(500,NULL,NULL,'description',NULL,'Unwilling targets resist with opposed Spirit roll (when targeted and each turn)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(501,NULL,NULL,'description',NULL,'BASH: Target takes Str+d6 damage','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(502,NULL,NULL,'description',NULL,'CHANGE TARGETS: Release/pick up as free action, new unwilling target is action','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(503,NULL,NULL,'description',NULL,'MANIPULATE: Use tools or wield weapon with arcane skill','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(504,NULL,NULL,'description',NULL,'MOVE: Move target up to Smarts as limited free action','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(505,NULL,NULL,'description',NULL,'Raise: Increase any one Trait by one die type','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(506,NULL,NULL,'description',NULL,'Zombie retains physical skills, Smarts/Spirit/related skills reset to d4','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(507,NULL,NULL,'description',NULL,'Must be controlled by voice, obedient but literal-minded','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(508,NULL,NULL,'description',NULL,'Requires corpses in Range, Incapacitated undead cannot be raised again','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(509,NULL,NULL,'description',NULL,'Zombie creatures: 2 PP (Size -1/-2), 3 PP (Size 0), 1/2/3 PP (Small/Medium/Large Swarms), 3+Size PP (larger)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(510,NULL,NULL,'description',NULL,'Additional Zombies (+1): Pay +1 PP per extra zombie raised at once','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(511,NULL,NULL,'description',NULL,'Armed & Armored (+1 per Zombie): +2 Armor and weapons (Hand Weapon Str+d6 or Ranged 12/24/48 2d6)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(512,NULL,NULL,'description',NULL,'Mind Rider (+1): Communicate and sense through one undead, jump to others if destroyed','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(513,NULL,NULL,'description',NULL,'Permanent (0): Zombie animated until Incapacitated, PP invested until destroyed, can terminate at any time','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(514,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(515,NULL,NULL,'description',NULL,'Power (+1): The caster can burrow through stone, concrete, or similar substances','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(516,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(517,NULL,NULL,'description',NULL,'Size (+1): The recipient may assume the shape of someone up to two Sizes larger or smaller than themselves','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(518,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(519,NULL,NULL,'description',NULL,'Additional Recipients (+3): The power may affect more than one target for 3 additional Power Points each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(520,NULL,NULL,'description',NULL,'Mobile (+1): The caster can move the area of effect up to his arcane skill die type each round after casting, or attach it to an inanimate object when first cast','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(521,NULL,NULL,'description',NULL,'Wound Effects: The shape changed individual cannot benefit from a creature''s additional Wounds or Wound reduction abilities like Resilient, Very Resilient, or Unstoppable, or extra Wounds from Large or Huge Scale creatures','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(522,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(523,NULL,NULL,'description',NULL,'Teleport Foe (+2): Foes may be targeted by a Touch attack. The foe resists the casting with an opposed Spirit roll against the arcane skill total and is sent up to 12″ away with success and 24″ with a raise. Foes may not be teleported into solid objects','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(524,'power_slots',NULL,'flat_bonus',2,'+2 power slots','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(525,'power_points',NULL,'flat_bonus',5,'+5 power points','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(526,'wealth',NULL,'flat_bonus',1000,'+$1000 starting wealth (3x total)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(527,'wealth',NULL,'flat_bonus',1000,'+$1000 starting wealth (5x total with Rich)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(528,'derived_stat','parry','flat_bonus',1,'+1 Parry (small shield)','2025-12-30 18:25:49','2025-12-30 18:25:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(529,'derived_stat','parry','flat_bonus',2,'+2 Parry (medium shield)','2025-12-30 18:25:49','2025-12-30 18:25:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(530,'derived_stat','parry','flat_bonus',2,'+2 Parry (large shield)','2025-12-30 18:25:49','2025-12-30 18:25:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(531,'derived_stat','parry','flat_bonus',2,'+2 Parry (riot shield)','2025-12-30 18:25:49','2025-12-30 18:25:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(532,'derived_stat','parry','flat_bonus',2,'+2 Parry (ballistic shield)','2025-12-30 18:25:49','2025-12-30 18:25:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(533,'derived_stat','parry','flat_bonus',1,'+1 Parry (energy shield)','2025-12-30 18:25:49','2025-12-30 18:25:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(534,'derived_stat','parry','flat_bonus',1,'+1 Parry (rapier)','2025-12-30 18:25:49','2025-12-30 18:25:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(535,'skill','Healing','roll_bonus',1,'+1 to Healing skill','2025-12-30 18:25:49','2025-12-30 18:25:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(536,'derived_stat','parry','flat_bonus',1,'+1 Parry (small shield)','2025-12-30 18:41:48','2025-12-30 18:41:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(537,'derived_stat','parry','flat_bonus',2,'+2 Parry (medium shield)','2025-12-30 18:41:48','2025-12-30 18:41:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(538,'derived_stat','parry','flat_bonus',2,'+2 Parry (large shield)','2025-12-30 18:41:48','2025-12-30 18:41:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(539,'derived_stat','parry','flat_bonus',2,'+2 Parry (riot shield)','2025-12-30 18:41:48','2025-12-30 18:41:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(540,'derived_stat','parry','flat_bonus',2,'+2 Parry (ballistic shield)','2025-12-30 18:41:48','2025-12-30 18:41:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(541,'derived_stat','parry','flat_bonus',1,'+1 Parry (energy shield)','2025-12-30 18:41:48','2025-12-30 18:41:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(542,'derived_stat','parry','flat_bonus',1,'+1 Parry (rapier)','2025-12-30 18:41:48','2025-12-30 18:41:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(543,'skill','Healing','roll_bonus',1,'+1 to Healing skill','2025-12-30 18:41:48','2025-12-30 18:41:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(544,NULL,NULL,'description',NULL,'-2 penalty to resist Tests from characters with Attractive or Very Attractive Edge','2026-01-02 18:46:20','2026-01-02 18:46:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(545,NULL,NULL,'description',NULL,'Subtract 2 when making a Trait roll to resist a power, including arcane creature abilities','2026-01-02 18:46:46','2026-01-02 18:46:46',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(546,NULL,NULL,'description',NULL,'Subtract 4 when making a Trait roll to resist a power, including arcane creature abilities','2026-01-02 18:46:46','2026-01-02 18:46:46',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(547,NULL,NULL,'description',NULL,'Subtract 4 from arcane skill rolls when wearing medium or heavy armor, or using a medium or heavy shield','2026-01-02 18:47:03','2026-01-02 18:47:03',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(548,NULL,NULL,'description',NULL,'Cannot use abilities from Arcane Background Edge or Edges requiring Arcane Background when wearing medium or heavy armor, or using a medium or heavy shield','2026-01-02 18:47:03','2026-01-02 18:47:03',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(549,NULL,NULL,'description',NULL,'May wear cloth or light armor, or use light shields, without interference','2026-01-02 18:47:03','2026-01-02 18:47:03',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(550,NULL,NULL,'description',NULL,'Subtract 4 from arcane skill rolls when wearing any armor (including light), or using a medium or heavy shield','2026-01-02 18:47:03','2026-01-02 18:47:03',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(551,NULL,NULL,'description',NULL,'Cannot use abilities from Arcane Background Edge or Edges requiring Arcane Background when wearing any armor (including light), or using a medium or heavy shield','2026-01-02 18:47:03','2026-01-02 18:47:03',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(552,NULL,NULL,'description',NULL,'May use light shields without interference','2026-01-02 18:47:03','2026-01-02 18:47:03',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(553,NULL,NULL,'description',NULL,'Suffers a Critical Failure whenever a chosen skill roll fails and that skill die is a 1','2026-01-02 18:47:24','2026-01-02 18:47:24',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(554,NULL,NULL,'description',NULL,'On Critical Failure of arcane skill roll, gain a new Minor Hindrance or upgrade an existing Corruption-gained Minor Hindrance to Major','2026-01-02 18:47:38','2026-01-02 18:47:38',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(555,NULL,NULL,'description',NULL,'At next Advance, may remove one Minor Hindrance or reduce/trade a Major Hindrance for a Minor (in addition to regular Advance)','2026-01-02 18:47:38','2026-01-02 18:47:38',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(556,NULL,NULL,'description',NULL,'Magic draws on foul, evil, or tainted source','2026-01-02 18:47:38','2026-01-02 18:47:38',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(557,NULL,NULL,'roll_bonus',-2,'Beneficial magical powers targeting this character suffer -2 to the arcane skill roll','2026-01-02 18:47:54','2026-01-02 18:47:54',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(558,NULL,NULL,'description',NULL,'On a Critical Failure when casting beneficial powers on this character, the caster is Stunned','2026-01-02 18:47:54','2026-01-02 18:47:54',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(559,NULL,NULL,'description',NULL,'Curse applies to area effect powers and Additional Recipients modifier; no effect on hostile powers','2026-01-02 18:47:54','2026-01-02 18:47:54',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(560,'derived_stat','soak','roll_bonus',-2,'-2 to Soak rolls','2026-01-02 18:48:08','2026-01-02 18:48:08',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(561,NULL,NULL,'description',NULL,'Automatically Provoked on any successful Taunt (Provoke Edge not required)','2026-01-02 18:48:14','2026-01-02 18:48:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(562,NULL,NULL,'description',NULL,'When Provoked, -2 to rolls affecting anyone except the one who insulted him','2026-01-02 18:48:14','2026-01-02 18:48:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(563,NULL,NULL,'description',NULL,'Provoked condition lasts until a Joker is drawn or someone else successfully Taunts','2026-01-02 18:48:14','2026-01-02 18:48:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(564,NULL,NULL,'description',NULL,'Sees morality in black and white terms, struggles with nuanced dilemmas','2026-01-02 18:48:21','2026-01-02 18:48:21',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(565,'skill','Persuasion','roll_bonus',-2,'-2 to Persuasion rolls when interacting with members of disliked cultures','2026-01-02 18:48:27','2026-01-02 18:48:27',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(566,NULL,NULL,'description',NULL,'Targets of character''s cultural disdain may not benefit from his Command Edges (minor)','2026-01-02 18:48:27','2026-01-02 18:48:27',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(567,NULL,NULL,'description',NULL,'Believes own culture is superior and belittles other cultures at every opportunity (minor)','2026-01-02 18:48:27','2026-01-02 18:48:27',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(568,'skill','Persuasion','roll_bonus',-4,'-4 to Persuasion rolls when interacting with members of disliked cultures','2026-01-02 18:48:27','2026-01-02 18:48:27',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(569,NULL,NULL,'description',NULL,'Targets of character''s cultural disdain may not benefit from his Command Edges (major)','2026-01-02 18:48:27','2026-01-02 18:48:27',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(570,NULL,NULL,'description',NULL,'Believes own culture is superior and belittles other cultures at every opportunity (major)','2026-01-02 18:48:27','2026-01-02 18:48:27',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(571,NULL,NULL,'description',NULL,'Powers consume material components (assumed to be at hand in pocket, bag, or pouch)','2026-01-02 18:48:33','2026-01-02 18:48:33',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(572,NULL,NULL,'description',NULL,'Subtract 4 from all arcane skill rolls when stripped of possessions and unable to get materials','2026-01-02 18:48:33','2026-01-02 18:48:33',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(573,NULL,NULL,'description',NULL,'Restore materials at shop for 50gp x Rank, or gather/forage for a day with Survival roll per Rank','2026-01-02 18:48:33','2026-01-02 18:48:33',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(574,NULL,NULL,'description',NULL,'Runs out of material components on a Critical Failure','2026-01-02 18:48:33','2026-01-02 18:48:33',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(575,NULL,NULL,'description',NULL,'Puts others'' needs before own comfort and safety (minor extent/frequency)','2026-01-02 18:48:39','2026-01-02 18:48:39',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(576,NULL,NULL,'description',NULL,'Gives up creature comforts for others (bed, food, resources)','2026-01-02 18:48:39','2026-01-02 18:48:39',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(577,NULL,NULL,'description',NULL,'Puts others'' needs before own comfort and safety (major extent/frequency)','2026-01-02 18:48:39','2026-01-02 18:48:39',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(578,NULL,NULL,'description',NULL,'Gives up creature comforts for others (bed, food, resources)','2026-01-02 18:48:39','2026-01-02 18:48:39',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(579,NULL,NULL,'description',NULL,'Shields others from danger, even at great personal risk','2026-01-02 18:48:39','2026-01-02 18:48:39',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(580,NULL,NULL,'description',NULL,'Subtract 1 from all arcane skill rolls when caught without talisman','2026-01-02 18:48:45','2026-01-02 18:48:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(581,NULL,NULL,'description',NULL,'Stunned if arcane skill roll is a Critical Failure when without talisman','2026-01-02 18:48:45','2026-01-02 18:48:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(582,NULL,NULL,'description',NULL,'Subtract 2 from all arcane skill rolls when caught without talisman','2026-01-02 18:48:45','2026-01-02 18:48:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(583,NULL,NULL,'description',NULL,'Stunned if arcane skill roll is a Critical Failure when without talisman (major)','2026-01-02 18:48:45','2026-01-02 18:48:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(584,NULL,NULL,'description',NULL,'Dependency: Must immerse in water 1 hour out of every 24 or become Fatigued each day until Incapacitated; the day after Incapacitation, they perish; each hour in water restores one level of Fatigue','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(585,'derived_stat','toughness','flat_bonus',1,'Toughness +1: Dense, resilient flesh from life in the depths','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(586,'skill','Athletics','roll_bonus',-2,'Can''t Swim: -2 Athletics (swimming), each inch moved in water costs 3" of Pace','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(587,NULL,NULL,'description',NULL,'Flight: Fly at Pace 12, use Athletics when maneuvering','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(588,'derived_stat','toughness','flat_bonus',-1,'Frail: Toughness -1 due to hollow bones','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(589,'skill','Notice','die_increment',1,'Keen Senses: Notice starts at d6 (max d12+1)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(590,'derived_stat','pace','flat_bonus',-1,'Reduced Pace: Pace -1 due to dependence on flight and bulky wings','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(591,NULL,NULL,'description',NULL,'Reduced Pace: Running die reduced by one die type','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(592,NULL,NULL,'description',NULL,'Flight: Fly Pace 12 per round, use Athletics when maneuvering','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(593,NULL,NULL,'description',NULL,'Dependency: Must gallop outdoors at least 1 hour out of every 24 or take a level of Fatigue each day (recovers 1 level per hour outdoors, cannot cause Incapacitation)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(594,NULL,NULL,'description',NULL,'Hooves: Str+d4 damage (natural weapon, Martial Artist applies)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(595,'derived_stat','size','flat_bonus',1,'Size +1: Human-sized upper body with horse hindquarters','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(596,'derived_stat','toughness','flat_bonus',1,'Size +1: Toughness +1','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(597,'derived_stat','pace','flat_bonus',4,'Pace +4: Swift runners (Pace 10)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(598,NULL,NULL,'description',NULL,'Pace +4: Running die is d10','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(599,NULL,NULL,'description',NULL,'Unusual Form: Cannot ride mounts or use certain armor, gear, or equipment not fitted for form','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(600,'derived_stat','toughness','flat_bonus',2,'Armor +2: Thick scaly skin','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(601,NULL,NULL,'description',NULL,'Bite/Claws: Str+d4, AP 2; Bite allows biting grappled prey; Claws add +2 to Athletics when climbing','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(602,NULL,NULL,'description',NULL,'Breath Weapon: Athletics roll, 2d6 damage in Cone Template or 12" line (chosen at creation), may be Evaded; Critical Failure causes Fatigue','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(603,NULL,NULL,'description',NULL,'Cold-Blooded: -1 to Agility, Strength, and Vigor rolls after 10+ minutes below 60F (18C)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(604,NULL,NULL,'description',NULL,'Environmental Resistance (Heat): +4 to resist heat/fire effects, -4 damage from heat/fire','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(605,NULL,NULL,'description',NULL,'Environmental Weakness (Cold): -4 to resist cold effects, +4 damage from cold','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(606,'derived_stat','pace','flat_bonus',-1,'Reduced Pace: Pace -1 due to short legs','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(607,'attribute','Vigor','die_increment',1,'Tough: Vigor starts at d6 (max d12+1)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(608,NULL,NULL,'description',NULL,'Environmental Resistance: +4 to resist ancestral element effects, -4 damage from ancestral element','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(609,'skill','Persuasion','roll_bonus',-2,'Outsider (Minor): -2 Persuasion with non-scions','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(610,'attribute','Agility','die_increment',1,'Air Scion - Inner Air: Agility starts at d6 instead of d4 (maximum Agility d12+1)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(611,'attribute','Vigor','die_increment',1,'Earth Scion - Rock Solid: Vigor starts at d6 instead of d4 (maximum Vigor d12+1)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(612,'attribute','Spirit','die_increment',1,'Water Scion - Aquatic: Spirit starts at d6 instead of d4 (maximum Spirit d12+1)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(613,'attribute','Agility','die_increment',1,'Agile: Agility starts at d6 (max d12+1)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(614,NULL,NULL,'description',NULL,'All Thumbs: Has the All Thumbs Hindrance (inherent dislike of mechanical objects, -2 when using mechanical or electronic devices)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(615,NULL,NULL,'description',NULL,'All Thumbs: Has the All Thumbs Hindrance (disdain for mechanical objects)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(616,NULL,NULL,'description',NULL,'Big Mouth: Has the Big Mouth Hindrance (rarely keep secrets)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(617,NULL,NULL,'description',NULL,'Curious: Has the Curious Hindrance (endless questions about life)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(618,NULL,NULL,'description',NULL,'Flight: Fly at Pace 6','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(619,NULL,NULL,'description',NULL,'Impulsive: Has the Impulsive Hindrance (lack restraint)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(620,'derived_stat','size','flat_bonus',-4,'Diminutive (Tiny): Size -4','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(621,'derived_stat','toughness','flat_bonus',-4,'Diminutive (Tiny): Toughness -4','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(622,NULL,NULL,'description',NULL,'Diminutive (Tiny): -4 to damage rolls, maximum Strength d4, benefits from Scale modifiers','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(623,'attribute','Smarts','die_increment',1,'Cunning: Smarts starts at d6 (max d12+1)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(624,'derived_stat','size','flat_bonus',-1,'Size -1: Four feet tall','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(625,'derived_stat','toughness','flat_bonus',-1,'Size -1: Toughness -1','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(626,'derived_stat','pace','flat_bonus',-1,'Reduced Pace: Pace -1','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(627,NULL,NULL,'description',NULL,'Infravision: Halve penalties for bad lighting when attacking heat- or cold-radiating targets','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(628,'skill','Persuasion','roll_bonus',-1,'Mean: -1 Persuasion','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(629,'derived_stat','size','flat_bonus',-1,'Short: Size -1','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(630,'derived_stat','toughness','flat_bonus',-1,'Short: Toughness -1','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(631,'skill','Stealth','die_increment',1,'Sneaky: Stealth starts at d6 (max d12+1)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(632,'derived_stat','toughness','flat_bonus',2,'Armor +2: Made of rock','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(633,NULL,NULL,'description',NULL,'Big: -2 to Trait rolls when using equipment made for normal-sized humanoids','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(634,'skill','Common Knowledge','roll_bonus',-1,'Clueless: -1 Common Knowledge','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(635,'skill','Notice','roll_bonus',-1,'Clueless: -1 Notice','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(636,'skill','Athletics','roll_bonus',-2,'Clumsy: -2 Athletics','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(637,'skill','Stealth','roll_bonus',-2,'Clumsy: -2 Stealth','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(638,NULL,NULL,'description',NULL,'Construct: +2 to recover from Shaken, ignore 1 point of Wound penalties, do not breathe or eat, immune to disease and poison, do not Bleed Out, Wounds removed with Repair (not Healing, no Golden Hour)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(639,NULL,NULL,'description',NULL,'No Vital Organs: Called shots do no extra damage','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(640,NULL,NULL,'description',NULL,'Reduced Core Skills: Do not start with Common Knowledge, Persuasion, or Stealth','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(641,'derived_stat','pace','flat_bonus',-1,'Reduced Pace: Pace 5','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(642,NULL,NULL,'description',NULL,'Reduced Pace: Running die is d4','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(643,'derived_stat','size','flat_bonus',2,'Size +2: Dense, hulking, about 900 lbs','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(644,'derived_stat','toughness','flat_bonus',2,'Size +2: Toughness +2','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(645,NULL,NULL,'description',NULL,'Animal Aversion: Animals will not approach within 5" (10 yards); -2 to control or ride animals (except wolves, rats, ravens, and other animals associated with the undead)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(646,NULL,NULL,'description',NULL,'Bite: Fangs cause Str+d4 damage','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(647,NULL,NULL,'description',NULL,'Blood Drinker: Once per session, drink humanoid blood to make a natural healing roll (Wounding with Bite provides enough blood, or drinking from living downed foe who must make Vigor to survive)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(648,'skill','Persuasion','roll_bonus',-2,'Outsider (Major): -2 Persuasion, give off uneasy aura, may be mistaken for vampires','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(649,NULL,NULL,'description',NULL,'Sunlight Sensitivity: Must cover completely or be Distracted while exposed to sunlight (or magical/artificial equivalent)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(650,'attribute','Strength','die_increment',1,'Unnatural Strength: Strength starts at d6 (max d12+1)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(651,NULL,NULL,'description',NULL,'Cold Resistance: +4 to resist cold effects, -4 cold damage','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(652,NULL,NULL,'description',NULL,'Outsider (Minor): -2 Persuasion with all but other half-elves','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(653,'attribute','Agility','die_increment',1,'Elven Grace: Agility starts at d6 instead of d4 (maximum Agility d12+1)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(654,NULL,NULL,'description',NULL,'Luck: Draw one additional Benny per game session','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(655,'derived_stat','size','flat_bonus',-1,'Size -1: About four feet tall','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(656,'attribute','Spirit','die_increment',1,'Spirited: Spirit starts at d6 (max d12+1)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(657,NULL,NULL,'description',NULL,'Big: -2 when using humanoid equipment, cannot wear humanoid armor or clothing, equipment/armor/food/clothing cost double','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(658,'attribute','Smarts','roll_bonus',-1,'Boneheaded: -1 to Smarts rolls','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(659,NULL,NULL,'description',NULL,'Illiterate: Cannot read or write (may be bought off with an Advance)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(660,'skill','Persuasion','roll_bonus',-2,'Outsider (Major): -2 Persuasion, no basic rights outside own familial clans','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(661,'derived_stat','size','flat_bonus',3,'Size +3: 10 feet tall','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(662,'derived_stat','toughness','flat_bonus',3,'Size +3: Toughness +3','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(663,'attribute','Strength','die_increment',2,'Very Strong: Strength starts at d8 (max d12+2)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(664,'attribute','Vigor','die_increment',2,'Very Tough: Vigor starts at d8 (max d12+2)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(665,NULL,NULL,'description',NULL,'Infravision: Halve penalties for bad lighting when attacking heat-radiating targets','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(666,'skill','Persuasion','roll_bonus',-2,'Outsider (Minor): -2 Persuasion with non-half-orcs','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(667,NULL,NULL,'description',NULL,'Darkvision: Ignore penalties for Illumination up to 10" (20 yards)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(668,'skill','Intimidation','roll_bonus',1,'Devilish Nature: +1 to Intimidation rolls','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(669,NULL,NULL,'description',NULL,'Horns: Str+d4 damage (+4 damage if run at least 5" and hit)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(670,'skill','Persuasion','roll_bonus',-2,'Outsider (Minor): -2 Persuasion (no one truly trusts devilish heritage)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(671,NULL,NULL,'description',NULL,'Additional Actions: Four usable arms; reduce total Multi-Action penalty by 2 when at least one action uses limbs (Agility, Strength, or linked skills)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(672,'derived_stat','toughness','flat_bonus',2,'Armor +2: Thick exoskeleton','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(673,NULL,NULL,'description',NULL,'Hive Minded: Has Driven (Major) and Loyal Hindrances when serving colony (community, adventuring party, etc.); great stress when goal contradicts loyalty','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(674,NULL,NULL,'description',NULL,'Bite or Claw: Mandibles or pincers deal Str+d4 damage','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(675,'skill','Persuasion','roll_bonus',-2,'Outsider (Minor): -2 Persuasion (other races find insectfolk unsettling)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(676,NULL,NULL,'description',NULL,'Unusual Body Shape: Cannot use most clothing, armor, or furniture fitted for humanoids (can use weapons and hand-held equipment normally)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(677,NULL,NULL,'description',NULL,'Wall Walker: Walk on vertical surfaces normally, inverted surfaces at half Pace','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(678,'derived_stat','size','flat_bonus',1,'Size +1: Tall and broad','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(679,NULL,NULL,'description',NULL,'Horns: Str+d6 damage (+4 damage if run at least 5" and hit)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(680,'attribute','Smarts','roll_bonus',-1,'Uneducated: -1 to Smarts rolls','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(681,'derived_stat','size','flat_bonus',-4,'Diminutive (Tiny): Size -4 (about six inches tall)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(682,'skill','Persuasion','roll_bonus',-2,'Outsider (Major): -2 Persuasion, no basic rights outside own people','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(683,NULL,NULL,'description',NULL,'Phobia (Minor) Cats: Instinctual fear of cats','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(684,NULL,NULL,'description',NULL,'Unimposing: Has the Mild Mannered Hindrance (most foes do not consider mouselings a threat)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(685,NULL,NULL,'description',NULL,'Arrogant: Has the Arrogant Hindrance (culture of boasts and deeds)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(686,'skill','Persuasion','roll_bonus',-2,'Outsider (Minor): -2 Persuasion (awkward, imposing size makes others nervous)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(687,NULL,NULL,'description',NULL,'Hardy: A second Shaken result from damage does not cause a Wound','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(688,'derived_stat','size','flat_bonus',1,'Size +1: 8-9 feet tall','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(689,'attribute','Smarts','roll_bonus',-1,'Brutish: -1 to Smarts rolls','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(690,'skill','Persuasion','roll_bonus',-2,'Outsider (Major): -2 Persuasion, may be treated as monsters','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(691,'derived_stat','size','flat_bonus',1,'Size +1: 7-8 feet tall','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(692,'attribute','Strength','die_increment',1,'Strong: Strength starts at d6 (max d12+1)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(693,NULL,NULL,'description',NULL,'Bite: Fangs cause Str+d4 damage (can be used against grappled foes)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(694,NULL,NULL,'description',NULL,'Claws: Str+d4 damage, AP 2, +2 to Athletics (Climbing) rolls','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(695,NULL,NULL,'description',NULL,'Bloodthirsty: Cruel to foes, often toy with them for amusement, rarely take prisoners','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(696,NULL,NULL,'description',NULL,'Can''t Swim: -2 Athletics when swimming, each tabletop inch of movement in water costs 3" of Pace','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(697,NULL,NULL,'description',NULL,'Claws: Str+d4 damage, +2 to Athletics (Climbing) rolls','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(698,NULL,NULL,'description',NULL,'Natural Resistance: Immune to poison and disease (life scavenging in filth)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(699,NULL,NULL,'description',NULL,'Scavenger: Has the Scavenger Edge (obsessively collect items)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(700,'skill','Persuasion','roll_bonus',-2,'Outsider (Major): -2 Persuasion, treated as monstrous vermin outside own packs','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(701,NULL,NULL,'description',NULL,'Craven: Has the Yellow Hindrance (discretion is the better part of valor)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(702,NULL,NULL,'description',NULL,'Greedy (Minor): Has the Greedy (Minor) Hindrance (obsessed with acquiring treasures)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(703,'derived_stat','toughness','flat_bonus',2,'Armor +2: Scaly skin acts as leather armor','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(704,NULL,NULL,'description',NULL,'Environmental Weakness (Cold): -4 to resist cold effects, +4 damage from cold attacks','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(705,NULL,NULL,'description',NULL,'Keen Senses: Has the Alertness Edge (acute senses)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(706,'skill','Persuasion','roll_bonus',-2,'Outsider (Minor): -2 Persuasion (most races distrust saurians)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(707,NULL,NULL,'description',NULL,'Bite: Str+d4 damage','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(708,NULL,NULL,'description',NULL,'Infravision: Halve penalties for bad lighting when attacking heat-radiating targets (pit organs)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(709,'derived_stat','pace','flat_bonus',4,'Pace 10: Move quickly like snakes','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(710,NULL,NULL,'description',NULL,'Pace 10: Running die is d10','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(711,NULL,NULL,'description',NULL,'Venomous Bite: Victims Shaken or Wounded by bite must make Vigor roll or suffer Mild Poison effects','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(712,NULL,NULL,'description',NULL,'Outsider (Minor): -2 to Persuasion rolls outside their own clans','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(713,NULL,NULL,'description',NULL,'Charismatic: Has the Charismatic Edge (quickly learn to navigate tricky conversations)','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(714,NULL,NULL,'description',NULL,'Change Shape: Has Arcane Background (Gifted); as limited free action, change physical appearance using disguise power with Personal Limitation','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(715,NULL,NULL,'description',NULL,'Secret (Major): True nature rarely known; if discovered by non-allies, trade this Hindrance for Enemy, Shamed, Wanted, Outsider, or another appropriate Hindrance','2026-01-03 21:24:41','2026-01-03 21:24:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(716,NULL,NULL,'description',NULL,'Enemy arcane abilities targeting this character suffer −2 penalty to arcane skill roll (FC)','2026-01-03 21:46:52','2026-01-03 21:46:52',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(717,NULL,NULL,'description',NULL,'Magical damage reduced by 2 including magical weapon bonuses (FC)','2026-01-03 21:46:52','2026-01-03 21:46:52',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(718,NULL,NULL,'description',NULL,'When spending Conviction, it lasts until end of encounter without maintaining with Bennies','2026-01-03 21:47:07','2026-01-03 21:47:07',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(719,NULL,NULL,'description',NULL,'Comes with Enemy (Major) Hindrance - a powerful being opposed to the Chosen''s quest','2026-01-03 21:47:07','2026-01-03 21:47:07',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(720,NULL,NULL,'description',NULL,'Has a visible mark that cannot be removed and occasionally shows through despite attempts to hide it','2026-01-03 21:47:07','2026-01-03 21:47:07',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(721,NULL,NULL,'description',NULL,'Free reroll when rolling Survival to track chosen enemy type','2026-01-03 21:47:19','2026-01-03 21:47:19',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(722,NULL,NULL,'description',NULL,'Free reroll when attacking chosen enemy type (Athletics throwing, Fighting, Shooting)','2026-01-03 21:47:19','2026-01-03 21:47:19',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(723,NULL,NULL,'description',NULL,'Free reroll to Survival rolls while in Favored Terrain','2026-01-03 21:47:33','2026-01-03 21:47:33',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(724,NULL,NULL,'description',NULL,'Free reroll to Notice rolls while in Favored Terrain','2026-01-03 21:47:33','2026-01-03 21:47:33',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(725,NULL,NULL,'description',NULL,'Draw additional Action Card for initiative while in Favored Terrain; choose from all cards','2026-01-03 21:47:33','2026-01-03 21:47:33',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(726,NULL,NULL,'description',NULL,'Free reroll when resisting enemy powers and spell-like effects','2026-01-03 21:47:42','2026-01-03 21:47:42',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(727,NULL,NULL,'description',NULL,'Grants one magic item (or themed set) worth up to 10,000 gold pieces','2026-01-03 21:47:58','2026-01-03 21:47:58',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(728,NULL,NULL,'description',NULL,'One-time benefit - item not replenished if destroyed or consumed; must recover if lost','2026-01-03 21:47:58','2026-01-03 21:47:58',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(729,NULL,NULL,'description',NULL,'Enemy arcane abilities targeting this character suffer −4 penalty to arcane skill roll (FC)','2026-01-03 21:48:09','2026-01-03 21:48:09',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(730,NULL,NULL,'description',NULL,'Magical damage reduced by 4 including magical weapon bonuses (FC)','2026-01-03 21:48:09','2026-01-03 21:48:09',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(731,NULL,NULL,'description',NULL,'+2 damage on first Fighting attack after running at least 5" (Charge)','2026-01-03 21:50:24','2026-01-03 21:50:24',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(732,NULL,NULL,'description',NULL,'Can be combined with Wild Attack (Charge)','2026-01-03 21:50:24','2026-01-03 21:50:24',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(733,NULL,NULL,'description',NULL,'With small weapon, attacks and Parry vs chosen adjacent target increase by target''s Reach+1 (Close Fighting)','2026-01-03 21:50:26','2026-01-03 21:50:26',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(734,NULL,NULL,'description',NULL,'No bonus if foe is unarmed or also armed with small weapon (Close Fighting)','2026-01-03 21:50:26','2026-01-03 21:50:26',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(735,NULL,NULL,'description',NULL,'Can share shield''s Parry and cover bonus with one adjacent ally as free action (Defender)','2026-01-03 21:50:27','2026-01-03 21:50:27',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(736,NULL,NULL,'description',NULL,'Shield bonuses don''t stack; use highest if protected by more than one (Defender)','2026-01-03 21:50:27','2026-01-03 21:50:27',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(737,NULL,NULL,'description',NULL,'+2 to rolls when performing Test with Fighting skill (Dirty Fighter)','2026-01-03 21:50:28','2026-01-03 21:50:28',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(738,NULL,NULL,'description',NULL,'Fire two arrows or throw two weapons as a single limited action (FC)','2026-01-03 21:50:29','2026-01-03 21:50:29',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(739,NULL,NULL,'description',NULL,'Roll two Shooting or Athletics (throwing) dice; each die hits and damages separately (FC)','2026-01-03 21:50:29','2026-01-03 21:50:29',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(740,NULL,NULL,'description',NULL,'Increases Gang Up bonus by +1 for self and allies (Formation Fighter)','2026-01-03 21:50:30','2026-01-03 21:50:30',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(741,NULL,NULL,'description',NULL,'Maximum Gang Up bonus is still +4 (Formation Fighter)','2026-01-03 21:50:30','2026-01-03 21:50:30',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(742,NULL,NULL,'description',NULL,'Close Fighter bonus increases to target''s Reach +2','2026-01-03 21:50:39','2026-01-03 21:50:39',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(743,NULL,NULL,'description',NULL,'May use Double Shot Edge twice per turn','2026-01-03 21:50:40','2026-01-03 21:50:40',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(744,NULL,NULL,'description',NULL,'Sneak Attack bonus also applies if foe is Distracted','2026-01-03 21:50:42','2026-01-03 21:50:42',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(745,NULL,NULL,'description',NULL,'Once per encounter, as free action, gain benefits of single Combat Edge for 5 rounds (must meet requirements) (FC)','2026-01-03 21:50:43','2026-01-03 21:50:43',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(746,NULL,NULL,'description',NULL,'With melee weapon or Martial Artist Edge, physical ranged attacks use Parry as base TN (if hero can see the attack)','2026-01-03 21:50:45','2026-01-03 21:50:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(747,NULL,NULL,'description',NULL,'When dealt a Joker, add +4 to Trait and damage rolls instead of +2 (FC)','2026-01-03 21:50:46','2026-01-03 21:50:46',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(748,NULL,NULL,'description',NULL,'On raise with Test, gain The Drop against foe until no longer Shaken (FC)','2026-01-03 21:50:57','2026-01-03 21:50:57',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(749,NULL,NULL,'description',NULL,'As limited action, make Intimidation Test in Cone Template (Roar)','2026-01-03 21:50:58','2026-01-03 21:50:58',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(750,NULL,NULL,'description',NULL,'All targets within Cone Template are affected and must resist separately (Roar)','2026-01-03 21:50:58','2026-01-03 21:50:58',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(751,NULL,NULL,'description',NULL,'+4 damage on Wild Attack instead of +2 (Savagery)','2026-01-03 21:50:59','2026-01-03 21:50:59',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(752,NULL,NULL,'description',NULL,'Breath weapon damage increases by one die type','2026-01-03 21:51:00','2026-01-03 21:51:00',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(753,NULL,NULL,'description',NULL,'Can use either Cone or Stream template for breath weapon as desired','2026-01-03 21:51:00','2026-01-03 21:51:00',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(754,NULL,NULL,'description',NULL,'With medium+ shield, +1 Parry if adjacent to 1 ally with Shield Wall Edge','2026-01-03 21:51:01','2026-01-03 21:51:01',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(755,NULL,NULL,'description',NULL,'With medium+ shield, +2 Parry if adjacent to 2+ allies with Shield Wall Edge (max bonus)','2026-01-03 21:51:01','2026-01-03 21:51:01',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(756,NULL,NULL,'description',NULL,'Assassin +2 bonus replaced by d6 (can Ace) for Athletics throwing, Fighting, Shooting attacks (FC)','2026-01-03 21:51:02','2026-01-03 21:51:02',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(757,NULL,NULL,'description',NULL,'With blunt weapon, on Shaken/Wound result, victim makes Vigor roll or Stunned (Stunning Blow)','2026-01-03 21:51:13','2026-01-03 21:51:13',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(758,NULL,NULL,'description',NULL,'+d6 to damage when striking to break objects (damage dice may not Ace) (FC)','2026-01-03 21:51:14','2026-01-03 21:51:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(759,NULL,NULL,'description',NULL,'Free reroll on Soak rolls made to eliminate Wounds','2026-01-03 21:51:19','2026-01-03 21:51:19',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(760,NULL,NULL,'description',NULL,'Free reroll on Vigor rolls to resist Knockout Blows','2026-01-03 21:51:19','2026-01-03 21:51:19',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(761,NULL,NULL,'description',NULL,'On Test with Athletics or Shooting, target resists with Smarts instead of Agility (FC)','2026-01-03 21:51:19','2026-01-03 21:51:19',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(762,NULL,NULL,'description',NULL,'Ignore -2 Agility penalty when making Evasion attempts (FC)','2026-01-03 21:51:20','2026-01-03 21:51:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(763,NULL,NULL,'description',NULL,'Get regular Evasion attempt against area effect attacks (FC)','2026-01-03 21:51:20','2026-01-03 21:51:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(764,NULL,NULL,'description',NULL,'As limited action, make Athletics roll to gust wings against same Size or smaller targets','2026-01-03 21:51:21','2026-01-03 21:51:21',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(765,NULL,NULL,'description',NULL,'On Critical Failure, take a level of Fatigue from over-exertion','2026-01-03 21:51:21','2026-01-03 21:51:21',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(766,NULL,NULL,'description',NULL,'On success, characters in Cone must make Vigor roll or be Shaken (-2 with raise)','2026-01-03 21:51:21','2026-01-03 21:51:21',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(767,NULL,NULL,'description',NULL,'If Action Card is Joker or under Conviction, failed Vigor causes Stunned instead of Shaken','2026-01-03 21:51:21','2026-01-03 21:51:21',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(768,NULL,NULL,'description',NULL,'Once per encounter in home area, spend Benny to recover all Power Points (Home Ground)','2026-01-03 21:52:33','2026-01-03 21:52:33',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(769,NULL,NULL,'description',NULL,'Concentrate 10 min (1 min on home ground) to ask 3 questions about observable state of home area (Home Ground)','2026-01-03 21:52:33','2026-01-03 21:52:33',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(770,NULL,NULL,'description',NULL,'Choose any one magical item from the book (Relic)','2026-01-03 21:52:36','2026-01-03 21:52:36',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(771,NULL,NULL,'description',NULL,'If lost, item is eventually recovered or replaced (may take 1-2 sessions) (Relic)','2026-01-03 21:52:36','2026-01-03 21:52:36',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(772,NULL,NULL,'description',NULL,'Has the Unstoppable monstrous ability (Unstoppable)','2026-01-03 21:52:38','2026-01-03 21:52:38',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(773,NULL,NULL,'description',NULL,'Five Followers gain the Resilient Monstrous Ability (Warband)','2026-01-03 21:52:39','2026-01-03 21:52:39',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(774,NULL,NULL,'description',NULL,'Can create temporary Arcane Devices (FC Artificer)','2026-01-03 21:54:09','2026-01-03 21:54:09',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(775,NULL,NULL,'description',NULL,'Can craft permanent magical items (FC Artificer)','2026-01-03 21:54:09','2026-01-03 21:54:09',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(776,NULL,NULL,'description',NULL,'Can cast spells on large units of Extras such as soldiers, henchmen, or guards (Battle Magic)','2026-01-03 21:54:17','2026-01-03 21:54:17',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(777,NULL,NULL,'description',NULL,'Recover d6 Power Points when causing unsoaked Wound to conscious sapient being (Blood Magic)','2026-01-03 21:54:18','2026-01-03 21:54:18',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(778,NULL,NULL,'description',NULL,'Must cause damage directly with powers, hands, or weapons; no benefit from indirect harm (Blood Magic)','2026-01-03 21:54:18','2026-01-03 21:54:18',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(779,NULL,NULL,'description',NULL,'Hunted by local authorities; often entire orders dedicated to eradication (Blood Magic)','2026-01-03 21:54:18','2026-01-03 21:54:18',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(780,NULL,NULL,'description',NULL,'Access to Epic Power Modifiers for all powers (Epic Mastery)','2026-01-03 21:54:20','2026-01-03 21:54:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(781,NULL,NULL,'description',NULL,'Applies to all Arcane Backgrounds if hero has multiple; must meet arcane skill d6+ for each (Epic Mastery)','2026-01-03 21:54:20','2026-01-03 21:54:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(782,NULL,NULL,'description',NULL,'Familiar is small creature (Size -2 or -3) as loyal pet and magical companion (Familiar)','2026-01-03 21:54:21','2026-01-03 21:54:21',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(783,NULL,NULL,'description',NULL,'Familiar is Wild Card (Wounds, Wild Die); no Bennies but mage can spend own on its behalf (Familiar)','2026-01-03 21:54:21','2026-01-03 21:54:21',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(784,NULL,NULL,'description',NULL,'Familiar and master can understand each other''s speech; concepts limited by species (Familiar)','2026-01-03 21:54:21','2026-01-03 21:54:21',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(785,NULL,NULL,'description',NULL,'Familiar has 5 Power Points; recharge separately; mage can draw as own; immune to beast friend (Familiar)','2026-01-03 21:54:21','2026-01-03 21:54:21',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(786,NULL,NULL,'description',NULL,'Familiar gains advancement option per master Rank and every 4th advance past Legendary: Second Sight, Training, or Enhancement (Familiar)','2026-01-03 21:54:21','2026-01-03 21:54:21',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(787,NULL,NULL,'description',NULL,'If familiar dies, hero is automatically Stunned; can summon new after 10 days (Familiar)','2026-01-03 21:54:21','2026-01-03 21:54:21',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(788,NULL,NULL,'description',NULL,'Choose one power to master (Favored Power)','2026-01-03 21:54:22','2026-01-03 21:54:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(789,NULL,NULL,'description',NULL,'Ignore up to 2 points of penalties (Multi-Action, Wounds, Fatigue, etc.) when activating chosen power (Favored Power)','2026-01-03 21:54:22','2026-01-03 21:54:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(790,NULL,NULL,'description',NULL,'Gains 1000 gp progress for each success and raise on Occult roll (Master Artificer)','2026-01-03 21:54:23','2026-01-03 21:54:23',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(791,NULL,NULL,'description',NULL,'Not limited to 2000 gp total progress per roll (Master Artificer)','2026-01-03 21:54:23','2026-01-03 21:54:23',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(792,NULL,NULL,'description',NULL,'10 dedicated Power Points that recharge normally (Mystic Powers)','2026-01-03 21:54:24','2026-01-03 21:54:24',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(793,NULL,NULL,'description',NULL,'Auto-activate powers with success as limited free action; raise for +2 PP (Mystic Powers)','2026-01-03 21:54:24','2026-01-03 21:54:24',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(794,NULL,NULL,'description',NULL,'Can use applicable Power Modifiers at usual cost (Mystic Powers)','2026-01-03 21:54:24','2026-01-03 21:54:24',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(795,NULL,NULL,'description',NULL,'No access to Edges requiring Arcane Background; can take Power Points or Soul Drain Edge (Mystic Powers)','2026-01-03 21:54:24','2026-01-03 21:54:24',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(796,NULL,NULL,'description',NULL,'If hero has Arcane Background, its Edges/abilities cannot be used with Mystic Powers and vice-versa (Mystic Powers)','2026-01-03 21:54:24','2026-01-03 21:54:24',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(797,NULL,NULL,'description',NULL,'Can cast spells or invoke miracles without speaking (Silent Caster)','2026-01-03 21:54:25','2026-01-03 21:54:25',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(798,NULL,NULL,'description',NULL,'Can cast underwater, in vacuum, while gagged, within silence spell, etc. (Silent Caster)','2026-01-03 21:54:25','2026-01-03 21:54:25',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(799,NULL,NULL,'description',NULL,'Transfer up to 5 Power Points to anyone in sight (Transfer)','2026-01-03 21:54:26','2026-01-03 21:54:26',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(800,NULL,NULL,'description',NULL,'Transfer is a limited free action (Transfer)','2026-01-03 21:54:26','2026-01-03 21:54:26',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(801,NULL,NULL,'description',NULL,'Free reroll on all Riding rolls (Born in the Saddle)','2026-01-03 21:55:40','2026-01-03 21:55:40',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(802,NULL,NULL,'description',NULL,'Mount''s Pace +2, running die one step higher (Born in the Saddle)','2026-01-03 21:55:40','2026-01-03 21:55:40',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(803,NULL,NULL,'description',NULL,'Survival roll for extra Action Card during travel, two on raise (Explorer)','2026-01-03 21:55:41','2026-01-03 21:55:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(804,NULL,NULL,'description',NULL,'Reduce Travel Times by 10 percent for party (Explorer)','2026-01-03 21:55:41','2026-01-03 21:55:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(805,NULL,NULL,'description',NULL,'+1 Intimidation or Persuasion vs those who respect or fear authority (Knight)','2026-01-03 21:55:42','2026-01-03 21:55:42',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(806,NULL,NULL,'description',NULL,'Starting equipment: war horse, lance, long sword, heavy armor, medium shield (Knight)','2026-01-03 21:55:42','2026-01-03 21:55:42',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(807,NULL,NULL,'description',NULL,'Mount''s Smarts one die type higher than normal, mount is Resilient (Mount)','2026-01-03 21:55:43','2026-01-03 21:55:43',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(808,NULL,NULL,'description',NULL,'Mount gains one Advance per character Rank (Mount)','2026-01-03 21:55:43','2026-01-03 21:55:43',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(809,NULL,NULL,'description',NULL,'At Heroic: upgrade to Great Mount (Very Resilient, larger creatures) (Mount)','2026-01-03 21:55:43','2026-01-03 21:55:43',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(810,NULL,NULL,'description',NULL,'At Legendary: upgrade to Legendary Mount (dragons, rocs, etc.) (Mount)','2026-01-03 21:55:43','2026-01-03 21:55:43',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(811,NULL,NULL,'description',NULL,'Create poisons in half the time (Poisoner)','2026-01-03 21:55:44','2026-01-03 21:55:44',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(812,NULL,NULL,'description',NULL,'Contact poisons last 12 hours instead of 4 (Poisoner)','2026-01-03 21:55:44','2026-01-03 21:55:44',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(813,NULL,NULL,'description',NULL,'Notice roll at -2 to detect hazards/creatures/events on travel face card (Scout)','2026-01-03 21:55:45','2026-01-03 21:55:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(814,NULL,NULL,'description',NULL,'Always considered alert for Notice rolls versus Stealth (Scout)','2026-01-03 21:55:45','2026-01-03 21:55:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(815,NULL,NULL,'description',NULL,'+2 Common Knowledge for routes/landmarks previously traveled (Scout)','2026-01-03 21:55:45','2026-01-03 21:55:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(816,NULL,NULL,'description',NULL,'Free Notice roll at +2 to detect traps/hidden doors in stonework within 10 feet (Stonecunning)','2026-01-03 21:55:45','2026-01-03 21:55:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(817,NULL,NULL,'description',NULL,'Success detects alteration, raise gives insight into general purpose (Stonecunning)','2026-01-03 21:55:45','2026-01-03 21:55:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(818,NULL,NULL,'description',NULL,'Notice roll when detectable trap about to spring within 5" with line of sight (Trap Sense)','2026-01-03 21:55:46','2026-01-03 21:55:46',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(819,NULL,NULL,'description',NULL,'Ignore 2 points of Evasion penalties for traps (Trap Sense)','2026-01-03 21:55:46','2026-01-03 21:55:46',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(820,NULL,NULL,'description',NULL,'Ignore 2 points of Thievery penalties when disarming traps (Trap Sense)','2026-01-03 21:55:46','2026-01-03 21:55:46',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(821,NULL,NULL,'description',NULL,'Smarts roll to gauge value of goods or treasures (Treasure Hunter)','2026-01-03 21:55:47','2026-01-03 21:55:47',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(822,NULL,NULL,'description',NULL,'Smarts roll to get basic idea of magic item abilities (Treasure Hunter)','2026-01-03 21:55:47','2026-01-03 21:55:47',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(823,NULL,NULL,'description',NULL,'Spend Benny to have GM reroll magic item table (Treasure Hunter)','2026-01-03 21:55:47','2026-01-03 21:55:47',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(824,NULL,NULL,'description',NULL,'+2 to most Common Knowledge rolls (Troubadour)','2026-01-03 21:55:48','2026-01-03 21:55:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(825,NULL,NULL,'description',NULL,'Use Performance in place of Battle for Leadership Edges (Troubadour)','2026-01-03 21:55:48','2026-01-03 21:55:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(826,NULL,NULL,'description',NULL,'On Tests with Smarts/Spirit-linked skills, choose whether target resists with Smarts or Spirit (Deceptive)','2026-01-03 21:57:00','2026-01-03 21:57:00',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(827,NULL,NULL,'description',NULL,'Allies within 10" (20 yards) add +1 to Fear checks (Aura of Courage)','2026-01-03 21:58:04','2026-01-03 21:58:04',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(828,NULL,NULL,'description',NULL,'Allies within 10" (20 yards) subtract 1 from Fear Table results (Aura of Courage)','2026-01-03 21:58:04','2026-01-03 21:58:04',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(829,NULL,NULL,'description',NULL,'Speak to and understand a general class of animals (Beast Talker)','2026-01-03 21:58:08','2026-01-03 21:58:08',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(830,NULL,NULL,'description',NULL,'Can attempt to persuade animals to help or provide information (Beast Talker)','2026-01-03 21:58:08','2026-01-03 21:58:08',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(831,NULL,NULL,'description',NULL,'Change lycanthrope form as a limited action instead of 10 minutes (Rapid Change)','2026-01-03 21:58:11','2026-01-03 21:58:11',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(832,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:23:41','2026-01-04 19:23:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(833,NULL,NULL,'description',NULL,'Greater Arcane Protection (+2): Hostile powers suffer a -4 penalty (-6 with a raise).','2026-01-04 19:23:41','2026-01-04 19:23:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(834,NULL,NULL,'description',NULL,'Area of Effect (+0/+1): The caster can make the blast a Small Blast Template for no extra cost, or a Large Blast Template for +1.','2026-01-04 19:23:48','2026-01-04 19:23:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(835,NULL,NULL,'description',NULL,'Damage (+2): The blast causes 3d6 damage (4d6 with a raise).','2026-01-04 19:23:48','2026-01-04 19:23:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(836,NULL,NULL,'description',NULL,'Greater Blast (+4): The blast causes 4d6 damage (5d6 with a raise) and is considered a Heavy Weapon.','2026-01-04 19:23:48','2026-01-04 19:23:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(837,NULL,NULL,'description',NULL,'Damage (+2): The bolt causes 3d6 damage (4d6 with a raise).','2026-01-04 19:23:59','2026-01-04 19:23:59',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(838,NULL,NULL,'description',NULL,'Disintegrate (+2): If the bolt Incapacitates a creature, it must make a Vigor roll or its body turns to dust. If the target is an object, the caster rolls damage against its Hardness as usual. If the bolt breaks the object, it disintegrates. Disintegrate allows the caster to roll bonus damage against objects and all damage dice can Ace. Enchanted Items: The Hardness of enchanted items is doubled for the purposes of being disintegrated.','2026-01-04 19:23:59','2026-01-04 19:23:59',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(839,NULL,NULL,'description',NULL,'Greater Bolt (+4): The bolt causes 4d6 damage (5d6 with a raise) and is considered a Heavy Weapon.','2026-01-04 19:23:59','2026-01-04 19:23:59',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(840,NULL,NULL,'description',NULL,'Rate of Fire (+2): The bolt attack is made at Rate of Fire 2 (at no Recoil penalty).','2026-01-04 19:23:59','2026-01-04 19:23:59',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(841,NULL,NULL,'description',NULL,'Damage (+2): The burst causes 3d6 damage, or 4d6 with a raise.','2026-01-04 19:24:08','2026-01-04 19:24:08',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(842,NULL,NULL,'description',NULL,'Greater Burst (+4): The burst causes 4d6 damage (5d6 with a raise) and is considered a Heavy Weapon.','2026-01-04 19:24:08','2026-01-04 19:24:08',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(843,NULL,NULL,'description',NULL,'Push (+1): The burst pushes everyone within the template back 2d6". Large or greater creatures are pushed back d4" instead.','2026-01-04 19:24:08','2026-01-04 19:24:08',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(844,NULL,NULL,'description',NULL,'Area of Effect (+2): The caster may extend the damage field into a Medium Blast Template centered on the recipient, the caster is immune to her own damage field.','2026-01-04 19:24:22','2026-01-04 19:24:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(845,NULL,NULL,'description',NULL,'Damage (+2): The damage field causes 2d6 damage.','2026-01-04 19:24:22','2026-01-04 19:24:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(846,NULL,NULL,'description',NULL,'Greater Damage Field (+4): The damage field causes 3d6 damage and is considered a Heavy Weapon.','2026-01-04 19:24:22','2026-01-04 19:24:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(847,NULL,NULL,'description',NULL,'Mobile (+2): Mobile works only with the Area of Effect modifier. It allows the caster to detach the damage field from the recipient and move it up to his Smarts die type each round as a limited free action.','2026-01-04 19:24:22','2026-01-04 19:24:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(848,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:24:32','2026-01-04 19:24:32',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(849,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect additional targets at a cost of 1 Power Point each.','2026-01-04 19:24:50','2026-01-04 19:24:50',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(850,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:24:50','2026-01-04 19:24:50',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(851,NULL,NULL,'description',NULL,'Greater Smite (+2): The weapon''s damage is increased by +4, or +6 damage with a raise and is considered a Heavy Weapon.','2026-01-04 19:24:50','2026-01-04 19:24:50',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(852,NULL,NULL,'description',NULL,'Area of Effect (+2/+3): For +2 points the power affects everyone in a Medium Blast Template. For +3 points the area of effect is increased to a Large Blast Template.','2026-01-04 19:24:50','2026-01-04 19:24:50',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(853,NULL,NULL,'description',NULL,'Area of Effect (+1/+2/+3): The power affects everything in a Small, Medium, or Large Blast Template.','2026-01-04 19:25:30','2026-01-04 19:25:30',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(854,NULL,NULL,'description',NULL,'Damage (+0/+1): The barrier causes 2d4 damage to anyone who contacts it. If the barrier is Immaterial, this modifier costs +0 instead of +1.','2026-01-04 19:25:30','2026-01-04 19:25:30',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(855,NULL,NULL,'description',NULL,'Deadly (+1/+2): The barrier causes 2d6 damage. If the barrier is Immaterial, this modifier costs +1 instead of +2.','2026-01-04 19:25:30','2026-01-04 19:25:30',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(856,NULL,NULL,'description',NULL,'Hardened (+1): The wall is Hardness 12 (or 14 with a raise).','2026-01-04 19:25:30','2026-01-04 19:25:30',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(857,NULL,NULL,'description',NULL,'Immaterial (+0): Anything can pass through the barrier, its Hardness is 0, and it cannot be destroyed (but still provides concealment).','2026-01-04 19:25:30','2026-01-04 19:25:30',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(858,NULL,NULL,'description',NULL,'Shaped (+1): The barrier forms a circle, square, or rectangle. It can''t have a roof or floor, and can''t Bind or Entangle anything within (use the entangle power for that).','2026-01-04 19:25:30','2026-01-04 19:25:30',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(859,NULL,NULL,'description',NULL,'Size (+1): The length and height of the barrier doubles.','2026-01-04 19:25:30','2026-01-04 19:25:30',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(860,NULL,NULL,'description',NULL,'Area of Effect (+2/+3): For +2 points the power affects everyone in a Medium Blast Template. For +3 points the area of effect is increased to a Large Blast Template.','2026-01-04 19:25:53','2026-01-04 19:25:53',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(861,NULL,NULL,'description',NULL,'Strong (+1): The Vigor roll to shake off the effect is made at -2.','2026-01-04 19:25:53','2026-01-04 19:25:53',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(862,NULL,NULL,'description',NULL,'Area of Effect (+0/+1): The caster can focus the confusion to a Small Blast Template for no extra cost, or a Large Blast Template for +1.','2026-01-04 19:25:53','2026-01-04 19:25:53',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(863,NULL,NULL,'description',NULL,'Greater Confusion (+2): On a success, targets are Shaken in addition to being Distracted or Vulnerable (or both with a raise). Confusion is a non-damaging effect and cannot cause a Wound on an already Shaken target.','2026-01-04 19:25:53','2026-01-04 19:25:53',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(864,NULL,NULL,'description',NULL,'Turn to Stone (+5): The accursed must make a Spirit roll every round or take a level of Fatigue. When Incapacitated, the victim turns to stone (his Toughness becomes Hardness). The curse must be broken to stop the effect.','2026-01-04 19:25:53','2026-01-04 19:25:53',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(865,NULL,NULL,'description',NULL,'Anti-Magic Field (+8): Instead of affecting a power at Range, this modifier creates a Medium Blast Template centered on the recipient with a Duration of 5. Magic items, effects, and powers have no effect within the field. Summoned creatures in the field must make a Spirit roll each round or take a Wound. Magically-created objects are unaffected. Anti-Magic Field has no effect on deities or artifacts, and can''t be combined with any other modifiers.','2026-01-04 19:26:12','2026-01-04 19:26:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(866,NULL,NULL,'description',NULL,'Area of Effect (+1/+2/+3): The effect covers an area: +1 Power Point uses a Small Blast Template, +2 PPs uses Medium, or +3 uses Large. The caster makes one arcane skill roll and chooses which power on each target is affected. The caster of the affected power opposes with arcane skill. This affects all targets in the area unless the Selective modifier is used.','2026-01-04 19:26:12','2026-01-04 19:26:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(867,NULL,NULL,'description',NULL,'Disenchant (+1): The caster can target a magical item rather than an individual or a power. This is a standard arcane skill roll, not an opposed roll (the GM may apply a penalty to disenchant powerful items or artifacts). If successful, all the item''s magical abilities are negated for one round, or two with a raise.','2026-01-04 19:26:12','2026-01-04 19:26:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(868,NULL,NULL,'description',NULL,'Multiple Powers (+3): If successful, the caster dispels all the target''s currently activated powers.','2026-01-04 19:26:12','2026-01-04 19:26:12',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(869,NULL,NULL,'description',NULL,'Power (+2): The caster drains 2d6 Power Points with success.','2026-01-04 19:26:31','2026-01-04 19:26:31',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(870,NULL,NULL,'description',NULL,'Area of Effect (+2/+3): For +2 points the power affects everyone in a Medium Blast Template. For +3 points the area of effect is increased to a Large Blast Template or use the Stream Template.','2026-01-04 19:26:31','2026-01-04 19:26:31',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(871,NULL,NULL,'description',NULL,'Damage (+2): Entangled targets take 2d4 damage at the end of their turn.','2026-01-04 19:26:31','2026-01-04 19:26:31',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(872,NULL,NULL,'description',NULL,'Deadly (+4): Entangled targets take 2d6 damage at the end of their turn. Does not stack with Damage.','2026-01-04 19:26:31','2026-01-04 19:26:31',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(873,NULL,NULL,'description',NULL,'Tough (+1): The entangling material is particularly resilient. Increase its Hardness to 10.','2026-01-04 19:26:31','2026-01-04 19:26:31',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(874,NULL,NULL,'description',NULL,'Area of Effect (+2/+3): For +2 points the power affects everyone in a Small or Medium Blast Template (caster''s choice). For +3 points the area of effect is increased to a Large Blast Template.','2026-01-04 19:26:48','2026-01-04 19:26:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(875,NULL,NULL,'description',NULL,'Greater Fear (+2): Fear rolls are made at -2, or -4 if cast with a raise.','2026-01-04 19:26:48','2026-01-04 19:26:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(876,NULL,NULL,'description',NULL,'Area Effect (+1): Havoc affects a Large Blast Template.','2026-01-04 19:26:48','2026-01-04 19:26:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(877,NULL,NULL,'description',NULL,'Greater Havoc (+2): Targets are knocked back a total of 3d6" and take 2d6 damage if they strike a hard object.','2026-01-04 19:26:48','2026-01-04 19:26:48',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(878,NULL,NULL,'description',NULL,'Additional Recipients (+2): The caster may affect others at the cost of 2 Power Points each.','2026-01-04 19:27:09','2026-01-04 19:27:09',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(879,NULL,NULL,'description',NULL,'Strong (+2): Spirit rolls to resist commands are made at -2. If the caster also uses mind link on the target, she may extend her control of him to any distance, even out of her sight.','2026-01-04 19:27:09','2026-01-04 19:27:09',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(880,NULL,NULL,'description',NULL,'Area of Effect (+2/+3): For +2 points the power affects everyone in a Medium Blast Template. For +3 points the area of effect is increased to a Large Blast Template.','2026-01-04 19:27:09','2026-01-04 19:27:09',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(881,NULL,NULL,'description',NULL,'Additional Recipients (+2): The power may affect more than one target for 2 additional Power Points each.','2026-01-04 19:27:41','2026-01-04 19:27:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(882,NULL,NULL,'description',NULL,'Greater Boost/Lower Trait (+2): With success, the selected Trait gains a free reroll once per round, or once per action with a raise. For lower Trait the target applies a -2 penalty to the affected Trait''s rolls.','2026-01-04 19:27:41','2026-01-04 19:27:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(883,NULL,NULL,'description',NULL,'Strong (+1): Lower Trait only. The Spirit roll to shake off the effect is made at -2.','2026-01-04 19:27:41','2026-01-04 19:27:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(884,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:27:41','2026-01-04 19:27:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(885,NULL,NULL,'description',NULL,'Greater Darksight (+2): The recipient''s senses extend so far into the infrared and ultraviolet spectrums that he ignores all Illumination penalties and 4 points of penalties from invisible creatures.','2026-01-04 19:27:41','2026-01-04 19:27:41',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(886,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:27:58','2026-01-04 19:27:58',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(887,NULL,NULL,'description',NULL,'Greater Farsight (+2): Allows the recipient to ignore all Range penalties, and a raise doubles sight range.','2026-01-04 19:27:58','2026-01-04 19:27:58',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(888,NULL,NULL,'description',NULL,'Additional Recipients (+2): The power may affect more than one target for 2 additional Power Points each.','2026-01-04 19:27:58','2026-01-04 19:27:58',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(889,NULL,NULL,'description',NULL,'Swift Flight (+5): The base flying Pace increases to 24, or 48 with a raise.','2026-01-04 19:27:58','2026-01-04 19:27:58',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(890,NULL,NULL,'description',NULL,'Duration (+2): The power''s Duration is measured in minutes instead of rounds.','2026-01-04 19:27:58','2026-01-04 19:27:58',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(891,NULL,NULL,'description',NULL,'Power (+2): The target of shrink may retain her normal Strength and Toughness while small.','2026-01-04 19:27:58','2026-01-04 19:27:58',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(892,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:28:20','2026-01-04 19:28:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(893,NULL,NULL,'description',NULL,'Restoration (+3): If using the Recover option, the caster can restore one die type from an energy draining effect with a success, two with a raise.','2026-01-04 19:28:20','2026-01-04 19:28:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(894,NULL,NULL,'description',NULL,'Stunned (+1): If using the Recover option, the caster may also affect the Stunned condition. This completely removes the effects of being Stunned.','2026-01-04 19:28:20','2026-01-04 19:28:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(895,NULL,NULL,'description',NULL,'Area Effect (+2/+3): For +2 points the power affects all allies within a Medium Blast Template centered on the recipient. For +3 points the area of effect is increased to a Large Blast Template.','2026-01-04 19:28:20','2026-01-04 19:28:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(896,NULL,NULL,'description',NULL,'Strong (+1): Spirit rolls by the creature are made at -2 (-4 with a raise).','2026-01-04 19:28:20','2026-01-04 19:28:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(897,NULL,NULL,'description',NULL,'Additional Recipients (+1): Speed only. The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:28:45','2026-01-04 19:28:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(898,NULL,NULL,'description',NULL,'Area of Effect (+2/+3): Sloth only. For +2 points the power affects everyone in a Medium Blast Template. For +3 points the area of effect is increased to a Large Blast Template.','2026-01-04 19:28:45','2026-01-04 19:28:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(899,NULL,NULL,'description',NULL,'Dash (+2): Speed only. The recipient''s running die isn''t rolled-she gains the maximum result possible.','2026-01-04 19:28:45','2026-01-04 19:28:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(900,NULL,NULL,'description',NULL,'Quickness (+2): Speed only. The character''s total Multi-Action penalty each turn is reduced by 2 (she can do two actions at no penalty or three at -2 each).','2026-01-04 19:28:45','2026-01-04 19:28:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(901,NULL,NULL,'description',NULL,'Strong (+1): Sloth only. The Spirit roll to shake off sloth''s effects is made at -2.','2026-01-04 19:28:45','2026-01-04 19:28:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(902,NULL,NULL,'description',NULL,'Bellow (+1): Sound only. The character''s voice booms out, carrying over one mile distant-a useful way of warning populations of impending danger. Doing so also adds +2 to one Intimidation Test as long any subjects are within 12" (24 yards).','2026-01-04 19:28:45','2026-01-04 19:28:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(903,NULL,NULL,'description',NULL,'Greater Sound/Silence (+1): The Smarts roll made to resist a Test (or Targeted modifier) is made at -2.','2026-01-04 19:28:45','2026-01-04 19:28:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(904,NULL,NULL,'description',NULL,'Mobile (+1): The caster can move the area of effect up to his arcane skill die type each round.','2026-01-04 19:28:45','2026-01-04 19:28:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(905,NULL,NULL,'description',NULL,'Targeted (+1/Target): Instead of casting silence in an area of effect, the caster may instead target individuals in Range for 1 Power Point each. Unwilling targets resist with Spirit (at -2 if the caster gets a raise).','2026-01-04 19:28:45','2026-01-04 19:28:45',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(906,NULL,NULL,'description',NULL,'Additional Recipients (+1): The caster may affect others at the cost of 1 Power Point each.','2026-01-04 19:28:57','2026-01-04 19:28:57',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(907,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:28:57','2026-01-04 19:28:57',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(908,NULL,NULL,'description',NULL,'Power (+4): The recipient gains two Combat Edges at once.','2026-01-04 19:28:57','2026-01-04 19:28:57',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(909,NULL,NULL,'description',NULL,'Greater Healing (+10): Greater healing can restore any Wound, including those more than an hour old.','2026-01-04 19:29:32','2026-01-04 19:29:32',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(910,NULL,NULL,'description',NULL,'Crippling Injuries (+20): The power can heal a permanent Crippling Injury. This requires an hour of preparation and only one casting is permitted per injury. If it fails, this caster cannot heal that particular injury (but someone else may try). If successful, the subject is Exhausted for 24 hours.','2026-01-04 19:29:32','2026-01-04 19:29:32',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(911,NULL,NULL,'description',NULL,'Mass Healing (+2/+3): For +2 Power Points, healing affects all allies within a Medium Blast Template centered on the caster (or a Large Blast Template for +3).','2026-01-04 19:29:32','2026-01-04 19:29:32',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(912,NULL,NULL,'description',NULL,'Neutralize Poison or Disease (+1): A successful healing roll negates any poison or disease. If the poison or disease has a bonus or penalty associated with it, the modifier applies to the arcane skill roll as well.','2026-01-04 19:29:32','2026-01-04 19:29:32',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(913,NULL,NULL,'description',NULL,'Greater Resurrection (+10): The boundaries of life and death no longer matter. A caster can restore the dead to life regardless of how long the target was dead and even without a corpse. To resurrect the dead in this fashion requires a twelve-hour ritual and leaves the caster Exhausted.','2026-01-04 19:29:54','2026-01-04 19:29:54',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(914,NULL,NULL,'description',NULL,'Power (+5): The hero can raise a decade-old spirit for +5 Power Points.','2026-01-04 19:29:54','2026-01-04 19:29:54',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(915,NULL,NULL,'description',NULL,'Bestarium (+2): The caster may affect magical beasts with animal intelligence.','2026-01-04 19:30:28','2026-01-04 19:30:28',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(916,NULL,NULL,'description',NULL,'Duration (+1): The base Duration increases to 30 minutes.','2026-01-04 19:30:28','2026-01-04 19:30:28',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(917,NULL,NULL,'description',NULL,'Mind Rider (+1): The caster can communicate and sense through any of the beasts he currently has befriended.','2026-01-04 19:30:28','2026-01-04 19:30:28',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(918,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:30:28','2026-01-04 19:30:28',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(919,NULL,NULL,'description',NULL,'Charm (+2): If the target is Uncooperative or Neutral, a successful casting makes him Cooperative, or Friendly with a raise. The spell is instantly broken if the caster or her allies attack the victim or her allies.','2026-01-04 19:30:28','2026-01-04 19:30:28',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(920,NULL,NULL,'description',NULL,'Duration (+1): Duration is measured in minutes instead of rounds. If the character Critically Fails any of the affected skill rolls against the target, the effect terminates immediately.','2026-01-04 19:30:28','2026-01-04 19:30:28',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(921,NULL,NULL,'description',NULL,'Truth (+2): The caster knows if the target believes he''s telling the truth or not.','2026-01-04 19:30:28','2026-01-04 19:30:28',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(922,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect additional individuals for 1 additional Power Point each.','2026-01-04 19:30:55','2026-01-04 19:30:55',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(923,NULL,NULL,'description',NULL,'Broadcast (+2): The caster can broadcast a short telepathic message to every sentient mind within Smarts x4 radius (Smarts x8 with a raise). The caster can only broadcast, she doesn''t receive their responses.','2026-01-04 19:30:55','2026-01-04 19:30:55',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(924,NULL,NULL,'description',NULL,'Long Distance Link (+3): Casters can connect with any mind they''re familiar with on the same plane of existence, regardless of distance.','2026-01-04 19:30:55','2026-01-04 19:30:55',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(925,NULL,NULL,'description',NULL,'Mind Walk (+2): The caster has greater access to the target''s mind and memories, unlocking an entire scene or chain of related memories (GM''s call).','2026-01-04 19:30:55','2026-01-04 19:30:55',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(926,NULL,NULL,'description',NULL,'Edit (+1): Instead of wiping the memory, the caster edits it as she sees fit.','2026-01-04 19:30:55','2026-01-04 19:30:55',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(927,NULL,NULL,'description',NULL,'Fast Cast (+2): The caster can wipe a memory as an action.','2026-01-04 19:30:55','2026-01-04 19:30:55',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(928,NULL,NULL,'description',NULL,'Greater Mind Wipe (+2): The effect completely removes a single event, person, place, thing, etc., from the victim''s memory.','2026-01-04 19:30:55','2026-01-04 19:30:55',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(929,NULL,NULL,'description',NULL,'Projection (+2): The caster may broadcast the vision, projecting it out into a space the size of a small room. Anyone present may see and hear the events and examine the projection from all angles.','2026-01-04 19:31:18','2026-01-04 19:31:18',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(930,NULL,NULL,'description',NULL,'Group Sight (+1): The caster may share her perception with allies within a Range of Smarts for the power''s Duration.','2026-01-04 19:31:18','2026-01-04 19:31:18',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(931,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:31:18','2026-01-04 19:31:18',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(932,NULL,NULL,'description',NULL,'Greater Speak Language (+2): The recipient can speak, read, and write all sapient languages at once.','2026-01-04 19:31:18','2026-01-04 19:31:18',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(933,NULL,NULL,'description',NULL,'Mass Understanding (+5): The recipient causes characters within Smarts x2 of him to understand one another, regardless of language.','2026-01-04 19:31:18','2026-01-04 19:31:18',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(934,NULL,NULL,'description',NULL,'Additional Allies (Varies): Additional allies of the same type may be summoned at the same time for half the Power Points cost of the original summons (total cost including modifiers, rounded up).','2026-01-04 19:31:59','2026-01-04 19:31:59',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(935,NULL,NULL,'description',NULL,'Combat Edge (+1): The ally has a Combat Edge (it must meet the Requirements as usual). You may take this modifier up to three times per ally.','2026-01-04 19:31:59','2026-01-04 19:31:59',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(936,NULL,NULL,'description',NULL,'Flight (+1): The ally can fly at its base Pace.','2026-01-04 19:31:59','2026-01-04 19:31:59',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(937,NULL,NULL,'description',NULL,'Increased Trait (+1): The caster can increase a Trait one die type for one Power Point. She may do this for as many Traits as she likes, but only once per Trait.','2026-01-04 19:31:59','2026-01-04 19:31:59',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(938,NULL,NULL,'description',NULL,'Mind Rider (+1): The caster can communicate and sense through any of the allies summoned in this casting.','2026-01-04 19:31:59','2026-01-04 19:31:59',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(939,NULL,NULL,'description',NULL,'Additional Animals (Varies): Additional allies of the same type may be summoned at the same time for half the Power Points cost of the original summons (total cost including modifiers, rounded up).','2026-01-04 19:32:16','2026-01-04 19:32:16',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(940,NULL,NULL,'description',NULL,'Increased Trait (+1): The caster can increase a Trait one die type for one Power Point. She may do this for as many Traits as she likes, but only once per Trait.','2026-01-04 19:32:16','2026-01-04 19:32:16',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(941,NULL,NULL,'description',NULL,'Mind Rider (+1): The caster can communicate and sense through any of the animals summoned in this casting.','2026-01-04 19:32:16','2026-01-04 19:32:16',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(942,NULL,NULL,'description',NULL,'Additional Monsters (Varies): Additional allies of the same type may be summoned at the same time for half the Power Points cost of the original summons (total cost including modifiers, rounded up).','2026-01-04 19:32:16','2026-01-04 19:32:16',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(943,NULL,NULL,'description',NULL,'Increased Trait (+1): The caster can increase the monster''s Trait one die type for one Power Point. She may do this for as many Traits as she likes, but only once per Trait.','2026-01-04 19:32:16','2026-01-04 19:32:16',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(944,NULL,NULL,'description',NULL,'Mind Rider (+1): The caster can communicate and sense through any of the monsters conjured in this casting.','2026-01-04 19:32:16','2026-01-04 19:32:16',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(945,NULL,NULL,'description',NULL,'Additional Undead (Varies): Additional undead of the same type may be summoned at the same time for half the Power Points cost of the original summons (total cost including modifiers, rounded up).','2026-01-04 19:32:43','2026-01-04 19:32:43',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(946,NULL,NULL,'description',NULL,'Increased Trait (+1): The caster can increase the monster''s Trait one die type for one Power Point. She may do this for as many Traits as she likes, but only once per Trait.','2026-01-04 19:32:43','2026-01-04 19:32:43',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(947,NULL,NULL,'description',NULL,'Mind Rider (+1): The caster can communicate and sense through the undead.','2026-01-04 19:32:43','2026-01-04 19:32:43',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(948,NULL,NULL,'description',NULL,'Additional Zombies (+1): A larger horde can be raised in a single casting by paying an additional 1 Power Point for each extra zombie raised.','2026-01-04 19:32:43','2026-01-04 19:32:43',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(949,NULL,NULL,'description',NULL,'Armed & Armored (+1 per Zombie): The dead rise with +2 armor and weapons common to their background or environment. Choose one: Hand Weapon (Str+d6) or Ranged Weapon (Range 12/24/48, Damage 2d6).','2026-01-04 19:32:43','2026-01-04 19:32:43',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(950,NULL,NULL,'description',NULL,'Mind Rider (+1): The caster can communicate and sense through one of the undead he''s conjured in this casting.','2026-01-04 19:32:43','2026-01-04 19:32:43',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(951,NULL,NULL,'description',NULL,'Permanent (0): The zombie is given unlife until Incapacitated by Wounds. The Power Points used to raise it are "invested" and unavailable until it''s destroyed. The necromancer may terminate his creations'' unlife at any time, regardless of sight, distance, or other factors. His Power Points then begin recharging normally. Permanent zombies remain animated even if their creator is slain!','2026-01-04 19:32:43','2026-01-04 19:32:43',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(952,NULL,NULL,'description',NULL,'Skeletal (+1 per Zombie): The corpse sloughs off the anchor of dead flesh and rises as a skeleton. Use the skeleton profile instead of the zombie.','2026-01-04 19:32:43','2026-01-04 19:32:43',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(953,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:33:25','2026-01-04 19:33:25',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(954,NULL,NULL,'description',NULL,'Power (+1): The caster can burrow through stone, concrete, or similar substances. (Some substances, like glass, solid lead, etc., might be resistant at the GM''s discretion.)','2026-01-04 19:33:25','2026-01-04 19:33:25',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(955,NULL,NULL,'description',NULL,'Complete (+1): The object is a complete set, such as a chess set or a quiver of arrows. The set must fit under the weight limit.','2026-01-04 19:33:25','2026-01-04 19:33:25',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(956,NULL,NULL,'description',NULL,'Create Food and Water (Special): Instead of an object, every 1 Power Point creates enough food and water to sustain a Size 0 humanoid for one day. The nourishing food is permanent (doesn''t disappear after you eat it), though it decays and is inedible after 24 hours if not consumed.','2026-01-04 19:33:25','2026-01-04 19:33:25',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(957,NULL,NULL,'description',NULL,'Enduring (+1 per pound): The object''s Duration becomes "permanent" until dispelled. The Power Points are "invested" and unavailable until it''s destroyed, dispelled, or dismissed by the caster.','2026-01-04 19:33:25','2026-01-04 19:33:25',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(958,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one viewer for detect, or item for conceal, for 1 additional Power Point each.','2026-01-04 19:33:49','2026-01-04 19:33:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(959,NULL,NULL,'description',NULL,'Alignment Sense (+1): The caster can sense the presence and exact location of supernatural good or evil within range. Detect arcana activated with Alignment Sense no longer requires line of sight to locate creatures.','2026-01-04 19:33:49','2026-01-04 19:33:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(960,NULL,NULL,'description',NULL,'Area of Effect (+1/+2): Conceal only. The power affects everything in a sphere the size of a Medium Blast Template for +1 points, or a Large Blast Template for +2.','2026-01-04 19:33:49','2026-01-04 19:33:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(961,NULL,NULL,'description',NULL,'Identify (+1): The caster can identify a magic item''s powers and how they work. Success reveals its powers and how they work, but only a raise reveals any cursed powers.','2026-01-04 19:33:49','2026-01-04 19:33:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(962,NULL,NULL,'description',NULL,'Strong (+1): Conceal only. Detection rolls to see through the concealment are made at -2. This stacks with the additional penalty for casting with a raise.','2026-01-04 19:33:49','2026-01-04 19:33:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(963,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:33:49','2026-01-04 19:33:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(964,NULL,NULL,'description',NULL,'Size (+1): The recipient may assume the shape of someone up to two Sizes larger or smaller than themselves.','2026-01-04 19:33:49','2026-01-04 19:33:49',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(965,NULL,NULL,'description',NULL,'Power (+3): This modifier works only on ground sacred to the entity the caster is attempting to contact. The being may even manifest or appear in some form with a raise. The entity not only answers the petitioner''s questions but also offers unsolicited advice.','2026-01-04 19:34:15','2026-01-04 19:34:15',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(966,NULL,NULL,'description',NULL,'Power (+3): The volume of air, earth, fire, or water affected is tripled, the target of Push suffers a -2 penalty to resist, and the damage of Attack is increased to 2d6 (3d6 on a raise).','2026-01-04 19:34:15','2026-01-04 19:34:15',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(967,NULL,NULL,'description',NULL,'Weather (+5): The caster can summon or dispel storms, bring a cooling rain, scorch the earth with a heatwave, or even bring snow to the hottest climes. The caster can bring or disperse rain, snow, sun, and wind in about a five-mile radius. The effects take 10 minutes to create and last an hour.','2026-01-04 19:34:15','2026-01-04 19:34:15',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(968,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:34:15','2026-01-04 19:34:15',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(969,NULL,NULL,'description',NULL,'Environmental Resistance (+1): The power now offers environmental resistance, reducing damage from one energy source (fire, cold, electricity, etc.) by 4 (6 with a raise).','2026-01-04 19:34:15','2026-01-04 19:34:15',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(970,NULL,NULL,'description',NULL,'Area of Effect (+1): The illusion''s area of effect is increased to the size of a Large Blast Template.','2026-01-04 19:34:40','2026-01-04 19:34:40',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(971,NULL,NULL,'description',NULL,'Deadly Illusion (+3): The illusion may attack or otherwise cause harm. The caster may use her actions to make the illusion "attack" an individual. This is an opposed roll of the caster''s arcane skill versus the target''s Smarts (at -2 if the power was activated with a raise). If the caster wins, the target is Shaken (this cannot cause Incapacitation). With a raise, the target suffers a Wound (this can cause Incapacitation).','2026-01-04 19:34:40','2026-01-04 19:34:40',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(972,NULL,NULL,'description',NULL,'Duration (+2): The Duration is measured in minutes.','2026-01-04 19:34:40','2026-01-04 19:34:40',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(973,NULL,NULL,'description',NULL,'Mobility (+1/+2): For +1 Power Point, the illusion can move (and fly) at a Pace of 12, or 24 for +2 Power Points. This is a limited free action.','2026-01-04 19:34:40','2026-01-04 19:34:40',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(974,NULL,NULL,'description',NULL,'Sound (+1): The power generates sound appropriate to the illusion. Illusionary allies may talk, illusionary crows caw, and so on. It may not mask or mute existing sound, however.','2026-01-04 19:34:40','2026-01-04 19:34:40',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(975,NULL,NULL,'description',NULL,'Strong (+2): Smarts rolls to disbelieve the illusion are made at -2.','2026-01-04 19:34:40','2026-01-04 19:34:40',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(976,NULL,NULL,'description',NULL,'Additional Recipients (+3): The power may affect more than one target for 3 additional Power Points each.','2026-01-04 19:34:40','2026-01-04 19:34:40',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(977,NULL,NULL,'description',NULL,'Duration (+3): Duration is measured in minutes instead of rounds.','2026-01-04 19:34:40','2026-01-04 19:34:40',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(978,NULL,NULL,'description',NULL,'Additional Recipients (+3): The power may affect more than one target for 3 additional Power Points each.','2026-01-04 19:35:08','2026-01-04 19:35:08',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(979,NULL,NULL,'description',NULL,'Duration (+2): Duration is measured in minutes instead of rounds. If the character attempts a damage-causing attack or targets an unwilling character with a power, the effect terminates immediately.','2026-01-04 19:35:08','2026-01-04 19:35:08',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(980,NULL,NULL,'description',NULL,'Area Effect (+2): Light only. The light expands to the caster''s Smarts in radius, with a Range of Smarts x2. The light is sunlight and acts as natural sunlight.','2026-01-04 19:35:08','2026-01-04 19:35:08',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(981,NULL,NULL,'description',NULL,'Illuminate (+1): Light only. The light forms around a target of Size 1 or less, illuminating it in a dull glow that eliminates 2 points of penalties from invisibility, darkness, or similar conditions. With a raise, the glow eliminates up to 4 points of penalties.','2026-01-04 19:35:08','2026-01-04 19:35:08',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(982,NULL,NULL,'description',NULL,'True Darkness (+2): Darkness only. The darkness blocks Infravision, Low Light Vision, and Darkvision. It has no effect on magical sight such as detect arcana.','2026-01-04 19:35:08','2026-01-04 19:35:08',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(983,NULL,NULL,'description',NULL,'Mobile (+1): The caster can move the template at a Pace equal to his arcane skill die type as a limited free action each round. He can also attach it to an inanimate object when first cast if desired. It can''t be cast on a living being.','2026-01-04 19:35:08','2026-01-04 19:35:08',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(984,NULL,NULL,'description',NULL,'Find the Path (+1): In addition to the direction and distance to a target, the caster also has a supernatural sense of the best or fastest way to navigate to its general area.','2026-01-04 19:35:08','2026-01-04 19:35:08',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(985,NULL,NULL,'description',NULL,'Alert (+1): Lock only. If the magically locked object is opened, the caster instantly knows this happened (and which object was unlocked if multiple instances of this power are in effect). A being who speaks the password (determined at the time of casting) doesn''t set off the alert. This warning lasts 1 day.','2026-01-04 19:35:34','2026-01-04 19:35:34',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(986,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:35:34','2026-01-04 19:35:34',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(987,NULL,NULL,'description',NULL,'Extra-Dimensional Space (+1): Instead of traveling to a known plane, the recipient travels to a pocket dimension where he''s conjured a magical shelter or similar structure with basic necessities, food, and water for up to five people. The structure remains afterward, so the mage and his companions may return to this space or to a new one, as desired.','2026-01-04 19:35:34','2026-01-04 19:35:34',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(988,NULL,NULL,'description',NULL,'Transport Foe (+2): The caster sends an unwilling target to another plane or extra-dimensional space. The being resists the casting with a Spirit roll. If failed, he''s transported to another plane of the caster''s choice for three rounds (five rounds with a raise) before returning (or as close as possible if the space is occupied).','2026-01-04 19:35:34','2026-01-04 19:35:34',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(989,NULL,NULL,'description',NULL,'Duration (+1): The power''s Duration is measured in minutes instead of rounds.','2026-01-04 19:36:01','2026-01-04 19:36:01',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(990,NULL,NULL,'description',NULL,'Polymorph (+3): The caster may, at a Range of Touch, transform a living being of Size -4 to Size 3 into a form of her choice. The spell is resisted by Spirit, and the new form must be able to survive in its current environment. Gear is absorbed into the creature''s form. With success, the victim retains his Smarts. With a raise, his Smarts is transformed into Animal Smarts and he cannot attempt to recover for one hour. Victims attempt to recover at the end of their turn by making a Spirit roll at -2 (-4 if the caster got a raise). This is a free action.','2026-01-04 19:36:01','2026-01-04 19:36:01',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(991,NULL,NULL,'description',NULL,'Transform (+2/+3): The power may affect a willing target at a Range of Touch for +2 or Smarts for +3 (the Range modifier may increase this further).','2026-01-04 19:36:01','2026-01-04 19:36:01',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(992,NULL,NULL,'description',NULL,'Power (+3): The Strength of telekinesis is d12, or d12+2 with a raise. Bashing victims inflicts Str+d8 damage. Objects weighing 500 pounds or more are treated as Heavy Weapons.','2026-01-04 19:36:01','2026-01-04 19:36:01',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(993,NULL,NULL,'description',NULL,'Additional Recipients (+1): The power may affect more than one target for 1 additional Power Point each.','2026-01-04 19:36:29','2026-01-04 19:36:29',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(994,NULL,NULL,'description',NULL,'Gate (+5): Instead of teleporting, the caster opens a temporary portal connecting her current location and the destination. Creatures and objects can travel through this gate from either side for 5 rounds.','2026-01-04 19:36:29','2026-01-04 19:36:29',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(995,NULL,NULL,'description',NULL,'Greater Teleport (+5): The character can teleport much farther. The caster must concentrate on her destination for at least one round, taking no other actions. This makes her Vulnerable; if Shaken or Stunned, the spell fails. For one round of concentration, the character can teleport up to 100 miles. For two rounds, she can travel 1000 miles, and with three rounds, she can go anywhere in the world.','2026-01-04 19:36:29','2026-01-04 19:36:29',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(996,NULL,NULL,'description',NULL,'Teleport Foe (+2): Foes may be targeted at a Range of Touch. The foe resists the casting with an opposed Spirit roll against the arcane skill total and is sent up to 12" away with success and 24" with a raise. Foes may not be teleported into solid objects.','2026-01-04 19:36:29','2026-01-04 19:36:29',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(997,NULL,NULL,'description',NULL,'With spellbook access, spend a Benny to cast any power of Rank or lower (not added to power list after) (Eldritch Inspiration)','2026-01-04 20:18:20','2026-01-04 20:18:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(998,NULL,NULL,'description',NULL,'New Powers Edge grants 3 powers instead of 2 (Spellbooks)','2026-01-04 20:18:20','2026-01-04 20:18:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(999,'power_slots',NULL,'flat_bonus',1,'Gain immediate power of Rank or lower on taking this Edge (Spellbooks)','2026-01-04 20:18:20','2026-01-04 20:18:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1000,NULL,NULL,'description',NULL,'Concoctions given to others last 1 week instead of 48 hours (Chemist)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1001,NULL,NULL,'description',NULL,'Create permanent potions at half cost with Simple Workshop equivalent (Master Alchemist)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1002,NULL,NULL,'description',NULL,'Spend 2 PP: enemies in LBT make Spirit or Shaken (Dirge)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1003,NULL,NULL,'description',NULL,'Support using Performance grants +2 (+3 with raise) instead of +1 (+2) (Inspire Heroics)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1004,NULL,NULL,'description',NULL,'Free reroll on Performance rolls with magical instrument in hand (Instrument)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1005,NULL,NULL,'description',NULL,'Limited action: 1 PP = 2d6 damage or 2 PP = 3d6 damage to undead in LBT (Destroy Undead)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1006,NULL,NULL,'description',NULL,'Action + 1 PP: remove Distracted, Vulnerable, or Shaken within Spirit range (Mercy)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1007,NULL,NULL,'description',NULL,'Bolt, blast, and burst deal +2 damage (Hell''s Wrath)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1008,NULL,NULL,'description',NULL,'Action: +2 armor from Hellish glow (no Stealth) until dismissed (Infernal Armor)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1009,NULL,NULL,'description',NULL,'Heartwood Staff: Str+d8, Parry +1, Reach 1; spend 1 PP after hit for +d6 damage (Heartwood Staff)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1010,NULL,NULL,'description',NULL,'Speak and cast at -2 while shape changed (True Form)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1011,NULL,NULL,'description',NULL,'+2 Toughness while experiencing elemental synergy (Elemental Absorption)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1012,NULL,NULL,'description',NULL,'Master another element; change Trappings between mastered elements (Elemental Master)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1013,NULL,NULL,'description',NULL,'Free Mobility and Sound modifiers on illusion (Master of Illusion)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1014,NULL,NULL,'description',NULL,'Free Deadly modifier on illusion (Deadly Illusion)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1015,NULL,NULL,'description',NULL,'Become Undead; respawn in 2d6 days if slain (vessel intact) (Soul Jar)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1016,NULL,NULL,'description',NULL,'Undead animal familiar with Undead abilities (Undead Familiar)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1017,NULL,NULL,'description',NULL,'+2 damage on powers; Critical Failure Stuns LBT including self (Primal Magic)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1018,NULL,NULL,'description',NULL,'Free reroll on failed Faith with sacred fetish (Sacred Fetish)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1019,NULL,NULL,'description',NULL,'+2 damage on all damaging powers (Great Power)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1020,NULL,NULL,'description',NULL,'Great Power damage bonus increases to +4 (Phenomenal Power)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1021,NULL,NULL,'description',NULL,'Summoned animals get +2 Toughness barding (undispellable) (Arcane Barding)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1022,NULL,NULL,'description',NULL,'Grant summoned monsters one Combat Edge of Rank or lower (Ferocious Summoning)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1023,NULL,NULL,'description',NULL,'Summon greater creatures: barghest/mammoth/T-rex/young dragon (Great Summoning)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1024,NULL,NULL,'description',NULL,'Modified armor: -1 die Min Str, choose Arms/Torso/Legs bonus (Tinkerer''s Armor)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1025,NULL,NULL,'description',NULL,'Construct animal familiar with Construct abilities (Construct Familiar)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1026,NULL,NULL,'description',NULL,'1/encounter free action: target Spirit -2 to spend Bennies (The Evil Eye)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1027,NULL,NULL,'description',NULL,'Midnight-1AM: no Critical Failures + free Soak on Wounds (The Witching Hour)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1028,'derived_stat','toughness','flat_bonus',1,'+1 Toughness (Leather Armor)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1029,'derived_stat','toughness','flat_bonus',3,'+3 Toughness (Chain Shirt)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1030,'derived_stat','toughness','flat_bonus',3,'+3 Toughness (Chain Hauberk)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1031,'derived_stat','toughness','flat_bonus',4,'+4 Toughness (Plate Corselet)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1032,'derived_stat','toughness','flat_bonus',3,'+3 Toughness (Plate Arms)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1033,'derived_stat','toughness','flat_bonus',3,'+3 Toughness (Plate Legs)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1034,'derived_stat','toughness','flat_bonus',4,'+4 Toughness (Plate Helmet)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1035,'derived_stat','toughness','flat_bonus',2,'+2 Toughness (Kevlar Vest)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1036,'derived_stat','toughness','flat_bonus',4,'+4 Toughness (Infantry Battle Suit)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1037,'derived_stat','toughness','flat_bonus',10,'+10 Toughness (Bomb Squad Suit)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1038,'derived_stat','toughness','flat_bonus',3,'+3 Toughness (Motorcycle Helmet)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1039,'derived_stat','toughness','flat_bonus',4,'+4 Toughness (Tactical Helmet)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1040,'derived_stat','toughness','flat_bonus',2,'+2 Toughness (Light Body Armor)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1041,'derived_stat','toughness','flat_bonus',4,'+4 Toughness (Medium Body Armor)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1042,'derived_stat','toughness','flat_bonus',6,'+6 Toughness (Heavy Body Armor)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1043,'derived_stat','toughness','flat_bonus',1,'+1 Toughness (Cloak with Hood)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1044,'derived_stat','toughness','flat_bonus',1,'+1 Toughness (Cloth Leggings)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1045,'derived_stat','toughness','flat_bonus',1,'+1 Toughness (Cloth Tunic)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1046,'derived_stat','toughness','flat_bonus',1,'+1 Toughness (Robe with Hooded Cloak)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1047,'derived_stat','toughness','flat_bonus',2,'+2 Toughness (Leather Tunic or Jacket)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1048,'derived_stat','toughness','flat_bonus',2,'+2 Toughness (Leather Leggings)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1049,'derived_stat','toughness','flat_bonus',2,'+2 Toughness (Leather or Natural Cap)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1050,'derived_stat','toughness','flat_bonus',3,'+3 Toughness (Chain Shirt)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1051,'derived_stat','toughness','flat_bonus',3,'+3 Toughness (Chain Leggings)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1052,'derived_stat','toughness','flat_bonus',3,'+3 Toughness (Chain Hood or Pot Helm)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1053,'derived_stat','toughness','flat_bonus',4,'+4 Toughness (Breastplate)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1054,'derived_stat','toughness','flat_bonus',4,'+4 Toughness (Plate Greaves)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1055,'derived_stat','toughness','flat_bonus',4,'+4 Toughness (Plate Vambraces)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1056,'derived_stat','toughness','flat_bonus',4,'+4 Toughness (Heavy Helm)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1057,'derived_stat','toughness','flat_bonus',4,'+4 Toughness (Heavy Helm, Enclosed)','2026-01-06 00:06:22','2026-01-06 00:06:22',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1058,'derived_stat','parry','flat_bonus',1,'+1 Parry (quarterstaff)','2026-01-06 00:08:20','2026-01-06 00:08:20',NULL);
+INSERT INTO modifiers(id,target_type,target_identifier,value_type,value,description,created_at,updated_at,condition) VALUES(1059,'derived_stat','parry','flat_bonus',1,'+1 Parry (parrying dagger, when wielded with one-handed weapon)','2026-01-06 00:08:20','2026-01-06 00:08:20',NULL);
+COMMIT;
@@ -0,0 +1,131 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE pack_contents (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    pack_gear_id INTEGER NOT NULL, -- The pack (e.g., Adventurer's Pack)
+    item_gear_id INTEGER NOT NULL, -- An item in the pack (e.g., Backpack)
+    quantity INTEGER NOT NULL DEFAULT 1,
+    notes TEXT, -- For items like "1 week's rations"
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (pack_gear_id) REFERENCES gear(id) ON DELETE CASCADE,
+    FOREIGN KEY (item_gear_id) REFERENCES gear(id) ON DELETE CASCADE
+);
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(1,263,1,1,'Backpack','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(2,263,2,1,'Bedroll','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(3,263,4,1,'Candles (10)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(4,263,141,1,'Chalk (12 pieces)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(5,263,110,1,'Common Clothing','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(6,263,7,1,'Flint and Steel','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(7,263,8,1,'Grappling Hook','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(8,263,10,1,'Lantern','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(9,263,262,1,'Mirror, small','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(10,263,160,1,'Rope, hemp (20 yards)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(11,263,13,1,'Oil (1 pint)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(12,263,15,1,'Shovel','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(13,263,169,1,'Soap','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(14,263,179,3,'Torches','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(15,263,181,1,'Waterskin','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(16,263,17,1,'Whetstone','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(17,263,121,7,'Rations (1 week)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(18,264,126,1,'Alchemist''s Bag','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(19,264,1,1,'Backpack','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(20,264,7,1,'Flint and Steel','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(21,264,2,1,'Bedroll','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(22,264,262,1,'Mirror, small','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(23,264,132,1,'Belt Pouch','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(24,264,13,3,'Oil (3 pints)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(25,264,160,1,'Rope, hemp (20 yards)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(26,264,121,7,'Rations (1 week)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(27,264,123,5,'Water (5 quarts)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(28,264,134,10,'Bottles','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(29,264,179,3,'Torches','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(30,265,148,1,'Holy Symbol, silver','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(31,265,2,1,'Bedroll','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(32,265,7,1,'Flint and Steel','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(33,265,4,1,'Candles (10)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(34,265,146,1,'Healer''s Kit','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(35,265,10,1,'Lantern','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(36,265,13,3,'Oil (3 pints)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(37,265,155,1,'Parchment','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(38,265,151,1,'Ink (vial)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(39,265,159,1,'Quill','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(40,265,1,1,'Backpack','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(41,265,181,1,'Waterskin','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(42,265,121,7,'Rations (1 week)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(43,266,154,1,'Musical Instrument','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(44,266,1,1,'Backpack','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(45,266,2,1,'Bedroll','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(46,266,262,1,'Mirror, small','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(47,266,152,1,'Journal','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(48,266,151,1,'Ink (vial)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(49,266,159,1,'Quill','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(50,266,7,1,'Flint and Steel','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(51,266,109,1,'Cloak, Light (w/hood)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(52,266,111,1,'Formal Clothing','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(53,266,121,7,'Rations (1 week)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(54,266,145,1,'Flask (metal)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(55,266,167,1,'Sewing Kit','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(56,266,124,1,'Wine, bottle','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(57,267,160,1,'Rope, hemp (20 yards)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(58,267,8,1,'Grappling Hook','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(59,267,1,1,'Backpack','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(60,267,7,1,'Flint and Steel','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(61,267,9,1,'Hammer','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(62,267,2,1,'Bedroll','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(63,267,6,1,'Crowbar','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(64,267,10,1,'Lantern','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(65,267,13,5,'Oil (5 pints)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(66,267,171,1,'Spikes/Pitons (10)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(67,267,15,1,'Shovel','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(68,267,181,3,'Waterskins','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(69,267,121,7,'Rations (1 week)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(70,268,170,1,'Spellbook (blank)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(71,268,1,1,'Backpack','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(72,268,2,1,'Bedroll','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(73,268,10,1,'Lantern (as lamp)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(74,268,7,1,'Flint and Steel','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(75,268,151,1,'Ink (vial)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(76,268,159,1,'Quill','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(77,268,13,3,'Oil (3 pints)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(78,268,109,1,'Cloak, Light (w/hood)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(79,268,133,1,'Book','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(80,268,181,1,'Waterskin','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(81,268,121,7,'Rations (1 week)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(82,269,2,1,'Bedroll','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(83,269,1,1,'Backpack','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(84,269,135,1,'Caltrops','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(85,269,10,1,'Lantern','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(86,269,13,3,'Oil (3 pints)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(87,269,114,1,'Ale, gallon','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(88,269,7,1,'Flint and Steel','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(89,269,174,1,'Tankard','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(90,269,160,1,'Rope, hemp (20 yards)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(91,269,12,1,'Manacles','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(92,269,181,1,'Waterskin','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(93,269,121,7,'Rations (1 week)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(94,270,178,1,'Thieves'' Tools','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(95,270,1,1,'Backpack','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(96,270,262,1,'Mirror, small','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(97,270,7,1,'Flint and Steel','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(98,270,132,1,'Belt Pouch','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(99,270,8,1,'Grappling Hook','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(100,270,160,1,'Rope, hemp (20 yards)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(101,270,135,1,'Caltrops','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(102,270,163,1,'Sack, small','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(103,270,13,2,'Oil (2 pints)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(104,270,10,1,'Lantern (bullseye)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(105,270,181,1,'Waterskin','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(106,270,121,7,'Rations (1 week)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(107,271,175,1,'Tent, bivouac (2 person)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(108,271,1,1,'Backpack','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(109,271,160,1,'Rope, hemp (20 yards)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(110,271,2,1,'Bedroll','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(111,271,113,1,'Winter Clothing','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(112,271,108,1,'Boots, Heavy','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(113,271,7,1,'Flint and Steel','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(114,271,168,1,'Shovel, small','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(115,271,109,1,'Cloak, Light (w/hood)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(116,271,181,1,'Waterskin','2026-01-04 23:42:00','2026-01-04 23:42:00');
+INSERT INTO pack_contents(id,pack_gear_id,item_gear_id,quantity,notes,created_at,updated_at) VALUES(117,271,121,7,'Rations (1 week)','2026-01-04 23:42:00','2026-01-04 23:42:00');
+COMMIT;
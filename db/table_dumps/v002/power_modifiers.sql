@@ -0,0 +1,341 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE power_modifiers (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    power_id INTEGER NOT NULL,
+    modifier_id INTEGER NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (power_id) REFERENCES powers(id),
+    FOREIGN KEY (modifier_id) REFERENCES modifiers(id),
+    UNIQUE(power_id, modifier_id)
+);
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(1,1,361,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(2,1,362,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(3,1,363,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(4,1,364,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(5,2,365,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(6,2,366,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(7,2,367,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(8,3,368,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(9,3,369,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(10,3,370,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(11,4,371,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(12,4,372,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(13,5,373,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(14,5,374,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(15,5,375,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(16,6,376,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(17,6,377,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(18,6,378,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(19,6,379,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(20,7,380,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(21,7,381,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(22,7,382,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(23,7,383,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(24,8,384,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(25,8,385,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(26,8,386,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(27,9,387,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(28,9,388,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(29,10,389,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(30,11,390,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(31,11,391,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(32,11,392,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(33,11,393,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(34,11,394,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(35,12,395,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(36,12,396,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(37,12,397,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(38,12,398,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(39,13,399,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(40,13,400,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(41,13,401,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(42,14,402,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(43,14,397,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(44,14,404,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(45,15,405,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(46,15,406,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(47,15,397,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(48,16,408,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(49,16,409,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(50,16,410,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(51,16,411,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(52,16,412,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(53,16,413,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(54,17,414,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(55,17,415,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(56,17,416,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(57,18,417,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(58,18,418,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(59,18,419,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(60,18,420,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(61,18,421,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(62,18,422,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(63,18,423,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(64,19,424,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(65,19,425,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(66,19,397,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(67,20,427,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(68,20,428,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(69,20,429,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(70,20,430,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(71,21,431,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(72,21,432,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(73,22,433,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(74,22,434,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(75,22,432,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(76,23,436,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(77,23,437,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(78,24,438,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(79,24,439,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(80,24,440,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(81,25,441,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(82,25,442,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(83,25,432,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(84,25,444,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(85,26,445,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(86,26,446,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(87,26,447,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(88,26,448,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(89,27,449,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(90,27,450,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(91,27,432,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(92,28,452,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(93,28,453,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(94,28,432,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(95,29,455,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(96,29,456,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(97,29,457,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(98,30,458,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(99,31,459,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(100,32,460,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(101,32,461,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(102,32,462,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(103,33,463,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(104,33,464,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(105,35,465,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(106,35,466,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(107,35,467,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(108,35,468,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(109,35,469,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(110,35,470,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(111,36,471,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(112,36,472,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(113,37,473,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(114,39,474,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(115,39,475,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(116,41,476,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(117,41,477,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(118,41,478,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(119,41,479,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(120,42,480,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(121,42,481,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(122,42,482,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(123,42,483,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(124,42,484,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(125,43,485,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(126,43,486,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(127,43,487,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(128,43,488,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(129,44,489,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(130,44,490,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(131,44,491,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(132,44,492,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(133,44,493,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(134,44,494,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(135,44,495,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(136,44,496,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(137,44,497,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(138,44,498,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(139,45,499,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(140,45,500,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(141,45,501,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(142,45,502,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(143,45,503,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(144,45,504,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(145,46,505,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(146,46,506,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(147,46,507,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(148,46,508,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(149,46,509,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(150,46,510,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(151,46,511,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(152,46,512,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(153,46,513,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(154,47,514,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(155,47,515,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(156,48,516,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(157,48,517,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(158,49,518,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(159,51,519,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(160,52,520,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(161,53,521,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(162,54,522,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(163,54,523,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(164,55,832,'2026-01-04 19:23:41','2026-01-04 19:23:41');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(165,55,833,'2026-01-04 19:23:41','2026-01-04 19:23:41');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(166,56,834,'2026-01-04 19:23:48','2026-01-04 19:23:48');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(167,56,835,'2026-01-04 19:23:48','2026-01-04 19:23:48');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(168,56,836,'2026-01-04 19:23:48','2026-01-04 19:23:48');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(169,57,837,'2026-01-04 19:23:59','2026-01-04 19:23:59');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(170,57,838,'2026-01-04 19:23:59','2026-01-04 19:23:59');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(171,57,839,'2026-01-04 19:23:59','2026-01-04 19:23:59');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(172,57,840,'2026-01-04 19:23:59','2026-01-04 19:23:59');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(173,58,841,'2026-01-04 19:24:08','2026-01-04 19:24:08');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(174,58,842,'2026-01-04 19:24:08','2026-01-04 19:24:08');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(175,58,843,'2026-01-04 19:24:08','2026-01-04 19:24:08');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(176,59,844,'2026-01-04 19:24:22','2026-01-04 19:24:22');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(177,59,845,'2026-01-04 19:24:22','2026-01-04 19:24:22');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(178,59,846,'2026-01-04 19:24:22','2026-01-04 19:24:22');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(179,59,847,'2026-01-04 19:24:22','2026-01-04 19:24:22');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(180,60,848,'2026-01-04 19:24:32','2026-01-04 19:24:32');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(181,61,849,'2026-01-04 19:24:50','2026-01-04 19:24:50');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(182,62,850,'2026-01-04 19:24:50','2026-01-04 19:24:50');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(183,62,851,'2026-01-04 19:24:50','2026-01-04 19:24:50');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(184,63,852,'2026-01-04 19:24:50','2026-01-04 19:24:50');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(185,64,853,'2026-01-04 19:25:30','2026-01-04 19:25:30');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(186,65,854,'2026-01-04 19:25:30','2026-01-04 19:25:30');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(187,65,855,'2026-01-04 19:25:30','2026-01-04 19:25:30');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(188,65,856,'2026-01-04 19:25:30','2026-01-04 19:25:30');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(189,65,857,'2026-01-04 19:25:30','2026-01-04 19:25:30');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(190,65,858,'2026-01-04 19:25:30','2026-01-04 19:25:30');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(191,65,859,'2026-01-04 19:25:30','2026-01-04 19:25:30');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(192,66,860,'2026-01-04 19:25:53','2026-01-04 19:25:53');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(193,66,861,'2026-01-04 19:25:53','2026-01-04 19:25:53');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(194,67,862,'2026-01-04 19:25:53','2026-01-04 19:25:53');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(195,67,863,'2026-01-04 19:25:53','2026-01-04 19:25:53');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(196,68,864,'2026-01-04 19:25:53','2026-01-04 19:25:53');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(197,69,865,'2026-01-04 19:26:12','2026-01-04 19:26:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(198,69,866,'2026-01-04 19:26:12','2026-01-04 19:26:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(199,69,867,'2026-01-04 19:26:12','2026-01-04 19:26:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(200,69,868,'2026-01-04 19:26:12','2026-01-04 19:26:12');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(201,70,869,'2026-01-04 19:26:31','2026-01-04 19:26:31');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(202,71,870,'2026-01-04 19:26:31','2026-01-04 19:26:31');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(203,71,871,'2026-01-04 19:26:31','2026-01-04 19:26:31');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(204,71,872,'2026-01-04 19:26:31','2026-01-04 19:26:31');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(205,71,873,'2026-01-04 19:26:31','2026-01-04 19:26:31');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(206,72,874,'2026-01-04 19:26:48','2026-01-04 19:26:48');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(207,72,875,'2026-01-04 19:26:48','2026-01-04 19:26:48');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(208,73,876,'2026-01-04 19:26:48','2026-01-04 19:26:48');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(209,73,877,'2026-01-04 19:26:48','2026-01-04 19:26:48');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(210,74,878,'2026-01-04 19:27:09','2026-01-04 19:27:09');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(211,74,879,'2026-01-04 19:27:09','2026-01-04 19:27:09');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(212,75,880,'2026-01-04 19:27:09','2026-01-04 19:27:09');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(213,76,881,'2026-01-04 19:27:41','2026-01-04 19:27:41');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(214,76,882,'2026-01-04 19:27:41','2026-01-04 19:27:41');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(215,76,883,'2026-01-04 19:27:41','2026-01-04 19:27:41');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(216,77,884,'2026-01-04 19:27:41','2026-01-04 19:27:41');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(217,77,885,'2026-01-04 19:27:41','2026-01-04 19:27:41');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(218,78,886,'2026-01-04 19:27:58','2026-01-04 19:27:58');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(219,78,887,'2026-01-04 19:27:58','2026-01-04 19:27:58');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(220,79,888,'2026-01-04 19:27:58','2026-01-04 19:27:58');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(221,79,889,'2026-01-04 19:27:58','2026-01-04 19:27:58');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(222,80,890,'2026-01-04 19:27:58','2026-01-04 19:27:58');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(223,80,891,'2026-01-04 19:27:58','2026-01-04 19:27:58');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(224,81,892,'2026-01-04 19:28:20','2026-01-04 19:28:20');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(225,81,893,'2026-01-04 19:28:20','2026-01-04 19:28:20');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(226,81,894,'2026-01-04 19:28:20','2026-01-04 19:28:20');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(227,82,895,'2026-01-04 19:28:20','2026-01-04 19:28:20');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(228,82,896,'2026-01-04 19:28:20','2026-01-04 19:28:20');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(229,83,897,'2026-01-04 19:28:45','2026-01-04 19:28:45');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(230,83,898,'2026-01-04 19:28:45','2026-01-04 19:28:45');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(231,83,899,'2026-01-04 19:28:45','2026-01-04 19:28:45');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(232,83,900,'2026-01-04 19:28:45','2026-01-04 19:28:45');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(233,83,901,'2026-01-04 19:28:45','2026-01-04 19:28:45');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(234,84,902,'2026-01-04 19:28:45','2026-01-04 19:28:45');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(235,84,903,'2026-01-04 19:28:45','2026-01-04 19:28:45');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(236,84,904,'2026-01-04 19:28:45','2026-01-04 19:28:45');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(237,84,905,'2026-01-04 19:28:45','2026-01-04 19:28:45');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(238,85,906,'2026-01-04 19:28:57','2026-01-04 19:28:57');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(239,86,907,'2026-01-04 19:28:57','2026-01-04 19:28:57');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(240,86,908,'2026-01-04 19:28:57','2026-01-04 19:28:57');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(241,88,909,'2026-01-04 19:29:32','2026-01-04 19:29:32');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(242,88,910,'2026-01-04 19:29:32','2026-01-04 19:29:32');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(243,88,911,'2026-01-04 19:29:32','2026-01-04 19:29:32');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(244,88,912,'2026-01-04 19:29:32','2026-01-04 19:29:32');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(245,90,913,'2026-01-04 19:29:54','2026-01-04 19:29:54');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(246,90,914,'2026-01-04 19:29:54','2026-01-04 19:29:54');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(247,91,915,'2026-01-04 19:30:28','2026-01-04 19:30:28');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(248,91,916,'2026-01-04 19:30:28','2026-01-04 19:30:28');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(249,91,917,'2026-01-04 19:30:28','2026-01-04 19:30:28');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(250,92,918,'2026-01-04 19:30:28','2026-01-04 19:30:28');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(251,92,919,'2026-01-04 19:30:28','2026-01-04 19:30:28');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(252,92,920,'2026-01-04 19:30:28','2026-01-04 19:30:28');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(253,92,921,'2026-01-04 19:30:28','2026-01-04 19:30:28');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(254,93,922,'2026-01-04 19:30:55','2026-01-04 19:30:55');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(255,93,923,'2026-01-04 19:30:55','2026-01-04 19:30:55');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(256,93,924,'2026-01-04 19:30:55','2026-01-04 19:30:55');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(257,94,925,'2026-01-04 19:30:55','2026-01-04 19:30:55');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(258,95,926,'2026-01-04 19:30:55','2026-01-04 19:30:55');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(259,95,927,'2026-01-04 19:30:55','2026-01-04 19:30:55');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(260,95,928,'2026-01-04 19:30:55','2026-01-04 19:30:55');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(261,96,929,'2026-01-04 19:31:18','2026-01-04 19:31:18');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(262,97,930,'2026-01-04 19:31:18','2026-01-04 19:31:18');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(263,98,931,'2026-01-04 19:31:18','2026-01-04 19:31:18');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(264,98,932,'2026-01-04 19:31:18','2026-01-04 19:31:18');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(265,98,933,'2026-01-04 19:31:18','2026-01-04 19:31:18');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(266,100,934,'2026-01-04 19:31:59','2026-01-04 19:31:59');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(267,100,935,'2026-01-04 19:31:59','2026-01-04 19:31:59');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(268,100,936,'2026-01-04 19:31:59','2026-01-04 19:31:59');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(269,100,937,'2026-01-04 19:31:59','2026-01-04 19:31:59');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(270,100,938,'2026-01-04 19:31:59','2026-01-04 19:31:59');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(271,101,939,'2026-01-04 19:32:16','2026-01-04 19:32:16');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(272,101,940,'2026-01-04 19:32:16','2026-01-04 19:32:16');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(273,101,941,'2026-01-04 19:32:16','2026-01-04 19:32:16');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(274,102,942,'2026-01-04 19:32:16','2026-01-04 19:32:16');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(275,102,943,'2026-01-04 19:32:16','2026-01-04 19:32:16');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(276,102,944,'2026-01-04 19:32:16','2026-01-04 19:32:16');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(277,103,945,'2026-01-04 19:32:43','2026-01-04 19:32:43');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(278,103,946,'2026-01-04 19:32:43','2026-01-04 19:32:43');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(279,103,947,'2026-01-04 19:32:43','2026-01-04 19:32:43');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(280,104,948,'2026-01-04 19:32:43','2026-01-04 19:32:43');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(281,104,949,'2026-01-04 19:32:43','2026-01-04 19:32:43');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(282,104,950,'2026-01-04 19:32:43','2026-01-04 19:32:43');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(283,104,951,'2026-01-04 19:32:43','2026-01-04 19:32:43');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(284,104,952,'2026-01-04 19:32:43','2026-01-04 19:32:43');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(285,105,953,'2026-01-04 19:33:25','2026-01-04 19:33:25');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(286,105,954,'2026-01-04 19:33:25','2026-01-04 19:33:25');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(287,106,955,'2026-01-04 19:33:25','2026-01-04 19:33:25');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(288,106,956,'2026-01-04 19:33:25','2026-01-04 19:33:25');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(289,106,957,'2026-01-04 19:33:25','2026-01-04 19:33:25');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(290,107,958,'2026-01-04 19:33:49','2026-01-04 19:33:49');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(291,107,959,'2026-01-04 19:33:49','2026-01-04 19:33:49');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(292,107,960,'2026-01-04 19:33:49','2026-01-04 19:33:49');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(293,107,961,'2026-01-04 19:33:49','2026-01-04 19:33:49');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(294,107,962,'2026-01-04 19:33:49','2026-01-04 19:33:49');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(295,108,963,'2026-01-04 19:33:49','2026-01-04 19:33:49');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(296,108,964,'2026-01-04 19:33:49','2026-01-04 19:33:49');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(297,109,965,'2026-01-04 19:34:15','2026-01-04 19:34:15');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(298,110,966,'2026-01-04 19:34:15','2026-01-04 19:34:15');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(299,110,967,'2026-01-04 19:34:15','2026-01-04 19:34:15');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(300,111,968,'2026-01-04 19:34:15','2026-01-04 19:34:15');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(301,111,969,'2026-01-04 19:34:15','2026-01-04 19:34:15');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(302,112,970,'2026-01-04 19:34:40','2026-01-04 19:34:40');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(303,112,971,'2026-01-04 19:34:40','2026-01-04 19:34:40');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(304,112,972,'2026-01-04 19:34:40','2026-01-04 19:34:40');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(305,112,973,'2026-01-04 19:34:40','2026-01-04 19:34:40');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(306,112,974,'2026-01-04 19:34:40','2026-01-04 19:34:40');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(307,112,975,'2026-01-04 19:34:40','2026-01-04 19:34:40');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(308,113,976,'2026-01-04 19:34:40','2026-01-04 19:34:40');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(309,113,977,'2026-01-04 19:34:40','2026-01-04 19:34:40');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(310,114,978,'2026-01-04 19:35:08','2026-01-04 19:35:08');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(311,114,979,'2026-01-04 19:35:08','2026-01-04 19:35:08');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(312,115,980,'2026-01-04 19:35:08','2026-01-04 19:35:08');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(313,115,981,'2026-01-04 19:35:08','2026-01-04 19:35:08');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(314,115,982,'2026-01-04 19:35:08','2026-01-04 19:35:08');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(315,115,983,'2026-01-04 19:35:08','2026-01-04 19:35:08');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(316,116,984,'2026-01-04 19:35:08','2026-01-04 19:35:08');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(317,117,985,'2026-01-04 19:35:34','2026-01-04 19:35:34');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(318,118,986,'2026-01-04 19:35:34','2026-01-04 19:35:34');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(319,118,987,'2026-01-04 19:35:34','2026-01-04 19:35:34');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(320,118,988,'2026-01-04 19:35:34','2026-01-04 19:35:34');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(321,119,989,'2026-01-04 19:36:01','2026-01-04 19:36:01');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(322,119,990,'2026-01-04 19:36:01','2026-01-04 19:36:01');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(323,119,991,'2026-01-04 19:36:01','2026-01-04 19:36:01');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(324,120,992,'2026-01-04 19:36:01','2026-01-04 19:36:01');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(325,121,993,'2026-01-04 19:36:29','2026-01-04 19:36:29');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(326,121,994,'2026-01-04 19:36:29','2026-01-04 19:36:29');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(327,121,995,'2026-01-04 19:36:29','2026-01-04 19:36:29');
+INSERT INTO power_modifiers(id,power_id,modifier_id,created_at,updated_at) VALUES(328,121,996,'2026-01-04 19:36:29','2026-01-04 19:36:29');
+COMMIT;
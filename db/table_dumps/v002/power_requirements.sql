@@ -0,0 +1,136 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE power_requirements (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    power_id INTEGER NOT NULL,
+    requirement_expression_id INTEGER NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (power_id) REFERENCES powers(id),
+    FOREIGN KEY (requirement_expression_id) REFERENCES requirement_expressions(id),
+    UNIQUE(power_id, requirement_expression_id)
+);
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(1,1,415,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(2,2,416,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(3,3,417,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(4,4,418,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(5,5,419,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(6,6,420,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(7,7,421,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(8,8,422,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(9,9,423,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(10,10,424,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(11,11,425,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(12,12,426,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(13,13,427,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(14,14,428,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(15,15,429,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(16,16,430,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(17,17,431,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(18,18,432,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(19,19,433,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(20,20,434,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(21,21,435,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(22,22,436,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(23,23,437,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(24,24,438,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(25,25,439,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(26,26,440,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(27,27,441,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(28,28,442,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(29,29,443,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(30,30,444,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(31,31,445,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(32,32,446,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(33,33,447,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(34,34,448,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(35,35,449,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(36,36,450,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(37,37,451,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(38,38,452,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(39,39,453,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(40,40,454,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(41,41,455,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(42,42,456,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(43,43,457,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(44,44,458,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(45,45,459,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(46,46,460,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(47,47,461,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(48,48,462,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(49,49,463,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(50,50,464,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(51,51,465,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(52,52,466,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(53,53,467,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(54,54,468,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(56,55,689,'2026-01-04 19:23:34','2026-01-04 19:23:34');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(57,56,690,'2026-01-04 19:23:48','2026-01-04 19:23:48');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(58,57,691,'2026-01-04 19:23:59','2026-01-04 19:23:59');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(59,58,692,'2026-01-04 19:24:08','2026-01-04 19:24:08');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(60,59,693,'2026-01-04 19:24:22','2026-01-04 19:24:22');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(61,60,694,'2026-01-04 19:24:32','2026-01-04 19:24:32');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(62,61,695,'2026-01-04 19:24:50','2026-01-04 19:24:50');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(63,62,696,'2026-01-04 19:24:50','2026-01-04 19:24:50');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(64,63,697,'2026-01-04 19:24:50','2026-01-04 19:24:50');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(65,64,698,'2026-01-04 19:25:30','2026-01-04 19:25:30');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(66,65,699,'2026-01-04 19:25:30','2026-01-04 19:25:30');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(67,66,700,'2026-01-04 19:25:53','2026-01-04 19:25:53');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(68,67,701,'2026-01-04 19:25:53','2026-01-04 19:25:53');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(69,68,702,'2026-01-04 19:25:53','2026-01-04 19:25:53');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(70,69,703,'2026-01-04 19:26:12','2026-01-04 19:26:12');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(71,70,704,'2026-01-04 19:26:31','2026-01-04 19:26:31');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(72,71,705,'2026-01-04 19:26:31','2026-01-04 19:26:31');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(73,72,706,'2026-01-04 19:26:48','2026-01-04 19:26:48');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(74,73,707,'2026-01-04 19:26:48','2026-01-04 19:26:48');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(75,74,708,'2026-01-04 19:27:09','2026-01-04 19:27:09');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(76,75,709,'2026-01-04 19:27:09','2026-01-04 19:27:09');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(77,76,710,'2026-01-04 19:27:41','2026-01-04 19:27:41');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(78,77,711,'2026-01-04 19:27:41','2026-01-04 19:27:41');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(79,78,712,'2026-01-04 19:27:58','2026-01-04 19:27:58');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(80,79,713,'2026-01-04 19:27:58','2026-01-04 19:27:58');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(81,80,714,'2026-01-04 19:27:58','2026-01-04 19:27:58');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(82,81,715,'2026-01-04 19:28:20','2026-01-04 19:28:20');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(83,82,716,'2026-01-04 19:28:20','2026-01-04 19:28:20');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(84,83,717,'2026-01-04 19:28:45','2026-01-04 19:28:45');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(85,84,718,'2026-01-04 19:28:45','2026-01-04 19:28:45');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(86,85,719,'2026-01-04 19:28:57','2026-01-04 19:28:57');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(87,86,720,'2026-01-04 19:28:57','2026-01-04 19:28:57');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(88,87,721,'2026-01-04 19:29:32','2026-01-04 19:29:32');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(89,88,722,'2026-01-04 19:29:32','2026-01-04 19:29:32');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(90,89,723,'2026-01-04 19:29:54','2026-01-04 19:29:54');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(91,90,724,'2026-01-04 19:29:54','2026-01-04 19:29:54');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(92,91,725,'2026-01-04 19:30:28','2026-01-04 19:30:28');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(93,92,726,'2026-01-04 19:30:28','2026-01-04 19:30:28');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(94,93,727,'2026-01-04 19:30:55','2026-01-04 19:30:55');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(95,94,728,'2026-01-04 19:30:55','2026-01-04 19:30:55');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(96,95,729,'2026-01-04 19:30:55','2026-01-04 19:30:55');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(97,96,730,'2026-01-04 19:31:18','2026-01-04 19:31:18');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(98,97,731,'2026-01-04 19:31:18','2026-01-04 19:31:18');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(99,98,732,'2026-01-04 19:31:18','2026-01-04 19:31:18');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(100,99,733,'2026-01-04 19:31:59','2026-01-04 19:31:59');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(101,100,734,'2026-01-04 19:31:59','2026-01-04 19:31:59');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(102,101,735,'2026-01-04 19:32:16','2026-01-04 19:32:16');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(103,102,736,'2026-01-04 19:32:16','2026-01-04 19:32:16');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(104,103,737,'2026-01-04 19:32:43','2026-01-04 19:32:43');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(105,104,738,'2026-01-04 19:32:43','2026-01-04 19:32:43');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(106,105,739,'2026-01-04 19:33:25','2026-01-04 19:33:25');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(107,106,740,'2026-01-04 19:33:25','2026-01-04 19:33:25');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(108,107,741,'2026-01-04 19:33:49','2026-01-04 19:33:49');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(109,108,742,'2026-01-04 19:33:49','2026-01-04 19:33:49');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(110,109,743,'2026-01-04 19:34:15','2026-01-04 19:34:15');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(111,110,744,'2026-01-04 19:34:15','2026-01-04 19:34:15');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(112,111,745,'2026-01-04 19:34:15','2026-01-04 19:34:15');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(113,112,746,'2026-01-04 19:34:40','2026-01-04 19:34:40');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(114,113,747,'2026-01-04 19:34:40','2026-01-04 19:34:40');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(115,114,748,'2026-01-04 19:35:08','2026-01-04 19:35:08');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(116,115,749,'2026-01-04 19:35:08','2026-01-04 19:35:08');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(117,116,750,'2026-01-04 19:35:08','2026-01-04 19:35:08');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(118,117,751,'2026-01-04 19:35:34','2026-01-04 19:35:34');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(119,118,752,'2026-01-04 19:35:34','2026-01-04 19:35:34');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(120,119,753,'2026-01-04 19:36:01','2026-01-04 19:36:01');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(121,120,754,'2026-01-04 19:36:01','2026-01-04 19:36:01');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(122,121,755,'2026-01-04 19:36:29','2026-01-04 19:36:29');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(123,122,756,'2026-01-04 19:36:29','2026-01-04 19:36:29');
+INSERT INTO power_requirements(id,power_id,requirement_expression_id,created_at,updated_at) VALUES(124,123,757,'2026-01-04 19:36:29','2026-01-04 19:36:29');
+COMMIT;
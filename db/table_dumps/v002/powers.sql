@@ -0,0 +1,138 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE powers (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name VARCHAR(50) NOT NULL,
+    power_points INTEGER NOT NULL,
+    range VARCHAR(30) NOT NULL,
+    duration VARCHAR(30) NOT NULL,
+    source VARCHAR(20) NOT NULL DEFAULT 'core',
+    description TEXT NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP, maintenance_power_points INTEGER,
+    UNIQUE(name, source)
+);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(1,'Arcane Protection',1,'Smarts','5','core','Success with arcane protection means hostile powers suffer a −2 penalty (−4 with a raise) to affect this character. If the hostile power fails to affect the target due to this penalty, it still activates and consumes Power Points (and may still affect other targets). If the power causes harm, damage is also reduced a like amount for the protected target. Arcane protection stacks with Arcane Resistance should the recipient have both!','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(2,'Blast',3,'Smarts ×2','Instant','core','Blast launches a ball of explosive energy or matter. The area of effect is a Medium Blast Template. Every target within suffers 2d6 damage, or 3d6 with a raise (see Area Effect Attacks, page 91).','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(3,'Bolt',1,'Smarts ×2','Instant','core','Bolt sends damaging bursts of energy, streaks of holy light, or shards of matter toward one''s foes. There are no Range penalties, but the arcane skill roll is affected by Cover, Illumination, and all other usual penalties. The damage of the bolt is 2d6, or 3d6 with a raise.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(4,'Burst',2,'Cone Template','Instant','core','Burst produces a large fan of energy that bathes its targets in red-hot fire, a cone of cold, or other damaging matter or energy. Success creates a Cone Template starting at the caster and extending outward (see Area Effect Attacks, page 91). Everything within suffers 2d6 damage (or 3d6 with a raise).','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(5,'Damage Field',4,'Smarts','5','core','Damage field creates a dangerous aura around the recipient that harms foes foolish enough to get too close. At the end of the affected character''s turn, all adjacent beings (including allies!) automatically take 2d4 damage.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(6,'Deflection',2,'Smarts','5','core','Deflection powers work in a variety of ways. Some actually deflect incoming attacks, others blur the target''s form or produce illusionary effects. The end result is the same, however—to misdirect incoming attacks. With success, foes must subtract 2 from either melee or ranged attacks (caster''s choice). With a raise, the penalty applies to both. An attacker using a ranged weapon in melee against the recipient suffers the penalty either way.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(7,'Protection',1,'Smarts','5','core','Protection creates a field of energy or armor around a character, giving him 2 points of Armor. With a raise, the bonus is applied to Toughness instead. Whether the protection is visible or not depends on the Trapping. Protection stacks with all other armor, natural or worn, and is negated by AP as usual.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(8,'Smite',2,'Smarts','5','core','This power is cast on a weapon of some sort. If it''s a ranged weapon, it affects one entire magazine, 20 bolts, shells, or arrows, or one full "load" of ammunition (the GM determines the exact quantity for unusual weapons). While the power is in effect, the weapon''s damage is increased by +2, or +4 with a raise.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(9,'Stun',2,'Smarts','Instant','core','Stun shocks a target with concussive force, sound, light, magical energy, or the like. A successful casting means the victim must make a Vigor roll (at −2 with a raise on the arcane skill roll) or be Stunned (see page 100).','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(10,'Banish',3,'Smarts','Instant','core','Banish sends entities from immaterial planes back to their native dimensions. This includes ghosts, demons, elementals, and similar beings (at the GM''s discretion). Banishing a being is an opposed roll of the caster''s arcane skill versus the target''s Spirit. Success means the target is Shaken, and each raise causes a Wound. If this Incapacitates the target it returns to its native plane of existence. Banished entities may return when the Game Master feels it''s appropriate, such as the next full moon, when summoned again, or even a few rounds later if it''s a particularly powerful creature under the right conditions or in a location of power.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(11,'Barrier',2,'Smarts','5','core','Barrier creates a straight wall 5″ (10 yards) long and 1″ (two yards) tall, of immobile material that conforms to the surface it''s cast upon. Thickness varies depending on what the wall is made of, but is usually a few inches. The wall has a Hardness of 10, and may be destroyed as any other object. When the spell expires or the wall is broken it crumbles to dust or dissipates. Trappings are never left behind.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(12,'Blind',2,'Smarts','Instant','core','Those affected by this malicious power suffer blurred vision or near-complete blindness with a raise. Success means the victim suffers a −2 penalty to all actions requiring sight, or −4 with a raise. The victim automatically tries to shake off the effect with a Vigor roll as a free action at the end of his following turns. Success removes 2 points of penalties, and a raise removes the effect entirely.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(13,'Confusion',1,'Smarts','Until the end of the victim''s next turn','core','Confusion confounds all targets in a Medium Blast Template, making them either Distracted or Vulnerable, or both with a raise. The same effect applies to all those affected. These states are removed at the end of the victim''s next turn as usual.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(14,'Entangle',2,'Smarts','Instant','core','Entangle allows the caster to restrain a target with ice, bands of energy, or other vine-like Trappings (Hardness 8). If successful, the target is Entangled. With a raise, he''s Bound. Victims may break free on their turn as detailed under Bound & Entangled on page 92.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(15,'Fear',2,'Smarts','Instant','core','This power causes overwhelming dread and horror. The affected make a Fear roll. Extras who fail are Panicked and Wild Cards roll on the Fear Table. If the power is cast with a raise, the Fear roll is at −2 and Wild Cards add +2 to Fear Table results.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(16,'Havoc',2,'Smarts','Instant','core','This ability creates chaos and mischief for all those within its area of effect, hurling debris and rivals in all directions. With success, the caster places a Medium Blast Template anywhere within Range, or a Cone Template emanating from the caster. Anyone touched by the template is Distracted and must then make a Strength roll (at −2 if the caster got a raise). Those who fail are hurled 2d6″—directly away from the caster if using the Cone Template or directly away from the center if using a Blast Template. Victims who strike a hard object take 2d4 damage (nonlethal unless it''s a spiked wall or other more dangerous hazard). Airborne targets suffer an additional −2 to their Strength rolls.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(17,'Puppet',3,'Smarts','5','core','Puppet is an opposed roll of the character''s arcane skill versus the target''s Spirit. With success, the victim obeys commands that don''t directly harm himself or those he cares about. With a raise, the target will harm himself or others (including by inaction). In either event, any time he''s ordered to harm himself or those he cares about, including by inaction, he gets a Spirit roll as a free action. If successful, he doesn''t carry out that particular command but remains a puppet. With a raise, he breaks the controller''s hold and the power ends. Commands are general, such as "attack that person" or "open that door."','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(18,'Sloth/Speed',2,'Smarts','Instant (sloth); 5 (speed)','core','Sloth lessens celerity and coordination while speed increases it. Sloth halves the target''s total movement each round (round up). With a raise, movement is also an action. The target automatically attempts to shake off the effects of sloth at the end of each of his next turns by making a Spirit roll. Success with speed doubles the target''s movement (basic Pace and running). With a raise the character also ignores the −2 running penalty.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(19,'Slumber',2,'Smarts','One hour','core','Those who favor stealth or want to avoid harming their foes are drawn to this spell, which puts its victims into a deep and restful sleep. Anyone affected by slumber must make a Spirit roll (at −2 if the caster got a raise on her arcane skill roll). Those who fail fall asleep for the Duration of the spell. Very loud noises or attempts to physically wake a sleeper (by shaking him, for example), grant another Spirit roll.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(20,'Boost/Lower Trait',3,'Smarts','5 (boost); Instant (lower)','core','This power allows a character to increase or decrease a target''s Trait (attribute or skill). Boosting an ally''s Trait increases the selected Trait one die type, or two with a raise, for five rounds. Lowering an enemy''s Trait has a Duration of Instant and lowers the selected attribute or skill a die type with success, or two with a raise (to a minimum of d4). A victim automatically tries to shake off the effect with a Spirit roll as a free action at the end of his following turns. Success improves the effect one die type, and a raise removes the effect entirely. Additional castings don''t stack on a single Trait (take the highest), but may affect different Traits.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(21,'Darksight',1,'Smarts','One hour','core','Darksight allows a hero to see in the dark. With success, he ignores up to 4 points of illumination penalties. With a raise, he ignores up to six points and can see in pitch darkness.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(22,'Farsight',2,'Smarts','5','core','Farsight allows the recipient to see in detail over great distances. She can read lips or read fine print up to a mile distant. With a raise, she also halves Range penalties for Shooting, Athletics (throwing), or other abilities affected by Range.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(23,'Fly',3,'Smarts','5','core','Fly allows a character to soar at Pace 12″, or twice that with a raise (he may not Run).','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(24,'Growth/Shrink',2,'Smarts','5','core','Growth increases the recipient''s Size by 1 for every 2 Power Points spent. Each increase in Size grants the target a one-step increase to Strength and 1 point of Toughness. Shrink reduces the Size of the subject one step for every 2 Power Points spent to a maximum of Size −2 (approximately the size of a cat). Each step reduced decreases Strength one die type (minimum of d4) and Toughness by 1 (minimum of 2). For unwilling targets, the caster''s arcane skill roll is opposed by Spirit.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(25,'Relief',1,'Smarts','Instant','core','Relief allows the caster to recover from a negative condition or the effects of Fatigue. Recover: The caster removes one of the following conditions: Shaken, Distracted, or Vulnerable. A raise on the arcane skill roll removes two conditions. Relief can''t remove conditions caused by ongoing effects (Bound or Entangled, Stunned, etc.) unless the source of the effect is removed. Numb: Reduces the recipient''s total penalties from Wounds and Fatigue by one with success, or two with a raise. This effect lasts one hour. It doesn''t actually remove the Wounds or the Fatigue, it just allows the recipient to ignore the penalties.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(26,'Sound/Silence',1,'Smarts ×5 (sound); Smarts (silence)','Instant (sound); 5 (silence)','core','Sound mimics any known sound or voice, emanating from a point of origin within Range at a volume up to the sound of a loud shout. If used as a Test, the defender opposes the casting roll with Smarts. Silence does the opposite, muting all sound up to a loud shout within a Large Blast Template. This subtracts 4 from Notice rolls made by those inside the area of effect, as well as anyone trying to hear sounds made from within. A raise completely mutes all sound inside the template—such Notice rolls automatically fail.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(27,'Wall Walker',2,'Smarts','5','core','Wall walker allows the recipient to walk on vertical or horizontal surfaces. With success, she moves at half her normal Pace. With a raise, she may move at full Pace and even run. If forced to make an Athletics roll to climb or hang on to a surface, she adds +4 to the total.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(28,'Warrior''s Gift',4,'Smarts','5','core','With a successful arcane skill roll, the recipient gains the benefits of a single Combat Edge chosen by the caster. The caster (not the recipient) must have the same Rank or higher as the Edge''s Requirements. With a raise, the recipient gains the Improved version of the Edge (if there is one, and even if he doesn''t meet the Rank Requirement).','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(29,'Healing',3,'Touch','Instant','core','Healing removes Wounds less than an hour old. A success removes one Wound, and a raise removes two. The power may be cast additional times to remove additional Wounds within that hour and as long as the healer has enough Power Points. For Extras, the GM must first determine if the ally is still alive. If so, a successful arcane skill roll returns the ally to action (Shaken if it matters.)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(30,'resurrection',30,'Touch','Instant','core','Perhaps the greatest power of all is the ability to bring someone back from the dead. It can restore lost loves, bring solace to the grieving, and topple kingdoms. Returning the dead to life requires a reasonably complete corpse no more than a year old. The caster then prays, meditates, chants, or otherwise concentrates on mending the body and pulling the spirit back to it for four hours. Once done, the healer makes a casting roll at −8. If successful, the victim returns to life with three Wounds and is Exhausted. With a raise, she returns merely Exhausted.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(31,'speak_language',1,'Smarts','10 minutes','core','This power allows a character to speak, read, and write a sapient language other than his own. A raise on the arcane skill roll allows the user to appropriately use and understand slang and dialect as well.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(32,'Detect/Conceal Arcana',2,'Smarts','5 rounds (detect); 1 hour (conceal)','core','Detect arcana allows the recipient to see and detect all supernatural persons, objects, or effects in sight for five rounds. This includes invisible foes, enchantments on people or items, weird science devices, and so on. With a raise, the caster knows the general type of enchantment. When used on a supernatural creature, the caster learns its active powers and arcane abilities. With a raise, he knows Weaknesses common to that creature type. Detect arcana also allows a character to ignore up to 4 points of penalties when attacking foes hidden by magical darkness, invisibility, or similar abilities (or all penalties with a raise). Conceal arcana prevents detection of arcane energies on one being or item of Normal Scale for one hour. With success, attempts to detect arcana on the being or item suffer a −2 penalty or −4 with a raise.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(33,'Dispel',1,'Smarts','Instant','core','Dispel allows a hero to negate enemy powers. It has no effect on permanent enchantments or innate abilities such as a dragon''s breath or a banshee''s scream. Dispel affects arcane devices normally. Dispel can target a character with a power already in effect or a caster activating a power. The latter counters the power but requires the countering mage to be on Hold and interrupt his foe''s action. In either case, dispelling an opponent''s power is an opposed roll of arcane skills (with a −2 modifier if the rival''s power is of another type such as magic vs. miracles, psionics vs. weird science, etc.). If the dispelling character wins, the targeted power ends immediately (or fails if it was countered with the usual results of failure). With a raise, the recipient of the dispelled power is also Distracted.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(34,'Divination',5,'Self','5 minutes','core','Divination allows the caster to contact otherworldly beings or forces to gain information. Preparing the spell takes one uninterrupted minute, and must be done at a site relevant to the spirit being contacted. If speaking with a ghost, for example, the conversation should occur at its grave, where it was killed, or with a personal object in hand. After the preparation time is complete, the caster makes her arcane skill roll. Success allows her to engage with the spirit and ask it any questions she might have. The entity must answer to the best of its ability, as directly or ambiguously as the Game Master feels fits the spirit''s personality and the feel of her campaign. A raise means the spirit is more helpful, knowledgeable, or direct than usual. The type of entity contacted determines how it communicates. Once a spirit has been conversed with it may not be contacted again for 24 hours.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(35,'Empathy',1,'Smarts','5 rounds','core','A successful arcane skill vs Spirit roll gives the caster insight into a target''s basic emotions. This grants him a +1 bonus (+2 with a raise) to all Intimidation, Persuasion, Performance, or Taunt rolls against the target for the Duration of the power. The bonus does not apply to skill rolls made to activate powers. Empathy works on animals, adding +1 (+2 with a raise) to Riding or other rolls to interact with the creature.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(36,'Illusion',3,'Smarts','5 rounds','core','Illusion can be used to create a visual scene or replica of most anything the caster can imagine, but it is silent, intangible, and incapable of affecting the real world. For example, illusionary weapons pass through foes, one cannot sit in an illusionary chair, and an illusionary dog has no audible bark. Those who contact an illusion or doubt it''s real make a Smarts roll as a free action (at −2 if the power was activated with a raise). If successful, that individual is no longer subject to this casting. The GM should make group rolls for Extras as they point out the illusion''s inconsistencies to each other. The volume of the illusion must fit within a sphere the size of a Medium Blast Template (4″ or eight yards in diameter).','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(37,'Mind Link',1,'Smarts','30 minutes','core','Mind link creates a telepathic connection between two individuals (which does not necessarily have to include the caster). The link accommodates only willing subjects and communication—thoughts that aren''t consciously transmitted aren''t relayed. Once activated, the Range between all linked minds is one mile, or five with a raise. If any of the linked characters suffers a Wound, all others must make a Smarts roll or be Shaken (this cannot cause a Wound). The speed of communication is that of normal speech, but with a raise members may communicate up to 30 seconds or so of speech on a single combat turn.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(38,'Mind Reading',2,'Smarts','Instant','core','Mind reading is an opposed roll versus the target''s Smarts. Success allows the character to gain one truthful answer from the subject. The target is aware of the mental intrusion unless the mind reader gets a raise. The GM may apply modifiers based on the subject''s mental Hindrances or current state of mind.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(39,'Mind Wipe',3,'Smarts','Instant','core','Mind wipe removes a target''s memories, a terrible violation in most civilized societies. The power is opposed by a victim''s Smarts and requires a full minute of mental struggle. The victim must be conscious during this time, so if not restrained he can simply move out of Range. If successful, the victim forgets a single event (up to about 30 minutes of time). A raise removes a complex memory of several hours. If the defender wins, the caster cannot affect him with mind wipe for 24 hours. (Others may reattempt the power, however.) The memory remains missing but strong evidence that challenges its "logic" allows a Smarts roll at −2 to remember fragments determined by the GM.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(40,'Object Reading',2,'Touch','Special','core','Object reading is the ability to get visions of the past from an object. With a successful casting, the reader gains a vague impression of whatever information she''s looking for, from the object''s creation forward. A raise grants her more specific information. If the caster uses object reading on a bloody knife to investigate a murder, for example, success might tell her the wielder was a human and that the attack happened at night. With a raise, she might get a glimpse of the killer''s face or some other clue that reveals his identity.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(41,'Beast Friend',0,'Smarts','10 minutes','core','This spell allows an individual to speak with and guide the actions of nature''s beasts. The cost to cast is equal to the sum of their Size (minimum 1 per creature). Success means the creatures obey simple commands, like a well-trained dog. They attack foes and endanger their lives for their master. A raise on the arcane skill roll means the beasts are more obedient. They won''t kill themselves but overcome their natural fears to follow their orders. Swarms can also be controlled. Small Swarms cost 1 point, Medium Swarms cost 2, and Large Swarms cost 3. Beast friend works only on natural creatures with animal intelligence, not humanoids, and has no effect on conjured, magical, or otherwise "unnatural" animals.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(42,'Elemental Manipulation',1,'Smarts','5','core','This power grants basic control over the four traditional elements: air, earth, fire, and water. The power acts as if it has a d6 Strength, or d8 with a raise. Upon activation, the caster may choose one of the following effects: ATTACK (2d4 damage, 3d4 with raise), MOVE (move a cubic foot of element up to Smarts), PUSH (use activation roll in place of Strength for Push), or SPECIAL EFFECTS (create additional effects with GM approval). After activation, each additional use of the effects above is an action.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(43,'Drain Power Points',2,'Smarts','Instant','core','Powerful mages, priests, psykers, and the like can use this ability to drain arcane energy from their foes. They can sometimes even leech the stolen energy for themselves. The power is opposed by the target''s Spirit, and the caster suffers a −2 penalty to her roll if the target has a different Arcane Background than her own. Success drains 1d6 of the rival''s Power Points (if any, and the die doesn''t Ace). With a raise, the caster adds the points to her own. This may take her above her usual maximum! They last until used, and must be the first spent when casting. Drain Power Points can also be used on an arcane device (the creator still resists with her arcane skill whether she wields it or not).','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(44,'Summon Ally',2,'Smarts','5','core','This power allows the character to summon a magical servant from nothing. The ally typically takes the form of a basic humanoid of the appropriate Size but can appear differently based on the caster''s trappings. It materializes anywhere in Range, and with a raise on the arcane skill roll, it''s Resilient as well. The ally acts on its creator''s Action Card and follows her commands to the best of its ability. It has no personality, creativity, or emotions. When the power ends or the ally is Incapacitated, it fades into nothingness, leaving no trace behind. Cost varies: Attendant (1), Bodyguard (3), Sentinel (5), Mirror Self (7).','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(45,'Telekinesis',5,'Smarts ×2','5','core','Telekinesis is the ability to move objects or creatures (including oneself) with arcane will. It has a Strength of d10, or d12 with a raise. On activation, the caster may immediately perform one of the uses below. Subsequent uses are actions and use the arcane skill (but are not activation rolls and therefore not subject to Backlash from Critical Failures). Unwilling beings targeted by telekinesis resist the caster''s arcane skill with an opposed Spirit roll when first targeted, and at the start of each of their turns thereafter until they''re released. USES: BASH (Str+d6 damage), CHANGE TARGETS (release/pick up as free action, new unwilling target is action), MANIPULATE (use tools or wield weapon with arcane skill), MOVE (move target up to Smarts as limited free action).','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(46,'Zombie',3,'Smarts','One hour','core','Zombie grants animation and basic intelligence to the remains of a once-living being. The summoned horror is obedient but literal-minded in its duties. It isn''t telepathic, and must be controlled by voice. The being has the physical skills it had in life, but its Smarts, Spirit, and related skills are reset to d4. A raise on the casting roll increases any one of their Traits by one die type (caster''s choice). Corpses aren''t summoned by this ability, so there must actually be a supply of bodies in Range. An undead Incapacitated by damage can''t be raised with this power again. Animals and other creatures can also be raised: 2 PP for Size -1/-2, 3 PP for Size 0, 1/2/3 PP for Small/Medium/Large Swarms. Larger creatures cost 3 + Size in Power Points.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(47,'Burrow',2,'Smarts','5','core','Burrow allows the recipient to meld into raw earth. He can remain underground if he wants in a sort of "limbo" or burrow through the ground at half his normal Pace (or full Pace with a raise). He may not run. A burrowing character may attempt to surprise a foe by making an opposed Stealth versus Notice roll. If the burrower wins, the target is Vulnerable to him only. With a raise, the burrower gets the Drop.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(48,'Disguise',2,'Smarts','10 minutes','core','Disguise allows the target to assume the appearance of another person of the same Size and shape, including clothing. It does not confer any abilities, however. Those who have reason to question the imposter''s identity make a Notice roll at −2 to see through the disguise (−4 with a raise on the casting roll). This is a free action.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(49,'Environmental Protection',2,'Smarts','One hour','core','Environmental protection allows the target to breathe, speak, and move at his normal Pace in an otherwise fatal environment. It protects him against intense heat, cold, radiation, atmospheric or fluid pressure, and lack of oxygen. Generally, the adventurer can function normally underwater, in space, within the cone of a volcano, etc. Environmental protection reduces damage from like sources by 4 (6 with a raise).','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(50,'Intangibility',5,'Smarts','5','core','With a successful arcane skill roll, whoever receives this power becomes incorporeal. He is unable to affect the physical world, and it cannot affect him. He can travel through walls, and non-magical weapons pass straight through him. While incorporeal, the character may affect other incorporeal beings (including himself), and is still susceptible to supernatural attacks, including powers and enchanted items. With a raise on the activation roll, reduce the damage from supernatural attacks by 4. If cast on an unwilling target, the victim resists with Spirit.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(51,'Invisibility',5,'Smarts','5','core','With a success, the character and his personal items are transparent except for a vague blur or outline. Any action taken against him that requires sight is made at −4, or −6 with a raise. The same penalty applies to Notice rolls to detect the unseen presence.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(52,'Light/Darkness',2,'Smarts','10 minutes','core','Light creates bright illumination in a Large Blast Template. With a raise, the light can be focused into a 5″ (10 yard) beam as well. Darkness blocks illumination in an area the size of a Large Blast Template, making the area Dark, or Pitch Darkness with a raise. If light and darkness overlap, they create a patch of Dim light (−2).','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(53,'Shape Change',3,'Self','5','core','This power allows the caster to take the form of other living creatures. He can only turn into the base form of such beings, not elite or alternative versions. With a raise, the caster can increase the creature''s Strength and Vigor by one die type each. The Size of the new form is limited by the caster''s Rank: Novice (Size −4 to −1, 3 PP), Seasoned (Size 0, 5 PP), Veteran (Size 1-2, 8 PP), Heroic (Size 3-4, 11 PP), Legendary (Size 5-10, 15 PP). While transformed, the character retains his Edges, Hindrances, Smarts, Spirit, and their linked skills. He gains the creature''s Agility, Strength, Vigor, and linked skills, as well as any powers common to that creature.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(54,'Teleport',2,'Smarts','Instant','core','Teleport allows a character to disappear and instantly reappear up to 12″ (24 yards) distant, or double that with a raise. Teleporting to an unseen location incurs a −2 penalty on the arcane skill roll. Opponents adjacent to a character who teleports away don''t get a free attack. If casting teleport on a willing subject, the caster decides where they move to, not the target.','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(55,'Arcane Protection',1,'Smarts','5','fantasy_companion','Success with arcane protection means hostile powers suffer a -2 penalty (-4 with a raise) to affect this character. If the hostile power fails to affect the target due to this penalty, it still activates and consumes Power Points (and may still affect other targets). If the power causes harm, damage is also reduced a like amount for the protected target. Arcane protection stacks with Arcane Resistance should the recipient have both\!','2026-01-04 19:23:28','2026-01-04 19:23:28',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(56,'Blast',3,'Smarts x2','Instant','fantasy_companion','Blast launches a ball of explosive energy or matter. The area of effect is a Medium Blast Template. Every target within suffers 2d6 damage, or 3d6 with a raise.','2026-01-04 19:23:48','2026-01-04 19:23:48',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(57,'Bolt',1,'Smarts x2','Instant','fantasy_companion','Bolt sends damaging bursts of energy, streaks of holy light, or shards of matter toward one''s foes. There are no Range penalties, but the arcane skill roll is affected by Cover, Illumination, and all other usual penalties. The damage of the bolt is 2d6, or 3d6 with a raise.','2026-01-04 19:23:59','2026-01-04 19:23:59',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(58,'Burst',2,'Cone or Stream Template','Instant','fantasy_companion','Burst produces a large fan of energy that bathes its targets in red-hot fire, a cone of cold, or other damaging matter or energy. Success creates a Cone or Stream Template starting at the caster and extending outward. Everything within suffers 2d6 damage (or 3d6 with a raise). This can be nonlethal damage if the caster wishes.','2026-01-04 19:24:08','2026-01-04 19:24:08',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(59,'Damage Field',4,'Smarts','5','fantasy_companion','Damage field creates a dangerous aura around the recipient that harms foes foolish enough to get too close. At the end of the affected character''s turn, all adjacent beings (including allies!) automatically take 2d4 damage.','2026-01-04 19:24:22','2026-01-04 19:24:22',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(60,'Deflection',2,'Smarts','5','fantasy_companion','Deflection powers work in a variety of ways. Some manifestations actually deflect incoming attacks, others blur the target''s form or produce illusionary effects. The end result is always the same, however-to misdirect incoming melee or missile attacks from the recipient of the power. With success, foes must subtract 2 from either melee or ranged attacks (caster''s choice). With a raise, the penalty applies to both. An attacker who uses a ranged weapon in melee against the recipient suffers the penalty either way.','2026-01-04 19:24:32','2026-01-04 19:24:32',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(61,'Protection',1,'Smarts','5','fantasy_companion','Protection creates a field of energy or armor around a character, giving him 2 points of Armor. With a raise, the bonus is applied to Toughness instead (and therefore immune to Armor Piercing modifiers). Whether the protection is visible or not depends on the Trapping-this is entirely up to the caster. Protection stacks with all other armor, natural or worn.','2026-01-04 19:24:50','2026-01-04 19:24:50',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(62,'Smite',2,'Smarts','5','fantasy_companion','This power is cast on a weapon of some sort. If it''s a ranged weapon, it affects one entire magazine, 20 bolts, shells, or arrows, or one full "load" of ammunition (the GM determines the exact quantity for unusual weapons). While the power is in effect, the weapon''s damage is increased by +2, or +4 with a raise. Limitations: In the case of smite with a Personal Limitation, "Self" refers to the specific weapon instead.','2026-01-04 19:24:50','2026-01-04 19:24:50',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(63,'Stun',2,'Smarts','Instant','fantasy_companion','Stun shocks a target with concussive force, sound, light, magical energy, or the like. A successful casting means the victim must make a Vigor roll (at -2 with a raise on the arcane skill roll) or be Stunned.','2026-01-04 19:24:50','2026-01-04 19:24:50',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(64,'Banish',3,'Smarts','Instant','fantasy_companion','Banish sends entities from other planes back to their native dimensions. This includes ghosts, demons, elementals, and similar beings (at the GM''s discretion). Banishing a being is an opposed roll of the caster''s arcane skill versus the target''s Spirit. Success means the target is Shaken, and each raise causes a Wound. If this Incapacitates the target it returns to its native plane of existence. Banished entities may return when the Game Master feels it''s appropriate, or even a few rounds later if the being has the ability to travel between planes on its own! Exorcism: Binding a powerful entity and then subjecting it to banish allows the caster to vanquish particularly powerful entities.','2026-01-04 19:25:30','2026-01-04 19:25:30',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(65,'Barrier',2,'Smarts','5','fantasy_companion','Barrier creates a straight wall 5" (10 yards) long and 1" (two yards) tall, of immobile material that conforms to the surface it''s cast upon. Double the length with a raise. Thickness varies depending on what the wall is made of, but is usually a few inches. The wall is Hardness 10 (or 12 with a raise), and may be destroyed as any other object. Each Wound destroys a 1" (2 yard) square section of wall (piercing weapons can''t damage the barrier). When the spell expires or the wall is broken it crumbles to dust or dissipates. Trappings are never left behind.','2026-01-04 19:25:30','2026-01-04 19:25:30',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(66,'Blind',2,'Smarts','Instant','fantasy_companion','Those affected by this malicious power suffer blurred vision or near-complete blindness with a raise. Success means the victim suffers a -2 penalty to all actions involving sight, or -4 with a raise. The victim automatically tries to shake off the effect with a Vigor roll as a free action at the end of his following turns. Success removes 2 points of penalties, and a raise removes the effect entirely.','2026-01-04 19:25:53','2026-01-04 19:25:53',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(67,'Confusion',2,'Smarts','Instant','fantasy_companion','Confusion confounds all targets in a Medium Blast Template, making them either Distracted or Vulnerable, or both with a raise. The same effect applies to all those affected. These states are removed at the end of the victims'' next turn as usual.','2026-01-04 19:25:53','2026-01-04 19:25:53',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(68,'Curse',5,'Touch','Special','fantasy_companion','Some practitioners of the arcane arts heal their allies. Others inflict sickness upon their enemies. To use this particularly nasty spell, the caster makes an arcane skill roll opposed by the victim''s Spirit roll. Failure means the victim suffers a level of Fatigue immediately and at sunset each day thereafter (further castings of curse have no additional effect). The Fatigue cannot be recovered until the curse is lifted, and once the accursed becomes Incapacitated, he makes a Vigor roll each day to avoid death. A caster may not attempt to curse an individual more than once per day. Breaking the Curse: The curse can be lifted by the original caster at will, and ends automatically if she''s slain. Dispel also removes a curse (at -2), though each individual may only try once-if failed it''s beyond her abilities.','2026-01-04 19:25:53','2026-01-04 19:25:53',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(69,'Dispel',1,'Smarts','Instant','fantasy_companion','Dispel allows a hero to negate enemy powers. It has no effect on permanent enchantments or natural abilities such as a dragon''s breath or a banshee''s scream. Dispel affects arcane devices normally. Dispel can be used on a power already in effect or to counter an enemy power as it''s being used. The latter requires the countering mage to be on Hold and interrupt his foe''s action. In either case, dispelling an opponent''s power is an opposed roll of arcane skills. A target using the Mystic Powers Edge or innate powers uses Spirit as its arcane skill. If the dispelling character wins, the targeted power ends immediately (or fails if it was countered with the usual results of failure). With a raise, the caster may also make the recipient of the dispelled power Distracted.','2026-01-04 19:26:12','2026-01-04 19:26:12',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(70,'Drain Power Points',2,'Smarts','Instant','fantasy_companion','Powerful mages, priests, and other arcanists can use this ability to drain magical energy from their foes. With a raise they can even leech the stolen energy for themselves. The power is opposed by the target''s Spirit, and the caster suffers a -2 penalty to her roll if the target has a different Arcane Background than her own. Success drains 1d6 of the rival''s Power Points (if any, and the die doesn''t Ace). With a raise, the caster adds the points to her own. This may take her above her usual maximum! They last until used, and must be the first spent when casting. Drain Power Points can also be used on an arcane device (the creator still resists with her arcane skill whether she wields it or not). The device can''t lose more Power Points than it has.','2026-01-04 19:26:30','2026-01-04 19:26:30',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(71,'Entangle',2,'Smarts','Instant','fantasy_companion','Entangle restrains a target with ice, bands of energy, vines, disembodied hands, etc (Hardness 8). If cast successfully, the target is Entangled (or Bound with a raise on the arcane skill roll). Victims may try to break free on their turn as detailed under Bound & Entangled.','2026-01-04 19:26:31','2026-01-04 19:26:31',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(72,'Fear',2,'Smarts','Instant','fantasy_companion','This power causes overwhelming dread and horror. The target makes a Fear check. Extras who fail are Panicked and Wild Cards roll on the Fear Table. If the power is cast with a raise, the Fear roll is at -2 and Wild Cards add +2 to Fear Table results.','2026-01-04 19:26:48','2026-01-04 19:26:48',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(73,'Havoc',2,'Smarts','Instant','fantasy_companion','This ability creates chaos and mischief for all those within its area of effect, hurling debris and rivals in all directions. With success, the caster places a Medium Blast Template anywhere within Range, or a Cone or Stream Template emanating from the caster. Anyone touched by the template is Distracted and must then make a Strength roll (at -2 if the caster got a raise). Those who fail are hurled 2d6"-directly away from the caster if using the Cone Template or directly away from the center if using a Blast Template (the caster chooses for those in the dead center). Victims who strike a hard object (such as a wall) take 2d4 damage (nonlethal unless it''s a spiked wall or other more dangerous hazard). Airborne Targets: Flying or airborne targets suffer an additional -2 to their Strength rolls as they have no ground to brace themselves on.','2026-01-04 19:26:48','2026-01-04 19:26:48',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(74,'Puppet',3,'Smarts','5','fantasy_companion','Puppet is an opposed roll of the character''s arcane skill versus the target''s Spirit. With success, the victim obeys commands that don''t directly harm himself or those he cares about. This includes inaction, as well, so a controlled character won''t just stand idly by or leave the area if he knows his friends are in danger. With a raise, the target will harm himself or others (including by inaction), but gets a Spirit roll as a free action. If successful, he doesn''t carry out that particular command but remains a puppet. With a raise, he breaks the controller''s hold and the power ends. Commands are general, such as "attack that person" or "open that door." The controller doesn''t get to dictate how many actions the victim uses in a turn, whether or not he uses his Sweep Edge, etc.','2026-01-04 19:27:09','2026-01-04 19:27:09',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(75,'Slumber',2,'Smarts','One hour','fantasy_companion','Those who favor stealth or want to avoid harming their foes are drawn to this spell, which puts its victims into a deep and restful sleep. Anyone affected by slumber must make a Spirit roll (at -2 if the caster got a raise on her arcane skill roll). Those who fail fall asleep for the Duration of the spell. Very loud noises or attempts to physically wake a sleeper (by shaking him, for example), grant another Spirit roll.','2026-01-04 19:27:09','2026-01-04 19:27:09',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(76,'Boost/Lower Trait',3,'Smarts','5 (boost); Instant (lower)','fantasy_companion','This power allows a character to increase or decrease a target''s Trait (attribute or skill). Boosting an ally''s Trait increases the selected Trait one die type, or two with a raise. Lowering an enemy''s Trait has a Duration of Instant and lowers the selected attribute or skill a die type with success, or two with a raise (to a minimum of d4). A victim automatically tries to shake off the effect with a Spirit roll as a free action at the end of his following turns. Success improves the effect one die type, and a raise removes the effect entirely. Additional castings don''t stack on a single Trait (take the highest), but may affect different Traits.','2026-01-04 19:27:41','2026-01-04 19:27:41',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(77,'Darksight',1,'Smarts','One hour','fantasy_companion','Darksight allows a hero to see in the dark. With success, he ignores up to 4 points of illumination penalties. With a raise, he ignores up to 6 points and can see in pitch darkness.','2026-01-04 19:27:41','2026-01-04 19:27:41',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(78,'Farsight',2,'Smarts','5','fantasy_companion','Farsight allows the recipient to see in detail over great distances. She can read lips or fine print up to a mile distant. With a raise, she also halves Range penalties for Shooting, Athletics (throwing), or other abilities affected by Range.','2026-01-04 19:27:58','2026-01-04 19:27:58',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(79,'Fly',3,'Smarts','5','fantasy_companion','Fly allows a character to soar at Pace 12, or twice that with a raise (he may not run).','2026-01-04 19:27:58','2026-01-04 19:27:58',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(80,'Growth/Shrink',2,'Smarts','5','fantasy_companion','Growth increases the recipient''s Size by 1 for every 2 Power Points spent. Each increase in Size grants the target a one-step increase to Strength and 1 point of Toughness. This does not increase Wounds regardless of change to Scale. Shrink reduces the Size of the subject one step for every 2 Power Points spent to a maximum of Size -2 (approximately the size of a cat). Each step reduced decreases Strength one die type (minimum of d4) and Toughness by 1 (minimum of 2). For unwilling targets, the caster''s arcane skill roll is opposed by Spirit.','2026-01-04 19:27:58','2026-01-04 19:27:58',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(81,'Relief',1,'Smarts','Instant/One Hour','fantasy_companion','Select one of the options listed below: Recover: The caster removes one of the following negative conditions: Shaken, Distracted, or Vulnerable. A raise on the arcane skill roll removes two conditions. If affecting more than one target (via the Additional Recipients modifier), the caster may choose which condition(s) to remove for each ally. Relief can''t remove negative conditions caused by ongoing effects (Bound or Entangled, Stunned, etc.) unless the source of the effect is removed. Numb: Reduces the recipient''s total penalties from Wounds and Fatigue by one with success, or two with a raise. An Exhausted (-2) hero with three Wounds (-3), for example, reduces her total -5 penalty to -4 with success, and -3 with a raise. This effect lasts one hour. It doesn''t remove the Wounds or the Fatigue, it just allows the recipient to ignore the penalties. If the target would be Incapacitated by Wounds or Fatigue, she''s still Incapacitated as usual.','2026-01-04 19:28:20','2026-01-04 19:28:20',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(82,'Sanctuary',2,'Touch','5','fantasy_companion','Sanctuary protects the righteous from the vile clutches of malignant entities. Any supernaturally evil creature attempting a damaging attack that affects the recipient must make a Spirit roll (at -2 if cast with a raise). If the roll fails, the creature loses that action. (The GM should use Group rolls for identical Extras.) If anyone under the effects of sanctuary attempts to harm another creature (evil or otherwise), he loses the benefit of sanctuary instantly. Sanctuary trumps any ward the caster may be holding or protected by. If the creature overcomes the sanctuary spell, it ignores any wards that turn as well.','2026-01-04 19:28:20','2026-01-04 19:28:20',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(83,'Sloth/Speed',2,'Smarts','Instant (sloth); 5 (speed)','fantasy_companion','Sloth halves the target''s total movement (round up). With a raise, movement is also an action. The target automatically attempts to shake off the effects of sloth at the end of each of his next turns by making a Spirit roll. Speed doubles the target''s movement (Pace and running). With a raise the character also ignores the -2 running penalty.','2026-01-04 19:28:45','2026-01-04 19:28:45',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(84,'Sound/Silence',1,'Smarts x5 (sound); Smarts (silence)','5','fantasy_companion','Sound mimics any known sound or voice, emanating from a point of origin within Range at a volume up to the sound of a loud shout. If used as a Test, the defender opposes the casting roll with Smarts. Silence does the opposite, muting all sound up to a loud shout within a Large Blast Template. This subtracts 4 from Notice rolls made by those inside the area of effect, as well as anyone trying to hear sounds made from within. A raise completely mutes all sound inside the template (note that sound is usually required to cast spells).','2026-01-04 19:28:45','2026-01-04 19:28:45',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(85,'Wall Walker',2,'Smarts','5','fantasy_companion','Wall walker allows the recipient to walk on vertical or horizontal surfaces. With success, she moves at half her normal Pace. With a raise, she may move at full Pace and even run. If forced to make an Athletics roll to climb or hang on to a surface, she adds +4 to the total.','2026-01-04 19:28:57','2026-01-04 19:28:57',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(86,'Warrior''s Gift',4,'Smarts','5','fantasy_companion','With a successful arcane skill roll, the recipient gains the benefits of a single Combat Edge chosen by the caster. The caster (not the recipient) must have the same Rank or higher as the Edge''s Requirements. With a raise, the recipient gains the Improved version of the Edge (if there is one, and even if he doesn''t meet the Rank Requirement).','2026-01-04 19:28:57','2026-01-04 19:28:57',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(87,'Blessing',10,'One town/community','One Year','fantasy_companion','The people of the land depend on priests, shamans, and other caretakers to bless their communities. This powerful spell allows them to bless the crops, the children, and the beasts of the fields. The caretaker must spend an hour walking about the town and its fields, praying, chanting, sprinkling holy water, shaking sage, and otherwise asking for divine or magical aid. The arcane skill roll is attempted afterwards. If failed, the caster must start over. With a Critical Failure, the priest may not cast a blessing on these subjects again for one year. If successful, the area''s crops come in hale and hearty, sickness and disease are rare, and couples are fertile and prosperous. The effects are relative. Casting blessing in a blighted land may only raise a community to subsistence-level.','2026-01-04 19:29:32','2026-01-04 19:29:32',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(88,'Healing',3,'Touch','Instant','fantasy_companion','Healing removes Wounds less than an hour old. A success removes one Wound, and a raise removes two. The power may be cast additional times to remove additional Wounds within that hour and as long as the healer has enough Power Points. For Extras, the GM must first determine if the ally is still alive. If so, a successful arcane skill roll returns the ally to action (Shaken if it matters.)','2026-01-04 19:29:32','2026-01-04 19:29:32',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(89,'Mystic Intervention',20,'Special','Special','fantasy_companion','Sometimes even a wizard''s best spell or a cleric''s greatest miracle isn''t enough. In times of great need, casters must turn to greater powers than their own and ask a patron to take a personal hand in the affairs of mortals. Mystic intervention allows a caster to trigger a great event of some sort. The event should be epic in proportions, such as saving a city from a siege, causing an earthquake to level a city, raising a legendary creature such as the Century Beast, bringing a centuries-dead hero to life, or closing the gate to some terrible and otherworldly dimension. The caster must stand in a magical ring, divine circle, sacred site, or other place of power and use the Ritual Magic rules to cast. If successful, he''s Exhausted for the next five days but some version of the desired effect, narrated by the Game Master as she sees fit, happens with a great display of magical energy. If the ritual''s Dramatic Task fails, the caster is Exhausted as above and may not attempt the ritual again for five days or until some narrative goal is met. A Critical Failure also causes 5d6 damage in a 20" (40 yard) radius as well. Mystic intervention should give the heroes respite from major events but shouldn''t short circuit the Game Master''s entire campaign. Finally, the power can''t override free will.','2026-01-04 19:29:54','2026-01-04 19:29:54',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(90,'Resurrection',30,'Touch','Instant','fantasy_companion','Perhaps the greatest power of all is the ability to bring someone back from the dead. It can restore lost loves, bring solace to the grieving, and topple kingdoms. Returning the dead to life requires a reasonably complete corpse no more than a year old. The caster then prays, meditates, chants, or otherwise concentrates on mending the body and pulling the spirit back to it for four hours. Once done, the healer makes a casting roll at -4. If successful, the victim returns to life with three Wounds and is Exhausted. With a raise, she returns merely Exhausted.','2026-01-04 19:29:54','2026-01-04 19:29:54',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(91,'Beast Friend',0,'Smarts','10 minutes','fantasy_companion','This spell allows an individual to speak with and guide the actions of nature''s beasts. The cost to cast is equal to the sum of their Size (minimum 1 per creature). Controlling five wolves (Size -1) costs 5 points, for example. Controlling a rhino (Size 5) also costs 5 points. Success means the creatures obey simple commands, like a well-trained dog. They attack foes and endanger their lives for their master. A raise on the arcane skill roll means the beasts are more obedient. They won''t kill themselves but overcome their natural fears (fire, fearful creatures, etc.) to follow their orders. Swarms can also be controlled. Small Swarms cost 1 point, Medium Swarms cost 2, and Large Swarms cost 3. Beast friend works only on natural creatures with animal intelligence, not humanoids, and has no effect on conjured, magical, or otherwise "unnatural" animals unless the Bestarium modifier is applied.','2026-01-04 19:30:28','2026-01-04 19:30:28',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(92,'Empathy',1,'Smarts','5','fantasy_companion','A successful arcane skill vs Spirit roll gives the caster insight into a target''s basic emotions. This grants him a +1 bonus (+2 with a raise) to all Intimidation, Persuasion, Performance, or Taunt rolls against the target for the Duration of the power. The bonus does not apply to skill rolls made to activate powers. Empathy works on animals, adding +1 (+2 with a raise) to Riding or other rolls to interact with the creature.','2026-01-04 19:30:28','2026-01-04 19:30:28',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(93,'Mind Link',1,'Smarts','30 minutes','fantasy_companion','Mind link creates a telepathic connection between two individuals (which does not necessarily have to include the caster). The link accommodates only willing subjects and communication-thoughts that aren''t consciously transmitted aren''t relayed. Once activated, the Range between linked minds is one mile, or five with a raise. If any of the linked characters suffers a Wound, all others must make a Smarts roll or be Shaken (this cannot cause a Wound). The speed of communication is that of normal speech, but with a raise members may communicate up to 30 seconds or so of speech on a single combat turn.','2026-01-04 19:30:55','2026-01-04 19:30:55',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(94,'Mind Reading',2,'Smarts','Instant','fantasy_companion','Mind reading is an opposed roll versus the target''s Smarts. Success allows the character to gain one truthful answer from the subject. The target is aware of the mental intrusion unless the mind reader gets a raise. The GM may apply modifiers based on the subject''s mental Hindrances or current state of mind.','2026-01-04 19:30:55','2026-01-04 19:30:55',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(95,'Mind Wipe',3,'Smarts','Instant','fantasy_companion','Mind wipe removes a target''s memories, a terrible violation in most civilized societies. The power is opposed by a victim''s Smarts and requires a full minute of mental struggle. The victim must be conscious during this time, so if not restrained he can simply move out of Range. If successful, the victim forgets a single event (up to about 30 minutes of time). A raise removes a complex memory of several hours. If the defender wins, the caster cannot affect him with mind wipe for 24 hours. (Others may reattempt the power, however.) The memory remains missing, but strong evidence that challenges its "logic" allows a Smarts roll at -2 to remember fragments determined by the GM.','2026-01-04 19:30:55','2026-01-04 19:30:55',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(96,'Object Reading',2,'Touch','One minute','fantasy_companion','Object reading is the ability to get visions of the past from an object. With a successful casting, she gains a vague impression of whatever information she''s looking for, from the object''s creation forward. A raise grants her more specific information. If the caster uses object reading on a bloody knife to investigate a murder, for example, success might tell her the wielder was a human and that the attack happened at night. With a raise, she might get a glimpse of the killer''s face or some other clue that reveals his identity.','2026-01-04 19:31:18','2026-01-04 19:31:18',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(97,'Scrying',3,'Self','5','fantasy_companion','Scrying allows the caster to spy on a distant target. Preparing the spell takes one uninterrupted minute while the caster focuses on a target item, creature, or location. This makes her Vulnerable and unable to take any other actions. If she''s Shaken or Stunned during this time, the spell fails. If the caster has never seen the target herself, she makes her arcane skill roll at -2. Scrying on a target in a different plane of existence also imposes a -2 penalty. Scrying is opposed by the subject''s Spirit. If the target wins, he knows he''s being spied on. If the caster wins, he can see and hear the target and the area around it but cannot move his perspective. With a raise, he can shift perspective and follow the target at a Pace 24. A scrying "eye" may be seen with detect arcana (but not detect magic). It may also be dispelled (at -2 if suspected but not revealed).','2026-01-04 19:31:18','2026-01-04 19:31:18',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(98,'Speak Language',1,'Smarts','10 minutes','fantasy_companion','This power allows a character to speak, read, and write a sapient language other than his own. A raise on the arcane skill roll allows the user to appropriately use and understand slang and dialect as well.','2026-01-04 19:31:18','2026-01-04 19:31:18',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(99,'Planar Binding',8,'Smarts','Instant','fantasy_companion','The caster summons a creature from another plane, trapping them in some sort of prison, such as a binding circle. The trap is created with chalk, salt, glyphs and wards, or other materials prior to the power''s activation, and takes ten minutes to prepare. Once the trap is prepared, the caster selects an extra-planar entity to summon. She must know about the creature somehow, perhaps by previous encounter, access to books and scrolls, or an Occult roll with a modifier set by the Game Master. The caster can summon a specific entity if she knows its proper name. Casting is an opposed roll between the caster''s arcane skill and the entity''s Spirit. If the spell fails the entity knows who attempted to bind it. If the caster rolls a Critical Failure, the creature may appear if it chooses to and is unbound-it may do as it wishes and its abilities allow. If the caster wins, the creature is summoned into the trap and magically bound there until it agrees to perform a service for the caster or breaks free. The Power Points used to bind the target are invested and unavailable until the creature breaks free or completes its task. Bargaining and Breaking Free rules apply.','2026-01-04 19:31:59','2026-01-04 19:31:59',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(100,'Summon Ally',0,'Smarts','5','fantasy_companion','This power allows the character to summon a magical servant from nothing. The ally typically takes the form of a basic humanoid of the appropriate Size but can appear differently based on the caster''s Trappings. It materializes anywhere in Range, and with a raise on the arcane skill roll, it''s Resilient as well. The ally acts on its creator''s Action Card and follows her commands to the best of its ability. It has no personality, creativity, or emotions. When the power ends or the ally is Incapacitated, it fades into nothingness, leaving no trace behind. The type of servant that can be summoned depends on the caster''s Rank. Attendant (Novice, 1 PP), Bodyguard (Seasoned, 3 PP), Sentinel (Veteran, 5 PP), Mirror Self (Heroic, 7 PP). With the GM''s approval, the player may swap abilities.','2026-01-04 19:31:59','2026-01-04 19:31:59',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(101,'Summon Animal',0,'Smarts','5','fantasy_companion','This power channels beasts of the natural world to perform tasks or attack one''s foes. From mighty elephants to spying rats, such creatures are powerful friends in the right circumstances. Summoned animals are loyal to the caster (but may be taken over by beast friend using the Bestarium modifier or similar abilities). They understand simple commands such as attack a particular target, push that rock, and so on, but are animals and act as such. Summoned animals act on the caster''s Action Card. They (or their corpses) return to wherever they came from when the spell expires. Cost varies by animal type.','2026-01-04 19:32:16','2026-01-04 19:32:16',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(102,'Summon Monster',0,'Smarts','5','fantasy_companion','Conjuring a mindless ally or simple beast is one thing. But summoning a monster is a true display of a sorcerer''s power! Summoned monsters are loyal to the caster, understand simple commands, and act on the caster''s Action Card. Monsters return from whence they came when the spell expires. Cost varies by monster type.','2026-01-04 19:32:16','2026-01-04 19:32:16',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(103,'Summon Undead',0,'Smarts','5','fantasy_companion','The zombie power raises basic undead that serve a necromancer''s whims for an hour. Summon undead doesn''t require existing corpses but brings them from wherever they currently haunt to the necromancer''s current location. They serve for a limited time, but are much more powerful than those typically summoned in groaning hordes of walking dead or clinking mobs of chattering skeletons. Summoned undead appear as listed in the Bestiary, including any equipment in their profile. When the power expires, the loathsome dead fade back through shadow to their original location. Cost varies by undead type.','2026-01-04 19:32:43','2026-01-04 19:32:43',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(104,'Zombie',3,'Smarts','One hour','fantasy_companion','Zombie grants animation and basic intelligence to the remains of a once-living being. The summoned horror is obedient but literal-minded in its duties. It isn''t telepathic, and must be controlled by voice. The being has the physical skills it had in life, but its Smarts, Spirit, and related skills are reset to d4. A raise on the casting roll increases any one of their Traits one die type (caster''s choice). See Savage Worlds for the standard profile of a human zombie. Corpses aren''t summoned by this ability, so there must actually be a supply of bodies in Range (GM''s call). An undead Incapacitated by damage can''t be raised with this power again.','2026-01-04 19:32:43','2026-01-04 19:32:43',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(105,'Burrow',2,'Smarts','5','fantasy_companion','Burrow allows the recipient to meld into raw earth. He can remain underground if he wants or burrow through the ground at half his normal Pace (or full Pace with a raise). He may not run. A burrowing character may attempt to surprise a foe by making an opposed Stealth versus Notice roll. If the burrower wins, the target is Vulnerable to him only. With a raise, the burrower gets the Drop. Targets on Hold may attempt to interrupt the attack before it occurs. Burrowers can''t usually surprise a foe once their presence is known, but can still burrow for protection and mobility.','2026-01-04 19:33:25','2026-01-04 19:33:25',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(106,'Conjure Item',0,'Smarts','One hour','fantasy_companion','The caster can create an object weighing up to one pound for every 2 Power Points spent. The item must be a simple, mundane object made of a common material (GM''s call) such as a 10-foot pole, blanket, shield, etc. A raise on the arcane skill roll produces a more durable version of the conjured item. Conjured items appear in the hands of the caster or on the ground nearby. Once the Duration expires, the item fades from reality. This can cause issues, for example, if the conjured item holds a door open or was given as a gift.','2026-01-04 19:33:25','2026-01-04 19:33:25',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(107,'Detect/Conceal Arcana',2,'Smarts','5 (detect); one hour (conceal)','fantasy_companion','Detect arcana allows the recipient of the power to see and detect all supernatural persons, objects, or effects in sight for five rounds. This includes invisible foes, enchantments on people or items, and so on. With a raise, the caster knows the type of enchantment as well. When used on a supernatural creature, the caster learns its active powers and arcane abilities. With a raise, he knows Weaknesses common to that creature type. Invisible Creatures: Detect arcana also allows a character to ignore up to 4 points of penalties when attacking foes hidden by magical darkness, invisibility, or similar abilities (or all penalties with a raise). Conceal arcana obscures one being or item of Normal Scale or smaller from the detect magic ability for one hour. With a success, attempts to detect arcana on the being or item suffer a -2 penalty or -4 with a raise.','2026-01-04 19:33:49','2026-01-04 19:33:49',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(108,'Disguise',2,'Smarts','10 minutes','fantasy_companion','Disguise allows the target to assume the appearance of another person of the same Size and shape, including clothing. It does not confer any abilities, however. Those who have reason to question the imposter''s identity make a Notice roll at -2 to see through the disguise (-4 with a raise on the casting roll). This is a free action.','2026-01-04 19:33:49','2026-01-04 19:33:49',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(109,'Divination',5,'Self','A brief conversation of about five minutes','fantasy_companion','Divination allows the caster to contact otherworldly beings to gain information. Preparing the spell takes one uninterrupted minute of prayer, coaxing or otherwise communing with a spirit or deity. Once that''s done, the caster makes her arcane skill roll. Success means the entity answers the character''s questions to the best of its ability. A raise means the spirit is more helpful, knowledgeable, or direct than usual (whether it wants to be or not). Answers are typically vague or symbolic rather than direct-they provide clues and guidance rather than spoilers that ruin a mystery or adventure. Sacred Ground: Contacting a spirit on ground sacred to it adds +2 to the roll.','2026-01-04 19:34:15','2026-01-04 19:34:15',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(110,'Elemental Manipulation',1,'Smarts','5','fantasy_companion','This power grants basic control over the four traditional elements: air, earth, fire, and water. Upon activation, the caster may choose one of the following effects: Attack (2d4 damage, 3d4 with raise), Move (move a cubic foot of element up to Smarts), Push (use activation roll in place of Strength), Special Effects (various utility effects with GM approval). After activation, each additional use of the effects above are an action.','2026-01-04 19:34:15','2026-01-04 19:34:15',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(111,'Environmental Protection',2,'Smarts','One hour','fantasy_companion','Adventurers sometimes travel beneath the waves, through planes of fire, or other hazardous environments. This power protects them from crushing depths, scathing heat, intense cold, and even radiation. Environmental protection allows the target to breathe, speak, and move at his normal Pace in an otherwise fatal environment. It protects him from intense heat, cold, radiation, pressure, and lack of oxygen. A raise increases the Duration to eight hours.','2026-01-04 19:34:15','2026-01-04 19:34:15',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(112,'Illusion',3,'Smarts','5','fantasy_companion','One of the greatest powers is the ability to create something from nothing-even if it''s not real! Illusion can be used to create a visual scene or replica of most anything the caster can imagine, but it is silent, intangible, and incapable of affecting the real world. Those who contact an illusion or doubt it''s real make a Smarts roll as a free action (at -2 if the power was activated with a raise). If successful, that individual is no longer subject to this casting. The volume of the illusion must fit within a sphere the size of a Medium Blast Template (4" or eight yards in diameter).','2026-01-04 19:34:40','2026-01-04 19:34:40',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(113,'Intangibility',5,'Smarts','5','fantasy_companion','With a successful arcane skill roll, whoever receives this power becomes incorporeal. He is unable to affect the physical world, and it cannot affect him. He can travel through walls, and non-magical weapons pass straight through him. Items carried at the time of casting are also incorporeal. While incorporeal, the character may affect other incorporeal beings (including himself), and he is still susceptible to supernatural attacks, including powers and enchanted items. With a raise on the activation roll, reduce the damage from supernatural attacks by 4. The being becomes corporeal when the power ends, but if within someone or something he''s shunted to the nearest open space and Stunned. If cast on an unwilling target, the victim resists with Spirit. If affected, he shakes off the effect with a Spirit roll as a free action at the end of his following turns.','2026-01-04 19:34:40','2026-01-04 19:34:40',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(114,'Invisibility',5,'Smarts','5','fantasy_companion','With a success, the character and his personal items are transparent except for a vague blur or outline. Any action taken against him that requires sight is made at -4, or -6 with a raise. The same penalty applies to Notice rolls to detect the unseen presence. Reduce the penalty by 2 if the invisible character''s position is given away, such as moving in water, hit with mud or another substance that reveals her form, outside in the rain, etc.','2026-01-04 19:35:08','2026-01-04 19:35:08',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(115,'Light/Darkness',2,'Smarts','10 minutes','fantasy_companion','Light creates bright illumination in a Large Blast Template. With a raise, the light can be focused into a 5" (10 yard) beam as well. Darkness blocks illumination in an area the size of a Large Blast Template, making the area Dark, or Pitch Dark with a raise. If light and darkness overlap, they create a patch of Dim light (-2).','2026-01-04 19:35:08','2026-01-04 19:35:08',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(116,'Locate',3,'Self','10 minutes','fantasy_companion','Sometimes it''s literally impossible to find something via mundane means. Locate allows the caster to find these hidden things. When casters use this power, they choose an object, creature, or location. If the caster has never seen the target, the roll is at -2. A success gives a sense of the general direction and distance of the target. A raise also gives an impression of the target (e.g., the creature is asleep, the object is in a strongbox, the secret door is behind a bookshelf). Running Water: Running water blocks locate if it''s in the path between the caster and the target.','2026-01-04 19:35:08','2026-01-04 19:35:08',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(117,'Lock/Unlock',1,'Smarts','Permanent (lock); Instant (unlock)','fantasy_companion','Lock magically seals a door, window, container, envelope, drawer, box, or other closeable item. Any attempt to open a magically locked container, door, or portal suffers a -4 penalty. Any further attempts by a character automatically fail until he increases his skill, at which point he may try again. A raise when magically locking the container means it can only be unlocked via the unlock version of this power (at the -4 penalty listed above) or by dispelling the magical lock then picking the lock as usual. The caster may set a passphrase when the lock is created; the portal opens for anyone who speaks the phrase and remains open until the phrase is repeated. Unlock may be used in place of Repair or Thievery (but not Strength) when attempting to unlock a container, door, or portal. It ignores up to 4 points of penalties to the task, and with a raise disarms any traps or alarms on the portal as well. Neither spell has any effect on barriers, barricades, or blockages-only distinct objects which can be opened or shut.','2026-01-04 19:35:34','2026-01-04 19:35:34',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(118,'Plane Shift',4,'Smarts','Instant','fantasy_companion','Plane shift allows the caster to move directly to another plane of existence. If plane shifting to a known location, a success indicates the target appears within 10d10 miles of the intended location. With a raise, the mage appears half that distance away. If the caster doesn''t know exactly where he wants to go, he can research locations within that plane to target a specific city, geographic location, etc. Failure means he arrives at a completely random location on that plane. Success puts him in the general vicinity, and a raise puts him closer to the desired locale. This is in addition to the accuracy of the arcane skill roll.','2026-01-04 19:35:34','2026-01-04 19:35:34',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(119,'Shape Change',0,'Self','5','fantasy_companion','This power allows the caster to take the form of other living creatures. She can only turn into the base form of such beings, not elite or alternative versions (so a goblin is allowed but a goblin shaman is not). With a raise, the caster can increase the creature''s Strength and Vigor by one die type each. The size of the new form is limited by the caster''s Rank: Novice (Size -4 to -1, 3 PP), Seasoned (Size 0, 5 PP), Veteran (Size 1-2, 8 PP), Heroic (Size 3-4, 11 PP), Legendary (Size 5-10, 15 PP). Held and worn items are assumed into the new form and reappear when the power ends. While transformed, the character retains his Edges and Hindrances, and his Smarts, Spirit, and their linked skills. He gains the creature''s Agility, Strength, Vigor, and linked skills, as well as any powers common to that creature. The caster does not gain a target''s Power Points if it has them-he retains his own.','2026-01-04 19:36:01','2026-01-04 19:36:01',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(120,'Telekinesis',5,'Smarts x2','5','fantasy_companion','Telekinesis is the ability to move objects or creatures (including oneself) with arcane will. It has a Strength of d10, or d12 with a raise. On activation, the caster may immediately perform one of the "uses" below. Subsequent uses are actions and use the arcane skill (but are not activation rolls and therefore not subject to Backlash from Critical Failures). Unwilling beings targeted by telekinesis resist the caster''s arcane skill with an opposed Spirit roll when first targeted, and at the start of each of their turns thereafter until they''re released. Uses: Bash (Str+d6 damage), Change Targets (release or pick up new target), Manipulate (use tools or wield weapon with arcane skill), Move (move target up to Smarts as limited free action).','2026-01-04 19:36:01','2026-01-04 19:36:01',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(121,'Teleport',2,'Smarts','Instant','fantasy_companion','Teleport allows a character to disappear and instantly reappear up to 12" (24 yards) distant, or double that with a raise. Opponents adjacent to a character who teleports away don''t get a free attack. If casting teleport on willing subjects, the caster decides where they move to, not the targets.','2026-01-04 19:36:29','2026-01-04 19:36:29',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(122,'Time Stop',10,'Self','Instant','fantasy_companion','Time is a constant for most, but not this spellcaster. Time stop stops time for all but the caster, granting her 1d4+1 new turns, which she must take immediately after she finishes this one. During these turns the caster may act as normal on her current Action Card. With a raise, she may choose to draw a new Action Card each turn. Time stop ends if any of the caster''s action affect another character or creature.','2026-01-04 19:36:29','2026-01-04 19:36:29',NULL);
+INSERT INTO powers(id,name,power_points,"range",duration,source,description,created_at,updated_at,maintenance_power_points) VALUES(123,'Wish',20,'Smarts','Instant','fantasy_companion','Wish allows a character to bend reality to his will. It is taxing to alter reality, however, so the caster must permanently sacrifice 3 of his own Power Points (after the spell is cast). A raise on the arcane skill roll eliminates this cost. A caster may wish for any one of the following effects per casting, chosen before the roll is made: Activate a Power (any power with a raise, opponents resist at -2), Intercession (aid appears after a short period), Gain an Edge (permanent Edge for recipient who meets Requirements), Wealth (up to 10,000 gp worth of goods), Travel Back Through Time (one minute or to start of combat round), See the Future (near future grants 3 Conviction tokens or far future answers one question), Raise a Trait (permanently increase one Trait one die type), Restore Fate (refill Bennies for hero and Wild Cards in Range), Special (minor reality changes at GM discretion).','2026-01-04 19:36:29','2026-01-04 19:36:29',NULL);
+COMMIT;
@@ -0,0 +1,782 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE requirement_expressions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    parent_id INTEGER, -- NULL for root nodes
+    node_type VARCHAR(15) NOT NULL CHECK (node_type IN ('requirement', 'and', 'or', 'not')),
+    requirement_id INTEGER, -- FK to requirements table, NULL for operators
+    position INTEGER NOT NULL, -- order within parent (0, 1, 2, etc.)
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (parent_id) REFERENCES requirement_expressions(id),
+    FOREIGN KEY (requirement_id) REFERENCES requirements(id),
+    -- Constraints to ensure data integrity
+    CHECK (
+        (node_type = 'requirement' AND requirement_id IS NOT NULL) OR
+        (node_type != 'requirement' AND requirement_id IS NULL)
+    )
+);
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(1,NULL,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(2,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(3,2,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(4,2,'requirement',3,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(5,NULL,'requirement',4,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(6,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(7,6,'requirement',5,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(8,6,'requirement',6,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(9,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(10,9,'requirement',7,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(11,9,'requirement',8,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(12,NULL,'requirement',9,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(13,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(14,13,'requirement',10,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(15,13,'requirement',11,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(16,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(17,16,'requirement',12,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(18,16,'requirement',13,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(19,NULL,'requirement',14,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(20,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(21,20,'requirement',15,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(22,20,'requirement',16,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(23,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(24,23,'requirement',17,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(25,23,'requirement',18,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(26,23,'requirement',19,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(27,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(28,27,'requirement',20,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(29,27,'requirement',21,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(30,27,'requirement',22,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(31,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(32,31,'requirement',23,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(33,31,'requirement',24,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(34,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(35,34,'requirement',25,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(36,34,'requirement',26,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(37,NULL,'requirement',27,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(38,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(39,38,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(40,38,'requirement',29,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(41,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(42,41,'requirement',30,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(43,41,'requirement',31,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(44,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(45,44,'requirement',32,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(46,44,'requirement',33,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(47,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(48,47,'requirement',34,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(49,47,'requirement',35,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(50,NULL,'requirement',36,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(51,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(52,51,'requirement',37,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(53,51,'requirement',38,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(54,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(55,54,'requirement',39,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(56,54,'requirement',40,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(57,NULL,'requirement',41,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(58,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(59,58,'requirement',42,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(60,58,'requirement',43,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(61,NULL,'requirement',44,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(62,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(63,62,'requirement',14,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(64,62,'requirement',3,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(65,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(66,65,'requirement',47,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(67,65,'requirement',31,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(68,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(69,68,'requirement',49,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(70,68,'requirement',50,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(71,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(72,71,'requirement',51,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(73,71,'requirement',52,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(74,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(75,74,'requirement',53,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(76,74,'requirement',54,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(77,74,'requirement',55,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(78,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(79,78,'requirement',56,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(80,78,'requirement',57,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(81,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(82,81,'requirement',58,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(83,81,'requirement',59,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(84,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(85,84,'requirement',60,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(86,84,'requirement',61,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(87,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(88,87,'requirement',62,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(89,87,'requirement',63,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(90,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(91,90,'requirement',64,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(92,90,'requirement',65,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(93,90,'or',NULL,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(94,93,'requirement',66,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(95,93,'requirement',67,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(96,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(97,96,'requirement',68,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(98,96,'requirement',69,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(99,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(100,99,'requirement',70,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(101,99,'requirement',71,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(102,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(103,102,'requirement',72,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(104,102,'requirement',73,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(105,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(106,105,'requirement',74,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(107,105,'requirement',75,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(108,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(109,108,'requirement',76,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(110,108,'requirement',77,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(111,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(112,111,'requirement',78,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(113,111,'requirement',79,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(114,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(115,114,'requirement',80,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(116,114,'requirement',81,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(117,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(118,117,'requirement',82,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(119,117,'requirement',83,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(120,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(121,120,'requirement',84,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(122,120,'requirement',85,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(123,120,'requirement',86,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(124,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(125,124,'requirement',87,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(126,124,'requirement',88,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(127,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(128,127,'requirement',89,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(129,127,'requirement',90,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(130,NULL,'requirement',91,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(131,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(132,131,'requirement',92,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(133,131,'requirement',6,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(134,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(135,134,'requirement',94,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(136,134,'requirement',95,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(137,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(138,137,'requirement',96,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(139,137,'requirement',35,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(140,NULL,'requirement',98,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(141,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(142,141,'requirement',99,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(143,141,'requirement',100,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(144,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(145,144,'requirement',101,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(146,144,'requirement',102,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(147,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(148,147,'requirement',103,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(149,147,'or',NULL,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(150,149,'requirement',104,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(151,149,'requirement',105,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(152,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(153,152,'requirement',106,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(154,152,'requirement',107,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(155,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(156,155,'requirement',108,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(157,155,'requirement',109,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(158,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(159,158,'requirement',110,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(160,158,'requirement',111,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(161,158,'requirement',112,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(162,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(163,162,'requirement',113,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(164,162,'requirement',114,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(165,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(166,165,'requirement',115,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(167,165,'requirement',116,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(168,NULL,'requirement',117,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(169,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(170,169,'requirement',118,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(171,169,'requirement',119,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(172,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(173,172,'requirement',120,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(174,172,'requirement',121,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(175,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(176,175,'requirement',122,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(177,175,'requirement',123,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(178,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(179,178,'requirement',124,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(180,178,'requirement',125,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(181,178,'requirement',126,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(182,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(183,182,'requirement',127,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(184,182,'requirement',128,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(185,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(186,185,'requirement',129,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(187,185,'requirement',130,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(188,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(189,188,'requirement',131,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(190,188,'requirement',132,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(191,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(192,191,'requirement',133,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(193,191,'requirement',134,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(194,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(195,194,'requirement',135,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(196,194,'requirement',136,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(197,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(198,197,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(199,197,'requirement',35,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(200,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(201,200,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(202,200,'requirement',140,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(203,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(204,203,'requirement',141,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(205,203,'requirement',6,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(206,203,'requirement',140,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(207,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(208,207,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(209,207,'requirement',59,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(210,207,'requirement',140,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(211,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(212,211,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(213,211,'requirement',140,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(214,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(215,214,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(216,214,'requirement',6,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(217,214,'requirement',140,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(218,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(219,218,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(220,218,'requirement',59,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(221,218,'requirement',140,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(222,218,'requirement',155,3,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(223,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(224,223,'requirement',141,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(225,223,'requirement',157,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(226,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(227,226,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(228,226,'requirement',159,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(229,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(230,229,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(231,229,'requirement',159,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(232,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(233,232,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(234,232,'requirement',159,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(235,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(236,235,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(237,235,'requirement',165,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(238,235,'requirement',166,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(239,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(240,239,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(241,239,'requirement',168,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(242,239,'requirement',169,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(243,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(244,243,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(245,243,'requirement',171,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(246,243,'requirement',172,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(247,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(248,247,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(249,247,'requirement',174,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(250,247,'requirement',175,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(251,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(252,251,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(253,251,'requirement',159,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(254,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(255,254,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(256,254,'requirement',159,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(257,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(258,257,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(259,257,'requirement',65,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(260,257,'requirement',159,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(261,257,'requirement',183,3,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(262,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(263,262,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(264,262,'requirement',16,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(265,262,'requirement',159,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(266,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(267,266,'requirement',141,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(268,266,'requirement',188,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(269,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(270,269,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(271,269,'requirement',159,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(272,269,'requirement',191,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(273,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(274,273,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(275,273,'requirement',193,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(276,273,'requirement',194,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(277,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(278,277,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(279,277,'requirement',3,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(280,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(281,280,'requirement',197,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(282,280,'requirement',198,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(283,280,'requirement',66,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(284,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(285,284,'requirement',200,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(286,284,'requirement',201,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(287,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(288,287,'requirement',202,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(289,287,'requirement',203,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(290,287,'requirement',107,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(291,287,'requirement',205,3,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(292,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(293,292,'requirement',206,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(294,292,'requirement',59,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(295,292,'requirement',208,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(296,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(297,296,'requirement',209,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(298,296,'requirement',210,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(299,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(300,299,'requirement',211,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(301,299,'requirement',35,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(302,299,'requirement',213,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(303,299,'requirement',214,3,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(304,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(305,304,'requirement',215,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(306,304,'requirement',216,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(307,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(308,307,'requirement',217,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(309,307,'requirement',218,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(310,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(311,310,'requirement',219,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(312,310,'requirement',18,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(313,310,'requirement',221,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(314,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(315,314,'requirement',222,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(316,314,'requirement',223,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(317,314,'requirement',224,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(318,314,'requirement',225,3,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(319,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(320,319,'requirement',226,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(321,319,'requirement',16,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(322,319,'requirement',228,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(323,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(324,323,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(325,323,'requirement',6,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(326,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(327,326,'requirement',65,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(328,326,'requirement',232,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(329,326,'requirement',233,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(330,NULL,'requirement',234,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(331,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(332,331,'requirement',235,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(333,331,'requirement',236,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(334,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(335,334,'requirement',237,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(336,334,'requirement',238,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(337,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(338,337,'requirement',239,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(339,337,'requirement',240,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(340,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(341,340,'requirement',28,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(342,340,'requirement',242,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(343,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(344,343,'requirement',243,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(345,343,'requirement',244,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(346,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(347,346,'requirement',245,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(348,346,'requirement',246,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(349,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(350,349,'requirement',247,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(351,349,'requirement',35,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(352,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(353,352,'requirement',249,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(354,352,'requirement',250,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(355,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(356,355,'requirement',251,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(357,355,'requirement',252,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(358,355,'requirement',253,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(359,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(360,359,'requirement',254,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(361,359,'requirement',255,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(362,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(363,362,'requirement',256,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(364,362,'requirement',257,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(365,NULL,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(366,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(367,366,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(368,366,'requirement',6,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(369,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(370,369,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(371,369,'requirement',6,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(372,369,'requirement',107,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(373,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(374,373,'requirement',51,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(375,373,'requirement',265,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(376,NULL,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(377,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(378,377,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(379,377,'requirement',6,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(380,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(381,380,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(382,380,'requirement',31,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(383,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(384,383,'requirement',1,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(385,383,'requirement',38,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(386,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(387,386,'requirement',130,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(388,386,'requirement',274,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(389,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(390,389,'requirement',274,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(391,389,'requirement',276,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(392,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(393,392,'requirement',274,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(394,392,'requirement',278,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(395,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(396,395,'requirement',130,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(397,395,'requirement',274,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(398,395,'requirement',281,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(399,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(400,399,'requirement',130,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(401,399,'requirement',274,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(402,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(403,402,'requirement',274,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(404,402,'requirement',31,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(405,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(406,405,'requirement',274,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(407,405,'requirement',287,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(408,405,'requirement',288,2,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(409,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(410,409,'requirement',274,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(411,409,'requirement',290,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(412,NULL,'and',NULL,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(413,412,'requirement',274,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(414,412,'requirement',292,1,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(415,NULL,'requirement',293,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(416,NULL,'requirement',294,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(417,NULL,'requirement',295,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(418,NULL,'requirement',296,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(419,NULL,'requirement',297,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(420,NULL,'requirement',298,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(421,NULL,'requirement',299,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(422,NULL,'requirement',300,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(423,NULL,'requirement',301,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(424,NULL,'requirement',302,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(425,NULL,'requirement',303,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(426,NULL,'requirement',304,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(427,NULL,'requirement',305,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(428,NULL,'requirement',306,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(429,NULL,'requirement',307,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(430,NULL,'requirement',308,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(431,NULL,'requirement',309,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(432,NULL,'requirement',310,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(433,NULL,'requirement',311,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(434,NULL,'requirement',312,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(435,NULL,'requirement',313,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(436,NULL,'requirement',314,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(437,NULL,'requirement',315,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(438,NULL,'requirement',316,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(439,NULL,'requirement',317,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(440,NULL,'requirement',318,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(441,NULL,'requirement',319,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(442,NULL,'requirement',320,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(443,NULL,'requirement',321,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(444,NULL,'requirement',322,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(445,NULL,'requirement',323,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(446,NULL,'requirement',324,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(447,NULL,'requirement',325,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(448,NULL,'requirement',326,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(449,NULL,'requirement',327,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(450,NULL,'requirement',328,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(451,NULL,'requirement',329,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(452,NULL,'requirement',330,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(453,NULL,'requirement',331,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(454,NULL,'requirement',332,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(455,NULL,'requirement',333,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(456,NULL,'requirement',334,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(457,NULL,'requirement',335,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(458,NULL,'requirement',336,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(459,NULL,'requirement',337,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(460,NULL,'requirement',338,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(461,NULL,'requirement',339,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(462,NULL,'requirement',340,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(463,NULL,'requirement',341,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(464,NULL,'requirement',342,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(465,NULL,'requirement',343,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(466,NULL,'requirement',344,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(467,NULL,'requirement',345,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(468,NULL,'requirement',346,0,'2025-10-08 06:13:12','2025-10-08 06:13:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(469,NULL,'requirement',347,0,'2025-10-18 03:20:11','2025-10-18 03:20:11');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(470,NULL,'requirement',348,0,'2025-10-18 03:20:11','2025-10-18 03:20:11');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(471,NULL,'requirement',349,0,'2025-10-18 03:20:11','2025-10-18 03:20:11');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(472,NULL,'requirement',350,0,'2025-10-18 03:20:11','2025-10-18 03:20:11');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(473,NULL,'requirement',351,0,'2025-10-18 03:20:11','2025-10-18 03:20:11');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(474,NULL,'requirement',159,0,'2026-01-02 18:47:03','2026-01-02 18:47:03');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(475,NULL,'requirement',159,0,'2026-01-02 18:47:03','2026-01-02 18:47:03');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(476,NULL,'requirement',159,0,'2026-01-02 18:47:38','2026-01-02 18:47:38');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(477,NULL,'requirement',159,0,'2026-01-02 18:48:33','2026-01-02 18:48:33');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(478,NULL,'requirement',159,0,'2026-01-02 18:48:45','2026-01-02 18:48:45');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(479,NULL,'requirement',159,0,'2026-01-02 18:48:45','2026-01-02 18:48:45');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(480,NULL,'and',NULL,0,'2026-01-03 21:46:52','2026-01-03 21:46:52');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(481,480,'requirement',1,0,'2026-01-03 21:46:52','2026-01-03 21:46:52');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(482,480,'requirement',6,1,'2026-01-03 21:46:52','2026-01-03 21:46:52');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(483,NULL,'and',NULL,0,'2026-01-03 21:47:07','2026-01-03 21:47:07');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(484,483,'requirement',1,0,'2026-01-03 21:47:07','2026-01-03 21:47:07');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(485,483,'requirement',65,1,'2026-01-03 21:47:07','2026-01-03 21:47:07');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(486,NULL,'and',NULL,0,'2026-01-03 21:47:19','2026-01-03 21:47:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(487,486,'requirement',1,0,'2026-01-03 21:47:19','2026-01-03 21:47:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(488,486,'or',NULL,1,'2026-01-03 21:47:19','2026-01-03 21:47:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(489,488,'requirement',86,0,'2026-01-03 21:47:19','2026-01-03 21:47:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(490,488,'requirement',107,1,'2026-01-03 21:47:19','2026-01-03 21:47:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(491,488,'requirement',73,2,'2026-01-03 21:47:19','2026-01-03 21:47:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(492,NULL,'and',NULL,0,'2026-01-03 21:47:33','2026-01-03 21:47:33');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(493,492,'requirement',1,0,'2026-01-03 21:47:33','2026-01-03 21:47:33');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(494,492,'requirement',353,1,'2026-01-03 21:47:33','2026-01-03 21:47:33');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(495,NULL,'requirement',1,0,'2026-01-03 21:47:42','2026-01-03 21:47:42');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(496,NULL,'requirement',1,0,'2026-01-03 21:47:58','2026-01-03 21:47:58');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(497,NULL,'and',NULL,0,'2026-01-03 21:48:09','2026-01-03 21:48:09');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(498,497,'requirement',1,0,'2026-01-03 21:48:09','2026-01-03 21:48:09');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(499,497,'requirement',8,1,'2026-01-03 21:48:09','2026-01-03 21:48:09');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(500,NULL,'and',NULL,0,'2026-01-03 21:50:24','2026-01-03 21:50:24');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(501,500,'requirement',28,0,'2026-01-03 21:50:24','2026-01-03 21:50:24');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(502,500,'requirement',50,1,'2026-01-03 21:50:24','2026-01-03 21:50:24');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(503,NULL,'and',NULL,0,'2026-01-03 21:50:26','2026-01-03 21:50:26');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(504,503,'requirement',1,0,'2026-01-03 21:50:26','2026-01-03 21:50:26');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(505,503,'requirement',3,1,'2026-01-03 21:50:26','2026-01-03 21:50:26');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(506,503,'requirement',50,2,'2026-01-03 21:50:26','2026-01-03 21:50:26');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(507,NULL,'and',NULL,0,'2026-01-03 21:50:27','2026-01-03 21:50:27');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(508,507,'requirement',28,0,'2026-01-03 21:50:27','2026-01-03 21:50:27');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(509,507,'requirement',107,1,'2026-01-03 21:50:27','2026-01-03 21:50:27');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(510,NULL,'requirement',28,0,'2026-01-03 21:50:28','2026-01-03 21:50:28');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(511,NULL,'and',NULL,0,'2026-01-03 21:50:29','2026-01-03 21:50:29');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(512,511,'requirement',28,0,'2026-01-03 21:50:29','2026-01-03 21:50:29');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(513,511,'or',NULL,1,'2026-01-03 21:50:29','2026-01-03 21:50:29');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(514,513,'requirement',66,0,'2026-01-03 21:50:29','2026-01-03 21:50:29');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(515,513,'requirement',67,1,'2026-01-03 21:50:29','2026-01-03 21:50:29');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(516,NULL,'and',NULL,0,'2026-01-03 21:50:30','2026-01-03 21:50:30');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(517,516,'requirement',1,0,'2026-01-03 21:50:30','2026-01-03 21:50:30');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(518,516,'requirement',50,1,'2026-01-03 21:50:30','2026-01-03 21:50:30');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(519,NULL,'and',NULL,0,'2026-01-03 21:50:39','2026-01-03 21:50:39');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(520,519,'requirement',51,0,'2026-01-03 21:50:39','2026-01-03 21:50:39');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(521,519,'requirement',354,1,'2026-01-03 21:50:39','2026-01-03 21:50:39');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(522,NULL,'and',NULL,0,'2026-01-03 21:50:40','2026-01-03 21:50:40');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(523,522,'requirement',82,0,'2026-01-03 21:50:40','2026-01-03 21:50:40');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(524,522,'requirement',357,1,'2026-01-03 21:50:40','2026-01-03 21:50:40');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(525,522,'or',NULL,2,'2026-01-03 21:50:40','2026-01-03 21:50:40');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(526,525,'requirement',355,0,'2026-01-03 21:50:40','2026-01-03 21:50:40');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(527,525,'requirement',356,1,'2026-01-03 21:50:40','2026-01-03 21:50:40');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(528,NULL,'and',NULL,0,'2026-01-03 21:50:42','2026-01-03 21:50:42');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(529,528,'requirement',51,0,'2026-01-03 21:50:42','2026-01-03 21:50:42');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(530,NULL,'and',NULL,0,'2026-01-03 21:50:43','2026-01-03 21:50:43');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(531,530,'requirement',28,0,'2026-01-03 21:50:43','2026-01-03 21:50:43');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(532,530,'requirement',50,1,'2026-01-03 21:50:43','2026-01-03 21:50:43');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(533,NULL,'and',NULL,0,'2026-01-03 21:50:45','2026-01-03 21:50:45');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(534,533,'requirement',82,0,'2026-01-03 21:50:45','2026-01-03 21:50:45');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(535,533,'requirement',358,1,'2026-01-03 21:50:45','2026-01-03 21:50:45');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(536,NULL,'and',NULL,0,'2026-01-03 21:50:46','2026-01-03 21:50:46');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(537,536,'requirement',51,0,'2026-01-03 21:50:46','2026-01-03 21:50:46');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(538,NULL,'and',NULL,0,'2026-01-03 21:50:57','2026-01-03 21:50:57');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(539,538,'requirement',28,0,'2026-01-03 21:50:57','2026-01-03 21:50:57');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(540,538,'requirement',359,1,'2026-01-03 21:50:57','2026-01-03 21:50:57');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(541,NULL,'and',NULL,0,'2026-01-03 21:50:58','2026-01-03 21:50:58');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(542,541,'requirement',28,0,'2026-01-03 21:50:58','2026-01-03 21:50:58');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(543,541,'requirement',6,1,'2026-01-03 21:50:58','2026-01-03 21:50:58');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(544,NULL,'and',NULL,0,'2026-01-03 21:50:59','2026-01-03 21:50:59');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(545,544,'requirement',1,0,'2026-01-03 21:50:59','2026-01-03 21:50:59');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(546,544,'requirement',107,1,'2026-01-03 21:50:59','2026-01-03 21:50:59');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(547,NULL,'and',NULL,0,'2026-01-03 21:51:00','2026-01-03 21:51:00');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(548,547,'requirement',28,0,'2026-01-03 21:51:00','2026-01-03 21:51:00');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(549,547,'requirement',31,1,'2026-01-03 21:51:00','2026-01-03 21:51:00');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(550,547,'requirement',360,2,'2026-01-03 21:51:00','2026-01-03 21:51:00');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(551,NULL,'and',NULL,0,'2026-01-03 21:51:01','2026-01-03 21:51:01');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(552,551,'requirement',1,0,'2026-01-03 21:51:01','2026-01-03 21:51:01');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(553,551,'requirement',361,1,'2026-01-03 21:51:01','2026-01-03 21:51:01');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(554,551,'requirement',50,2,'2026-01-03 21:51:01','2026-01-03 21:51:01');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(555,NULL,'and',NULL,0,'2026-01-03 21:51:02','2026-01-03 21:51:02');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(556,555,'requirement',28,0,'2026-01-03 21:51:02','2026-01-03 21:51:02');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(557,555,'requirement',362,1,'2026-01-03 21:51:02','2026-01-03 21:51:02');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(558,NULL,'and',NULL,0,'2026-01-03 21:51:13','2026-01-03 21:51:13');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(559,558,'requirement',28,0,'2026-01-03 21:51:13','2026-01-03 21:51:13');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(560,558,'requirement',54,1,'2026-01-03 21:51:13','2026-01-03 21:51:13');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(561,NULL,'and',NULL,0,'2026-01-03 21:51:14','2026-01-03 21:51:14');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(562,561,'requirement',1,0,'2026-01-03 21:51:14','2026-01-03 21:51:14');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(563,561,'requirement',54,1,'2026-01-03 21:51:14','2026-01-03 21:51:14');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(564,NULL,'and',NULL,0,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(565,564,'requirement',28,0,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(566,564,'requirement',364,1,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(567,564,'requirement',363,2,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(568,NULL,'and',NULL,0,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(569,568,'requirement',28,0,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(570,568,'or',NULL,1,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(571,570,'requirement',66,0,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(572,570,'requirement',67,1,'2026-01-03 21:51:19','2026-01-03 21:51:19');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(573,NULL,'and',NULL,0,'2026-01-03 21:51:20','2026-01-03 21:51:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(574,573,'requirement',51,0,'2026-01-03 21:51:20','2026-01-03 21:51:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(575,573,'requirement',3,1,'2026-01-03 21:51:20','2026-01-03 21:51:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(576,573,'requirement',66,2,'2026-01-03 21:51:20','2026-01-03 21:51:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(577,NULL,'and',NULL,0,'2026-01-03 21:51:21','2026-01-03 21:51:21');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(578,577,'requirement',28,0,'2026-01-03 21:51:21','2026-01-03 21:51:21');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(579,577,'requirement',365,1,'2026-01-03 21:51:21','2026-01-03 21:51:21');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(580,NULL,'and',NULL,0,'2026-01-03 21:52:33','2026-01-03 21:52:33');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(581,580,'requirement',65,0,'2026-01-03 21:52:33','2026-01-03 21:52:33');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(582,580,'requirement',274,1,'2026-01-03 21:52:33','2026-01-03 21:52:33');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(583,580,'requirement',6,2,'2026-01-03 21:52:33','2026-01-03 21:52:33');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(584,580,'requirement',159,3,'2026-01-03 21:52:33','2026-01-03 21:52:33');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(585,NULL,'and',NULL,0,'2026-01-03 21:52:36','2026-01-03 21:52:36');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(586,585,'requirement',65,0,'2026-01-03 21:52:36','2026-01-03 21:52:36');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(587,585,'requirement',274,1,'2026-01-03 21:52:36','2026-01-03 21:52:36');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(588,NULL,'and',NULL,0,'2026-01-03 21:52:38','2026-01-03 21:52:38');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(589,588,'requirement',65,0,'2026-01-03 21:52:38','2026-01-03 21:52:38');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(590,588,'requirement',274,1,'2026-01-03 21:52:38','2026-01-03 21:52:38');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(591,588,'requirement',359,2,'2026-01-03 21:52:38','2026-01-03 21:52:38');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(592,588,'requirement',360,3,'2026-01-03 21:52:38','2026-01-03 21:52:38');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(593,588,'requirement',116,4,'2026-01-03 21:52:38','2026-01-03 21:52:38');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(594,NULL,'and',NULL,0,'2026-01-03 21:52:39','2026-01-03 21:52:39');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(595,594,'requirement',65,0,'2026-01-03 21:52:39','2026-01-03 21:52:39');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(596,594,'requirement',274,1,'2026-01-03 21:52:39','2026-01-03 21:52:39');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(597,594,'requirement',140,2,'2026-01-03 21:52:39','2026-01-03 21:52:39');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(598,594,'requirement',367,3,'2026-01-03 21:52:39','2026-01-03 21:52:39');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(599,594,'requirement',366,4,'2026-01-03 21:52:39','2026-01-03 21:52:39');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(600,NULL,'and',NULL,0,'2026-01-03 21:54:09','2026-01-03 21:54:09');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(601,600,'requirement',28,0,'2026-01-03 21:54:09','2026-01-03 21:54:09');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(602,600,'requirement',159,1,'2026-01-03 21:54:09','2026-01-03 21:54:09');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(603,NULL,'and',NULL,0,'2026-01-03 21:54:17','2026-01-03 21:54:17');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(604,603,'requirement',51,0,'2026-01-03 21:54:17','2026-01-03 21:54:17');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(605,603,'requirement',159,1,'2026-01-03 21:54:17','2026-01-03 21:54:17');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(606,603,'requirement',368,2,'2026-01-03 21:54:17','2026-01-03 21:54:17');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(607,NULL,'and',NULL,0,'2026-01-03 21:54:18','2026-01-03 21:54:18');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(608,607,'requirement',1,0,'2026-01-03 21:54:18','2026-01-03 21:54:18');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(609,607,'requirement',159,1,'2026-01-03 21:54:18','2026-01-03 21:54:18');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(610,607,'requirement',369,2,'2026-01-03 21:54:18','2026-01-03 21:54:18');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(611,NULL,'and',NULL,0,'2026-01-03 21:54:20','2026-01-03 21:54:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(612,611,'requirement',51,0,'2026-01-03 21:54:20','2026-01-03 21:54:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(613,611,'requirement',159,1,'2026-01-03 21:54:20','2026-01-03 21:54:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(614,611,'requirement',370,2,'2026-01-03 21:54:20','2026-01-03 21:54:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(615,NULL,'and',NULL,0,'2026-01-03 21:54:21','2026-01-03 21:54:21');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(616,615,'requirement',1,0,'2026-01-03 21:54:21','2026-01-03 21:54:21');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(617,615,'requirement',371,1,'2026-01-03 21:54:21','2026-01-03 21:54:21');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(618,NULL,'and',NULL,0,'2026-01-03 21:54:22','2026-01-03 21:54:22');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(619,618,'requirement',28,0,'2026-01-03 21:54:22','2026-01-03 21:54:22');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(620,618,'requirement',159,1,'2026-01-03 21:54:22','2026-01-03 21:54:22');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(621,618,'requirement',372,2,'2026-01-03 21:54:22','2026-01-03 21:54:22');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(622,NULL,'and',NULL,0,'2026-01-03 21:54:23','2026-01-03 21:54:23');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(623,622,'requirement',82,0,'2026-01-03 21:54:23','2026-01-03 21:54:23');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(624,622,'requirement',374,1,'2026-01-03 21:54:23','2026-01-03 21:54:23');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(625,622,'requirement',373,2,'2026-01-03 21:54:23','2026-01-03 21:54:23');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(626,NULL,'and',NULL,0,'2026-01-03 21:54:24','2026-01-03 21:54:24');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(627,626,'requirement',28,0,'2026-01-03 21:54:24','2026-01-03 21:54:24');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(628,626,'requirement',375,1,'2026-01-03 21:54:24','2026-01-03 21:54:24');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(629,NULL,'and',NULL,0,'2026-01-03 21:54:25','2026-01-03 21:54:25');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(630,629,'requirement',1,0,'2026-01-03 21:54:25','2026-01-03 21:54:25');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(631,629,'requirement',376,1,'2026-01-03 21:54:25','2026-01-03 21:54:25');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(632,629,'requirement',377,2,'2026-01-03 21:54:25','2026-01-03 21:54:25');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(633,NULL,'and',NULL,0,'2026-01-03 21:54:26','2026-01-03 21:54:26');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(634,633,'requirement',1,0,'2026-01-03 21:54:26','2026-01-03 21:54:26');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(635,633,'requirement',159,1,'2026-01-03 21:54:26','2026-01-03 21:54:26');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(636,NULL,'and',NULL,0,'2026-01-03 21:55:40','2026-01-03 21:55:40');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(637,636,'requirement',1,0,'2026-01-03 21:55:40','2026-01-03 21:55:40');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(638,636,'requirement',3,1,'2026-01-03 21:55:40','2026-01-03 21:55:40');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(639,636,'requirement',378,2,'2026-01-03 21:55:40','2026-01-03 21:55:40');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(640,NULL,'and',NULL,0,'2026-01-03 21:55:41','2026-01-03 21:55:41');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(641,640,'requirement',1,0,'2026-01-03 21:55:41','2026-01-03 21:55:41');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(642,640,'requirement',11,1,'2026-01-03 21:55:41','2026-01-03 21:55:41');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(643,640,'requirement',228,2,'2026-01-03 21:55:41','2026-01-03 21:55:41');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(644,NULL,'and',NULL,0,'2026-01-03 21:55:42','2026-01-03 21:55:42');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(645,644,'requirement',1,0,'2026-01-03 21:55:42','2026-01-03 21:55:42');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(646,644,'requirement',16,1,'2026-01-03 21:55:42','2026-01-03 21:55:42');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(647,644,'requirement',54,2,'2026-01-03 21:55:42','2026-01-03 21:55:42');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(648,644,'requirement',31,3,'2026-01-03 21:55:42','2026-01-03 21:55:42');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(649,644,'requirement',50,4,'2026-01-03 21:55:42','2026-01-03 21:55:42');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(650,644,'requirement',378,5,'2026-01-03 21:55:42','2026-01-03 21:55:42');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(651,644,'requirement',379,6,'2026-01-03 21:55:42','2026-01-03 21:55:42');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(652,NULL,'and',NULL,0,'2026-01-03 21:55:43','2026-01-03 21:55:43');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(653,652,'requirement',1,0,'2026-01-03 21:55:43','2026-01-03 21:55:43');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(654,652,'requirement',378,1,'2026-01-03 21:55:43','2026-01-03 21:55:43');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(655,NULL,'and',NULL,0,'2026-01-03 21:55:44','2026-01-03 21:55:44');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(656,655,'requirement',1,0,'2026-01-03 21:55:44','2026-01-03 21:55:44');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(657,655,'or',NULL,1,'2026-01-03 21:55:44','2026-01-03 21:55:44');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(658,657,'requirement',380,0,'2026-01-03 21:55:44','2026-01-03 21:55:44');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(659,657,'requirement',381,1,'2026-01-03 21:55:44','2026-01-03 21:55:44');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(660,657,'requirement',353,2,'2026-01-03 21:55:44','2026-01-03 21:55:44');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(661,NULL,'and',NULL,0,'2026-01-03 21:55:45','2026-01-03 21:55:45');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(662,661,'requirement',28,0,'2026-01-03 21:55:45','2026-01-03 21:55:45');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(663,661,'requirement',353,1,'2026-01-03 21:55:45','2026-01-03 21:55:45');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(664,NULL,'and',NULL,0,'2026-01-03 21:55:45','2026-01-03 21:55:45');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(665,664,'requirement',1,0,'2026-01-03 21:55:45','2026-01-03 21:55:45');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(666,664,'requirement',214,1,'2026-01-03 21:55:45','2026-01-03 21:55:45');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(667,NULL,'and',NULL,0,'2026-01-03 21:55:46','2026-01-03 21:55:46');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(668,667,'requirement',28,0,'2026-01-03 21:55:46','2026-01-03 21:55:46');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(669,667,'requirement',214,1,'2026-01-03 21:55:46','2026-01-03 21:55:46');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(670,NULL,'and',NULL,0,'2026-01-03 21:55:47','2026-01-03 21:55:47');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(671,670,'requirement',1,0,'2026-01-03 21:55:47','2026-01-03 21:55:47');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(672,670,'requirement',213,1,'2026-01-03 21:55:47','2026-01-03 21:55:47');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(673,670,'requirement',377,2,'2026-01-03 21:55:47','2026-01-03 21:55:47');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(674,NULL,'and',NULL,0,'2026-01-03 21:55:48','2026-01-03 21:55:48');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(675,674,'requirement',28,0,'2026-01-03 21:55:48','2026-01-03 21:55:48');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(676,674,'requirement',382,1,'2026-01-03 21:55:48','2026-01-03 21:55:48');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(677,674,'requirement',383,2,'2026-01-03 21:55:48','2026-01-03 21:55:48');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(678,NULL,'and',NULL,0,'2026-01-03 21:57:00','2026-01-03 21:57:00');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(679,678,'requirement',28,0,'2026-01-03 21:57:00','2026-01-03 21:57:00');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(680,678,'requirement',59,1,'2026-01-03 21:57:00','2026-01-03 21:57:00');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(681,NULL,'and',NULL,0,'2026-01-03 21:58:04','2026-01-03 21:58:04');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(682,681,'requirement',1,0,'2026-01-03 21:58:04','2026-01-03 21:58:04');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(683,681,'requirement',6,1,'2026-01-03 21:58:04','2026-01-03 21:58:04');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(684,NULL,'and',NULL,0,'2026-01-03 21:58:08','2026-01-03 21:58:08');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(685,684,'requirement',1,0,'2026-01-03 21:58:08','2026-01-03 21:58:08');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(686,NULL,'and',NULL,0,'2026-01-03 21:58:11','2026-01-03 21:58:11');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(687,686,'requirement',1,0,'2026-01-03 21:58:11','2026-01-03 21:58:11');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(688,686,'requirement',384,1,'2026-01-03 21:58:11','2026-01-03 21:58:11');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(689,NULL,'requirement',385,0,'2026-01-04 19:23:28','2026-01-04 19:23:28');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(690,NULL,'requirement',386,0,'2026-01-04 19:23:48','2026-01-04 19:23:48');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(691,NULL,'requirement',385,0,'2026-01-04 19:23:59','2026-01-04 19:23:59');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(692,NULL,'requirement',385,0,'2026-01-04 19:24:08','2026-01-04 19:24:08');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(693,NULL,'requirement',386,0,'2026-01-04 19:24:22','2026-01-04 19:24:22');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(694,NULL,'requirement',385,0,'2026-01-04 19:24:32','2026-01-04 19:24:32');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(695,NULL,'requirement',385,0,'2026-01-04 19:24:50','2026-01-04 19:24:50');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(696,NULL,'requirement',385,0,'2026-01-04 19:24:50','2026-01-04 19:24:50');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(697,NULL,'requirement',385,0,'2026-01-04 19:24:50','2026-01-04 19:24:50');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(698,NULL,'requirement',387,0,'2026-01-04 19:25:30','2026-01-04 19:25:30');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(699,NULL,'requirement',386,0,'2026-01-04 19:25:30','2026-01-04 19:25:30');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(700,NULL,'requirement',385,0,'2026-01-04 19:25:53','2026-01-04 19:25:53');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(701,NULL,'requirement',385,0,'2026-01-04 19:25:53','2026-01-04 19:25:53');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(702,NULL,'requirement',386,0,'2026-01-04 19:25:53','2026-01-04 19:25:53');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(703,NULL,'requirement',385,0,'2026-01-04 19:26:12','2026-01-04 19:26:12');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(704,NULL,'requirement',387,0,'2026-01-04 19:26:31','2026-01-04 19:26:31');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(705,NULL,'requirement',385,0,'2026-01-04 19:26:31','2026-01-04 19:26:31');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(706,NULL,'requirement',385,0,'2026-01-04 19:26:48','2026-01-04 19:26:48');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(707,NULL,'requirement',385,0,'2026-01-04 19:26:48','2026-01-04 19:26:48');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(708,NULL,'requirement',387,0,'2026-01-04 19:27:09','2026-01-04 19:27:09');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(709,NULL,'requirement',386,0,'2026-01-04 19:27:09','2026-01-04 19:27:09');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(710,NULL,'requirement',385,0,'2026-01-04 19:27:41','2026-01-04 19:27:41');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(711,NULL,'requirement',385,0,'2026-01-04 19:27:41','2026-01-04 19:27:41');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(712,NULL,'requirement',386,0,'2026-01-04 19:27:58','2026-01-04 19:27:58');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(713,NULL,'requirement',387,0,'2026-01-04 19:27:58','2026-01-04 19:27:58');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(714,NULL,'requirement',386,0,'2026-01-04 19:27:58','2026-01-04 19:27:58');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(715,NULL,'requirement',385,0,'2026-01-04 19:28:20','2026-01-04 19:28:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(716,NULL,'requirement',385,0,'2026-01-04 19:28:20','2026-01-04 19:28:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(717,NULL,'requirement',386,0,'2026-01-04 19:28:45','2026-01-04 19:28:45');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(718,NULL,'requirement',385,0,'2026-01-04 19:28:45','2026-01-04 19:28:45');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(719,NULL,'requirement',385,0,'2026-01-04 19:28:57','2026-01-04 19:28:57');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(720,NULL,'requirement',386,0,'2026-01-04 19:28:57','2026-01-04 19:28:57');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(721,NULL,'requirement',386,0,'2026-01-04 19:29:32','2026-01-04 19:29:32');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(722,NULL,'requirement',385,0,'2026-01-04 19:29:32','2026-01-04 19:29:32');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(723,NULL,'requirement',389,0,'2026-01-04 19:29:54','2026-01-04 19:29:54');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(724,NULL,'requirement',388,0,'2026-01-04 19:29:54','2026-01-04 19:29:54');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(725,NULL,'requirement',385,0,'2026-01-04 19:30:28','2026-01-04 19:30:28');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(726,NULL,'requirement',385,0,'2026-01-04 19:30:28','2026-01-04 19:30:28');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(727,NULL,'requirement',385,0,'2026-01-04 19:30:55','2026-01-04 19:30:55');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(728,NULL,'requirement',385,0,'2026-01-04 19:30:55','2026-01-04 19:30:55');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(729,NULL,'requirement',387,0,'2026-01-04 19:30:55','2026-01-04 19:30:55');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(730,NULL,'requirement',386,0,'2026-01-04 19:31:18','2026-01-04 19:31:18');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(731,NULL,'requirement',386,0,'2026-01-04 19:31:18','2026-01-04 19:31:18');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(732,NULL,'requirement',385,0,'2026-01-04 19:31:18','2026-01-04 19:31:18');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(733,NULL,'requirement',387,0,'2026-01-04 19:31:59','2026-01-04 19:31:59');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(734,NULL,'requirement',385,0,'2026-01-04 19:31:59','2026-01-04 19:31:59');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(735,NULL,'requirement',385,0,'2026-01-04 19:32:16','2026-01-04 19:32:16');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(736,NULL,'requirement',385,0,'2026-01-04 19:32:16','2026-01-04 19:32:16');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(737,NULL,'requirement',385,0,'2026-01-04 19:32:43','2026-01-04 19:32:43');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(738,NULL,'requirement',387,0,'2026-01-04 19:32:43','2026-01-04 19:32:43');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(739,NULL,'requirement',385,0,'2026-01-04 19:33:25','2026-01-04 19:33:25');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(740,NULL,'requirement',385,0,'2026-01-04 19:33:25','2026-01-04 19:33:25');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(741,NULL,'requirement',385,0,'2026-01-04 19:33:49','2026-01-04 19:33:49');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(742,NULL,'requirement',386,0,'2026-01-04 19:33:49','2026-01-04 19:33:49');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(743,NULL,'requirement',386,0,'2026-01-04 19:34:15','2026-01-04 19:34:15');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(744,NULL,'requirement',385,0,'2026-01-04 19:34:15','2026-01-04 19:34:15');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(745,NULL,'requirement',385,0,'2026-01-04 19:34:15','2026-01-04 19:34:15');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(746,NULL,'requirement',385,0,'2026-01-04 19:34:40','2026-01-04 19:34:40');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(747,NULL,'requirement',387,0,'2026-01-04 19:34:40','2026-01-04 19:34:40');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(748,NULL,'requirement',386,0,'2026-01-04 19:35:08','2026-01-04 19:35:08');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(749,NULL,'requirement',385,0,'2026-01-04 19:35:08','2026-01-04 19:35:08');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(750,NULL,'requirement',385,0,'2026-01-04 19:35:08','2026-01-04 19:35:08');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(751,NULL,'requirement',385,0,'2026-01-04 19:35:34','2026-01-04 19:35:34');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(752,NULL,'requirement',387,0,'2026-01-04 19:35:34','2026-01-04 19:35:34');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(753,NULL,'requirement',385,0,'2026-01-04 19:36:01','2026-01-04 19:36:01');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(754,NULL,'requirement',386,0,'2026-01-04 19:36:01','2026-01-04 19:36:01');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(755,NULL,'requirement',386,0,'2026-01-04 19:36:29','2026-01-04 19:36:29');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(756,NULL,'requirement',388,0,'2026-01-04 19:36:29','2026-01-04 19:36:29');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(757,NULL,'requirement',389,0,'2026-01-04 19:36:29','2026-01-04 19:36:29');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(758,NULL,'and',NULL,0,'2026-01-04 20:18:20','2026-01-04 20:18:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(759,758,'requirement',82,0,'2026-01-04 20:18:20','2026-01-04 20:18:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(760,758,'requirement',390,1,'2026-01-04 20:18:20','2026-01-04 20:18:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(761,NULL,'and',NULL,0,'2026-01-04 20:18:20','2026-01-04 20:18:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(762,761,'requirement',1,0,'2026-01-04 20:18:20','2026-01-04 20:18:20');
+INSERT INTO requirement_expressions(id,parent_id,node_type,requirement_id,position,created_at,updated_at) VALUES(763,761,'requirement',390,1,'2026-01-04 20:18:20','2026-01-04 20:18:20');
+COMMIT;
@@ -0,0 +1,435 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE requirements (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    requirement_type VARCHAR(20) NOT NULL CHECK (requirement_type IN (
+        'attribute', 'skill', 'rank', 'arcane_skill',
+        'edge', 'arcane_background', 'wild_card', 'description'
+    )),
+    target_id INTEGER, -- nullable for types like rank_min, power_points_min
+    value INTEGER, -- can be NULL for description type
+    description TEXT NOT NULL, -- always present, human-readable requirement text
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+, target_name VARCHAR(50));
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(1,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(2,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(3,'attribute',1,8,'Agility d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(4,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(5,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(6,'attribute',3,8,'Spirit d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(7,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(8,'edge',4,1,'Arcane Resistance','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(9,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(10,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(11,'attribute',5,6,'Vigor d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(12,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(13,'edge',7,1,'Attractive','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(14,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(15,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(16,'attribute',3,6,'Spirit d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(17,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(18,'attribute',4,6,'Strength d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(19,'attribute',5,6,'Vigor d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(20,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(21,'attribute',4,6,'Strength d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(22,'attribute',5,6,'Vigor d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(23,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(24,'attribute',3,8,'Spirit d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(25,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(26,'attribute',3,8,'Spirit d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(27,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(28,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(29,'edge',15,1,'Fame','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(30,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(31,'attribute',5,8,'Vigor d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(32,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(33,'attribute',1,6,'Agility d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(34,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(35,'attribute',2,6,'Smarts d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(36,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(37,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(38,'edge',20,1,'Luck','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(39,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(40,'attribute',1,8,'Agility d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(41,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(42,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(43,'edge',23,1,'Rich','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(44,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(45,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(46,'attribute',1,8,'Agility d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(47,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(48,'attribute',5,8,'Vigor d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(49,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(50,'skill',12,8,'Fighting d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(51,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(52,'edge',28,1,'Block','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(53,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(54,'attribute',4,8,'Strength d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(55,'attribute',5,8,'Vigor d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(56,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(57,'edge',30,1,'Brawler','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(58,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(59,'attribute',2,8,'Smarts d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(60,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(61,'skill',12,8,'Fighting d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(62,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(63,'edge',33,1,'Counterattack','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(64,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(65,'wild_card',NULL,1,'Wild Card','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(66,'skill',1,8,'Athletics d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(67,'skill',27,8,'Shooting d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(68,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(69,'attribute',1,8,'Agility d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(70,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(71,'edge',36,1,'Dodge','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(72,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(73,'skill',27,6,'Shooting d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(74,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(75,'attribute',1,8,'Agility d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(76,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(77,'edge',39,1,'Extraction','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(78,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(79,'skill',12,8,'Fighting d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(80,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(81,'attribute',1,8,'Agility d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(82,'rank',4,4,'Heroic','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(83,'edge',42,1,'First Strike','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(84,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(85,'attribute',1,8,'Agility d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(86,'skill',1,6,'Athletics d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(87,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(88,'skill',12,8,'Fighting d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(89,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(90,'edge',45,1,'Frenzy','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(91,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(92,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(93,'attribute',3,8,'Spirit d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(94,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(95,'edge',48,1,'Hard To Kill','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(96,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(97,'attribute',2,6,'Smarts d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(98,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(99,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(100,'attribute',2,8,'Smarts d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(101,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(102,'edge',52,1,'Level Headed','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(103,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(104,'skill',1,8,'Athletics d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(105,'skill',27,8,'Shooting d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(106,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(107,'skill',12,6,'Fighting d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(108,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(109,'edge',55,1,'Martial Artist','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(110,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(111,'wild_card',NULL,1,'Wild Card','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(112,'skill',12,8,'Fighting d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(113,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(114,'attribute',5,8,'Vigor d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(115,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(116,'edge',58,1,'Nerves of Steel','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(117,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(118,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(119,'skill',27,6,'Shooting d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(120,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(121,'edge',61,1,'Rapid Fire','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(122,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(123,'skill',27,8,'Shooting d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(124,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(125,'attribute',4,8,'Strength d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(126,'skill',12,8,'Fighting d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(127,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(128,'edge',64,1,'Sweep','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(129,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(130,'description',NULL,NULL,'Skill with chosen weapon d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(131,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(132,'edge',66,1,'Trademark Weapon','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(133,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(134,'attribute',1,8,'Agility d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(135,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(136,'attribute',1,8,'Agility d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(137,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(138,'attribute',2,6,'Smarts d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(139,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(140,'edge',70,1,'Command','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(141,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(142,'attribute',3,8,'Spirit d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(143,'edge',70,1,'Command','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(144,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(145,'attribute',2,8,'Smarts d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(146,'edge',70,1,'Command','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(147,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(148,'edge',70,1,'Command','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(149,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(150,'attribute',3,8,'Spirit d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(151,'edge',70,1,'Command','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(152,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(153,'attribute',2,8,'Smarts d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(154,'edge',70,1,'Command','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(155,'skill',7,6,'Battle d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(156,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(157,'edge',76,1,'Tactician','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(158,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(159,'edge',3,1,'Arcane Background (Any)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(160,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(161,'edge',3,1,'Arcane Background (Any)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(162,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(163,'edge',3,1,'Arcane Background (Any)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(164,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(165,'arcane_background',1,1,'Arcane Background (Gifted)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(166,'skill',13,6,'Focus d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(167,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(168,'arcane_background',5,1,'Arcane Background (Weird Science)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(169,'skill',32,6,'Weird Science d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(170,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(171,'arcane_background',3,1,'Arcane Background (Miracles)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(172,'skill',11,6,'Faith d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(173,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(174,'arcane_background',4,1,'Arcane Background (Psionics)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(175,'skill',22,6,'Psionics d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(176,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(177,'edge',3,1,'Arcane Background (Any)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(178,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(179,'edge',3,1,'Arcane Background (Any)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(180,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(181,'wild_card',NULL,1,'Wild Card','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(182,'edge',3,1,'Arcane Background (Any)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(183,'arcane_skill',NULL,8,'arcane skill d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(184,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(185,'attribute',3,6,'Spirit d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(186,'edge',3,1,'Arcane Background (Any)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(187,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(188,'edge',88,1,'Rapid Recharge','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(189,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(190,'edge',3,1,'Arcane Background (Any)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(191,'arcane_skill',NULL,10,'arcane skill d10+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(192,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(193,'arcane_background',2,1,'Arcane Background (Magic)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(194,'skill',28,6,'Spellcasting d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(195,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(196,'attribute',1,8,'Agility d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(197,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(198,'attribute',1,8,'Agility d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(199,'skill',1,8,'Athletics d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(200,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(201,'edge',93,1,'Acrobat','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(202,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(203,'attribute',1,8,'Agility d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(204,'skill',12,6,'Fighting d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(205,'skill',5,8,'Stealth d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(206,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(207,'attribute',2,8,'Smarts d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(208,'skill',24,8,'Research d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(209,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(210,'attribute',2,10,'Smarts d10+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(211,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(212,'attribute',2,6,'Smarts d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(213,'skill',3,8,'Notice d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(214,'skill',23,6,'Repair d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(215,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(216,'skill',23,8,'Repair d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(217,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(218,'skill',24,8,'Research d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(219,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(220,'attribute',4,6,'Strength d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(221,'attribute',5,6,'Vigor d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(222,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(223,'attribute',1,8,'Agility d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(224,'skill',5,6,'Stealth d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(225,'skill',31,6,'Thievery d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(226,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(227,'attribute',3,6,'Spirit d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(228,'skill',29,8,'Survival d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(229,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(230,'attribute',3,8,'Spirit d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(231,'description',NULL,NULL,'Wild Card','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(232,'rank',1,1,'Novice (Common Bond)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(233,'attribute',3,8,'Spirit d8+ (Common Bond)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(234,'rank',1,1,'Novice (Connections)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(235,'rank',1,1,'Novice (Humiliate)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(236,'skill',30,8,'Taunt d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(237,'rank',1,1,'Novice (Menacing)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(238,'description',NULL,NULL,'Any one of: Bloodthirsty, Mean, Ruthless, or Ugly hindrance','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(239,'rank',1,1,'Novice (Provoke)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(240,'skill',30,6,'Taunt d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(241,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(242,'attribute',3,8,'Spirit d8+ (Rabble-Rouser)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(243,'rank',1,1,'Novice (Reliable)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(244,'attribute',3,8,'Spirit d8+ (Reliable)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(245,'rank',1,1,'Novice (Retort)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(246,'skill',30,6,'Taunt d6+ (Retort)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(247,'rank',1,1,'Novice (Streetwise)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(248,'attribute',2,6,'Smarts d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(249,'rank',1,1,'Novice (Strong Willed)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(250,'attribute',3,8,'Spirit d8+ (Strong Willed)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(251,'rank',2,2,'Seasoned (Iron Will)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(252,'edge',10,1,'Brave','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(253,'edge',114,1,'Strong Willed','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(254,'rank',1,1,'Novice (Work the Room)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(255,'attribute',3,8,'Spirit d8+ (Work the Room)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(256,'rank',2,2,'Seasoned (Work the Crowd)','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(257,'edge',116,1,'Work the Room','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(258,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(259,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(260,'attribute',3,8,'Spirit d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(261,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(262,'attribute',3,8,'Spirit d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(263,'skill',12,6,'Fighting d6+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(264,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(265,'edge',56,1,'Martial Warrior','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(266,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(267,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(268,'attribute',3,8,'Spirit d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(269,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(270,'attribute',5,8,'Vigor d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(271,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(272,'edge',20,1,'Luck','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(273,'wild_card',NULL,1,'Wild Card','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(274,'rank',5,5,'Legendary','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(275,'rank',5,5,'Legendary','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(276,'description',NULL,NULL,'Must have maximum die type possible in affected Trait','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(277,'rank',5,5,'Legendary','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(278,'edge',127,1,'Professional','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(279,'wild_card',NULL,1,'Wild Card','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(280,'rank',5,5,'Legendary','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(281,'edge',128,1,'Expert in affected Trait','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(282,'wild_card',NULL,1,'Wild Card','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(283,'rank',5,5,'Legendary','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(284,'rank',5,5,'Legendary','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(285,'attribute',5,8,'Vigor d8+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(286,'rank',5,5,'Legendary','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(287,'edge',131,1,'Tough as Nails','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(288,'attribute',5,12,'Vigor d12+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(289,'rank',5,5,'Legendary','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(290,'skill',12,12,'Fighting d12+','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(291,'rank',5,5,'Legendary','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(292,'edge',133,1,'Weapon Master','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(293,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(294,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(295,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(296,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(297,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(298,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(299,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(300,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(301,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(302,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(303,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(304,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(305,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(306,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(307,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(308,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(309,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(310,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(311,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(312,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(313,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(314,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(315,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(316,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(317,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(318,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(319,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(320,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(321,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(322,'rank',4,4,'Heroic','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(323,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(324,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(325,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(326,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(327,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(328,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(329,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(330,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(331,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(332,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(333,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(334,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(335,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(336,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(337,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(338,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(339,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(340,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(341,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(342,'rank',3,3,'Veteran','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(343,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(344,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(345,'rank',1,1,'Novice','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(346,'rank',2,2,'Seasoned','2025-10-08 06:13:12','2025-10-08 06:13:12',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(347,'edge',3,1,'Arcane Background','2025-10-18 03:20:11','2025-10-18 03:20:11',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(348,'edge',3,1,'Arcane Background','2025-10-18 03:20:11','2025-10-18 03:20:11',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(349,'edge',3,1,'Arcane Background','2025-10-18 03:20:11','2025-10-18 03:20:11',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(350,'edge',3,1,'Arcane Background','2025-10-18 03:20:11','2025-10-18 03:20:11',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(351,'edge',3,1,'Arcane Background','2025-10-18 03:20:11','2025-10-18 03:20:11',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(352,'edge',3,1,'Arcane Background (Any)','2026-01-02 18:47:38','2026-01-02 18:47:38',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(353,'skill',29,6,'Survival d6+','2026-01-03 21:47:33','2026-01-03 21:47:33',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(354,'edge',143,1,'Close Fighting','2026-01-03 21:50:39','2026-01-03 21:50:39',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(355,'skill',1,10,'Athletics d10+','2026-01-03 21:50:40','2026-01-03 21:50:40',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(356,'skill',27,10,'Shooting d10+','2026-01-03 21:50:40','2026-01-03 21:50:40',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(357,'edge',146,1,'Double Shot','2026-01-03 21:50:40','2026-01-03 21:50:40',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(358,'skill',12,10,'Fighting d10+','2026-01-03 21:50:45','2026-01-03 21:50:45',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(359,'edge',145,NULL,'Dirty Fighter','2026-01-03 21:50:57','2026-01-03 21:50:57',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(360,'description',NULL,NULL,'Breath Weapon','2026-01-03 21:51:00','2026-01-03 21:51:00',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(361,'edge',147,1,'Formation Fighter','2026-01-03 21:51:01','2026-01-03 21:51:01',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(362,'edge',95,NULL,'Assassin','2026-01-03 21:51:02','2026-01-03 21:51:02',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(363,'attribute',5,10,'Vigor d10+','2026-01-03 21:51:19','2026-01-03 21:51:19',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(364,'edge',27,1,'Iron Jaw','2026-01-03 21:51:19','2026-01-03 21:51:19',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(365,'description',NULL,NULL,'Wings','2026-01-03 21:51:21','2026-01-03 21:51:21',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(366,'edge',126,1,'Followers','2026-01-03 21:52:39','2026-01-03 21:52:39',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(367,'description',NULL,NULL,'At least two other Leadership edges','2026-01-03 21:52:39','2026-01-03 21:52:39',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(368,'description',NULL,NULL,'arcane skill d10+','2026-01-03 21:54:17','2026-01-03 21:54:17',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(369,'description',NULL,NULL,'an evil disposition','2026-01-03 21:54:18','2026-01-03 21:54:18',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(370,'description',NULL,NULL,'arcane skill d6+','2026-01-03 21:54:20','2026-01-03 21:54:20',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(371,'description',NULL,NULL,'Arcane Background (Diabolist, Druid, Elementalist, Necromancer, Shaman, Sorcerer, Warlock/Witch, or Wizard)','2026-01-03 21:54:21','2026-01-03 21:54:21',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(372,'description',NULL,NULL,'arcane skill d8+','2026-01-03 21:54:22','2026-01-03 21:54:22',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(373,'skill',19,10,'Occult d10+','2026-01-03 21:54:23','2026-01-03 21:54:23',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(374,'edge',170,NULL,'Artificer (FC)','2026-01-03 21:54:23','2026-01-03 21:54:23',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(375,'description',NULL,NULL,'One of: Barbarian (Strength d8+), Fighter (Fighting d8+), Monk (Athletics d8+), Paladin (Spirit d8+), Ranger (Survival d8+), or Rogue (Thievery d8+)','2026-01-03 21:54:24','2026-01-03 21:54:24',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(376,'description',NULL,NULL,'Arcane Background (Any but Bard)','2026-01-03 21:54:25','2026-01-03 21:54:25',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(377,'skill',19,8,'Occult d8+','2026-01-03 21:54:25','2026-01-03 21:54:25',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(378,'skill',25,6,'Riding d6+','2026-01-03 21:55:40','2026-01-03 21:55:40',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(379,'description',NULL,NULL,'Obligation (Major) hindrance','2026-01-03 21:55:42','2026-01-03 21:55:42',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(380,'skill',33,6,'Alchemy d6+','2026-01-03 21:55:44','2026-01-03 21:55:44',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(381,'skill',16,6,'Healing d6+','2026-01-03 21:55:44','2026-01-03 21:55:44',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(382,'skill',2,6,'Common Knowledge d6+','2026-01-03 21:55:48','2026-01-03 21:55:48',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(383,'skill',20,8,'Performance d8+','2026-01-03 21:55:48','2026-01-03 21:55:48',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(384,'description',NULL,NULL,'Lycanthropy','2026-01-03 21:58:11','2026-01-03 21:58:11',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(385,'rank',1,1,'Novice','2026-01-04 19:22:53','2026-01-04 19:22:53',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(386,'rank',2,2,'Seasoned','2026-01-04 19:22:53','2026-01-04 19:22:53',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(387,'rank',3,3,'Veteran','2026-01-04 19:22:53','2026-01-04 19:22:53',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(388,'rank',4,4,'Heroic','2026-01-04 19:22:53','2026-01-04 19:22:53',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(389,'rank',5,5,'Legendary','2026-01-04 19:22:53','2026-01-04 19:22:53',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(390,'description',NULL,NULL,'Arcane Background (Wizard)','2026-01-04 20:18:20','2026-01-04 20:18:20',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(391,'rank',4,4,'Heroic','2026-01-04 20:18:20','2026-01-04 20:18:20',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(392,'description',NULL,NULL,'Arcane Background (Wizard)','2026-01-04 20:50:17','2026-01-04 20:50:17',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(393,'rank',4,4,'Heroic','2026-01-04 20:50:17','2026-01-04 20:50:17',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(394,'description',NULL,NULL,'Arcane Background (Alchemist)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(395,'description',NULL,NULL,'Arcane Background (Bard)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(396,'description',NULL,NULL,'Arcane Background (Cleric)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(397,'description',NULL,NULL,'Arcane Background (Diabolist)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(398,'description',NULL,NULL,'Arcane Background (Druid)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(399,'description',NULL,NULL,'Arcane Background (Elementalist)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(400,'description',NULL,NULL,'Arcane Background (Illusionist)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(401,'description',NULL,NULL,'Arcane Background (Necromancer)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(402,'description',NULL,NULL,'Arcane Background (Shaman)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(403,'description',NULL,NULL,'Arcane Background (Sorcerer)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(404,'description',NULL,NULL,'Arcane Background (Summoner)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(405,'description',NULL,NULL,'Arcane Background (Tinkerer)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(406,'description',NULL,NULL,'Arcane Background (Warlock/Witch)','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(407,'rank',2,2,'Seasoned','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(408,'rank',3,3,'Veteran','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(409,'rank',4,4,'Heroic','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(410,'rank',5,5,'Legendary','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(411,'skill',33,8,'Alchemy d8+','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(412,'skill',33,10,'Alchemy d10+','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(413,'skill',20,8,'Performance d8+','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(414,'skill',11,8,'Faith d8+','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(415,'skill',28,8,'Spellcasting d8+','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(416,'skill',28,10,'Spellcasting d10+','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(417,'skill',18,10,'Occult d10+','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(418,'description',NULL,NULL,'Great Power','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(419,'description',NULL,NULL,'summon animal power','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+INSERT INTO requirements(id,requirement_type,target_id,value,description,created_at,updated_at,target_name) VALUES(420,'description',NULL,NULL,'summon monster power','2026-01-04 20:56:14','2026-01-04 20:56:14',NULL);
+COMMIT;
@@ -0,0 +1,27 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE schema_migrations (
+    version INTEGER PRIMARY KEY,
+    description TEXT NOT NULL,
+    applied_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(1,'Baseline schema','2026-10-16 10:07:15');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(2,'Add archived flag to characters','2026-10-16 10:14:46');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(3,'Add pinned flag and category to character notes','2026-10-16 10:18:06');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(4,'Add maintenance cost to powers','2026-10-16 10:22:44');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(5,'Move hindrance advance sources out of notes','2026-10-16 10:31:56');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(6,'Allow grant_skill modifiers','2026-10-16 10:39:53');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(7,'Allow max_die_increase modifiers','2026-10-16 10:41:55');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(8,'Add load limit multiplier to character config','2026-10-16 10:54:37');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(9,'Add situational condition to modifiers','2026-10-16 11:10:39');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(10,'Add parry skill to character config','2026-10-16 11:15:00');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(11,'Allow parry_skill modifiers','2026-10-16 11:15:00');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(12,'Add max wounds to character config','2026-10-16 11:21:02');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(13,'Add experience tracking to characters','2026-10-16 11:36:56');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(14,'Allow requirements to name their target','2026-10-16 11:40:09');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(15,'Add archetype templates','2026-10-16 11:52:17');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(16,'Add character snapshots','2026-10-16 12:00:20');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(17,'Link arcane backgrounds to the edge that grants them','2026-10-16 12:35:04');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(18,'Add sell-back rate to character config','2026-10-16 13:13:56');
+INSERT INTO schema_migrations(version,description,applied_at) VALUES(19,'Add weight unit to character config','2026-10-16 13:16:57');
+COMMIT;
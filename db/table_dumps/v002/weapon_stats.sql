@@ -0,0 +1,116 @@
+PRAGMA foreign_keys=OFF;
+BEGIN TRANSACTION;
+CREATE TABLE weapon_stats (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    gear_id INTEGER NOT NULL UNIQUE,
+    damage VARCHAR(30) NOT NULL, -- e.g., "Str+d4", "2d6", "3d6"
+    ap INTEGER NOT NULL DEFAULT 0, -- Armor Piercing value
+    range_short INTEGER, -- Short range in inches (NULL for melee)
+    range_medium INTEGER, -- Medium range in inches (NULL for melee)
+    range_long INTEGER, -- Long range in inches (NULL for melee)
+    rof INTEGER, -- Rate of Fire (NULL for melee)
+    shots INTEGER, -- Magazine capacity (NULL for melee/thrown)
+    min_strength INTEGER, -- Minimum strength die size (4, 6, 8, 10, 12)
+    is_two_handed BOOLEAN NOT NULL DEFAULT 0,
+    reach INTEGER, -- Melee reach in inches (0 for normal, 1+ for extended reach)
+    blast_template VARCHAR(10), -- 'SBT', 'MBT', 'LBT' for area effect weapons (alchemical items)
+    notes TEXT, -- Weapon-specific notes (reload, snapfire, etc.)
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (gear_id) REFERENCES gear(id) ON DELETE CASCADE
+);
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(1,26,'Str+d6',0,NULL,NULL,NULL,NULL,NULL,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(2,27,'Str+d10',2,NULL,NULL,NULL,NULL,NULL,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(3,28,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(4,29,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(5,30,'Str+d6',1,NULL,NULL,NULL,NULL,NULL,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(6,31,'Str+d10',2,NULL,NULL,NULL,NULL,NULL,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(7,32,'Str+d8',2,NULL,NULL,NULL,NULL,NULL,NULL,1,1,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(8,33,'Str+d6+2',1,NULL,NULL,NULL,NULL,NULL,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(9,34,'Str+d8',2,NULL,NULL,NULL,NULL,NULL,NULL,0,2,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(10,35,'Str+d8',0,NULL,NULL,NULL,NULL,NULL,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(11,36,'Str+d6',0,NULL,NULL,NULL,NULL,NULL,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(12,37,'Str+d10',2,NULL,NULL,NULL,NULL,NULL,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(13,38,'Str+d8',2,NULL,NULL,NULL,NULL,NULL,NULL,1,2,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(14,39,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(15,40,'Str+d6',0,NULL,NULL,NULL,NULL,NULL,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(16,41,'Str+d6',0,NULL,NULL,NULL,NULL,NULL,NULL,0,1,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(17,42,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,NULL,1,1,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(18,43,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,NULL,0,2,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(19,44,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(20,45,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(21,46,'Str+d4+2',4,NULL,NULL,NULL,NULL,NULL,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(22,47,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(23,48,'2d6',0,12,24,48,1,1,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(24,49,'2d6',2,15,30,60,1,1,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(25,50,'2d6',1,15,30,60,1,1,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(26,51,'Str+d4',0,4,8,16,1,1,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(27,52,'Str+d4',0,3,6,12,1,1,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(28,53,'2d4',0,3,6,12,1,2,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(29,54,'2d6',1,12,24,48,1,17,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(30,55,'2d6+1',1,12,24,48,1,7,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(31,56,'2d8',2,15,30,60,1,7,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(32,57,'2d4',0,10,20,40,1,9,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(33,58,'2d6+1',1,12,24,48,1,6,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(34,59,'1-3d6',0,12,24,48,1,2,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(35,60,'1-3d6',0,12,24,48,1,6,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(36,61,'2d8',2,24,48,96,3,30,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(37,62,'2d8+1',2,24,48,96,3,30,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(38,63,'2d8',2,24,48,96,1,5,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(39,64,'2d10',4,50,100,200,1,5,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(40,65,'2d6',1,12,24,48,3,30,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(41,66,'2d6',1,12,24,48,3,32,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(42,67,'2d6',1,12,24,48,3,30,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(43,68,'2d8+1',2,30,60,120,3,100,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(44,69,'2d6',2,15,30,60,1,50,NULL,0,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(45,70,'3d6',2,30,60,120,3,100,NULL,1,NULL,NULL,NULL,'2025-12-30 18:25:49','2025-12-30 18:25:49');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(46,184,'Str+d8',0,NULL,NULL,NULL,NULL,NULL,8,0,NULL,NULL,NULL,'2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(47,185,'Str+d10',0,NULL,NULL,NULL,NULL,NULL,10,1,NULL,NULL,'Two-handed','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(48,186,'Str+d6',0,NULL,NULL,NULL,NULL,NULL,6,0,NULL,NULL,NULL,'2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(49,187,'Str+d6',0,3,6,12,NULL,NULL,6,0,NULL,NULL,'Can be thrown','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(50,188,'Str+d6',0,NULL,NULL,NULL,NULL,NULL,6,0,NULL,NULL,NULL,'2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(51,189,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,4,0,NULL,NULL,NULL,'2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(52,190,'Str+d4',0,3,6,12,NULL,NULL,4,0,NULL,NULL,'Can be thrown','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(53,191,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,4,0,NULL,NULL,'+1 Parry when wielded with one-handed weapon','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(54,192,'Str+d6',0,NULL,NULL,NULL,NULL,NULL,8,0,NULL,NULL,'Ignores shield bonus','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(55,193,'Str+d8',0,NULL,NULL,NULL,NULL,NULL,8,1,1,NULL,'Reach 1','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(56,194,'Str+d8',0,NULL,NULL,NULL,NULL,NULL,8,1,1,NULL,'Reach 1','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(57,195,'Str+d6',1,NULL,NULL,NULL,NULL,NULL,6,0,NULL,NULL,'AP 1 vs rigid armor','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(58,196,'Str+d6',0,3,6,12,NULL,NULL,6,0,NULL,NULL,'Throwing weapon','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(59,197,'Str+d6+1',0,NULL,NULL,NULL,NULL,NULL,6,0,NULL,NULL,'Fine blade','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(60,198,'Str+d4',0,3,6,12,NULL,NULL,4,0,NULL,NULL,'Can be thrown','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(61,199,'Str+d8',2,NULL,NULL,NULL,NULL,NULL,8,1,2,NULL,'Reach 2, AP 2 when charging','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(62,200,'Str+d6',1,NULL,NULL,NULL,NULL,NULL,6,0,NULL,NULL,'AP 1 vs rigid armor','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(63,201,'Str+d6',1,NULL,NULL,NULL,NULL,NULL,6,0,NULL,NULL,'AP 1 vs rigid armor','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(64,202,'Str+d8',0,NULL,NULL,NULL,NULL,NULL,10,1,2,NULL,'Reach 2','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(65,203,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,4,1,NULL,NULL,'+1 Parry','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(66,204,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,4,0,NULL,NULL,'+1 Parry','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(67,205,'Str+d6',0,NULL,NULL,NULL,NULL,NULL,6,0,NULL,NULL,'Curved blade','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(68,206,'Str+d6',0,NULL,NULL,NULL,NULL,NULL,6,1,1,NULL,'Improvised weapon -1 to hit, Reach 1','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(69,207,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,4,0,NULL,NULL,'Curved blade','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(70,208,'Str+d6',0,3,6,12,NULL,NULL,6,0,1,NULL,'Reach 1, can be thrown','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(71,209,'Str+d4',0,3,6,12,NULL,NULL,4,0,NULL,NULL,'Can be thrown','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(72,210,'Str+d8',0,NULL,NULL,NULL,NULL,NULL,8,0,NULL,NULL,'One or two-handed','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(73,211,'Str+d10',0,NULL,NULL,NULL,NULL,NULL,10,1,NULL,NULL,'Two-handed','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(74,212,'Str+d6',0,NULL,NULL,NULL,NULL,NULL,6,0,NULL,NULL,'Standard sword','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(75,213,'Str+d6',0,NULL,NULL,NULL,NULL,NULL,4,0,NULL,NULL,'Shorter blade','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(76,214,'Str+d6',0,NULL,NULL,NULL,NULL,NULL,6,0,1,NULL,'Reach 1','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(77,215,'Str+d8',2,NULL,NULL,NULL,NULL,NULL,10,1,NULL,NULL,'AP 2 vs rigid armor','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(78,216,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,4,0,2,NULL,'Reach 2, can entangle','2026-01-04 23:31:24','2026-01-04 23:31:24');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(79,217,'d4',0,3,6,12,1,1,4,1,NULL,NULL,'Uses darts','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(80,218,'2d6',0,15,30,60,1,1,8,1,NULL,NULL,'Str requirement for full damage','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(81,219,'2d6',0,15,30,60,1,1,8,1,NULL,NULL,NULL,'2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(82,220,'2d6',0,12,24,48,1,1,6,1,NULL,NULL,NULL,'2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(83,221,'2d6',2,15,30,60,1,1,8,1,NULL,NULL,'Reload 2 actions','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(84,222,'2d6',2,10,20,40,1,1,6,1,NULL,NULL,'Reload 1 action','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(85,223,'—',0,3,6,12,1,1,6,0,NULL,NULL,'Entangles on hit','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(86,224,'Str+d4',0,4,8,16,1,1,4,0,NULL,NULL,'Uses stones','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(87,225,'Str+d6',0,5,10,20,1,1,6,1,NULL,NULL,'Extended range','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(88,226,'2d4',0,3,6,12,1,1,4,0,NULL,'SBT','2d4-2 next round','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(89,227,'2d4',0,3,6,12,1,1,4,0,NULL,'SBT','Heavy Weapon, may ignite','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(90,230,'2d4',0,3,6,12,1,1,4,0,NULL,'SBT','Only affects undead/evil','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(91,231,'—',0,3,6,12,1,1,4,0,NULL,'MBT','Creates Dark illumination','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(92,232,'Str+d4',0,NULL,NULL,NULL,NULL,NULL,4,0,NULL,NULL,'Can use as melee weapon','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(93,233,'—',0,1,2,4,1,1,4,0,NULL,NULL,'Distracted, Athletics or Entangled','2026-01-04 23:32:27','2026-01-04 23:32:27');
+INSERT INTO weapon_stats(id,gear_id,damage,ap,range_short,range_medium,range_long,rof,shots,min_strength,is_two_handed,reach,blast_template,notes,created_at,updated_at) VALUES(94,234,'—',0,3,6,12,1,1,4,0,NULL,'MBT','Vigor or Shaken and deafened','2026-01-04 23:32:27','2026-01-04 23:32:27');
+COMMIT;
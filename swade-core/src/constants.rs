@@ -15,10 +15,46 @@ pub const BASE_PARRY: i32 = 2;
 /// Base toughness before Vigor bonus, size, and modifiers.
 pub const BASE_TOUGHNESS: i32 = 2;
 
+/// Skill Parry is derived from unless a setting or modifier says otherwise.
+pub const DEFAULT_PARRY_SKILL: &str = "Fighting";
+
+/// Derived stat names that `derived_stat` modifiers may target.
+pub const DERIVED_STAT_NAMES: [&str; 6] = ["pace", "parry", "toughness", "size", "soak", "shaken_recovery"];
+
+/// Lowest Toughness a character can have, however small or frail.
+pub const MIN_TOUGHNESS: i32 = 1;
+
+/// Pounds of load limit per Strength die step (d4 = 20, d6 = 40, ...).
+pub const LOAD_LIMIT_PER_STRENGTH_STEP: i64 = 20;
+
+/// Gear weights are stored in pounds; this converts them for metric display.
+pub const KILOGRAMS_PER_POUND: f64 = 0.453_592_37;
+
+/// Pace lost while encumbered.
+pub const ENCUMBRANCE_PACE_PENALTY: i64 = 2;
+
+/// Penalty to Agility and Strength rolls (and their linked skills) while encumbered.
+pub const ENCUMBRANCE_TRAIT_PENALTY: i64 = 2;
+
+/// Largest penalty wounds can apply to trait rolls.
+pub const MAX_WOUND_PENALTY: i64 = 3;
+
+/// Wounds a Wild Card can take; one more leaves them Incapacitated.
+pub const MAX_WOUNDS: i64 = 3;
+
+/// Fatigue levels before Incapacitation (Fatigued, Exhausted).
+pub const MAX_FATIGUE: i64 = 2;
+
 // ============================================================================
 // Point Costs
 // ============================================================================
 
+/// Attribute points to raise an attribute one die step at creation.
+pub const ATTRIBUTE_STEP_COST: i64 = 1;
+
+/// Maximum hindrance points a character can earn at creation.
+pub const MAX_HINDRANCE_POINTS: i64 = 4;
+
 /// Cost in hindrance points to purchase one edge.
 pub const EDGE_HINDRANCE_POINT_COST: i64 = 2;
 
@@ -28,6 +64,20 @@ pub const ATTRIBUTE_HINDRANCE_POINT_COST: i64 = 2;
 /// Skill points to hindrance points conversion ratio (1:1).
 pub const SKILL_HINDRANCE_POINT_RATIO: i64 = 1;
 
+/// Starting funds for a new character.
+pub const STARTING_WEALTH: i64 = 500;
+
+/// Additional starting funds per hindrance point. One point doubles the
+/// starting funds.
+pub const WEALTH_PER_HINDRANCE_POINT: i64 = STARTING_WEALTH;
+
+/// Percentage of an item's cost refunded when it is sold back, unless the
+/// setting says otherwise.
+pub const DEFAULT_SELL_BACK_PERCENT: i64 = 50;
+
+/// Experience points per advance, for tables that track XP.
+pub const XP_PER_ADVANCE: i64 = 5;
+
 // ============================================================================
 // Source Type Identifiers
 // ============================================================================
@@ -44,3 +94,28 @@ pub const SOURCE_HINDRANCE_POINTS: &str = "hindrance_points";
 
 /// Source for hindrances/powers from an arcane background.
 pub const SOURCE_ARCANE_BACKGROUND: &str = "arcane_background";
+
+// ============================================================================
+// Dice
+// ============================================================================
+
+/// Highest bonus a d12 can legally carry (d12+3, from stacking Professional,
+/// Expert and Master). Stored dice past this are treated as corrupt.
+pub const MAX_DIE_MODIFIER: u8 = 3;
+
+// ============================================================================
+// Character Info
+// ============================================================================
+
+/// Longest character name accepted, in characters.
+pub const MAX_CHARACTER_NAME_LENGTH: usize = 100;
+
+// ============================================================================
+// Portraits
+// ============================================================================
+
+/// Largest width or height a stored portrait may have, in pixels.
+pub const MAX_PORTRAIT_DIMENSION: u32 = 512;
+
+/// Mime type portraits are re-encoded to before they are stored.
+pub const PORTRAIT_MIME_TYPE: &str = "image/png";
@@ -0,0 +1,265 @@
+//! Backups of the user database.
+//!
+//! A timestamped copy of the database is written to `backups/` in the user data
+//! directory before every update. Only the most recent `MAX_BACKUPS` are kept.
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Result, SwadeError};
+
+use super::embedded::{DB_FILENAME, refresh_database, user_data_dir};
+
+/// Name of the backup directory inside the user data directory
+const BACKUP_DIRNAME: &str = "backups";
+
+/// Prefix of backup file names, followed by the timestamp
+const BACKUP_PREFIX: &str = "swade-";
+
+/// Timestamp format used in backup file names
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S%.3f";
+
+/// Number of backups kept before the oldest are deleted
+pub const MAX_BACKUPS: usize = 5;
+
+/// A backup of the user database.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct BackupInfo {
+    /// File name inside the backup directory, used to restore it.
+    pub file_name: String,
+    /// When the backup was taken (UTC).
+    pub created_at: String,
+    pub size_bytes: i64,
+}
+
+/// List backups of the user database, newest first.
+pub fn list_backups() -> Result<Vec<BackupInfo>> {
+    list_backups_in(&user_data_dir()?)
+}
+
+/// Replace the user database with a backup.
+///
+/// The restored database is brought up to the current embedded version, so
+/// backups taken before an update can still be opened. The current database
+/// is backed up first, so a restore can itself be undone.
+pub fn restore_backup(file_name: &str) -> Result<()> {
+    restore_backup_in(&user_data_dir()?, file_name)
+}
+
+fn backup_dir(data_dir: &Path) -> PathBuf {
+    data_dir.join(BACKUP_DIRNAME)
+}
+
+/// Copy the database in `data_dir` into the backup directory and prune old
+/// backups. Returns None if there is no database to back up.
+pub(super) fn backup_database(data_dir: &Path) -> Result<Option<PathBuf>> {
+    let db_path = data_dir.join(DB_FILENAME);
+    if !db_path.exists() {
+        return Ok(None);
+    }
+
+    let dir = backup_dir(data_dir);
+    fs::create_dir_all(&dir)?;
+
+    let timestamp = chrono::Utc::now().format(BACKUP_TIMESTAMP_FORMAT);
+    let backup_path = dir.join(format!("{}{}.db", BACKUP_PREFIX, timestamp));
+    fs::copy(&db_path, &backup_path)?;
+
+    prune_backups(data_dir, MAX_BACKUPS)?;
+
+    Ok(Some(backup_path))
+}
+
+/// Delete all but the newest `keep` backups.
+fn prune_backups(data_dir: &Path, keep: usize) -> Result<()> {
+    for backup in list_backups_in(data_dir)?.into_iter().skip(keep) {
+        fs::remove_file(backup_dir(data_dir).join(backup.file_name))?;
+    }
+    Ok(())
+}
+
+/// Parse the timestamp out of a backup file name, or None if it isn't one.
+fn backup_timestamp(file_name: &str) -> Option<NaiveDateTime> {
+    let timestamp = file_name.strip_prefix(BACKUP_PREFIX)?.strip_suffix(".db")?;
+    NaiveDateTime::parse_from_str(timestamp, BACKUP_TIMESTAMP_FORMAT).ok()
+}
+
+pub(super) fn list_backups_in(data_dir: &Path) -> Result<Vec<BackupInfo>> {
+    let dir = backup_dir(data_dir);
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(&dir)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if let Some(timestamp) = backup_timestamp(&file_name) {
+            backups.push((
+                timestamp,
+                BackupInfo {
+                    file_name,
+                    created_at: timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    size_bytes: entry.metadata()?.len() as i64,
+                },
+            ));
+        }
+    }
+
+    backups.sort_by_key(|(timestamp, _)| std::cmp::Reverse(*timestamp));
+    Ok(backups.into_iter().map(|(_, backup)| backup).collect())
+}
+
+fn restore_backup_in(data_dir: &Path, file_name: &str) -> Result<()> {
+    // Only restore files listed as backups, never an arbitrary path
+    let backup = list_backups_in(data_dir)?
+        .into_iter()
+        .find(|b| b.file_name == file_name)
+        .ok_or_else(|| SwadeError::NotFound("Backup".to_string()))?;
+
+    // Read the backup before taking a new one, which may prune it
+    let contents = fs::read(backup_dir(data_dir).join(&backup.file_name))?;
+    backup_database(data_dir)?;
+
+    let db_path = data_dir.join(DB_FILENAME);
+    fs::write(&db_path, contents)?;
+
+    refresh_database(data_dir)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+    use tempfile::TempDir;
+
+    fn extract_with_character(data_dir: &Path) -> PathBuf {
+        let db_path = data_dir.join(DB_FILENAME);
+        refresh_database(data_dir).unwrap();
+        let conn = Connection::open(&db_path).unwrap();
+        conn.execute(
+            "INSERT INTO characters (id, is_wild_card, name) VALUES (1, 1, 'Test Hero')",
+            [],
+        )
+        .unwrap();
+        db_path
+    }
+
+    fn character_count(db_path: &Path) -> i64 {
+        Connection::open(db_path)
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM characters", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn backup_without_database_is_none() {
+        let temp = TempDir::new().unwrap();
+
+        assert!(backup_database(temp.path()).unwrap().is_none());
+        assert!(list_backups_in(temp.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn backup_is_restorable() {
+        let temp = TempDir::new().unwrap();
+        let db_path = extract_with_character(temp.path());
+
+        let backup_path = backup_database(temp.path()).unwrap().unwrap();
+        assert!(backup_path.exists());
+
+        Connection::open(&db_path)
+            .unwrap()
+            .execute("DELETE FROM characters", [])
+            .unwrap();
+        assert_eq!(character_count(&db_path), 0);
+
+        let backups = list_backups_in(temp.path()).unwrap();
+        assert_eq!(backups.len(), 1);
+        restore_backup_in(temp.path(), &backups[0].file_name).unwrap();
+
+        assert_eq!(character_count(&db_path), 1);
+    }
+
+    #[test]
+    fn restore_backs_up_current_database() {
+        let temp = TempDir::new().unwrap();
+        let db_path = extract_with_character(temp.path());
+        let file_name = "swade-20240101-120000.000.db";
+        fs::create_dir_all(backup_dir(temp.path())).unwrap();
+        fs::copy(&db_path, backup_dir(temp.path()).join(file_name)).unwrap();
+
+        Connection::open(&db_path)
+            .unwrap()
+            .execute(
+                "INSERT INTO characters (id, is_wild_card, name) VALUES (2, 1, 'Newer Hero')",
+                [],
+            )
+            .unwrap();
+        restore_backup_in(temp.path(), file_name).unwrap();
+        assert_eq!(character_count(&db_path), 1);
+
+        let backups = list_backups_in(temp.path()).unwrap();
+        assert_eq!(backups.len(), 2);
+        assert_ne!(backups[0].file_name, file_name);
+        let pre_restore = backup_dir(temp.path()).join(&backups[0].file_name);
+        assert_eq!(character_count(&pre_restore), 2);
+    }
+
+    #[test]
+    fn restore_keeps_oldest_backup_when_pruning() {
+        let temp = TempDir::new().unwrap();
+        let db_path = extract_with_character(temp.path());
+        let dir = backup_dir(temp.path());
+        fs::create_dir_all(&dir).unwrap();
+        for day in 1..=MAX_BACKUPS {
+            fs::copy(&db_path, dir.join(format!("swade-202401{:02}-120000.000.db", day))).unwrap();
+        }
+        Connection::open(&db_path)
+            .unwrap()
+            .execute("DELETE FROM characters", [])
+            .unwrap();
+
+        restore_backup_in(temp.path(), "swade-20240101-120000.000.db").unwrap();
+
+        assert_eq!(character_count(&db_path), 1);
+        assert_eq!(list_backups_in(temp.path()).unwrap().len(), MAX_BACKUPS);
+    }
+
+    #[test]
+    fn restore_rejects_unknown_file() {
+        let temp = TempDir::new().unwrap();
+        extract_with_character(temp.path());
+
+        let result = restore_backup_in(temp.path(), "../swade.db");
+
+        assert!(matches!(result, Err(SwadeError::NotFound(_))));
+    }
+
+    #[test]
+    fn prune_keeps_newest_backups() {
+        let temp = TempDir::new().unwrap();
+        let dir = backup_dir(temp.path());
+        fs::create_dir_all(&dir).unwrap();
+        for day in 1..=7 {
+            fs::write(
+                dir.join(format!("swade-202401{:02}-120000.000.db", day)),
+                b"db",
+            )
+            .unwrap();
+        }
+        fs::write(dir.join("notes.txt"), b"not a backup").unwrap();
+
+        prune_backups(temp.path(), MAX_BACKUPS).unwrap();
+
+        let backups = list_backups_in(temp.path()).unwrap();
+        assert_eq!(backups.len(), MAX_BACKUPS);
+        assert_eq!(backups[0].created_at, "2024-01-07 12:00:00");
+        assert_eq!(backups[4].created_at, "2024-01-03 12:00:00");
+        assert!(dir.join("notes.txt").exists());
+    }
+}
@@ -8,6 +8,7 @@ use rusqlite::Connection;
 use std::path::PathBuf;
 
 use super::embedded::ensure_database;
+use super::migrations::run_pending_migrations;
 
 /// Database handle for obtaining connections to the SWADE database.
 ///
@@ -37,7 +38,8 @@ impl Database {
     /// 1. Determine the user data directory
     /// 2. Check if the database needs to be extracted or updated
     /// 3. Extract the embedded database if necessary
-    /// 4. Return a `Database` handle for obtaining connections
+    /// 4. Apply any pending schema migrations
+    /// 5. Return a `Database` handle for obtaining connections
     ///
     /// # Errors
     ///
@@ -47,7 +49,9 @@ impl Database {
     /// - The database migration fails
     pub fn init() -> Result<Self> {
         let path = ensure_database()?;
-        Ok(Self { path })
+        let db = Self { path };
+        run_pending_migrations(&db.connection()?)?;
+        Ok(db)
     }
 
     /// Create a database handle from a specific path.
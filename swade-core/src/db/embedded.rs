@@ -5,6 +5,9 @@
 
 use crate::error::{Result, SwadeError};
 use rusqlite::Connection;
+
+use super::backup::backup_database;
+use super::migrations::{mark_all_applied, run_pending_migrations};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -18,33 +21,69 @@ const EMBEDDED_DB_CHECKSUM: &str = env!("SWADE_DB_CHECKSUM");
 const APP_NAME: &str = "swade";
 
 /// Name of the database file
-const DB_FILENAME: &str = "swade.db";
+pub(super) const DB_FILENAME: &str = "swade.db";
 
 /// Name of the checksum file stored alongside the database
 const CHECKSUM_FILENAME: &str = "swade.db.checksum";
 
+/// Tables holding user data, which updates never overwrite. Every other table
+/// holds game rules and is refreshed from the embedded database.
+const USER_TABLES: &[&str] = &[
+    "characters",
+    "character_attributes",
+    "character_skills",
+    "character_edges",
+    "character_hindrances",
+    "character_powers",
+    "character_modifiers",
+    "character_arcane_backgrounds",
+    "character_arcane_background_choices",
+    "character_ancestry_choices",
+    "character_notes",
+    "character_gear",
+    "character_advances",
+    "companion_links",
+    "character_snapshots",
+    "schema_migrations",
+];
+
 /// Ensures the database exists and is up to date in the user data directory.
 /// Returns the path to the database file.
 pub fn ensure_database() -> Result<PathBuf> {
     let data_dir = user_data_dir()?;
+    ensure_database_in(&data_dir)
+}
+
+/// Ensures the database in `data_dir` exists and is up to date.
+///
+/// An existing database is backed up before it's updated.
+fn ensure_database_in(data_dir: &Path) -> Result<PathBuf> {
     let db_path = data_dir.join(DB_FILENAME);
 
     // Create the data directory if it doesn't exist
     if !data_dir.exists() {
-        fs::create_dir_all(&data_dir)?;
+        fs::create_dir_all(data_dir)?;
     }
 
     // Check if we need to update the database
-    if needs_update(&data_dir)? {
-        extract_database(&db_path)?;
-        write_checksum(&data_dir)?;
+    if needs_update(data_dir)? {
+        backup_database(data_dir)?;
+        update_database(&db_path)?;
+        write_checksum(data_dir)?;
     }
 
     Ok(db_path)
 }
 
+/// Bring the database in `data_dir` up to the embedded version, keeping its
+/// user data. Used after restoring a backup taken with an older version.
+pub(super) fn refresh_database(data_dir: &Path) -> Result<()> {
+    update_database(&data_dir.join(DB_FILENAME))?;
+    write_checksum(data_dir)
+}
+
 /// Returns the path to the user data directory for this application.
-fn user_data_dir() -> Result<PathBuf> {
+pub(super) fn user_data_dir() -> Result<PathBuf> {
     #[cfg(target_os = "linux")]
     {
         if let Ok(xdg_data) = std::env::var("XDG_DATA_HOME") {
@@ -99,29 +138,28 @@ fn needs_update(data_dir: &Path) -> Result<bool> {
     Ok(stored_checksum.trim() != EMBEDDED_DB_CHECKSUM)
 }
 
-/// Extracts the embedded database to the specified path.
-/// If a database already exists with user data, migrates the user data.
-fn extract_database(db_path: &Path) -> Result<()> {
-    let has_existing_db = db_path.exists();
-
-    if has_existing_db {
-        // Database exists - need to migrate user data
-        let temp_path = db_path.with_extension("db.new");
-
-        // Write embedded database to temp location
-        fs::write(&temp_path, EMBEDDED_DB).map_err(SwadeError::Extraction)?;
-
-        // Migrate user data from old database to new
-        migrate_user_data(db_path, &temp_path)?;
-
-        // Replace old database with new one
-        fs::rename(&temp_path, db_path).map_err(SwadeError::Extraction)?;
-    } else {
-        // No existing database - just extract
+/// Extracts the embedded database to the specified path, or updates the
+/// database already there.
+///
+/// An existing database is migrated in place: pending schema migrations run
+/// against it, then its game rules tables are replaced with the embedded
+/// copy's. Character tables are never rewritten.
+fn update_database(db_path: &Path) -> Result<()> {
+    if !db_path.exists() {
         fs::write(db_path, EMBEDDED_DB).map_err(SwadeError::Extraction)?;
+        // The embedded schema is already current
+        return mark_all_applied(&Connection::open(db_path)?);
     }
 
-    Ok(())
+    let conn = Connection::open(db_path)?;
+    run_pending_migrations(&conn)?;
+
+    // Attach the embedded database from a temporary copy
+    let embedded_path = db_path.with_extension("db.new");
+    fs::write(&embedded_path, EMBEDDED_DB).map_err(SwadeError::Extraction)?;
+    let result = refresh_reference_data(&conn, &embedded_path);
+    fs::remove_file(&embedded_path)?;
+    result
 }
 
 /// Writes the current embedded checksum to the data directory.
@@ -131,72 +169,67 @@ fn write_checksum(data_dir: &Path) -> Result<()> {
     Ok(())
 }
 
-/// Migrates user data (characters) from the old database to the new database.
-fn migrate_user_data(old_db_path: &Path, new_db_path: &Path) -> Result<()> {
-    let old_conn = Connection::open(old_db_path)?;
-    let new_conn = Connection::open(new_db_path)?;
-
-    // Attach the old database to the new connection
-    new_conn.execute(
-        "ATTACH DATABASE ?1 AS old_db",
-        [old_db_path.to_string_lossy().as_ref()],
+/// Replace every game rules table in `conn` with the rows from the database
+/// at `embedded_path`, in one transaction.
+///
+/// Rows keep their ids, so characters' references to edges, skills, and so on
+/// stay valid. Foreign keys are off while the tables are emptied and refilled.
+fn refresh_reference_data(conn: &Connection, embedded_path: &Path) -> Result<()> {
+    conn.execute(
+        "ATTACH DATABASE ?1 AS embedded",
+        [embedded_path.to_string_lossy().as_ref()],
     )?;
 
-    // List of character tables to migrate (order matters for foreign keys)
-    let character_tables = [
-        "characters",
-        "character_attributes",
-        "character_skills",
-        "character_edges",
-        "character_hindrances",
-        "character_powers",
-        "character_modifiers",
-        "character_arcane_backgrounds",
-        "character_ancestry_choices",
-        "character_notes",
-        "character_gear",
-        "character_advancements",
-    ];
-
-    // Migrate each character table
-    for table in character_tables {
-        // Check if table exists in old database and has data
-        let has_data: bool = old_conn
-            .query_row(
-                "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type='table' AND name=?1)",
-                [table],
-                |row| row.get(0),
-            )
-            .unwrap_or(false);
+    // The pragma is a no-op inside a transaction, so it has to be set first
+    let foreign_keys_on: bool = conn.query_row("PRAGMA foreign_keys", [], |row| row.get(0))?;
+    conn.execute_batch("PRAGMA foreign_keys = OFF")?;
 
-        if has_data {
-            // Get column names from the new table
-            let columns = get_table_columns(&new_conn, table)?;
+    let result = copy_reference_tables(conn);
 
-            if !columns.is_empty() {
-                let columns_str = columns.join(", ");
-
-                // Copy data from old table to new table
-                let sql = format!(
-                    "INSERT OR REPLACE INTO main.{} ({}) SELECT {} FROM old_db.{}",
-                    table, columns_str, columns_str, table
-                );
+    if foreign_keys_on {
+        conn.execute_batch("PRAGMA foreign_keys = ON")?;
+    }
+    conn.execute("DETACH DATABASE embedded", [])?;
+    result
+}
 
-                // Ignore errors if old table doesn't have the data
-                let _ = new_conn.execute(&sql, []);
-            }
+fn copy_reference_tables(conn: &Connection) -> Result<()> {
+    let tables: Vec<String> = conn
+        .prepare(
+            "SELECT name FROM embedded.sqlite_master
+              WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
+        )?
+        .query_map([], |row| row.get(0))?
+        .collect::<rusqlite::Result<_>>()?;
+
+    let tx = conn.unchecked_transaction()?;
+    for table in tables.iter().filter(|t| !USER_TABLES.contains(&t.as_str())) {
+        let embedded_columns = get_table_columns(&tx, "embedded", table)?;
+        let columns: Vec<String> = get_table_columns(&tx, "main", table)?
+            .into_iter()
+            .filter(|c| embedded_columns.contains(c))
+            .collect();
+        if columns.is_empty() {
+            return Err(SwadeError::Migration(format!(
+                "Table {} is missing from the user database",
+                table
+            )));
         }
-    }
 
-    // Detach the old database
-    new_conn.execute("DETACH DATABASE old_db", [])?;
+        let columns_str = columns.join(", ");
+        tx.execute_batch(&format!(
+            "DELETE FROM main.{table};
+             INSERT INTO main.{table} ({columns_str}) SELECT {columns_str} FROM embedded.{table};"
+        ))?;
+    }
+    tx.commit()?;
 
     Ok(())
 }
 
-/// Gets the column names for a table.
-fn get_table_columns(conn: &Connection, table: &str) -> Result<Vec<String>> {
-    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+/// Gets the column names for a table in the given attached schema.
+fn get_table_columns(conn: &Connection, schema: &str, table: &str) -> Result<Vec<String>> {
+    let mut stmt = conn.prepare(&format!("PRAGMA {}.table_info({})", schema, table))?;
     let columns: Vec<String> = stmt
         .query_map([], |row| row.get::<_, String>(1))?
         .filter_map(|r| r.ok())
@@ -218,6 +251,8 @@ pub fn embedded_size() -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::db::backup::list_backups_in;
+    use crate::test_utils::{setup_test_db, table_columns};
     use tempfile::TempDir;
 
     #[test]
@@ -280,15 +315,26 @@ mod tests {
     }
 
     #[test]
-    fn test_extract_database_new() {
+    fn test_update_database_new() {
         let temp = TempDir::new().unwrap();
         let db_path = temp.path().join(DB_FILENAME);
 
-        extract_database(&db_path).unwrap();
+        update_database(&db_path).unwrap();
 
         assert!(db_path.exists());
-        let extracted = fs::read(&db_path).unwrap();
-        assert_eq!(extracted, EMBEDDED_DB);
+        let conn = Connection::open(&db_path).unwrap();
+        assert!(run_pending_migrations(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_embedded_db_matches_schema() {
+        let temp = TempDir::new().unwrap();
+        let db_path = temp.path().join(DB_FILENAME);
+        update_database(&db_path).unwrap();
+
+        let conn = Connection::open(&db_path).unwrap();
+
+        assert_eq!(table_columns(&conn), table_columns(&setup_test_db()));
     }
 
     #[test]
@@ -317,7 +363,7 @@ mod tests {
         assert!(needs_update(&data_dir).unwrap());
 
         // Extract the database
-        extract_database(&db_path).unwrap();
+        update_database(&db_path).unwrap();
         write_checksum(&data_dir).unwrap();
 
         // Verify files exist
@@ -328,30 +374,46 @@ mod tests {
         assert!(!needs_update(&data_dir).unwrap());
     }
 
+    fn insert_character(conn: &Connection) {
+        conn.execute(
+            "INSERT INTO characters (id, is_wild_card, name,
+                attribute_points_spent, attribute_points_earned, skill_points_spent,
+                skill_points_earned, hindrance_points_spent, hindrance_points_earned,
+                hindrance_points_to_edges, hindrance_points_to_attributes,
+                hindrance_points_to_skills, hindrance_points_to_wealth, power_points, wealth)
+             VALUES (1, 1, 'Test Hero', 0, 5, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0)",
+            [],
+        )
+        .unwrap();
+    }
+
     #[test]
-    fn test_extract_preserves_user_data() {
+    fn test_update_preserves_user_data() {
         let temp = TempDir::new().unwrap();
         let db_path = temp.path().join(DB_FILENAME);
 
         // First, extract the embedded database
-        extract_database(&db_path).unwrap();
+        update_database(&db_path).unwrap();
 
         // Insert a test character
         let conn = Connection::open(&db_path).unwrap();
+        insert_character(&conn);
         conn.execute(
-            "INSERT INTO characters (id, is_wild_card, name,
-                attribute_points_spent, attribute_points_earned, skill_points_spent,
-                skill_points_earned, hindrance_points_spent, hindrance_points_earned,
-                hindrance_points_to_edges, hindrance_points_to_attributes,
-                hindrance_points_to_skills, hindrance_points_to_wealth, power_points, wealth)
-             VALUES (1, 1, 'Test Hero', 0, 5, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0)",
+            "INSERT INTO character_snapshots (character_id, label, data)
+             VALUES (1, 'Before the heist', '{}')",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO character_advances (character_id, advance_number, advance_type, notes)
+             VALUES (1, 1, 'edge', 'After the heist')",
             [],
         )
         .unwrap();
         drop(conn);
 
-        // Simulate an update by re-extracting
-        extract_database(&db_path).unwrap();
+        // Simulate an update
+        update_database(&db_path).unwrap();
 
         // Verify the character data was preserved
         let conn = Connection::open(&db_path).unwrap();
@@ -361,16 +423,123 @@ mod tests {
             })
             .unwrap();
         assert_eq!(name, "Test Hero");
+
+        let label: String = conn
+            .query_row(
+                "SELECT label FROM character_snapshots WHERE character_id = 1",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(label, "Before the heist");
+
+        let notes: String = conn
+            .query_row(
+                "SELECT notes FROM character_advances WHERE character_id = 1",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(notes, "After the heist");
+    }
+
+    #[test]
+    fn test_update_refreshes_reference_data() {
+        let temp = TempDir::new().unwrap();
+        let db_path = temp.path().join(DB_FILENAME);
+        update_database(&db_path).unwrap();
+        let conn = Connection::open(&db_path).unwrap();
+        let edge_count = |conn: &Connection| -> i64 {
+            conn.query_row("SELECT COUNT(*) FROM edges", [], |row| row.get(0))
+                .unwrap()
+        };
+        let shipped_edges = edge_count(&conn);
+        conn.execute_batch(
+            "PRAGMA foreign_keys = OFF;
+             UPDATE edges SET name = 'Renamed' WHERE id = 1;
+             DELETE FROM edges WHERE id = 2;",
+        )
+        .unwrap();
+        drop(conn);
+
+        update_database(&db_path).unwrap();
+
+        let conn = Connection::open(&db_path).unwrap();
+        assert_eq!(edge_count(&conn), shipped_edges);
+        let renamed: i64 = conn
+            .query_row("SELECT COUNT(*) FROM edges WHERE name = 'Renamed'", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(renamed, 0);
+    }
+
+    #[test]
+    fn test_update_migrates_baseline_database_in_place() {
+        let temp = TempDir::new().unwrap();
+        let db_path = temp.path().join(DB_FILENAME);
+
+        // A user database from before numbered migrations, with a hindrance
+        // advance that still keeps its source in notes
+        let conn = Connection::open(&db_path).unwrap();
+        conn.execute_batch(include_str!("../../tests/fixtures/baseline_schema.sql"))
+            .unwrap();
+        insert_character(&conn);
+        conn.execute_batch(
+            "INSERT INTO character_advances (character_id, advance_number, advance_type,
+                                             hindrance_action, notes)
+             VALUES (1, 1, 'hindrance', 'remove_minor', 'chosen');
+             INSERT INTO character_advances (character_id, advance_number, advance_type, notes)
+             VALUES (1, 2, 'edge', 'For the duel');",
+        )
+        .unwrap();
+        drop(conn);
+
+        update_database(&db_path).unwrap();
+
+        let conn = Connection::open(&db_path).unwrap();
+        assert_eq!(table_columns(&conn), table_columns(&setup_test_db()));
+        let rows: Vec<(Option<String>, Option<String>)> = conn
+            .prepare("SELECT hindrance_source, notes FROM character_advances ORDER BY advance_number")
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (Some("chosen".to_string()), None),
+                (None, Some("For the duel".to_string())),
+            ]
+        );
+        let edges: i64 = conn
+            .query_row("SELECT COUNT(*) FROM edges", [], |row| row.get(0))
+            .unwrap();
+        assert!(edges > 0);
+    }
+
+    #[test]
+    fn test_update_backs_up_existing_database() {
+        let temp = TempDir::new().unwrap();
+        let db_path = temp.path().join(DB_FILENAME);
+        update_database(&db_path).unwrap();
+        fs::write(temp.path().join(CHECKSUM_FILENAME), "old_checksum").unwrap();
+
+        ensure_database_in(temp.path()).unwrap();
+
+        assert_eq!(list_backups_in(temp.path()).unwrap().len(), 1);
+        assert!(!needs_update(temp.path()).unwrap());
     }
 
     #[test]
     fn test_get_table_columns() {
         let temp = TempDir::new().unwrap();
         let db_path = temp.path().join(DB_FILENAME);
-        extract_database(&db_path).unwrap();
+        update_database(&db_path).unwrap();
 
         let conn = Connection::open(&db_path).unwrap();
-        let columns = get_table_columns(&conn, "characters").unwrap();
+        let columns = get_table_columns(&conn, "main", "characters").unwrap();
 
         assert!(columns.contains(&"id".to_string()));
         assert!(columns.contains(&"name".to_string()));
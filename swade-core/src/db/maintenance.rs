@@ -0,0 +1,165 @@
+//! Maintenance checks and operations on an open database.
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, SwadeError};
+
+/// A problem found by `check_integrity`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct IntegrityIssue {
+    /// "integrity" for file corruption, "foreign_key" for orphaned rows.
+    pub kind: String,
+    /// Table holding the bad row, for foreign key issues.
+    pub table: Option<String>,
+    /// Row id of the bad row, for foreign key issues.
+    pub row_id: Option<i64>,
+    pub message: String,
+}
+
+/// Run SQLite's integrity and foreign key checks.
+///
+/// Foreign keys are checked even when enforcement is off on the connection, so
+/// this finds rows (e.g. `character_skills`) whose parent no longer exists.
+/// Returns an empty list when the database is clean.
+pub fn check_integrity(conn: &Connection) -> Result<Vec<IntegrityIssue>> {
+    let mut issues = Vec::new();
+
+    let mut stmt = conn.prepare("PRAGMA integrity_check")?;
+    let messages = stmt.query_map([], |row| row.get::<_, String>(0))?;
+    for message in messages {
+        let message = message?;
+        if message != "ok" {
+            issues.push(IntegrityIssue {
+                kind: "integrity".to_string(),
+                table: None,
+                row_id: None,
+                message,
+            });
+        }
+    }
+
+    let mut stmt = conn.prepare("PRAGMA foreign_key_check")?;
+    let violations = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, Option<i64>>(1)?,
+            row.get::<_, String>(2)?,
+        ))
+    })?;
+    for violation in violations {
+        let (table, row_id, parent) = violation?;
+        let message = match row_id {
+            Some(id) => format!("{} row {} references a missing {} row", table, id, parent),
+            None => format!("{} row references a missing {} row", table, parent),
+        };
+        issues.push(IntegrityIssue {
+            kind: "foreign_key".to_string(),
+            table: Some(table),
+            row_id,
+            message,
+        });
+    }
+
+    Ok(issues)
+}
+
+/// Database size before and after `compact`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct CompactReport {
+    pub before_bytes: i64,
+    pub after_bytes: i64,
+}
+
+/// Size of the database in bytes (page count times page size).
+fn database_size(conn: &Connection) -> Result<i64> {
+    let page_count: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+    let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))?;
+    Ok(page_count * page_size)
+}
+
+/// Rebuild the database file with `VACUUM`, reclaiming space left by deletes.
+///
+/// `VACUUM` can't run inside a transaction, so this fails if one is open on
+/// the connection.
+pub fn compact(conn: &Connection) -> Result<CompactReport> {
+    if !conn.is_autocommit() {
+        return Err(SwadeError::Validation(
+            "Cannot compact the database while a transaction is open".to_string(),
+        ));
+    }
+
+    let before_bytes = database_size(conn)?;
+    conn.execute_batch("VACUUM")?;
+    let after_bytes = database_size(conn)?;
+
+    Ok(CompactReport {
+        before_bytes,
+        after_bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::setup_test_db_no_fk;
+
+    #[test]
+    fn clean_database_has_no_issues() {
+        let conn = setup_test_db_no_fk();
+
+        assert!(check_integrity(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn orphaned_character_skill_is_reported() {
+        let conn = setup_test_db_no_fk();
+        conn.execute(
+            "INSERT INTO character_skills (id, character_id, skill_id, current_die_size)
+             VALUES (7, 99, 99, 4)",
+            [],
+        )
+        .unwrap();
+
+        let issues = check_integrity(&conn).unwrap();
+
+        assert!(!issues.is_empty());
+        assert!(issues.iter().all(|i| i.kind == "foreign_key"));
+        assert!(issues.iter().any(|i| {
+            i.table.as_deref() == Some("character_skills")
+                && i.row_id == Some(7)
+                && i.message.contains("characters")
+        }));
+    }
+
+    #[test]
+    fn compact_after_deleting_characters_shrinks_database() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let conn = Connection::open(temp.path().join("test.db")).unwrap();
+        conn.execute_batch(include_str!("../../../db/schema.sql"))
+            .unwrap();
+        let background = "x".repeat(2000);
+        for id in 1..=200 {
+            conn.execute(
+                "INSERT INTO characters (id, is_wild_card, name, background) VALUES (?1, 1, 'Hero', ?2)",
+                rusqlite::params![id, background],
+            )
+            .unwrap();
+        }
+        conn.execute("DELETE FROM characters", []).unwrap();
+
+        let report = compact(&conn).unwrap();
+
+        assert!(report.after_bytes < report.before_bytes);
+    }
+
+    #[test]
+    fn compact_rejects_open_transaction() {
+        let conn = setup_test_db_no_fk();
+        conn.execute_batch("BEGIN").unwrap();
+
+        let result = compact(&conn);
+
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+    }
+}
@@ -0,0 +1,817 @@
+//! Numbered schema migrations for the user database.
+//!
+//! Each migration has a version number and runs exactly once. Applied versions
+//! are recorded in the `schema_migrations` table, so opening a database only
+//! runs the migrations it hasn't seen yet. A database without the table is
+//! treated as version 0.
+//!
+//! To change the schema, append a migration with the next version number to
+//! `MIGRATIONS` and make the same change in `db/schema.sql`, then rebuild
+//! `db/swade.db`. A freshly extracted database is marked as having every
+//! migration applied, and tests check that migrating the pre-migration
+//! baseline schema ends up with the same tables and columns as `schema.sql`.
+
+use rusqlite::{Connection, params};
+
+use crate::error::{Result, SwadeError};
+
+/// What a migration does when applied.
+pub enum MigrationStep {
+    /// A batch of SQL statements.
+    Sql(&'static str),
+    /// Arbitrary Rust code, for changes SQL alone can't express.
+    Rust(fn(&Connection) -> Result<()>),
+    /// A batch of SQL that rebuilds a table other tables reference. Foreign keys
+    /// are switched off while it runs, since SQLite won't drop a referenced
+    /// table otherwise, so the rebuild must keep row ids unchanged.
+    TableRebuild(&'static str),
+}
+
+/// A single numbered schema change.
+pub struct Migration {
+    pub version: i64,
+    pub description: &'static str,
+    pub step: MigrationStep,
+}
+
+/// Every migration, in version order.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Baseline schema",
+        step: MigrationStep::Rust(baseline_schema),
+    },
+    Migration {
+        version: 2,
+        description: "Add archived flag to characters",
+        step: MigrationStep::Sql(
+            "ALTER TABLE characters ADD COLUMN archived BOOLEAN NOT NULL DEFAULT 0",
+        ),
+    },
+    Migration {
+        version: 3,
+        description: "Add pinned flag and category to character notes",
+        step: MigrationStep::Sql(
+            "ALTER TABLE character_notes ADD COLUMN pinned BOOLEAN NOT NULL DEFAULT 0;
+             ALTER TABLE character_notes ADD COLUMN category VARCHAR(50);",
+        ),
+    },
+    Migration {
+        version: 4,
+        description: "Add maintenance cost to powers",
+        step: MigrationStep::Sql("ALTER TABLE powers ADD COLUMN maintenance_power_points INTEGER"),
+    },
+    Migration {
+        version: 5,
+        description: "Move hindrance advance sources out of notes",
+        step: MigrationStep::Sql(
+            "ALTER TABLE character_advances ADD COLUMN hindrance_source VARCHAR(20);
+             UPDATE character_advances SET hindrance_source = notes, notes = NULL
+              WHERE advance_type = 'hindrance';",
+        ),
+    },
+    Migration {
+        version: 6,
+        description: "Allow grant_skill modifiers",
+        // SQLite can't alter a CHECK constraint, so the table is rebuilt with the same ids
+        step: MigrationStep::TableRebuild(
+            "CREATE TABLE modifiers_new (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                target_type VARCHAR(20) CHECK (target_type IN ('attribute', 'skill', 'derived_stat', 'edge_choice', 'hindrance_choice', 'heritage_choice', 'skill_points', 'attribute_points', 'power_slots', 'power_points', 'wealth')),
+                target_identifier VARCHAR(30),
+                value_type VARCHAR(20) NOT NULL CHECK (value_type IN ('die_increment', 'roll_bonus', 'flat_bonus', 'description', 'bonus_selection', 'mandatory_selection', 'grant_skill')),
+                value INTEGER,
+                description TEXT NOT NULL,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+             );
+             INSERT INTO modifiers_new SELECT id, target_type, target_identifier, value_type, value,
+                                              description, created_at, updated_at
+               FROM modifiers;
+             DROP TABLE modifiers;
+             ALTER TABLE modifiers_new RENAME TO modifiers;",
+        ),
+    },
+    Migration {
+        version: 7,
+        description: "Allow max_die_increase modifiers",
+        step: MigrationStep::TableRebuild(
+            "CREATE TABLE modifiers_new (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                target_type VARCHAR(20) CHECK (target_type IN ('attribute', 'skill', 'derived_stat', 'edge_choice', 'hindrance_choice', 'heritage_choice', 'skill_points', 'attribute_points', 'power_slots', 'power_points', 'wealth')),
+                target_identifier VARCHAR(30),
+                value_type VARCHAR(20) NOT NULL CHECK (value_type IN ('die_increment', 'roll_bonus', 'flat_bonus', 'description', 'bonus_selection', 'mandatory_selection', 'grant_skill', 'max_die_increase')),
+                value INTEGER,
+                description TEXT NOT NULL,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+             );
+             INSERT INTO modifiers_new SELECT id, target_type, target_identifier, value_type, value,
+                                              description, created_at, updated_at
+               FROM modifiers;
+             DROP TABLE modifiers;
+             ALTER TABLE modifiers_new RENAME TO modifiers;",
+        ),
+    },
+    Migration {
+        version: 8,
+        description: "Add load limit multiplier to character config",
+        step: MigrationStep::Sql("ALTER TABLE characters ADD COLUMN load_limit_multiplier INTEGER"),
+    },
+    Migration {
+        version: 9,
+        description: "Add situational condition to modifiers",
+        step: MigrationStep::Sql("ALTER TABLE modifiers ADD COLUMN condition TEXT"),
+    },
+    Migration {
+        version: 10,
+        description: "Add parry skill to character config",
+        step: MigrationStep::Sql("ALTER TABLE characters ADD COLUMN parry_skill VARCHAR(30)"),
+    },
+    Migration {
+        version: 11,
+        description: "Allow parry_skill modifiers",
+        step: MigrationStep::TableRebuild(
+            "CREATE TABLE modifiers_new (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                target_type VARCHAR(20) CHECK (target_type IN ('attribute', 'skill', 'derived_stat', 'edge_choice', 'hindrance_choice', 'heritage_choice', 'skill_points', 'attribute_points', 'power_slots', 'power_points', 'wealth')),
+                target_identifier VARCHAR(30),
+                value_type VARCHAR(20) NOT NULL CHECK (value_type IN ('die_increment', 'roll_bonus', 'flat_bonus', 'description', 'bonus_selection', 'mandatory_selection', 'grant_skill', 'max_die_increase', 'parry_skill')),
+                value INTEGER,
+                description TEXT NOT NULL,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                condition TEXT
+             );
+             INSERT INTO modifiers_new SELECT id, target_type, target_identifier, value_type, value,
+                                              description, created_at, updated_at, condition
+               FROM modifiers;
+             DROP TABLE modifiers;
+             ALTER TABLE modifiers_new RENAME TO modifiers;",
+        ),
+    },
+    Migration {
+        version: 12,
+        description: "Add max wounds to character config",
+        step: MigrationStep::Sql("ALTER TABLE characters ADD COLUMN max_wounds INTEGER"),
+    },
+    Migration {
+        version: 13,
+        description: "Add experience tracking to characters",
+        step: MigrationStep::Sql(
+            "ALTER TABLE characters ADD COLUMN track_experience BOOLEAN NOT NULL DEFAULT 0;
+             ALTER TABLE characters ADD COLUMN experience_points INTEGER NOT NULL DEFAULT 0;",
+        ),
+    },
+    Migration {
+        version: 14,
+        description: "Allow requirements to name their target",
+        step: MigrationStep::Sql("ALTER TABLE requirements ADD COLUMN target_name VARCHAR(50)"),
+    },
+    Migration {
+        version: 15,
+        description: "Add archetype templates",
+        // Suggestions are seeded by name, skipping any the database doesn't have
+        step: MigrationStep::Sql(
+            "CREATE TABLE archetypes (
+                 id INTEGER PRIMARY KEY AUTOINCREMENT,
+                 key VARCHAR(50) NOT NULL UNIQUE, -- stable identifier, e.g. 'brawler'
+                 name VARCHAR(100) NOT NULL,
+                 description TEXT NOT NULL,
+                 created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                 updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+             );
+
+             CREATE TABLE archetype_suggestions (
+                 id INTEGER PRIMARY KEY AUTOINCREMENT,
+                 archetype_id INTEGER NOT NULL,
+                 suggestion_type VARCHAR(20) NOT NULL CHECK (suggestion_type IN ('attribute', 'skill', 'edge', 'hindrance')),
+                 target_id INTEGER NOT NULL, -- attribute, skill, edge, or hindrance id
+                 die_size INTEGER, -- target die for attributes and skills
+                 sort_order INTEGER NOT NULL DEFAULT 0,
+                 created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                 updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                 FOREIGN KEY (archetype_id) REFERENCES archetypes(id) ON DELETE CASCADE
+             );
+
+             INSERT INTO archetypes (key, name, description) VALUES
+                 ('brawler', 'Brawler', 'A tough fighter who settles things up close.'),
+                 ('scholar', 'Scholar', 'A learned researcher who knows a little about everything.'),
+                 ('face', 'Face', 'A smooth talker who gets the party through doors and out of trouble.');
+             WITH suggestions (archetype_key, suggestion_type, name, severity, die_size, sort_order) AS (VALUES
+                 ('brawler', 'attribute', 'Agility', NULL, 6, 1),
+                 ('brawler', 'attribute', 'Strength', NULL, 8, 2),
+                 ('brawler', 'attribute', 'Vigor', NULL, 8, 3),
+                 ('brawler', 'skill', 'Fighting', NULL, 8, 4),
+                 ('brawler', 'skill', 'Athletics', NULL, 6, 5),
+                 ('brawler', 'skill', 'Intimidation', NULL, 6, 6),
+                 ('brawler', 'skill', 'Notice', NULL, 6, 7),
+                 ('brawler', 'skill', 'Survival', NULL, 4, 8),
+                 ('brawler', 'skill', 'Stealth', NULL, 6, 9),
+                 ('brawler', 'edge', 'Brawny', NULL, NULL, 10),
+                 ('brawler', 'hindrance', 'Overconfident', 'major', NULL, 11),
+                 ('brawler', 'hindrance', 'Big Mouth', 'minor', NULL, 12),
+                 ('scholar', 'attribute', 'Smarts', NULL, 8, 1),
+                 ('scholar', 'attribute', 'Spirit', NULL, 6, 2),
+                 ('scholar', 'attribute', 'Agility', NULL, 6, 3),
+                 ('scholar', 'attribute', 'Vigor', NULL, 6, 4),
+                 ('scholar', 'skill', 'Research', NULL, 8, 5),
+                 ('scholar', 'skill', 'Academics', NULL, 8, 6),
+                 ('scholar', 'skill', 'Notice', NULL, 6, 7),
+                 ('scholar', 'skill', 'Common Knowledge', NULL, 8, 8),
+                 ('scholar', 'skill', 'Science', NULL, 6, 9),
+                 ('scholar', 'skill', 'Healing', NULL, 4, 10),
+                 ('scholar', 'edge', 'Scholar', NULL, NULL, 11),
+                 ('scholar', 'hindrance', 'Curious', 'major', NULL, 12),
+                 ('scholar', 'hindrance', 'Cautious', 'minor', NULL, 13),
+                 ('face', 'attribute', 'Spirit', NULL, 8, 1),
+                 ('face', 'attribute', 'Smarts', NULL, 6, 2),
+                 ('face', 'attribute', 'Agility', NULL, 6, 3),
+                 ('face', 'attribute', 'Vigor', NULL, 6, 4),
+                 ('face', 'skill', 'Persuasion', NULL, 8, 5),
+                 ('face', 'skill', 'Performance', NULL, 6, 6),
+                 ('face', 'skill', 'Intimidation', NULL, 6, 7),
+                 ('face', 'skill', 'Notice', NULL, 6, 8),
+                 ('face', 'skill', 'Common Knowledge', NULL, 6, 9),
+                 ('face', 'skill', 'Taunt', NULL, 6, 10),
+                 ('face', 'skill', 'Gambling', NULL, 4, 11),
+                 ('face', 'skill', 'Stealth', NULL, 6, 12),
+                 ('face', 'edge', 'Charismatic', NULL, NULL, 13),
+                 ('face', 'hindrance', 'Arrogant', 'major', NULL, 14),
+                 ('face', 'hindrance', 'Greedy', 'minor', NULL, 15)
+             )
+             INSERT INTO archetype_suggestions (archetype_id, suggestion_type, target_id, die_size, sort_order)
+             SELECT a.id, s.suggestion_type,
+                    CASE s.suggestion_type
+                        WHEN 'attribute' THEN (SELECT id FROM attributes WHERE name = s.name)
+                        WHEN 'skill' THEN (SELECT id FROM skills WHERE name = s.name)
+                        WHEN 'edge' THEN (SELECT id FROM edges WHERE name = s.name)
+                        ELSE (SELECT id FROM hindrances WHERE name = s.name AND severity = s.severity)
+                    END AS target_id,
+                    s.die_size, s.sort_order
+               FROM suggestions s JOIN archetypes a ON a.key = s.archetype_key
+              WHERE target_id IS NOT NULL;",
+        ),
+    },
+    Migration {
+        version: 16,
+        description: "Add character snapshots",
+        step: MigrationStep::Sql(
+            "CREATE TABLE character_snapshots (
+                 id INTEGER PRIMARY KEY AUTOINCREMENT,
+                 character_id INTEGER NOT NULL, -- No foreign key: snapshots outlive the character so restoring can recreate it
+                 label VARCHAR(100) NOT NULL,
+                 data TEXT NOT NULL, -- JSON of the CharacterView and its advance history
+                 created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+                 updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+             );",
+        ),
+    },
+    Migration {
+        version: 17,
+        description: "Link arcane backgrounds to the edge that grants them",
+        // Every shipped arcane background is taken through the core Arcane Background edge
+        step: MigrationStep::Sql(
+            "ALTER TABLE arcane_backgrounds ADD COLUMN edge_id INTEGER;
+
+             UPDATE arcane_backgrounds SET edge_id = (
+                 SELECT id FROM edges WHERE name = 'Arcane Background' AND source = 'core'
+             );",
+        ),
+    },
+    Migration {
+        version: 18,
+        description: "Add sell-back rate to character config",
+        step: MigrationStep::Sql("ALTER TABLE characters ADD COLUMN sell_back_percent INTEGER"),
+    },
+    Migration {
+        version: 19,
+        description: "Add weight unit to character config",
+        step: MigrationStep::Sql(
+            "ALTER TABLE characters ADD COLUMN weight_unit VARCHAR(2) NOT NULL DEFAULT 'lb'",
+        ),
+    },
+    Migration {
+        version: 20,
+        description: "Add revision counter to characters",
+        step: MigrationStep::Sql("ALTER TABLE characters ADD COLUMN revision INTEGER NOT NULL DEFAULT 0"),
+    },
+    Migration {
+        version: 21,
+        description: "Record the arcane background an edge advance granted",
+        step: MigrationStep::Sql(
+            "ALTER TABLE character_advances
+                 ADD COLUMN arcane_background_id INTEGER REFERENCES arcane_backgrounds(id);
+
+             UPDATE character_advances SET arcane_background_id = (
+                 SELECT cab.arcane_background_id FROM character_arcane_backgrounds cab
+                  WHERE cab.character_id = character_advances.character_id
+                    AND cab.advance_taken = character_advances.advance_number
+             )
+              WHERE advance_type = 'edge';",
+        ),
+    },
+];
+
+/// Columns added before numbered migrations existed, with their definitions.
+const PRE_MIGRATION_COLUMNS: &[(&str, &str, &str)] = &[
+    ("hindrances", "can_take_multiple_times", "BOOLEAN NOT NULL DEFAULT 0"),
+    ("characters", "campaign", "VARCHAR(100)"),
+    ("characters", "max_attribute_die_size", "INTEGER"),
+    ("characters", "max_attribute_die_modifier", "INTEGER NOT NULL DEFAULT 0"),
+    ("characters", "allow_hindrances_past_cap", "BOOLEAN NOT NULL DEFAULT 0"),
+    ("character_arcane_backgrounds", "power_points_used", "INTEGER NOT NULL DEFAULT 0"),
+];
+
+/// Bring a database from before numbered migrations up to the baseline schema.
+///
+/// Databases from earlier releases may already have some of these changes, so
+/// each column and table is only added when it's missing.
+fn baseline_schema(conn: &Connection) -> Result<()> {
+    for (table, column, definition) in PRE_MIGRATION_COLUMNS {
+        if !has_column(conn, table, column)? {
+            conn.execute_batch(&format!(
+                "ALTER TABLE {} ADD COLUMN {} {}",
+                table, column, definition
+            ))?;
+        }
+    }
+
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS companion_links (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            parent_character_id INTEGER NOT NULL,
+            child_character_id INTEGER NOT NULL,
+            relationship VARCHAR(50) NOT NULL,
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            FOREIGN KEY (parent_character_id) REFERENCES characters(id) ON DELETE CASCADE,
+            FOREIGN KEY (child_character_id) REFERENCES characters(id) ON DELETE CASCADE,
+            UNIQUE(parent_character_id, child_character_id)
+        )",
+    )?;
+    Ok(())
+}
+
+fn has_column(conn: &Connection, table: &str, column: &str) -> Result<bool> {
+    let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+    let columns = stmt
+        .query_map([], |row| row.get::<_, String>(1))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(columns.iter().any(|c| c == column))
+}
+
+/// Run one migration and record it, in a single transaction.
+fn apply_migration(conn: &Connection, migration: &Migration) -> Result<()> {
+    let tx = conn.unchecked_transaction()?;
+    let result = match migration.step {
+        MigrationStep::Sql(sql) | MigrationStep::TableRebuild(sql) => {
+            tx.execute_batch(sql).map_err(SwadeError::from)
+        }
+        MigrationStep::Rust(run) => run(&tx),
+    };
+    result.map_err(|e| {
+        SwadeError::Migration(format!(
+            "Migration {} ({}) failed: {}",
+            migration.version, migration.description, e
+        ))
+    })?;
+
+    tx.execute(
+        "INSERT INTO schema_migrations (version, description) VALUES (?1, ?2)",
+        params![migration.version, migration.description],
+    )?;
+    tx.commit()?;
+
+    Ok(())
+}
+
+/// Create the `schema_migrations` table if it doesn't exist yet.
+fn ensure_migrations_table(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+            version INTEGER PRIMARY KEY,
+            description TEXT NOT NULL,
+            applied_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+        )",
+    )?;
+    Ok(())
+}
+
+/// The highest migration version applied to a database, or 0 if none.
+pub fn schema_version(conn: &Connection) -> Result<i64> {
+    ensure_migrations_table(conn)?;
+    let version = conn.query_row(
+        "SELECT COALESCE(MAX(version), 0) FROM schema_migrations",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(version)
+}
+
+/// Record every migration in `MIGRATIONS` as applied without running it.
+///
+/// For a database freshly extracted from the embedded copy, whose schema is
+/// already current.
+pub(super) fn mark_all_applied(conn: &Connection) -> Result<()> {
+    ensure_migrations_table(conn)?;
+    for migration in MIGRATIONS {
+        conn.execute(
+            "INSERT OR IGNORE INTO schema_migrations (version, description) VALUES (?1, ?2)",
+            params![migration.version, migration.description],
+        )?;
+    }
+    Ok(())
+}
+
+/// Apply every pending migration from `MIGRATIONS`.
+///
+/// Returns the versions that were applied.
+pub fn run_pending_migrations(conn: &Connection) -> Result<Vec<i64>> {
+    apply_migrations(conn, MIGRATIONS)
+}
+
+/// Apply the migrations newer than the database's current version, in order.
+///
+/// Each migration runs in its own transaction together with the update to
+/// `schema_migrations`, so a failing migration leaves the database at the last
+/// version that succeeded. Returns the versions that were applied.
+pub fn apply_migrations(conn: &Connection, migrations: &[Migration]) -> Result<Vec<i64>> {
+    if migrations.windows(2).any(|w| w[0].version >= w[1].version) {
+        return Err(SwadeError::Migration(
+            "Migrations must be in strictly increasing version order".to_string(),
+        ));
+    }
+
+    let current = schema_version(conn)?;
+    let mut applied = Vec::new();
+
+    for migration in migrations.iter().filter(|m| m.version > current) {
+        // The pragma is a no-op inside a transaction, so it has to be set first
+        let foreign_keys_on: bool = conn.query_row("PRAGMA foreign_keys", [], |row| row.get(0))?;
+        let rebuild = matches!(migration.step, MigrationStep::TableRebuild(_));
+        if rebuild {
+            conn.execute_batch("PRAGMA foreign_keys = OFF")?;
+        }
+
+        let result = apply_migration(conn, migration);
+
+        if rebuild && foreign_keys_on {
+            conn.execute_batch("PRAGMA foreign_keys = ON")?;
+        }
+        result?;
+
+        applied.push(migration.version);
+    }
+
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{setup_test_db, table_columns};
+
+    const V1: Migration = Migration {
+        version: 1,
+        description: "Baseline schema",
+        step: MigrationStep::Sql(""),
+    };
+
+    const V2_ADD_BENNIES: Migration = Migration {
+        version: 2,
+        description: "Add bennies to characters",
+        step: MigrationStep::Sql(
+            "ALTER TABLE characters ADD COLUMN bennies INTEGER NOT NULL DEFAULT 3",
+        ),
+    };
+
+    fn insert_character(conn: &Connection, id: i64) {
+        conn.execute(
+            "INSERT INTO characters (id, is_wild_card, name, created_at, updated_at)
+             VALUES (?, 1, 'Hero', '2024-01-01', '2024-01-01')",
+            params![id],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn new_database_starts_at_version_zero() {
+        let conn = setup_test_db();
+
+        assert_eq!(schema_version(&conn).unwrap(), 0);
+    }
+
+    /// A database with the schema from before numbered migrations, as an
+    /// existing user would have it.
+    fn setup_baseline_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(include_str!("../../tests/fixtures/baseline_schema.sql"))
+            .unwrap();
+        conn
+    }
+
+    #[test]
+    fn run_pending_migrations_applies_each_once() {
+        let conn = setup_baseline_db();
+
+        assert_eq!(run_pending_migrations(&conn).unwrap(), vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21]);
+        assert!(run_pending_migrations(&conn).unwrap().is_empty());
+        assert_eq!(
+            schema_version(&conn).unwrap(),
+            MIGRATIONS.last().unwrap().version
+        );
+    }
+
+    #[test]
+    fn migrated_baseline_matches_schema() {
+        let conn = setup_baseline_db();
+
+        run_pending_migrations(&conn).unwrap();
+
+        assert_eq!(table_columns(&conn), table_columns(&setup_test_db()));
+    }
+
+    #[test]
+    fn v1_adds_columns_from_before_numbered_migrations() {
+        let conn = setup_baseline_db();
+        insert_character(&conn, 1);
+
+        apply_migrations(&conn, &MIGRATIONS[..1]).unwrap();
+
+        let (campaign, cap, past_cap): (Option<String>, Option<i64>, bool) = conn
+            .query_row(
+                "SELECT campaign, max_attribute_die_size, allow_hindrances_past_cap
+                   FROM characters WHERE id = 1",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!((campaign, cap, past_cap), (None, None, false));
+        conn.execute(
+            "INSERT INTO companion_links (parent_character_id, child_character_id, relationship)
+             VALUES (1, 1, 'Familiar')",
+            [],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn v1_skips_columns_a_database_already_has() {
+        let conn = setup_baseline_db();
+        conn.execute_batch("ALTER TABLE characters ADD COLUMN campaign VARCHAR(100)")
+            .unwrap();
+
+        assert_eq!(apply_migrations(&conn, &MIGRATIONS[..1]).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn mark_all_applied_leaves_nothing_pending() {
+        let conn = setup_test_db();
+
+        mark_all_applied(&conn).unwrap();
+
+        assert!(run_pending_migrations(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn v2_archives_nothing_by_default() {
+        let conn = setup_baseline_db();
+        apply_migrations(&conn, &MIGRATIONS[..1]).unwrap();
+        insert_character(&conn, 1);
+
+        run_pending_migrations(&conn).unwrap();
+
+        let archived: bool = conn
+            .query_row("SELECT archived FROM characters WHERE id = 1", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert!(!archived);
+    }
+
+    #[test]
+    fn v11_rebuilds_modifiers_without_breaking_links() {
+        use crate::test_utils::{insert_test_edge, insert_test_edge_modifier, insert_test_modifier};
+
+        let conn = setup_baseline_db();
+        apply_migrations(&conn, &MIGRATIONS[..10]).unwrap();
+        insert_test_edge(&conn, 1, "Brawny", "Background");
+        insert_test_modifier(&conn, 1, "derived_stat", "flat_bonus", "+1 Toughness");
+        insert_test_edge_modifier(&conn, 1, 1, 1);
+
+        assert_eq!(apply_migrations(&conn, &MIGRATIONS[10..11]).unwrap(), vec![11]);
+
+        let linked: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM edge_modifiers em JOIN modifiers m ON m.id = em.modifier_id",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(linked, 1);
+        let foreign_keys_on: bool = conn
+            .query_row("PRAGMA foreign_keys", [], |row| row.get(0))
+            .unwrap();
+        assert!(foreign_keys_on);
+    }
+
+    #[test]
+    fn v5_moves_hindrance_sources_out_of_notes() {
+        let conn = setup_baseline_db();
+        apply_migrations(&conn, &MIGRATIONS[..4]).unwrap();
+        insert_character(&conn, 1);
+        conn.execute_batch(
+            "INSERT INTO character_advances (character_id, advance_number, advance_type,
+                                            hindrance_action, notes)
+             VALUES (1, 1, 'hindrance', 'remove_minor', 'chosen');
+             INSERT INTO character_advances (character_id, advance_number, advance_type, notes)
+             VALUES (1, 2, 'edge', 'For the duel');",
+        )
+        .unwrap();
+
+        run_pending_migrations(&conn).unwrap();
+
+        let rows: Vec<(Option<String>, Option<String>)> = conn
+            .prepare("SELECT hindrance_source, notes FROM character_advances ORDER BY advance_number")
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (Some("chosen".to_string()), None),
+                (None, Some("For the duel".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn v6_keeps_modifiers_and_accepts_grant_skill() {
+        use crate::test_utils::{insert_test_edge, insert_test_edge_modifier};
+
+        let conn = setup_baseline_db();
+        apply_migrations(&conn, &MIGRATIONS[..5]).unwrap();
+        conn.execute(
+            "INSERT INTO modifiers (id, target_type, target_identifier, value_type, value, description)
+             VALUES (7, 'attribute', 'Vigor', 'die_increment', 1, '+1 Vigor')",
+            [],
+        )
+        .unwrap();
+        insert_test_edge(&conn, 1, "Brawny", "Background");
+        insert_test_edge_modifier(&conn, 1, 1, 7);
+
+        assert_eq!(apply_migrations(&conn, &MIGRATIONS[5..6]).unwrap(), vec![6]);
+
+        let linked: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM edge_modifiers em JOIN modifiers m ON m.id = em.modifier_id",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(linked, 1);
+
+        let description: String = conn
+            .query_row("SELECT description FROM modifiers WHERE id = 7", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(description, "+1 Vigor");
+        conn.execute(
+            "INSERT INTO modifiers (target_type, target_identifier, value_type, value, description)
+             VALUES ('skill', 'Fighting', 'grant_skill', 6, 'Fighting d6')",
+            [],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn v7_keeps_modifier_links_and_accepts_max_die_increase() {
+        use crate::test_utils::{insert_test_edge, insert_test_edge_modifier, insert_test_modifier};
+
+        let conn = setup_baseline_db();
+        apply_migrations(&conn, &MIGRATIONS[..6]).unwrap();
+        insert_test_edge(&conn, 1, "Brawny", "Background");
+        insert_test_modifier(&conn, 1, "derived_stat", "flat_bonus", "+1 Toughness");
+        insert_test_edge_modifier(&conn, 1, 1, 1);
+
+        assert_eq!(apply_migrations(&conn, &MIGRATIONS[6..7]).unwrap(), vec![7]);
+
+        let linked: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM edge_modifiers em JOIN modifiers m ON m.id = em.modifier_id",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(linked, 1);
+
+        conn.execute(
+            "INSERT INTO modifiers (target_type, target_identifier, value_type, value, description)
+             VALUES ('attribute', 'Agility', 'max_die_increase', 1, 'Agility max d12+1')",
+            [],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn v21_records_arcane_backgrounds_granted_by_edge_advances() {
+        let conn = setup_baseline_db();
+        apply_migrations(&conn, &MIGRATIONS[..20]).unwrap();
+        conn.execute_batch("PRAGMA foreign_keys = OFF;").unwrap();
+        insert_character(&conn, 1);
+        conn.execute_batch(
+            "INSERT INTO character_advances (character_id, advance_number, advance_type, edge_id)
+             VALUES (1, 1, 'edge', 3), (1, 2, 'edge', 4);
+             INSERT INTO character_arcane_backgrounds (character_id, arcane_background_id, advance_taken)
+             VALUES (1, 2, 1), (1, 1, NULL);",
+        )
+        .unwrap();
+
+        assert_eq!(apply_migrations(&conn, &MIGRATIONS[20..21]).unwrap(), vec![21]);
+
+        let granted: Vec<Option<i64>> = conn
+            .prepare("SELECT arcane_background_id FROM character_advances ORDER BY advance_number")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .map(|r| r.unwrap())
+            .collect();
+        assert_eq!(granted, vec![Some(2), None]);
+    }
+
+    #[test]
+    fn v1_to_v2_adds_column_with_default_and_keeps_data() {
+        let conn = setup_test_db();
+        apply_migrations(&conn, &[V1]).unwrap();
+        insert_character(&conn, 1);
+
+        let applied = apply_migrations(&conn, &[V1, V2_ADD_BENNIES]).unwrap();
+
+        assert_eq!(applied, vec![2]);
+        assert_eq!(schema_version(&conn).unwrap(), 2);
+        let (name, bennies): (String, i64) = conn
+            .query_row(
+                "SELECT name, bennies FROM characters WHERE id = 1",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(name, "Hero");
+        assert_eq!(bennies, 3);
+    }
+
+    #[test]
+    fn rust_migration_runs() {
+        fn add_rust_table(conn: &Connection) -> Result<()> {
+            conn.execute_batch("CREATE TABLE rust_migration_test (id INTEGER PRIMARY KEY)")?;
+            Ok(())
+        }
+        let conn = setup_test_db();
+        let migration = Migration {
+            version: 1,
+            description: "Rust step",
+            step: MigrationStep::Rust(add_rust_table),
+        };
+
+        apply_migrations(&conn, &[migration]).unwrap();
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM rust_migration_test", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn failed_migration_rolls_back_and_keeps_version() {
+        let conn = setup_test_db();
+        let broken = Migration {
+            version: 2,
+            description: "Broken",
+            step: MigrationStep::Sql(
+                "ALTER TABLE characters ADD COLUMN half_done INTEGER; SELECT * FROM missing_table;",
+            ),
+        };
+
+        let result = apply_migrations(&conn, &[V1, broken]);
+
+        assert!(matches!(result, Err(SwadeError::Migration(_))));
+        assert_eq!(schema_version(&conn).unwrap(), 1);
+        assert!(conn.prepare("SELECT half_done FROM characters").is_err());
+    }
+
+    #[test]
+    fn out_of_order_migrations_are_rejected() {
+        let conn = setup_test_db();
+
+        let result = apply_migrations(&conn, &[V2_ADD_BENNIES, V1]);
+
+        assert!(matches!(result, Err(SwadeError::Migration(_))));
+        assert_eq!(schema_version(&conn).unwrap(), 0);
+    }
+}
@@ -3,6 +3,9 @@
 //! This module handles:
 //! - Embedded database distribution (the SQLite database is compiled into the binary)
 //! - Automatic extraction and versioning (database is extracted to user data directory)
+//! - Numbered schema migrations for the user database
+//! - Backups of the user database taken before each update
+//! - Maintenance checks and compaction
 //! - Connection management
 //!
 //! # Usage
@@ -28,11 +31,22 @@
 //! determine if the database needs to be updated when a new version of the application
 //! is installed.
 //!
-//! User data (characters) is preserved during updates through a migration process
-//! that copies character tables from the old database to the new one.
+//! On update, the user's database is changed in place: schema changes are
+//! applied as numbered migrations (see `migrations`), recorded in the
+//! `schema_migrations` table, and then the game rules tables are refreshed from
+//! the embedded copy. Character tables are never rewritten.
 
+mod backup;
 mod connection;
 mod embedded;
+mod maintenance;
+mod migrations;
 
+pub use backup::{BackupInfo, MAX_BACKUPS, list_backups, restore_backup};
 pub use connection::Database;
 pub use embedded::{embedded_checksum, embedded_size, ensure_database};
+pub use maintenance::{CompactReport, IntegrityIssue, check_integrity, compact};
+pub use migrations::{
+    MIGRATIONS, Migration, MigrationStep, apply_migrations, run_pending_migrations,
+    schema_version,
+};
@@ -11,6 +11,12 @@ pub enum SwadeError {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Insufficient funds. Need ${needed}, have ${available}")]
+    InsufficientFunds { needed: i64, available: i64 },
+
     #[error("Failed to determine user data directory")]
     UserDataDir,
 
@@ -12,4 +12,6 @@ pub struct ArcaneBackground {
     pub description: String,
     pub created_at: String,
     pub updated_at: String,
+    /// Edge that grants this arcane background when taken as an advance.
+    pub edge_id: Option<i64>,
 }
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Archetype {
+    pub id: i64,
+    pub key: String,
+    pub name: String,
+    pub description: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchetypeSuggestion {
+    pub id: i64,
+    pub archetype_id: i64,
+    pub suggestion_type: String,
+    pub target_id: i64,
+    pub die_size: Option<i64>,
+    pub sort_order: i64,
+    pub created_at: String,
+    pub updated_at: String,
+}
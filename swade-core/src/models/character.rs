@@ -29,6 +29,20 @@ pub struct Character {
     pub wealth: i64,
     pub background: Option<String>,
     pub description: Option<String>,
+    pub campaign: Option<String>,
+    pub archived: bool,
+
+    // House Rule Configuration
+    pub max_attribute_die_size: Option<i64>,
+    pub max_attribute_die_modifier: i64,
+    pub allow_hindrances_past_cap: bool,
+    pub load_limit_multiplier: Option<i64>,
+    pub parry_skill: Option<String>,
+    pub max_wounds: Option<i64>,
+    pub track_experience: bool,
+    pub experience_points: i64,
+    pub sell_back_percent: Option<i64>,
+    pub weight_unit: String,
 
     // Portrait (stored as resized image, max 1024x1024)
     pub portrait: Option<Vec<u8>>,
@@ -36,4 +50,5 @@ pub struct Character {
 
     pub created_at: String,
     pub updated_at: String,
+    pub revision: i64,
 }
@@ -7,11 +7,13 @@ pub struct CharacterAdvance {
     pub advance_number: i64,
     pub advance_type: String, // 'edge', 'attribute', 'skill_expensive', 'skill_cheap', 'hindrance'
     pub edge_id: Option<i64>,
+    pub arcane_background_id: Option<i64>, // Arcane background an edge advance granted
     pub attribute_id: Option<i64>,
     pub skill_id_1: Option<i64>,
     pub skill_id_2: Option<i64>,
     pub hindrance_id: Option<i64>,
-    pub hindrance_action: Option<String>, // 'remove_minor', 'reduce_major', 'remove_major_half'
+    pub hindrance_action: Option<String>, // 'remove_minor', 'reduce_major', 'remove_major_half', 'complete_major_removal'
+    pub hindrance_source: Option<String>, // Source of the hindrance a removal took away, restored on undo
     pub notes: Option<String>,
     pub created_at: String,
     pub updated_at: String,
@@ -6,6 +6,7 @@ pub struct CharacterArcaneBackground {
     pub character_id: i64,
     pub arcane_background_id: i64,
     pub advance_taken: Option<i64>,
+    pub power_points_used: i64,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -6,6 +6,17 @@ pub struct CharacterNote {
     pub character_id: i64,
     pub title: String,
     pub body: String,
+    pub pinned: bool,
+    pub category: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
+
+/// Which timestamp to sort a character's notes by. Newest come first either way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum NoteOrder {
+    #[default]
+    UpdatedAt,
+    CreatedAt,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharacterSnapshot {
+    pub id: i64,
+    pub character_id: i64,
+    pub label: String,
+    pub data: String, // JSON of the CharacterView and its advance history
+    pub created_at: String,
+    pub updated_at: String,
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompanionLink {
+    pub id: i64,
+    pub parent_character_id: i64,
+    pub child_character_id: i64,
+    pub relationship: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
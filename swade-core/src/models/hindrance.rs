@@ -7,6 +7,7 @@ pub struct Hindrance {
     pub severity: String,
     pub point_value: i64,
     pub companion_hindrance_id: Option<i64>,
+    pub can_take_multiple_times: bool,
     pub source: String,
     pub description: String,
     pub created_at: String,
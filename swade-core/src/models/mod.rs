@@ -6,6 +6,7 @@ mod ancestry_requirement;
 mod arcane_background;
 mod arcane_background_choice;
 mod arcane_background_requirement;
+mod archetype;
 mod armor_stats;
 mod attribute;
 mod character;
@@ -21,6 +22,8 @@ mod character_modifier;
 mod character_note;
 mod character_power;
 mod character_skill;
+mod character_snapshot;
+mod companion_link;
 mod edge;
 mod edge_modifier;
 mod edge_requirement;
@@ -52,6 +55,7 @@ pub use ancestry_requirement::*;
 pub use arcane_background::*;
 pub use arcane_background_choice::*;
 pub use arcane_background_requirement::*;
+pub use archetype::*;
 pub use armor_stats::*;
 pub use attribute::*;
 pub use character::*;
@@ -67,6 +71,8 @@ pub use character_modifier::*;
 pub use character_note::*;
 pub use character_power::*;
 pub use character_skill::*;
+pub use character_snapshot::*;
+pub use companion_link::*;
 pub use edge::*;
 pub use edge_modifier::*;
 pub use edge_requirement::*;
@@ -6,8 +6,21 @@ pub struct Modifier {
     pub target_type: Option<String>,
     pub target_identifier: Option<String>,
     pub value_type: String,
+    /// Signed amount. Positive values are bonuses and negative values are penalties
+    /// (e.g. a hindrance's Pace −1 is a `flat_bonus` of -1). For `die_increment`,
+    /// each point is one die step, so -1 lowers d6 to d4.
     pub value: Option<i64>,
     pub description: String,
+    /// When a situational bonus applies (e.g. "unarmed"). `None` for modifiers
+    /// that are always on.
+    pub condition: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
+
+impl Modifier {
+    /// True for situational modifiers that only apply under their condition.
+    pub fn is_conditional(&self) -> bool {
+        self.condition.is_some()
+    }
+}
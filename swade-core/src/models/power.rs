@@ -5,6 +5,7 @@ pub struct Power {
     pub id: i64,
     pub name: String,
     pub power_points: i64,
+    pub maintenance_power_points: Option<i64>,
     pub range: String,
     pub duration: String,
     pub source: String,
@@ -5,6 +5,9 @@ pub struct Requirement {
     pub id: i64,
     pub requirement_type: String,
     pub target_id: Option<i64>,
+    /// Target referenced by name when there's no id, e.g. a rank in imported
+    /// content. Resolved to `target_id` when the requirement is loaded.
+    pub target_name: Option<String>,
     pub value: Option<i64>,
     pub description: String,
     pub created_at: String,
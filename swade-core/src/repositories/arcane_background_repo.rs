@@ -1,6 +1,6 @@
 use crate::error::Result;
 use crate::models::ArcaneBackground;
-use super::base_repository::{query_one_by_id, query_all_ordered};
+use super::base_repository::{query_one_by_id, query_all_ordered, query_by_fk_id};
 use rusqlite::{Connection, Row};
 
 pub struct ArcaneBackgroundRepository;
@@ -8,7 +8,8 @@ pub struct ArcaneBackgroundRepository;
 impl ArcaneBackgroundRepository {
     const TABLE: &'static str = "arcane_backgrounds";
     const COLUMNS: &'static str = "id, name, arcane_skill_id, starting_powers, starting_power_points,
-                                   has_power_list, source, description, created_at, updated_at";
+                                   has_power_list, source, description, created_at, updated_at,
+                                   edge_id";
 
     pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<ArcaneBackground>> {
         query_one_by_id(conn, Self::TABLE, Self::COLUMNS, id, Self::row_to_model)
@@ -18,6 +19,11 @@ impl ArcaneBackgroundRepository {
         query_all_ordered(conn, Self::TABLE, Self::COLUMNS, "name", Self::row_to_model)
     }
 
+    /// Arcane backgrounds granted by taking the given edge.
+    pub fn get_by_edge_id(conn: &Connection, edge_id: i64) -> Result<Vec<ArcaneBackground>> {
+        query_by_fk_id(conn, Self::TABLE, Self::COLUMNS, "edge_id", edge_id, "name", Self::row_to_model)
+    }
+
     fn row_to_model(row: &Row) -> rusqlite::Result<ArcaneBackground> {
         Ok(ArcaneBackground {
             id: row.get(0)?,
@@ -30,6 +36,7 @@ impl ArcaneBackgroundRepository {
             description: row.get(7)?,
             created_at: row.get(8)?,
             updated_at: row.get(9)?,
+            edge_id: row.get(10)?,
         })
     }
 }
@@ -129,6 +136,28 @@ mod tests {
         assert_eq!(arcane_backgrounds[1].name, "Miracles");
     }
 
+    #[test]
+    fn test_get_by_edge_id() {
+        let conn = setup_test_db();
+        insert_test_attribute(&conn);
+        insert_test_skill(&conn);
+        insert_test_arcane_background(&conn);
+        conn.execute(
+            "INSERT INTO edges (id, name, background, source, description)
+             VALUES (3, 'Arcane Background', 'Background', 'core', 'Grants arcane powers')",
+            [],
+        )
+        .unwrap();
+        conn.execute("UPDATE arcane_backgrounds SET edge_id = 3 WHERE id = 1", []).unwrap();
+
+        let granted = ArcaneBackgroundRepository::get_by_edge_id(&conn, 3).unwrap();
+
+        assert_eq!(granted.len(), 1);
+        assert_eq!(granted[0].name, "Magic");
+        assert_eq!(granted[0].edge_id, Some(3));
+        assert!(ArcaneBackgroundRepository::get_by_edge_id(&conn, 4).unwrap().is_empty());
+    }
+
     #[test]
     fn test_get_all_empty() {
         let conn = setup_test_db();
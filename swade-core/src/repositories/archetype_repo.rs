@@ -0,0 +1,118 @@
+use crate::error::Result;
+use crate::models::{Archetype, ArchetypeSuggestion};
+use super::base_repository::{query_all_ordered, query_by_fk_id, query_one_by_field, query_one_by_id};
+use rusqlite::{Connection, Row};
+
+pub struct ArchetypeRepository;
+
+impl ArchetypeRepository {
+    const TABLE: &'static str = "archetypes";
+    const COLUMNS: &'static str = "id, key, name, description, created_at, updated_at";
+
+    const SUGGESTION_TABLE: &'static str = "archetype_suggestions";
+    const SUGGESTION_COLUMNS: &'static str = "id, archetype_id, suggestion_type, target_id,
+                                              die_size, sort_order, created_at, updated_at";
+
+    pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<Archetype>> {
+        query_one_by_id(conn, Self::TABLE, Self::COLUMNS, id, Self::row_to_model)
+    }
+
+    /// Get an archetype by key (case-insensitive)
+    pub fn get_by_key(conn: &Connection, key: &str) -> Result<Option<Archetype>> {
+        query_one_by_field(conn, Self::TABLE, Self::COLUMNS, "key", key, Self::row_to_model)
+    }
+
+    pub fn get_all(conn: &Connection) -> Result<Vec<Archetype>> {
+        query_all_ordered(conn, Self::TABLE, Self::COLUMNS, "id", Self::row_to_model)
+    }
+
+    /// Get an archetype's suggestions in the order they should be applied
+    pub fn get_suggestions_by_archetype_id(
+        conn: &Connection,
+        archetype_id: i64,
+    ) -> Result<Vec<ArchetypeSuggestion>> {
+        query_by_fk_id(
+            conn,
+            Self::SUGGESTION_TABLE,
+            Self::SUGGESTION_COLUMNS,
+            "archetype_id",
+            archetype_id,
+            "sort_order, id",
+            Self::row_to_suggestion,
+        )
+    }
+
+    fn row_to_model(row: &Row) -> rusqlite::Result<Archetype> {
+        Ok(Archetype {
+            id: row.get(0)?,
+            key: row.get(1)?,
+            name: row.get(2)?,
+            description: row.get(3)?,
+            created_at: row.get(4)?,
+            updated_at: row.get(5)?,
+        })
+    }
+
+    fn row_to_suggestion(row: &Row) -> rusqlite::Result<ArchetypeSuggestion> {
+        Ok(ArchetypeSuggestion {
+            id: row.get(0)?,
+            archetype_id: row.get(1)?,
+            suggestion_type: row.get(2)?,
+            target_id: row.get(3)?,
+            die_size: row.get(4)?,
+            sort_order: row.get(5)?,
+            created_at: row.get(6)?,
+            updated_at: row.get(7)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{insert_test_archetype, insert_test_archetype_suggestion, setup_test_db};
+
+    #[test]
+    fn get_by_key_is_case_insensitive() {
+        let conn = setup_test_db();
+        insert_test_archetype(&conn, 1, "brawler", "Brawler");
+
+        let archetype = ArchetypeRepository::get_by_key(&conn, "Brawler").unwrap().unwrap();
+
+        assert_eq!(archetype.id, 1);
+        assert_eq!(archetype.name, "Brawler");
+        assert!(ArchetypeRepository::get_by_key(&conn, "wizard").unwrap().is_none());
+    }
+
+    #[test]
+    fn get_all_orders_by_id() {
+        let conn = setup_test_db();
+        insert_test_archetype(&conn, 2, "scholar", "Scholar");
+        insert_test_archetype(&conn, 1, "brawler", "Brawler");
+
+        let keys: Vec<String> = ArchetypeRepository::get_all(&conn)
+            .unwrap()
+            .into_iter()
+            .map(|a| a.key)
+            .collect();
+
+        assert_eq!(keys, vec!["brawler", "scholar"]);
+    }
+
+    #[test]
+    fn suggestions_come_back_in_sort_order() {
+        let conn = setup_test_db();
+        insert_test_archetype(&conn, 1, "brawler", "Brawler");
+        insert_test_archetype(&conn, 2, "scholar", "Scholar");
+        insert_test_archetype_suggestion(&conn, 1, 1, "skill", 7, Some(8), 2);
+        insert_test_archetype_suggestion(&conn, 2, 1, "attribute", 3, Some(6), 1);
+        insert_test_archetype_suggestion(&conn, 3, 2, "edge", 4, None, 1);
+
+        let suggestions = ArchetypeRepository::get_suggestions_by_archetype_id(&conn, 1).unwrap();
+
+        assert_eq!(suggestions.len(), 2);
+        assert_eq!(suggestions[0].suggestion_type, "attribute");
+        assert_eq!(suggestions[0].die_size, Some(6));
+        assert_eq!(suggestions[1].target_id, 7);
+    }
+}
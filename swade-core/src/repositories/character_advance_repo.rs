@@ -10,8 +10,8 @@ impl CharacterAdvanceRepository {
         let mut stmt = conn.prepare(
             "SELECT id, character_id, advance_number, advance_type,
                     edge_id, attribute_id, skill_id_1, skill_id_2,
-                    hindrance_id, hindrance_action, notes,
-                    created_at, updated_at
+                    hindrance_id, hindrance_action, hindrance_source, notes,
+                    created_at, updated_at, arcane_background_id
              FROM character_advances WHERE id = ?",
         )?;
 
@@ -28,8 +28,8 @@ impl CharacterAdvanceRepository {
         let mut stmt = conn.prepare(
             "SELECT id, character_id, advance_number, advance_type,
                     edge_id, attribute_id, skill_id_1, skill_id_2,
-                    hindrance_id, hindrance_action, notes,
-                    created_at, updated_at
+                    hindrance_id, hindrance_action, hindrance_source, notes,
+                    created_at, updated_at, arcane_background_id
              FROM character_advances WHERE character_id = ? ORDER BY advance_number",
         )?;
 
@@ -47,8 +47,8 @@ impl CharacterAdvanceRepository {
         let mut stmt = conn.prepare(
             "SELECT id, character_id, advance_number, advance_type,
                     edge_id, attribute_id, skill_id_1, skill_id_2,
-                    hindrance_id, hindrance_action, notes,
-                    created_at, updated_at
+                    hindrance_id, hindrance_action, hindrance_source, notes,
+                    created_at, updated_at, arcane_background_id
              FROM character_advances WHERE character_id = ? AND advance_number = ?",
         )?;
 
@@ -97,8 +97,8 @@ impl CharacterAdvanceRepository {
         let mut stmt = conn.prepare(
             "SELECT id, character_id, advance_number, advance_type,
                     edge_id, attribute_id, skill_id_1, skill_id_2,
-                    hindrance_id, hindrance_action, notes,
-                    created_at, updated_at
+                    hindrance_id, hindrance_action, hindrance_source, notes,
+                    created_at, updated_at, arcane_background_id
              FROM character_advances
              WHERE character_id = ? AND hindrance_id = ? AND hindrance_action = 'remove_major_half'
              ORDER BY advance_number DESC
@@ -110,10 +110,11 @@ impl CharacterAdvanceRepository {
         match rows.next()? {
             Some(row) => {
                 let advance = Self::row_to_model(row)?;
-                // Check if there's a second remove_major_half for this hindrance
+                // Count both halves; older records stored the completing half as remove_major_half too
                 let count: i64 = conn.query_row(
                     "SELECT COUNT(*) FROM character_advances
-                     WHERE character_id = ? AND hindrance_id = ? AND hindrance_action = 'remove_major_half'",
+                     WHERE character_id = ? AND hindrance_id = ?
+                       AND hindrance_action IN ('remove_major_half', 'complete_major_removal')",
                     params![character_id, hindrance_id],
                     |row| row.get(0),
                 )?;
@@ -134,8 +135,9 @@ impl CharacterAdvanceRepository {
             "INSERT INTO character_advances (
                 character_id, advance_number, advance_type,
                 edge_id, attribute_id, skill_id_1, skill_id_2,
-                hindrance_id, hindrance_action, notes
-             ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                hindrance_id, hindrance_action, hindrance_source, notes,
+                arcane_background_id
+             ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
             params![
                 model.character_id,
                 model.advance_number,
@@ -146,19 +148,39 @@ impl CharacterAdvanceRepository {
                 model.skill_id_2,
                 model.hindrance_id,
                 model.hindrance_action,
+                model.hindrance_source,
                 model.notes,
+                model.arcane_background_id,
             ],
         )?;
 
         Ok(conn.last_insert_rowid())
     }
 
+    /// Replace the notes on an existing advance
+    pub fn update_notes(conn: &Connection, id: i64, notes: Option<&str>) -> Result<()> {
+        conn.execute(
+            "UPDATE character_advances SET notes = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+            params![notes, id],
+        )?;
+        Ok(())
+    }
+
     /// Delete a character advance by ID (for undo functionality)
     pub fn delete(conn: &Connection, id: i64) -> Result<()> {
         conn.execute("DELETE FROM character_advances WHERE id = ?", params![id])?;
         Ok(())
     }
 
+    /// Delete all advances for a character
+    pub fn delete_by_character_id(conn: &Connection, character_id: i64) -> Result<()> {
+        conn.execute(
+            "DELETE FROM character_advances WHERE character_id = ?",
+            params![character_id],
+        )?;
+        Ok(())
+    }
+
     /// Delete the most recent advance for a character (for undo functionality)
     pub fn delete_latest(conn: &Connection, character_id: i64) -> Result<bool> {
         let affected = conn.execute(
@@ -184,9 +206,11 @@ impl CharacterAdvanceRepository {
             skill_id_2: row.get(7)?,
             hindrance_id: row.get(8)?,
             hindrance_action: row.get(9)?,
-            notes: row.get(10)?,
-            created_at: row.get(11)?,
-            updated_at: row.get(12)?,
+            hindrance_source: row.get(10)?,
+            notes: row.get(11)?,
+            created_at: row.get(12)?,
+            updated_at: row.get(13)?,
+            arcane_background_id: row.get(14)?,
         })
     }
 }
@@ -366,11 +390,13 @@ mod tests {
             advance_number: 1,
             advance_type: "edge".to_string(),
             edge_id: Some(5),
+            arcane_background_id: Some(2),
             attribute_id: None,
             skill_id_1: None,
             skill_id_2: None,
             hindrance_id: None,
             hindrance_action: None,
+            hindrance_source: None,
             notes: Some("Took Alertness".to_string()),
             created_at: String::new(),
             updated_at: String::new(),
@@ -384,6 +410,7 @@ mod tests {
         assert_eq!(retrieved.advance_number, 1);
         assert_eq!(retrieved.advance_type, "edge");
         assert_eq!(retrieved.edge_id, Some(5));
+        assert_eq!(retrieved.arcane_background_id, Some(2));
         assert_eq!(retrieved.notes, Some("Took Alertness".to_string()));
     }
 
@@ -477,4 +504,43 @@ mod tests {
 
         assert!(result.is_none());
     }
+
+    #[test]
+    fn test_banked_hindrance_removal_explicitly_completed() {
+        let conn = setup_test_db();
+        insert_test_character(&conn, 1, "Test Character");
+        conn.execute(
+            "INSERT INTO character_advances (id, character_id, advance_number, advance_type,
+                                            hindrance_id, hindrance_action, created_at, updated_at)
+             VALUES (1, 1, 1, 'hindrance', 5, 'remove_major_half', '2024-01-01', '2024-01-01')",
+            [],
+        ).unwrap();
+        conn.execute(
+            "INSERT INTO character_advances (id, character_id, advance_number, advance_type,
+                                            hindrance_id, hindrance_action, created_at, updated_at)
+             VALUES (2, 1, 2, 'hindrance', 5, 'complete_major_removal', '2024-01-01', '2024-01-01')",
+            [],
+        ).unwrap();
+
+        let result = CharacterAdvanceRepository::get_banked_hindrance_removal(&conn, 1, 5).unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_update_notes() {
+        let conn = setup_test_db();
+        insert_test_character(&conn, 1, "Test Character");
+        conn.execute(
+            "INSERT INTO character_advances (id, character_id, advance_number, advance_type, created_at, updated_at)
+             VALUES (1, 1, 1, 'edge', '2024-01-01', '2024-01-01')",
+            [],
+        ).unwrap();
+
+        CharacterAdvanceRepository::update_notes(&conn, 1, Some("For the duel")).unwrap();
+
+        let advance = CharacterAdvanceRepository::get_by_id(&conn, 1).unwrap().unwrap();
+        assert_eq!(advance.notes.as_deref(), Some("For the duel"));
+        assert_ne!(advance.updated_at, "2024-01-01");
+    }
 }
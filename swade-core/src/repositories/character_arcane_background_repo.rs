@@ -9,7 +9,7 @@ impl CharacterArcaneBackgroundRepository {
     pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<CharacterArcaneBackground>> {
         let mut stmt = conn.prepare(
             "SELECT id, character_id, arcane_background_id, advance_taken,
-                    created_at, updated_at
+                    power_points_used, created_at, updated_at
              FROM character_arcane_backgrounds WHERE id = ?",
         )?;
 
@@ -28,7 +28,7 @@ impl CharacterArcaneBackgroundRepository {
     ) -> Result<Vec<CharacterArcaneBackground>> {
         let mut stmt = conn.prepare(
             "SELECT id, character_id, arcane_background_id, advance_taken,
-                    created_at, updated_at
+                    power_points_used, created_at, updated_at
              FROM character_arcane_backgrounds WHERE character_id = ?",
         )?;
 
@@ -41,12 +41,13 @@ impl CharacterArcaneBackgroundRepository {
     pub fn insert(conn: &Connection, model: &CharacterArcaneBackground) -> Result<i64> {
         conn.execute(
             "INSERT INTO character_arcane_backgrounds (character_id, arcane_background_id, advance_taken,
-                                                       created_at, updated_at)
-             VALUES (?, ?, ?, ?, ?)",
+                                                       power_points_used, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
             params![
                 model.character_id,
                 model.arcane_background_id,
                 model.advance_taken,
+                model.power_points_used,
                 model.created_at,
                 model.updated_at
             ],
@@ -59,12 +60,14 @@ impl CharacterArcaneBackgroundRepository {
     pub fn update(conn: &Connection, model: &CharacterArcaneBackground) -> Result<()> {
         conn.execute(
             "UPDATE character_arcane_backgrounds
-             SET character_id = ?, arcane_background_id = ?, advance_taken = ?, updated_at = ?
+             SET character_id = ?, arcane_background_id = ?, advance_taken = ?,
+                 power_points_used = ?, updated_at = ?
              WHERE id = ?",
             params![
                 model.character_id,
                 model.arcane_background_id,
                 model.advance_taken,
+                model.power_points_used,
                 model.updated_at,
                 model.id
             ],
@@ -100,8 +103,9 @@ impl CharacterArcaneBackgroundRepository {
             character_id: row.get(1)?,
             arcane_background_id: row.get(2)?,
             advance_taken: row.get(3)?,
-            created_at: row.get(4)?,
-            updated_at: row.get(5)?,
+            power_points_used: row.get(4)?,
+            created_at: row.get(5)?,
+            updated_at: row.get(6)?,
         })
     }
 }
@@ -292,6 +296,7 @@ mod tests {
             character_id: 1,
             arcane_background_id: 1,
             advance_taken: Some(0),
+            power_points_used: 0,
             created_at: "2024-06-15 10:00:00".to_string(),
             updated_at: "2024-06-15 10:00:00".to_string(),
         };
@@ -324,6 +329,7 @@ mod tests {
             character_id: 1,
             arcane_background_id: 1,
             advance_taken: None,
+            power_points_used: 0,
             created_at: "2024-01-01 00:00:00".to_string(),
             updated_at: "2024-01-01 00:00:00".to_string(),
         };
@@ -352,6 +358,7 @@ mod tests {
             .unwrap();
         char_ab.arcane_background_id = 2;
         char_ab.advance_taken = Some(5);
+        char_ab.power_points_used = 4;
         char_ab.updated_at = "2024-12-01 15:30:00".to_string();
 
         CharacterArcaneBackgroundRepository::update(&conn, &char_ab).unwrap();
@@ -362,6 +369,7 @@ mod tests {
             .unwrap();
         assert_eq!(result.arcane_background_id, 2);
         assert_eq!(result.advance_taken, Some(5));
+        assert_eq!(result.power_points_used, 4);
         assert_eq!(result.updated_at, "2024-12-01 15:30:00");
         // created_at should remain unchanged
         assert_eq!(result.created_at, "2024-01-01 00:00:00");
@@ -400,6 +408,7 @@ mod tests {
             character_id: 1,
             arcane_background_id: 1,
             advance_taken: Some(0),
+            power_points_used: 0,
             created_at: "2024-01-01 00:00:00".to_string(),
             updated_at: "2024-01-01 00:00:00".to_string(),
         };
@@ -543,6 +552,7 @@ mod tests {
             character_id: 1,
             arcane_background_id: 1,
             advance_taken: Some(0),
+            power_points_used: 0,
             created_at: "2024-01-01 00:00:00".to_string(),
             updated_at: "2024-01-01 00:00:00".to_string(),
         };
@@ -552,6 +562,7 @@ mod tests {
             character_id: 1,
             arcane_background_id: 2,
             advance_taken: Some(4),
+            power_points_used: 0,
             created_at: "2024-01-01 00:00:00".to_string(),
             updated_at: "2024-01-01 00:00:00".to_string(),
         };
@@ -561,6 +572,7 @@ mod tests {
             character_id: 1,
             arcane_background_id: 3,
             advance_taken: Some(8),
+            power_points_used: 0,
             created_at: "2024-01-01 00:00:00".to_string(),
             updated_at: "2024-01-01 00:00:00".to_string(),
         };
@@ -80,6 +80,15 @@ impl CharacterAttributeRepository {
         Ok(())
     }
 
+    /// Delete all attributes for a character
+    pub fn delete_by_character_id(conn: &Connection, character_id: i64) -> Result<()> {
+        conn.execute(
+            "DELETE FROM character_attributes WHERE character_id = ?",
+            params![character_id],
+        )?;
+        Ok(())
+    }
+
     /// Convert a database row to a CharacterAttribute model
     fn row_to_model(row: &Row) -> rusqlite::Result<CharacterAttribute> {
         Ok(CharacterAttribute {
@@ -81,6 +81,15 @@ impl CharacterEdgeRepository {
         Ok(())
     }
 
+    /// Delete all edges for a character
+    pub fn delete_by_character_id(conn: &Connection, character_id: i64) -> Result<()> {
+        conn.execute(
+            "DELETE FROM character_edges WHERE character_id = ?",
+            params![character_id],
+        )?;
+        Ok(())
+    }
+
     /// Delete all edges for a character with a specific source
     pub fn delete_by_character_id_and_source(
         conn: &Connection,
@@ -89,6 +89,15 @@ impl CharacterHindranceRepository {
         Ok(())
     }
 
+    /// Delete all hindrances for a character
+    pub fn delete_by_character_id(conn: &Connection, character_id: i64) -> Result<()> {
+        conn.execute(
+            "DELETE FROM character_hindrances WHERE character_id = ?",
+            params![character_id],
+        )?;
+        Ok(())
+    }
+
     /// Delete all hindrances for a character with a specific source
     pub fn delete_by_character_id_and_source(
         conn: &Connection,
@@ -1,7 +1,7 @@
 use rusqlite::{Connection, Row, params};
 
 use crate::error::Result;
-use crate::models::CharacterNote;
+use crate::models::{CharacterNote, NoteOrder};
 
 pub struct CharacterNoteRepository;
 
@@ -9,7 +9,7 @@ impl CharacterNoteRepository {
     /// Get a single note by ID
     pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<CharacterNote>> {
         let mut stmt = conn.prepare(
-            "SELECT id, character_id, title, body, created_at, updated_at
+            "SELECT id, character_id, title, body, pinned, category, created_at, updated_at
              FROM character_notes WHERE id = ?",
         )?;
 
@@ -21,28 +21,77 @@ impl CharacterNoteRepository {
         }
     }
 
-    /// Get all notes for a specific character (newest first)
+    /// Get all notes for a specific character (pinned first, then newest first)
     pub fn get_by_character_id(
         conn: &Connection,
         character_id: i64,
+        order: NoteOrder,
     ) -> Result<Vec<CharacterNote>> {
-        let mut stmt = conn.prepare(
-            "SELECT id, character_id, title, body, created_at, updated_at
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id, character_id, title, body, pinned, category, created_at, updated_at
              FROM character_notes WHERE character_id = ?
-             ORDER BY created_at DESC",
-        )?;
+             ORDER BY {}",
+            Self::order_clause(order)
+        ))?;
 
         let rows = stmt.query_map(params![character_id], Self::row_to_model)?;
 
         Ok(rows.filter_map(|r| r.ok()).collect())
     }
 
+    /// Get a character's notes in one category (pinned first, then newest first)
+    pub fn get_by_category(
+        conn: &Connection,
+        character_id: i64,
+        category: &str,
+        order: NoteOrder,
+    ) -> Result<Vec<CharacterNote>> {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id, character_id, title, body, pinned, category, created_at, updated_at
+             FROM character_notes WHERE character_id = ? AND category = ?
+             ORDER BY {}",
+            Self::order_clause(order)
+        ))?;
+
+        let rows = stmt.query_map(params![character_id, category], Self::row_to_model)?;
+
+        Ok(rows.filter_map(|r| r.ok()).collect())
+    }
+
+    /// Search a character's notes by title or body (case-insensitive, most
+    /// recently updated first)
+    pub fn search(conn: &Connection, character_id: i64, query: &str) -> Result<Vec<CharacterNote>> {
+        let escaped = query
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        let pattern = format!("%{}%", escaped);
+
+        let mut stmt = conn.prepare(
+            "SELECT id, character_id, title, body, pinned, category, created_at, updated_at
+             FROM character_notes
+             WHERE character_id = ?1
+               AND (title LIKE ?2 ESCAPE '\\' OR body LIKE ?2 ESCAPE '\\')
+             ORDER BY updated_at DESC, id DESC",
+        )?;
+
+        let rows = stmt.query_map(params![character_id, pattern], Self::row_to_model)?;
+
+        Ok(rows.filter_map(|r| r.ok()).collect())
+    }
+
     /// Insert a new note, return generated ID
     pub fn insert(conn: &Connection, note: &CharacterNote) -> Result<i64> {
         conn.execute(
-            "INSERT INTO character_notes (character_id, title, body)
-             VALUES (?1, ?2, ?3)",
-            params![note.character_id, note.title, note.body],
+            "INSERT INTO character_notes (character_id, title, body, pinned, category)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                note.character_id,
+                note.title,
+                note.body,
+                note.pinned,
+                note.category
+            ],
         )?;
 
         Ok(conn.last_insert_rowid())
@@ -54,14 +103,25 @@ impl CharacterNoteRepository {
             "UPDATE character_notes SET
                 title = ?1,
                 body = ?2,
+                category = ?3,
                 updated_at = CURRENT_TIMESTAMP
-             WHERE id = ?3",
-            params![note.title, note.body, note.id],
+             WHERE id = ?4",
+            params![note.title, note.body, note.category, note.id],
         )?;
 
         Ok(())
     }
 
+    /// Pin or unpin a note. Leaves `updated_at` alone, since pinning doesn't
+    /// change the note's content.
+    pub fn set_pinned(conn: &Connection, id: i64, pinned: bool) -> Result<()> {
+        conn.execute(
+            "UPDATE character_notes SET pinned = ?1 WHERE id = ?2",
+            params![pinned, id],
+        )?;
+        Ok(())
+    }
+
     /// Delete a note by ID
     pub fn delete(conn: &Connection, id: i64) -> Result<()> {
         conn.execute("DELETE FROM character_notes WHERE id = ?", params![id])?;
@@ -77,6 +137,15 @@ impl CharacterNoteRepository {
         Ok(())
     }
 
+    /// ORDER BY clause listing pinned notes first, then newest by the chosen
+    /// timestamp. Ties fall back to the most recently inserted note.
+    fn order_clause(order: NoteOrder) -> &'static str {
+        match order {
+            NoteOrder::UpdatedAt => "pinned DESC, updated_at DESC, id DESC",
+            NoteOrder::CreatedAt => "pinned DESC, created_at DESC, id DESC",
+        }
+    }
+
     /// Convert a database row to a CharacterNote model
     fn row_to_model(row: &Row) -> rusqlite::Result<CharacterNote> {
         Ok(CharacterNote {
@@ -84,8 +153,10 @@ impl CharacterNoteRepository {
             character_id: row.get(1)?,
             title: row.get(2)?,
             body: row.get(3)?,
-            created_at: row.get(4)?,
-            updated_at: row.get(5)?,
+            pinned: row.get(4)?,
+            category: row.get(5)?,
+            created_at: row.get(6)?,
+            updated_at: row.get(7)?,
         })
     }
 }
@@ -164,7 +235,7 @@ mod tests {
         let conn = setup_test_db();
         insert_test_character(&conn, 1, "Test Character");
 
-        let notes = CharacterNoteRepository::get_by_character_id(&conn, 1).unwrap();
+        let notes = CharacterNoteRepository::get_by_character_id(&conn, 1, NoteOrder::UpdatedAt).unwrap();
 
         assert_eq!(notes.len(), 0);
     }
@@ -191,7 +262,7 @@ mod tests {
             [],
         ).unwrap();
 
-        let notes = CharacterNoteRepository::get_by_character_id(&conn, 1).unwrap();
+        let notes = CharacterNoteRepository::get_by_character_id(&conn, 1, NoteOrder::UpdatedAt).unwrap();
 
         assert_eq!(notes.len(), 3);
         // Should be ordered newest first
@@ -200,6 +271,34 @@ mod tests {
         assert_eq!(notes[2].title, "First Note");
     }
 
+    #[test]
+    fn test_updated_note_moves_to_top() {
+        let conn = setup_test_db();
+        insert_test_character(&conn, 1, "Test Character");
+        for (id, day) in [(1, 1), (2, 2), (3, 3)] {
+            conn.execute(
+                "INSERT INTO character_notes (id, character_id, title, body, created_at, updated_at)
+                 VALUES (?1, 1, ?2, 'Body', ?3, ?3)",
+                params![id, format!("Note {}", id), format!("2024-01-0{} 10:00:00", day)],
+            )
+            .unwrap();
+        }
+
+        let mut first = CharacterNoteRepository::get_by_id(&conn, 1).unwrap().unwrap();
+        first.body = "Edited".to_string();
+        CharacterNoteRepository::update(&conn, &first).unwrap();
+
+        let by_updated =
+            CharacterNoteRepository::get_by_character_id(&conn, 1, NoteOrder::UpdatedAt).unwrap();
+        let titles: Vec<&str> = by_updated.iter().map(|n| n.title.as_str()).collect();
+        assert_eq!(titles, vec!["Note 1", "Note 3", "Note 2"]);
+
+        let by_created =
+            CharacterNoteRepository::get_by_character_id(&conn, 1, NoteOrder::CreatedAt).unwrap();
+        let titles: Vec<&str> = by_created.iter().map(|n| n.title.as_str()).collect();
+        assert_eq!(titles, vec!["Note 3", "Note 2", "Note 1"]);
+    }
+
     #[test]
     fn test_get_by_character_id_filters_correctly() {
         let conn = setup_test_db();
@@ -208,12 +307,105 @@ mod tests {
         insert_test_note(&conn, 1, 1, "Note for Char 1", "Body");
         insert_test_note(&conn, 2, 2, "Note for Char 2", "Body");
 
-        let notes = CharacterNoteRepository::get_by_character_id(&conn, 1).unwrap();
+        let notes = CharacterNoteRepository::get_by_character_id(&conn, 1, NoteOrder::UpdatedAt).unwrap();
 
         assert_eq!(notes.len(), 1);
         assert_eq!(notes[0].character_id, 1);
     }
 
+    #[test]
+    fn test_pinned_note_sorts_first() {
+        let conn = setup_test_db();
+        insert_test_character(&conn, 1, "Test Character");
+        conn.execute(
+            "INSERT INTO character_notes (id, character_id, title, body, created_at, updated_at)
+             VALUES (1, 1, 'Old Note', 'Body', '2024-01-01 10:00:00', '2024-01-01 10:00:00')",
+            [],
+        ).unwrap();
+        conn.execute(
+            "INSERT INTO character_notes (id, character_id, title, body, created_at, updated_at)
+             VALUES (2, 1, 'New Note', 'Body', '2024-01-02 10:00:00', '2024-01-02 10:00:00')",
+            [],
+        ).unwrap();
+
+        CharacterNoteRepository::set_pinned(&conn, 1, true).unwrap();
+
+        let notes = CharacterNoteRepository::get_by_character_id(&conn, 1, NoteOrder::UpdatedAt).unwrap();
+        assert_eq!(notes[0].title, "Old Note");
+        assert!(notes[0].pinned);
+        assert_eq!(notes[1].title, "New Note");
+        assert_eq!(notes[0].updated_at, "2024-01-01 10:00:00");
+    }
+
+    #[test]
+    fn test_get_by_category() {
+        let conn = setup_test_db();
+        insert_test_character(&conn, 1, "Test Character");
+        insert_test_note(&conn, 1, 1, "Session 1", "Goblins");
+        insert_test_note(&conn, 2, 1, "Old Tom", "Innkeeper");
+        insert_test_note(&conn, 3, 1, "Session 2", "Dragons");
+        conn.execute(
+            "UPDATE character_notes SET category = 'Session Log' WHERE id IN (1, 3)",
+            [],
+        )
+        .unwrap();
+        conn.execute("UPDATE character_notes SET category = 'Contacts' WHERE id = 2", [])
+            .unwrap();
+
+        let notes = CharacterNoteRepository::get_by_category(&conn, 1, "Session Log", NoteOrder::UpdatedAt).unwrap();
+
+        assert_eq!(notes.len(), 2);
+        assert!(notes.iter().all(|n| n.category.as_deref() == Some("Session Log")));
+    }
+
+    #[test]
+    fn test_search_matches_title_case_insensitively() {
+        let conn = setup_test_db();
+        insert_test_character(&conn, 1, "Test Character");
+        insert_test_note(&conn, 1, 1, "Goblin Ambush", "We ran.");
+        insert_test_note(&conn, 2, 1, "Shopping", "Bought rope.");
+
+        let notes = CharacterNoteRepository::search(&conn, 1, "goblin").unwrap();
+
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].title, "Goblin Ambush");
+    }
+
+    #[test]
+    fn test_search_matches_body_newest_first() {
+        let conn = setup_test_db();
+        insert_test_character(&conn, 1, "Test Character");
+        conn.execute(
+            "INSERT INTO character_notes (id, character_id, title, body, created_at, updated_at)
+             VALUES (1, 1, 'Session 1', 'Met the DRAGON', '2024-01-01 10:00:00', '2024-01-01 10:00:00')",
+            [],
+        ).unwrap();
+        conn.execute(
+            "INSERT INTO character_notes (id, character_id, title, body, created_at, updated_at)
+             VALUES (2, 1, 'Session 2', 'Fled the dragon', '2024-01-02 10:00:00', '2024-01-02 10:00:00')",
+            [],
+        ).unwrap();
+        insert_test_note(&conn, 3, 1, "Session 3", "Quiet day");
+
+        let notes = CharacterNoteRepository::search(&conn, 1, "Dragon").unwrap();
+
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0].title, "Session 2");
+        assert_eq!(notes[1].title, "Session 1");
+    }
+
+    #[test]
+    fn test_search_no_match_returns_empty() {
+        let conn = setup_test_db();
+        insert_test_character(&conn, 1, "Test Character");
+        insert_test_character(&conn, 2, "Other Character");
+        insert_test_note(&conn, 1, 1, "Session 1", "Goblins");
+        insert_test_note(&conn, 2, 2, "Session 1", "Dragons");
+
+        assert!(CharacterNoteRepository::search(&conn, 1, "dragon").unwrap().is_empty());
+        assert!(CharacterNoteRepository::search(&conn, 1, "%").unwrap().is_empty());
+    }
+
     #[test]
     fn test_insert() {
         let conn = setup_test_db();
@@ -224,6 +416,8 @@ mod tests {
             character_id: 1,
             title: "New Note".to_string(),
             body: "Some content here.".to_string(),
+            pinned: false,
+            category: Some("Background".to_string()),
             created_at: String::new(),
             updated_at: String::new(),
         };
@@ -237,6 +431,7 @@ mod tests {
         let inserted = result.unwrap();
         assert_eq!(inserted.title, "New Note");
         assert_eq!(inserted.body, "Some content here.");
+        assert_eq!(inserted.category.as_deref(), Some("Background"));
     }
 
     #[test]
@@ -281,13 +476,13 @@ mod tests {
         insert_test_note(&conn, 3, 1, "Note 3", "Body 3");
 
         // Verify they exist
-        assert_eq!(CharacterNoteRepository::get_by_character_id(&conn, 1).unwrap().len(), 3);
+        assert_eq!(CharacterNoteRepository::get_by_character_id(&conn, 1, NoteOrder::UpdatedAt).unwrap().len(), 3);
 
         // Delete all notes for character
         CharacterNoteRepository::delete_by_character_id(&conn, 1).unwrap();
 
         // Verify they're gone
-        assert_eq!(CharacterNoteRepository::get_by_character_id(&conn, 1).unwrap().len(), 0);
+        assert_eq!(CharacterNoteRepository::get_by_character_id(&conn, 1, NoteOrder::UpdatedAt).unwrap().len(), 0);
     }
 
     #[test]
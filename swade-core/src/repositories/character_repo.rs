@@ -6,20 +6,25 @@ use crate::models::Character;
 pub struct CharacterRepository;
 
 impl CharacterRepository {
+    const COLUMNS: &'static str = "id, is_wild_card, name, ancestry_id,
+                                   attribute_points_spent, attribute_points_earned,
+                                   skill_points_spent, skill_points_earned,
+                                   hindrance_points_spent, hindrance_points_earned,
+                                   hindrance_points_to_edges, hindrance_points_to_attributes,
+                                   hindrance_points_to_skills, hindrance_points_to_wealth,
+                                   power_points, power_points_used, wounds, fatigue,
+                                   wealth, background, description, campaign, archived,
+                                   max_attribute_die_size, max_attribute_die_modifier,
+                                   allow_hindrances_past_cap, load_limit_multiplier, parry_skill,
+                                   max_wounds, track_experience, experience_points,
+                                   sell_back_percent, weight_unit, portrait, portrait_mime_type,
+                                   created_at, updated_at, revision";
+
     pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<Character>> {
-        let mut stmt = conn.prepare(
-            "SELECT id, is_wild_card, name, ancestry_id,
-                    attribute_points_spent, attribute_points_earned,
-                    skill_points_spent, skill_points_earned,
-                    hindrance_points_spent, hindrance_points_earned,
-                    hindrance_points_to_edges, hindrance_points_to_attributes,
-                    hindrance_points_to_skills, hindrance_points_to_wealth,
-                    power_points, power_points_used, wounds, fatigue,
-                    wealth, background, description,
-                    portrait, portrait_mime_type,
-                    created_at, updated_at
-             FROM characters WHERE id = ?",
-        )?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM characters WHERE id = ?",
+            Self::COLUMNS
+        ))?;
 
         let mut rows = stmt.query(params![id])?;
 
@@ -29,26 +34,57 @@ impl CharacterRepository {
         }
     }
 
+    /// Get all characters that aren't archived.
     pub fn get_all(conn: &Connection) -> Result<Vec<Character>> {
-        let mut stmt = conn.prepare(
-            "SELECT id, is_wild_card, name, ancestry_id,
-                    attribute_points_spent, attribute_points_earned,
-                    skill_points_spent, skill_points_earned,
-                    hindrance_points_spent, hindrance_points_earned,
-                    hindrance_points_to_edges, hindrance_points_to_attributes,
-                    hindrance_points_to_skills, hindrance_points_to_wealth,
-                    power_points, power_points_used, wounds, fatigue,
-                    wealth, background, description,
-                    portrait, portrait_mime_type,
-                    created_at, updated_at
-             FROM characters ORDER BY name",
-        )?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM characters WHERE archived = 0 ORDER BY name",
+            Self::COLUMNS
+        ))?;
+
+        let rows = stmt.query_map([], Self::row_to_model)?;
+
+        Ok(rows.filter_map(|r| r.ok()).collect())
+    }
+
+    /// Get all characters that aren't archived, leaving their portraits unread.
+    ///
+    /// For listings that never show a portrait, so no image bytes are loaded.
+    pub fn get_all_without_portraits(conn: &Connection) -> Result<Vec<Character>> {
+        let columns = Self::COLUMNS.replace("portrait, portrait_mime_type", "NULL, NULL");
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM characters WHERE archived = 0 ORDER BY name",
+            columns
+        ))?;
+
+        let rows = stmt.query_map([], Self::row_to_model)?;
+
+        Ok(rows.filter_map(|r| r.ok()).collect())
+    }
+
+    pub fn get_archived(conn: &Connection) -> Result<Vec<Character>> {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM characters WHERE archived = 1 ORDER BY name",
+            Self::COLUMNS
+        ))?;
 
         let rows = stmt.query_map([], Self::row_to_model)?;
 
         Ok(rows.filter_map(|r| r.ok()).collect())
     }
 
+    pub fn get_characters_by_campaign(conn: &Connection, campaign: &str) -> Result<Vec<Character>> {
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM characters WHERE campaign = ? AND archived = 0 ORDER BY name",
+            Self::COLUMNS
+        ))?;
+
+        let rows = stmt.query_map(params![campaign], Self::row_to_model)?;
+
+        Ok(rows.filter_map(|r| r.ok()).collect())
+    }
+
+    /// Insert a character, returning its ID. An `id` of 0 lets the database
+    /// assign one; any other id is kept (used to recreate a deleted character).
     pub fn insert(conn: &Connection, character: &Character) -> Result<i64> {
         conn.execute(
             "INSERT INTO characters (
@@ -59,9 +95,12 @@ impl CharacterRepository {
                 hindrance_points_to_edges, hindrance_points_to_attributes,
                 hindrance_points_to_skills, hindrance_points_to_wealth,
                 power_points, power_points_used, wounds, fatigue,
-                wealth, background, description,
-                portrait, portrait_mime_type
-             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22)",
+                wealth, background, description, campaign,
+                max_attribute_die_size, max_attribute_die_modifier,
+                allow_hindrances_past_cap, load_limit_multiplier, parry_skill, max_wounds,
+                track_experience, experience_points, sell_back_percent, weight_unit,
+                portrait, portrait_mime_type, id
+             ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31, ?32, ?33, ?34)",
             params![
                 character.is_wild_card,
                 character.name,
@@ -83,8 +122,20 @@ impl CharacterRepository {
                 character.wealth,
                 character.background,
                 character.description,
+                character.campaign,
+                character.max_attribute_die_size,
+                character.max_attribute_die_modifier,
+                character.allow_hindrances_past_cap,
+                character.load_limit_multiplier,
+                character.parry_skill,
+                character.max_wounds,
+                character.track_experience,
+                character.experience_points,
+                character.sell_back_percent,
+                character.weight_unit,
                 character.portrait,
                 character.portrait_mime_type,
+                (character.id != 0).then_some(character.id),
             ],
         )?;
 
@@ -114,8 +165,20 @@ impl CharacterRepository {
                 wealth = ?18,
                 background = ?19,
                 description = ?20,
-                updated_at = CURRENT_TIMESTAMP
-             WHERE id = ?21",
+                campaign = ?21,
+                max_attribute_die_size = ?22,
+                max_attribute_die_modifier = ?23,
+                allow_hindrances_past_cap = ?24,
+                load_limit_multiplier = ?25,
+                parry_skill = ?26,
+                max_wounds = ?27,
+                track_experience = ?28,
+                experience_points = ?29,
+                sell_back_percent = ?30,
+                weight_unit = ?31,
+                updated_at = CURRENT_TIMESTAMP,
+                revision = revision + 1
+             WHERE id = ?32",
             params![
                 character.is_wild_card,
                 character.name,
@@ -137,6 +200,17 @@ impl CharacterRepository {
                 character.wealth,
                 character.background,
                 character.description,
+                character.campaign,
+                character.max_attribute_die_size,
+                character.max_attribute_die_modifier,
+                character.allow_hindrances_past_cap,
+                character.load_limit_multiplier,
+                character.parry_skill,
+                character.max_wounds,
+                character.track_experience,
+                character.experience_points,
+                character.sell_back_percent,
+                character.weight_unit,
                 character.id,
             ],
         )?;
@@ -149,6 +223,74 @@ impl CharacterRepository {
         Ok(())
     }
 
+    /// Archive or restore a character. Archived characters are left out of
+    /// `get_all`.
+    pub fn set_archived(conn: &Connection, id: i64, archived: bool) -> Result<()> {
+        conn.execute(
+            "UPDATE characters SET archived = ?1, updated_at = CURRENT_TIMESTAMP, revision = revision + 1 WHERE id = ?2",
+            params![archived, id],
+        )?;
+        Ok(())
+    }
+
+    /// Rename a character.
+    pub fn set_name(conn: &Connection, id: i64, name: &str) -> Result<()> {
+        conn.execute(
+            "UPDATE characters SET name = ?1, updated_at = CURRENT_TIMESTAMP, revision = revision + 1 WHERE id = ?2",
+            params![name, id],
+        )?;
+        Ok(())
+    }
+
+    /// Set whether a character is a Wild Card.
+    pub fn set_wild_card(conn: &Connection, id: i64, is_wild_card: bool) -> Result<()> {
+        conn.execute(
+            "UPDATE characters SET is_wild_card = ?1, updated_at = CURRENT_TIMESTAMP, revision = revision + 1 WHERE id = ?2",
+            params![is_wild_card, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn set_wounds(conn: &Connection, id: i64, wounds: i64) -> Result<()> {
+        conn.execute(
+            "UPDATE characters SET wounds = ?1, updated_at = CURRENT_TIMESTAMP, revision = revision + 1 WHERE id = ?2",
+            params![wounds, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn adjust_wealth(conn: &Connection, id: i64, delta: i64) -> Result<()> {
+        conn.execute(
+            "UPDATE characters SET wealth = wealth + ?1, updated_at = CURRENT_TIMESTAMP, revision = revision + 1 WHERE id = ?2",
+            params![delta, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn adjust_power_points(conn: &Connection, id: i64, delta: i64) -> Result<()> {
+        conn.execute(
+            "UPDATE characters SET power_points = power_points + ?1, updated_at = CURRENT_TIMESTAMP, revision = revision + 1 WHERE id = ?2",
+            params![delta, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn set_experience_points(conn: &Connection, id: i64, experience_points: i64) -> Result<()> {
+        conn.execute(
+            "UPDATE characters SET experience_points = ?1, updated_at = CURRENT_TIMESTAMP, revision = revision + 1 WHERE id = ?2",
+            params![experience_points, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn set_fatigue(conn: &Connection, id: i64, fatigue: i64) -> Result<()> {
+        conn.execute(
+            "UPDATE characters SET fatigue = ?1, updated_at = CURRENT_TIMESTAMP, revision = revision + 1 WHERE id = ?2",
+            params![fatigue, id],
+        )?;
+        Ok(())
+    }
+
     /// Update only the portrait fields for a character.
     /// Pass None for both to clear the portrait.
     pub fn update_portrait(
@@ -158,7 +300,7 @@ impl CharacterRepository {
         mime_type: Option<&str>,
     ) -> Result<()> {
         conn.execute(
-            "UPDATE characters SET portrait = ?1, portrait_mime_type = ?2, updated_at = CURRENT_TIMESTAMP WHERE id = ?3",
+            "UPDATE characters SET portrait = ?1, portrait_mime_type = ?2, updated_at = CURRENT_TIMESTAMP, revision = revision + 1 WHERE id = ?3",
             params![portrait, mime_type, id],
         )?;
         Ok(())
@@ -187,10 +329,23 @@ impl CharacterRepository {
             wealth: row.get(18)?,
             background: row.get(19)?,
             description: row.get(20)?,
-            portrait: row.get(21)?,
-            portrait_mime_type: row.get(22)?,
-            created_at: row.get(23)?,
-            updated_at: row.get(24)?,
+            campaign: row.get(21)?,
+            archived: row.get(22)?,
+            max_attribute_die_size: row.get(23)?,
+            max_attribute_die_modifier: row.get(24)?,
+            allow_hindrances_past_cap: row.get(25)?,
+            load_limit_multiplier: row.get(26)?,
+            parry_skill: row.get(27)?,
+            max_wounds: row.get(28)?,
+            track_experience: row.get(29)?,
+            experience_points: row.get(30)?,
+            sell_back_percent: row.get(31)?,
+            weight_unit: row.get(32)?,
+            portrait: row.get(33)?,
+            portrait_mime_type: row.get(34)?,
+            created_at: row.get(35)?,
+            updated_at: row.get(36)?,
+            revision: row.get(37)?,
         })
     }
 }
@@ -234,10 +389,23 @@ mod tests {
             wealth: 500,
             background: Some("A brave adventurer".to_string()),
             description: Some("Tall and strong".to_string()),
+            campaign: None,
+            archived: false,
+            max_attribute_die_size: None,
+            max_attribute_die_modifier: 0,
+            allow_hindrances_past_cap: false,
+            load_limit_multiplier: None,
+            parry_skill: None,
+            max_wounds: None,
+            track_experience: false,
+            experience_points: 0,
+            sell_back_percent: None,
+            weight_unit: "lb".to_string(),
             portrait: None,
             portrait_mime_type: None,
             created_at: "2024-01-01 00:00:00".to_string(),
             updated_at: "2024-01-01 00:00:00".to_string(),
+            revision: 0,
         }
     }
 
@@ -320,6 +488,43 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_get_characters_by_campaign() {
+        let conn = setup_test_db();
+
+        for (name, campaign) in [
+            ("Bob", Some("Deadlands")),
+            ("Alice", Some("Deadlands")),
+            ("Carol", Some("Rippers")),
+            ("Dave", None),
+        ] {
+            let mut character = create_test_character();
+            character.name = name.to_string();
+            character.campaign = campaign.map(str::to_string);
+            CharacterRepository::insert(&conn, &character).unwrap();
+        }
+
+        let characters = CharacterRepository::get_characters_by_campaign(&conn, "Deadlands").unwrap();
+
+        assert_eq!(characters.len(), 2);
+        assert_eq!(characters[0].name, "Alice");
+        assert_eq!(characters[1].name, "Bob");
+        assert!(characters.iter().all(|c| c.campaign.as_deref() == Some("Deadlands")));
+    }
+
+    #[test]
+    fn test_set_archived_excludes_from_get_all() {
+        let conn = setup_test_db();
+        let id = insert_test_character(&conn);
+
+        CharacterRepository::set_archived(&conn, id, true).unwrap();
+
+        assert!(CharacterRepository::get_all(&conn).unwrap().is_empty());
+        let archived = CharacterRepository::get_archived(&conn).unwrap();
+        assert_eq!(archived.len(), 1);
+        assert!(archived[0].archived);
+    }
+
     #[test]
     fn test_get_all_empty() {
         let conn = setup_test_db();
@@ -355,6 +560,24 @@ mod tests {
         assert_eq!(characters[2].name, "Charlie");
     }
 
+    #[test]
+    fn test_get_all_without_portraits_skips_image_and_archived() {
+        let conn = setup_test_db();
+        let mut character = create_test_character();
+        character.portrait = Some(vec![1, 2, 3]);
+        character.portrait_mime_type = Some("image/png".to_string());
+        CharacterRepository::insert(&conn, &character).unwrap();
+        let archived_id = insert_test_character(&conn);
+        CharacterRepository::set_archived(&conn, archived_id, true).unwrap();
+
+        let characters = CharacterRepository::get_all_without_portraits(&conn).unwrap();
+
+        assert_eq!(characters.len(), 1);
+        assert_eq!(characters[0].name, "Test Hero");
+        assert_eq!(characters[0].portrait, None);
+        assert_eq!(characters[0].portrait_mime_type, None);
+    }
+
     #[test]
     fn test_update() {
         let conn = setup_test_db();
@@ -382,6 +605,37 @@ mod tests {
         assert_eq!(result.background, Some("Updated background".to_string()));
     }
 
+    #[test]
+    fn test_updates_increment_revision() {
+        let conn = setup_test_db();
+        let id = insert_test_character(&conn);
+        let character = CharacterRepository::get_by_id(&conn, id).unwrap().unwrap();
+        assert_eq!(character.revision, 0);
+
+        CharacterRepository::update(&conn, &character).unwrap();
+        CharacterRepository::set_wounds(&conn, id, 1).unwrap();
+
+        let result = CharacterRepository::get_by_id(&conn, id).unwrap().unwrap();
+        assert_eq!(result.revision, 2);
+    }
+
+    #[test]
+    fn test_update_max_attribute_die() {
+        let conn = setup_test_db();
+        let id = insert_test_character(&conn);
+
+        let mut character = CharacterRepository::get_by_id(&conn, id).unwrap().unwrap();
+        assert_eq!(character.max_attribute_die_size, None);
+
+        character.max_attribute_die_size = Some(12);
+        character.max_attribute_die_modifier = 2;
+        CharacterRepository::update(&conn, &character).unwrap();
+
+        let result = CharacterRepository::get_by_id(&conn, id).unwrap().unwrap();
+        assert_eq!(result.max_attribute_die_size, Some(12));
+        assert_eq!(result.max_attribute_die_modifier, 2);
+    }
+
     #[test]
     fn test_update_to_null() {
         let conn = setup_test_db();
@@ -83,6 +83,15 @@ impl CharacterSkillRepository {
         Ok(())
     }
 
+    /// Delete all skills for a character
+    pub fn delete_by_character_id(conn: &Connection, character_id: i64) -> Result<()> {
+        conn.execute(
+            "DELETE FROM character_skills WHERE character_id = ?",
+            params![character_id],
+        )?;
+        Ok(())
+    }
+
     /// Convert a database row to a CharacterSkill model
     fn row_to_model(row: &Row) -> rusqlite::Result<CharacterSkill> {
         Ok(CharacterSkill {
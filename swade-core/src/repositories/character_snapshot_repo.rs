@@ -0,0 +1,106 @@
+use rusqlite::{Connection, Row, params};
+
+use crate::error::Result;
+use crate::models::CharacterSnapshot;
+
+pub struct CharacterSnapshotRepository;
+
+impl CharacterSnapshotRepository {
+    /// Get a single snapshot by ID
+    pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<CharacterSnapshot>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, character_id, label, data, created_at, updated_at
+             FROM character_snapshots WHERE id = ?",
+        )?;
+
+        let mut rows = stmt.query(params![id])?;
+
+        match rows.next()? {
+            Some(row) => Ok(Some(Self::row_to_model(row)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get all snapshots for a character (newest first)
+    pub fn get_by_character_id(conn: &Connection, character_id: i64) -> Result<Vec<CharacterSnapshot>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, character_id, label, data, created_at, updated_at
+             FROM character_snapshots WHERE character_id = ?
+             ORDER BY created_at DESC, id DESC",
+        )?;
+
+        let rows = stmt.query_map(params![character_id], Self::row_to_model)?;
+
+        Ok(rows.filter_map(|r| r.ok()).collect())
+    }
+
+    /// Insert a new snapshot, return generated ID
+    pub fn insert(conn: &Connection, snapshot: &CharacterSnapshot) -> Result<i64> {
+        conn.execute(
+            "INSERT INTO character_snapshots (character_id, label, data) VALUES (?1, ?2, ?3)",
+            params![snapshot.character_id, snapshot.label, snapshot.data],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Convert a database row to a CharacterSnapshot model
+    fn row_to_model(row: &Row) -> rusqlite::Result<CharacterSnapshot> {
+        Ok(CharacterSnapshot {
+            id: row.get(0)?,
+            character_id: row.get(1)?,
+            label: row.get(2)?,
+            data: row.get(3)?,
+            created_at: row.get(4)?,
+            updated_at: row.get(5)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::setup_test_db;
+
+    fn insert_snapshot(conn: &Connection, character_id: i64, label: &str) -> i64 {
+        let snapshot = CharacterSnapshot {
+            id: 0,
+            character_id,
+            label: label.to_string(),
+            data: "{}".to_string(),
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+        CharacterSnapshotRepository::insert(conn, &snapshot).unwrap()
+    }
+
+    #[test]
+    fn get_by_id_returns_inserted_snapshot() {
+        let conn = setup_test_db();
+        let id = insert_snapshot(&conn, 1, "Before Seasoned");
+
+        let snapshot = CharacterSnapshotRepository::get_by_id(&conn, id).unwrap().unwrap();
+
+        assert_eq!(snapshot.character_id, 1);
+        assert_eq!(snapshot.label, "Before Seasoned");
+        assert_eq!(snapshot.data, "{}");
+        assert!(!snapshot.created_at.is_empty());
+        assert!(CharacterSnapshotRepository::get_by_id(&conn, 999).unwrap().is_none());
+    }
+
+    #[test]
+    fn get_by_character_id_lists_newest_first() {
+        let conn = setup_test_db();
+        insert_snapshot(&conn, 1, "First");
+        insert_snapshot(&conn, 2, "Someone else");
+        insert_snapshot(&conn, 1, "Second");
+
+        let labels: Vec<String> = CharacterSnapshotRepository::get_by_character_id(&conn, 1)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.label)
+            .collect();
+
+        assert_eq!(labels, vec!["Second", "First"]);
+    }
+}
@@ -0,0 +1,176 @@
+use rusqlite::{Connection, Row, params};
+
+use crate::error::Result;
+use crate::models::CompanionLink;
+
+pub struct CompanionLinkRepository;
+
+impl CompanionLinkRepository {
+    /// Get the link between a parent and a companion, if any
+    pub fn get_by_pair(
+        conn: &Connection,
+        parent_character_id: i64,
+        child_character_id: i64,
+    ) -> Result<Option<CompanionLink>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, parent_character_id, child_character_id, relationship, created_at, updated_at
+             FROM companion_links WHERE parent_character_id = ? AND child_character_id = ?",
+        )?;
+
+        let mut rows = stmt.query(params![parent_character_id, child_character_id])?;
+
+        match rows.next()? {
+            Some(row) => Ok(Some(Self::row_to_model(row)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get all companion links for a parent character (oldest first)
+    pub fn get_by_parent_id(
+        conn: &Connection,
+        parent_character_id: i64,
+    ) -> Result<Vec<CompanionLink>> {
+        let mut stmt = conn.prepare(
+            "SELECT id, parent_character_id, child_character_id, relationship, created_at, updated_at
+             FROM companion_links WHERE parent_character_id = ?
+             ORDER BY id",
+        )?;
+
+        let rows = stmt.query_map(params![parent_character_id], Self::row_to_model)?;
+
+        Ok(rows.filter_map(|r| r.ok()).collect())
+    }
+
+    /// Insert a new link, return generated ID
+    pub fn insert(conn: &Connection, link: &CompanionLink) -> Result<i64> {
+        conn.execute(
+            "INSERT INTO companion_links (parent_character_id, child_character_id, relationship)
+             VALUES (?1, ?2, ?3)",
+            params![
+                link.parent_character_id,
+                link.child_character_id,
+                link.relationship
+            ],
+        )?;
+
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Delete a link by ID
+    pub fn delete(conn: &Connection, id: i64) -> Result<()> {
+        conn.execute("DELETE FROM companion_links WHERE id = ?", params![id])?;
+        Ok(())
+    }
+
+    /// Delete every link a character is part of, as parent or companion
+    pub fn delete_by_character_id(conn: &Connection, character_id: i64) -> Result<()> {
+        conn.execute(
+            "DELETE FROM companion_links
+             WHERE parent_character_id = ?1 OR child_character_id = ?1",
+            params![character_id],
+        )?;
+        Ok(())
+    }
+
+    /// Convert a database row to a CompanionLink model
+    fn row_to_model(row: &Row) -> rusqlite::Result<CompanionLink> {
+        Ok(CompanionLink {
+            id: row.get(0)?,
+            parent_character_id: row.get(1)?,
+            child_character_id: row.get(2)?,
+            relationship: row.get(3)?,
+            created_at: row.get(4)?,
+            updated_at: row.get(5)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::setup_test_db_no_fk;
+
+    fn insert_test_link(conn: &Connection, parent: i64, child: i64, relationship: &str) -> i64 {
+        let link = CompanionLink {
+            id: 0,
+            parent_character_id: parent,
+            child_character_id: child,
+            relationship: relationship.to_string(),
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+        CompanionLinkRepository::insert(conn, &link).unwrap()
+    }
+
+    #[test]
+    fn test_insert_and_get_by_parent_id() {
+        let conn = setup_test_db_no_fk();
+        insert_test_link(&conn, 1, 2, "Sidekick");
+        insert_test_link(&conn, 1, 3, "Animal Companion");
+        insert_test_link(&conn, 4, 5, "Sidekick");
+
+        let links = CompanionLinkRepository::get_by_parent_id(&conn, 1).unwrap();
+
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].child_character_id, 2);
+        assert_eq!(links[0].relationship, "Sidekick");
+        assert_eq!(links[1].child_character_id, 3);
+    }
+
+    #[test]
+    fn test_get_by_pair() {
+        let conn = setup_test_db_no_fk();
+        let id = insert_test_link(&conn, 1, 2, "Sidekick");
+
+        let link = CompanionLinkRepository::get_by_pair(&conn, 1, 2)
+            .unwrap()
+            .unwrap();
+        assert_eq!(link.id, id);
+        assert!(
+            CompanionLinkRepository::get_by_pair(&conn, 2, 1)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_delete() {
+        let conn = setup_test_db_no_fk();
+        let id = insert_test_link(&conn, 1, 2, "Sidekick");
+
+        CompanionLinkRepository::delete(&conn, id).unwrap();
+
+        assert!(
+            CompanionLinkRepository::get_by_parent_id(&conn, 1)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_delete_by_character_id_removes_both_directions() {
+        let conn = setup_test_db_no_fk();
+        insert_test_link(&conn, 1, 2, "Sidekick");
+        insert_test_link(&conn, 2, 3, "Animal Companion");
+        insert_test_link(&conn, 4, 5, "Sidekick");
+
+        CompanionLinkRepository::delete_by_character_id(&conn, 2).unwrap();
+
+        assert!(
+            CompanionLinkRepository::get_by_parent_id(&conn, 1)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            CompanionLinkRepository::get_by_parent_id(&conn, 2)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            CompanionLinkRepository::get_by_parent_id(&conn, 4)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use crate::error::Result;
 use crate::models::Edge;
 use super::base_repository::{query_one_by_id, query_all_ordered};
-use rusqlite::{Connection, Row};
+use rusqlite::{params, Connection, Row};
 
 pub struct EdgeRepository;
 
@@ -20,6 +20,24 @@ impl EdgeRepository {
         query_all_ordered(conn, Self::TABLE, Self::COLUMNS, "name", Self::row_to_model)
     }
 
+    /// Search edges by a case-insensitive match on name or description,
+    /// optionally restricted to a single category
+    pub fn search(conn: &Connection, query: &str, category: Option<&str>) -> Result<Vec<Edge>> {
+        let sql = format!(
+            "SELECT {} FROM {}
+             WHERE (name LIKE ?1 OR description LIKE ?1)
+               AND (?2 IS NULL OR background = ?2 COLLATE NOCASE)
+             ORDER BY name",
+            Self::COLUMNS,
+            Self::TABLE
+        );
+        let pattern = format!("%{}%", query.trim());
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params![pattern, category], Self::row_to_model)?;
+
+        Ok(rows.filter_map(|r| r.ok()).collect())
+    }
+
     /// Convert a database row to an Edge model
     fn row_to_model(row: &Row) -> rusqlite::Result<Edge> {
         Ok(Edge {
@@ -39,7 +57,6 @@ impl EdgeRepository {
 mod tests {
     use super::*;
     use crate::test_utils::setup_test_db;
-    use rusqlite::params;
 
     // Local helper with custom signature for edge-specific tests
     fn insert_test_edge(
@@ -178,4 +195,71 @@ mod tests {
         assert_eq!(edge.created_at, "2024-12-25 10:30:00");
         assert_eq!(edge.updated_at, "2024-12-26 15:45:00");
     }
+
+    #[test]
+    fn test_search_by_partial_name_case_insensitive() {
+        let conn = setup_test_db();
+        insert_test_edge(&conn, 1, "Alertness", "Background", false);
+        insert_test_edge(&conn, 2, "Block", "Combat", false);
+        insert_test_edge(&conn, 3, "Improved Block", "Combat", false);
+
+        let edges = EdgeRepository::search(&conn, "bLoCk", None).unwrap();
+
+        assert_eq!(edges.len(), 2);
+        assert_eq!(edges[0].name, "Block");
+        assert_eq!(edges[1].name, "Improved Block");
+    }
+
+    #[test]
+    fn test_search_matches_description() {
+        let conn = setup_test_db();
+        insert_test_edge(&conn, 1, "Alertness", "Background", false);
+        conn.execute(
+            "UPDATE edges SET description = 'Adds +2 to Notice rolls' WHERE id = 1",
+            [],
+        )
+        .unwrap();
+        insert_test_edge(&conn, 2, "Block", "Combat", false);
+
+        let edges = EdgeRepository::search(&conn, "notice", None).unwrap();
+
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].name, "Alertness");
+    }
+
+    #[test]
+    fn test_search_by_partial_name_and_category() {
+        let conn = setup_test_db();
+        insert_test_edge(&conn, 1, "Brawny", "Background", false);
+        insert_test_edge(&conn, 2, "Brawler", "Combat", false);
+        insert_test_edge(&conn, 3, "Bruiser", "Combat", false);
+        insert_test_edge(&conn, 4, "Block", "Combat", false);
+
+        let edges = EdgeRepository::search(&conn, "braw", Some("combat")).unwrap();
+
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].name, "Brawler");
+    }
+
+    #[test]
+    fn test_search_empty_query_with_category() {
+        let conn = setup_test_db();
+        insert_test_edge(&conn, 1, "Brawny", "Background", false);
+        insert_test_edge(&conn, 2, "Block", "Combat", false);
+
+        let edges = EdgeRepository::search(&conn, "", Some("Combat")).unwrap();
+
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].name, "Block");
+    }
+
+    #[test]
+    fn test_search_no_matches() {
+        let conn = setup_test_db();
+        insert_test_edge(&conn, 1, "Alertness", "Background", false);
+
+        let edges = EdgeRepository::search(&conn, "wizard", None).unwrap();
+
+        assert!(edges.is_empty());
+    }
 }
@@ -8,7 +8,8 @@ pub struct HindranceRepository;
 impl HindranceRepository {
     const TABLE: &'static str = "hindrances";
     const COLUMNS: &'static str = "id, name, severity, point_value, companion_hindrance_id,
-                                   source, description, created_at, updated_at";
+                                   can_take_multiple_times, source, description,
+                                   created_at, updated_at";
 
     pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<Hindrance>> {
         query_one_by_id(conn, Self::TABLE, Self::COLUMNS, id, Self::row_to_model)
@@ -25,10 +26,11 @@ impl HindranceRepository {
             severity: row.get(2)?,
             point_value: row.get(3)?,
             companion_hindrance_id: row.get(4)?,
-            source: row.get(5)?,
-            description: row.get(6)?,
-            created_at: row.get(7)?,
-            updated_at: row.get(8)?,
+            can_take_multiple_times: row.get(5)?,
+            source: row.get(6)?,
+            description: row.get(7)?,
+            created_at: row.get(8)?,
+            updated_at: row.get(9)?,
         })
     }
 }
@@ -179,6 +181,7 @@ mod tests {
         assert_eq!(hindrance.severity, "major");
         assert_eq!(hindrance.point_value, 2);
         assert_eq!(hindrance.companion_hindrance_id, None);
+        assert!(!hindrance.can_take_multiple_times);
         assert_eq!(hindrance.source, "test_source");
         assert_eq!(hindrance.description, "Detailed test description");
         assert_eq!(hindrance.created_at, "2024-06-15 10:30:00");
@@ -7,6 +7,7 @@ mod ancestry_requirement_repo;
 mod arcane_background_choice_repo;
 mod arcane_background_repo;
 mod arcane_background_requirement_repo;
+mod archetype_repo;
 mod armor_stats_repo;
 mod attribute_repo;
 mod character_advance_repo;
@@ -22,6 +23,8 @@ mod character_note_repo;
 mod character_power_repo;
 mod character_repo;
 mod character_skill_repo;
+mod character_snapshot_repo;
+mod companion_link_repo;
 mod edge_modifier_repo;
 mod edge_repo;
 mod edge_requirement_repo;
@@ -53,6 +56,7 @@ pub use ancestry_requirement_repo::*;
 pub use arcane_background_choice_repo::*;
 pub use arcane_background_repo::*;
 pub use arcane_background_requirement_repo::*;
+pub use archetype_repo::*;
 pub use armor_stats_repo::*;
 pub use attribute_repo::*;
 pub use character_advance_repo::*;
@@ -68,6 +72,8 @@ pub use character_note_repo::*;
 pub use character_power_repo::*;
 pub use character_repo::*;
 pub use character_skill_repo::*;
+pub use character_snapshot_repo::*;
+pub use companion_link_repo::*;
 pub use edge_modifier_repo::*;
 pub use edge_repo::*;
 pub use edge_requirement_repo::*;
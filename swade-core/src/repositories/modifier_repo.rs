@@ -8,7 +8,7 @@ pub struct ModifierRepository;
 impl ModifierRepository {
     const TABLE: &'static str = "modifiers";
     const COLUMNS: &'static str = "id, target_type, target_identifier, value_type, value,
-                                   description, created_at, updated_at";
+                                   description, condition, created_at, updated_at";
 
     pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<Modifier>> {
         query_one_by_id(conn, Self::TABLE, Self::COLUMNS, id, Self::row_to_model)
@@ -26,8 +26,9 @@ impl ModifierRepository {
             value_type: row.get(3)?,
             value: row.get(4)?,
             description: row.get(5)?,
-            created_at: row.get(6)?,
-            updated_at: row.get(7)?,
+            condition: row.get(6)?,
+            created_at: row.get(7)?,
+            updated_at: row.get(8)?,
         })
     }
 }
@@ -75,6 +76,25 @@ mod tests {
         assert_eq!(modifier.value_type, "die_increment");
         assert_eq!(modifier.value, Some(1));
         assert_eq!(modifier.description, "Vigor starts at d6");
+        assert!(!modifier.is_conditional());
+    }
+
+    #[test]
+    fn test_get_by_id_reads_condition() {
+        let conn = setup_test_db();
+        conn.execute(
+            "INSERT INTO modifiers (id, target_type, target_identifier, value_type,
+                                   value, description, condition, created_at, updated_at)
+             VALUES (3, 'skill', 'Fighting', 'roll_bonus', 1,
+                     '+1 Fighting when unarmed', 'unarmed', '2024-01-01', '2024-01-01')",
+            [],
+        )
+        .unwrap();
+
+        let modifier = ModifierRepository::get_by_id(&conn, 3).unwrap().unwrap();
+
+        assert_eq!(modifier.condition, Some("unarmed".to_string()));
+        assert!(modifier.is_conditional());
     }
 
     #[test]
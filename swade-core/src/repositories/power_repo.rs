@@ -7,8 +7,8 @@ pub struct PowerRepository;
 
 impl PowerRepository {
     const TABLE: &'static str = "powers";
-    const COLUMNS: &'static str = "id, name, power_points, range, duration, source, description,
-                                   created_at, updated_at";
+    const COLUMNS: &'static str = "id, name, power_points, maintenance_power_points, range,
+                                   duration, source, description, created_at, updated_at";
 
     pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<Power>> {
         query_one_by_id(conn, Self::TABLE, Self::COLUMNS, id, Self::row_to_model)
@@ -23,12 +23,13 @@ impl PowerRepository {
             id: row.get(0)?,
             name: row.get(1)?,
             power_points: row.get(2)?,
-            range: row.get(3)?,
-            duration: row.get(4)?,
-            source: row.get(5)?,
-            description: row.get(6)?,
-            created_at: row.get(7)?,
-            updated_at: row.get(8)?,
+            maintenance_power_points: row.get(3)?,
+            range: row.get(4)?,
+            duration: row.get(5)?,
+            source: row.get(6)?,
+            description: row.get(7)?,
+            created_at: row.get(8)?,
+            updated_at: row.get(9)?,
         })
     }
 }
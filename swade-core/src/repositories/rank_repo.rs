@@ -1,6 +1,6 @@
 use crate::error::Result;
 use crate::models::Rank;
-use super::base_repository::{query_one_by_id, query_all_ordered};
+use super::base_repository::{query_one_by_field, query_one_by_id, query_all_ordered};
 use rusqlite::{Connection, Row};
 
 pub struct RankRepository;
@@ -14,6 +14,11 @@ impl RankRepository {
         query_one_by_id(conn, Self::TABLE, Self::COLUMNS, id, Self::row_to_model)
     }
 
+    /// Get a rank by name (case-insensitive)
+    pub fn get_by_name(conn: &Connection, name: &str) -> Result<Option<Rank>> {
+        query_one_by_field(conn, Self::TABLE, Self::COLUMNS, "name", name, Self::row_to_model)
+    }
+
     pub fn get_all(conn: &Connection) -> Result<Vec<Rank>> {
         query_all_ordered(conn, Self::TABLE, Self::COLUMNS, "min_advances", Self::row_to_model)
     }
@@ -114,6 +119,17 @@ mod tests {
         assert_eq!(ranks[2].name, "Legendary");
     }
 
+    #[test]
+    fn test_get_by_name_ignores_case() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 2, "Seasoned", 4, Some(7));
+
+        let rank = RankRepository::get_by_name(&conn, "seasoned").unwrap().unwrap();
+
+        assert_eq!(rank.id, 2);
+        assert!(RankRepository::get_by_name(&conn, "Heroic").unwrap().is_none());
+    }
+
     #[test]
     fn test_get_all_empty() {
         let conn = setup_test_db();
@@ -7,7 +7,7 @@ pub struct RequirementRepository;
 
 impl RequirementRepository {
     const TABLE: &'static str = "requirements";
-    const COLUMNS: &'static str = "id, requirement_type, target_id, value, description,
+    const COLUMNS: &'static str = "id, requirement_type, target_id, target_name, value, description,
                                    created_at, updated_at";
 
     pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<Requirement>> {
@@ -23,10 +23,11 @@ impl RequirementRepository {
             id: row.get(0)?,
             requirement_type: row.get(1)?,
             target_id: row.get(2)?,
-            value: row.get(3)?,
-            description: row.get(4)?,
-            created_at: row.get(5)?,
-            updated_at: row.get(6)?,
+            target_name: row.get(3)?,
+            value: row.get(4)?,
+            description: row.get(5)?,
+            created_at: row.get(6)?,
+            updated_at: row.get(7)?,
         })
     }
 }
@@ -8,17 +8,24 @@
 
 use rusqlite::Connection;
 
+use crate::constants::XP_PER_ADVANCE;
 use crate::error::{Result, SwadeError};
-use crate::models::CharacterAdvance;
+use crate::models::{CharacterAdvance, CharacterArcaneBackground, CharacterPower, CharacterSkill};
 use crate::repositories::{
-    AttributeRepository, CharacterAdvanceRepository, CharacterAttributeRepository,
-    CharacterEdgeRepository, CharacterHindranceRepository, CharacterSkillRepository,
-    HindranceRepository, RankRepository, SkillRepository,
+    ArcaneBackgroundRepository, AttributeRepository, CharacterAdvanceRepository,
+    CharacterArcaneBackgroundRepository, CharacterAttributeRepository, CharacterRepository,
+    CharacterEdgeRepository, CharacterHindranceRepository, CharacterPowerRepository,
+    CharacterSkillRepository, HindranceRepository, RankRepository, SkillRepository,
 };
-use crate::services::{CharacterService, EdgeService};
+use crate::services::{ArcaneBackgroundService, CharacterService, EdgeService};
+
+use super::character_load;
 use crate::views::{
-    AdvanceType, AdvancementOptions, AttributeAdvanceOption, CharacterAdvanceValue,
-    HindranceAction, HindranceAdvanceOption, SkillAdvanceOption,
+    AdvancePlanStep, AdvanceType, AdvancementOptions, AdvancementTimelineEntry, AppliedAdvance,
+    ArcaneBackgroundChoiceType,
+    ArcaneBackgroundOptionType, ArcaneBackgroundView, AttributeAdvanceOption, CharacterAdvanceValue,
+    CharacterView, Die, EdgeView, HindranceAction, HindranceAdvanceOption, PlannedAdvance,
+    SkillAdvanceOption,
 };
 
 pub struct AdvancementService;
@@ -26,16 +33,21 @@ pub struct AdvancementService;
 impl AdvancementService {
     /// Get the available advancement options for a character's next advance.
     pub fn get_advancement_options(conn: &Connection, character_id: i64) -> Result<AdvancementOptions> {
-        let current_advances = CharacterAdvanceRepository::count_by_character_id(conn, character_id)?;
-        let next_advance_number = current_advances + 1;
-
-        let current_rank = Self::get_rank_for_advances(conn, current_advances)?;
-        let next_rank = Self::get_rank_for_advances(conn, next_advance_number)?;
-
         // Load character for attribute/skill/hindrance data
         let character = CharacterService::get_by_id(conn, character_id)?
             .ok_or_else(|| SwadeError::NotFound(format!("Character with id {}", character_id)))?;
 
+        let current_advances = CharacterAdvanceRepository::count_by_character_id(conn, character_id)?;
+        let next_advance_number = current_advances + 1;
+
+        // With XP, taking an advance spends what's earned without changing rank.
+        let (rank_advances, current_rank) = Self::advances_and_rank(conn, character_id)?;
+        let (next_rank, unspent_advances) = if character.config.track_experience {
+            (current_rank.clone(), Some(rank_advances - current_advances))
+        } else {
+            (Self::get_rank_for_advances(conn, next_advance_number)?, None)
+        };
+
         // Check if attribute advance is available
         let (can_increase_attribute, attribute_blocked_reason) =
             Self::check_attribute_advance_available(conn, character_id, current_advances, &current_rank)?;
@@ -45,18 +57,22 @@ impl AdvancementService {
             .attributes
             .iter()
             .map(|attr| {
-                let current_die = attr.die.size();
-                let effective_die = attr.effective_die.size();
-                let is_maxed = current_die >= 12;
-                let next_die = if is_maxed { current_die } else { current_die + 2 };
-                // Effective next = apply same modifier bonus to next_die
-                let modifier_bonus = effective_die.saturating_sub(current_die);
-                let effective_next_die = if is_maxed { effective_die } else { next_die + modifier_bonus };
+                let attribute_cap = character
+                    .attribute_die_cap(attr.attribute.id)
+                    .unwrap_or_else(|| character.config.attribute_die_cap());
+                let is_maxed = attr.die >= attribute_cap;
+                let next_die = if is_maxed { attr.die } else { attr.die.increment() };
+                // Effective next = shift next_die by the same steps modifiers add today
+                let effective_next_die = if is_maxed {
+                    attr.effective_die
+                } else {
+                    Self::shift_die(next_die, attr.die.steps_to(attr.effective_die))
+                };
                 AttributeAdvanceOption {
                     id: attr.attribute.id,
                     name: attr.attribute.name.clone(),
-                    current_die,
-                    effective_die,
+                    current_die: attr.die,
+                    effective_die: attr.effective_die,
                     next_die,
                     effective_next_die,
                     is_maxed,
@@ -69,40 +85,45 @@ impl AdvancementService {
         let mut cheap_skill_options = Vec::new();
 
         for skill_value in &character.skills {
-            let skill_die = skill_value.die.map(|d| d.size()).unwrap_or(0);
-            let effective_skill_die = skill_value.effective_die.map(|d| d.size()).unwrap_or(0);
             let linked_attr = character
                 .attributes
                 .iter()
                 .find(|a| a.attribute.id == skill_value.skill.linked_attribute_id);
-            let attr_die = linked_attr.map(|a| a.die.size()).unwrap_or(4);
-
-            let is_maxed = skill_die >= 12;
-            let next_die = if skill_die == 0 { 4 } else { skill_die + 2 };
-            let next_die = if is_maxed { skill_die } else { next_die };
-            // Effective next = apply same modifier bonus to next_die
-            let modifier_bonus = effective_skill_die.saturating_sub(skill_die);
-            let effective_next_die = if is_maxed { effective_skill_die } else { next_die + modifier_bonus };
+            let attr_die = linked_attr.map(|a| a.die).unwrap_or(Die::d4());
+
+            let skill_cap = character
+                .skill_die_cap(skill_value.skill.id)
+                .unwrap_or(skill_value.skill.max_die);
+            let is_maxed = skill_value.die.is_some_and(|d| d >= skill_cap);
+            let next_die = match skill_value.die {
+                Some(die) if is_maxed => die,
+                Some(die) => die.increment(),
+                None => Die::d4(),
+            };
+            // Effective next = shift next_die by the same steps modifiers add today
+            let effective_next_die = match (skill_value.die, skill_value.effective_die) {
+                (Some(_), Some(effective)) if is_maxed => effective,
+                (Some(die), Some(effective)) => Self::shift_die(next_die, die.steps_to(effective)),
+                _ => next_die,
+            };
 
             let option = SkillAdvanceOption {
                 id: skill_value.skill.id,
                 name: skill_value.skill.name.clone(),
-                current_die: skill_die,
-                effective_die: effective_skill_die,
+                current_die: skill_value.die,
+                effective_die: skill_value.effective_die,
                 next_die,
                 effective_next_die,
                 is_maxed,
             };
 
-            if skill_die == 0 {
+            match skill_value.die {
                 // Untrained skills are cheap (going to d4)
-                cheap_skill_options.push(option);
-            } else if skill_die >= attr_die {
+                None => cheap_skill_options.push(option),
                 // At or above linked attribute = expensive
-                expensive_skill_options.push(option);
-            } else {
+                Some(die) if die >= attr_die => expensive_skill_options.push(option),
                 // Below linked attribute = cheap
-                cheap_skill_options.push(option);
+                Some(_) => cheap_skill_options.push(option),
             }
         }
 
@@ -142,6 +163,12 @@ impl AdvancementService {
 
         let can_modify_hindrance = !hindrance_options.is_empty();
 
+        // Position within the current rank, from its advance boundaries
+        let advances_into_rank = rank_advances - current_rank.min_advances;
+        let advances_remaining_in_rank = current_rank
+            .max_advances
+            .map(|max| max + 1 - rank_advances);
+
         Ok(AdvancementOptions {
             can_take_edge: true,
             can_increase_attribute,
@@ -154,20 +181,40 @@ impl AdvancementService {
             can_modify_hindrance,
             hindrance_options,
             next_advance_number,
+            unspent_advances,
             current_rank: current_rank.name,
             rank_after_advance: next_rank.name,
+            advances_into_rank,
+            advances_remaining_in_rank,
         })
     }
 
     /// Apply an edge advancement.
+    ///
+    /// `arcane_background_id` picks the arcane background an edge like Arcane
+    /// Background grants, when it grants more than one.
     pub fn apply_edge_advance(
         conn: &Connection,
         character_id: i64,
         edge_id: i64,
+        arcane_background_id: Option<i64>,
         notes: Option<String>,
     ) -> Result<CharacterAdvanceValue> {
-        let current_advances = CharacterAdvanceRepository::count_by_character_id(conn, character_id)?;
+        Self::in_transaction(conn, |conn| {
+            Self::apply_edge_advance_inner(conn, character_id, edge_id, arcane_background_id, notes)
+        })
+    }
+
+    fn apply_edge_advance_inner(
+        conn: &Connection,
+        character_id: i64,
+        edge_id: i64,
+        arcane_background_id: Option<i64>,
+        notes: Option<String>,
+    ) -> Result<CharacterAdvanceValue> {
+        let current_advances = Self::advances_taken_before_new(conn, character_id)?;
         let advance_number = current_advances + 1;
+        let (_, current_rank) = Self::advances_and_rank(conn, character_id)?;
 
         // Load the character for requirement checking
         let character = CharacterService::get_by_id(conn, character_id)?
@@ -177,6 +224,11 @@ impl AdvancementService {
         let edge = EdgeService::get_by_id(conn, edge_id)?
             .ok_or_else(|| SwadeError::NotFound(format!("Edge with id {}", edge_id)))?;
 
+        // Only repeatable edges can be taken again, each copy with its own notes
+        if let Some(reason) = character.edge_conflict(&edge, notes.as_deref()) {
+            return Err(SwadeError::Validation(reason));
+        }
+
         // Validate edge requirements
         if !edge.requirements.is_empty() {
             let req_context = character.to_requirement_context();
@@ -197,6 +249,10 @@ impl AdvancementService {
             }
         }
 
+        // Edges like Arcane Background also grant an arcane background
+        let arcane_background =
+            Self::granted_arcane_background(conn, &character, &edge, arcane_background_id)?;
+
         // Create the advance record
         let advance = CharacterAdvance {
             id: 0,
@@ -204,11 +260,13 @@ impl AdvancementService {
             advance_number,
             advance_type: "edge".to_string(),
             edge_id: Some(edge_id),
+            arcane_background_id: arcane_background.as_ref().map(|ab| ab.id),
             attribute_id: None,
             skill_id_1: None,
             skill_id_2: None,
             hindrance_id: None,
             hindrance_action: None,
+            hindrance_source: None,
             notes: notes.clone(),
             created_at: String::new(),
             updated_at: String::new(),
@@ -230,12 +288,18 @@ impl AdvancementService {
         };
         CharacterEdgeRepository::insert(conn, &char_edge)?;
 
+        if let Some(arcane_background) = &arcane_background {
+            Self::grant_arcane_background(conn, &character, arcane_background, advance_number)?;
+        }
+
         Ok(CharacterAdvanceValue {
             id: advance_id,
             advance_number,
             advance_type: AdvanceType::Edge,
             description: format!("Gained edge: {}", edge.name),
+            notes: advance.notes,
             created_at: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            rank_changed: Self::rank_change(conn, character_id, &current_rank)?,
         })
     }
 
@@ -244,10 +308,22 @@ impl AdvancementService {
         conn: &Connection,
         character_id: i64,
         attribute_id: i64,
+        notes: Option<String>,
     ) -> Result<CharacterAdvanceValue> {
-        let current_advances = CharacterAdvanceRepository::count_by_character_id(conn, character_id)?;
+        Self::in_transaction(conn, |conn| {
+            Self::apply_attribute_advance_inner(conn, character_id, attribute_id, notes)
+        })
+    }
+
+    fn apply_attribute_advance_inner(
+        conn: &Connection,
+        character_id: i64,
+        attribute_id: i64,
+        notes: Option<String>,
+    ) -> Result<CharacterAdvanceValue> {
+        let current_advances = Self::advances_taken_before_new(conn, character_id)?;
         let advance_number = current_advances + 1;
-        let current_rank = Self::get_rank_for_advances(conn, current_advances)?;
+        let (_, current_rank) = Self::advances_and_rank(conn, character_id)?;
 
         // Check if attribute advance is allowed
         let (can_advance, blocked_reason) =
@@ -270,13 +346,23 @@ impl AdvancementService {
             .find(|a| a.attribute_id == attribute_id)
             .ok_or_else(|| SwadeError::NotFound(format!("Character attribute {}", attribute_id)))?;
 
-        // Check if attribute is at max (d12)
+        // Check if attribute is at the character's cap (d12 unless a house rule or edge raises it)
+        let character = CharacterService::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Character with id {}", character_id)))?;
+        let attribute_cap = character
+            .attribute_die_cap(attribute_id)
+            .unwrap_or_else(|| character.config.attribute_die_cap());
+        let base_die = u8::try_from(attribute.base_value)
+            .ok()
+            .and_then(Die::new)
+            .unwrap_or(Die::d4());
+        let max_steps = attribute_cap.steps_from(base_die) as i64;
+
         let current_steps = char_attr.steps_incremented;
-        if current_steps >= 4 {
-            // d4 + 4 steps = d12
+        if current_steps >= max_steps {
             return Err(SwadeError::Validation(format!(
-                "{} is already at maximum (d12)",
-                attribute.name
+                "{} is already at maximum ({})",
+                attribute.name, attribute_cap
             )));
         }
 
@@ -287,12 +373,14 @@ impl AdvancementService {
             advance_number,
             advance_type: "attribute".to_string(),
             edge_id: None,
+            arcane_background_id: None,
             attribute_id: Some(attribute_id),
             skill_id_1: None,
             skill_id_2: None,
             hindrance_id: None,
             hindrance_action: None,
-            notes: None,
+            hindrance_source: None,
+            notes,
             created_at: String::new(),
             updated_at: String::new(),
         };
@@ -310,7 +398,9 @@ impl AdvancementService {
             advance_number,
             advance_type: AdvanceType::Attribute,
             description: format!("Increased {}", attribute.name),
+            notes: advance.notes,
             created_at: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            rank_changed: Self::rank_change(conn, character_id, &current_rank)?,
         })
     }
 
@@ -319,9 +409,22 @@ impl AdvancementService {
         conn: &Connection,
         character_id: i64,
         skill_id: i64,
+        notes: Option<String>,
     ) -> Result<CharacterAdvanceValue> {
-        let current_advances = CharacterAdvanceRepository::count_by_character_id(conn, character_id)?;
+        Self::in_transaction(conn, |conn| {
+            Self::apply_expensive_skill_advance_inner(conn, character_id, skill_id, notes)
+        })
+    }
+
+    fn apply_expensive_skill_advance_inner(
+        conn: &Connection,
+        character_id: i64,
+        skill_id: i64,
+        notes: Option<String>,
+    ) -> Result<CharacterAdvanceValue> {
+        let current_advances = Self::advances_taken_before_new(conn, character_id)?;
         let advance_number = current_advances + 1;
+        let (_, current_rank) = Self::advances_and_rank(conn, character_id)?;
 
         // Verify the skill exists
         let skill = SkillRepository::get_by_id(conn, skill_id)?
@@ -342,23 +445,29 @@ impl AdvancementService {
                 SwadeError::NotFound(format!("Linked attribute {}", skill.linked_attribute_id))
             })?;
 
-        // Calculate die sizes
-        let skill_die_size = char_skill.current_die_size.unwrap_or(0);
-        let attr_die_size = 4 + (linked_attr.steps_incremented * 2); // d4=4, d6=6, etc.
+        // Compare dice, keeping any modifier on the skill
+        let skill_die = Self::skill_die(char_skill);
+        let attr_die = Self::shift_die(Die::d4(), linked_attr.steps_incremented as i32);
 
         // Verify skill is at or above linked attribute (expensive)
-        if skill_die_size < attr_die_size {
-            return Err(SwadeError::Validation(format!(
-                "{} (d{}) is below linked attribute (d{}). Use cheap skill advance instead.",
-                skill.name, skill_die_size, attr_die_size
-            )));
-        }
+        let skill_die = match skill_die {
+            Some(die) if die >= attr_die => die,
+            _ => {
+                return Err(SwadeError::Validation(format!(
+                    "{} ({}) is below linked attribute ({}). Use cheap skill advance instead.",
+                    skill.name,
+                    skill_die.unwrap_or_else(Die::untrained),
+                    attr_die
+                )));
+            }
+        };
 
-        // Check if skill is at max
-        if skill_die_size >= 12 {
+        // Check if skill is at its cap (its max die plus any edge that raises it)
+        let skill_cap = Self::skill_die_cap(conn, character_id, skill_id)?;
+        if skill_die >= skill_cap {
             return Err(SwadeError::Validation(format!(
-                "{} is already at maximum (d12)",
-                skill.name
+                "{} is already at maximum ({})",
+                skill.name, skill_cap
             )));
         }
 
@@ -369,12 +478,14 @@ impl AdvancementService {
             advance_number,
             advance_type: "skill_expensive".to_string(),
             edge_id: None,
+            arcane_background_id: None,
             attribute_id: None,
             skill_id_1: Some(skill_id),
             skill_id_2: None,
             hindrance_id: None,
             hindrance_action: None,
-            notes: None,
+            hindrance_source: None,
+            notes,
             created_at: String::new(),
             updated_at: String::new(),
         };
@@ -382,18 +493,16 @@ impl AdvancementService {
         let advance_id = CharacterAdvanceRepository::insert(conn, &advance)?;
 
         // Update the character_skills record
-        let mut updated_skill = char_skill.clone();
-        let new_die_size = skill_die_size + 2; // Increment one die step
-        updated_skill.current_die_size = Some(new_die_size);
-        updated_skill.updated_at = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
-        CharacterSkillRepository::update(conn, &updated_skill)?;
+        let new_die = Self::apply_skill_increase(conn, character_id, skill_id)?;
 
         Ok(CharacterAdvanceValue {
             id: advance_id,
             advance_number,
             advance_type: AdvanceType::SkillExpensive,
-            description: format!("Increased {} to d{}", skill.name, new_die_size),
+            description: format!("Increased {} to {}", skill.name, new_die),
+            notes: advance.notes,
             created_at: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            rank_changed: Self::rank_change(conn, character_id, &current_rank)?,
         })
     }
 
@@ -403,9 +512,23 @@ impl AdvancementService {
         character_id: i64,
         skill_id_1: i64,
         skill_id_2: i64,
+        notes: Option<String>,
     ) -> Result<CharacterAdvanceValue> {
-        let current_advances = CharacterAdvanceRepository::count_by_character_id(conn, character_id)?;
+        Self::in_transaction(conn, |conn| {
+            Self::apply_cheap_skill_advance_inner(conn, character_id, skill_id_1, skill_id_2, notes)
+        })
+    }
+
+    fn apply_cheap_skill_advance_inner(
+        conn: &Connection,
+        character_id: i64,
+        skill_id_1: i64,
+        skill_id_2: i64,
+        notes: Option<String>,
+    ) -> Result<CharacterAdvanceValue> {
+        let current_advances = Self::advances_taken_before_new(conn, character_id)?;
         let advance_number = current_advances + 1;
+        let (_, current_rank) = Self::advances_and_rank(conn, character_id)?;
 
         // Validate both skills
         let (skill1, new_die_1) = Self::validate_cheap_skill(conn, character_id, skill_id_1)?;
@@ -418,12 +541,14 @@ impl AdvancementService {
             advance_number,
             advance_type: "skill_cheap".to_string(),
             edge_id: None,
+            arcane_background_id: None,
             attribute_id: None,
             skill_id_1: Some(skill_id_1),
             skill_id_2: Some(skill_id_2),
             hindrance_id: None,
             hindrance_action: None,
-            notes: None,
+            hindrance_source: None,
+            notes,
             created_at: String::new(),
             updated_at: String::new(),
         };
@@ -439,10 +564,12 @@ impl AdvancementService {
             advance_number,
             advance_type: AdvanceType::SkillCheap,
             description: format!(
-                "Increased {} to d{} and {} to d{}",
+                "Increased {} to {} and {} to {}",
                 skill1.name, new_die_1, skill2.name, new_die_2
             ),
+            notes: advance.notes,
             created_at: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            rank_changed: Self::rank_change(conn, character_id, &current_rank)?,
         })
     }
 
@@ -452,9 +579,23 @@ impl AdvancementService {
         character_id: i64,
         hindrance_id: i64,
         action: &str,
+        notes: Option<String>,
     ) -> Result<CharacterAdvanceValue> {
-        let current_advances = CharacterAdvanceRepository::count_by_character_id(conn, character_id)?;
+        Self::in_transaction(conn, |conn| {
+            Self::apply_hindrance_advance_inner(conn, character_id, hindrance_id, action, notes)
+        })
+    }
+
+    fn apply_hindrance_advance_inner(
+        conn: &Connection,
+        character_id: i64,
+        hindrance_id: i64,
+        action: &str,
+        notes: Option<String>,
+    ) -> Result<CharacterAdvanceValue> {
+        let current_advances = Self::advances_taken_before_new(conn, character_id)?;
         let advance_number = current_advances + 1;
+        let (_, current_rank) = Self::advances_and_rank(conn, character_id)?;
 
         // Verify the hindrance exists on the character
         let char_hindrances = CharacterHindranceRepository::get_by_character_id(conn, character_id)?;
@@ -468,6 +609,9 @@ impl AdvancementService {
         let hindrance = HindranceRepository::get_by_id(conn, hindrance_id)?
             .ok_or_else(|| SwadeError::NotFound(format!("Hindrance with id {}", hindrance_id)))?;
 
+        // Major removals are recorded by which half they are, so undo never has to guess
+        let mut stored_action = action;
+
         let description = match action {
             "remove_minor" => {
                 // Verify it's a minor hindrance
@@ -540,6 +684,7 @@ impl AdvancementService {
                         SwadeError::Validation(format!("Character doesn't have {}", hindrance.name))
                     })?;
                     CharacterHindranceRepository::delete(conn, char_h.id)?;
+                    stored_action = "complete_major_removal";
                     format!("Removed major hindrance: {} (2nd advance)", hindrance.name)
                 } else {
                     // This is the first half - just bank it
@@ -547,6 +692,7 @@ impl AdvancementService {
                     char_hindrance.ok_or_else(|| {
                         SwadeError::Validation(format!("Character doesn't have {}", hindrance.name))
                     })?;
+                    stored_action = "remove_major_half";
                     format!(
                         "Banked advance toward removing: {} (1 of 2)",
                         hindrance.name
@@ -561,19 +707,21 @@ impl AdvancementService {
             }
         };
 
-        // Create the advance record (store original source in notes for undo)
+        // Create the advance record (store original source for undo)
         let advance = CharacterAdvance {
             id: 0,
             character_id,
             advance_number,
             advance_type: "hindrance".to_string(),
             edge_id: None,
+            arcane_background_id: None,
             attribute_id: None,
             skill_id_1: None,
             skill_id_2: None,
             hindrance_id: Some(hindrance_id),
-            hindrance_action: Some(action.to_string()),
-            notes: original_source,
+            hindrance_action: Some(stored_action.to_string()),
+            hindrance_source: original_source,
+            notes,
             created_at: String::new(),
             updated_at: String::new(),
         };
@@ -585,12 +733,225 @@ impl AdvancementService {
             advance_number,
             advance_type: AdvanceType::Hindrance,
             description,
+            notes: advance.notes,
             created_at: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            rank_changed: Self::rank_change(conn, character_id, &current_rank)?,
+        })
+    }
+
+    /// Apply one planned advance using the matching `apply_*` method.
+    pub fn apply_planned_advance(
+        conn: &Connection,
+        character_id: i64,
+        advance: &PlannedAdvance,
+    ) -> Result<CharacterAdvanceValue> {
+        Self::apply_planned_advance_with_notes(conn, character_id, advance, None)
+    }
+
+    /// Apply one planned advance and return it with the reloaded character.
+    pub fn take_advance(
+        conn: &Connection,
+        character_id: i64,
+        advance: &PlannedAdvance,
+        notes: Option<String>,
+    ) -> Result<AppliedAdvance> {
+        let advance = Self::apply_planned_advance_with_notes(conn, character_id, advance, notes)?;
+        let character = CharacterService::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Character with id {}", character_id)))?;
+        Ok(AppliedAdvance { advance, character })
+    }
+
+    fn apply_planned_advance_with_notes(
+        conn: &Connection,
+        character_id: i64,
+        advance: &PlannedAdvance,
+        notes: Option<String>,
+    ) -> Result<CharacterAdvanceValue> {
+        match advance {
+            PlannedAdvance::Edge { edge_id, arcane_background_id } => {
+                Self::apply_edge_advance(conn, character_id, *edge_id, *arcane_background_id, notes)
+            }
+            PlannedAdvance::Attribute { attribute_id } => {
+                Self::apply_attribute_advance(conn, character_id, *attribute_id, notes)
+            }
+            PlannedAdvance::SkillExpensive { skill_id } => {
+                Self::apply_expensive_skill_advance(conn, character_id, *skill_id, notes)
+            }
+            PlannedAdvance::SkillCheap { skill_id_1, skill_id_2 } => {
+                Self::apply_cheap_skill_advance(conn, character_id, *skill_id_1, *skill_id_2, notes)
+            }
+            PlannedAdvance::Hindrance { hindrance_id, action } => {
+                Self::apply_hindrance_advance(
+                    conn,
+                    character_id,
+                    *hindrance_id,
+                    action.as_str(),
+                    notes,
+                )
+            }
+        }
+    }
+
+    /// Advances earned from experience points, one per 5 XP.
+    pub fn advances_from_experience(experience_points: i64) -> i64 {
+        experience_points.max(0) / XP_PER_ADVANCE
+    }
+
+    /// Add experience points to a saved character.
+    pub fn award_xp(conn: &Connection, character_id: i64, amount: i64) -> Result<CharacterView> {
+        if amount <= 0 {
+            return Err(SwadeError::Validation("XP awarded must be positive".to_string()));
+        }
+        let character = CharacterRepository::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Character with id {}", character_id)))?;
+        CharacterRepository::set_experience_points(conn, character_id, character.experience_points + amount)?;
+
+        CharacterService::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Character with id {}", character_id)))
+    }
+
+    /// Take experience points back off a saved character, e.g. to correct an award.
+    ///
+    /// When the character tracks XP, it can't drop below what the advances
+    /// already taken cost.
+    pub fn spend_xp(conn: &Connection, character_id: i64, amount: i64) -> Result<CharacterView> {
+        if amount <= 0 {
+            return Err(SwadeError::Validation("XP spent must be positive".to_string()));
+        }
+        let character = CharacterRepository::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Character with id {}", character_id)))?;
+
+        let remaining = character.experience_points - amount;
+        let committed = if character.track_experience {
+            CharacterAdvanceRepository::count_by_character_id(conn, character_id)? * XP_PER_ADVANCE
+        } else {
+            0
+        };
+        if remaining < committed {
+            return Err(SwadeError::Validation(format!(
+                "Cannot spend {} XP: {} has {} XP and {} is committed to advances taken",
+                amount, character.name, character.experience_points, committed
+            )));
+        }
+        CharacterRepository::set_experience_points(conn, character_id, remaining)?;
+
+        CharacterService::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Character with id {}", character_id)))
+    }
+
+    /// Edges a saved character could take as their next advance.
+    ///
+    /// Applies the same checks as `apply_edge_advance`: requirements (rank
+    /// included) must be met against the persisted character, and edges
+    /// already held are left out unless they can be taken more than once.
+    pub fn get_takeable_edges(conn: &Connection, character_id: i64) -> Result<Vec<EdgeView>> {
+        let req_context = CharacterService::requirement_context(conn, character_id)?;
+
+        Ok(EdgeService::get_all(conn)?
+            .into_iter()
+            .filter(|edge| edge.can_take_multiple_times || !req_context.edge_ids.contains(&edge.id))
+            .filter(|edge| edge.requirements.evaluate(&req_context))
+            .collect())
+    }
+
+    /// How many more advances a character needs before they can take an edge.
+    ///
+    /// Returns `Some(0)` if the edge can be taken now, and the advances needed
+    /// to reach its minimum rank if rank is all that's missing. Returns None
+    /// if other requirements would still be unmet at that rank.
+    pub fn advances_until_available(
+        conn: &Connection,
+        character_id: i64,
+        edge_id: i64,
+    ) -> Result<Option<i64>> {
+        let mut req_context = CharacterService::requirement_context(conn, character_id)?;
+        let edge = EdgeService::get_by_id(conn, edge_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Edge with id {}", edge_id)))?;
+
+        if edge.requirements.evaluate(&req_context) {
+            return Ok(Some(0));
+        }
+
+        let Some(min_rank_id) = edge.requirements.min_rank() else {
+            return Ok(None);
+        };
+        let Some(min_rank) = RankRepository::get_by_id(conn, min_rank_id)? else {
+            return Ok(None);
+        };
+
+        // Only a rank shortfall can be fixed by taking more advances
+        req_context.rank_id = req_context.rank_id.max(min_rank.id);
+        if !edge.requirements.evaluate(&req_context) {
+            return Ok(None);
+        }
+
+        let (rank_advances, _) = Self::advances_and_rank(conn, character_id)?;
+        Ok(Some((min_rank.min_advances - rank_advances).max(0)))
+    }
+
+    /// Validate one advance and describe what it would do, without saving it.
+    ///
+    /// Runs the same `apply_*` logic as taking the advance for real and then
+    /// rolls it back. The returned value has an `id` of 0 since nothing was stored.
+    pub fn check_advance(
+        conn: &Connection,
+        character_id: i64,
+        advance: &PlannedAdvance,
+    ) -> Result<CharacterAdvanceValue> {
+        let mut value = Self::with_rollback(conn, |conn| {
+            Self::apply_planned_advance(conn, character_id, advance)
+        })?;
+        value.id = 0;
+        Ok(value)
+    }
+
+    /// Check a sequence of advances against the character without saving anything.
+    ///
+    /// Each step is applied to the state left by the steps before it, then
+    /// everything is rolled back. A step that fails is reported and skipped, so
+    /// later steps are checked as if it had not been planned.
+    pub fn preview_advancement_plan(
+        conn: &Connection,
+        character_id: i64,
+        plan: &[PlannedAdvance],
+    ) -> Result<Vec<AdvancePlanStep>> {
+        CharacterService::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Character with id {}", character_id)))?;
+
+        Self::with_rollback(conn, |conn| {
+            let mut steps = Vec::new();
+
+            for (i, advance) in plan.iter().enumerate() {
+                let (description, error) =
+                    match Self::apply_planned_advance(conn, character_id, advance) {
+                        Ok(value) => (Some(value.description), None),
+                        Err(SwadeError::Validation(msg)) => (None, Some(msg)),
+                        Err(SwadeError::NotFound(what)) => (None, Some(format!("{} not found", what))),
+                        Err(e) => return Err(e),
+                    };
+
+                let advances = CharacterAdvanceRepository::count_by_character_id(conn, character_id)?;
+                let (_, rank) = Self::advances_and_rank(conn, character_id)?;
+                steps.push(AdvancePlanStep {
+                    step: i as i64 + 1,
+                    success: error.is_none(),
+                    description,
+                    error,
+                    advances,
+                    rank: rank.name,
+                });
+            }
+
+            Ok(steps)
         })
     }
 
     /// Undo the most recent advance for a character.
     pub fn undo_advance(conn: &Connection, character_id: i64) -> Result<bool> {
+        Self::in_transaction(conn, |conn| Self::undo_advance_inner(conn, character_id))
+    }
+
+    fn undo_advance_inner(conn: &Connection, character_id: i64) -> Result<bool> {
         let advances = CharacterAdvanceRepository::get_by_character_id(conn, character_id)?;
 
         let latest = advances.last().ok_or_else(|| {
@@ -609,6 +970,14 @@ impl AdvancementService {
                     {
                         CharacterEdgeRepository::delete(conn, char_edge.id)?;
                     }
+                    if let Some(arcane_background_id) = latest.arcane_background_id {
+                        Self::revoke_arcane_background(
+                            conn,
+                            character_id,
+                            arcane_background_id,
+                            latest.advance_number,
+                        )?;
+                    }
                 }
             }
             "attribute" => {
@@ -638,8 +1007,10 @@ impl AdvancementService {
             }
             "hindrance" => {
                 if let (Some(hindrance_id), Some(action)) = (latest.hindrance_id, &latest.hindrance_action) {
-                    // The original source is stored in notes field
-                    let original_source = latest.notes.clone().unwrap_or_else(|| "chosen".to_string());
+                    let original_source = latest
+                        .hindrance_source
+                        .clone()
+                        .unwrap_or_else(|| "chosen".to_string());
 
                     match action.as_str() {
                         "remove_minor" => {
@@ -678,13 +1049,16 @@ impl AdvancementService {
                                 CharacterHindranceRepository::insert(conn, &char_hindrance)?;
                             }
                         }
-                        "remove_major_half" => {
-                            // Check if this was the completing advance (hindrance was deleted)
+                        "remove_major_half" | "complete_major_removal" => {
+                            // Older records stored both halves as remove_major_half; for those the
+                            // half is recovered from how many removal advances precede this one
+                            let completing = action == "complete_major_removal"
+                                || Self::major_removal_count(&advances, hindrance_id) % 2 == 0;
                             let char_hindrances = CharacterHindranceRepository::get_by_character_id(conn, character_id)?;
                             let has_hindrance = char_hindrances.iter().any(|h| h.hindrance_id == hindrance_id);
 
-                            if !has_hindrance {
-                                // Hindrance was deleted, re-add it with its original source
+                            if completing && !has_hindrance {
+                                // The completing half deleted the hindrance, re-add it with its original source
                                 let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
                                 let char_hindrance = crate::models::CharacterHindrance {
                                     id: 0,
@@ -696,7 +1070,7 @@ impl AdvancementService {
                                 };
                                 CharacterHindranceRepository::insert(conn, &char_hindrance)?;
                             }
-                            // If hindrance exists, this was just the first half - just delete the advance record
+                            // The first half only banked progress - just delete the advance record
                         }
                         _ => {}
                     }
@@ -720,21 +1094,261 @@ impl AdvancementService {
         let mut result = Vec::new();
 
         for advance in advances {
-            let description = Self::build_advance_description(conn, &advance)?;
-            result.push(CharacterAdvanceValue {
-                id: advance.id,
-                advance_number: advance.advance_number,
-                advance_type: advance.advance_type.parse().unwrap_or(AdvanceType::Edge),
-                description,
-                created_at: advance.created_at,
-            });
+            result.push(Self::to_history_value(conn, advance)?);
         }
 
         Ok(result)
     }
 
+    /// Get a character's advances in order, each labelled with its rank.
+    ///
+    /// An advance belongs to the rank the character reached by taking it, so
+    /// the advance that crosses a boundary opens the new rank (the fourth
+    /// advance is the first Seasoned one).
+    pub fn export_advancement_timeline(
+        conn: &Connection,
+        character_id: i64,
+    ) -> Result<Vec<AdvancementTimelineEntry>> {
+        Self::get_advancement_history(conn, character_id)?
+            .into_iter()
+            .map(|advance| {
+                Ok(AdvancementTimelineEntry {
+                    advance_number: advance.advance_number,
+                    rank: Self::get_rank_for_advances(conn, advance.advance_number)?.name,
+                    description: advance.description,
+                    created_at: advance.created_at,
+                })
+            })
+            .collect()
+    }
+
+    /// Replace the notes on an advance already taken, leaving the advance itself untouched.
+    ///
+    /// Blank notes clear the annotation. Returns the updated history entry.
+    pub fn update_advance_notes(
+        conn: &Connection,
+        character_id: i64,
+        advance_id: i64,
+        notes: Option<String>,
+    ) -> Result<CharacterAdvanceValue> {
+        let advance = CharacterAdvanceRepository::get_by_id(conn, advance_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Advance with id {}", advance_id)))?;
+
+        if advance.character_id != character_id {
+            return Err(SwadeError::Validation(format!(
+                "Advance {} does not belong to character {}",
+                advance_id, character_id
+            )));
+        }
+
+        let notes = notes
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty());
+        CharacterAdvanceRepository::update_notes(conn, advance_id, notes.as_deref())?;
+
+        let updated = CharacterAdvanceRepository::get_by_id(conn, advance_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Advance with id {}", advance_id)))?;
+        Self::to_history_value(conn, updated)
+    }
+
     // ========== Helper Functions ==========
 
+    /// Advances already taken. Fails if the character tracks XP and hasn't
+    /// earned another advance yet.
+    fn advances_taken_before_new(conn: &Connection, character_id: i64) -> Result<i64> {
+        let taken = CharacterAdvanceRepository::count_by_character_id(conn, character_id)?;
+        if let Some(character) = CharacterRepository::get_by_id(conn, character_id)?
+            && character.track_experience
+            && taken >= Self::advances_from_experience(character.experience_points)
+        {
+            return Err(SwadeError::Validation(format!(
+                "Not enough experience for another advance: {} XP covers {} advances, {} taken",
+                character.experience_points,
+                Self::advances_from_experience(character.experience_points),
+                taken
+            )));
+        }
+        Ok(taken)
+    }
+
+    fn to_history_value(conn: &Connection, advance: CharacterAdvance) -> Result<CharacterAdvanceValue> {
+        let description = Self::build_advance_description(conn, &advance)?;
+        Ok(CharacterAdvanceValue {
+            id: advance.id,
+            advance_number: advance.advance_number,
+            advance_type: advance.advance_type.parse().unwrap_or(AdvanceType::Edge),
+            description,
+            notes: advance.notes,
+            created_at: advance.created_at,
+            rank_changed: None,
+        })
+    }
+
+    /// Run `f` inside a savepoint that is released only if it succeeds, so an
+    /// advance either writes all of its rows or none of them. Savepoints nest,
+    /// so this also works under `with_rollback` or an open transaction.
+    fn in_transaction<T>(conn: &Connection, f: impl FnOnce(&Connection) -> Result<T>) -> Result<T> {
+        conn.execute_batch("SAVEPOINT advancement_apply")?;
+        let result = f(conn);
+        match result {
+            Ok(_) => conn.execute_batch("RELEASE advancement_apply")?,
+            Err(_) => conn.execute_batch("ROLLBACK TO advancement_apply; RELEASE advancement_apply")?,
+        }
+        result
+    }
+
+    /// Run `f` inside a savepoint that is always rolled back, so nothing it
+    /// writes is kept. Savepoints nest, so this works inside an open transaction.
+    fn with_rollback<T>(conn: &Connection, f: impl FnOnce(&Connection) -> Result<T>) -> Result<T> {
+        conn.execute_batch("SAVEPOINT advancement_preview")?;
+        let result = f(conn);
+        conn.execute_batch("ROLLBACK TO advancement_preview; RELEASE advancement_preview")?;
+        result
+    }
+
+    /// Work out which arcane background an edge advance grants, if any.
+    ///
+    /// When the edge grants more than one, `arcane_background_id` must pick
+    /// one of them; a single candidate is picked without it.
+    fn granted_arcane_background(
+        conn: &Connection,
+        character: &CharacterView,
+        edge: &EdgeView,
+        arcane_background_id: Option<i64>,
+    ) -> Result<Option<ArcaneBackgroundView>> {
+        let candidates = ArcaneBackgroundRepository::get_by_edge_id(conn, edge.id)?;
+
+        let chosen = match arcane_background_id {
+            Some(id) => candidates.iter().find(|ab| ab.id == id).ok_or_else(|| {
+                SwadeError::Validation(format!(
+                    "{} doesn't grant the arcane background with id {}",
+                    edge.name, id
+                ))
+            })?,
+            None if candidates.is_empty() => return Ok(None),
+            None if candidates.len() == 1 => &candidates[0],
+            None => {
+                let names: Vec<&str> = candidates.iter().map(|ab| ab.name.as_str()).collect();
+                return Err(SwadeError::Validation(format!(
+                    "{} grants an arcane background; choose one of {}",
+                    edge.name,
+                    names.join(", ")
+                )));
+            }
+        };
+
+        if character
+            .arcane_backgrounds
+            .iter()
+            .any(|ab| ab.arcane_background.id == chosen.id)
+        {
+            return Err(SwadeError::Validation(format!(
+                "{} already has the {} arcane background",
+                character.name, chosen.name
+            )));
+        }
+
+        let arcane_background = ArcaneBackgroundService::get_by_id(conn, chosen.id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Arcane background with id {}", chosen.id)))?;
+
+        if !arcane_background.requirements.evaluate(&character.to_requirement_context()) {
+            return Err(SwadeError::Validation(format!(
+                "Requirements not met for arcane background {}",
+                arcane_background.name
+            )));
+        }
+
+        Ok(Some(arcane_background))
+    }
+
+    /// Give a character an arcane background taken with the given advance: the
+    /// background itself, its starting power points and any required powers.
+    fn grant_arcane_background(
+        conn: &Connection,
+        character: &CharacterView,
+        arcane_background: &ArcaneBackgroundView,
+        advance_number: i64,
+    ) -> Result<()> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        CharacterArcaneBackgroundRepository::insert(
+            conn,
+            &CharacterArcaneBackground {
+                id: 0,
+                character_id: character.id,
+                arcane_background_id: arcane_background.id,
+                advance_taken: Some(advance_number),
+                power_points_used: 0,
+                created_at: now.clone(),
+                updated_at: now.clone(),
+            },
+        )?;
+
+        CharacterRepository::adjust_power_points(
+            conn,
+            character.id,
+            arcane_background.starting_power_points,
+        )?;
+
+        let required_powers = arcane_background
+            .choices
+            .iter()
+            .filter(|c| c.choice_type == ArcaneBackgroundChoiceType::RequiredStartingPower)
+            .flat_map(|c| &c.options)
+            .filter(|o| o.option_type == ArcaneBackgroundOptionType::Power)
+            .filter_map(|o| o.option_id);
+        for power_id in required_powers {
+            if character.powers.iter().any(|p| p.power.id == power_id) {
+                continue;
+            }
+            CharacterPowerRepository::insert(
+                conn,
+                &CharacterPower {
+                    id: 0,
+                    character_id: character.id,
+                    power_id,
+                    advance_taken: Some(advance_number),
+                    created_at: now.clone(),
+                    updated_at: now.clone(),
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Reverse `grant_arcane_background` for the arcane background an edge advance granted.
+    fn revoke_arcane_background(
+        conn: &Connection,
+        character_id: i64,
+        arcane_background_id: i64,
+        advance_number: i64,
+    ) -> Result<()> {
+        let char_abs = CharacterArcaneBackgroundRepository::get_by_character_id(conn, character_id)?;
+        let Some(char_ab) = char_abs
+            .iter()
+            .find(|ab| ab.arcane_background_id == arcane_background_id)
+        else {
+            return Ok(());
+        };
+
+        if let Some(arcane_background) = ArcaneBackgroundRepository::get_by_id(conn, char_ab.arcane_background_id)? {
+            CharacterRepository::adjust_power_points(
+                conn,
+                character_id,
+                -arcane_background.starting_power_points,
+            )?;
+        }
+        CharacterArcaneBackgroundRepository::delete(conn, char_ab.id)?;
+
+        for power in CharacterPowerRepository::get_by_character_id(conn, character_id)? {
+            if power.advance_taken == Some(advance_number) {
+                CharacterPowerRepository::delete(conn, power.id)?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn get_rank_for_advances(conn: &Connection, advances: i64) -> Result<crate::models::Rank> {
         let ranks = RankRepository::get_all(conn)?;
 
@@ -752,6 +1366,42 @@ impl AdvancementService {
             .ok_or_else(|| SwadeError::NotFound("No ranks found".to_string()))
     }
 
+    /// Advances counted toward rank, and the rank they give.
+    ///
+    /// That's advances taken, or advances earned when the character tracks XP,
+    /// so options, advances and the character sheet all agree on rank.
+    pub(crate) fn advances_and_rank(
+        conn: &Connection,
+        character_id: i64,
+    ) -> Result<(i64, crate::models::Rank)> {
+        let character = CharacterRepository::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Character with id {}", character_id)))?;
+        Self::advances_and_rank_for(conn, &character)
+    }
+
+    /// `advances_and_rank` for a character row that's already loaded.
+    pub(crate) fn advances_and_rank_for(
+        conn: &Connection,
+        character: &crate::models::Character,
+    ) -> Result<(i64, crate::models::Rank)> {
+        let advances = if character.track_experience {
+            Self::advances_from_experience(character.experience_points)
+        } else {
+            CharacterAdvanceRepository::count_by_character_id(conn, character.id)?
+        };
+        Ok((advances, Self::get_rank_for_advances(conn, advances)?))
+    }
+
+    /// The rank entered by the advance just taken, or None if the rank stayed the same.
+    fn rank_change(
+        conn: &Connection,
+        character_id: i64,
+        before: &crate::models::Rank,
+    ) -> Result<Option<crate::models::Rank>> {
+        let (_, after) = Self::advances_and_rank(conn, character_id)?;
+        Ok((after.id != before.id).then_some(after))
+    }
+
     fn check_attribute_advance_available(
         conn: &Connection,
         character_id: i64,
@@ -763,12 +1413,14 @@ impl AdvancementService {
         let max_advance_in_rank = current_rank.max_advances.unwrap_or(i64::MAX);
 
         if current_rank.name == "Legendary" {
-            // Legendary: can take attribute every other advance after becoming Legendary
-            let advances_since_legendary = current_advances - 16 + 1; // +1 for the advance about to be taken
+            // Legendary: can take attribute every other advance after becoming Legendary.
+            // Advances taken while Legendary are numbered from min_advances + 1, since
+            // the advance that reached the threshold was taken at the previous rank.
+            let advances_since_legendary = current_advances - min_advance_in_rank + 1; // +1 for the advance about to be taken
             let attr_advances_since_legendary = CharacterAdvanceRepository::count_attribute_advances_in_range(
                 conn,
                 character_id,
-                16, // Legendary starts at advance 16
+                min_advance_in_rank + 1,
                 current_advances,
             )?;
 
@@ -808,11 +1460,10 @@ impl AdvancementService {
         let advances = CharacterAdvanceRepository::get_by_character_id(conn, character_id)?;
         let mut banked = Vec::new();
 
-        // Count remove_major_half for each hindrance
+        // Count major removal halves for each hindrance
         let mut counts: std::collections::HashMap<i64, i64> = std::collections::HashMap::new();
         for advance in &advances {
-            if advance.advance_type == "hindrance"
-                && advance.hindrance_action.as_deref() == Some("remove_major_half")
+            if Self::is_major_removal(advance)
                 && let Some(h_id) = advance.hindrance_id
             {
                 *counts.entry(h_id).or_insert(0) += 1;
@@ -829,16 +1480,34 @@ impl AdvancementService {
         Ok(banked)
     }
 
+    fn is_major_removal(advance: &CharacterAdvance) -> bool {
+        advance.advance_type == "hindrance"
+            && matches!(
+                advance.hindrance_action.as_deref(),
+                Some("remove_major_half") | Some("complete_major_removal")
+            )
+    }
+
+    fn major_removal_count(advances: &[CharacterAdvance], hindrance_id: i64) -> usize {
+        advances
+            .iter()
+            .filter(|a| Self::is_major_removal(a) && a.hindrance_id == Some(hindrance_id))
+            .count()
+    }
+
     fn validate_cheap_skill(
         conn: &Connection,
         character_id: i64,
         skill_id: i64,
-    ) -> Result<(crate::models::Skill, i64)> {
+    ) -> Result<(crate::models::Skill, Die)> {
         let skill = SkillRepository::get_by_id(conn, skill_id)?
             .ok_or_else(|| SwadeError::NotFound(format!("Skill with id {}", skill_id)))?;
 
         let char_skills = CharacterSkillRepository::get_by_character_id(conn, character_id)?;
-        let char_skill = char_skills.iter().find(|s| s.skill_id == skill_id);
+        let char_skill = char_skills
+            .iter()
+            .find(|s| s.skill_id == skill_id)
+            .ok_or_else(|| SwadeError::NotFound(format!("Character skill {}", skill_id)))?;
 
         let char_attrs = CharacterAttributeRepository::get_by_character_id(conn, character_id)?;
         let linked_attr = char_attrs
@@ -848,62 +1517,101 @@ impl AdvancementService {
                 SwadeError::NotFound(format!("Linked attribute {}", skill.linked_attribute_id))
             })?;
 
-        let skill_die_size = char_skill.and_then(|s| s.current_die_size).unwrap_or(0);
-        let attr_die_size = 4 + (linked_attr.steps_incremented * 2);
+        let skill_die = Self::skill_die(char_skill);
+        let attr_die = Self::shift_die(Die::d4(), linked_attr.steps_incremented as i32);
 
         // For cheap skills, the skill must be below linked attribute
-        // OR be a new skill (die size 0 -> d4)
-        if skill_die_size == 0 {
+        // OR be a new skill (untrained -> d4)
+        match skill_die {
             // New skill, going to d4
-            Ok((skill, 4))
-        } else if skill_die_size < attr_die_size {
+            None => Ok((skill, Die::d4())),
             // Below attribute, can increase
-            if skill_die_size >= 12 {
-                return Err(SwadeError::Validation(format!(
-                    "{} is already at maximum (d12)",
-                    skill.name
-                )));
+            Some(die) if die < attr_die => {
+                let skill_cap = Self::skill_die_cap(conn, character_id, skill_id)?;
+                if die >= skill_cap {
+                    return Err(SwadeError::Validation(format!(
+                        "{} is already at maximum ({})",
+                        skill.name, skill_cap
+                    )));
+                }
+                Ok((skill, die.increment()))
             }
-            Ok((skill, skill_die_size + 2))
-        } else {
-            Err(SwadeError::Validation(format!(
-                "{} (d{}) is at or above linked attribute (d{}). Use expensive skill advance.",
-                skill.name, skill_die_size, attr_die_size
-            )))
+            Some(die) => Err(SwadeError::Validation(format!(
+                "{} ({}) is at or above linked attribute ({}). Use expensive skill advance.",
+                skill.name, die, attr_die
+            ))),
+        }
+    }
+
+    /// The highest die a character's skill can be advanced to: the skill's
+    /// max die plus any max_die_increase modifiers the character has.
+    fn skill_die_cap(conn: &Connection, character_id: i64, skill_id: i64) -> Result<Die> {
+        let character = CharacterService::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Character with id {}", character_id)))?;
+        character
+            .skill_die_cap(skill_id)
+            .ok_or_else(|| SwadeError::NotFound(format!("Character skill {}", skill_id)))
+    }
+
+    /// Move a die up (positive) or down (negative) by a number of steps, stopping at d4.
+    fn shift_die(die: Die, steps: i32) -> Die {
+        let mut shifted = die;
+        for _ in 0..steps.unsigned_abs() {
+            shifted = if steps > 0 {
+                shifted.increment()
+            } else {
+                shifted.decrement().unwrap_or(shifted)
+            };
         }
+        shifted
+    }
+
+    /// The die stored on a character skill, including any modifier. None if untrained.
+    ///
+    /// Only a d12 can carry a modifier, so a stored die that isn't legal (a
+    /// d10+1) is read as the nearest legal die, the same die the character
+    /// sheet shows for it.
+    fn skill_die(char_skill: &CharacterSkill) -> Option<Die> {
+        let size = char_skill.current_die_size?;
+        Some(
+            character_load::legal_die(size, char_skill.current_die_modifier)
+                .unwrap_or_else(|| character_load::nearest_legal_die(size)),
+        )
+    }
+
+    /// Store a die (or untrained) on a character skill, keeping size and modifier together.
+    fn set_skill_die(char_skill: &mut CharacterSkill, die: Option<Die>) {
+        char_skill.current_die_size = die.map(|d| d.size() as i64);
+        char_skill.current_die_modifier = die.map(|d| d.modifier() as i64).unwrap_or(0);
+        char_skill.updated_at = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
     }
 
-    fn apply_skill_increase(conn: &Connection, character_id: i64, skill_id: i64) -> Result<()> {
+    /// Raise a skill one die step (untrained goes to d4). Returns the new die.
+    fn apply_skill_increase(conn: &Connection, character_id: i64, skill_id: i64) -> Result<Die> {
         let char_skills = CharacterSkillRepository::get_by_character_id(conn, character_id)?;
+        let char_skill = char_skills
+            .iter()
+            .find(|s| s.skill_id == skill_id)
+            .ok_or_else(|| SwadeError::NotFound(format!("Character skill {}", skill_id)))?;
 
-        if let Some(char_skill) = char_skills.iter().find(|s| s.skill_id == skill_id) {
-            let current_die = char_skill.current_die_size.unwrap_or(0);
-            let new_die = if current_die == 0 { 4 } else { current_die + 2 };
+        let new_die = Self::skill_die(char_skill).map_or(Die::d4(), |d| d.increment());
 
-            let mut updated = char_skill.clone();
-            updated.current_die_size = Some(new_die);
-            updated.updated_at = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
-            CharacterSkillRepository::update(conn, &updated)?;
-        }
+        let mut updated = char_skill.clone();
+        Self::set_skill_die(&mut updated, Some(new_die));
+        CharacterSkillRepository::update(conn, &updated)?;
 
-        Ok(())
+        Ok(new_die)
     }
 
     fn revert_skill_increase(conn: &Connection, character_id: i64, skill_id: i64) -> Result<()> {
         let char_skills = CharacterSkillRepository::get_by_character_id(conn, character_id)?;
 
         if let Some(char_skill) = char_skills.iter().find(|s| s.skill_id == skill_id)
-            && let Some(current_die) = char_skill.current_die_size
+            && let Some(current_die) = Self::skill_die(char_skill)
         {
-            let new_die = if current_die <= 4 {
-                None // Revert to untrained
-            } else {
-                Some(current_die - 2)
-            };
-
+            // Dropping below d4 reverts to untrained
             let mut updated = char_skill.clone();
-            updated.current_die_size = new_die;
-            updated.updated_at = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+            Self::set_skill_die(&mut updated, current_die.decrement());
             CharacterSkillRepository::update(conn, &updated)?;
         }
 
@@ -971,6 +1679,9 @@ impl AdvancementService {
                     Some("remove_major_half") => {
                         format!("Progress toward removing: {}", hindrance_name)
                     }
+                    Some("complete_major_removal") => {
+                        format!("Removed major: {}", hindrance_name)
+                    }
                     _ => format!("Modified: {}", hindrance_name),
                 }
             }
@@ -984,7 +1695,13 @@ impl AdvancementService {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::{setup_test_db, insert_test_rank, insert_test_character, insert_test_attribute, insert_test_edge};
+    use crate::test_utils::{
+        insert_test_arcane_background, insert_test_attribute, insert_test_character, insert_test_edge_multi,
+        insert_test_character_arcane_background, insert_test_character_hindrance,
+        insert_test_character_edge_at_advance, insert_test_edge, insert_test_edge_modifier,
+        insert_test_edge_requirement, insert_test_hindrance, insert_test_rank, insert_test_skill, insert_test_requirement_expression, insert_test_requirement_with_target,
+        setup_test_db,
+    };
     use rusqlite::params;
 
     fn insert_character_attribute(conn: &Connection, character_id: i64, attribute_id: i64, steps: i64) {
@@ -997,6 +1714,72 @@ mod tests {
         .unwrap();
     }
 
+    fn insert_character_skill(
+        conn: &Connection,
+        character_id: i64,
+        skill_id: i64,
+        die_size: Option<i64>,
+        die_modifier: i64,
+    ) {
+        conn.execute(
+            "INSERT INTO character_skills (character_id, skill_id, current_die_size, current_die_modifier,
+                                           created_at, updated_at)
+             VALUES (?, ?, ?, ?, '2024-01-01', '2024-01-01')",
+            params![character_id, skill_id, die_size, die_modifier],
+        )
+        .unwrap();
+    }
+
+    fn stored_skill_die(conn: &Connection, character_id: i64, skill_id: i64) -> Option<Die> {
+        let char_skills = CharacterSkillRepository::get_by_character_id(conn, character_id).unwrap();
+        let char_skill = char_skills.iter().find(|s| s.skill_id == skill_id).unwrap();
+        AdvancementService::skill_die(char_skill)
+    }
+
+    fn insert_rank_with_range(conn: &Connection, id: i64, name: &str, min: i64, max: i64) {
+        conn.execute(
+            "INSERT INTO ranks (id, name, min_advances, max_advances, description, created_at, updated_at)
+             VALUES (?, ?, ?, ?, 'Test rank', '2024-01-01', '2024-01-01')",
+            params![id, name, min, max],
+        )
+        .unwrap();
+    }
+
+    fn insert_open_ended_rank(conn: &Connection, id: i64, name: &str, min: i64) {
+        conn.execute(
+            "INSERT INTO ranks (id, name, min_advances, max_advances, description, created_at, updated_at)
+             VALUES (?, ?, ?, NULL, 'Test rank', '2024-01-01', '2024-01-01')",
+            params![id, name, min],
+        )
+        .unwrap();
+    }
+
+    fn insert_attribute_advance_record(conn: &Connection, character_id: i64, advance_number: i64) {
+        conn.execute(
+            "INSERT INTO character_advances (character_id, advance_number, advance_type,
+                                             attribute_id, created_at, updated_at)
+             VALUES (?, ?, 'attribute', 1, '2024-01-01', '2024-01-01')",
+            params![character_id, advance_number],
+        )
+        .unwrap();
+    }
+
+    fn attribute_advance_available(conn: &Connection) -> bool {
+        AdvancementService::get_advancement_options(conn, 1)
+            .unwrap()
+            .can_increase_attribute
+    }
+
+    fn insert_edge_advance_record(conn: &Connection, character_id: i64, advance_number: i64) {
+        conn.execute(
+            "INSERT INTO character_advances (character_id, advance_number, advance_type,
+                                             created_at, updated_at)
+             VALUES (?, ?, 'edge', '2024-01-01', '2024-01-01')",
+            params![character_id, advance_number],
+        )
+        .unwrap();
+    }
+
     #[test]
     fn test_get_advancement_options_novice() {
         let conn = setup_test_db();
@@ -1024,7 +1807,7 @@ mod tests {
         insert_character_attribute(&conn, 1, 1, 0);
 
         // Take an attribute advance
-        let _ = AdvancementService::apply_attribute_advance(&conn, 1, 1).unwrap();
+        let _ = AdvancementService::apply_attribute_advance(&conn, 1, 1, None).unwrap();
 
         // Check options - attribute should now be blocked
         let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
@@ -1032,6 +1815,233 @@ mod tests {
         assert!(options.attribute_blocked_reason.is_some());
     }
 
+    #[test]
+    fn test_take_advance_returns_updated_character() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Vigor");
+        insert_character_attribute(&conn, 1, 1, 0);
+        let before = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+
+        let applied = AdvancementService::take_advance(
+            &conn,
+            1,
+            &PlannedAdvance::Attribute { attribute_id: 1 },
+            Some("Training montage".to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(applied.advance.advance_type, AdvanceType::Attribute);
+        assert_eq!(applied.advance.notes.as_deref(), Some("Training montage"));
+        assert_eq!(applied.character.attributes[0].die, Die::d6());
+        assert_eq!(applied.character.derived_stats.toughness, before.derived_stats.toughness + 1);
+        assert_eq!(applied.character.current_advances, 1);
+    }
+
+    #[test]
+    fn test_attribute_advance_blocked_at_d12_by_default() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 4);
+
+        let result = AdvancementService::apply_attribute_advance(&conn, 1, 1, None);
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        assert!(options.attribute_options[0].is_maxed);
+    }
+
+    #[test]
+    fn test_attribute_advance_past_d12_with_raised_cap() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 4);
+        conn.execute(
+            "UPDATE characters SET max_attribute_die_size = 12, max_attribute_die_modifier = 2 WHERE id = 1",
+            [],
+        )
+        .unwrap();
+
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        assert!(!options.attribute_options[0].is_maxed);
+        assert_eq!(options.attribute_options[0].next_die, Die::with_modifier(12, 1).unwrap());
+
+        AdvancementService::apply_attribute_advance(&conn, 1, 1, None).unwrap();
+
+        let character = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        assert_eq!(character.attributes[0].die, Die::with_modifier(12, 1).unwrap());
+    }
+
+    #[test]
+    fn test_attribute_option_for_d12_plus_1_keeps_modifier() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 5);
+        conn.execute(
+            "UPDATE characters SET max_attribute_die_size = 12, max_attribute_die_modifier = 2 WHERE id = 1",
+            [],
+        )
+        .unwrap();
+
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        let option = &options.attribute_options[0];
+        assert_eq!(option.current_die, Die::with_modifier(12, 1).unwrap());
+        assert_eq!(option.next_die, Die::with_modifier(12, 2).unwrap());
+        assert_eq!(option.effective_next_die, Die::with_modifier(12, 2).unwrap());
+        assert!(!option.is_maxed);
+
+        AdvancementService::apply_attribute_advance(&conn, 1, 1, None).unwrap();
+
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        let option = &options.attribute_options[0];
+        assert!(option.is_maxed);
+        assert_eq!(option.next_die, Die::with_modifier(12, 2).unwrap());
+    }
+
+    #[test]
+    fn test_attribute_option_for_d12_plus_1_is_maxed_at_default_cap() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 5);
+
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        let option = &options.attribute_options[0];
+
+        assert!(option.is_maxed);
+        assert_eq!(option.current_die, Die::with_modifier(12, 1).unwrap());
+        assert_eq!(option.next_die, Die::with_modifier(12, 1).unwrap());
+    }
+
+    #[test]
+    fn test_professional_edge_allows_attribute_past_d12() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 4);
+        insert_test_edge(&conn, 1, "Professional", "Legendary");
+        conn.execute(
+            "INSERT INTO modifiers (id, target_type, target_identifier, value_type, value, description,
+                                    created_at, updated_at)
+             VALUES (1, 'attribute', 'Agility', 'max_die_increase', 1, 'Agility max d12+1',
+                     '2024-01-01', '2024-01-01')",
+            [],
+        )
+        .unwrap();
+        insert_test_edge_modifier(&conn, 1, 1, 1);
+
+        // Without the edge, Agility is maxed at d12
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        assert!(options.attribute_options[0].is_maxed);
+
+        insert_test_character_edge_at_advance(&conn, 1, 1, 1, 0);
+
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        assert!(!options.attribute_options[0].is_maxed);
+        assert_eq!(options.attribute_options[0].next_die, Die::with_modifier(12, 1).unwrap());
+
+        AdvancementService::apply_attribute_advance(&conn, 1, 1, None).unwrap();
+
+        let character = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        assert_eq!(character.attributes[0].die, Die::with_modifier(12, 1).unwrap());
+        assert!(!character.attributes[0].can_increment);
+    }
+
+    #[test]
+    fn test_edge_raising_skill_max_allows_advance_past_d12() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 4);
+        insert_test_skill(&conn, 1, "Fighting", 1);
+        insert_character_skill(&conn, 1, 1, Some(12), 0);
+        insert_test_edge(&conn, 1, "Expert (Fighting)", "Legendary");
+        conn.execute(
+            "INSERT INTO modifiers (id, target_type, target_identifier, value_type, value, description,
+                                    created_at, updated_at)
+             VALUES (1, 'skill', 'Fighting', 'max_die_increase', 1, 'Fighting max d12+1',
+                     '2024-01-01', '2024-01-01')",
+            [],
+        )
+        .unwrap();
+        insert_test_edge_modifier(&conn, 1, 1, 1);
+
+        // Without the edge, Fighting is maxed at d12
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        assert!(options.expensive_skill_options[0].is_maxed);
+        assert!(AdvancementService::apply_expensive_skill_advance(&conn, 1, 1, None).is_err());
+
+        insert_test_character_edge_at_advance(&conn, 1, 1, 1, 0);
+
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        assert!(!options.expensive_skill_options[0].is_maxed);
+        assert_eq!(options.expensive_skill_options[0].next_die, Die::with_modifier(12, 1).unwrap());
+
+        let taken = AdvancementService::apply_expensive_skill_advance(&conn, 1, 1, None).unwrap();
+        assert_eq!(taken.description, "Increased Fighting to d12+1");
+        assert_eq!(stored_skill_die(&conn, 1, 1), Die::with_modifier(12, 1));
+
+        // The raised cap is now reached
+        assert!(AdvancementService::apply_expensive_skill_advance(&conn, 1, 1, None).is_err());
+    }
+
+    #[test]
+    fn test_legendary_attribute_every_other_advance() {
+        let conn = setup_test_db();
+        insert_rank_with_range(&conn, 1, "Heroic", 0, 15);
+        insert_open_ended_rank(&conn, 2, "Legendary", 16);
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 0);
+        for advance_number in 1..=15 {
+            insert_edge_advance_record(&conn, 1, advance_number);
+        }
+        // The advance that reaches Legendary is taken while Heroic
+        insert_attribute_advance_record(&conn, 1, 16);
+
+        // First Legendary advance (17) can't be an attribute, the second (18) can
+        assert!(!attribute_advance_available(&conn));
+        insert_edge_advance_record(&conn, 1, 17);
+        assert!(attribute_advance_available(&conn));
+
+        AdvancementService::apply_attribute_advance(&conn, 1, 1, None).unwrap();
+
+        assert!(!attribute_advance_available(&conn));
+        insert_edge_advance_record(&conn, 1, 19);
+        assert!(attribute_advance_available(&conn));
+    }
+
+    #[test]
+    fn test_legendary_attribute_cadence_uses_rank_threshold() {
+        let conn = setup_test_db();
+        insert_rank_with_range(&conn, 1, "Heroic", 0, 9);
+        insert_open_ended_rank(&conn, 2, "Legendary", 10);
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 0);
+        for advance_number in 1..=10 {
+            insert_edge_advance_record(&conn, 1, advance_number);
+        }
+
+        assert!(!attribute_advance_available(&conn));
+        insert_edge_advance_record(&conn, 1, 11);
+        assert!(attribute_advance_available(&conn));
+        insert_attribute_advance_record(&conn, 1, 12);
+        assert!(!attribute_advance_available(&conn));
+        insert_edge_advance_record(&conn, 1, 13);
+        assert!(attribute_advance_available(&conn));
+    }
+
     #[test]
     fn test_apply_edge_advance() {
         let conn = setup_test_db();
@@ -1039,7 +2049,7 @@ mod tests {
         insert_test_character(&conn, 1, "Test Hero");
         insert_test_edge(&conn, 1, "Alertness", "Background");
 
-        let result = AdvancementService::apply_edge_advance(&conn, 1, 1, None).unwrap();
+        let result = AdvancementService::apply_edge_advance(&conn, 1, 1, None, None).unwrap();
 
         assert_eq!(result.advance_number, 1);
         assert_eq!(result.advance_type, AdvanceType::Edge);
@@ -1052,47 +2062,888 @@ mod tests {
     }
 
     #[test]
-    fn test_get_advancement_history() {
+    fn test_failed_edge_advance_leaves_no_advance_row() {
         let conn = setup_test_db();
         insert_test_rank(&conn, 1, "Novice");
         insert_test_character(&conn, 1, "Test Hero");
         insert_test_edge(&conn, 1, "Alertness", "Background");
-        insert_test_edge(&conn, 2, "Brawny", "Background");
+        // Fail the second write, after the advance row is already inserted
+        conn.execute_batch(
+            "CREATE TRIGGER fail_character_edges BEFORE INSERT ON character_edges
+             BEGIN SELECT RAISE(ABORT, 'injected failure'); END;",
+        )
+        .unwrap();
 
-        // Take two advances
-        AdvancementService::apply_edge_advance(&conn, 1, 1, None).unwrap();
-        AdvancementService::apply_edge_advance(&conn, 1, 2, None).unwrap();
+        let result = AdvancementService::apply_edge_advance(&conn, 1, 1, None, None);
 
-        let history = AdvancementService::get_advancement_history(&conn, 1).unwrap();
+        assert!(result.is_err());
+        assert_eq!(CharacterAdvanceRepository::count_by_character_id(&conn, 1).unwrap(), 0);
 
-        assert_eq!(history.len(), 2);
-        assert_eq!(history[0].advance_number, 1);
-        assert_eq!(history[1].advance_number, 2);
+        // The savepoint was released, so later advances work as usual
+        conn.execute_batch("DROP TRIGGER fail_character_edges").unwrap();
+        AdvancementService::apply_edge_advance(&conn, 1, 1, None, None).unwrap();
+        assert_eq!(CharacterAdvanceRepository::count_by_character_id(&conn, 1).unwrap(), 1);
     }
 
     #[test]
-    fn test_undo_edge_advance() {
+    fn test_advance_within_rank_has_no_rank_change() {
         let conn = setup_test_db();
-        insert_test_rank(&conn, 1, "Novice");
+        insert_rank_with_range(&conn, 1, "Novice", 0, 3);
+        insert_rank_with_range(&conn, 2, "Seasoned", 4, 7);
         insert_test_character(&conn, 1, "Test Hero");
         insert_test_edge(&conn, 1, "Alertness", "Background");
 
-        // Take an advance
-        AdvancementService::apply_edge_advance(&conn, 1, 1, None).unwrap();
+        let result = AdvancementService::apply_edge_advance(&conn, 1, 1, None, None).unwrap();
 
-        // Verify edge exists
-        let edges_before = CharacterEdgeRepository::get_by_character_id(&conn, 1).unwrap();
-        assert_eq!(edges_before.len(), 1);
+        assert!(result.rank_changed.is_none());
+    }
 
-        // Undo
-        AdvancementService::undo_advance(&conn, 1).unwrap();
+    #[test]
+    fn test_advance_crossing_into_seasoned_reports_rank_change() {
+        let conn = setup_test_db();
+        insert_rank_with_range(&conn, 1, "Novice", 0, 3);
+        insert_rank_with_range(&conn, 2, "Seasoned", 4, 7);
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_edge(&conn, 1, "Alertness", "Background");
+        for advance_number in 1..=3 {
+            insert_edge_advance_record(&conn, 1, advance_number);
+        }
 
-        // Verify edge was removed
-        let edges_after = CharacterEdgeRepository::get_by_character_id(&conn, 1).unwrap();
-        assert_eq!(edges_after.len(), 0);
+        let result = AdvancementService::apply_edge_advance(&conn, 1, 1, None, None).unwrap();
 
-        // Verify advance was removed
+        assert_eq!(result.advance_number, 4);
+        let rank = result.rank_changed.expect("advance 4 should enter Seasoned");
+        assert_eq!(rank.name, "Seasoned");
+
+        // History entries never carry a rank change
         let history = AdvancementService::get_advancement_history(&conn, 1).unwrap();
-        assert_eq!(history.len(), 0);
+        assert!(history.iter().all(|a| a.rank_changed.is_none()));
+    }
+
+    #[test]
+    fn test_edge_advance_rejects_edge_already_held() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_edge(&conn, 1, "Quick", "Background");
+        // Held from character creation, not from an earlier advance
+        insert_test_character_edge_at_advance(&conn, 1, 1, 1, 0);
+
+        let result = AdvancementService::apply_edge_advance(&conn, 1, 1, None, None);
+
+        match result {
+            Err(SwadeError::Validation(message)) => {
+                assert_eq!(message, "Quick is already taken and can't be taken more than once");
+            }
+            other => panic!("expected a validation error, got {:?}", other.map(|a| a.id)),
+        }
+        assert!(AdvancementService::get_advancement_history(&conn, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_repeatable_edge_can_be_taken_again_with_different_notes() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_edge(&conn, 1, "Scholar", "Professional");
+        conn.execute("UPDATE edges SET can_take_multiple_times = 1 WHERE id = 1", [])
+            .unwrap();
+
+        AdvancementService::apply_edge_advance(&conn, 1, 1, None, Some("Occult".to_string())).unwrap();
+        AdvancementService::apply_edge_advance(&conn, 1, 1, None, Some("History".to_string())).unwrap();
+
+        let result = AdvancementService::apply_edge_advance(&conn, 1, 1, None, Some(" occult ".to_string()));
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+
+        let character = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        let notes: Vec<Option<&str>> = character.edges.iter().map(|e| e.notes.as_deref()).collect();
+        assert_eq!(notes, vec![Some("Occult"), Some("History")]);
+    }
+
+    #[test]
+    fn test_non_repeatable_edge_cannot_be_taken_twice() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_edge(&conn, 1, "Alertness", "Background");
+
+        AdvancementService::apply_edge_advance(&conn, 1, 1, None, None).unwrap();
+        let result = AdvancementService::apply_edge_advance(&conn, 1, 1, None, Some("Again".to_string()));
+
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+        assert_eq!(AdvancementService::get_advancement_history(&conn, 1).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_get_advancement_history() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_edge(&conn, 1, "Alertness", "Background");
+        insert_test_edge(&conn, 2, "Brawny", "Background");
+
+        // Take two advances
+        AdvancementService::apply_edge_advance(&conn, 1, 1, None, None).unwrap();
+        AdvancementService::apply_edge_advance(&conn, 1, 2, None, None).unwrap();
+
+        let history = AdvancementService::get_advancement_history(&conn, 1).unwrap();
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].advance_number, 1);
+        assert_eq!(history[1].advance_number, 2);
+    }
+
+    #[test]
+    fn test_advancement_options_report_position_in_rank() {
+        let conn = setup_test_db();
+        insert_rank_with_range(&conn, 1, "Novice", 0, 3);
+        insert_rank_with_range(&conn, 2, "Seasoned", 4, 7);
+        insert_open_ended_rank(&conn, 3, "Legendary", 8);
+        insert_test_character(&conn, 1, "Test Hero");
+        for advance_number in 1..=6 {
+            insert_edge_advance_record(&conn, 1, advance_number);
+        }
+
+        // Six advances: two into Seasoned, two more until Veteran
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        assert_eq!(options.current_rank, "Seasoned");
+        assert_eq!(options.advances_into_rank, 2);
+        assert_eq!(options.advances_remaining_in_rank, Some(2));
+
+        for advance_number in 7..=9 {
+            insert_edge_advance_record(&conn, 1, advance_number);
+        }
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        assert_eq!(options.current_rank, "Legendary");
+        assert_eq!(options.advances_into_rank, 1);
+        assert_eq!(options.advances_remaining_in_rank, None);
+    }
+
+    #[test]
+    fn test_advancement_timeline_groups_advances_by_rank() {
+        let conn = setup_test_db();
+        insert_rank_with_range(&conn, 1, "Novice", 0, 3);
+        insert_rank_with_range(&conn, 2, "Seasoned", 4, 7);
+        insert_test_character(&conn, 1, "Test Hero");
+        for advance_number in 1..=5 {
+            insert_edge_advance_record(&conn, 1, advance_number);
+        }
+
+        let timeline = AdvancementService::export_advancement_timeline(&conn, 1).unwrap();
+
+        let ranks: Vec<(i64, &str)> = timeline
+            .iter()
+            .map(|entry| (entry.advance_number, entry.rank.as_str()))
+            .collect();
+        assert_eq!(
+            ranks,
+            vec![
+                (1, "Novice"),
+                (2, "Novice"),
+                (3, "Novice"),
+                (4, "Seasoned"),
+                (5, "Seasoned"),
+            ]
+        );
+        assert_eq!(timeline[0].created_at, "2024-01-01");
+    }
+
+    #[test]
+    fn test_advancement_history_includes_notes() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 0);
+
+        let taken = AdvancementService::apply_attribute_advance(
+            &conn,
+            1,
+            1,
+            Some("Took it for the duel arc".to_string()),
+        )
+        .unwrap();
+
+        let history = AdvancementService::get_advancement_history(&conn, 1).unwrap();
+
+        assert_eq!(taken.notes.as_deref(), Some("Took it for the duel arc"));
+        assert_eq!(history[0].notes.as_deref(), Some("Took it for the duel arc"));
+    }
+
+    #[test]
+    fn test_update_advance_notes_shows_in_history() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_edge(&conn, 1, "Quick", "Combat");
+
+        let taken = AdvancementService::apply_edge_advance(&conn, 1, 1, None, None).unwrap();
+        let updated = AdvancementService::update_advance_notes(
+            &conn,
+            1,
+            taken.id,
+            Some("  Took Quick for the duel arc ".to_string()),
+        )
+        .unwrap();
+
+        let history = AdvancementService::get_advancement_history(&conn, 1).unwrap();
+
+        assert_eq!(updated.notes.as_deref(), Some("Took Quick for the duel arc"));
+        assert_eq!(history[0].notes.as_deref(), Some("Took Quick for the duel arc"));
+        assert_eq!(history[0].description, "Gained edge: Quick");
+    }
+
+    #[test]
+    fn test_update_advance_notes_rejects_other_character() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_character(&conn, 2, "Other Hero");
+        insert_test_edge(&conn, 1, "Quick", "Combat");
+
+        let taken = AdvancementService::apply_edge_advance(&conn, 1, 1, None, None).unwrap();
+        let result = AdvancementService::update_advance_notes(&conn, 2, taken.id, Some("Mine".to_string()));
+
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+        let history = AdvancementService::get_advancement_history(&conn, 1).unwrap();
+        assert_eq!(history[0].notes, None);
+    }
+
+    #[test]
+    fn test_hindrance_advance_notes_do_not_affect_undo() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_hindrance(&conn, 1, "Clumsy", "minor", 1);
+        insert_test_character_hindrance(&conn, 1, 1, 1);
+
+        AdvancementService::apply_hindrance_advance(
+            &conn,
+            1,
+            1,
+            "remove_minor",
+            Some("Finally learned to dance".to_string()),
+        )
+        .unwrap();
+        AdvancementService::undo_advance(&conn, 1).unwrap();
+
+        let hindrances = CharacterHindranceRepository::get_by_character_id(&conn, 1).unwrap();
+        assert_eq!(hindrances.len(), 1);
+        assert_ne!(hindrances[0].source, "Finally learned to dance");
+    }
+
+    #[test]
+    fn test_preview_advancement_plan_reports_illegal_step() {
+        let conn = setup_test_db();
+        insert_rank_with_range(&conn, 1, "Novice", 0, 3);
+        insert_rank_with_range(&conn, 2, "Seasoned", 4, 7);
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 0);
+        insert_test_edge(&conn, 1, "Alertness", "Background");
+        insert_test_edge(&conn, 2, "Brawny", "Background");
+        insert_test_edge(&conn, 3, "Quick", "Background");
+
+        let plan = vec![
+            PlannedAdvance::Edge { edge_id: 1, arcane_background_id: None },
+            PlannedAdvance::Attribute { attribute_id: 1 },
+            PlannedAdvance::Edge { edge_id: 2, arcane_background_id: None },
+            // Second attribute increase in Novice
+            PlannedAdvance::Attribute { attribute_id: 1 },
+            PlannedAdvance::Edge { edge_id: 3, arcane_background_id: None },
+        ];
+
+        let steps = AdvancementService::preview_advancement_plan(&conn, 1, &plan).unwrap();
+
+        assert_eq!(steps.len(), 5);
+        assert!(steps[..3].iter().all(|s| s.success));
+        assert!(!steps[3].success);
+        assert_eq!(steps[3].step, 4);
+        assert!(steps[3].error.as_ref().unwrap().contains("Already increased an attribute"));
+        assert_eq!(steps[3].advances, 3);
+        assert_eq!(steps[3].rank, "Novice");
+        assert!(steps[4].success);
+        assert_eq!(steps[4].advances, 4);
+        assert_eq!(steps[4].rank, "Seasoned");
+
+        // Nothing was saved
+        assert!(AdvancementService::get_advancement_history(&conn, 1).unwrap().is_empty());
+        assert!(CharacterEdgeRepository::get_by_character_id(&conn, 1).unwrap().is_empty());
+        let attrs = CharacterAttributeRepository::get_by_character_id(&conn, 1).unwrap();
+        assert_eq!(attrs[0].steps_incremented, 0);
+    }
+
+    #[test]
+    fn test_check_advance_with_unmet_requirements_writes_nothing() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_edge(&conn, 1, "Alertness", "Background");
+        insert_test_edge(&conn, 2, "Improved Alertness", "Background");
+        insert_test_requirement_with_target(&conn, 1, "edge", 1, 1, "Alertness");
+        insert_test_requirement_expression(&conn, 1, "requirement", Some(1));
+        insert_test_edge_requirement(&conn, 1, 2, 1);
+
+        let result = AdvancementService::check_advance(&conn, 1, &PlannedAdvance::Edge { edge_id: 2, arcane_background_id: None });
+
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+        assert!(AdvancementService::get_advancement_history(&conn, 1).unwrap().is_empty());
+        assert!(CharacterEdgeRepository::get_by_character_id(&conn, 1).unwrap().is_empty());
+    }
+
+    fn track_experience(conn: &Connection, character_id: i64) {
+        conn.execute(
+            "UPDATE characters SET track_experience = 1 WHERE id = ?",
+            params![character_id],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_advances_from_experience_boundaries() {
+        assert_eq!(AdvancementService::advances_from_experience(-5), 0);
+        assert_eq!(AdvancementService::advances_from_experience(0), 0);
+        assert_eq!(AdvancementService::advances_from_experience(4), 0);
+        assert_eq!(AdvancementService::advances_from_experience(5), 1);
+        assert_eq!(AdvancementService::advances_from_experience(9), 1);
+        assert_eq!(AdvancementService::advances_from_experience(10), 2);
+    }
+
+    #[test]
+    fn test_tracked_experience_drives_rank() {
+        let conn = setup_test_db();
+        insert_rank_with_range(&conn, 1, "Novice", 0, 3);
+        insert_rank_with_range(&conn, 2, "Seasoned", 4, 7);
+        insert_test_character(&conn, 1, "Test Hero");
+        track_experience(&conn, 1);
+
+        let character = AdvancementService::award_xp(&conn, 1, 19).unwrap();
+        assert_eq!(character.current_advances, 3);
+        assert_eq!(character.rank.name, "Novice");
+
+        let character = AdvancementService::award_xp(&conn, 1, 1).unwrap();
+        assert_eq!(character.experience_points, 20);
+        assert_eq!(character.current_advances, 4);
+        assert_eq!(character.rank.name, "Seasoned");
+
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        assert_eq!(options.current_rank, "Seasoned");
+        assert_eq!(options.rank_after_advance, "Seasoned");
+        assert_eq!(options.next_advance_number, 1);
+        assert_eq!(options.unspent_advances, Some(4));
+    }
+
+    #[test]
+    fn test_tracked_experience_limits_advances_taken() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_edge(&conn, 1, "Alertness", "Background");
+        insert_test_edge(&conn, 2, "Brawny", "Background");
+        track_experience(&conn, 1);
+
+        let result = AdvancementService::apply_edge_advance(&conn, 1, 1, None, None);
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+
+        AdvancementService::award_xp(&conn, 1, 5).unwrap();
+        AdvancementService::apply_edge_advance(&conn, 1, 1, None, None).unwrap();
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        assert_eq!(options.unspent_advances, Some(0));
+
+        let result = AdvancementService::apply_edge_advance(&conn, 1, 2, None, None);
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+    }
+
+    #[test]
+    fn test_tracked_experience_advances_use_rank_from_xp() {
+        let conn = setup_test_db();
+        insert_rank_with_range(&conn, 1, "Novice", 0, 3);
+        insert_rank_with_range(&conn, 2, "Seasoned", 4, 7);
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 0);
+        insert_test_edge(&conn, 1, "Alertness", "Background");
+        insert_test_edge(&conn, 2, "Brawny", "Background");
+        for advance_number in 1..=3 {
+            insert_edge_advance_record(&conn, 1, advance_number);
+        }
+        track_experience(&conn, 1);
+        AdvancementService::award_xp(&conn, 1, 25).unwrap();
+
+        // Already Seasoned from XP, so the fourth advance doesn't enter a new rank
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        assert_eq!(options.current_rank, "Seasoned");
+        assert!(options.can_increase_attribute);
+        let first = AdvancementService::apply_attribute_advance(&conn, 1, 1, None).unwrap();
+        assert!(first.rank_changed.is_none());
+
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        assert!(!options.can_increase_attribute);
+        assert!(matches!(
+            AdvancementService::apply_attribute_advance(&conn, 1, 1, None),
+            Err(SwadeError::Validation(_))
+        ));
+        let second = AdvancementService::apply_edge_advance(&conn, 1, 2, None, None).unwrap();
+        assert_eq!(second.advance_number, 5);
+        assert!(second.rank_changed.is_none());
+    }
+
+    #[test]
+    fn test_spend_xp_keeps_cost_of_advances_taken() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_edge(&conn, 1, "Alertness", "Background");
+        track_experience(&conn, 1);
+        AdvancementService::award_xp(&conn, 1, 8).unwrap();
+        AdvancementService::apply_edge_advance(&conn, 1, 1, None, None).unwrap();
+
+        let result = AdvancementService::spend_xp(&conn, 1, 4);
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+
+        let character = AdvancementService::spend_xp(&conn, 1, 3).unwrap();
+        assert_eq!(character.experience_points, 5);
+        assert_eq!(character.current_advances, 1);
+    }
+
+    #[test]
+    fn test_experience_ignored_without_tracking() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+
+        let character = AdvancementService::award_xp(&conn, 1, 10).unwrap();
+
+        assert_eq!(character.experience_points, 10);
+        assert_eq!(character.current_advances, 0);
+        let options = AdvancementService::get_advancement_options(&conn, 1).unwrap();
+        assert_eq!(options.unspent_advances, None);
+        assert!(matches!(
+            AdvancementService::award_xp(&conn, 1, 0),
+            Err(SwadeError::Validation(_))
+        ));
+    }
+
+    fn insert_seasoned_edge(conn: &Connection) {
+        insert_test_edge(conn, 1, "Alertness", "Background");
+        insert_test_edge(conn, 2, "Improved Alertness", "Background");
+        insert_test_requirement_with_target(conn, 1, "rank", 2, 0, "Seasoned");
+        insert_test_requirement_expression(conn, 1, "requirement", Some(1));
+        insert_test_edge_requirement(conn, 1, 2, 1);
+    }
+
+    #[test]
+    fn test_takeable_edges_for_seasoned_character() {
+        let conn = setup_test_db();
+        insert_rank_with_range(&conn, 1, "Novice", 0, 3);
+        insert_rank_with_range(&conn, 2, "Seasoned", 4, 7);
+        insert_rank_with_range(&conn, 3, "Veteran", 8, 11);
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_seasoned_edge(&conn);
+        insert_test_edge(&conn, 3, "Combat Reflexes", "Combat");
+        insert_test_requirement_with_target(&conn, 2, "rank", 3, 0, "Veteran");
+        insert_test_requirement_expression(&conn, 2, "requirement", Some(2));
+        insert_test_edge_requirement(&conn, 2, 3, 2);
+        insert_test_edge(&conn, 4, "Brawny", "Background");
+        insert_test_edge_multi(&conn, 5, "Power Points", "Power");
+        insert_test_character_edge_at_advance(&conn, 1, 1, 4, 0);
+        insert_test_character_edge_at_advance(&conn, 2, 1, 5, 0);
+        for advance_number in 1..=4 {
+            insert_edge_advance_record(&conn, 1, advance_number);
+        }
+
+        let takeable = AdvancementService::get_takeable_edges(&conn, 1).unwrap();
+        let names: Vec<&str> = takeable.iter().map(|e| e.name.as_str()).collect();
+
+        // Veteran edge is out of reach, Brawny is already held, Power Points repeats
+        assert_eq!(names, vec!["Alertness", "Improved Alertness", "Power Points"]);
+    }
+
+    #[test]
+    fn test_advances_until_available_for_seasoned_edge_on_novice() {
+        let conn = setup_test_db();
+        insert_rank_with_range(&conn, 1, "Novice", 0, 3);
+        insert_rank_with_range(&conn, 2, "Seasoned", 4, 7);
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_seasoned_edge(&conn);
+
+        assert_eq!(AdvancementService::advances_until_available(&conn, 1, 2).unwrap(), Some(4));
+
+        insert_edge_advance_record(&conn, 1, 1);
+        assert_eq!(AdvancementService::advances_until_available(&conn, 1, 2).unwrap(), Some(3));
+        assert_eq!(AdvancementService::advances_until_available(&conn, 1, 1).unwrap(), Some(0));
+    }
+
+    #[test]
+    fn test_advances_until_available_is_none_when_blocked_by_more_than_rank() {
+        let conn = setup_test_db();
+        insert_rank_with_range(&conn, 1, "Novice", 0, 3);
+        insert_rank_with_range(&conn, 2, "Seasoned", 4, 7);
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_edge(&conn, 1, "Quick", "Background");
+        insert_test_requirement_with_target(&conn, 1, "rank", 2, 0, "Seasoned");
+        insert_test_requirement_with_target(&conn, 2, "attribute", 1, 8, "Agility d8+");
+        conn.execute_batch(
+            "INSERT INTO requirement_expressions (id, parent_id, node_type, requirement_id, position, created_at, updated_at)
+             VALUES (1, NULL, 'and', NULL, 0, '2024-01-01', '2024-01-01'),
+                    (2, 1, 'requirement', 1, 0, '2024-01-01', '2024-01-01'),
+                    (3, 1, 'requirement', 2, 1, '2024-01-01', '2024-01-01');",
+        )
+        .unwrap();
+        insert_test_edge_requirement(&conn, 1, 1, 1);
+
+        assert_eq!(AdvancementService::advances_until_available(&conn, 1, 1).unwrap(), None);
+    }
+
+    #[test]
+    fn test_check_advance_describes_without_applying() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_edge(&conn, 1, "Alertness", "Background");
+
+        let value =
+            AdvancementService::check_advance(&conn, 1, &PlannedAdvance::Edge { edge_id: 1, arcane_background_id: None }).unwrap();
+
+        assert_eq!(value.id, 0);
+        assert_eq!(value.advance_number, 1);
+        assert_eq!(value.description, "Gained edge: Alertness");
+        assert!(AdvancementService::get_advancement_history(&conn, 1).unwrap().is_empty());
+        assert!(CharacterEdgeRepository::get_by_character_id(&conn, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_undo_edge_advance() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_edge(&conn, 1, "Alertness", "Background");
+
+        // Take an advance
+        AdvancementService::apply_edge_advance(&conn, 1, 1, None, None).unwrap();
+
+        // Verify edge exists
+        let edges_before = CharacterEdgeRepository::get_by_character_id(&conn, 1).unwrap();
+        assert_eq!(edges_before.len(), 1);
+
+        // Undo
+        AdvancementService::undo_advance(&conn, 1).unwrap();
+
+        // Verify edge was removed
+        let edges_after = CharacterEdgeRepository::get_by_character_id(&conn, 1).unwrap();
+        assert_eq!(edges_after.len(), 0);
+
+        // Verify advance was removed
+        let history = AdvancementService::get_advancement_history(&conn, 1).unwrap();
+        assert_eq!(history.len(), 0);
+    }
+
+    #[test]
+    fn test_arcane_background_edge_advance_grants_power_points_and_undoes() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Smarts");
+        insert_test_skill(&conn, 1, "Spellcasting", 1);
+        insert_test_edge(&conn, 3, "Arcane Background", "Background");
+        insert_test_arcane_background(&conn, 1, "Magic", 1);
+        insert_test_arcane_background(&conn, 2, "Miracles", 1);
+        conn.execute("UPDATE arcane_backgrounds SET edge_id = 3", []).unwrap();
+
+        // With more than one background on offer, one must be chosen
+        let result = AdvancementService::apply_edge_advance(&conn, 1, 3, None, None);
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+        assert!(AdvancementService::get_advancement_history(&conn, 1).unwrap().is_empty());
+
+        AdvancementService::apply_edge_advance(&conn, 1, 3, Some(1), None).unwrap();
+
+        let character = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        assert_eq!(character.arcane_backgrounds.len(), 1);
+        assert_eq!(character.arcane_backgrounds[0].arcane_background.name, "Magic");
+        assert_eq!(character.arcane_backgrounds[0].advance_taken, Some(1));
+        assert_eq!(character.power_points, 10);
+        assert_eq!(character.get_power_point_pool(1).unwrap().max, 10);
+
+        AdvancementService::undo_advance(&conn, 1).unwrap();
+
+        let character = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        assert!(character.arcane_backgrounds.is_empty());
+        assert!(character.power_point_pools.is_empty());
+        assert_eq!(character.power_points, 0);
+    }
+
+    #[test]
+    fn test_arcane_background_edge_rejects_background_it_does_not_grant() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Smarts");
+        insert_test_skill(&conn, 1, "Spellcasting", 1);
+        insert_test_edge(&conn, 3, "Arcane Background", "Background");
+        insert_test_arcane_background(&conn, 1, "Magic", 1);
+        insert_test_arcane_background(&conn, 2, "Gifted", 1);
+        conn.execute("UPDATE arcane_backgrounds SET edge_id = 3 WHERE id = 1", []).unwrap();
+
+        let result = AdvancementService::apply_edge_advance(&conn, 1, 3, Some(2), None);
+
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+        assert!(AdvancementService::get_advancement_history(&conn, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_undo_revokes_the_arcane_background_recorded_on_the_advance() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Smarts");
+        insert_test_skill(&conn, 1, "Spellcasting", 1);
+        insert_test_edge(&conn, 3, "Arcane Background", "Background");
+        insert_test_arcane_background(&conn, 1, "Magic", 1);
+        insert_test_arcane_background(&conn, 2, "Miracles", 1);
+        conn.execute("UPDATE arcane_backgrounds SET edge_id = 3", []).unwrap();
+        // Magic was picked at creation
+        insert_test_character_arcane_background(&conn, 1, 1, 1);
+
+        // Notes no longer have anything to do with which background is granted
+        AdvancementService::apply_edge_advance(&conn, 1, 3, Some(2), Some("Magic".to_string())).unwrap();
+
+        let advances = CharacterAdvanceRepository::get_by_character_id(&conn, 1).unwrap();
+        assert_eq!(advances[0].arcane_background_id, Some(2));
+
+        AdvancementService::undo_advance(&conn, 1).unwrap();
+
+        let character = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        let names: Vec<&str> = character
+            .arcane_backgrounds
+            .iter()
+            .map(|ab| ab.arcane_background.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Magic"]);
+    }
+
+    fn has_hindrance(conn: &Connection, character_id: i64, hindrance_id: i64) -> bool {
+        CharacterHindranceRepository::get_by_character_id(conn, character_id)
+            .unwrap()
+            .iter()
+            .any(|h| h.hindrance_id == hindrance_id)
+    }
+
+    #[test]
+    fn test_major_removal_records_each_half() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_hindrance(&conn, 1, "Wanted", "major", 2);
+        insert_test_character_hindrance(&conn, 1, 1, 1);
+
+        AdvancementService::apply_hindrance_advance(&conn, 1, 1, "remove_major_half", None).unwrap();
+        AdvancementService::apply_hindrance_advance(&conn, 1, 1, "remove_major_half", None).unwrap();
+
+        let actions: Vec<_> = CharacterAdvanceRepository::get_by_character_id(&conn, 1)
+            .unwrap()
+            .into_iter()
+            .map(|a| a.hindrance_action)
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                Some("remove_major_half".to_string()),
+                Some("complete_major_removal".to_string())
+            ]
+        );
+        assert!(!has_hindrance(&conn, 1, 1));
+    }
+
+    #[test]
+    fn test_undo_first_half_of_major_removal() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_hindrance(&conn, 1, "Wanted", "major", 2);
+        insert_test_character_hindrance(&conn, 1, 1, 1);
+
+        AdvancementService::apply_hindrance_advance(&conn, 1, 1, "remove_major_half", None).unwrap();
+        AdvancementService::undo_advance(&conn, 1).unwrap();
+
+        assert!(has_hindrance(&conn, 1, 1));
+        assert_eq!(
+            CharacterHindranceRepository::get_by_character_id(&conn, 1).unwrap().len(),
+            1
+        );
+        assert!(
+            CharacterAdvanceRepository::get_banked_hindrance_removal(&conn, 1, 1)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_undo_completing_half_of_major_removal() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_hindrance(&conn, 1, "Wanted", "major", 2);
+        insert_test_character_hindrance(&conn, 1, 1, 1);
+
+        AdvancementService::apply_hindrance_advance(&conn, 1, 1, "remove_major_half", None).unwrap();
+        AdvancementService::apply_hindrance_advance(&conn, 1, 1, "remove_major_half", None).unwrap();
+        AdvancementService::undo_advance(&conn, 1).unwrap();
+
+        // The hindrance is back and the first half is still banked
+        assert!(has_hindrance(&conn, 1, 1));
+        assert!(
+            CharacterAdvanceRepository::get_banked_hindrance_removal(&conn, 1, 1)
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_undo_first_half_after_hindrance_removed_elsewhere() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_hindrance(&conn, 1, "Wanted", "major", 2);
+        insert_test_character_hindrance(&conn, 1, 1, 1);
+
+        // Bank, complete, then the hindrance comes back by another path and is banked again
+        AdvancementService::apply_hindrance_advance(&conn, 1, 1, "remove_major_half", None).unwrap();
+        AdvancementService::apply_hindrance_advance(&conn, 1, 1, "remove_major_half", None).unwrap();
+        insert_test_character_hindrance(&conn, 2, 1, 1);
+        AdvancementService::apply_hindrance_advance(&conn, 1, 1, "remove_major_half", None).unwrap();
+
+        // The hindrance is then dropped outside of advancement
+        CharacterHindranceRepository::delete(&conn, 2).unwrap();
+
+        // Undoing a first half must not resurrect it
+        AdvancementService::undo_advance(&conn, 1).unwrap();
+
+        assert!(!has_hindrance(&conn, 1, 1));
+    }
+
+    #[test]
+    fn test_undo_legacy_completing_half_of_major_removal() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_hindrance(&conn, 1, "Wanted", "major", 2);
+
+        // Records written before halves were distinguished use the same action twice
+        for n in 1..=2 {
+            conn.execute(
+                "INSERT INTO character_advances (character_id, advance_number, advance_type,
+                                                hindrance_id, hindrance_action, created_at, updated_at)
+                 VALUES (1, ?, 'hindrance', 1, 'remove_major_half', '2024-01-01', '2024-01-01')",
+                params![n],
+            )
+            .unwrap();
+        }
+
+        AdvancementService::undo_advance(&conn, 1).unwrap();
+
+        assert!(has_hindrance(&conn, 1, 1));
+    }
+
+    #[test]
+    fn test_expensive_skill_advance_and_undo_round_trip() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 3);
+        insert_test_skill(&conn, 1, "Fighting", 1);
+        insert_character_skill(&conn, 1, 1, Some(10), 0);
+
+        let taken = AdvancementService::apply_expensive_skill_advance(&conn, 1, 1, None).unwrap();
+        assert_eq!(taken.description, "Increased Fighting to d12");
+        assert_eq!(stored_skill_die(&conn, 1, 1), Some(Die::d12()));
+
+        AdvancementService::undo_advance(&conn, 1).unwrap();
+        assert_eq!(stored_skill_die(&conn, 1, 1), Some(Die::d10()));
+    }
+
+    #[test]
+    fn test_undo_skill_advance_keeps_die_modifier() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 4);
+        insert_test_skill(&conn, 1, "Fighting", 1);
+        insert_character_skill(&conn, 1, 1, Some(12), 2);
+        conn.execute(
+            "INSERT INTO character_advances (character_id, advance_number, advance_type, skill_id_1,
+                                            created_at, updated_at)
+             VALUES (1, 1, 'skill_expensive', 1, '2024-01-01', '2024-01-01')",
+            [],
+        )
+        .unwrap();
+
+        AdvancementService::undo_advance(&conn, 1).unwrap();
+
+        assert_eq!(stored_skill_die(&conn, 1, 1), Die::with_modifier(12, 1));
+    }
+
+    #[test]
+    fn test_skill_advance_from_illegal_modified_die_and_undo() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 3);
+        insert_test_skill(&conn, 1, "Fighting", 1);
+        // Only a d12 can carry a modifier; a d10+1 is read as the d10 the sheet shows
+        insert_character_skill(&conn, 1, 1, Some(10), 1);
+
+        let taken = AdvancementService::apply_expensive_skill_advance(&conn, 1, 1, None).unwrap();
+        assert_eq!(taken.description, "Increased Fighting to d12");
+        assert_eq!(stored_skill_die(&conn, 1, 1), Some(Die::d12()));
+
+        AdvancementService::undo_advance(&conn, 1).unwrap();
+        assert_eq!(stored_skill_die(&conn, 1, 1), Some(Die::d10()));
+    }
+
+    #[test]
+    fn test_d12_plus_one_skill_advances_to_d12_plus_two_and_undoes() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 4);
+        insert_test_skill(&conn, 1, "Fighting", 1);
+        insert_character_skill(&conn, 1, 1, Some(12), 1);
+        conn.execute(
+            "UPDATE skills SET max_die_size = 12, max_die_modifier = 2 WHERE id = 1",
+            [],
+        )
+        .unwrap();
+
+        let taken = AdvancementService::apply_expensive_skill_advance(&conn, 1, 1, None).unwrap();
+        assert_eq!(taken.description, "Increased Fighting to d12+2");
+        assert_eq!(stored_skill_die(&conn, 1, 1), Die::with_modifier(12, 2));
+
+        AdvancementService::undo_advance(&conn, 1).unwrap();
+        assert_eq!(stored_skill_die(&conn, 1, 1), Die::with_modifier(12, 1));
+    }
+
+    #[test]
+    fn test_cheap_skill_advance_from_untrained_and_undo() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Smarts");
+        insert_character_attribute(&conn, 1, 1, 2);
+        insert_test_skill(&conn, 1, "Healing", 1);
+        insert_test_skill(&conn, 2, "Notice", 1);
+        insert_character_skill(&conn, 1, 1, None, 0);
+        insert_character_skill(&conn, 1, 2, Some(4), 0);
+
+        let taken = AdvancementService::apply_cheap_skill_advance(&conn, 1, 1, 2, None).unwrap();
+        assert_eq!(taken.description, "Increased Healing to d4 and Notice to d6");
+
+        AdvancementService::undo_advance(&conn, 1).unwrap();
+        assert_eq!(stored_skill_die(&conn, 1, 1), None);
+        assert_eq!(stored_skill_die(&conn, 1, 2), Some(Die::d4()));
     }
 }
@@ -0,0 +1,128 @@
+use rusqlite::Connection;
+
+use crate::error::{Result, SwadeError};
+use crate::models::Archetype;
+use crate::repositories::{ArchetypeRepository, AttributeRepository, SkillRepository};
+use crate::services::{EdgeService, HindranceService};
+use crate::views::{ArchetypeTraitView, ArchetypeView, Die};
+
+pub struct ArchetypeService;
+
+impl ArchetypeService {
+    pub fn get_all(conn: &Connection) -> Result<Vec<ArchetypeView>> {
+        ArchetypeRepository::get_all(conn)?
+            .into_iter()
+            .map(|archetype| Self::build_view(conn, archetype))
+            .collect()
+    }
+
+    /// Get an archetype by key (case-insensitive)
+    pub fn get_by_key(conn: &Connection, key: &str) -> Result<Option<ArchetypeView>> {
+        ArchetypeRepository::get_by_key(conn, key)?
+            .map(|archetype| Self::build_view(conn, archetype))
+            .transpose()
+    }
+
+    /// Resolve an archetype's suggestions. Suggestions pointing at rows that
+    /// no longer exist are left out.
+    fn build_view(conn: &Connection, archetype: Archetype) -> Result<ArchetypeView> {
+        let mut view = ArchetypeView {
+            id: archetype.id,
+            key: archetype.key,
+            name: archetype.name,
+            description: archetype.description,
+            attributes: vec![],
+            skills: vec![],
+            edges: vec![],
+            hindrances: vec![],
+        };
+
+        for suggestion in ArchetypeRepository::get_suggestions_by_archetype_id(conn, archetype.id)? {
+            let target_die = || {
+                suggestion
+                    .die_size
+                    .and_then(|size| Die::new(u8::try_from(size).ok()?))
+                    .ok_or_else(|| {
+                        SwadeError::Validation(format!(
+                            "Archetype {} suggests an invalid die ({:?})",
+                            view.name, suggestion.die_size
+                        ))
+                    })
+            };
+
+            match suggestion.suggestion_type.as_str() {
+                "attribute" => {
+                    if let Some(attribute) = AttributeRepository::get_by_id(conn, suggestion.target_id)? {
+                        let die = target_die()?;
+                        view.attributes.push(ArchetypeTraitView { id: attribute.id, name: attribute.name, die });
+                    }
+                }
+                "skill" => {
+                    if let Some(skill) = SkillRepository::get_by_id(conn, suggestion.target_id)? {
+                        let die = target_die()?;
+                        view.skills.push(ArchetypeTraitView { id: skill.id, name: skill.name, die });
+                    }
+                }
+                "edge" => view.edges.extend(EdgeService::get_by_id(conn, suggestion.target_id)?),
+                "hindrance" => view
+                    .hindrances
+                    .extend(HindranceService::get_by_id(conn, suggestion.target_id)?),
+                other => {
+                    return Err(SwadeError::Validation(format!(
+                        "Unknown archetype suggestion type: {}",
+                        other
+                    )));
+                }
+            }
+        }
+
+        Ok(view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        insert_test_archetype, insert_test_archetype_suggestion, insert_test_attribute,
+        insert_test_edge, insert_test_hindrance, insert_test_skill, setup_test_db,
+    };
+
+    #[test]
+    fn get_by_key_resolves_suggestions() {
+        let conn = setup_test_db();
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_skill(&conn, 1, "Fighting", 1);
+        insert_test_edge(&conn, 1, "Brawny", "Background");
+        insert_test_hindrance(&conn, 1, "Overconfident", "major", 2);
+        insert_test_archetype(&conn, 1, "brawler", "Brawler");
+        insert_test_archetype_suggestion(&conn, 1, 1, "hindrance", 1, None, 4);
+        insert_test_archetype_suggestion(&conn, 2, 1, "skill", 1, Some(8), 2);
+        insert_test_archetype_suggestion(&conn, 3, 1, "attribute", 1, Some(6), 1);
+        insert_test_archetype_suggestion(&conn, 4, 1, "edge", 1, None, 3);
+        // Points at a skill that doesn't exist
+        insert_test_archetype_suggestion(&conn, 5, 1, "skill", 99, Some(6), 5);
+
+        let archetype = ArchetypeService::get_by_key(&conn, "brawler").unwrap().unwrap();
+
+        assert_eq!(archetype.attributes.len(), 1);
+        assert_eq!(archetype.attributes[0].name, "Agility");
+        assert_eq!(archetype.attributes[0].die, Die::d6());
+        assert_eq!(archetype.skills.len(), 1);
+        assert_eq!(archetype.skills[0].die, Die::d8());
+        assert_eq!(archetype.edges[0].name, "Brawny");
+        assert_eq!(archetype.hindrances[0].name, "Overconfident");
+    }
+
+    #[test]
+    fn trait_suggestion_without_die_is_rejected() {
+        let conn = setup_test_db();
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_archetype(&conn, 1, "brawler", "Brawler");
+        insert_test_archetype_suggestion(&conn, 1, 1, "attribute", 1, None, 1);
+
+        let result = ArchetypeService::get_all(&conn);
+
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+    }
+}
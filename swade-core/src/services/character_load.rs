@@ -6,11 +6,13 @@
 use rusqlite::Connection;
 
 use crate::error::Result;
+use crate::models::NoteOrder;
 use crate::repositories::{
     AncestryChoiceRepository, AttributeRepository, CharacterAncestryChoiceRepository,
     CharacterArcaneBackgroundRepository, CharacterAttributeRepository, CharacterEdgeRepository,
     CharacterGearRepository, CharacterHindranceRepository, CharacterModifierRepository,
-    CharacterNoteRepository, CharacterPowerRepository, CharacterSkillRepository, ModifierRepository,
+    CharacterNoteRepository, CharacterPowerRepository, CharacterRepository, CharacterSkillRepository,
+    CompanionLinkRepository, ModifierRepository,
 };
 use crate::services::{
     ArcaneBackgroundService, EdgeService, GearService, HindranceService, PowerService, SkillService,
@@ -19,7 +21,7 @@ use crate::views::{
     AncestryChoiceOptionView, AncestryChoiceView, CharacterAncestryChoiceValue,
     CharacterArcaneBackgroundValue, CharacterAttributeValue, CharacterEdgeValue,
     CharacterGearValue, CharacterHindranceValue, CharacterNoteValue, CharacterPowerValue,
-    CharacterSkillValue, Die,
+    CharacterSkillValue, CompanionSummary, Die,
 };
 
 /// Load ancestry choices for a character.
@@ -58,9 +60,13 @@ pub fn load_ancestry_choices(
 }
 
 /// Load attributes for a character.
+///
+/// A negative stored step count is treated as zero, with a note added to
+/// `data_warnings`.
 pub fn load_attributes(
     conn: &Connection,
     character_id: i64,
+    data_warnings: &mut Vec<String>,
 ) -> Result<Vec<CharacterAttributeValue>> {
     let character_attributes =
         CharacterAttributeRepository::get_by_character_id(conn, character_id)?;
@@ -69,6 +75,12 @@ pub fn load_attributes(
     for ca in character_attributes {
         if let Some(attribute) = AttributeRepository::get_by_id(conn, ca.attribute_id)? {
             let attr_view = crate::views::AttributeView::new(attribute);
+            if ca.steps_incremented < 0 {
+                data_warnings.push(format!(
+                    "{} stored below its base die, clamped to {}",
+                    attr_view.name, attr_view.base_die
+                ));
+            }
             // Start with base die and increment for each step taken
             let mut die = attr_view.base_die;
             for _ in 0..ca.steps_incremented {
@@ -82,7 +94,14 @@ pub fn load_attributes(
 }
 
 /// Load skills for a character (all skills, with character's training applied).
-pub fn load_skills(conn: &Connection, character_id: i64) -> Result<Vec<CharacterSkillValue>> {
+///
+/// Stored dice that aren't real dice (a d14, or a d8+1) are clamped to the
+/// nearest legal die, with a note added to `data_warnings`.
+pub fn load_skills(
+    conn: &Connection,
+    character_id: i64,
+    data_warnings: &mut Vec<String>,
+) -> Result<Vec<CharacterSkillValue>> {
     // Load all skills from the database
     let all_skills = SkillService::get_all(conn)?;
 
@@ -100,8 +119,21 @@ pub fn load_skills(conn: &Connection, character_id: i64) -> Result<Vec<Character
         .into_iter()
         .map(|skill_view| {
             let die = char_skill_map.get(&skill_view.id).and_then(|cs| {
-                cs.current_die_size
-                    .and_then(|size| Die::with_modifier(size as u8, cs.current_die_modifier as u8))
+                let size = cs.current_die_size?;
+                let modifier = cs.current_die_modifier;
+                Some(legal_die(size, modifier).unwrap_or_else(|| {
+                    let clamped = nearest_legal_die(size);
+                    let stored = if modifier == 0 {
+                        format!("d{}", size)
+                    } else {
+                        format!("d{}{:+}", size, modifier)
+                    };
+                    data_warnings.push(format!(
+                        "{} stored as {}, clamped to {}",
+                        skill_view.name, stored, clamped
+                    ));
+                    clamped
+                }))
             });
             CharacterSkillValue::new(skill_view, die)
         })
@@ -110,6 +142,22 @@ pub fn load_skills(conn: &Connection, character_id: i64) -> Result<Vec<Character
     Ok(values)
 }
 
+/// The die stored as a size and modifier, or None if that isn't a legal die.
+pub(crate) fn legal_die(size: i64, modifier: i64) -> Option<Die> {
+    let size = u8::try_from(size).ok()?;
+    let modifier = u8::try_from(modifier).ok()?;
+    Die::with_modifier(size, modifier)
+}
+
+/// The largest legal die no bigger than `size` (d4 for anything smaller).
+pub(crate) fn nearest_legal_die(size: i64) -> Die {
+    [12, 10, 8, 6, 4]
+        .into_iter()
+        .find(|&legal| i64::from(legal) <= size)
+        .and_then(Die::new)
+        .unwrap_or(Die::d4())
+}
+
 /// Load edges for a character.
 pub fn load_edges(conn: &Connection, character_id: i64) -> Result<Vec<CharacterEdgeValue>> {
     let character_edges = CharacterEdgeRepository::get_by_character_id(conn, character_id)?;
@@ -158,7 +206,11 @@ pub fn load_arcane_backgrounds(
     let mut values = Vec::new();
     for cab in character_abs {
         if let Some(ab_view) = ArcaneBackgroundService::get_by_id(conn, cab.arcane_background_id)? {
-            values.push(CharacterArcaneBackgroundValue::new(ab_view, cab.advance_taken));
+            values.push(CharacterArcaneBackgroundValue::new(
+                ab_view,
+                cab.advance_taken,
+                cab.power_points_used,
+            ));
         }
     }
 
@@ -194,16 +246,47 @@ pub fn load_modifiers(conn: &Connection, character_id: i64) -> Result<Vec<crate:
     Ok(modifiers)
 }
 
-/// Load notes for a character (newest first).
+/// Load notes for a character (pinned first, then most recently updated).
 pub fn load_notes(conn: &Connection, character_id: i64) -> Result<Vec<CharacterNoteValue>> {
-    let notes = CharacterNoteRepository::get_by_character_id(conn, character_id)?;
+    let notes =
+        CharacterNoteRepository::get_by_character_id(conn, character_id, NoteOrder::default())?;
 
     Ok(notes
         .into_iter()
-        .map(|n| CharacterNoteValue::new(n.id, n.title, n.body, n.created_at, n.updated_at))
+        .map(|n| {
+            CharacterNoteValue::new(
+                n.id,
+                n.title,
+                n.body,
+                n.pinned,
+                n.category,
+                n.created_at,
+                n.updated_at,
+            )
+        })
         .collect())
 }
 
+/// Load summaries of the companions linked to a character.
+pub fn load_companions(conn: &Connection, character_id: i64) -> Result<Vec<CompanionSummary>> {
+    let links = CompanionLinkRepository::get_by_parent_id(conn, character_id)?;
+
+    let mut values = Vec::new();
+    for link in links {
+        if let Some(companion) = CharacterRepository::get_by_id(conn, link.child_character_id)? {
+            values.push(CompanionSummary::new(
+                link.id,
+                companion.id,
+                companion.name,
+                companion.is_wild_card,
+                link.relationship,
+            ));
+        }
+    }
+
+    Ok(values)
+}
+
 /// Load gear for a character.
 pub fn load_gear(conn: &Connection, character_id: i64) -> Result<Vec<CharacterGearValue>> {
     let character_gear = CharacterGearRepository::get_by_character_id(conn, character_id)?;
@@ -1,24 +1,31 @@
 //! Character service for managing character views.
 
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use image::{ImageFormat, imageops::FilterType};
 use rusqlite::Connection;
+use std::io::Cursor;
 
-use crate::constants::{SOURCE_CHOSEN, SOURCE_HINDRANCE_POINTS};
+use crate::constants::{
+    ATTRIBUTE_HINDRANCE_POINT_COST, ATTRIBUTE_STEP_COST, EDGE_HINDRANCE_POINT_COST,
+    MAX_CHARACTER_NAME_LENGTH, MAX_FATIGUE, MAX_HINDRANCE_POINTS, MAX_PORTRAIT_DIMENSION,
+    PORTRAIT_MIME_TYPE, SKILL_HINDRANCE_POINT_RATIO, SOURCE_CHOSEN, SOURCE_HINDRANCE_POINTS, STARTING_WEALTH, WEALTH_PER_HINDRANCE_POINT,
+};
 use crate::error::{Result, SwadeError};
 use crate::models::{
     Character, CharacterArcaneBackground, CharacterAttribute, CharacterEdge, CharacterHindrance,
-    CharacterPower, CharacterSkill,
+    CharacterPower, CharacterSkill, CompanionLink,
 };
 use crate::repositories::{
-    AttributeRepository, CharacterAdvanceRepository, CharacterAncestryChoiceRepository,
+    AttributeRepository, CharacterAncestryChoiceRepository,
     CharacterArcaneBackgroundRepository, CharacterAttributeRepository, CharacterEdgeRepository,
     CharacterHindranceRepository, CharacterPowerRepository, CharacterRepository,
-    CharacterSkillRepository, RankRepository, SkillRepository,
+    CharacterSkillRepository, CompanionLinkRepository, RankRepository, SkillRepository,
 };
-use crate::services::AncestryService;
+use crate::services::{AdvancementService, AncestryService, SkillService};
 use crate::views::{
-    CharacterAttributeValue, CharacterSkillValue, CharacterView, DerivedStatsView, Die,
-    EncumbranceInfo,
+    CharacterAttributeValue, CharacterConfig, CharacterDiff, CharacterSkillValue, CharacterView,
+    CompanionSummary, DerivedStatsView, Die, DraftValidation, EncumbranceInfo, PowerSlots,
+    RequirementContext,
 };
 
 use super::character_load;
@@ -26,6 +33,64 @@ use super::character_sync;
 
 pub struct CharacterService;
 
+/// Hindrance points earned by a draft and how they're split across the four
+/// allocation pools.
+///
+/// Every allocation is checked against this so the pools together never exceed
+/// the points earned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HindrancePointBudget {
+    pub earned: i64,
+    pub to_edges: i64,
+    /// Attribute points bought, not hindrance points.
+    pub to_attributes: i64,
+    pub to_skills: i64,
+    pub to_wealth: i64,
+}
+
+impl HindrancePointBudget {
+    pub fn from_draft(draft: &CharacterView) -> Self {
+        Self {
+            earned: draft.hindrance_points_earned,
+            to_edges: draft.hindrance_points_to_edges,
+            to_attributes: draft.hindrance_points_to_attributes,
+            to_skills: draft.hindrance_points_to_skills,
+            to_wealth: draft.hindrance_points_to_wealth,
+        }
+    }
+
+    /// Hindrance points allocated across all four pools.
+    pub fn allocated(&self) -> i64 {
+        self.to_edges
+            + self.to_attributes * ATTRIBUTE_HINDRANCE_POINT_COST
+            + self.to_skills
+            + self.to_wealth
+    }
+
+    /// Hindrance points not yet allocated.
+    pub fn available(&self) -> i64 {
+        self.earned - self.allocated()
+    }
+
+    /// Check that `cost` more hindrance points can be allocated.
+    pub fn check_allocation(&self, cost: i64) -> Result<()> {
+        if cost <= self.available() {
+            return Ok(());
+        }
+
+        Err(SwadeError::Validation(format!(
+            "Not enough hindrance points. Need {}, have {} ({} earned: {} to edges, {} to attributes, {} to skills, {} to wealth)",
+            cost,
+            self.available(),
+            self.earned,
+            self.to_edges,
+            self.to_attributes * ATTRIBUTE_HINDRANCE_POINT_COST,
+            self.to_skills,
+            self.to_wealth
+        )))
+    }
+}
+
 /// Convert portrait bytes and mime type to a data URL.
 fn portrait_to_data_url(portrait: Option<&[u8]>, mime_type: Option<&str>) -> Option<String> {
     match (portrait, mime_type) {
@@ -37,6 +102,34 @@ fn portrait_to_data_url(portrait: Option<&[u8]>, mime_type: Option<&str>) -> Opt
     }
 }
 
+/// Decode an uploaded portrait, shrink it to fit within
+/// `MAX_PORTRAIT_DIMENSION` and re-encode it as PNG.
+fn normalize_portrait(image_bytes: &[u8]) -> Result<Vec<u8>> {
+    let image = image::load_from_memory(image_bytes)
+        .map_err(|e| SwadeError::Validation(format!("Portrait is not a valid image: {}", e)))?;
+
+    let image = if image.width() > MAX_PORTRAIT_DIMENSION || image.height() > MAX_PORTRAIT_DIMENSION {
+        image.resize(MAX_PORTRAIT_DIMENSION, MAX_PORTRAIT_DIMENSION, FilterType::Triangle)
+    } else {
+        image
+    };
+
+    let mut normalized = Cursor::new(Vec::new());
+    image
+        .write_to(&mut normalized, ImageFormat::Png)
+        .map_err(|e| SwadeError::Validation(format!("Could not encode portrait: {}", e)))?;
+    Ok(normalized.into_inner())
+}
+
+/// Quote a CSV field if it contains a comma, quote, or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
 impl CharacterService {
     /// Get all characters.
     pub fn get_all(conn: &Connection) -> Result<Vec<CharacterView>> {
@@ -52,16 +145,128 @@ impl CharacterService {
         Ok(views)
     }
 
+    /// Get all characters in a campaign.
+    pub fn get_by_campaign(conn: &Connection, campaign: &str) -> Result<Vec<CharacterView>> {
+        let characters = CharacterRepository::get_characters_by_campaign(conn, campaign)?;
+
+        let mut views = Vec::new();
+        for character in characters {
+            if let Some(view) = Self::get_by_id(conn, character.id)? {
+                views.push(view);
+            }
+        }
+
+        Ok(views)
+    }
+
     /// Get a character by ID.
+    ///
+    /// The portrait is left out to keep loads cheap; fetch it with
+    /// `get_portrait` or load the character with `get_by_id_with_portrait`.
     pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<CharacterView>> {
+        Self::load(conn, id, false)
+    }
+
+    /// Compare two saved characters' effective sheets, `before_id` against `after_id`.
+    pub fn diff(conn: &Connection, before_id: i64, after_id: i64) -> Result<CharacterDiff> {
+        let before = Self::get_by_id(conn, before_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Character {}", before_id)))?;
+        let after = Self::get_by_id(conn, after_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Character {}", after_id)))?;
+        Ok(CharacterDiff::between(&before, &after))
+    }
+
+    /// Get a character by ID with its portrait data URL filled in.
+    pub fn get_by_id_with_portrait(conn: &Connection, id: i64) -> Result<Option<CharacterView>> {
+        Self::load(conn, id, true)
+    }
+
+    /// Build the requirement context for a saved character.
+    ///
+    /// Uses the same `to_requirement_context` as drafts, so an edge or power
+    /// checks the same way during advancement as it did at creation.
+    pub fn requirement_context(conn: &Connection, id: i64) -> Result<RequirementContext> {
+        let character = Self::get_by_id(conn, id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Character with id {}", id)))?;
+        Ok(character.to_requirement_context())
+    }
+
+    /// Get a character's portrait as a data URL, or None if it has none.
+    pub fn get_portrait(conn: &Connection, id: i64) -> Result<Option<String>> {
+        let character = CharacterRepository::get_by_id(conn, id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+
+        Ok(portrait_to_data_url(
+            character.portrait.as_deref(),
+            character.portrait_mime_type.as_deref(),
+        ))
+    }
+
+    /// Export every active character as CSV, one row per character.
+    ///
+    /// Columns are name, rank, ancestry, the five attributes, Pace, Parry,
+    /// Toughness, and advance count. Rows come from one roster query that
+    /// skips portraits, and only the parts of the sheet the columns depend
+    /// on are loaded.
+    pub fn export_roster_csv(conn: &Connection) -> Result<String> {
+        const ATTRIBUTES: [&str; 5] = ["Agility", "Smarts", "Spirit", "Strength", "Vigor"];
+
+        let mut csv = String::from(
+            "Name,Rank,Ancestry,Agility,Smarts,Spirit,Strength,Vigor,Pace,Parry,Toughness,Advances\n",
+        );
+        for row in CharacterRepository::get_all_without_portraits(conn)? {
+            let character = Self::build_view(conn, row, false, false)?;
+            let mut fields = vec![
+                csv_field(&character.name),
+                csv_field(&character.rank.name),
+                csv_field(character.ancestry.as_ref().map_or("", |a| a.name.as_str())),
+            ];
+            fields.extend(ATTRIBUTES.iter().map(|name| {
+                character
+                    .attributes
+                    .iter()
+                    .find(|a| a.attribute.name == *name)
+                    .map(|a| a.effective_die.to_string())
+                    .unwrap_or_default()
+            }));
+            fields.extend([
+                character.derived_stats.pace.to_string(),
+                character.derived_stats.parry.to_string(),
+                character.derived_stats.toughness.to_string(),
+                character.current_advances.to_string(),
+            ]);
+
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+
+        Ok(csv)
+    }
+
+    fn load(conn: &Connection, id: i64, include_portrait: bool) -> Result<Option<CharacterView>> {
         let character = match CharacterRepository::get_by_id(conn, id)? {
             Some(c) => c,
             None => return Ok(None),
         };
 
-        // Count advances and derive rank
-        let current_advances = CharacterAdvanceRepository::count_by_character_id(conn, id)?;
-        let rank = Self::get_rank_for_advances(conn, current_advances)?;
+        Self::build_view(conn, character, include_portrait, true).map(Some)
+    }
+
+    /// Build the view for a saved character row.
+    ///
+    /// With `include_extras` false, notes, companions, powers and arcane
+    /// backgrounds are left empty. No effective die or derived stat depends
+    /// on them, so sheet numbers come out the same.
+    fn build_view(
+        conn: &Connection,
+        character: Character,
+        include_portrait: bool,
+        include_extras: bool,
+    ) -> Result<CharacterView> {
+        let id = character.id;
+
+        // Count advances (or convert XP, when tracked) and derive rank
+        let (current_advances, rank) = AdvancementService::advances_and_rank_for(conn, &character)?;
 
         // Load ancestry if present
         let ancestry = match character.ancestry_id {
@@ -71,21 +276,33 @@ impl CharacterService {
 
         // Load all related data using helper functions
         let ancestry_choices = character_load::load_ancestry_choices(conn, id)?;
-        let attributes = character_load::load_attributes(conn, id)?;
-        let skills = character_load::load_skills(conn, id)?;
+        let mut data_warnings = Vec::new();
+        let attributes = character_load::load_attributes(conn, id, &mut data_warnings)?;
+        let skills = character_load::load_skills(conn, id, &mut data_warnings)?;
         let edges = character_load::load_edges(conn, id)?;
         let hindrances = character_load::load_hindrances(conn, id)?;
-        let arcane_backgrounds = character_load::load_arcane_backgrounds(conn, id)?;
-        let powers = character_load::load_powers(conn, id)?;
         let modifiers = character_load::load_modifiers(conn, id)?;
-        let notes = character_load::load_notes(conn, id)?;
         let gear = character_load::load_gear(conn, id)?;
+        let (arcane_backgrounds, powers, notes, companions) = if include_extras {
+            (
+                character_load::load_arcane_backgrounds(conn, id)?,
+                character_load::load_powers(conn, id)?,
+                character_load::load_notes(conn, id)?,
+                character_load::load_companions(conn, id)?,
+            )
+        } else {
+            (vec![], vec![], vec![], vec![])
+        };
 
-        // Convert portrait to data URL
-        let portrait_data_url = portrait_to_data_url(
-            character.portrait.as_deref(),
-            character.portrait_mime_type.as_deref(),
-        );
+        // Convert portrait to data URL only when asked for
+        let portrait_data_url = if include_portrait {
+            portrait_to_data_url(
+                character.portrait.as_deref(),
+                character.portrait_mime_type.as_deref(),
+            )
+        } else {
+            None
+        };
 
         let mut character_view = CharacterView {
             id: character.id,
@@ -104,13 +321,22 @@ impl CharacterService {
             powers,
             power_points: character.power_points,
             power_points_used: character.power_points_used,
+            power_points_base: 0,
+            power_points_bonus: 0,
+            power_point_pools: vec![],
+            power_slots: PowerSlots::default(),
             wounds: character.wounds,
             fatigue: character.fatigue,
+            incapacitated: false,
+            experience_points: character.experience_points,
+            revision: character.revision,
             notes,
+            companions,
             gear,
             encumbrance: EncumbranceInfo::empty(4), // Will be computed by compute_effective_values
             modifiers,
             derived_stats: DerivedStatsView::default(),
+            situational_modifiers: vec![],
             attribute_points_spent: character.attribute_points_spent,
             attribute_points_earned: character.attribute_points_earned,
             skill_points_spent: character.skill_points_spent,
@@ -122,15 +348,30 @@ impl CharacterService {
             hindrance_points_to_skills: character.hindrance_points_to_skills,
             hindrance_points_to_wealth: character.hindrance_points_to_wealth,
             wealth: character.wealth,
+            net_worth: character.wealth,
             background: character.background,
             description: character.description,
+            campaign: character.campaign,
             portrait_data_url,
+            config: CharacterConfig::from_columns(
+                character.max_attribute_die_size,
+                character.max_attribute_die_modifier,
+                character.allow_hindrances_past_cap,
+                character.load_limit_multiplier,
+                character.parry_skill,
+                character.max_wounds,
+                character.track_experience,
+                character.sell_back_percent,
+                &character.weight_unit,
+            ),
+            data_warnings,
         };
 
         // Compute effective values based on modifiers
+        character_view.clamp_dice_to_caps();
         character_view.compute_effective_values();
 
-        Ok(Some(character_view))
+        Ok(character_view)
     }
 
     /// Build a new character in memory with default attributes and core skills (does not persist).
@@ -153,16 +394,15 @@ impl CharacterService {
             .collect();
 
         // Build all skills - core skills at d4, non-core skills untrained (None)
-        let all_skills = SkillRepository::get_all(conn)?;
+        let all_skills = SkillService::get_all(conn)?;
         let skill_values: Vec<CharacterSkillValue> = all_skills
             .into_iter()
-            .map(|skill| {
-                let die = if skill.is_core_skill {
+            .map(|skill_view| {
+                let die = if skill_view.is_core_skill {
                     Some(Die::d4())
                 } else {
                     None
                 };
-                let skill_view = crate::views::SkillView::new(skill);
                 CharacterSkillValue::new(skill_view, die)
             })
             .collect();
@@ -184,13 +424,22 @@ impl CharacterService {
             powers: vec![],
             power_points: 0,
             power_points_used: 0,
+            power_points_base: 0,
+            power_points_bonus: 0,
+            power_point_pools: vec![],
+            power_slots: PowerSlots::default(),
             wounds: 0,
             fatigue: 0,
+            incapacitated: false,
+            experience_points: 0,
+            revision: 0,
             notes: vec![],
+            companions: vec![],
             gear: vec![],
             encumbrance: EncumbranceInfo::empty(4), // Will be computed by compute_effective_values
             modifiers: vec![],
             derived_stats: DerivedStatsView::default(),
+            situational_modifiers: vec![],
             attribute_points_spent: 0,
             attribute_points_earned: 5, // Standard starting attribute points
             skill_points_spent: 0,
@@ -201,10 +450,14 @@ impl CharacterService {
             hindrance_points_to_attributes: 0,
             hindrance_points_to_skills: 0,
             hindrance_points_to_wealth: 0,
-            wealth: 500,
+            wealth: STARTING_WEALTH,
+            net_worth: STARTING_WEALTH,
             background: None,
             description: None,
+            campaign: None,
             portrait_data_url: None,
+            config: CharacterConfig::default(),
+            data_warnings: vec![],
         };
 
         // Compute effective values (no-op for new character without modifiers)
@@ -213,8 +466,474 @@ impl CharacterService {
         Ok(character_view)
     }
 
+    /// Reset a draft to a fresh character while keeping its identity.
+    ///
+    /// Everything `build_new` would produce replaces the draft's choices and point
+    /// spending. The id, name, wild card status, background, description, campaign,
+    /// portrait, notes, companions, and house rule config are carried over.
+    pub fn reset(conn: &Connection, draft: &CharacterView) -> Result<CharacterView> {
+        let mut character = Self::build_new(conn, draft.name.clone())?;
+
+        character.id = draft.id;
+        character.is_wild_card = draft.is_wild_card;
+        character.background = draft.background.clone();
+        character.description = draft.description.clone();
+        character.campaign = draft.campaign.clone();
+        character.portrait_data_url = draft.portrait_data_url.clone();
+        character.notes = draft.notes.clone();
+        character.companions = draft.companions.clone();
+        character.config = draft.config.clone();
+
+        character.compute_effective_values();
+
+        Ok(character)
+    }
+
+    /// Move hindrance points into edge purchases. Negative points give them back.
+    ///
+    /// Points are allocated in multiples of the edge cost, and can't be returned
+    /// while edges bought with them are still on the draft.
+    pub fn allocate_hindrance_points_to_edges(
+        draft: &mut CharacterView,
+        points: i64,
+    ) -> Result<()> {
+        if points < 0 {
+            let new_total = Self::deallocated_total(draft.hindrance_points_to_edges, points)?;
+            let edges_from_hindrance_points = draft
+                .edges
+                .iter()
+                .filter(|e| e.source == SOURCE_HINDRANCE_POINTS)
+                .count() as i64;
+            if new_total < edges_from_hindrance_points * EDGE_HINDRANCE_POINT_COST {
+                return Err(SwadeError::Validation(
+                    "Cannot deallocate: points already spent on edges. Remove edges first."
+                        .to_string(),
+                ));
+            }
+            draft.hindrance_points_to_edges = new_total;
+        } else {
+            if points % EDGE_HINDRANCE_POINT_COST != 0 {
+                return Err(SwadeError::Validation(format!(
+                    "Must allocate hindrance points to edges in multiples of {}",
+                    EDGE_HINDRANCE_POINT_COST
+                )));
+            }
+            HindrancePointBudget::from_draft(draft).check_allocation(points)?;
+            draft.hindrance_points_to_edges += points;
+        }
+
+        Ok(())
+    }
+
+    /// Convert hindrance points into attribute points. `points` counts attribute
+    /// points, each costing `ATTRIBUTE_HINDRANCE_POINT_COST` hindrance points.
+    pub fn allocate_hindrance_points_to_attributes(
+        draft: &mut CharacterView,
+        points: i64,
+    ) -> Result<()> {
+        if points < 0 {
+            let new_total = Self::deallocated_total(draft.hindrance_points_to_attributes, points)?;
+            if draft.attribute_points_spent > draft.attribute_points_earned + new_total {
+                return Err(SwadeError::Validation(
+                    "Cannot deallocate: attribute points already spent".to_string(),
+                ));
+            }
+            draft.hindrance_points_to_attributes = new_total;
+        } else {
+            HindrancePointBudget::from_draft(draft)
+                .check_allocation(points * ATTRIBUTE_HINDRANCE_POINT_COST)?;
+            draft.hindrance_points_to_attributes += points;
+        }
+
+        draft.compute_effective_values();
+        Ok(())
+    }
+
+    /// Convert hindrance points into skill points one for one. Negative points
+    /// give them back.
+    pub fn allocate_hindrance_points_to_skills(
+        draft: &mut CharacterView,
+        points: i64,
+    ) -> Result<()> {
+        if points < 0 {
+            let new_total = Self::deallocated_total(draft.hindrance_points_to_skills, points)?;
+            if draft.skill_points_spent > draft.skill_points_earned + new_total {
+                return Err(SwadeError::Validation(
+                    "Cannot deallocate: skill points already spent".to_string(),
+                ));
+            }
+            draft.hindrance_points_to_skills = new_total;
+        } else {
+            HindrancePointBudget::from_draft(draft)
+                .check_allocation(points * SKILL_HINDRANCE_POINT_RATIO)?;
+            draft.hindrance_points_to_skills += points;
+        }
+
+        draft.compute_effective_values();
+        Ok(())
+    }
+
+    /// Put every draft attribute back at its base die and refund the points.
+    ///
+    /// Hindrance points converted to attributes stay converted, so they are
+    /// available again to spend.
+    pub fn reset_attributes(draft: &mut CharacterView) {
+        for attr_value in &mut draft.attributes {
+            attr_value.die = attr_value.attribute.base_die;
+        }
+        draft.attribute_points_spent = 0;
+        draft.compute_effective_values();
+    }
+
+    /// Put core skills back at d4 and untrain the rest, refunding the points.
+    ///
+    /// Hindrance points converted to skills stay converted, so they are
+    /// available again to spend.
+    pub fn reset_skills(draft: &mut CharacterView) {
+        for skill_value in &mut draft.skills {
+            skill_value.die = skill_value.skill.is_core_skill.then(Die::d4);
+        }
+        draft.skill_points_spent = 0;
+        draft.compute_effective_values();
+    }
+
+    /// Set several draft attributes to target dice at once.
+    ///
+    /// Each step above an attribute's base die costs one attribute point. Only
+    /// the final state is checked against the points available (including
+    /// hindrance points converted to attributes), so a step can be moved from
+    /// one attribute to another in one call. Nothing changes if any target is
+    /// invalid.
+    pub fn set_attribute_dice(draft: &mut CharacterView, targets: &[(i64, Die)]) -> Result<()> {
+        let mut seen = std::collections::HashSet::new();
+        let mut point_change = 0;
+        for &(attribute_id, target) in targets {
+            if !seen.insert(attribute_id) {
+                return Err(SwadeError::Validation(format!(
+                    "Attribute {} is listed more than once",
+                    attribute_id
+                )));
+            }
+            let attr_value = draft
+                .attributes
+                .iter()
+                .find(|a| a.attribute.id == attribute_id)
+                .ok_or_else(|| SwadeError::NotFound(format!("Attribute with id {}", attribute_id)))?;
+            let attribute = &attr_value.attribute;
+
+            if target < attribute.base_die {
+                return Err(SwadeError::Validation(format!(
+                    "{} cannot go below {}",
+                    attribute.name, attribute.base_die
+                )));
+            }
+            let cap = draft
+                .attribute_die_cap(attribute_id)
+                .unwrap_or_else(|| draft.config.attribute_die_cap());
+            if target > cap {
+                return Err(SwadeError::Validation(format!(
+                    "{} cannot be raised above {}",
+                    attribute.name, cap
+                )));
+            }
+
+            point_change += attr_value.die.steps_to(target) as i64 * ATTRIBUTE_STEP_COST;
+        }
+
+        let available = draft.attribute_points_earned + draft.hindrance_points_to_attributes;
+        let spent = draft.attribute_points_spent + point_change;
+        if spent > available {
+            return Err(SwadeError::Validation(format!(
+                "Not enough attribute points: needs {}, {} available",
+                spent, available
+            )));
+        }
+
+        for &(attribute_id, target) in targets {
+            if let Some(attr_value) =
+                draft.attributes.iter_mut().find(|a| a.attribute.id == attribute_id)
+            {
+                attr_value.die = target;
+            }
+        }
+        draft.attribute_points_spent = spent;
+        draft.compute_effective_values();
+        Ok(())
+    }
+
+    /// Set several draft skills to target dice at once (None leaves a skill
+    /// untrained).
+    ///
+    /// Only the final state is checked against the available skill points, so
+    /// points can be moved between skills in one step without tripping the
+    /// limit halfway through. Nothing changes if any target is invalid.
+    pub fn set_skill_dice(draft: &mut CharacterView, targets: &[(i64, Option<Die>)]) -> Result<()> {
+        let mut seen = std::collections::HashSet::new();
+        let mut point_change = 0;
+        for &(skill_id, target) in targets {
+            if !seen.insert(skill_id) {
+                return Err(SwadeError::Validation(format!(
+                    "Skill {} is listed more than once",
+                    skill_id
+                )));
+            }
+            let skill_value = draft
+                .skills
+                .iter()
+                .find(|s| s.skill.id == skill_id)
+                .ok_or_else(|| SwadeError::NotFound(format!("Skill with id {}", skill_id)))?;
+            let skill = &skill_value.skill;
+
+            match target {
+                None if skill.is_core_skill => {
+                    return Err(SwadeError::Validation(format!(
+                        "{} is a core skill and cannot go below d4",
+                        skill.name
+                    )));
+                }
+                Some(die) => {
+                    let cap = draft.skill_die_cap(skill_id).unwrap_or(skill.max_die);
+                    if die > cap {
+                        return Err(SwadeError::Validation(format!(
+                            "{} cannot be raised above {}",
+                            skill.name, cap
+                        )));
+                    }
+                }
+                None => {}
+            }
+
+            let linked_die = draft
+                .attributes
+                .iter()
+                .find(|a| a.attribute.id == skill.linked_attribute_id)
+                .map(|a| a.effective_die)
+                .ok_or_else(|| SwadeError::NotFound("Linked attribute".to_string()))?;
+            point_change += Self::skill_die_cost(skill.is_core_skill, target, linked_die)
+                - Self::skill_die_cost(skill.is_core_skill, skill_value.die, linked_die);
+        }
+
+        let available = draft.skill_points_earned + draft.hindrance_points_to_skills;
+        let spent = draft.skill_points_spent + point_change;
+        if spent > available {
+            return Err(SwadeError::Validation(format!(
+                "Not enough skill points: needs {}, {} available",
+                spent, available
+            )));
+        }
+
+        for &(skill_id, target) in targets {
+            if let Some(skill_value) = draft.skills.iter_mut().find(|s| s.skill.id == skill_id) {
+                skill_value.die = target;
+            }
+        }
+        draft.skill_points_spent = spent;
+        draft.compute_effective_values();
+        Ok(())
+    }
+
+    /// Skill points needed to take a skill from its starting die to `die`,
+    /// paying double for steps above the linked attribute's die.
+    ///
+    /// Core skills start at d4 for free; others pay 1 to reach d4.
+    pub(crate) fn skill_die_cost(is_core_skill: bool, die: Option<Die>, linked_die: Die) -> i64 {
+        let Some(target) = die else {
+            return 0;
+        };
+        let mut current = Die::d4();
+        let mut cost = if is_core_skill { 0 } else { 1 };
+        while current < target {
+            current = current.increment();
+            cost += if current > linked_die { 2 } else { 1 };
+        }
+        cost
+    }
+
+    /// Convert hindrance points into starting funds. Negative points give them back.
+    ///
+    /// Each point adds `WEALTH_PER_HINDRANCE_POINT` to wealth. Points can't be
+    /// returned once the funds have been spent.
+    pub fn allocate_hindrance_points_to_wealth(
+        draft: &mut CharacterView,
+        points: i64,
+    ) -> Result<()> {
+        let wealth_change = points * WEALTH_PER_HINDRANCE_POINT;
+
+        if points < 0 {
+            let new_total = Self::deallocated_total(draft.hindrance_points_to_wealth, points)?;
+            if draft.wealth + wealth_change < 0 {
+                return Err(SwadeError::Validation(
+                    "Cannot deallocate: starting funds already spent".to_string(),
+                ));
+            }
+            draft.hindrance_points_to_wealth = new_total;
+        } else {
+            HindrancePointBudget::from_draft(draft).check_allocation(points)?;
+            draft.hindrance_points_to_wealth += points;
+        }
+
+        draft.wealth += wealth_change;
+        Ok(())
+    }
+
+    /// Pool total after giving back points, rejecting more than were allocated.
+    fn deallocated_total(allocated: i64, points: i64) -> Result<i64> {
+        let new_total = allocated + points;
+        if new_total < 0 {
+            return Err(SwadeError::Validation(
+                "Cannot deallocate more points than allocated".to_string(),
+            ));
+        }
+        Ok(new_total)
+    }
+
+    /// Check a draft against the character creation rules.
+    ///
+    /// Covers overspent attribute, skill, and hindrance points, hindrances past
+    /// the cap, dice above their caps, edges bought without enough hindrance
+    /// points, edges whose requirements aren't met, and powers beyond the
+    /// starting slots or without an arcane background. Unspent attribute and
+    /// skill points are reported as warnings.
+    pub fn validate_draft(draft: &CharacterView) -> DraftValidation {
+        let mut errors = Vec::new();
+
+        let attribute_points = draft.attribute_points_earned + draft.hindrance_points_to_attributes;
+        if draft.attribute_points_spent > attribute_points {
+            errors.push(format!(
+                "Attribute points overspent ({}/{})",
+                draft.attribute_points_spent, attribute_points
+            ));
+        }
+
+        let skill_points = draft.skill_points_earned + draft.hindrance_points_to_skills;
+        if draft.skill_points_spent > skill_points {
+            errors.push(format!(
+                "Skill points overspent ({}/{})",
+                draft.skill_points_spent, skill_points
+            ));
+        }
+
+        let budget = HindrancePointBudget::from_draft(draft);
+        if budget.available() < 0 {
+            errors.push(format!(
+                "Hindrance points overallocated ({}/{})",
+                budget.allocated(),
+                budget.earned
+            ));
+        }
+
+        let earned = draft.earned_hindrance_points();
+        if !draft.config.allow_hindrances_past_cap && earned > MAX_HINDRANCE_POINTS {
+            errors.push(format!(
+                "Hindrance points exceed maximum ({}/{})",
+                earned, MAX_HINDRANCE_POINTS
+            ));
+        }
+
+        for attr in &draft.attributes {
+            if let Some(cap) = draft.attribute_die_cap(attr.attribute.id)
+                && attr.die > cap
+            {
+                errors.push(format!("{} is above its maximum ({})", attr.attribute.name, cap));
+            }
+        }
+
+        for skill in &draft.skills {
+            if let (Some(die), Some(cap)) = (skill.die, draft.skill_die_cap(skill.skill.id))
+                && die > cap
+            {
+                errors.push(format!("{} is above its maximum ({})", skill.skill.name, cap));
+            }
+        }
+
+        let edges_from_hindrance_points = draft
+            .edges
+            .iter()
+            .filter(|e| e.source == SOURCE_HINDRANCE_POINTS)
+            .count() as i64;
+        if edges_from_hindrance_points * EDGE_HINDRANCE_POINT_COST > draft.hindrance_points_to_edges {
+            errors.push(format!(
+                "{} edges bought with only {} hindrance points allocated to edges",
+                edges_from_hindrance_points, draft.hindrance_points_to_edges
+            ));
+        }
+
+        let slots = draft.power_slots();
+        if slots.used > slots.total {
+            errors.push(format!(
+                "Too many starting powers ({}/{})",
+                slots.used, slots.total
+            ));
+        }
+
+        errors.extend(draft.unmet_edge_requirements());
+        errors.extend(Self::incomplete_ancestry_choices(draft));
+        errors.extend(Self::powers_without_arcane_background(draft));
+
+        DraftValidation {
+            errors,
+            warnings: Self::unspent_point_warnings(draft),
+        }
+    }
+
+    /// Describe attribute and skill points the draft has left to spend.
+    ///
+    /// Saving is allowed with points unspent; these let the UI remind the
+    /// player before they lose track of them.
+    pub fn unspent_point_warnings(draft: &CharacterView) -> Vec<String> {
+        let pools = [
+            (
+                "attribute",
+                draft.attribute_points_earned + draft.hindrance_points_to_attributes
+                    - draft.attribute_points_spent,
+            ),
+            (
+                "skill",
+                draft.skill_points_earned + draft.hindrance_points_to_skills - draft.skill_points_spent,
+            ),
+        ];
+        pools
+            .into_iter()
+            .filter(|(_, remaining)| *remaining > 0)
+            .map(|(pool, remaining)| {
+                let plural = if remaining == 1 { "" } else { "s" };
+                format!("{} {} point{} unspent", remaining, pool, plural)
+            })
+            .collect()
+    }
+
+    /// Describe each ancestry choice still short of its minimum selections.
+    fn incomplete_ancestry_choices(character: &CharacterView) -> Vec<String> {
+        character
+            .pending_ancestry_choices()
+            .into_iter()
+            .map(|p| {
+                format!(
+                    "Ancestry choice incomplete: {} ({} more to select)",
+                    p.description, p.remaining
+                )
+            })
+            .collect()
+    }
+
+    /// Describe powers left behind without an arcane background to use them,
+    /// e.g. after the background was removed from a draft.
+    fn powers_without_arcane_background(character: &CharacterView) -> Option<String> {
+        if character.powers.is_empty() || !character.arcane_backgrounds.is_empty() {
+            return None;
+        }
+        let names: Vec<&str> = character.powers.iter().map(|p| p.power.name.as_str()).collect();
+        Some(format!("Powers known without an arcane background: {}", names.join(", ")))
+    }
+
     /// Save a character view to the database (persists a new character or updates existing).
+    ///
+    /// Refuses with `Validation` while any ancestry choice is short of its
+    /// minimum selections or powers are known without an arcane background,
+    /// so a half-configured character is never persisted.
     pub fn save(conn: &Connection, character: &CharacterView) -> Result<CharacterView> {
+        Self::check_saveable(character)?;
+
         // Use a transaction for atomicity
         conn.execute("BEGIN TRANSACTION", [])?;
 
@@ -232,13 +951,41 @@ impl CharacterService {
         }
     }
 
-    fn save_inner(conn: &Connection, character: &CharacterView) -> Result<CharacterView> {
-        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    /// The checks `save` runs before writing anything.
+    pub(crate) fn check_saveable(character: &CharacterView) -> Result<()> {
+        let incomplete = Self::incomplete_ancestry_choices(character);
+        if !incomplete.is_empty() {
+            return Err(SwadeError::Validation(incomplete.join("; ")));
+        }
+        if let Some(orphaned) = Self::powers_without_arcane_background(character) {
+            return Err(SwadeError::Validation(orphaned));
+        }
+        Ok(())
+    }
+
+    /// Fail with `Conflict` if the stored character was written after
+    /// `character` was loaded, e.g. by a save from another window.
+    ///
+    /// Unsaved drafts (id 0) and characters that no longer exist always pass.
+    pub fn check_not_stale(conn: &Connection, character: &CharacterView) -> Result<()> {
+        if character.id == 0 {
+            return Ok(());
+        }
+        match CharacterRepository::get_by_id(conn, character.id)? {
+            Some(stored) if stored.revision != character.revision => Err(SwadeError::Conflict(format!(
+                "{} was changed elsewhere since it was loaded",
+                stored.name
+            ))),
+            _ => Ok(()),
+        }
+    }
 
-        // Create the character model
-        // Note: portrait is NOT included here - it's updated via a separate command
-        // to avoid sending large blobs with every save
-        let char_model = Character {
+    /// Build the `characters` row for a character view.
+    ///
+    /// The portrait is left out (it's updated via a separate command to avoid
+    /// sending large blobs with every save) and `archived` is always false.
+    pub(crate) fn to_model(character: &CharacterView, now: &str) -> Character {
+        Character {
             id: character.id,
             is_wild_card: character.is_wild_card,
             name: character.name.clone(),
@@ -260,11 +1007,38 @@ impl CharacterService {
             wealth: character.wealth,
             background: character.background.clone(),
             description: character.description.clone(),
+            campaign: character.campaign.clone(),
+            archived: false, // Updated via archive/restore
+            max_attribute_die_size: character
+                .config
+                .max_attribute_die
+                .map(|d| d.size() as i64),
+            max_attribute_die_modifier: character
+                .config
+                .max_attribute_die
+                .map(|d| d.modifier() as i64)
+                .unwrap_or(0),
+            allow_hindrances_past_cap: character.config.allow_hindrances_past_cap,
+            load_limit_multiplier: character.config.load_limit_multiplier,
+            parry_skill: character.config.parry_skill.clone(),
+            max_wounds: character.config.max_wounds,
+            track_experience: character.config.track_experience,
+            sell_back_percent: character.config.sell_back_percent,
+            weight_unit: character.config.weight_unit.to_string(),
+            experience_points: character.experience_points,
             portrait: None, // Updated via separate command
             portrait_mime_type: None,
-            created_at: now.clone(),
-            updated_at: now.clone(),
-        };
+            created_at: now.to_string(),
+            updated_at: now.to_string(),
+            revision: character.revision,
+        }
+    }
+
+    /// Write a character without checking it or opening a transaction, for
+    /// callers that already run inside one.
+    pub(crate) fn save_inner(conn: &Connection, character: &CharacterView) -> Result<CharacterView> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let char_model = Self::to_model(character, &now);
 
         if character.id == 0 {
             // New character - use insert logic
@@ -357,6 +1131,7 @@ impl CharacterService {
                 character_id,
                 arcane_background_id: ab_value.arcane_background.id,
                 advance_taken: ab_value.advance_taken,
+                power_points_used: ab_value.power_points_used,
                 created_at: now.to_string(),
                 updated_at: now.to_string(),
             };
@@ -461,6 +1236,7 @@ impl CharacterService {
                 character_id,
                 arcane_background_id: ab_value.arcane_background.id,
                 advance_taken: ab_value.advance_taken,
+                power_points_used: ab_value.power_points_used,
                 created_at: now.to_string(),
                 updated_at: now.to_string(),
             };
@@ -485,100 +1261,449 @@ impl CharacterService {
             .ok_or_else(|| crate::error::SwadeError::NotFound("Character".to_string()))
     }
 
-    /// Get the rank for a given number of advances.
-    fn get_rank_for_advances(conn: &Connection, advances: i64) -> Result<crate::models::Rank> {
-        let ranks = RankRepository::get_all(conn)?;
-
-        // Find the rank where advances falls within [min_advances, max_advances]
-        // Ranks are ordered by min_advances
-        for rank in ranks.iter().rev() {
-            if advances >= rank.min_advances {
-                // Check max_advances (None means no upper limit, i.e., Legendary)
-                if rank.max_advances.is_none() || advances <= rank.max_advances.unwrap() {
-                    return Ok(rank.clone());
-                }
-            }
+    /// Set the power points spent from a single arcane background's pool.
+    ///
+    /// Each arcane background keeps its own pool, so spending from one never
+    /// affects another.
+    pub fn update_power_points_used(
+        conn: &Connection,
+        character_id: i64,
+        arcane_background_id: i64,
+        power_points_used: i64,
+    ) -> Result<CharacterView> {
+        let character = Self::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+
+        let pool = character
+            .get_power_point_pool(arcane_background_id)
+            .ok_or_else(|| SwadeError::NotFound("Arcane background".to_string()))?;
+
+        if power_points_used < 0 || power_points_used > pool.max {
+            return Err(SwadeError::Validation(format!(
+                "Power points used for {} must be between 0 and {}",
+                pool.arcane_background_name, pool.max
+            )));
         }
 
-        // Default to first rank (Novice) if something goes wrong
-        ranks
-            .into_iter()
-            .next()
-            .ok_or_else(|| SwadeError::NotFound("No ranks found".to_string()))
-    }
-}
+        let mut char_ab =
+            CharacterArcaneBackgroundRepository::get_by_character_id(conn, character_id)?
+                .into_iter()
+                .find(|cab| cab.arcane_background_id == arcane_background_id)
+                .ok_or_else(|| SwadeError::NotFound("Arcane background".to_string()))?;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::test_utils::setup_test_db;
-    use rusqlite::params;
+        char_ab.power_points_used = power_points_used;
+        char_ab.updated_at = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        CharacterArcaneBackgroundRepository::update(conn, &char_ab)?;
 
-    fn insert_test_rank(conn: &Connection, id: i64, name: &str) {
-        conn.execute(
-            "INSERT INTO ranks (id, name, min_advances, max_advances, description, created_at, updated_at)
-             VALUES (?, ?, 0, 3, 'Test rank', '2024-01-01', '2024-01-01')",
-            params![id, name],
-        )
-        .unwrap();
+        Self::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))
     }
 
-    fn insert_test_character(conn: &Connection, id: i64, name: &str) {
-        conn.execute(
-            "INSERT INTO characters (id, is_wild_card, name, ancestry_id,
-                                    attribute_points_spent, attribute_points_earned,
-                                    skill_points_spent, skill_points_earned, hindrance_points_spent,
-                                    hindrance_points_earned, hindrance_points_to_edges,
-                                    hindrance_points_to_attributes, hindrance_points_to_skills,
-                                    hindrance_points_to_wealth, power_points, wealth,
-                                    created_at, updated_at)
-             VALUES (?, 1, ?, NULL, 0, 5, 0, 12, 0, 0, 0, 0, 0, 0, 0, 500,
-                     '2024-01-01', '2024-01-01')",
-            params![id, name],
-        )
-        .unwrap();
+    /// Trim a character name, rejecting blank names and names longer than
+    /// `MAX_CHARACTER_NAME_LENGTH`.
+    pub fn normalize_name(name: &str) -> Result<String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(SwadeError::Validation("Character name cannot be empty".to_string()));
+        }
+        if name.chars().count() > MAX_CHARACTER_NAME_LENGTH {
+            return Err(SwadeError::Validation(format!(
+                "Character name cannot be longer than {} characters",
+                MAX_CHARACTER_NAME_LENGTH
+            )));
+        }
+        Ok(name.to_string())
     }
 
-    fn insert_test_attribute(conn: &Connection, id: i64, name: &str) {
-        conn.execute(
-            "INSERT INTO attributes (id, name, description, base_value, created_at, updated_at)
-             VALUES (?, ?, 'Test attribute', 4, '2024-01-01', '2024-01-01')",
-            params![id, name],
-        )
-        .unwrap();
+    /// Rename a saved character without loading it into a draft.
+    ///
+    /// The name is normalized with `normalize_name`.
+    pub fn rename(conn: &Connection, id: i64, name: &str) -> Result<CharacterView> {
+        let name = Self::normalize_name(name)?;
+        CharacterRepository::get_by_id(conn, id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+        CharacterRepository::set_name(conn, id, &name)?;
+
+        Self::get_by_id(conn, id)?.ok_or_else(|| SwadeError::NotFound("Character".to_string()))
     }
 
-    fn insert_character_attribute(
+    /// Set or clear a saved character's campaign. Blank names clear it.
+    pub fn set_campaign(
         conn: &Connection,
         character_id: i64,
-        attribute_id: i64,
-        steps: i64,
-    ) {
-        conn.execute(
-            "INSERT INTO character_attributes (character_id, attribute_id, steps_incremented,
-                                               created_at, updated_at)
-             VALUES (?, ?, ?, '2024-01-01', '2024-01-01')",
-            params![character_id, attribute_id, steps],
-        )
-        .unwrap();
+        campaign: Option<&str>,
+    ) -> Result<CharacterView> {
+        let mut character = CharacterRepository::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+
+        character.campaign = campaign
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+            .map(str::to_string);
+        CharacterRepository::update(conn, &character)?;
+
+        Self::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))
     }
 
-    #[test]
-    fn get_by_id_returns_none_when_not_found() {
-        let conn = setup_test_db();
+    /// Get all archived characters.
+    pub fn get_archived(conn: &Connection) -> Result<Vec<CharacterView>> {
+        let characters = CharacterRepository::get_archived(conn)?;
 
-        let result = CharacterService::get_by_id(&conn, 999).unwrap();
+        let mut views = Vec::new();
+        for character in characters {
+            if let Some(view) = Self::get_by_id(conn, character.id)? {
+                views.push(view);
+            }
+        }
 
-        assert!(result.is_none());
+        Ok(views)
     }
 
-    #[test]
-    fn get_by_id_returns_character_with_basic_info() {
-        let conn = setup_test_db();
-        insert_test_rank(&conn, 1, "Novice");
-        insert_test_character(&conn, 1, "Test Hero");
+    /// Archive a character, hiding it from `get_all` without deleting any data.
+    pub fn archive(conn: &Connection, id: i64) -> Result<()> {
+        CharacterRepository::get_by_id(conn, id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+        CharacterRepository::set_archived(conn, id, true)
+    }
 
-        let character = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+    /// Restore an archived character to the character list.
+    pub fn restore(conn: &Connection, id: i64) -> Result<CharacterView> {
+        CharacterRepository::get_by_id(conn, id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+        CharacterRepository::set_archived(conn, id, false)?;
+
+        Self::get_by_id(conn, id)?.ok_or_else(|| SwadeError::NotFound("Character".to_string()))
+    }
+
+    /// Replace a character's portrait.
+    ///
+    /// The image is downscaled to fit within `MAX_PORTRAIT_DIMENSION` and
+    /// stored as PNG, whatever format it was uploaded in.
+    pub fn update_portrait(conn: &Connection, id: i64, image_bytes: &[u8]) -> Result<CharacterView> {
+        CharacterRepository::get_by_id(conn, id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+        let portrait = normalize_portrait(image_bytes)?;
+        CharacterRepository::update_portrait(conn, id, Some(&portrait), Some(PORTRAIT_MIME_TYPE))?;
+
+        Self::get_by_id_with_portrait(conn, id)?.ok_or_else(|| SwadeError::NotFound("Character".to_string()))
+    }
+
+    /// Change whether a character is a Wild Card or an Extra.
+    ///
+    /// Returns the updated character along with a warning for each held edge
+    /// whose requirements are no longer met (e.g. edges that need a Wild Card).
+    pub fn set_wild_card(
+        conn: &Connection,
+        id: i64,
+        is_wild_card: bool,
+    ) -> Result<(CharacterView, Vec<String>)> {
+        CharacterRepository::get_by_id(conn, id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+        CharacterRepository::set_wild_card(conn, id, is_wild_card)?;
+
+        let character =
+            Self::get_by_id(conn, id)?.ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+        let warnings = character.unmet_edge_requirements();
+
+        Ok((character, warnings))
+    }
+
+    /// Set a saved character's wounds.
+    ///
+    /// Clamped to zero and one past the character's maximum (3 for Wild Cards
+    /// by default, 0 for Extras); that last wound leaves them Incapacitated.
+    pub fn set_wounds(conn: &Connection, id: i64, wounds: i64) -> Result<CharacterView> {
+        let character =
+            Self::get_by_id(conn, id)?.ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+        let wounds = wounds.clamp(0, character.max_wounds() + 1);
+        CharacterRepository::set_wounds(conn, id, wounds)?;
+
+        Self::get_by_id(conn, id)?.ok_or_else(|| SwadeError::NotFound("Character".to_string()))
+    }
+
+    /// Add to (or, with a negative amount, heal) a saved character's wounds,
+    /// clamped like `set_wounds`.
+    pub fn increment_wounds(conn: &Connection, id: i64, amount: i64) -> Result<CharacterView> {
+        let character =
+            Self::get_by_id(conn, id)?.ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+        Self::set_wounds(conn, id, character.wounds.saturating_add(amount))
+    }
+
+    /// Set a saved character's Fatigue.
+    ///
+    /// Clamped to zero and one past Exhausted; that last level leaves them
+    /// Incapacitated.
+    pub fn set_fatigue(conn: &Connection, id: i64, fatigue: i64) -> Result<CharacterView> {
+        CharacterRepository::get_by_id(conn, id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+        CharacterRepository::set_fatigue(conn, id, fatigue.clamp(0, MAX_FATIGUE + 1))?;
+
+        Self::get_by_id(conn, id)?.ok_or_else(|| SwadeError::NotFound("Character".to_string()))
+    }
+
+    /// Add to (or recover) a saved character's Fatigue, clamped like `set_fatigue`.
+    pub fn increment_fatigue(conn: &Connection, id: i64, amount: i64) -> Result<CharacterView> {
+        let character = CharacterRepository::get_by_id(conn, id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+        Self::set_fatigue(conn, id, character.fatigue.saturating_add(amount))
+    }
+
+    /// Give a saved character every current core skill it is missing.
+    ///
+    /// Characters created before a skill joined the core list have no d4 in it.
+    /// Missing core skills (or core skills stored untrained) are set to d4;
+    /// trained dice are never changed, so running this twice adds nothing the
+    /// second time. Returns the updated character and the names of the skills
+    /// that were added. Runs in one transaction, so a failure adds nothing.
+    pub fn repair_character_skills(
+        conn: &Connection,
+        id: i64,
+    ) -> Result<(CharacterView, Vec<String>)> {
+        CharacterRepository::get_by_id(conn, id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+
+        let tx = conn.unchecked_transaction()?;
+        let added = Self::repair_character_skills_inner(&tx, id)?;
+        tx.commit()?;
+
+        let character =
+            Self::get_by_id(conn, id)?.ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+
+        Ok((character, added))
+    }
+
+    fn repair_character_skills_inner(conn: &Connection, id: i64) -> Result<Vec<String>> {
+        let existing = CharacterSkillRepository::get_by_character_id(conn, id)?;
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let d4 = Die::d4();
+        let mut added = Vec::new();
+
+        for skill in SkillRepository::get_all(conn)?.into_iter().filter(|s| s.is_core_skill) {
+            match existing.iter().find(|cs| cs.skill_id == skill.id) {
+                Some(cs) if cs.current_die_size.is_some() => continue,
+                Some(cs) => CharacterSkillRepository::update(
+                    conn,
+                    &CharacterSkill {
+                        current_die_size: Some(d4.size() as i64),
+                        current_die_modifier: 0,
+                        updated_at: now.clone(),
+                        ..cs.clone()
+                    },
+                )?,
+                None => {
+                    CharacterSkillRepository::insert(
+                        conn,
+                        &CharacterSkill {
+                            id: 0,
+                            character_id: id,
+                            skill_id: skill.id,
+                            current_die_size: Some(d4.size() as i64),
+                            current_die_modifier: 0,
+                            created_at: now.clone(),
+                            updated_at: now.clone(),
+                        },
+                    )?;
+                }
+            }
+            added.push(skill.name);
+        }
+
+        Ok(added)
+    }
+
+    /// Permanently delete a character. Links to and from its companions are
+    /// removed, but the companions themselves are kept.
+    pub fn delete(conn: &Connection, id: i64) -> Result<()> {
+        CompanionLinkRepository::delete_by_character_id(conn, id)?;
+        CharacterRepository::delete(conn, id)
+    }
+
+    /// Get summaries of the companions linked to a character.
+    pub fn get_companions(conn: &Connection, character_id: i64) -> Result<Vec<CompanionSummary>> {
+        character_load::load_companions(conn, character_id)
+    }
+
+    /// Link an existing character to another as its companion.
+    ///
+    /// Returns the parent's updated companion list.
+    pub fn link_companion(
+        conn: &Connection,
+        parent_id: i64,
+        companion_id: i64,
+        relationship: &str,
+    ) -> Result<Vec<CompanionSummary>> {
+        let relationship = relationship.trim();
+        if relationship.is_empty() {
+            return Err(SwadeError::Validation(
+                "Companion relationship cannot be empty".to_string(),
+            ));
+        }
+        if parent_id == companion_id {
+            return Err(SwadeError::Validation(
+                "A character cannot be its own companion".to_string(),
+            ));
+        }
+
+        let parent = CharacterRepository::get_by_id(conn, parent_id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))?;
+        let companion = CharacterRepository::get_by_id(conn, companion_id)?
+            .ok_or_else(|| SwadeError::NotFound("Companion".to_string()))?;
+
+        if CompanionLinkRepository::get_by_pair(conn, parent_id, companion_id)?.is_some() {
+            return Err(SwadeError::Validation(format!(
+                "{} is already a companion of {}",
+                companion.name, parent.name
+            )));
+        }
+        if CompanionLinkRepository::get_by_pair(conn, companion_id, parent_id)?.is_some() {
+            return Err(SwadeError::Validation(format!(
+                "{} is already a companion of {}",
+                parent.name, companion.name
+            )));
+        }
+
+        let link = CompanionLink {
+            id: 0,
+            parent_character_id: parent_id,
+            child_character_id: companion_id,
+            relationship: relationship.to_string(),
+            created_at: String::new(),
+            updated_at: String::new(),
+        };
+        CompanionLinkRepository::insert(conn, &link)?;
+
+        Self::get_companions(conn, parent_id)
+    }
+
+    /// Remove a companion link. Both characters are kept.
+    ///
+    /// Returns the parent's updated companion list.
+    pub fn unlink_companion(
+        conn: &Connection,
+        parent_id: i64,
+        companion_id: i64,
+    ) -> Result<Vec<CompanionSummary>> {
+        let link = CompanionLinkRepository::get_by_pair(conn, parent_id, companion_id)?
+            .ok_or_else(|| SwadeError::NotFound("Companion link".to_string()))?;
+        CompanionLinkRepository::delete(conn, link.id)?;
+
+        Self::get_companions(conn, parent_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::setup_test_db;
+    use rusqlite::params;
+
+    fn insert_test_rank(conn: &Connection, id: i64, name: &str) {
+        conn.execute(
+            "INSERT INTO ranks (id, name, min_advances, max_advances, description, created_at, updated_at)
+             VALUES (?, ?, 0, 3, 'Test rank', '2024-01-01', '2024-01-01')",
+            params![id, name],
+        )
+        .unwrap();
+    }
+
+    fn insert_test_character(conn: &Connection, id: i64, name: &str) {
+        conn.execute(
+            "INSERT INTO characters (id, is_wild_card, name, ancestry_id,
+                                    attribute_points_spent, attribute_points_earned,
+                                    skill_points_spent, skill_points_earned, hindrance_points_spent,
+                                    hindrance_points_earned, hindrance_points_to_edges,
+                                    hindrance_points_to_attributes, hindrance_points_to_skills,
+                                    hindrance_points_to_wealth, power_points, wealth,
+                                    created_at, updated_at)
+             VALUES (?, 1, ?, NULL, 0, 5, 0, 12, 0, 0, 0, 0, 0, 0, 0, 500,
+                     '2024-01-01', '2024-01-01')",
+            params![id, name],
+        )
+        .unwrap();
+    }
+
+    fn insert_test_attribute(conn: &Connection, id: i64, name: &str) {
+        conn.execute(
+            "INSERT INTO attributes (id, name, description, base_value, created_at, updated_at)
+             VALUES (?, ?, 'Test attribute', 4, '2024-01-01', '2024-01-01')",
+            params![id, name],
+        )
+        .unwrap();
+    }
+
+    fn insert_character_attribute(
+        conn: &Connection,
+        character_id: i64,
+        attribute_id: i64,
+        steps: i64,
+    ) {
+        conn.execute(
+            "INSERT INTO character_attributes (character_id, attribute_id, steps_incremented,
+                                               created_at, updated_at)
+             VALUES (?, ?, ?, '2024-01-01', '2024-01-01')",
+            params![character_id, attribute_id, steps],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn get_by_id_returns_none_when_not_found() {
+        let conn = setup_test_db();
+
+        let result = CharacterService::get_by_id(&conn, 999).unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn diff_reports_changes_made_by_advances() {
+        use crate::test_utils::insert_test_edge;
+        use crate::views::DiffKind;
+
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_attribute(&conn, 1, "Vigor");
+        insert_test_edge(&conn, 1, "Alertness", "Background");
+        for id in [1, 2] {
+            insert_test_character(&conn, id, "Hero");
+            insert_character_attribute(&conn, id, 1, 1);
+        }
+        AdvancementService::apply_attribute_advance(&conn, 2, 1, None).unwrap();
+        AdvancementService::apply_edge_advance(&conn, 2, 1, None, None).unwrap();
+
+        let diff = CharacterService::diff(&conn, 1, 2).unwrap();
+
+        assert_eq!(diff.attributes.len(), 1);
+        assert_eq!(diff.attributes[0].name, "Vigor");
+        assert_eq!(diff.attributes[0].kind, DiffKind::Changed);
+        assert_eq!(diff.attributes[0].old_die, Some(Die::d6()));
+        assert_eq!(diff.attributes[0].new_die, Some(Die::d8()));
+        assert_eq!(diff.edges.len(), 1);
+        assert_eq!(diff.edges[0].name, "Alertness");
+        assert_eq!(diff.edges[0].kind, DiffKind::Added);
+        assert_eq!(diff.derived_stats.len(), 1);
+        assert_eq!(diff.derived_stats[0].stat, "toughness");
+        assert_eq!(
+            (diff.derived_stats[0].old_value, diff.derived_stats[0].new_value),
+            (5, 6)
+        );
+        assert!(diff.hindrances.is_empty());
+
+        assert!(CharacterService::diff(&conn, 1, 1).unwrap().is_empty());
+        assert!(matches!(
+            CharacterService::diff(&conn, 1, 999),
+            Err(SwadeError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn get_by_id_returns_character_with_basic_info() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+
+        let character = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
 
         assert_eq!(character.id, 1);
         assert_eq!(character.name, "Test Hero");
@@ -602,6 +1727,98 @@ mod tests {
         assert_eq!(character.attributes[0].die, Die::d8());
     }
 
+    #[test]
+    fn export_roster_csv_writes_header_and_a_row_per_character() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_character(&conn, 2, "Smith, \"Red\" John");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_attribute(&conn, 2, "Vigor");
+        insert_character_attribute(&conn, 1, 1, 2);
+        insert_character_attribute(&conn, 1, 2, 1);
+
+        let csv = CharacterService::export_roster_csv(&conn).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "Name,Rank,Ancestry,Agility,Smarts,Spirit,Strength,Vigor,Pace,Parry,Toughness,Advances",
+                "Hero,Novice,,d8,,,,d6,6,2,5,0",
+                "\"Smith, \"\"Red\"\" John\",Novice,,,,,,,6,2,2,0",
+            ]
+        );
+    }
+
+    #[test]
+    fn export_roster_csv_applies_modifiers_and_skips_archived() {
+        use crate::test_utils::{
+            insert_test_character_edge_at_advance, insert_test_edge, insert_test_edge_modifier,
+            insert_test_modifier,
+        };
+
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_attribute(&conn, 1, "Vigor");
+        insert_test_character(&conn, 1, "Brute");
+        insert_test_character(&conn, 2, "Retired");
+        insert_character_attribute(&conn, 1, 1, 1);
+        insert_test_edge(&conn, 1, "Brawny", "Background");
+        insert_test_modifier(&conn, 1, "derived_stat", "flat_bonus", "+1 Toughness");
+        conn.execute("UPDATE modifiers SET target_identifier = 'toughness' WHERE id = 1", [])
+            .unwrap();
+        insert_test_edge_modifier(&conn, 1, 1, 1);
+        insert_test_character_edge_at_advance(&conn, 1, 1, 1, 0);
+        CharacterRepository::set_archived(&conn, 2, true).unwrap();
+
+        let csv = CharacterService::export_roster_csv(&conn).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1], "Brute,Novice,,,,,,d6,6,2,6,0");
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("Plain"), "Plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn reset_keeps_identity_and_restores_fresh_point_pools() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 2);
+
+        let mut draft = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        draft.attribute_points_spent = 2;
+        draft.skill_points_spent = 4;
+        draft.hindrance_points_earned = 4;
+        draft.hindrance_points_to_edges = 2;
+        draft.background = Some("Farmhand".to_string());
+
+        let reset = CharacterService::reset(&conn, &draft).unwrap();
+        let fresh = CharacterService::build_new(&conn, "Test Hero".to_string()).unwrap();
+
+        assert_eq!(reset.id, 1);
+        assert_eq!(reset.name, "Test Hero");
+        assert_eq!(reset.background, Some("Farmhand".to_string()));
+        assert_eq!(reset.attributes[0].die, Die::d4());
+        assert_eq!(reset.attribute_points_spent, fresh.attribute_points_spent);
+        assert_eq!(reset.attribute_points_earned, fresh.attribute_points_earned);
+        assert_eq!(reset.skill_points_spent, fresh.skill_points_spent);
+        assert_eq!(reset.skill_points_earned, fresh.skill_points_earned);
+        assert_eq!(reset.hindrance_points_earned, fresh.hindrance_points_earned);
+        assert_eq!(reset.hindrance_points_to_edges, fresh.hindrance_points_to_edges);
+        assert!(reset.edges.is_empty());
+        assert!(reset.hindrances.is_empty());
+        assert!(reset.ancestry.is_none());
+    }
+
     #[test]
     fn get_all_returns_empty_when_no_characters() {
         let conn = setup_test_db();
@@ -622,4 +1839,1174 @@ mod tests {
 
         assert_eq!(characters.len(), 2);
     }
+
+    fn insert_test_arcane_background_with_pool(
+        conn: &Connection,
+        id: i64,
+        name: &str,
+        skill_id: i64,
+        starting_power_points: i64,
+    ) {
+        conn.execute(
+            "INSERT INTO skills (id, name, description, linked_attribute_id, created_at, updated_at)
+             VALUES (?, ?, 'Arcane skill', 1, '2024-01-01', '2024-01-01')",
+            params![skill_id, format!("{} Skill", name)],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO arcane_backgrounds (id, name, arcane_skill_id, starting_powers,
+                                             starting_power_points, source, description,
+                                             created_at, updated_at)
+             VALUES (?, ?, ?, 3, ?, 'core', 'Test', '2024-01-01', '2024-01-01')",
+            params![id, name, skill_id, starting_power_points],
+        )
+        .unwrap();
+    }
+
+    fn insert_character_arcane_background(conn: &Connection, character_id: i64, ab_id: i64) {
+        conn.execute(
+            "INSERT INTO character_arcane_backgrounds (character_id, arcane_background_id,
+                                                       created_at, updated_at)
+             VALUES (?, ?, '2024-01-01', '2024-01-01')",
+            params![character_id, ab_id],
+        )
+        .unwrap();
+    }
+
+    fn setup_character_with_two_arcane_backgrounds(conn: &Connection) {
+        insert_test_rank(conn, 1, "Novice");
+        insert_test_attribute(conn, 1, "Smarts");
+        insert_test_character(conn, 1, "Test Hero");
+        insert_test_arcane_background_with_pool(conn, 1, "Magic", 10, 10);
+        insert_test_arcane_background_with_pool(conn, 2, "Miracles", 11, 15);
+        insert_character_arcane_background(conn, 1, 1);
+        insert_character_arcane_background(conn, 1, 2);
+    }
+
+    #[test]
+    fn update_power_points_used_only_affects_target_pool() {
+        let conn = setup_test_db();
+        setup_character_with_two_arcane_backgrounds(&conn);
+
+        let character = CharacterService::update_power_points_used(&conn, 1, 1, 4).unwrap();
+
+        let magic = character.get_power_point_pool(1).unwrap();
+        let miracles = character.get_power_point_pool(2).unwrap();
+        assert_eq!(magic.used, 4);
+        assert_eq!(magic.max, 10);
+        assert_eq!(miracles.used, 0);
+        assert_eq!(miracles.max, 15);
+        assert_eq!(character.total_power_points_used(), 4);
+
+        let character = CharacterService::update_power_points_used(&conn, 1, 2, 15).unwrap();
+
+        assert_eq!(character.get_power_point_pool(1).unwrap().used, 4);
+        assert_eq!(character.get_power_point_pool(2).unwrap().used, 15);
+        assert_eq!(character.total_power_points_used(), 19);
+    }
+
+    #[test]
+    fn update_power_points_used_rejects_more_than_pool_max() {
+        let conn = setup_test_db();
+        setup_character_with_two_arcane_backgrounds(&conn);
+
+        // Magic only has 10 points even though the combined total is 25
+        let result = CharacterService::update_power_points_used(&conn, 1, 1, 11);
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+
+        let result = CharacterService::update_power_points_used(&conn, 1, 1, -1);
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+    }
+
+    #[test]
+    fn update_power_points_used_unknown_arcane_background() {
+        let conn = setup_test_db();
+        setup_character_with_two_arcane_backgrounds(&conn);
+
+        let result = CharacterService::update_power_points_used(&conn, 1, 99, 1);
+        assert!(matches!(result, Err(SwadeError::NotFound(_))));
+    }
+
+    #[test]
+    fn save_preserves_power_point_pools() {
+        let conn = setup_test_db();
+        setup_character_with_two_arcane_backgrounds(&conn);
+        CharacterService::update_power_points_used(&conn, 1, 2, 5).unwrap();
+
+        let character = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        let saved = CharacterService::save(&conn, &character).unwrap();
+
+        assert_eq!(saved.get_power_point_pool(1).unwrap().used, 0);
+        assert_eq!(saved.get_power_point_pool(2).unwrap().used, 5);
+    }
+
+    #[test]
+    fn save_persists_sell_back_percent() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        let mut draft = CharacterService::build_new(&conn, "Test Hero".to_string()).unwrap();
+        draft.config.sell_back_percent = Some(25);
+
+        let saved = CharacterService::save(&conn, &draft).unwrap();
+        let loaded = CharacterService::get_by_id(&conn, saved.id).unwrap().unwrap();
+
+        assert_eq!(loaded.config.sell_back_percent(), 25);
+    }
+
+    #[test]
+    fn allocate_hindrance_points_to_wealth_adds_and_returns_funds() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        let mut draft = CharacterService::build_new(&conn, "Test Hero".to_string()).unwrap();
+        draft.hindrance_points_earned = 2;
+
+        CharacterService::allocate_hindrance_points_to_wealth(&mut draft, 1).unwrap();
+        assert_eq!(draft.hindrance_points_to_wealth, 1);
+        assert_eq!(draft.wealth, 1000);
+
+        CharacterService::allocate_hindrance_points_to_wealth(&mut draft, -1).unwrap();
+        assert_eq!(draft.hindrance_points_to_wealth, 0);
+        assert_eq!(draft.wealth, 500);
+    }
+
+    #[test]
+    fn allocate_hindrance_points_to_wealth_rejects_over_allocation() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        let mut draft = CharacterService::build_new(&conn, "Test Hero".to_string()).unwrap();
+        draft.hindrance_points_earned = 4;
+        draft.hindrance_points_to_edges = 2;
+        draft.hindrance_points_to_skills = 1;
+
+        let result = CharacterService::allocate_hindrance_points_to_wealth(&mut draft, 2);
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+        assert_eq!(draft.hindrance_points_to_wealth, 0);
+        assert_eq!(draft.wealth, 500);
+
+        let result = CharacterService::allocate_hindrance_points_to_wealth(&mut draft, -1);
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+    }
+
+    #[test]
+    fn allocate_hindrance_points_to_wealth_keeps_spent_funds() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        let mut draft = CharacterService::build_new(&conn, "Test Hero".to_string()).unwrap();
+        draft.hindrance_points_earned = 1;
+        CharacterService::allocate_hindrance_points_to_wealth(&mut draft, 1).unwrap();
+        draft.wealth = 200;
+
+        let result = CharacterService::allocate_hindrance_points_to_wealth(&mut draft, -1);
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+        assert_eq!(draft.hindrance_points_to_wealth, 1);
+    }
+
+    #[test]
+    fn hindrance_point_budget_rejects_overspend_across_pools() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        let mut draft = CharacterService::build_new(&conn, "Test Hero".to_string()).unwrap();
+        draft.hindrance_points_earned = 4;
+
+        CharacterService::allocate_hindrance_points_to_edges(&mut draft, 2).unwrap();
+        CharacterService::allocate_hindrance_points_to_skills(&mut draft, 1).unwrap();
+        CharacterService::allocate_hindrance_points_to_wealth(&mut draft, 1).unwrap();
+        assert_eq!(HindrancePointBudget::from_draft(&draft).available(), 0);
+
+        let result = CharacterService::allocate_hindrance_points_to_attributes(&mut draft, 1);
+        match result {
+            Err(SwadeError::Validation(msg)) => {
+                assert!(msg.contains("Need 2, have 0"));
+                assert!(msg.contains("2 to edges, 0 to attributes, 1 to skills, 1 to wealth"));
+            }
+            other => panic!("expected validation error, got {:?}", other),
+        }
+        assert_eq!(draft.hindrance_points_to_attributes, 0);
+    }
+
+    #[test]
+    fn allocate_hindrance_points_to_edges_requires_edge_cost_multiples() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        let mut draft = CharacterService::build_new(&conn, "Test Hero".to_string()).unwrap();
+        draft.hindrance_points_earned = 4;
+
+        let result = CharacterService::allocate_hindrance_points_to_edges(&mut draft, 1);
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+        assert_eq!(draft.hindrance_points_to_edges, 0);
+    }
+
+    #[test]
+    fn validate_draft_accepts_fresh_character() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        let draft = CharacterService::build_new(&conn, "Test Hero".to_string()).unwrap();
+
+        assert!(CharacterService::validate_draft(&draft).is_valid());
+    }
+
+    #[test]
+    fn validate_draft_reports_overspent_points() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        let mut draft = CharacterService::build_new(&conn, "Test Hero".to_string()).unwrap();
+        draft.attribute_points_spent = 6;
+        draft.skill_points_spent = 13;
+        draft.hindrance_points_earned = 1;
+        draft.hindrance_points_to_skills = 2;
+
+        let errors = CharacterService::validate_draft(&draft).errors;
+
+        assert_eq!(
+            errors,
+            vec![
+                "Attribute points overspent (6/5)".to_string(),
+                "Hindrance points overallocated (2/1)".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn link_companion_lists_companion_on_parent() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_character(&conn, 2, "Rex");
+
+        let companions = CharacterService::link_companion(&conn, 1, 2, "Animal Companion").unwrap();
+
+        assert_eq!(companions.len(), 1);
+        assert_eq!(companions[0].character_id, 2);
+        assert_eq!(companions[0].name, "Rex");
+        assert_eq!(companions[0].relationship, "Animal Companion");
+
+        let parent = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        assert_eq!(parent.companions, companions);
+        assert!(CharacterService::get_companions(&conn, 2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn link_companion_rejects_self_and_duplicate_links() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_character(&conn, 2, "Sidekick");
+
+        let result = CharacterService::link_companion(&conn, 1, 1, "Sidekick");
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+
+        CharacterService::link_companion(&conn, 1, 2, "Sidekick").unwrap();
+        let result = CharacterService::link_companion(&conn, 1, 2, "Sidekick");
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+        let result = CharacterService::link_companion(&conn, 2, 1, "Sidekick");
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+
+        let result = CharacterService::link_companion(&conn, 1, 99, "Sidekick");
+        assert!(matches!(result, Err(SwadeError::NotFound(_))));
+    }
+
+    #[test]
+    fn unlink_companion_keeps_both_characters() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_character(&conn, 2, "Sidekick");
+        CharacterService::link_companion(&conn, 1, 2, "Sidekick").unwrap();
+
+        let companions = CharacterService::unlink_companion(&conn, 1, 2).unwrap();
+
+        assert!(companions.is_empty());
+        assert!(CharacterService::get_by_id(&conn, 2).unwrap().is_some());
+        let result = CharacterService::unlink_companion(&conn, 1, 2);
+        assert!(matches!(result, Err(SwadeError::NotFound(_))));
+    }
+
+    #[test]
+    fn delete_parent_keeps_companion_and_removes_link() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_character(&conn, 2, "Sidekick");
+        CharacterService::link_companion(&conn, 1, 2, "Sidekick").unwrap();
+
+        CharacterService::delete(&conn, 1).unwrap();
+
+        assert!(CharacterService::get_by_id(&conn, 1).unwrap().is_none());
+        assert!(CharacterService::get_by_id(&conn, 2).unwrap().is_some());
+        assert!(CompanionLinkRepository::get_by_parent_id(&conn, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn reset_attributes_and_skills_refund_all_points() {
+        use crate::test_utils::{insert_test_core_skill, insert_test_skill};
+
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_core_skill(&conn, 1, "Athletics", 1);
+        insert_test_skill(&conn, 2, "Fighting", 1);
+        let mut draft = CharacterService::build_new(&conn, "Hero".to_string()).unwrap();
+        draft.hindrance_points_to_attributes = 1;
+        draft.hindrance_points_to_skills = 2;
+
+        CharacterService::set_attribute_dice(&mut draft, &[(1, Die::d8())]).unwrap();
+        CharacterService::set_skill_dice(&mut draft, &[(1, Some(Die::d6())), (2, Some(Die::d4()))])
+            .unwrap();
+        let full_attribute_points =
+            draft.attribute_points_earned + draft.hindrance_points_to_attributes;
+        let full_skill_points = draft.skill_points_earned + draft.hindrance_points_to_skills;
+
+        CharacterService::reset_attributes(&mut draft);
+        CharacterService::reset_skills(&mut draft);
+
+        assert_eq!(draft.attributes[0].die, Die::d4());
+        assert_eq!(draft.skills[0].die, Some(Die::d4()));
+        assert_eq!(draft.skills[1].die, None);
+        assert_eq!(draft.attribute_points_spent, 0);
+        assert_eq!(draft.skill_points_spent, 0);
+        assert_eq!(draft.hindrance_points_to_attributes, 1);
+        assert_eq!(draft.hindrance_points_to_skills, 2);
+
+        // Conversions included, the whole of each pool is available again
+        assert_eq!(
+            draft.attribute_points_earned + draft.hindrance_points_to_attributes
+                - draft.attribute_points_spent,
+            full_attribute_points
+        );
+        assert_eq!(
+            draft.skill_points_earned + draft.hindrance_points_to_skills - draft.skill_points_spent,
+            full_skill_points
+        );
+    }
+
+    #[test]
+    fn set_attribute_dice_moves_a_step_between_attributes() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_attribute(&conn, 2, "Strength");
+        let mut draft = CharacterService::build_new(&conn, "Hero".to_string()).unwrap();
+        draft.attribute_points_earned = 2;
+
+        CharacterService::set_attribute_dice(&mut draft, &[(1, Die::d8())]).unwrap();
+        assert_eq!(draft.attribute_points_spent, 2);
+
+        // Raising Strength first would overspend before Agility refunds
+        let mut sequential = draft.clone();
+        assert!(matches!(
+            CharacterService::set_attribute_dice(&mut sequential, &[(2, Die::d6())]),
+            Err(SwadeError::Validation(_))
+        ));
+
+        CharacterService::set_attribute_dice(&mut draft, &[(2, Die::d6()), (1, Die::d6())]).unwrap();
+
+        let die = |id: i64| draft.attributes.iter().find(|a| a.attribute.id == id).unwrap().die;
+        assert_eq!(die(1), Die::d6());
+        assert_eq!(die(2), Die::d6());
+        assert_eq!(draft.attribute_points_spent, 2);
+
+        // Hindrance points converted to attributes count toward the budget
+        draft.hindrance_points_to_attributes = 1;
+        CharacterService::set_attribute_dice(&mut draft, &[(2, Die::d8())]).unwrap();
+        assert_eq!(draft.attribute_points_spent, 3);
+    }
+
+    #[test]
+    fn requirement_context_matches_draft_for_saved_character() {
+        use crate::services::EdgeService;
+        use crate::test_utils::{
+            insert_test_edge, insert_test_edge_requirement, insert_test_requirement_expression,
+            insert_test_requirement_with_target,
+        };
+
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_edge(&conn, 1, "Quick", "Background");
+        insert_test_requirement_with_target(&conn, 1, "attribute", 1, 8, "Agility d8+");
+        insert_test_requirement_expression(&conn, 1, "requirement", Some(1));
+        insert_test_edge_requirement(&conn, 1, 1, 1);
+        insert_test_edge(&conn, 2, "Improved Quick", "Background");
+        insert_test_requirement_with_target(&conn, 2, "rank", 2, 0, "Seasoned");
+        insert_test_requirement_expression(&conn, 2, "requirement", Some(2));
+        insert_test_edge_requirement(&conn, 2, 2, 2);
+
+        let mut draft = CharacterService::build_new(&conn, "Hero".to_string()).unwrap();
+        draft.attribute_points_earned = 2;
+        CharacterService::set_attribute_dice(&mut draft, &[(1, Die::d8())]).unwrap();
+        let saved = CharacterService::save(&conn, &draft).unwrap();
+
+        let draft_context = draft.to_requirement_context();
+        let saved_context = CharacterService::requirement_context(&conn, saved.id).unwrap();
+        for (edge_id, expected) in [(1, true), (2, false)] {
+            let edge = EdgeService::get_by_id(&conn, edge_id).unwrap().unwrap();
+            assert_eq!(edge.requirements.evaluate(&draft_context), expected, "{}", edge.name);
+            assert_eq!(edge.requirements.evaluate(&saved_context), expected, "{}", edge.name);
+        }
+
+        assert!(matches!(
+            CharacterService::requirement_context(&conn, 99),
+            Err(SwadeError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn set_attribute_dice_rejects_invalid_targets_without_changes() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_attribute(&conn, 1, "Agility");
+        let mut draft = CharacterService::build_new(&conn, "Hero".to_string()).unwrap();
+        draft.attribute_points_earned = 10;
+
+        for targets in [
+            vec![(1, Die::with_modifier(12, 1).unwrap())],
+            vec![(1, Die::d6()), (1, Die::d8())],
+            vec![(99, Die::d6())],
+        ] {
+            assert!(CharacterService::set_attribute_dice(&mut draft, &targets).is_err());
+        }
+        assert_eq!(draft.attributes[0].die, Die::d4());
+        assert_eq!(draft.attribute_points_spent, 0);
+    }
+
+    #[test]
+    fn set_skill_dice_moves_points_between_skills_at_once() {
+        use crate::test_utils::{insert_test_core_skill, insert_test_skill};
+
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_core_skill(&conn, 1, "Athletics", 1);
+        insert_test_skill(&conn, 2, "Fighting", 1);
+        let mut draft = CharacterService::build_new(&conn, "Hero".to_string()).unwrap();
+        draft.skill_points_earned = 4;
+
+        // d4 -> d8 is two steps above the d4 Agility, 2 points each
+        CharacterService::set_skill_dice(&mut draft, &[(1, Some(Die::d8()))]).unwrap();
+        assert_eq!(draft.skill_points_spent, 4);
+
+        // Training Fighting first would overspend before Athletics refunds
+        let mut sequential = draft.clone();
+        assert!(matches!(
+            CharacterService::set_skill_dice(&mut sequential, &[(2, Some(Die::d4()))]),
+            Err(SwadeError::Validation(_))
+        ));
+
+        CharacterService::set_skill_dice(&mut draft, &[(2, Some(Die::d4())), (1, Some(Die::d6()))])
+            .unwrap();
+
+        let die = |id: i64| draft.skills.iter().find(|s| s.skill.id == id).unwrap().die;
+        assert_eq!(die(1), Some(Die::d6()));
+        assert_eq!(die(2), Some(Die::d4()));
+        assert_eq!(draft.skill_points_spent, 3);
+    }
+
+    #[test]
+    fn set_skill_dice_rejects_invalid_targets_without_changes() {
+        use crate::test_utils::insert_test_core_skill;
+
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_core_skill(&conn, 1, "Athletics", 1);
+        let mut draft = CharacterService::build_new(&conn, "Hero".to_string()).unwrap();
+
+        for targets in [
+            vec![(1, None)],
+            vec![(1, Die::with_modifier(12, 1))],
+            vec![(1, Some(Die::d6())), (1, Some(Die::d8()))],
+            vec![(99, Some(Die::d6()))],
+        ] {
+            assert!(CharacterService::set_skill_dice(&mut draft, &targets).is_err());
+        }
+        assert_eq!(draft.skills[0].die, Some(Die::d4()));
+        assert_eq!(draft.skill_points_spent, 0);
+    }
+
+    #[test]
+    fn save_with_an_unspent_skill_point_warns_but_succeeds() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        let mut draft = CharacterService::build_new(&conn, "Hero".to_string()).unwrap();
+        draft.attribute_points_spent = draft.attribute_points_earned;
+        draft.skill_points_spent = draft.skill_points_earned - 1;
+
+        let validation = CharacterService::validate_draft(&draft);
+        assert!(validation.is_valid());
+        assert_eq!(validation.warnings, vec!["1 skill point unspent".to_string()]);
+        assert_eq!(
+            CharacterService::unspent_point_warnings(&draft),
+            vec!["1 skill point unspent".to_string()]
+        );
+
+        let saved = CharacterService::save(&conn, &draft).unwrap();
+        assert_ne!(saved.id, 0);
+        assert_eq!(saved.skill_points_spent, draft.skill_points_earned - 1);
+
+        draft.skill_points_spent = draft.skill_points_earned;
+        assert!(CharacterService::unspent_point_warnings(&draft).is_empty());
+        draft.attribute_points_spent = 0;
+        assert_eq!(
+            CharacterService::unspent_point_warnings(&draft),
+            vec![format!("{} attribute points unspent", draft.attribute_points_earned)]
+        );
+    }
+
+    /// Insert a Human ancestry whose mandatory free-edge choice offers
+    /// Alertness (option 1) or Luck (option 2).
+    fn insert_human_with_free_edge_choice(conn: &Connection) {
+        use crate::test_utils::{insert_test_ancestry, insert_test_edge};
+
+        insert_test_ancestry(conn, 1, "Human");
+        insert_test_edge(conn, 1, "Alertness", "Background");
+        insert_test_edge(conn, 2, "Luck", "Background");
+        conn.execute_batch(
+            "INSERT INTO ancestry_choices (id, ancestry_id, choice_type, description)
+             VALUES (1, 1, 'free_edge', 'Adaptable');
+             INSERT INTO ancestry_choice_options (id, choice_id, option_type, option_id)
+             VALUES (1, 1, 'edge', 1), (2, 1, 'edge', 2);",
+        )
+        .unwrap();
+    }
+
+    /// A new draft with the Human ancestry and its choice not yet made.
+    fn build_human_draft(conn: &Connection) -> CharacterView {
+        use crate::views::CharacterAncestryChoiceValue;
+
+        let mut draft = CharacterService::build_new(conn, "Hero".to_string()).unwrap();
+        let ancestry = AncestryService::get_by_id(conn, 1).unwrap().unwrap();
+        draft.ancestry_choices = ancestry
+            .choices
+            .iter()
+            .map(|c| CharacterAncestryChoiceValue::new(c.clone(), None))
+            .collect();
+        draft.ancestry = Some(ancestry);
+        draft
+    }
+
+    #[test]
+    fn save_refuses_powers_without_arcane_background() {
+        use crate::services::PowerService;
+        use crate::test_utils::insert_test_power;
+        use crate::views::CharacterPowerValue;
+
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_power(&conn, 1, "Bolt");
+        let mut draft = CharacterService::build_new(&conn, "Hero".to_string()).unwrap();
+        let bolt = PowerService::get_by_id(&conn, 1).unwrap().unwrap();
+        draft.powers.push(CharacterPowerValue::new(bolt, None));
+
+        let validation = CharacterService::validate_draft(&draft);
+        assert!(validation.errors.iter().any(|e| e.contains("without an arcane background")));
+        assert!(validation.errors.iter().any(|e| e == "Too many starting powers (1/0)"));
+
+        match CharacterService::save(&conn, &draft) {
+            Err(SwadeError::Validation(message)) => assert!(message.contains("Bolt"), "{}", message),
+            other => panic!("expected validation error, got {:?}", other.map(|c| c.id)),
+        }
+        assert!(CharacterRepository::get_all(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn save_refuses_unmade_mandatory_ancestry_choice() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_human_with_free_edge_choice(&conn);
+        let mut draft = build_human_draft(&conn);
+
+        let result = CharacterService::save(&conn, &draft);
+        match result {
+            Err(SwadeError::Validation(message)) => {
+                assert!(message.contains("Adaptable"), "{}", message);
+            }
+            other => panic!("expected validation error, got {:?}", other.map(|c| c.id)),
+        }
+        assert!(CharacterRepository::get_all(&conn).unwrap().is_empty());
+        assert!(!CharacterService::validate_draft(&draft).is_valid());
+
+        let option = draft.ancestry_choices[0].choice.options[0].clone();
+        draft.ancestry_choices[0].selected_option = Some(option);
+        let saved = CharacterService::save(&conn, &draft).unwrap();
+
+        assert_ne!(saved.id, 0);
+        assert!(saved.pending_ancestry_choices().is_empty());
+    }
+
+    #[test]
+    fn changing_free_edge_choice_swaps_the_ancestry_edge() {
+        use crate::constants::SOURCE_ANCESTRY;
+        use crate::services::EdgeService;
+        use crate::views::CharacterEdgeValue;
+
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_human_with_free_edge_choice(&conn);
+
+        // Select an option the way the draft does: swap the ancestry-sourced edge
+        let select = |character: &mut CharacterView, option_index: usize| {
+            let choice = &mut character.ancestry_choices[0];
+            let option = choice.choice.options[option_index].clone();
+            let edge = EdgeService::get_by_id(&conn, option.option_id.unwrap())
+                .unwrap()
+                .unwrap();
+            choice.selected_option = Some(option);
+            character.edges.retain(|e| e.source != SOURCE_ANCESTRY);
+            character
+                .edges
+                .push(CharacterEdgeValue::new(edge, 0, None, SOURCE_ANCESTRY.to_string()));
+        };
+        let ancestry_edges = |character_id: i64| -> Vec<i64> {
+            CharacterEdgeRepository::get_by_character_id(&conn, character_id)
+                .unwrap()
+                .into_iter()
+                .filter(|e| e.source == SOURCE_ANCESTRY)
+                .map(|e| e.edge_id)
+                .collect()
+        };
+
+        let mut draft = build_human_draft(&conn);
+        select(&mut draft, 0);
+        let mut saved = CharacterService::save(&conn, &draft).unwrap();
+        assert_eq!(ancestry_edges(saved.id), vec![1]);
+
+        select(&mut saved, 1);
+        let saved = CharacterService::save(&conn, &saved).unwrap();
+
+        assert_eq!(ancestry_edges(saved.id), vec![2]);
+        assert_eq!(saved.edges.len(), 1);
+        assert_eq!(saved.edges[0].edge.name, "Luck");
+        // A free edge costs nothing from the hindrance-point or advancement pools
+        assert_eq!(saved.hindrance_points_to_edges, 0);
+        assert_eq!(saved.current_advances, 0);
+        assert!(CharacterService::validate_draft(&saved).is_valid());
+    }
+
+    #[test]
+    fn archive_hides_character_until_restored() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_character(&conn, 2, "Villain");
+
+        CharacterService::archive(&conn, 1).unwrap();
+
+        let active = CharacterService::get_all(&conn).unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].name, "Villain");
+        let archived = CharacterService::get_archived(&conn).unwrap();
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].name, "Hero");
+
+        let restored = CharacterService::restore(&conn, 1).unwrap();
+
+        assert_eq!(restored.name, "Hero");
+        assert_eq!(CharacterService::get_all(&conn).unwrap().len(), 2);
+        assert!(CharacterService::get_archived(&conn).unwrap().is_empty());
+    }
+
+    #[test]
+    fn archive_missing_character_returns_not_found() {
+        let conn = setup_test_db();
+
+        assert!(matches!(
+            CharacterService::archive(&conn, 99),
+            Err(SwadeError::NotFound(_))
+        ));
+        assert!(matches!(
+            CharacterService::restore(&conn, 99),
+            Err(SwadeError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn set_wild_card_warns_about_edges_that_need_a_wild_card() {
+        use crate::test_utils::{
+            insert_test_character_edge_at_advance, insert_test_edge, insert_test_edge_requirement,
+            insert_test_requirement, insert_test_requirement_expression,
+        };
+
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_edge(&conn, 1, "Command", "Leadership");
+        insert_test_requirement(&conn, 1, "wild_card", "Wild Card");
+        insert_test_requirement_expression(&conn, 1, "requirement", Some(1));
+        insert_test_edge_requirement(&conn, 1, 1, 1);
+        insert_test_character_edge_at_advance(&conn, 1, 1, 1, 0);
+
+        let (extra, warnings) = CharacterService::set_wild_card(&conn, 1, false).unwrap();
+
+        assert!(!extra.is_wild_card);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("Command"));
+        assert!(warnings[0].contains("Wild Card"));
+        let stored = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        assert!(!stored.is_wild_card);
+
+        let (wild_card, warnings) = CharacterService::set_wild_card(&conn, 1, true).unwrap();
+
+        assert!(wild_card.is_wild_card);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn set_wild_card_missing_character_returns_not_found() {
+        let conn = setup_test_db();
+
+        assert!(matches!(
+            CharacterService::set_wild_card(&conn, 99, true),
+            Err(SwadeError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn get_by_id_loads_weapon_combat_stats_on_gear() {
+        use crate::test_utils::{insert_test_gear, insert_test_gear_category};
+
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_gear_category(&conn, 1, "Weapons");
+        insert_test_gear(&conn, 1, "Spear", 1, 100);
+        insert_test_gear(&conn, 2, "Throwing Axe", 1, 75);
+        conn.execute_batch(
+            "INSERT INTO weapon_stats (gear_id, damage, ap, reach, is_two_handed)
+             VALUES (1, 'Str+d6', 0, 1, 1);
+             INSERT INTO weapon_stats (gear_id, damage, ap, range_short, range_medium, range_long, rof)
+             VALUES (2, 'Str+d6', 0, 3, 6, 12, 1);
+             INSERT INTO character_gear (character_id, gear_id, quantity, is_equipped)
+             VALUES (1, 1, 1, 1), (1, 2, 1, 0);",
+        )
+        .unwrap();
+
+        let character = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+
+        let spear = character.gear.iter().find(|g| g.gear.name == "Spear").unwrap();
+        assert_eq!(spear.damage.as_deref(), Some("Str+d6"));
+        assert_eq!(spear.reach, Some(1));
+        assert_eq!(spear.range, None);
+        let axe = character.gear.iter().find(|g| g.gear.name == "Throwing Axe").unwrap();
+        assert_eq!(axe.range.as_deref(), Some("3/6/12"));
+        assert_eq!(axe.rof, Some(1));
+    }
+
+    #[test]
+    fn check_not_stale_detects_concurrent_save() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        let draft = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        assert!(CharacterService::check_not_stale(&conn, &draft).is_ok());
+
+        // Two windows save the same character within the same second
+        let mut first = draft.clone();
+        first.name = "Renamed".to_string();
+        CharacterService::save(&conn, &first).unwrap();
+        let mut second = draft.clone();
+        second.name = "Renamed again".to_string();
+
+        let result = CharacterService::check_not_stale(&conn, &second);
+        assert!(matches!(result, Err(SwadeError::Conflict(_))));
+
+        let reloaded = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        assert!(CharacterService::check_not_stale(&conn, &reloaded).is_ok());
+        let saved = CharacterService::save(&conn, &reloaded).unwrap();
+        assert!(CharacterService::check_not_stale(&conn, &saved).is_ok());
+        assert!(CharacterService::check_not_stale(&conn, &reloaded).is_err());
+    }
+
+    #[test]
+    fn check_not_stale_passes_unsaved_drafts() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        let draft = CharacterService::build_new(&conn, "New".to_string()).unwrap();
+
+        assert!(CharacterService::check_not_stale(&conn, &draft).is_ok());
+    }
+
+    #[test]
+    fn set_wounds_clamps_to_one_past_max() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+
+        let wounded = CharacterService::set_wounds(&conn, 1, 3).unwrap();
+        assert_eq!(wounded.wounds, 3);
+        assert_eq!(wounded.derived_stats.wound_penalty, 3);
+        assert!(!wounded.incapacitated);
+
+        let down = CharacterService::set_wounds(&conn, 1, 10).unwrap();
+        assert_eq!(down.wounds, 4);
+        assert_eq!(down.derived_stats.wound_penalty, 3);
+        assert!(down.incapacitated);
+
+        let healed = CharacterService::set_wounds(&conn, 1, -2).unwrap();
+        assert_eq!(healed.wounds, 0);
+        assert!(!healed.incapacitated);
+    }
+
+    #[test]
+    fn increment_wounds_respects_configured_max() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        conn.execute("UPDATE characters SET max_wounds = 4 WHERE id = 1", [])
+            .unwrap();
+
+        CharacterService::increment_wounds(&conn, 1, 4).unwrap();
+        let at_max = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        assert_eq!(at_max.wounds, 4);
+        assert!(!at_max.incapacitated);
+
+        let down = CharacterService::increment_wounds(&conn, 1, 3).unwrap();
+        assert_eq!(down.wounds, 5);
+        assert!(down.incapacitated);
+
+        let healed = CharacterService::increment_wounds(&conn, 1, -1).unwrap();
+        assert_eq!(healed.wounds, 4);
+    }
+
+    #[test]
+    fn fatigue_clamps_and_adds_to_trait_penalty() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        CharacterService::set_wounds(&conn, 1, 1).unwrap();
+
+        let exhausted = CharacterService::increment_fatigue(&conn, 1, 2).unwrap();
+        assert_eq!(exhausted.fatigue, 2);
+        assert_eq!(exhausted.derived_stats.fatigue_penalty, 2);
+        assert_eq!(exhausted.derived_stats.status_penalty(), 3);
+        assert!(!exhausted.incapacitated);
+
+        let down = CharacterService::increment_fatigue(&conn, 1, 5).unwrap();
+        assert_eq!(down.fatigue, 3);
+        assert_eq!(down.derived_stats.fatigue_penalty, 2);
+        assert!(down.incapacitated);
+
+        let rested = CharacterService::set_fatigue(&conn, 1, -1).unwrap();
+        assert_eq!(rested.fatigue, 0);
+    }
+
+    #[test]
+    fn status_changes_on_missing_character_return_not_found() {
+        let conn = setup_test_db();
+
+        assert!(matches!(
+            CharacterService::set_wounds(&conn, 99, 1),
+            Err(SwadeError::NotFound(_))
+        ));
+        assert!(matches!(
+            CharacterService::increment_fatigue(&conn, 99, 1),
+            Err(SwadeError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn repair_character_skills_adds_missing_core_skill_at_d4() {
+        use crate::test_utils::{insert_test_core_skill, insert_test_skill};
+
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Veteran");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_core_skill(&conn, 1, "Athletics", 1);
+        insert_test_core_skill(&conn, 2, "Notice", 1);
+        insert_test_skill(&conn, 3, "Fighting", 1);
+        conn.execute(
+            "INSERT INTO character_skills (character_id, skill_id, current_die_size,
+                                          current_die_modifier, created_at, updated_at)
+             VALUES (1, 1, 8, 0, '2024-01-01', '2024-01-01')",
+            [],
+        )
+        .unwrap();
+
+        let (character, added) = CharacterService::repair_character_skills(&conn, 1).unwrap();
+
+        assert_eq!(added, vec!["Notice".to_string()]);
+        let skill_die = |name: &str| {
+            character
+                .skills
+                .iter()
+                .find(|s| s.skill.name == name)
+                .and_then(|s| s.die)
+        };
+        assert_eq!(skill_die("Notice"), Some(Die::d4()));
+        assert_eq!(skill_die("Athletics"), Some(Die::d8()));
+        assert_eq!(skill_die("Fighting"), None);
+
+        let (_, added_again) = CharacterService::repair_character_skills(&conn, 1).unwrap();
+        assert!(added_again.is_empty());
+    }
+
+    #[test]
+    fn failed_repair_character_skills_adds_nothing() {
+        use crate::test_utils::insert_test_core_skill;
+
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Veteran");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_core_skill(&conn, 1, "Athletics", 1);
+        insert_test_core_skill(&conn, 2, "Notice", 1);
+        // Fail the second insert, after the first skill is already added
+        conn.execute_batch(
+            "CREATE TRIGGER fail_second_skill BEFORE INSERT ON character_skills
+             WHEN NEW.skill_id = 2
+             BEGIN SELECT RAISE(ABORT, 'injected failure'); END;",
+        )
+        .unwrap();
+
+        assert!(CharacterService::repair_character_skills(&conn, 1).is_err());
+        assert!(CharacterSkillRepository::get_by_character_id(&conn, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn repair_character_skills_missing_character_returns_not_found() {
+        let conn = setup_test_db();
+
+        assert!(matches!(
+            CharacterService::repair_character_skills(&conn, 99),
+            Err(SwadeError::NotFound(_))
+        ));
+    }
+
+    fn encode_test_image(width: u32, height: u32, format: ImageFormat) -> Vec<u8> {
+        let image = image::RgbImage::from_fn(width, height, |x, y| {
+            image::Rgb([(x % 256) as u8, (y % 256) as u8, ((x * y) % 256) as u8])
+        });
+        let mut bytes = Cursor::new(Vec::new());
+        image.write_to(&mut bytes, format).unwrap();
+        bytes.into_inner()
+    }
+
+    #[test]
+    fn update_portrait_downscales_oversized_images_to_png() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        let upload = encode_test_image(2048, 1024, ImageFormat::Png);
+
+        let character = CharacterService::update_portrait(&conn, 1, &upload).unwrap();
+
+        let stored = CharacterRepository::get_by_id(&conn, 1).unwrap().unwrap();
+        let portrait = stored.portrait.unwrap();
+        assert!(portrait.len() < upload.len());
+        assert_eq!(stored.portrait_mime_type.as_deref(), Some("image/png"));
+        let decoded = image::load_from_memory(&portrait).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (512, 256));
+        assert!(character.portrait_data_url.unwrap().starts_with("data:image/png;base64,"));
+    }
+
+    #[test]
+    fn get_by_id_omits_portrait_unless_asked_for() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        CharacterService::update_portrait(&conn, 1, &encode_test_image(16, 16, ImageFormat::Png))
+            .unwrap();
+
+        let character = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        let portrait = CharacterService::get_portrait(&conn, 1).unwrap();
+        let with_portrait = CharacterService::get_by_id_with_portrait(&conn, 1).unwrap().unwrap();
+
+        assert!(character.portrait_data_url.is_none());
+        assert!(portrait.as_deref().unwrap().starts_with("data:image/png;base64,"));
+        assert_eq!(with_portrait.portrait_data_url, portrait);
+    }
+
+    #[test]
+    fn get_portrait_returns_none_without_portrait_and_errors_for_missing_character() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+
+        assert_eq!(CharacterService::get_portrait(&conn, 1).unwrap(), None);
+        assert!(matches!(
+            CharacterService::get_portrait(&conn, 99),
+            Err(SwadeError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn update_portrait_reencodes_small_jpeg_without_resizing() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        let upload = encode_test_image(100, 80, ImageFormat::Jpeg);
+
+        CharacterService::update_portrait(&conn, 1, &upload).unwrap();
+
+        let stored = CharacterRepository::get_by_id(&conn, 1).unwrap().unwrap();
+        let decoded = image::load_from_memory_with_format(
+            &stored.portrait.unwrap(),
+            ImageFormat::Png,
+        )
+        .unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (100, 80));
+    }
+
+    #[test]
+    fn update_portrait_rejects_non_image_data() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+
+        let result = CharacterService::update_portrait(&conn, 1, b"not an image");
+
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+        let stored = CharacterRepository::get_by_id(&conn, 1).unwrap().unwrap();
+        assert!(stored.portrait.is_none());
+    }
+
+    #[test]
+    fn delete_archived_character_removes_it_permanently() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        conn.execute(
+            "INSERT INTO character_notes (character_id, title, body) VALUES (1, 'Note', 'Text')",
+            [],
+        )
+        .unwrap();
+        CharacterService::archive(&conn, 1).unwrap();
+
+        CharacterService::delete(&conn, 1).unwrap();
+
+        assert!(CharacterService::get_by_id(&conn, 1).unwrap().is_none());
+        assert!(CharacterService::get_archived(&conn).unwrap().is_empty());
+        let notes: i64 = conn
+            .query_row("SELECT COUNT(*) FROM character_notes", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(notes, 0);
+    }
+
+    #[test]
+    fn normalize_name_trims_and_rejects_blank_names() {
+        assert_eq!(CharacterService::normalize_name("  Hero ").unwrap(), "Hero");
+        assert!(matches!(
+            CharacterService::normalize_name(""),
+            Err(SwadeError::Validation(_))
+        ));
+        assert!(matches!(
+            CharacterService::normalize_name(" \t\n "),
+            Err(SwadeError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn normalize_name_rejects_overlong_names() {
+        let longest = "a".repeat(MAX_CHARACTER_NAME_LENGTH);
+        assert_eq!(CharacterService::normalize_name(&longest).unwrap(), longest);
+
+        let overlong = "a".repeat(MAX_CHARACTER_NAME_LENGTH + 1);
+        assert!(matches!(
+            CharacterService::normalize_name(&overlong),
+            Err(SwadeError::Validation(_))
+        ));
+        // Surrounding whitespace doesn't count toward the limit
+        assert!(CharacterService::normalize_name(&format!(" {} ", longest)).is_ok());
+    }
+
+    #[test]
+    fn get_by_id_clamps_out_of_range_stored_dice() {
+        use crate::test_utils::insert_test_skill;
+
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_attribute(&conn, 2, "Smarts");
+        insert_character_attribute(&conn, 1, 1, 10);
+        insert_character_attribute(&conn, 1, 2, 2);
+        insert_test_skill(&conn, 1, "Fighting", 1);
+        conn.execute(
+            "INSERT INTO character_skills (character_id, skill_id, current_die_size,
+                                          current_die_modifier, created_at, updated_at)
+             VALUES (1, 1, 14, 0, '2024-01-01', '2024-01-01')",
+            [],
+        )
+        .unwrap();
+
+        let character = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+
+        assert_eq!(character.attributes[0].die, Die::with_modifier(12, 3).unwrap());
+        assert_eq!(character.attributes[1].die, Die::d8());
+        assert_eq!(character.skills[0].die, Some(Die::d12()));
+        assert_eq!(
+            character.data_warnings,
+            vec![
+                "Fighting stored as d14, clamped to d12".to_string(),
+                "Agility stored as d12+6, clamped to d12+3".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn get_by_id_keeps_dice_raised_by_config() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_character_attribute(&conn, 1, 1, 9);
+        conn.execute(
+            "UPDATE characters SET max_attribute_die_size = 12, max_attribute_die_modifier = 5",
+            [],
+        )
+        .unwrap();
+
+        let character = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+
+        assert_eq!(character.attributes[0].die, Die::with_modifier(12, 5).unwrap());
+        assert!(character.data_warnings.is_empty());
+    }
+
+    #[test]
+    fn rename_updates_only_the_name() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        let before = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+
+        let renamed = CharacterService::rename(&conn, 1, "  Heroine ").unwrap();
+
+        assert_eq!(renamed.name, "Heroine");
+        assert_eq!(renamed.revision, before.revision + 1);
+        assert_eq!(renamed.background, before.background);
+        assert_eq!(renamed.description, before.description);
+        let stored = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        assert_eq!(stored.name, "Heroine");
+
+        assert!(matches!(
+            CharacterService::rename(&conn, 1, "   "),
+            Err(SwadeError::Validation(_))
+        ));
+        assert!(matches!(
+            CharacterService::rename(&conn, 99, "Nobody"),
+            Err(SwadeError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn set_campaign_persists_and_filters() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_character(&conn, 2, "Villain");
+
+        let hero = CharacterService::set_campaign(&conn, 1, Some("  Deadlands ")).unwrap();
+        CharacterService::set_campaign(&conn, 2, Some("Rippers")).unwrap();
+
+        assert_eq!(hero.campaign.as_deref(), Some("Deadlands"));
+        let deadlands = CharacterService::get_by_campaign(&conn, "Deadlands").unwrap();
+        assert_eq!(deadlands.len(), 1);
+        assert_eq!(deadlands[0].name, "Hero");
+
+        let saved = CharacterService::save(&conn, &hero).unwrap();
+        assert_eq!(saved.campaign.as_deref(), Some("Deadlands"));
+
+        let cleared = CharacterService::set_campaign(&conn, 1, Some(" ")).unwrap();
+        assert_eq!(cleared.campaign, None);
+    }
 }
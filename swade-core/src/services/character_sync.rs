@@ -58,6 +58,10 @@ pub fn insert_ancestry_choices(
 }
 
 /// Remove an ancestry-sourced edge or hindrance.
+///
+/// Every ancestry-sourced copy of an edge is removed, matching the draft, so a
+/// swapped free edge never leaves a stale copy behind. Copies of the same edge
+/// from other sources are left alone.
 fn remove_ancestry_selection(
     conn: &Connection,
     character_id: i64,
@@ -70,7 +74,6 @@ fn remove_ancestry_selection(
             for edge in edges {
                 if edge.edge_id == option_id && edge.source == SOURCE_ANCESTRY {
                     CharacterEdgeRepository::delete(conn, edge.id)?;
-                    break;
                 }
             }
         }
@@ -83,7 +86,8 @@ fn remove_ancestry_selection(
                 }
             }
         }
-        AncestryOptionType::Ancestry => {}
+        // Attribute and skill bonuses are derived from the saved choice, nothing else to remove
+        AncestryOptionType::Ancestry | AncestryOptionType::Attribute | AncestryOptionType::Skill => {}
     }
     Ok(())
 }
@@ -121,7 +125,7 @@ fn insert_ancestry_selection(
             };
             CharacterHindranceRepository::insert(conn, &char_hindrance)?;
         }
-        AncestryOptionType::Ancestry => {}
+        AncestryOptionType::Ancestry | AncestryOptionType::Attribute | AncestryOptionType::Skill => {}
     }
     Ok(())
 }
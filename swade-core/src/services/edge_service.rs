@@ -21,6 +21,24 @@ impl EdgeService {
         Ok(views)
     }
 
+    /// Search edges by name or description, optionally filtered by category.
+    pub fn search(
+        conn: &Connection,
+        query: &str,
+        category: Option<&str>,
+    ) -> Result<Vec<EdgeView>> {
+        let edges = EdgeRepository::search(conn, query, category)?;
+
+        let mut views = Vec::new();
+        for edge in edges {
+            let modifiers = ModifierService::get_for_edge(conn, edge.id)?;
+            let requirements = RequirementService::get_for_edge(conn, edge.id)?;
+            views.push(EdgeView::new(edge, modifiers, requirements));
+        }
+
+        Ok(views)
+    }
+
     pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<EdgeView>> {
         let edge = EdgeRepository::get_by_id(conn, id)?;
 
@@ -155,6 +173,22 @@ mod tests {
         assert!(!edges[0].requirements.is_empty());
     }
 
+    #[test]
+    fn search_filters_by_partial_name_and_category() {
+        let conn = setup_test_db();
+        insert_test_edge(&conn, 1, "Brawny", "Background");
+        insert_test_edge(&conn, 2, "Brawler", "Combat");
+        insert_test_edge(&conn, 3, "Block", "Combat");
+        insert_modifier(&conn, 1, "+2 to Notice");
+        insert_edge_modifier(&conn, 2, 1);
+
+        let edges = EdgeService::search(&conn, "BRAW", Some("Combat")).unwrap();
+
+        assert_eq!(edges.len(), 1);
+        assert_eq!(edges[0].name, "Brawler");
+        assert_eq!(edges[0].modifiers.len(), 1);
+    }
+
     #[test]
     fn get_by_id_returns_none_when_not_found() {
         let conn = setup_test_db();
@@ -0,0 +1,373 @@
+//! Import characters from Foundry VTT `swade` system actor exports.
+
+use std::collections::BTreeMap;
+
+use rusqlite::Connection;
+use serde::Deserialize;
+
+use crate::constants::SOURCE_CHOSEN;
+use crate::error::{Result, SwadeError};
+use crate::models::{CharacterEdge, CharacterNote};
+use crate::repositories::{CharacterEdgeRepository, CharacterNoteRepository};
+use crate::services::{CharacterService, EdgeService, HindranceService};
+use crate::views::{CharacterHindranceValue, CharacterView, Die, EdgeView, Severity};
+
+/// The parts of a Foundry actor export we read.
+#[derive(Debug, Deserialize)]
+struct FoundryActor {
+    name: String,
+    #[serde(rename = "type", default)]
+    actor_type: String,
+    // Foundry v10+ uses `system`; older exports use `data`
+    #[serde(alias = "data")]
+    system: FoundryActorSystem,
+    #[serde(default)]
+    items: Vec<FoundryItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FoundryActorSystem {
+    #[serde(default)]
+    attributes: BTreeMap<String, FoundryTrait>,
+    wildcard: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FoundryTrait {
+    die: FoundryDie,
+}
+
+#[derive(Debug, Deserialize)]
+struct FoundryDie {
+    sides: u8,
+    #[serde(default)]
+    modifier: i8,
+}
+
+impl FoundryDie {
+    fn to_die(&self) -> Option<Die> {
+        Die::from_size_modifier(self.sides, self.modifier)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct FoundryItem {
+    name: String,
+    #[serde(rename = "type")]
+    item_type: String,
+    #[serde(alias = "data", default)]
+    system: FoundryItemSystem,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FoundryItemSystem {
+    die: Option<FoundryDie>,
+    major: Option<bool>,
+}
+
+pub struct FoundryImportService;
+
+impl FoundryImportService {
+    /// Create a new character from a Foundry `swade` actor JSON export.
+    ///
+    /// Attributes, skills, edges, and hindrances are matched to ours by name.
+    /// Anything that can't be matched (unknown names, unsupported dice, gear,
+    /// powers, ...) is listed in an "Imported from Foundry" note on the new
+    /// character instead of being dropped. Runs in one transaction, so a
+    /// failed import leaves no partial character behind.
+    pub fn import(conn: &Connection, json: &str) -> Result<CharacterView> {
+        let actor: FoundryActor = serde_json::from_str(json)
+            .map_err(|e| SwadeError::Validation(format!("Invalid Foundry actor JSON: {}", e)))?;
+
+        let mut character = CharacterService::build_new(conn, actor.name)?;
+        character.is_wild_card = actor
+            .system
+            .wildcard
+            .unwrap_or(actor.actor_type == "character");
+
+        let mut unmatched = Vec::new();
+
+        for (name, foundry_trait) in &actor.system.attributes {
+            let attribute = character
+                .attributes
+                .iter_mut()
+                .find(|a| a.attribute.name.eq_ignore_ascii_case(name));
+            match (attribute, foundry_trait.die.to_die()) {
+                (Some(attribute), Some(die)) => attribute.die = die,
+                _ => unmatched.push(format!("Attribute: {}", name)),
+            }
+        }
+
+        let all_edges = EdgeService::get_all(conn)?;
+        let all_hindrances = HindranceService::get_all(conn)?;
+        let mut edges: Vec<&EdgeView> = Vec::new();
+
+        for item in &actor.items {
+            match item.item_type.as_str() {
+                "skill" => {
+                    let skill = character
+                        .skills
+                        .iter_mut()
+                        .find(|s| s.skill.name.eq_ignore_ascii_case(&item.name));
+                    match (skill, item.system.die.as_ref().and_then(|d| d.to_die())) {
+                        (Some(skill), Some(die)) => skill.die = Some(die),
+                        _ => unmatched.push(format!("Skill: {}", item.name)),
+                    }
+                }
+                "edge" => match all_edges
+                    .iter()
+                    .find(|e| e.name.eq_ignore_ascii_case(&item.name))
+                {
+                    Some(edge) => edges.push(edge),
+                    None => unmatched.push(format!("Edge: {}", item.name)),
+                },
+                "hindrance" => {
+                    let severity = if item.system.major.unwrap_or(false) {
+                        Severity::Major
+                    } else {
+                        Severity::Minor
+                    };
+                    let named: Vec<_> = all_hindrances
+                        .iter()
+                        .filter(|h| h.name.eq_ignore_ascii_case(&item.name))
+                        .collect();
+                    // Prefer the matching severity for hindrances that come in both
+                    let hindrance = named
+                        .iter()
+                        .find(|h| h.severity == severity)
+                        .or(named.first());
+                    match hindrance {
+                        Some(hindrance) => character.hindrances.push(CharacterHindranceValue::new(
+                            (*hindrance).clone(),
+                            SOURCE_CHOSEN.to_string(),
+                        )),
+                        None => unmatched.push(format!("Hindrance: {}", item.name)),
+                    }
+                }
+                other => unmatched.push(format!("{}: {}", other, item.name)),
+            }
+        }
+
+        character.attribute_points_spent = character
+            .attributes
+            .iter()
+            .map(|a| a.die.steps_from(a.attribute.base_die) as i64)
+            .sum();
+        character.skill_points_spent = Self::skill_points_spent(&character);
+        character.hindrance_points_earned = character.earned_hindrance_points();
+        character.compute_effective_values();
+
+        CharacterService::check_saveable(&character)?;
+
+        let tx = conn.unchecked_transaction()?;
+        let character_id = Self::import_inner(&tx, &character, &edges, &unmatched)?;
+        tx.commit()?;
+
+        CharacterService::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))
+    }
+
+    /// Write the built character, its edges, and the unmatched-items note,
+    /// returning the new character's id.
+    fn import_inner(
+        conn: &Connection,
+        character: &CharacterView,
+        edges: &[&EdgeView],
+        unmatched: &[String],
+    ) -> Result<i64> {
+        let saved = CharacterService::save_inner(conn, character)?;
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        // Creation saves only persist edges bought with hindrance points, so
+        // imported edges are recorded directly as chosen
+        for edge in edges {
+            CharacterEdgeRepository::insert(
+                conn,
+                &CharacterEdge {
+                    id: 0,
+                    character_id: saved.id,
+                    edge_id: edge.id,
+                    advance_taken: 0,
+                    notes: None,
+                    source: SOURCE_CHOSEN.to_string(),
+                    created_at: now.clone(),
+                    updated_at: now.clone(),
+                },
+            )?;
+        }
+
+        if !unmatched.is_empty() {
+            CharacterNoteRepository::insert(
+                conn,
+                &CharacterNote {
+                    id: 0,
+                    character_id: saved.id,
+                    title: "Imported from Foundry".to_string(),
+                    body: format!("Not imported:\n- {}", unmatched.join("\n- ")),
+                    pinned: false,
+                    category: None,
+                    created_at: now.clone(),
+                    updated_at: now,
+                },
+            )?;
+        }
+
+        Ok(saved.id)
+    }
+
+    /// Skill points needed to raise every skill from its starting die to its
+    /// current one, paying double for steps above the linked attribute.
+    fn skill_points_spent(character: &CharacterView) -> i64 {
+        character
+            .skills
+            .iter()
+            .map(|skill| {
+                let linked_die = character
+                    .attributes
+                    .iter()
+                    .find(|a| a.attribute.id == skill.skill.linked_attribute_id)
+                    .map(|a| a.die)
+                    .unwrap_or(Die::d4());
+                CharacterService::skill_die_cost(skill.skill.is_core_skill, skill.die, linked_die)
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        insert_test_attribute, insert_test_core_skill, insert_test_edge, insert_test_hindrance,
+        insert_test_rank, insert_test_skill, setup_test_db,
+    };
+
+    const FOUNDRY_ACTOR: &str = include_str!("../../tests/fixtures/foundry_actor.json");
+
+    fn setup_import_db() -> Connection {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        for (id, name) in ["Agility", "Smarts", "Spirit", "Strength", "Vigor"]
+            .into_iter()
+            .enumerate()
+        {
+            insert_test_attribute(&conn, id as i64 + 1, name);
+        }
+        insert_test_core_skill(&conn, 1, "Athletics", 1);
+        insert_test_core_skill(&conn, 2, "Notice", 2);
+        insert_test_skill(&conn, 3, "Fighting", 1);
+        insert_test_skill(&conn, 4, "Shooting", 1);
+        insert_test_edge(&conn, 1, "Brawny", "Background");
+        insert_test_hindrance(&conn, 1, "Loyal", "minor", 1);
+        insert_test_hindrance(&conn, 2, "Wanted", "minor", 1);
+        insert_test_hindrance(&conn, 3, "Wanted", "major", 2);
+        conn
+    }
+
+    #[test]
+    fn import_maps_attributes_and_skills() {
+        let conn = setup_import_db();
+
+        let character = FoundryImportService::import(&conn, FOUNDRY_ACTOR).unwrap();
+
+        assert!(character.id > 0);
+        assert_eq!(character.name, "Red Harlan");
+        assert!(character.is_wild_card);
+        let attribute_die = |name: &str| {
+            character
+                .attributes
+                .iter()
+                .find(|a| a.attribute.name == name)
+                .map(|a| a.die)
+        };
+        assert_eq!(attribute_die("Agility"), Some(Die::d8()));
+        assert_eq!(attribute_die("Smarts"), Some(Die::d6()));
+        assert_eq!(attribute_die("Strength"), Die::with_modifier(12, 1));
+        let skill_die = |name: &str| {
+            character
+                .skills
+                .iter()
+                .find(|s| s.skill.name == name)
+                .and_then(|s| s.die)
+        };
+        assert_eq!(skill_die("Fighting"), Some(Die::d8()));
+        assert_eq!(skill_die("Notice"), Some(Die::d6()));
+        assert_eq!(skill_die("Athletics"), Some(Die::d6()));
+        assert_eq!(skill_die("Shooting"), None);
+    }
+
+    #[test]
+    fn import_counts_points_spent_on_attributes_and_skills() {
+        let conn = setup_import_db();
+
+        let character = FoundryImportService::import(&conn, FOUNDRY_ACTOR).unwrap();
+
+        // d8 + d6 + d6 + d12+1 + d6 over d4 bases
+        assert_eq!(character.attribute_points_spent, 10);
+        // Athletics and Notice one step each, Fighting untrained to d8
+        assert_eq!(character.skill_points_spent, 5);
+        assert_eq!(character.hindrance_points_earned, 3);
+    }
+
+    #[test]
+    fn import_resolves_edges_and_hindrances_by_name() {
+        let conn = setup_import_db();
+
+        let character = FoundryImportService::import(&conn, FOUNDRY_ACTOR).unwrap();
+
+        let edges: Vec<&str> = character.edges.iter().map(|e| e.edge.name.as_str()).collect();
+        assert_eq!(edges, vec!["Brawny"]);
+        let mut hindrances: Vec<(&str, Severity)> = character
+            .hindrances
+            .iter()
+            .map(|h| (h.hindrance.name.as_str(), h.hindrance.severity))
+            .collect();
+        hindrances.sort_by_key(|h| h.0);
+        assert_eq!(
+            hindrances,
+            vec![("Loyal", Severity::Minor), ("Wanted", Severity::Major)]
+        );
+    }
+
+    #[test]
+    fn import_lists_unknown_items_in_a_note() {
+        let conn = setup_import_db();
+
+        let character = FoundryImportService::import(&conn, FOUNDRY_ACTOR).unwrap();
+
+        assert_eq!(character.notes.len(), 1);
+        let note = &character.notes[0];
+        assert_eq!(note.title, "Imported from Foundry");
+        assert!(note.body.contains("Skill: Underwater Basket Weaving"));
+        assert!(note.body.contains("Edge: Lucky Charm"));
+        assert!(note.body.contains("weapon: Long Sword"));
+    }
+
+    #[test]
+    fn failed_import_leaves_no_partial_character() {
+        let conn = setup_import_db();
+        // Fail the last write, after the character and its edges are inserted
+        conn.execute_batch(
+            "CREATE TRIGGER fail_character_notes BEFORE INSERT ON character_notes
+             BEGIN SELECT RAISE(ABORT, 'injected failure'); END;",
+        )
+        .unwrap();
+
+        let result = FoundryImportService::import(&conn, FOUNDRY_ACTOR);
+
+        assert!(result.is_err());
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM characters", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn import_rejects_invalid_json() {
+        let conn = setup_import_db();
+
+        let result = FoundryImportService::import(&conn, "{\"name\": \"No system\"}");
+
+        assert!(matches!(result, Err(SwadeError::Validation(_))));
+    }
+}
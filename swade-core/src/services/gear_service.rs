@@ -1,9 +1,10 @@
 use rusqlite::Connection;
 
-use crate::error::Result;
+use crate::error::{Result, SwadeError};
 use crate::repositories::{
-    AmmunitionStatsRepository, ArmorStatsRepository, GearCategoryRepository, GearRepository,
-    PackContentsRepository, PoisonStatsRepository, ShieldStatsRepository, WeaponStatsRepository,
+    AmmunitionStatsRepository, ArmorStatsRepository, CharacterGearRepository,
+    CharacterRepository, GearCategoryRepository, GearRepository, PackContentsRepository,
+    PoisonStatsRepository, ShieldStatsRepository, WeaponStatsRepository,
 };
 use crate::services::{ModifierService, RequirementService};
 use crate::views::{
@@ -56,6 +57,116 @@ impl GearService {
         Ok(categories.into_iter().map(GearCategoryView::new).collect())
     }
 
+    /// Fail with `InsufficientFunds` if `cost` is more than `wealth`.
+    pub fn check_affordable(wealth: i64, cost: i64) -> Result<()> {
+        if cost > wealth {
+            return Err(SwadeError::InsufficientFunds {
+                needed: cost,
+                available: wealth,
+            });
+        }
+        Ok(())
+    }
+
+    /// What selling `quantity` items costing `cost` each returns at
+    /// `sell_back_percent`, rounded down.
+    pub fn sale_value(cost: i64, quantity: i64, sell_back_percent: i64) -> i64 {
+        (cost * quantity * sell_back_percent).div_euclid(100)
+    }
+
+    /// Sell `quantity` of a character's gear entry at `sell_back_percent` and
+    /// credit the proceeds to their wealth. Returns the amount credited.
+    ///
+    /// Runs in a savepoint, so the wealth and the gear change together.
+    pub fn sell(
+        conn: &Connection,
+        character_gear_id: i64,
+        quantity: i64,
+        sell_back_percent: i64,
+    ) -> Result<i64> {
+        conn.execute_batch("SAVEPOINT sell_gear")?;
+        let result = Self::sell_item(conn, character_gear_id, quantity, sell_back_percent);
+        match result {
+            Ok(_) => conn.execute_batch("RELEASE sell_gear")?,
+            Err(_) => conn.execute_batch("ROLLBACK TO sell_gear; RELEASE sell_gear")?,
+        }
+        result
+    }
+
+    fn sell_item(
+        conn: &Connection,
+        character_gear_id: i64,
+        quantity: i64,
+        sell_back_percent: i64,
+    ) -> Result<i64> {
+        let mut item = CharacterGearRepository::get_by_id(conn, character_gear_id)?
+            .ok_or_else(|| SwadeError::NotFound("Character gear not found".to_string()))?;
+        if quantity > item.quantity {
+            return Err(SwadeError::Validation(format!(
+                "Cannot sell {} items, only have {}",
+                quantity, item.quantity
+            )));
+        }
+        let gear = GearRepository::get_by_id(conn, item.gear_id)?
+            .ok_or_else(|| SwadeError::NotFound("Gear not found".to_string()))?;
+
+        let value = Self::sale_value(gear.cost, quantity, sell_back_percent);
+        CharacterRepository::adjust_wealth(conn, item.character_id, value)?;
+
+        if quantity >= item.quantity {
+            CharacterGearRepository::delete(conn, character_gear_id)?;
+        } else {
+            item.quantity -= quantity;
+            item.updated_at = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+            CharacterGearRepository::update(conn, &item)?;
+        }
+        Ok(value)
+    }
+
+    /// Sell every item the character doesn't have equipped and credit the
+    /// proceeds to their wealth. Returns the total credited.
+    ///
+    /// Runs in a savepoint, so either everything is sold or nothing is.
+    pub fn sell_all_unequipped(
+        conn: &Connection,
+        character_id: i64,
+        sell_back_percent: i64,
+    ) -> Result<i64> {
+        conn.execute_batch("SAVEPOINT sell_all_unequipped")?;
+        let result = Self::sell_unequipped_items(conn, character_id, sell_back_percent);
+        match result {
+            Ok(_) => conn.execute_batch("RELEASE sell_all_unequipped")?,
+            Err(_) => conn.execute_batch(
+                "ROLLBACK TO sell_all_unequipped; RELEASE sell_all_unequipped",
+            )?,
+        }
+        result
+    }
+
+    fn sell_unequipped_items(
+        conn: &Connection,
+        character_id: i64,
+        sell_back_percent: i64,
+    ) -> Result<i64> {
+        let mut total = 0;
+        for item in CharacterGearRepository::get_by_character_id(conn, character_id)? {
+            if item.is_equipped {
+                continue;
+            }
+            let gear = GearRepository::get_by_id(conn, item.gear_id)?
+                .ok_or_else(|| SwadeError::NotFound(format!("Gear {} not found", item.gear_id)))?;
+            total += Self::sale_value(gear.cost, item.quantity, sell_back_percent);
+            CharacterGearRepository::delete(conn, item.id)?;
+        }
+        CharacterRepository::adjust_wealth(conn, character_id, total)?;
+        Ok(total)
+    }
+
+    /// Keep only the gear items that cost no more than the given wealth
+    pub fn filter_affordable(gear: Vec<GearView>, wealth: i64) -> Vec<GearView> {
+        gear.into_iter().filter(|g| g.cost <= wealth).collect()
+    }
+
     /// Build a complete GearView from a Gear model
     fn build_gear_view(conn: &Connection, gear: crate::models::Gear) -> Result<GearView> {
         Self::build_gear_view_internal(conn, gear, true)
@@ -141,3 +252,127 @@ impl GearService {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::DEFAULT_SELL_BACK_PERCENT;
+    use crate::test_utils::{
+        insert_test_character, insert_test_gear, insert_test_gear_category, setup_test_db,
+    };
+
+    fn insert_character_gear(conn: &Connection, id: i64, gear_id: i64, quantity: i64, equipped: bool) {
+        conn.execute(
+            "INSERT INTO character_gear (id, character_id, gear_id, quantity, is_equipped)
+             VALUES (?, 1, ?, ?, ?)",
+            rusqlite::params![id, gear_id, quantity, equipped],
+        )
+        .unwrap();
+    }
+
+    fn wealth(conn: &Connection) -> i64 {
+        conn.query_row("SELECT wealth FROM characters WHERE id = 1", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn check_affordable_reports_shortfall() {
+        assert!(GearService::check_affordable(100, 100).is_ok());
+        assert!(matches!(
+            GearService::check_affordable(100, 150),
+            Err(SwadeError::InsufficientFunds {
+                needed: 150,
+                available: 100
+            })
+        ));
+    }
+
+    #[test]
+    fn filter_affordable_keeps_items_within_wealth() {
+        let conn = setup_test_db();
+        insert_test_gear_category(&conn, 1, "Adventuring Gear");
+        insert_test_gear(&conn, 1, "Rope", 1, 10);
+        insert_test_gear(&conn, 2, "Lantern", 1, 100);
+        insert_test_gear(&conn, 3, "Horse", 1, 300);
+
+        let gear = GearService::get_all(&conn).unwrap();
+        let affordable = GearService::filter_affordable(gear, 100);
+
+        let names: Vec<&str> = affordable.iter().map(|g| g.name.as_str()).collect();
+        assert_eq!(names, vec!["Lantern", "Rope"]);
+    }
+
+    #[test]
+    fn filter_affordable_with_no_wealth_keeps_only_free_items() {
+        let conn = setup_test_db();
+        insert_test_gear_category(&conn, 1, "Adventuring Gear");
+        insert_test_gear(&conn, 1, "Rope", 1, 10);
+        insert_test_gear(&conn, 2, "Stick", 1, 0);
+
+        let gear = GearService::get_by_category_id(&conn, 1).unwrap();
+        let affordable = GearService::filter_affordable(gear, 0);
+
+        assert_eq!(affordable.len(), 1);
+        assert_eq!(affordable[0].name, "Stick");
+    }
+
+    #[test]
+    fn sell_all_unequipped_keeps_equipped_gear() {
+        let conn = setup_test_db();
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_gear_category(&conn, 1, "Adventuring Gear");
+        insert_test_gear(&conn, 1, "Rope", 1, 10);
+        insert_test_gear(&conn, 2, "Lantern", 1, 25);
+        insert_test_gear(&conn, 3, "Sword", 1, 300);
+        insert_character_gear(&conn, 1, 1, 3, false);
+        insert_character_gear(&conn, 2, 2, 1, false);
+        insert_character_gear(&conn, 3, 3, 1, true);
+        let before = wealth(&conn);
+
+        let credited = GearService::sell_all_unequipped(&conn, 1, DEFAULT_SELL_BACK_PERCENT).unwrap();
+
+        // 3 x 10 at half is 15, 25 at half rounds down to 12
+        assert_eq!(credited, 27);
+        assert_eq!(wealth(&conn), before + 27);
+        let remaining = CharacterGearRepository::get_by_character_id(&conn, 1).unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].gear_id, 3);
+    }
+
+    #[test]
+    fn sell_refunds_at_the_given_rate() {
+        let conn = setup_test_db();
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_gear_category(&conn, 1, "Adventuring Gear");
+        insert_test_gear(&conn, 1, "Lantern", 1, 100);
+        insert_character_gear(&conn, 1, 1, 2, false);
+        let before = wealth(&conn);
+
+        assert_eq!(GearService::sell(&conn, 1, 1, 50).unwrap(), 50);
+        assert_eq!(wealth(&conn), before + 50);
+        assert_eq!(GearService::sell(&conn, 1, 1, 25).unwrap(), 25);
+        assert_eq!(wealth(&conn), before + 75);
+        assert!(CharacterGearRepository::get_by_id(&conn, 1).unwrap().is_none());
+    }
+
+    #[test]
+    fn sale_value_rounds_down() {
+        assert_eq!(GearService::sale_value(25, 1, 50), 12);
+        assert_eq!(GearService::sale_value(10, 3, 25), 7);
+        assert_eq!(GearService::sale_value(100, 1, 100), 100);
+    }
+
+    #[test]
+    fn sell_rejects_more_than_owned() {
+        let conn = setup_test_db();
+        insert_test_character(&conn, 1, "Hero");
+        insert_test_gear_category(&conn, 1, "Adventuring Gear");
+        insert_test_gear(&conn, 1, "Lantern", 1, 100);
+        insert_character_gear(&conn, 1, 1, 1, false);
+
+        assert!(matches!(
+            GearService::sell(&conn, 1, 2, 50),
+            Err(SwadeError::Validation(_))
+        ));
+    }
+}
@@ -1,27 +1,37 @@
 mod advancement_service;
 mod ancestry_service;
 mod arcane_background_service;
+mod archetype_service;
 mod attribute_service;
 mod character_load;
 mod character_service;
 mod character_sync;
 mod edge_service;
+mod foundry_import_service;
 mod gear_service;
 mod hindrance_service;
 mod modifier_service;
 mod power_service;
+mod quick_build_service;
 mod requirement_service;
 mod skill_service;
+mod snapshot_service;
+mod static_data;
 
 pub use advancement_service::*;
 pub use ancestry_service::*;
 pub use arcane_background_service::*;
+pub use archetype_service::*;
 pub use attribute_service::*;
 pub use character_service::*;
 pub use edge_service::*;
+pub use foundry_import_service::*;
 pub use gear_service::*;
 pub use hindrance_service::*;
 pub use modifier_service::*;
 pub use power_service::*;
+pub use quick_build_service::*;
 pub use requirement_service::*;
 pub use skill_service::*;
+pub use snapshot_service::*;
+pub use static_data::*;
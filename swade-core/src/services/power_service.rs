@@ -20,6 +20,7 @@ impl PowerService {
                 id: power.id,
                 name: power.name,
                 power_points: power.power_points,
+            maintenance_power_points: power.maintenance_power_points,
                 range: power.range,
                 duration: power.duration,
                 source: power.source,
@@ -45,6 +46,7 @@ impl PowerService {
             id: power.id,
             name: power.name,
             power_points: power.power_points,
+            maintenance_power_points: power.maintenance_power_points,
             range: power.range,
             duration: power.duration,
             source: power.source,
@@ -114,6 +116,28 @@ mod tests {
         assert_eq!(powers[0].modifiers[0].description, "+2 to cast");
     }
 
+    #[test]
+    fn get_all_includes_casting_and_maintenance_costs() {
+        let conn = setup_test_db();
+        insert_test_power(&conn, 1, "Bolt");
+        conn.execute(
+            "INSERT INTO powers (id, name, power_points, maintenance_power_points, range, duration,
+                                source, description, created_at, updated_at)
+             VALUES (2, 'Armor', 2, 1, 'Touch', '3', 'core', 'Test power', '2024-01-01', '2024-01-01')",
+            [],
+        )
+        .unwrap();
+
+        let powers = PowerService::get_all(&conn).unwrap();
+
+        let armor = powers.iter().find(|p| p.name == "Armor").unwrap();
+        assert_eq!(armor.power_points, 2);
+        assert_eq!(armor.maintenance_power_points, Some(1));
+        let bolt = powers.iter().find(|p| p.name == "Bolt").unwrap();
+        assert_eq!(bolt.power_points, 2);
+        assert_eq!(bolt.maintenance_power_points, None);
+    }
+
     #[test]
     fn get_all_returns_powers_sorted_by_name() {
         let conn = setup_test_db();
@@ -0,0 +1,304 @@
+//! Quick build: a legal starting character from an archetype template.
+
+use rusqlite::Connection;
+
+use crate::constants::{
+    ATTRIBUTE_HINDRANCE_POINT_COST, EDGE_HINDRANCE_POINT_COST, SOURCE_CHOSEN,
+    SOURCE_HINDRANCE_POINTS,
+};
+use crate::error::{Result, SwadeError};
+use crate::services::{ArchetypeService, CharacterService};
+use crate::views::{
+    ArchetypeView, CharacterEdgeValue, CharacterHindranceValue, CharacterView, Die,
+};
+
+pub struct QuickBuildService;
+
+impl QuickBuildService {
+    /// Build a new, unsaved starting character from the archetype with the
+    /// given key, or the first archetype when no key is given.
+    pub fn build(conn: &Connection, name: String, archetype_key: Option<&str>) -> Result<CharacterView> {
+        let archetype = match archetype_key {
+            Some(key) => ArchetypeService::get_by_key(conn, key)?
+                .ok_or_else(|| SwadeError::NotFound(format!("Archetype '{}'", key)))?,
+            None => ArchetypeService::get_all(conn)?
+                .into_iter()
+                .next()
+                .ok_or_else(|| SwadeError::NotFound("No archetypes".to_string()))?,
+        };
+
+        let draft = CharacterService::build_new(conn, name)?;
+        Self::apply(draft, &archetype)
+    }
+
+    /// Apply an archetype to a fresh draft.
+    ///
+    /// The archetype's hindrances are taken first. Their points buy the
+    /// suggested edges, then one attribute point per
+    /// `ATTRIBUTE_HINDRANCE_POINT_COST`, and the rest go to skills. Attributes
+    /// and skills are raised to their target dice, and anything left over is
+    /// spent raising the same traits one step at a time. Suggested edges
+    /// whose requirements still aren't met are skipped and their points go to
+    /// skills instead.
+    pub fn apply(mut draft: CharacterView, archetype: &ArchetypeView) -> Result<CharacterView> {
+        Self::take_hindrances(&mut draft, archetype);
+
+        let earned = draft.hindrance_points_earned;
+        let edge_points = (archetype.edges.len() as i64 * EDGE_HINDRANCE_POINT_COST)
+            .min(earned - earned % EDGE_HINDRANCE_POINT_COST);
+        let remaining = earned - edge_points;
+        let attribute_points = remaining / ATTRIBUTE_HINDRANCE_POINT_COST;
+        CharacterService::allocate_hindrance_points_to_edges(&mut draft, edge_points)?;
+        CharacterService::allocate_hindrance_points_to_attributes(&mut draft, attribute_points)?;
+        CharacterService::allocate_hindrance_points_to_skills(
+            &mut draft,
+            remaining - attribute_points * ATTRIBUTE_HINDRANCE_POINT_COST,
+        )?;
+
+        // Attributes first, since skill costs depend on them
+        for target in &archetype.attributes {
+            while Self::attribute_die(&draft, target.id).is_some_and(|d| d < target.die)
+                && Self::raise_attribute(&mut draft, target.id)
+            {}
+        }
+        while archetype.attributes.iter().any(|t| Self::raise_attribute(&mut draft, t.id)) {}
+
+        for target in &archetype.skills {
+            while Self::skill_die(&draft, target.id).is_none_or(|d| d < target.die)
+                && Self::raise_skill(&mut draft, target.id)
+            {}
+        }
+        Self::fill_skills(&mut draft, archetype);
+
+        let unused_edge_points = Self::take_edges(&mut draft, archetype);
+        if unused_edge_points > 0 {
+            CharacterService::allocate_hindrance_points_to_edges(&mut draft, -unused_edge_points)?;
+            CharacterService::allocate_hindrance_points_to_skills(&mut draft, unused_edge_points)?;
+            Self::fill_skills(&mut draft, archetype);
+        }
+
+        draft.compute_effective_values();
+        Ok(draft)
+    }
+
+    fn take_hindrances(draft: &mut CharacterView, archetype: &ArchetypeView) {
+        for hindrance in &archetype.hindrances {
+            if draft.hindrance_conflict(hindrance).is_some()
+                || draft.hindrance_point_cap_exceeded(hindrance).is_some()
+            {
+                continue;
+            }
+            draft
+                .hindrances
+                .push(CharacterHindranceValue::new(hindrance.clone(), SOURCE_CHOSEN.to_string()));
+            draft.hindrance_points_earned = draft.earned_hindrance_points();
+        }
+        draft.compute_effective_values();
+    }
+
+    /// Take the suggested edges the draft qualifies for and has points for.
+    /// Returns the hindrance points allocated to edges that went unused.
+    fn take_edges(draft: &mut CharacterView, archetype: &ArchetypeView) -> i64 {
+        for edge in &archetype.edges {
+            let bought = draft
+                .edges
+                .iter()
+                .filter(|e| e.source == SOURCE_HINDRANCE_POINTS)
+                .count() as i64;
+            if (bought + 1) * EDGE_HINDRANCE_POINT_COST > draft.hindrance_points_to_edges {
+                break;
+            }
+            if draft.edges.iter().any(|e| e.edge.id == edge.id)
+                || !edge.requirements.evaluate(&draft.to_requirement_context())
+            {
+                continue;
+            }
+
+            // Wealth modifiers from the edge (e.g. Rich)
+            draft.wealth += edge
+                .modifiers
+                .iter()
+                .filter(|m| m.target_type.as_deref() == Some("wealth"))
+                .filter_map(|m| m.value)
+                .sum::<i64>();
+            draft.edges.push(CharacterEdgeValue::new(
+                edge.clone(),
+                0,
+                None,
+                SOURCE_HINDRANCE_POINTS.to_string(),
+            ));
+            draft.compute_effective_values();
+        }
+
+        let bought = draft
+            .edges
+            .iter()
+            .filter(|e| e.source == SOURCE_HINDRANCE_POINTS)
+            .count() as i64;
+        draft.hindrance_points_to_edges - bought * EDGE_HINDRANCE_POINT_COST
+    }
+
+    fn fill_skills(draft: &mut CharacterView, archetype: &ArchetypeView) {
+        while archetype.skills.iter().any(|t| Self::raise_skill(draft, t.id)) {}
+    }
+
+    fn attribute_die(draft: &CharacterView, attribute_id: i64) -> Option<Die> {
+        draft.attributes.iter().find(|a| a.attribute.id == attribute_id).map(|a| a.die)
+    }
+
+    fn skill_die(draft: &CharacterView, skill_id: i64) -> Option<Die> {
+        draft.skills.iter().find(|s| s.skill.id == skill_id)?.die
+    }
+
+    /// Raise an attribute one step if points remain and it's below its cap.
+    fn raise_attribute(draft: &mut CharacterView, attribute_id: i64) -> bool {
+        let available = draft.attribute_points_earned + draft.hindrance_points_to_attributes
+            - draft.attribute_points_spent;
+        let Some(cap) = draft.attribute_die_cap(attribute_id) else {
+            return false;
+        };
+        let Some(attr) = draft.attributes.iter_mut().find(|a| a.attribute.id == attribute_id) else {
+            return false;
+        };
+        if available < 1 || attr.die >= cap {
+            return false;
+        }
+
+        attr.die = attr.die.increment();
+        draft.attribute_points_spent += 1;
+        draft.compute_effective_values();
+        true
+    }
+
+    /// Raise a skill one step (training it at d4 if untrained) if it can be.
+    fn raise_skill(draft: &mut CharacterView, skill_id: i64) -> bool {
+        let Some(skill) = draft.skills.iter_mut().find(|s| s.skill.id == skill_id) else {
+            return false;
+        };
+        if !skill.can_increment {
+            return false;
+        }
+
+        skill.die = Some(skill.die.map_or(Die::d4(), |d| d.increment()));
+        draft.skill_points_spent += skill.increment_cost;
+        draft.compute_effective_values();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        insert_test_archetype, insert_test_archetype_suggestion, insert_test_attribute,
+        insert_test_core_skill, insert_test_edge, insert_test_edge_requirement,
+        insert_test_hindrance, insert_test_rank, insert_test_requirement_expression,
+        insert_test_requirement_with_target, insert_test_skill, setup_test_db,
+    };
+
+    /// A database with the Brawler archetype as the migration seeds it.
+    fn setup_quick_build_db() -> Connection {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        for (id, name) in ["Agility", "Smarts", "Spirit", "Strength", "Vigor"].iter().enumerate() {
+            insert_test_attribute(&conn, id as i64 + 1, name);
+        }
+        insert_test_core_skill(&conn, 1, "Athletics", 1);
+        insert_test_core_skill(&conn, 2, "Notice", 2);
+        insert_test_core_skill(&conn, 3, "Stealth", 1);
+        insert_test_skill(&conn, 4, "Fighting", 1);
+        insert_test_skill(&conn, 5, "Intimidation", 3);
+        insert_test_skill(&conn, 6, "Survival", 2);
+        insert_test_edge(&conn, 1, "Brawny", "Background");
+        insert_test_hindrance(&conn, 1, "Overconfident", "major", 2);
+        insert_test_hindrance(&conn, 2, "Big Mouth", "minor", 1);
+
+        insert_test_archetype(&conn, 1, "brawler", "Brawler");
+        let suggestions = [
+            ("attribute", 1, Some(6)),
+            ("attribute", 4, Some(8)),
+            ("attribute", 5, Some(8)),
+            ("skill", 4, Some(8)),
+            ("skill", 1, Some(6)),
+            ("skill", 5, Some(6)),
+            ("skill", 2, Some(6)),
+            ("skill", 6, Some(4)),
+            ("skill", 3, Some(6)),
+            ("edge", 1, None),
+            ("hindrance", 1, None),
+            ("hindrance", 2, None),
+        ];
+        for (i, (suggestion_type, target_id, die_size)) in suggestions.into_iter().enumerate() {
+            let id = i as i64 + 1;
+            insert_test_archetype_suggestion(&conn, id, 1, suggestion_type, target_id, die_size, id);
+        }
+        conn
+    }
+
+    fn die(draft: &CharacterView, name: &str) -> Option<u8> {
+        let attribute = draft.attributes.iter().find(|a| a.attribute.name == name);
+        let skill = draft.skills.iter().find(|s| s.skill.name == name);
+        attribute
+            .map(|a| a.die.size())
+            .or_else(|| skill.and_then(|s| s.die).map(|d| d.size()))
+    }
+
+    #[test]
+    fn brawler_gets_expected_trait_spread() {
+        let conn = setup_quick_build_db();
+
+        let draft = QuickBuildService::build(&conn, "Brute".to_string(), Some("brawler")).unwrap();
+
+        let validation = CharacterService::validate_draft(&draft);
+        assert!(validation.is_valid(), "{:?}", validation.errors);
+        assert_eq!(die(&draft, "Agility"), Some(6));
+        assert_eq!(die(&draft, "Strength"), Some(8));
+        assert_eq!(die(&draft, "Vigor"), Some(8));
+        assert_eq!(die(&draft, "Fighting"), Some(8));
+        assert_eq!(die(&draft, "Intimidation"), Some(6));
+        assert_eq!(die(&draft, "Survival"), Some(4));
+        assert_eq!(draft.attribute_points_spent, 5);
+        // 3 hindrance points: 2 bought Brawny, 1 went to skills
+        assert_eq!(draft.hindrances.len(), 2);
+        assert_eq!(draft.edges.len(), 1);
+        assert_eq!(draft.edges[0].edge.name, "Brawny");
+        assert_eq!(draft.hindrance_points_to_edges, 2);
+        assert_eq!(draft.hindrance_points_to_skills, 1);
+        assert_eq!(draft.skill_points_spent, 12);
+    }
+
+    #[test]
+    fn build_without_key_uses_first_archetype() {
+        let conn = setup_quick_build_db();
+
+        let draft = QuickBuildService::build(&conn, "Brute".to_string(), None).unwrap();
+
+        assert_eq!(die(&draft, "Fighting"), Some(8));
+    }
+
+    #[test]
+    fn unmet_edge_points_go_to_skills() {
+        let conn = setup_quick_build_db();
+        // Brawny now needs Strength d12, which the template never reaches
+        insert_test_requirement_with_target(&conn, 1, "attribute", 4, 12, "Strength d12+");
+        insert_test_requirement_expression(&conn, 1, "requirement", Some(1));
+        insert_test_edge_requirement(&conn, 1, 1, 1);
+
+        let draft = QuickBuildService::build(&conn, "Brute".to_string(), Some("brawler")).unwrap();
+
+        assert!(CharacterService::validate_draft(&draft).is_valid());
+        assert!(draft.edges.is_empty());
+        assert_eq!(draft.hindrance_points_to_edges, 0);
+        assert_eq!(draft.hindrance_points_to_skills, 3);
+        assert_eq!(draft.skill_points_spent, 14);
+    }
+
+    #[test]
+    fn unknown_archetype_is_not_found() {
+        let conn = setup_quick_build_db();
+
+        let result = QuickBuildService::build(&conn, "Quick".to_string(), Some("wizard"));
+
+        assert!(matches!(result, Err(SwadeError::NotFound(_))));
+    }
+}
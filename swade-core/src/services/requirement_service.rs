@@ -3,12 +3,13 @@ use rusqlite::Connection;
 use crate::error::Result;
 use crate::models::{
     AncestryRequirement, ArcaneBackgroundRequirement, EdgeRequirement, GearRequirement,
-    HindranceRequirement, PowerRequirement, RequirementExpression,
+    HindranceRequirement, PowerRequirement, Requirement, RequirementExpression,
 };
 use crate::repositories::{
     AncestryRequirementRepository, ArcaneBackgroundRequirementRepository,
     EdgeRequirementRepository, GearRequirementRepository, HindranceRequirementRepository,
-    PowerRequirementRepository, RequirementExpressionRepository, RequirementRepository,
+    PowerRequirementRepository, RankRepository, RequirementExpressionRepository,
+    RequirementRepository,
 };
 use crate::views::RequirementTree;
 
@@ -153,7 +154,7 @@ impl RequirementService {
                 // Leaf node - load the actual requirement
                 if let Some(req_id) = expr.requirement_id
                     && let Some(requirement) = RequirementRepository::get_by_id(conn, req_id)? {
-                        return Ok(Some(RequirementTree::leaf(requirement)));
+                        return Ok(Some(RequirementTree::leaf(Self::resolve_target_name(conn, requirement)?)));
                     }
                 Ok(None)
             }
@@ -207,14 +208,27 @@ impl RequirementService {
 
         Ok(trees)
     }
+
+    /// Fill in a rank requirement's `target_id` from its `target_name`, for
+    /// content that references ranks by name. Unknown names are left unresolved.
+    fn resolve_target_name(conn: &Connection, mut requirement: Requirement) -> Result<Requirement> {
+        if requirement.requirement_type == "rank"
+            && requirement.target_id.is_none()
+            && let Some(name) = &requirement.target_name
+        {
+            requirement.target_id = RankRepository::get_by_name(conn, name)?.map(|rank| rank.id);
+        }
+        Ok(requirement)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::setup_test_db;
-    use crate::views::RequirementNode;
+    use crate::test_utils::{insert_test_rank, setup_test_db};
+    use crate::views::{RequirementContext, RequirementNode};
     use rusqlite::params;
+    use std::collections::{HashMap, HashSet};
 
     fn insert_test_edge(conn: &Connection, id: i64) {
         conn.execute(
@@ -291,6 +305,41 @@ mod tests {
         }
     }
 
+    fn rank_context(rank_id: i64) -> RequirementContext {
+        RequirementContext {
+            rank_id,
+            is_wild_card: true,
+            attribute_dies: HashMap::new(),
+            skill_dies: HashMap::new(),
+            edge_ids: HashSet::new(),
+            arcane_background_ids: HashSet::new(),
+            arcane_skill_dies: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn get_for_edge_resolves_rank_by_name() {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_rank(&conn, 2, "Seasoned");
+        insert_test_edge(&conn, 1);
+        conn.execute(
+            "INSERT INTO requirements (id, requirement_type, target_id, target_name, value, description,
+                                       created_at, updated_at)
+             VALUES (1, 'rank', NULL, 'seasoned', NULL, 'Seasoned', '2024-01-01', '2024-01-01')",
+            [],
+        )
+        .unwrap();
+        insert_requirement_expression(&conn, 1, None, "requirement", Some(1), 0);
+        insert_edge_requirement(&conn, 1, 1);
+
+        let tree = RequirementService::get_for_edge(&conn, 1).unwrap();
+
+        assert_eq!(tree.min_rank(), Some(2));
+        assert!(tree.evaluate(&rank_context(2)));
+        assert!(!tree.evaluate(&rank_context(1)));
+    }
+
     #[test]
     fn get_for_edge_returns_and_tree() {
         let conn = setup_test_db();
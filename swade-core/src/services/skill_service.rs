@@ -1,26 +1,51 @@
 use rusqlite::Connection;
+use std::collections::HashMap;
 
 use crate::error::Result;
-use crate::repositories::SkillRepository;
+use crate::models::Skill;
+use crate::repositories::{AttributeRepository, SkillRepository};
 use crate::views::SkillView;
 
 pub struct SkillService;
 
 impl SkillService {
+    /// Get all skills, with linked attribute names from a single attribute
+    /// lookup.
     pub fn get_all(conn: &Connection) -> Result<Vec<SkillView>> {
         let skills = SkillRepository::get_all(conn)?;
-        Ok(skills.into_iter().map(SkillView::new).collect())
+        let attribute_names: HashMap<i64, String> = AttributeRepository::get_all(conn)?
+            .into_iter()
+            .map(|a| (a.id, a.name))
+            .collect();
+
+        Ok(skills
+            .into_iter()
+            .map(|skill| {
+                let name = attribute_names
+                    .get(&skill.linked_attribute_id)
+                    .cloned()
+                    .unwrap_or_default();
+                SkillView::new(skill, name)
+            })
+            .collect())
     }
 
     pub fn get_by_id(conn: &Connection, id: i64) -> Result<Option<SkillView>> {
         let skill = SkillRepository::get_by_id(conn, id)?;
-        Ok(skill.map(SkillView::new))
+        skill.map(|s| Self::to_view(conn, s)).transpose()
     }
 
     /// Get a skill by name (case-insensitive)
     pub fn get_by_name(conn: &Connection, name: &str) -> Result<Option<SkillView>> {
         let skill = SkillRepository::get_by_name(conn, name)?;
-        Ok(skill.map(SkillView::new))
+        skill.map(|s| Self::to_view(conn, s)).transpose()
+    }
+
+    fn to_view(conn: &Connection, skill: Skill) -> Result<SkillView> {
+        let name = AttributeRepository::get_by_id(conn, skill.linked_attribute_id)?
+            .map(|a| a.name)
+            .unwrap_or_default();
+        Ok(SkillView::new(skill, name))
     }
 }
 
@@ -74,6 +99,22 @@ mod tests {
         assert_eq!(views[1].name, "Stealth");
     }
 
+    #[test]
+    fn get_all_populates_linked_attribute_name() {
+        let conn = setup_test_db();
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_attribute(&conn, 2, "Smarts");
+        insert_test_skill(&conn, 1, "Fighting", 1, false);
+        insert_test_skill(&conn, 2, "Notice", 2, true);
+
+        let views = SkillService::get_all(&conn).unwrap();
+
+        assert_eq!(views[0].linked_attribute_name, "Agility");
+        assert_eq!(views[1].linked_attribute_name, "Smarts");
+        let notice = SkillService::get_by_name(&conn, "Notice").unwrap().unwrap();
+        assert_eq!(notice.linked_attribute_name, "Smarts");
+    }
+
     #[test]
     fn get_all_returns_empty_when_no_skills() {
         let conn = setup_test_db();
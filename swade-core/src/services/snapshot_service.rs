@@ -0,0 +1,378 @@
+//! Snapshot service for checkpointing characters and rolling back to them.
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Result, SwadeError};
+use crate::models::{
+    CharacterAdvance, CharacterAncestryChoice, CharacterArcaneBackground, CharacterAttribute,
+    CharacterEdge, CharacterGear, CharacterHindrance, CharacterPower, CharacterSkill,
+    CharacterSnapshot,
+};
+use crate::repositories::{
+    CharacterAdvanceRepository, CharacterAncestryChoiceRepository,
+    CharacterArcaneBackgroundRepository, CharacterAttributeRepository, CharacterEdgeRepository,
+    CharacterGearRepository, CharacterHindranceRepository, CharacterPowerRepository,
+    CharacterRepository, CharacterSkillRepository, CharacterSnapshotRepository,
+};
+use crate::services::CharacterService;
+use crate::views::{CharacterSnapshotValue, CharacterView};
+
+/// What a snapshot stores: the character as loaded plus its advance history,
+/// which the view alone doesn't carry.
+#[derive(Serialize, Deserialize)]
+struct SnapshotData {
+    character: CharacterView,
+    advances: Vec<CharacterAdvance>,
+}
+
+pub struct SnapshotService;
+
+impl SnapshotService {
+    /// Save the character's current state under a label.
+    pub fn snapshot(conn: &Connection, character_id: i64, label: &str) -> Result<CharacterSnapshotValue> {
+        let label = label.trim();
+        if label.is_empty() {
+            return Err(SwadeError::Validation(
+                "Snapshot label cannot be empty".to_string(),
+            ));
+        }
+
+        let character = CharacterService::get_by_id(conn, character_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Character {}", character_id)))?;
+        let data = SnapshotData {
+            character,
+            advances: CharacterAdvanceRepository::get_by_character_id(conn, character_id)?,
+        };
+        let data = serde_json::to_string(&data)
+            .map_err(|e| SwadeError::Validation(format!("Could not serialize snapshot: {}", e)))?;
+
+        let id = CharacterSnapshotRepository::insert(
+            conn,
+            &CharacterSnapshot {
+                id: 0,
+                character_id,
+                label: label.to_string(),
+                data,
+                created_at: String::new(),
+                updated_at: String::new(),
+            },
+        )?;
+        let snapshot = CharacterSnapshotRepository::get_by_id(conn, id)?
+            .ok_or_else(|| SwadeError::NotFound("Snapshot".to_string()))?;
+        Ok(Self::to_value(snapshot))
+    }
+
+    /// List a character's snapshots, newest first.
+    pub fn list(conn: &Connection, character_id: i64) -> Result<Vec<CharacterSnapshotValue>> {
+        Ok(CharacterSnapshotRepository::get_by_character_id(conn, character_id)?
+            .into_iter()
+            .map(Self::to_value)
+            .collect())
+    }
+
+    /// Put a character back the way it was when the snapshot was taken.
+    ///
+    /// Overwrites the character under the same id, or recreates it if it has
+    /// been deleted since. Traits, edges, hindrances, ancestry choices, arcane
+    /// backgrounds, powers, gear, and advance history are all replaced; notes,
+    /// companions, and the portrait are left alone. Runs in one transaction.
+    pub fn restore(conn: &Connection, snapshot_id: i64) -> Result<CharacterView> {
+        let snapshot = CharacterSnapshotRepository::get_by_id(conn, snapshot_id)?
+            .ok_or_else(|| SwadeError::NotFound(format!("Snapshot {}", snapshot_id)))?;
+        let data: SnapshotData = serde_json::from_str(&snapshot.data)
+            .map_err(|e| SwadeError::Validation(format!("Snapshot data is corrupt: {}", e)))?;
+
+        let tx = conn.unchecked_transaction()?;
+        Self::restore_inner(&tx, snapshot.character_id, &data)?;
+        tx.commit()?;
+
+        CharacterService::get_by_id(conn, snapshot.character_id)?
+            .ok_or_else(|| SwadeError::NotFound("Character".to_string()))
+    }
+
+    fn restore_inner(conn: &Connection, character_id: i64, data: &SnapshotData) -> Result<()> {
+        let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let character = &data.character;
+
+        let mut char_model = CharacterService::to_model(character, &now);
+        char_model.id = character_id;
+        if CharacterRepository::get_by_id(conn, character_id)?.is_some() {
+            CharacterRepository::update(conn, &char_model)?;
+        } else {
+            CharacterRepository::insert(conn, &char_model)?;
+        }
+
+        CharacterAttributeRepository::delete_by_character_id(conn, character_id)?;
+        CharacterSkillRepository::delete_by_character_id(conn, character_id)?;
+        CharacterEdgeRepository::delete_by_character_id(conn, character_id)?;
+        CharacterHindranceRepository::delete_by_character_id(conn, character_id)?;
+        CharacterAncestryChoiceRepository::delete_by_character_id(conn, character_id)?;
+        CharacterArcaneBackgroundRepository::delete_by_character_id(conn, character_id)?;
+        CharacterPowerRepository::delete_by_character_id(conn, character_id)?;
+        CharacterGearRepository::delete_by_character_id(conn, character_id)?;
+        CharacterAdvanceRepository::delete_by_character_id(conn, character_id)?;
+
+        for attr_value in &character.attributes {
+            CharacterAttributeRepository::insert(
+                conn,
+                &CharacterAttribute {
+                    id: 0,
+                    character_id,
+                    attribute_id: attr_value.attribute.id,
+                    steps_incremented: attr_value.die.steps_from(attr_value.attribute.base_die) as i64,
+                    created_at: now.clone(),
+                    updated_at: now.clone(),
+                },
+            )?;
+        }
+
+        for skill_value in &character.skills {
+            CharacterSkillRepository::insert(
+                conn,
+                &CharacterSkill {
+                    id: 0,
+                    character_id,
+                    skill_id: skill_value.skill.id,
+                    current_die_size: skill_value.die.as_ref().map(|d| d.size() as i64),
+                    current_die_modifier: skill_value
+                        .die
+                        .as_ref()
+                        .map(|d| d.modifier() as i64)
+                        .unwrap_or(0),
+                    created_at: now.clone(),
+                    updated_at: now.clone(),
+                },
+            )?;
+        }
+
+        // Edges and hindrances are restored from every source, so ancestry
+        // choices only need their choice records back
+        for edge_value in &character.edges {
+            CharacterEdgeRepository::insert(
+                conn,
+                &CharacterEdge {
+                    id: 0,
+                    character_id,
+                    edge_id: edge_value.edge.id,
+                    advance_taken: edge_value.advance_taken,
+                    notes: edge_value.notes.clone(),
+                    source: edge_value.source.clone(),
+                    created_at: now.clone(),
+                    updated_at: now.clone(),
+                },
+            )?;
+        }
+
+        for hindrance_value in &character.hindrances {
+            CharacterHindranceRepository::insert(
+                conn,
+                &CharacterHindrance {
+                    id: 0,
+                    character_id,
+                    hindrance_id: hindrance_value.hindrance.id,
+                    source: hindrance_value.source.clone(),
+                    created_at: now.clone(),
+                    updated_at: now.clone(),
+                },
+            )?;
+        }
+
+        for choice_value in &character.ancestry_choices {
+            if let Some(selected_option) = &choice_value.selected_option {
+                CharacterAncestryChoiceRepository::insert(
+                    conn,
+                    &CharacterAncestryChoice {
+                        id: 0,
+                        character_id,
+                        choice_id: choice_value.choice.id,
+                        selected_option_id: selected_option.id,
+                        created_at: now.clone(),
+                        updated_at: now.clone(),
+                    },
+                )?;
+            }
+        }
+
+        for ab_value in &character.arcane_backgrounds {
+            CharacterArcaneBackgroundRepository::insert(
+                conn,
+                &CharacterArcaneBackground {
+                    id: 0,
+                    character_id,
+                    arcane_background_id: ab_value.arcane_background.id,
+                    advance_taken: ab_value.advance_taken,
+                    power_points_used: ab_value.power_points_used,
+                    created_at: now.clone(),
+                    updated_at: now.clone(),
+                },
+            )?;
+        }
+
+        for power_value in &character.powers {
+            CharacterPowerRepository::insert(
+                conn,
+                &CharacterPower {
+                    id: 0,
+                    character_id,
+                    power_id: power_value.power.id,
+                    advance_taken: power_value.advance_taken,
+                    created_at: now.clone(),
+                    updated_at: now.clone(),
+                },
+            )?;
+        }
+
+        for gear_value in &character.gear {
+            CharacterGearRepository::insert(
+                conn,
+                &CharacterGear {
+                    id: 0,
+                    character_id,
+                    gear_id: gear_value.gear.id,
+                    quantity: gear_value.quantity,
+                    is_equipped: gear_value.is_equipped,
+                    custom_notes: gear_value.custom_notes.clone(),
+                    created_at: now.clone(),
+                    updated_at: now.clone(),
+                },
+            )?;
+        }
+
+        for advance in &data.advances {
+            CharacterAdvanceRepository::insert(
+                conn,
+                &CharacterAdvance {
+                    character_id,
+                    ..advance.clone()
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn to_value(snapshot: CharacterSnapshot) -> CharacterSnapshotValue {
+        CharacterSnapshotValue {
+            id: snapshot.id,
+            character_id: snapshot.character_id,
+            label: snapshot.label,
+            created_at: snapshot.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::AdvancementService;
+    use crate::test_utils::{
+        insert_test_attribute, insert_test_character, insert_test_edge, insert_test_rank,
+        setup_test_db,
+    };
+    use crate::views::Die;
+
+    fn setup() -> Connection {
+        let conn = setup_test_db();
+        insert_test_rank(&conn, 1, "Novice");
+        insert_test_character(&conn, 1, "Test Hero");
+        insert_test_attribute(&conn, 1, "Vigor");
+        conn.execute(
+            "INSERT INTO character_attributes (character_id, attribute_id, steps_incremented)
+             VALUES (1, 1, 1)",
+            [],
+        )
+        .unwrap();
+        insert_test_edge(&conn, 1, "Alertness", "Background");
+        conn
+    }
+
+    #[test]
+    fn restore_undoes_changes_made_after_the_snapshot() {
+        let conn = setup();
+        let before = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        let snapshot = SnapshotService::snapshot(&conn, 1, "  Before spree ").unwrap();
+        assert_eq!(snapshot.label, "Before spree");
+
+        AdvancementService::apply_attribute_advance(&conn, 1, 1, None).unwrap();
+        AdvancementService::apply_edge_advance(&conn, 1, 1, None, None).unwrap();
+        CharacterRepository::adjust_wealth(&conn, 1, -100).unwrap();
+        let advanced = CharacterService::get_by_id(&conn, 1).unwrap().unwrap();
+        assert_ne!(advanced.effective_snapshot(), before.effective_snapshot());
+
+        let restored = SnapshotService::restore(&conn, snapshot.id).unwrap();
+
+        assert_eq!(restored.id, 1);
+        assert_eq!(restored.effective_snapshot(), before.effective_snapshot());
+        assert_eq!(restored.attributes[0].die, Die::d6());
+        assert!(restored.edges.is_empty());
+        assert_eq!(restored.current_advances, 0);
+        assert!(CharacterAdvanceRepository::get_by_character_id(&conn, 1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn restore_keeps_advance_history_from_the_snapshot() {
+        let conn = setup();
+        AdvancementService::apply_edge_advance(&conn, 1, 1, None, None).unwrap();
+        let snapshot = SnapshotService::snapshot(&conn, 1, "One advance").unwrap();
+        AdvancementService::apply_attribute_advance(&conn, 1, 1, None).unwrap();
+
+        let restored = SnapshotService::restore(&conn, snapshot.id).unwrap();
+
+        let advances = CharacterAdvanceRepository::get_by_character_id(&conn, 1).unwrap();
+        assert_eq!(advances.len(), 1);
+        assert_eq!(advances[0].edge_id, Some(1));
+        assert_eq!(restored.current_advances, 1);
+        assert_eq!(restored.edges.len(), 1);
+        assert_eq!(restored.attributes[0].die, Die::d6());
+    }
+
+    #[test]
+    fn restore_recreates_a_deleted_character_under_the_same_id() {
+        let conn = setup();
+        let snapshot = SnapshotService::snapshot(&conn, 1, "Safe copy").unwrap();
+        // character_attributes rows don't cascade, so clear them first
+        CharacterAttributeRepository::delete_by_character_id(&conn, 1).unwrap();
+        CharacterService::delete(&conn, 1).unwrap();
+        assert!(CharacterService::get_by_id(&conn, 1).unwrap().is_none());
+
+        let restored = SnapshotService::restore(&conn, snapshot.id).unwrap();
+
+        assert_eq!(restored.id, 1);
+        assert_eq!(restored.name, "Test Hero");
+        assert_eq!(restored.attributes[0].die, Die::d6());
+    }
+
+    #[test]
+    fn list_returns_snapshots_newest_first() {
+        let conn = setup();
+        SnapshotService::snapshot(&conn, 1, "First").unwrap();
+        SnapshotService::snapshot(&conn, 1, "Second").unwrap();
+
+        let labels: Vec<String> = SnapshotService::list(&conn, 1)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.label)
+            .collect();
+
+        assert_eq!(labels, vec!["Second", "First"]);
+    }
+
+    #[test]
+    fn snapshot_rejects_blank_label_and_missing_character() {
+        let conn = setup();
+
+        assert!(matches!(
+            SnapshotService::snapshot(&conn, 1, "   "),
+            Err(SwadeError::Validation(_))
+        ));
+        assert!(matches!(
+            SnapshotService::snapshot(&conn, 999, "Nope"),
+            Err(SwadeError::NotFound(_))
+        ));
+        assert!(matches!(
+            SnapshotService::restore(&conn, 999),
+            Err(SwadeError::NotFound(_))
+        ));
+    }
+}
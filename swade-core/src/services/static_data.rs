@@ -0,0 +1,106 @@
+//! In-memory copy of the reference catalogs that don't change at runtime.
+
+use rusqlite::Connection;
+
+use crate::error::Result;
+use crate::services::{AncestryService, EdgeService, SkillService};
+use crate::views::{AncestryView, EdgeView, SkillView};
+
+/// Skills, edges, and ancestries loaded in one go.
+#[derive(Debug, Clone)]
+pub struct StaticData {
+    pub skills: Vec<SkillView>,
+    pub edges: Vec<EdgeView>,
+    pub ancestries: Vec<AncestryView>,
+}
+
+impl StaticData {
+    pub fn load(conn: &Connection) -> Result<Self> {
+        Ok(Self {
+            skills: SkillService::get_all(conn)?,
+            edges: EdgeService::get_all(conn)?,
+            ancestries: AncestryService::get_all(conn)?,
+        })
+    }
+}
+
+/// Loads `StaticData` on first use and keeps it until invalidated.
+///
+/// Invalidate whenever the database file itself is replaced, e.g. after
+/// restoring a backup.
+#[derive(Debug, Default)]
+pub struct StaticDataCache {
+    data: Option<StaticData>,
+}
+
+impl StaticDataCache {
+    pub fn get(&mut self, conn: &Connection) -> Result<&StaticData> {
+        let data = match self.data.take() {
+            Some(data) => data,
+            None => StaticData::load(conn)?,
+        };
+        Ok(self.data.insert(data))
+    }
+
+    pub fn invalidate(&mut self) {
+        self.data = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        insert_test_attribute, insert_test_edge, insert_test_skill, setup_test_db,
+    };
+
+    fn setup_static_db() -> Connection {
+        let conn = setup_test_db();
+        insert_test_attribute(&conn, 1, "Agility");
+        insert_test_skill(&conn, 1, "Fighting", 1);
+        insert_test_edge(&conn, 1, "Alertness", "Background");
+        conn
+    }
+
+    #[test]
+    fn cache_matches_direct_queries() {
+        let conn = setup_static_db();
+        let mut cache = StaticDataCache::default();
+
+        let data = cache.get(&conn).unwrap();
+
+        let skill_names: Vec<String> = SkillService::get_all(&conn)
+            .unwrap()
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        let edge_names: Vec<String> = EdgeService::get_all(&conn)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.name)
+            .collect();
+        assert_eq!(data.skills.iter().map(|s| s.name.clone()).collect::<Vec<_>>(), skill_names);
+        assert_eq!(data.edges.iter().map(|e| e.name.clone()).collect::<Vec<_>>(), edge_names);
+        assert_eq!(data.ancestries.len(), AncestryService::get_all(&conn).unwrap().len());
+    }
+
+    #[test]
+    fn cache_refreshes_only_after_invalidate() {
+        let conn = setup_static_db();
+        let mut cache = StaticDataCache::default();
+        assert_eq!(cache.get(&conn).unwrap().edges.len(), 1);
+
+        insert_test_edge(&conn, 2, "Brawny", "Background");
+        assert_eq!(cache.get(&conn).unwrap().edges.len(), 1);
+
+        cache.invalidate();
+        let edges: Vec<&str> = cache
+            .get(&conn)
+            .unwrap()
+            .edges
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(edges, vec!["Alertness", "Brawny"]);
+    }
+}
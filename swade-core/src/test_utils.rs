@@ -38,6 +38,38 @@ pub fn setup_test_db_with_fk() -> Connection {
     conn
 }
 
+/// A column as (table, name, type, not null, default).
+pub type ColumnInfo = (String, String, String, bool, Option<String>);
+
+/// Every table's columns, sorted. Declared lengths are dropped from types,
+/// since SQLite ignores them.
+pub fn table_columns(conn: &Connection) -> Vec<ColumnInfo> {
+    let tables: Vec<String> = conn
+        .prepare(
+            "SELECT name FROM sqlite_master
+              WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+        )
+        .unwrap()
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect();
+    let mut columns = Vec::new();
+    for table in tables {
+        let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table)).unwrap();
+        let rows = stmt
+            .query_map([], |row| {
+                let declared: String = row.get(2)?;
+                let column_type = declared.split('(').next().unwrap_or_default().to_string();
+                Ok((table.clone(), row.get(1)?, column_type, row.get(3)?, row.get(4)?))
+            })
+            .unwrap();
+        columns.extend(rows.map(|r| r.unwrap()));
+    }
+    columns.sort();
+    columns
+}
+
 /// Inserts a test rank into the database.
 pub fn insert_test_rank(conn: &Connection, id: i64, name: &str) {
     conn.execute(
@@ -128,6 +160,36 @@ pub fn insert_test_ancestry(conn: &Connection, id: i64, name: &str) {
     .unwrap();
 }
 
+/// Inserts a test archetype into the database.
+pub fn insert_test_archetype(conn: &Connection, id: i64, key: &str, name: &str) {
+    conn.execute(
+        "INSERT INTO archetypes (id, key, name, description, created_at, updated_at)
+         VALUES (?, ?, ?, 'Test archetype', ?, ?)",
+        params![id, key, name, TEST_TIMESTAMP, TEST_TIMESTAMP],
+    )
+    .unwrap();
+}
+
+/// Inserts a test archetype suggestion.
+/// Requires the specified archetype to exist.
+pub fn insert_test_archetype_suggestion(
+    conn: &Connection,
+    id: i64,
+    archetype_id: i64,
+    suggestion_type: &str,
+    target_id: i64,
+    die_size: Option<i64>,
+    sort_order: i64,
+) {
+    conn.execute(
+        "INSERT INTO archetype_suggestions (id, archetype_id, suggestion_type, target_id, die_size,
+                                            sort_order, created_at, updated_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        params![id, archetype_id, suggestion_type, target_id, die_size, sort_order, TEST_TIMESTAMP, TEST_TIMESTAMP],
+    )
+    .unwrap();
+}
+
 /// Inserts a test character into the database.
 pub fn insert_test_character(conn: &Connection, id: i64, name: &str) {
     conn.execute(
@@ -207,6 +269,27 @@ pub fn insert_test_arcane_background(conn: &Connection, id: i64, name: &str, arc
     .unwrap();
 }
 
+/// Inserts a test gear category into the database.
+pub fn insert_test_gear_category(conn: &Connection, id: i64, name: &str) {
+    conn.execute(
+        "INSERT INTO gear_categories (id, name, description, created_at, updated_at)
+         VALUES (?, ?, NULL, ?, ?)",
+        params![id, name, TEST_TIMESTAMP, TEST_TIMESTAMP],
+    )
+    .unwrap();
+}
+
+/// Inserts a test gear item into the database.
+/// Requires a gear category with the specified category_id to exist.
+pub fn insert_test_gear(conn: &Connection, id: i64, name: &str, category_id: i64, cost: i64) {
+    conn.execute(
+        "INSERT INTO gear (id, name, category_id, era, cost, weight, source, created_at, updated_at)
+         VALUES (?, ?, ?, 'any', ?, 1.0, 'core', ?, ?)",
+        params![id, name, category_id, cost, TEST_TIMESTAMP, TEST_TIMESTAMP],
+    )
+    .unwrap();
+}
+
 // Link table helpers
 
 /// Inserts a test edge modifier link.
@@ -291,6 +374,22 @@ pub fn insert_test_character_edge(conn: &Connection, id: i64, character_id: i64,
     .unwrap();
 }
 
+/// Inserts a test character edge link taken with the given advance (0 for creation).
+pub fn insert_test_character_edge_at_advance(
+    conn: &Connection,
+    id: i64,
+    character_id: i64,
+    edge_id: i64,
+    advance_taken: i64,
+) {
+    conn.execute(
+        "INSERT INTO character_edges (id, character_id, edge_id, advance_taken, source, created_at, updated_at)
+         VALUES (?, ?, ?, ?, 'chosen', ?, ?)",
+        params![id, character_id, edge_id, advance_taken, TEST_TIMESTAMP, TEST_TIMESTAMP],
+    )
+    .unwrap();
+}
+
 /// Inserts a test character hindrance link.
 pub fn insert_test_character_hindrance(conn: &Connection, id: i64, character_id: i64, hindrance_id: i64) {
     conn.execute(
@@ -9,6 +9,10 @@ pub enum AncestryChoiceType {
     FreeEdge,
     MandatoryHindrance,
     AncestralEnemy,
+    /// Player picks an attribute to raise one die step.
+    AttributeIncrease,
+    /// Player picks a skill to raise one die step (or take at d4 if untrained).
+    FreeSkill,
 }
 
 impl FromStr for AncestryChoiceType {
@@ -19,6 +23,8 @@ impl FromStr for AncestryChoiceType {
             "free_edge" => Ok(AncestryChoiceType::FreeEdge),
             "mandatory_hindrance" => Ok(AncestryChoiceType::MandatoryHindrance),
             "ancestral_enemy" => Ok(AncestryChoiceType::AncestralEnemy),
+            "attribute_increase" => Ok(AncestryChoiceType::AttributeIncrease),
+            "free_skill" => Ok(AncestryChoiceType::FreeSkill),
             _ => Err(format!("Invalid ancestry choice type: {}", s)),
         }
     }
@@ -30,6 +36,8 @@ impl fmt::Display for AncestryChoiceType {
             AncestryChoiceType::FreeEdge => write!(f, "free_edge"),
             AncestryChoiceType::MandatoryHindrance => write!(f, "mandatory_hindrance"),
             AncestryChoiceType::AncestralEnemy => write!(f, "ancestral_enemy"),
+            AncestryChoiceType::AttributeIncrease => write!(f, "attribute_increase"),
+            AncestryChoiceType::FreeSkill => write!(f, "free_skill"),
         }
     }
 }
@@ -52,6 +60,14 @@ mod tests {
             AncestryChoiceType::from_str("ancestral_enemy").unwrap(),
             AncestryChoiceType::AncestralEnemy
         );
+        assert_eq!(
+            AncestryChoiceType::from_str("attribute_increase").unwrap(),
+            AncestryChoiceType::AttributeIncrease
+        );
+        assert_eq!(
+            AncestryChoiceType::from_str("free_skill").unwrap(),
+            AncestryChoiceType::FreeSkill
+        );
     }
 
     #[test]
@@ -82,6 +98,11 @@ mod tests {
             AncestryChoiceType::AncestralEnemy.to_string(),
             "ancestral_enemy"
         );
+        assert_eq!(
+            AncestryChoiceType::AttributeIncrease.to_string(),
+            "attribute_increase"
+        );
+        assert_eq!(AncestryChoiceType::FreeSkill.to_string(), "free_skill");
     }
 
     #[test]
@@ -9,6 +9,11 @@ pub enum AncestryOptionType {
     Edge,
     Hindrance,
     Ancestry,
+    /// A free die step in an attribute; `option_id` is the attribute id.
+    Attribute,
+    /// A free die step in a skill (untrained skills start at d4); `option_id`
+    /// is the skill id.
+    Skill,
 }
 
 impl FromStr for AncestryOptionType {
@@ -19,6 +24,8 @@ impl FromStr for AncestryOptionType {
             "edge" => Ok(AncestryOptionType::Edge),
             "hindrance" => Ok(AncestryOptionType::Hindrance),
             "ancestry" => Ok(AncestryOptionType::Ancestry),
+            "attribute" => Ok(AncestryOptionType::Attribute),
+            "skill" => Ok(AncestryOptionType::Skill),
             _ => Err(format!("Invalid ancestry option type: {}", s)),
         }
     }
@@ -30,6 +37,8 @@ impl fmt::Display for AncestryOptionType {
             AncestryOptionType::Edge => write!(f, "edge"),
             AncestryOptionType::Hindrance => write!(f, "hindrance"),
             AncestryOptionType::Ancestry => write!(f, "ancestry"),
+            AncestryOptionType::Attribute => write!(f, "attribute"),
+            AncestryOptionType::Skill => write!(f, "skill"),
         }
     }
 }
@@ -52,6 +61,14 @@ mod tests {
             AncestryOptionType::from_str("ancestry").unwrap(),
             AncestryOptionType::Ancestry
         );
+        assert_eq!(
+            AncestryOptionType::from_str("attribute").unwrap(),
+            AncestryOptionType::Attribute
+        );
+        assert_eq!(
+            AncestryOptionType::from_str("skill").unwrap(),
+            AncestryOptionType::Skill
+        );
     }
 
     #[test]
@@ -76,6 +93,8 @@ mod tests {
         assert_eq!(AncestryOptionType::Edge.to_string(), "edge");
         assert_eq!(AncestryOptionType::Hindrance.to_string(), "hindrance");
         assert_eq!(AncestryOptionType::Ancestry.to_string(), "ancestry");
+        assert_eq!(AncestryOptionType::Attribute.to_string(), "attribute");
+        assert_eq!(AncestryOptionType::Skill.to_string(), "skill");
     }
 
     #[test]
@@ -84,6 +84,7 @@ mod tests {
             value_type: "die_increment".to_string(),
             value: Some(1),
             description: "+1 die type to Agility".to_string(),
+            condition: None,
             created_at: "2024-01-01".to_string(),
             updated_at: "2024-01-01".to_string(),
         }
@@ -94,6 +95,7 @@ mod tests {
             id: 1,
             requirement_type: "rank".to_string(),
             target_id: Some(1),
+            target_name: None,
             value: Some(1),
             description: "Novice".to_string(),
             created_at: "2024-01-01".to_string(),
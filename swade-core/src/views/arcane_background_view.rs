@@ -15,6 +15,8 @@ pub struct ArcaneBackgroundView {
     pub has_power_list: bool,
     pub source: String,
     pub description: String,
+    /// Edge taken as an advance to gain this arcane background
+    pub edge_id: Option<i64>,
     pub requirements: RequirementTree,
     pub choices: Vec<ArcaneBackgroundChoiceView>,
 }
@@ -36,6 +38,7 @@ impl ArcaneBackgroundView {
             has_power_list: arcane_background.has_power_list,
             source: arcane_background.source,
             description: arcane_background.description,
+            edge_id: arcane_background.edge_id,
             requirements,
             choices,
         }
@@ -59,6 +62,7 @@ mod tests {
             description: "Wizards and sorcerers".to_string(),
             created_at: "2024-01-01".to_string(),
             updated_at: "2024-01-01".to_string(),
+            edge_id: None,
         }
     }
 
@@ -67,6 +71,7 @@ mod tests {
             id: 1,
             requirement_type: "rank".to_string(),
             target_id: Some(1),
+            target_name: None,
             value: Some(1),
             description: "Novice".to_string(),
             created_at: "2024-01-01".to_string(),
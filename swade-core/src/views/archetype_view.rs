@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::views::{Die, EdgeView, HindranceView};
+
+/// An attribute or skill an archetype raises, and the die it aims for.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct ArchetypeTraitView {
+    pub id: i64,
+    pub name: String,
+    pub die: Die,
+}
+
+/// A quick build template with its suggestions resolved, each list in the
+/// order it is applied.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct ArchetypeView {
+    pub id: i64,
+    pub key: String,
+    pub name: String,
+    pub description: String,
+    pub attributes: Vec<ArchetypeTraitView>,
+    pub skills: Vec<ArchetypeTraitView>,
+    pub edges: Vec<EdgeView>,
+    pub hindrances: Vec<HindranceView>,
+}
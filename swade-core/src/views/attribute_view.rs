@@ -17,7 +17,10 @@ impl AttributeView {
             id: attribute.id,
             name: attribute.name,
             description: attribute.description,
-            base_die: Die::new(attribute.base_value as u8).unwrap_or(Die::d4()),
+            base_die: u8::try_from(attribute.base_value)
+                .ok()
+                .and_then(Die::new)
+                .unwrap_or(Die::d4()),
         }
     }
 }
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::Rank;
+use crate::views::{CharacterView, Die};
+
 /// Represents an advance that a character has taken, with resolved names for display.
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
 pub struct CharacterAdvanceValue {
@@ -7,6 +10,29 @@ pub struct CharacterAdvanceValue {
     pub advance_number: i64,
     pub advance_type: AdvanceType,
     pub description: String,
+    /// The player's own annotation for this advance, if any.
+    pub notes: Option<String>,
+    pub created_at: String,
+    /// The rank the character entered by taking this advance, if it crossed a rank boundary.
+    /// Only set on freshly applied advances, not on history entries.
+    pub rank_changed: Option<Rank>,
+}
+
+/// A freshly applied advance together with the character as it stands afterwards,
+/// so the UI gets the new dice and derived stats without fetching again.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct AppliedAdvance {
+    pub advance: CharacterAdvanceValue,
+    pub character: CharacterView,
+}
+
+/// One advance on a character's progression timeline, labelled with the rank
+/// the character held once it was taken.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct AdvancementTimelineEntry {
+    pub advance_number: i64,
+    pub rank: String,
+    pub description: String,
     pub created_at: String,
 }
 
@@ -15,14 +41,14 @@ pub struct CharacterAdvanceValue {
 pub struct AttributeAdvanceOption {
     pub id: i64,
     pub name: String,
-    /// Base die size (without modifiers)
-    pub current_die: u8,
-    /// Effective die size (with modifiers applied) - for display
-    pub effective_die: u8,
-    /// Base die size after advancement
-    pub next_die: u8,
-    /// Effective die size after advancement (with modifiers) - for display
-    pub effective_next_die: u8,
+    /// Base die (without modifiers)
+    pub current_die: Die,
+    /// Effective die (with modifiers applied) - for display
+    pub effective_die: Die,
+    /// Base die after advancement
+    pub next_die: Die,
+    /// Effective die after advancement (with modifiers) - for display
+    pub effective_next_die: Die,
     pub is_maxed: bool,
 }
 
@@ -31,14 +57,14 @@ pub struct AttributeAdvanceOption {
 pub struct SkillAdvanceOption {
     pub id: i64,
     pub name: String,
-    /// Base die size (0 if untrained)
-    pub current_die: u8,
-    /// Effective die size (with modifiers applied) - for display
-    pub effective_die: u8,
-    /// Base die size after advancement
-    pub next_die: u8,
-    /// Effective die size after advancement (with modifiers) - for display
-    pub effective_next_die: u8,
+    /// Base die (None if untrained)
+    pub current_die: Option<Die>,
+    /// Effective die (with modifiers applied) - for display
+    pub effective_die: Option<Die>,
+    /// Base die after advancement
+    pub next_die: Die,
+    /// Effective die after advancement (with modifiers) - for display
+    pub effective_next_die: Die,
     pub is_maxed: bool,
 }
 
@@ -62,7 +88,7 @@ impl HindranceAction {
             HindranceAction::RemoveMinor => "remove_minor",
             HindranceAction::ReduceMajor => "reduce_major",
             HindranceAction::RemoveMajorHalf => "remove_major_half",
-            HindranceAction::CompleteMajorRemoval => "remove_major_half", // Same DB value, different meaning
+            HindranceAction::CompleteMajorRemoval => "complete_major_removal",
         }
     }
 
@@ -129,6 +155,36 @@ impl AdvanceType {
     }
 }
 
+/// One advance in a proposed advancement plan.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PlannedAdvance {
+    /// `arcane_background_id` picks the arcane background the edge grants, if
+    /// it grants more than one.
+    Edge { edge_id: i64, arcane_background_id: Option<i64> },
+    Attribute { attribute_id: i64 },
+    SkillExpensive { skill_id: i64 },
+    SkillCheap { skill_id_1: i64, skill_id_2: i64 },
+    Hindrance { hindrance_id: i64, action: HindranceAction },
+}
+
+/// The outcome of one step of a previewed advancement plan.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct AdvancePlanStep {
+    /// Position of the step in the plan, starting at 1
+    pub step: i64,
+    /// Whether the advance could be taken at this point in the plan
+    pub success: bool,
+    /// What the advance does, if it succeeded
+    pub description: Option<String>,
+    /// Why the advance was rejected, if it failed
+    pub error: Option<String>,
+    /// Advances taken after this step
+    pub advances: i64,
+    /// Rank after this step
+    pub rank: String,
+}
+
 /// Options available for a character's next advancement.
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
 pub struct AdvancementOptions {
@@ -154,8 +210,16 @@ pub struct AdvancementOptions {
     pub hindrance_options: Vec<HindranceAdvanceOption>,
     /// Current advance number (what number this advance would be)
     pub next_advance_number: i64,
+    /// Advances earned from XP but not yet taken. None unless the character
+    /// tracks XP.
+    pub unspent_advances: Option<i64>,
     /// Current rank name
     pub current_rank: String,
     /// Rank after taking this advance
     pub rank_after_advance: String,
+    /// Advances since the character reached the current rank
+    pub advances_into_rank: i64,
+    /// Advances left until the next rank (the last one enters it). None in
+    /// the final, open-ended rank.
+    pub advances_remaining_in_rank: Option<i64>,
 }
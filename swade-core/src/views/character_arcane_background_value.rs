@@ -7,13 +7,20 @@ use crate::views::ArcaneBackgroundView;
 pub struct CharacterArcaneBackgroundValue {
     pub arcane_background: ArcaneBackgroundView,
     pub advance_taken: Option<i64>,
+    /// Power points spent from this background's pool.
+    pub power_points_used: i64,
 }
 
 impl CharacterArcaneBackgroundValue {
-    pub fn new(arcane_background: ArcaneBackgroundView, advance_taken: Option<i64>) -> Self {
+    pub fn new(
+        arcane_background: ArcaneBackgroundView,
+        advance_taken: Option<i64>,
+        power_points_used: i64,
+    ) -> Self {
         Self {
             arcane_background,
             advance_taken,
+            power_points_used,
         }
     }
 }
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::constants::ATTRIBUTE_STEP_COST;
 use crate::views::{AttributeView, Die};
 
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
@@ -17,6 +18,13 @@ pub struct CharacterAttributeValue {
     pub can_increment: bool,
     /// Whether this attribute can be decremented (not at base).
     pub can_decrement: bool,
+    /// The attribute points it costs to raise this attribute one step.
+    pub increment_cost: i64,
+    /// Attribute points that would remain after raising this attribute.
+    pub points_if_incremented: i64,
+    /// Attribute points that would remain after lowering this attribute (the
+    /// current remainder when it is already at base).
+    pub points_if_decremented: i64,
 }
 
 impl CharacterAttributeValue {
@@ -30,6 +38,9 @@ impl CharacterAttributeValue {
             effective_die: die,
             can_increment: false,
             can_decrement: false,
+            increment_cost: ATTRIBUTE_STEP_COST,
+            points_if_incremented: 0,
+            points_if_decremented: 0,
         }
     }
 
@@ -51,6 +62,9 @@ impl CharacterAttributeValue {
             max_die,
             can_increment,
             can_decrement,
+            increment_cost: ATTRIBUTE_STEP_COST,
+            points_if_incremented: 0,
+            points_if_decremented: 0,
         }
     }
 }
@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{
+    DEFAULT_PARRY_SKILL, DEFAULT_SELL_BACK_PERCENT, LOAD_LIMIT_PER_STRENGTH_STEP, MAX_WOUNDS,
+};
+use crate::views::{Die, WeightUnit};
+
+/// Per-character house rule settings.
+///
+/// Every option is unset by default, which keeps the core SWADE rules.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct CharacterConfig {
+    /// Highest die attributes can be raised to. Unset means d12.
+    pub max_attribute_die: Option<Die>,
+    /// Let hindrances be taken past the starting point cap. Points past the cap
+    /// are not earned.
+    pub allow_hindrances_past_cap: bool,
+    /// Pounds of load limit per Strength die step. Unset means the core 20.
+    pub load_limit_multiplier: Option<i64>,
+    /// Skill Parry is derived from, for settings that don't use Fighting.
+    /// Unset means Fighting.
+    pub parry_skill: Option<String>,
+    /// Wounds a Wild Card can take before Incapacitation. Unset means 3.
+    pub max_wounds: Option<i64>,
+    /// Earn advances from experience points (one per 5 XP) instead of
+    /// counting advances taken.
+    pub track_experience: bool,
+    /// Percent of an item's cost refunded when selling it. Unset means 50.
+    pub sell_back_percent: Option<i64>,
+    /// Unit gear weights and encumbrance are shown in. Weights are always
+    /// stored and compared in pounds.
+    pub weight_unit: WeightUnit,
+}
+
+impl CharacterConfig {
+    /// Build a config from stored column values.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_columns(
+        max_attribute_die_size: Option<i64>,
+        max_attribute_die_modifier: i64,
+        allow_hindrances_past_cap: bool,
+        load_limit_multiplier: Option<i64>,
+        parry_skill: Option<String>,
+        max_wounds: Option<i64>,
+        track_experience: bool,
+        sell_back_percent: Option<i64>,
+        weight_unit: &str,
+    ) -> Self {
+        Self {
+            max_attribute_die: max_attribute_die_size
+                .and_then(|size| {
+                    let size = u8::try_from(size).ok()?;
+                    let modifier = u8::try_from(max_attribute_die_modifier).ok()?;
+                    Die::with_modifier(size, modifier)
+                }),
+            allow_hindrances_past_cap,
+            load_limit_multiplier,
+            parry_skill,
+            max_wounds,
+            track_experience,
+            sell_back_percent,
+            weight_unit: weight_unit.parse().unwrap_or_default(),
+        }
+    }
+
+    /// The attribute die cap, falling back to d12.
+    pub fn attribute_die_cap(&self) -> Die {
+        self.max_attribute_die.unwrap_or(Die::d12())
+    }
+
+    /// Pounds of load limit per Strength die step, falling back to the core 20.
+    pub fn load_limit_multiplier(&self) -> i64 {
+        self.load_limit_multiplier.unwrap_or(LOAD_LIMIT_PER_STRENGTH_STEP)
+    }
+
+    /// Skill Parry is derived from, falling back to Fighting.
+    pub fn parry_skill(&self) -> &str {
+        self.parry_skill.as_deref().unwrap_or(DEFAULT_PARRY_SKILL)
+    }
+
+    /// Wounds a Wild Card can take before Incapacitation, falling back to 3.
+    pub fn max_wounds(&self) -> i64 {
+        self.max_wounds.unwrap_or(MAX_WOUNDS)
+    }
+
+    /// Percent of an item's cost refunded when selling it, falling back to 50.
+    pub fn sell_back_percent(&self) -> i64 {
+        self.sell_back_percent.unwrap_or(DEFAULT_SELL_BACK_PERCENT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_caps_attributes_at_d12() {
+        let config = CharacterConfig::default();
+
+        assert_eq!(config.max_attribute_die, None);
+        assert_eq!(config.attribute_die_cap(), Die::d12());
+        assert_eq!(config.load_limit_multiplier(), 20);
+        assert_eq!(config.parry_skill(), "Fighting");
+        assert_eq!(config.max_wounds(), 3);
+        assert_eq!(config.sell_back_percent(), 50);
+    }
+
+    #[test]
+    fn from_columns_ignores_out_of_range_attribute_cap() {
+        // 268 would wrap to 12 if truncated to a u8
+        let config = CharacterConfig::from_columns(Some(268), 0, false, None, None, None, false, None, "lb");
+
+        assert_eq!(config.max_attribute_die, None);
+        assert_eq!(config.attribute_die_cap(), Die::d12());
+    }
+
+    #[test]
+    fn from_columns_with_load_limit_multiplier() {
+        let config = CharacterConfig::from_columns(None, 0, false, Some(30), None, None, false, None, "lb");
+
+        assert_eq!(config.load_limit_multiplier(), 30);
+    }
+
+    #[test]
+    fn from_columns_with_parry_skill() {
+        let config =
+            CharacterConfig::from_columns(None, 0, false, None, Some("Martial Arts".to_string()), None, false, None, "lb");
+
+        assert_eq!(config.parry_skill(), "Martial Arts");
+    }
+
+    #[test]
+    fn from_columns_with_sell_back_percent() {
+        let config = CharacterConfig::from_columns(None, 0, false, None, None, None, false, Some(25), "lb");
+
+        assert_eq!(config.sell_back_percent(), 25);
+    }
+
+    #[test]
+    fn from_columns_parses_weight_unit() {
+        let kilograms = CharacterConfig::from_columns(None, 0, false, None, None, None, false, None, "kg");
+        let unknown = CharacterConfig::from_columns(None, 0, false, None, None, None, false, None, "st");
+
+        assert_eq!(kilograms.weight_unit, WeightUnit::Kg);
+        assert_eq!(unknown.weight_unit, WeightUnit::Lb);
+    }
+
+    #[test]
+    fn from_columns_with_d12_plus_cap() {
+        let config = CharacterConfig::from_columns(Some(12), 2, false, None, None, None, false, None, "lb");
+
+        assert_eq!(
+            config.attribute_die_cap(),
+            Die::with_modifier(12, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_columns_unset() {
+        let config = CharacterConfig::from_columns(None, 0, false, None, None, None, false, None, "lb");
+
+        assert_eq!(config, CharacterConfig::default());
+    }
+
+    #[test]
+    fn from_columns_invalid_size_falls_back() {
+        let config = CharacterConfig::from_columns(Some(7), 0, false, None, None, None, false, None, "lb");
+
+        assert_eq!(config.attribute_die_cap(), Die::d12());
+    }
+}
@@ -0,0 +1,189 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::views::{CharacterView, Die};
+
+/// How an entry differs between the two characters being compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// An attribute or skill whose effective die differs.
+///
+/// A skill going from untrained to trained is `Added` with no old die, and the
+/// reverse is `Removed` with no new die.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct TraitDiff {
+    pub id: i64,
+    pub name: String,
+    pub kind: DiffKind,
+    pub old_die: Option<Die>,
+    pub new_die: Option<Die>,
+}
+
+/// An edge or hindrance held by only one side, or held a different number of
+/// times (repeatable edges).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct FeatureDiff {
+    pub id: i64,
+    pub name: String,
+    pub kind: DiffKind,
+    pub old_count: i64,
+    pub new_count: i64,
+}
+
+/// A derived stat whose value differs, keyed by stat name (pace, parry, ...).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct StatDiff {
+    pub stat: String,
+    pub old_value: i32,
+    pub new_value: i32,
+}
+
+/// Differences between two characters' effective sheets.
+///
+/// Built by `CharacterDiff::between()` from each side's `EffectiveSheet`, so
+/// only effective values are compared. Every list is sorted by id (stats keep
+/// sheet order) and leaves out entries that are the same on both sides.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct CharacterDiff {
+    pub attributes: Vec<TraitDiff>,
+    pub skills: Vec<TraitDiff>,
+    pub edges: Vec<FeatureDiff>,
+    pub hindrances: Vec<FeatureDiff>,
+    pub derived_stats: Vec<StatDiff>,
+}
+
+impl CharacterDiff {
+    /// Compare `before` against `after`.
+    pub fn between(before: &CharacterView, after: &CharacterView) -> Self {
+        let (old, new) = (before.effective_snapshot(), after.effective_snapshot());
+
+        let mut attribute_names: HashMap<i64, &str> = HashMap::new();
+        let mut skill_names: HashMap<i64, &str> = HashMap::new();
+        let mut edge_names: HashMap<i64, &str> = HashMap::new();
+        let mut hindrance_names: HashMap<i64, &str> = HashMap::new();
+        for character in [before, after] {
+            for a in &character.attributes {
+                attribute_names.insert(a.attribute.id, &a.attribute.name);
+            }
+            for s in &character.skills {
+                skill_names.insert(s.skill.id, &s.skill.name);
+            }
+            for e in &character.edges {
+                edge_names.insert(e.edge.id, &e.edge.name);
+            }
+            for h in &character.hindrances {
+                hindrance_names.insert(h.hindrance.id, &h.hindrance.name);
+            }
+        }
+
+        let attributes = diff_traits(
+            &old.attributes.iter().map(|(id, die)| (*id, Some(*die))).collect::<Vec<_>>(),
+            &new.attributes.iter().map(|(id, die)| (*id, Some(*die))).collect::<Vec<_>>(),
+            &attribute_names,
+        );
+        let skills = diff_traits(&old.skills, &new.skills, &skill_names);
+
+        let derived_stats = [
+            ("pace", old.pace, new.pace),
+            ("parry", old.parry, new.parry),
+            ("toughness", old.toughness, new.toughness),
+            ("size", old.size, new.size),
+            ("wound_penalty", old.wound_penalty, new.wound_penalty),
+            ("fatigue_penalty", old.fatigue_penalty, new.fatigue_penalty),
+            ("encumbrance_penalty", old.encumbrance_penalty, new.encumbrance_penalty),
+        ]
+        .into_iter()
+        .filter(|(_, old_value, new_value)| old_value != new_value)
+        .map(|(stat, old_value, new_value)| StatDiff {
+            stat: stat.to_string(),
+            old_value,
+            new_value,
+        })
+        .collect();
+
+        Self {
+            attributes,
+            skills,
+            edges: diff_features(&old.edge_ids, &new.edge_ids, &edge_names),
+            hindrances: diff_features(&old.hindrance_ids, &new.hindrance_ids, &hindrance_names),
+            derived_stats,
+        }
+    }
+
+    /// True when both characters have the same effective sheet.
+    pub fn is_empty(&self) -> bool {
+        self.attributes.is_empty()
+            && self.skills.is_empty()
+            && self.edges.is_empty()
+            && self.hindrances.is_empty()
+            && self.derived_stats.is_empty()
+    }
+}
+
+fn diff_traits(
+    old: &[(i64, Option<Die>)],
+    new: &[(i64, Option<Die>)],
+    names: &HashMap<i64, &str>,
+) -> Vec<TraitDiff> {
+    let old: BTreeMap<i64, Option<Die>> = old.iter().copied().collect();
+    let new: BTreeMap<i64, Option<Die>> = new.iter().copied().collect();
+    let ids: BTreeSet<i64> = old.keys().chain(new.keys()).copied().collect();
+
+    ids.into_iter()
+        .filter_map(|id| {
+            let old_die = old.get(&id).copied().flatten();
+            let new_die = new.get(&id).copied().flatten();
+            let kind = match (old_die, new_die) {
+                (None, Some(_)) => DiffKind::Added,
+                (Some(_), None) => DiffKind::Removed,
+                (Some(a), Some(b)) if a != b => DiffKind::Changed,
+                _ => return None,
+            };
+            Some(TraitDiff {
+                id,
+                name: names.get(&id).copied().unwrap_or_default().to_string(),
+                kind,
+                old_die,
+                new_die,
+            })
+        })
+        .collect()
+}
+
+fn diff_features(old: &[i64], new: &[i64], names: &HashMap<i64, &str>) -> Vec<FeatureDiff> {
+    let count = |ids: &[i64]| {
+        let mut counts: BTreeMap<i64, i64> = BTreeMap::new();
+        for id in ids {
+            *counts.entry(*id).or_default() += 1;
+        }
+        counts
+    };
+    let (old, new) = (count(old), count(new));
+    let ids: BTreeSet<i64> = old.keys().chain(new.keys()).copied().collect();
+
+    ids.into_iter()
+        .filter_map(|id| {
+            let old_count = old.get(&id).copied().unwrap_or(0);
+            let new_count = new.get(&id).copied().unwrap_or(0);
+            let kind = match (old_count, new_count) {
+                (0, _) => DiffKind::Added,
+                (_, 0) => DiffKind::Removed,
+                (a, b) if a != b => DiffKind::Changed,
+                _ => return None,
+            };
+            Some(FeatureDiff {
+                id,
+                name: names.get(&id).copied().unwrap_or_default().to_string(),
+                kind,
+                old_count,
+                new_count,
+            })
+        })
+        .collect()
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::views::GearView;
+use crate::views::{Die, GearView, WeightUnit};
 
 /// Represents a gear item owned by a character
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
@@ -12,6 +12,16 @@ pub struct CharacterGearValue {
     pub custom_notes: Option<String>,
     /// Total weight of this gear stack (weight * quantity)
     pub total_weight: f64,
+    /// Total weight formatted in the owner's weight unit, e.g. "6 lb"
+    pub weight_label: String,
+
+    // Combat stats for the gear list, copied from the weapon stats (weapons only)
+    /// Damage as written, e.g. "Str+d6" or "2d6".
+    pub damage: Option<String>,
+    /// Range as "short/medium/long" in inches.
+    pub range: Option<String>,
+    pub reach: Option<i64>,
+    pub rof: Option<i64>,
 }
 
 impl CharacterGearValue {
@@ -23,6 +33,11 @@ impl CharacterGearValue {
         custom_notes: Option<String>,
     ) -> Self {
         let total_weight = gear.weight * quantity as f64;
+        let weapon = gear.weapon_stats.as_ref();
+        let damage = weapon.map(|w| w.damage.clone());
+        let range = weapon.and_then(|w| w.range_string());
+        let reach = weapon.and_then(|w| w.reach);
+        let rof = weapon.and_then(|w| w.rof);
         Self {
             id,
             gear,
@@ -30,6 +45,11 @@ impl CharacterGearValue {
             is_equipped,
             custom_notes,
             total_weight,
+            weight_label: WeightUnit::Lb.format(total_weight),
+            damage,
+            range,
+            reach,
+            rof,
         }
     }
 
@@ -38,8 +58,122 @@ impl CharacterGearValue {
         self.gear.weight * self.quantity as f64
     }
 
+    /// Total weight of this stack formatted in the given unit, e.g. "6 lb".
+    pub fn display_weight(&self, unit: WeightUnit) -> String {
+        unit.format(self.total_weight())
+    }
+
     /// Calculate the total cost of this gear (cost * quantity)
     pub fn total_cost(&self) -> i64 {
         self.gear.cost * self.quantity
     }
+
+    /// This weapon's damage with "Str" replaced by the given Strength die,
+    /// e.g. "Str+d6" with a d8 Strength becomes "d8+d6". `None` for gear
+    /// without weapon stats.
+    pub fn resolved_damage(&self, strength_die: Die) -> Option<String> {
+        self.damage
+            .as_deref()
+            .map(|damage| Self::resolve_damage(damage, strength_die))
+    }
+
+    /// Substitute a Strength die into any damage expression. Flat damage like
+    /// "2d6" is returned unchanged, and unarmed damage ("Str") is just the die.
+    pub fn resolve_damage(damage: &str, strength_die: Die) -> String {
+        damage.replace("Str", &strength_die.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::views::{GearCategoryView, RequirementTree, WeaponStatsView};
+
+    fn create_weapon(damage: &str) -> CharacterGearValue {
+        let gear = GearView {
+            id: 1,
+            name: "Weapon".to_string(),
+            category: GearCategoryView {
+                id: 1,
+                name: "Melee Weapons".to_string(),
+                description: None,
+            },
+            era: "medieval".to_string(),
+            cost: 100,
+            weight: 3.0,
+            source: "core".to_string(),
+            notes: None,
+            description: None,
+            modifiers: vec![],
+            requirements: RequirementTree::none(),
+            weapon_stats: Some(WeaponStatsView {
+                damage: damage.to_string(),
+                ap: 0,
+                range_short: None,
+                range_medium: None,
+                range_long: None,
+                rof: None,
+                shots: None,
+                min_strength: None,
+                is_two_handed: false,
+                reach: None,
+                blast_template: None,
+                notes: None,
+            }),
+            armor_stats: None,
+            shield_stats: None,
+            ammunition_stats: None,
+            poison_stats: None,
+            pack_contents: None,
+        };
+        CharacterGearValue::new(1, gear, 1, true, None)
+    }
+
+    #[test]
+    fn resolved_damage_substitutes_strength() {
+        let sword = create_weapon("Str+d8");
+
+        assert_eq!(sword.resolved_damage(Die::d8()), Some("d8+d8".to_string()));
+        assert_eq!(
+            sword.resolved_damage(Die::with_modifier(12, 1).unwrap()),
+            Some("d12+1+d8".to_string())
+        );
+    }
+
+    #[test]
+    fn resolved_damage_leaves_flat_damage_alone() {
+        let pistol = create_weapon("2d6+1");
+
+        assert_eq!(pistol.resolved_damage(Die::d10()), Some("2d6+1".to_string()));
+    }
+
+    #[test]
+    fn resolved_damage_handles_mixed_expressions() {
+        let great_axe = create_weapon("Str+d6+2");
+
+        assert_eq!(great_axe.resolved_damage(Die::d6()), Some("d6+d6+2".to_string()));
+    }
+
+    #[test]
+    fn resolve_damage_unarmed_is_strength_die() {
+        assert_eq!(CharacterGearValue::resolve_damage("Str", Die::d6()), "d6");
+    }
+
+    #[test]
+    fn resolved_damage_is_none_without_weapon_stats() {
+        let mut rope = create_weapon("Str");
+        rope.gear.weapon_stats = None;
+        let rope = CharacterGearValue::new(1, rope.gear, 1, false, None);
+
+        assert_eq!(rope.resolved_damage(Die::d6()), None);
+    }
+
+    #[test]
+    fn display_weight_in_pounds_and_kilograms() {
+        let mut sword = create_weapon("Str+d8");
+        sword.quantity = 2;
+
+        assert_eq!(sword.display_weight(WeightUnit::Lb), "6 lb");
+        assert_eq!(sword.display_weight(WeightUnit::Kg), "2.7 kg");
+    }
 }
@@ -6,16 +6,28 @@ pub struct CharacterNoteValue {
     pub id: i64,
     pub title: String,
     pub body: String,
+    pub pinned: bool,
+    pub category: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
 
 impl CharacterNoteValue {
-    pub fn new(id: i64, title: String, body: String, created_at: String, updated_at: String) -> Self {
+    pub fn new(
+        id: i64,
+        title: String,
+        body: String,
+        pinned: bool,
+        category: Option<String>,
+        created_at: String,
+        updated_at: String,
+    ) -> Self {
         Self {
             id,
             title,
             body,
+            pinned,
+            category,
             created_at,
             updated_at,
         }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 use crate::views::{Die, SkillView};
 
@@ -53,6 +54,23 @@ impl CharacterSkillValue {
             can_decrement,
         }
     }
+
+    /// Group skills under their linked attribute's name.
+    ///
+    /// Every skill lands in a group whether trained or not, and keeps its
+    /// position from `skills` within the group.
+    pub fn group_by_attribute(
+        skills: &[CharacterSkillValue],
+    ) -> BTreeMap<String, Vec<CharacterSkillValue>> {
+        let mut groups: BTreeMap<String, Vec<CharacterSkillValue>> = BTreeMap::new();
+        for value in skills {
+            groups
+                .entry(value.skill.linked_attribute_name.clone())
+                .or_default()
+                .push(value.clone());
+        }
+        groups
+    }
 }
 
 #[cfg(test)]
@@ -78,7 +96,7 @@ mod tests {
 
     #[test]
     fn new_with_trained_skill() {
-        let skill_view = SkillView::new(create_test_skill());
+        let skill_view = SkillView::new(create_test_skill(), "Agility".to_string());
         let value = CharacterSkillValue::new(skill_view, Some(Die::d8()));
 
         assert_eq!(value.skill.name, "Fighting");
@@ -88,7 +106,7 @@ mod tests {
 
     #[test]
     fn new_with_untrained_skill() {
-        let skill_view = SkillView::new(create_test_skill());
+        let skill_view = SkillView::new(create_test_skill(), "Agility".to_string());
         let value = CharacterSkillValue::new(skill_view, None);
 
         assert_eq!(value.skill.name, "Fighting");
@@ -98,7 +116,7 @@ mod tests {
 
     #[test]
     fn with_effective_creates_value_with_modifier() {
-        let skill_view = SkillView::new(create_test_skill());
+        let skill_view = SkillView::new(create_test_skill(), "Agility".to_string());
         let purchased = Some(Die::d6());
         let effective = Some(Die::d8()); // +1 from modifier
 
@@ -119,4 +137,28 @@ mod tests {
         assert!(value.can_increment);
         assert!(value.can_decrement);
     }
+
+    #[test]
+    fn group_by_attribute_collects_skills_under_their_attribute() {
+        let skill = |id: i64, name: &str, attribute_id: i64, attribute: &str| {
+            let mut skill = create_test_skill();
+            skill.id = id;
+            skill.name = name.to_string();
+            skill.linked_attribute_id = attribute_id;
+            SkillView::new(skill, attribute.to_string())
+        };
+        let skills = vec![
+            CharacterSkillValue::new(skill(1, "Athletics", 1, "Agility"), Some(Die::d6())),
+            CharacterSkillValue::new(skill(2, "Notice", 2, "Smarts"), Some(Die::d4())),
+            CharacterSkillValue::new(skill(3, "Fighting", 1, "Agility"), None),
+        ];
+
+        let groups = CharacterSkillValue::group_by_attribute(&skills);
+
+        assert_eq!(groups.len(), 2);
+        let agility: Vec<&str> = groups["Agility"].iter().map(|v| v.skill.name.as_str()).collect();
+        assert_eq!(agility, vec!["Athletics", "Fighting"]);
+        assert_eq!(groups["Agility"][1].die, None);
+        assert_eq!(groups["Smarts"][0].skill.name, "Notice");
+    }
 }
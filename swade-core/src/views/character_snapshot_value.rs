@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// A saved checkpoint of a character, without the snapshot data itself.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct CharacterSnapshotValue {
+    pub id: i64,
+    pub character_id: i64,
+    pub label: String,
+    pub created_at: String,
+}
@@ -1,12 +1,21 @@
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
-use crate::constants::{BASE_PACE, BASE_PARRY, BASE_TOUGHNESS};
+use crate::constants::{
+    ATTRIBUTE_STEP_COST, BASE_PACE, BASE_PARRY, BASE_TOUGHNESS, DERIVED_STAT_NAMES, MAX_DIE_MODIFIER,
+    MAX_FATIGUE, MAX_HINDRANCE_POINTS,
+    MIN_TOUGHNESS, MAX_WOUND_PENALTY,
+    SOURCE_ANCESTRY, SOURCE_CHOSEN,
+};
 use crate::models::{Modifier, Rank};
 use crate::views::{
-    AncestryView, CharacterAncestryChoiceValue, CharacterArcaneBackgroundChoiceValue,
-    CharacterArcaneBackgroundValue, CharacterAttributeValue, CharacterEdgeValue,
+    AncestryOptionType, AncestryView, ArcaneBackgroundChoiceType, ArcaneBackgroundOptionType,
+    CharacterAncestryChoiceValue, CharacterArcaneBackgroundChoiceValue,
+    CharacterArcaneBackgroundValue, CharacterAttributeValue, CharacterConfig, CharacterEdgeValue,
     CharacterGearValue, CharacterHindranceValue, CharacterNoteValue, CharacterPowerValue,
-    CharacterSkillValue, DerivedStatsView, Die, EncumbranceInfo, RequirementContext,
+    CharacterSkillValue, CompanionSummary, DecrementImpact, DerivedStatChange, DerivedStatsView, Die,
+    EdgeView, EffectiveSheet, EncumbranceInfo, HindranceView, ModifierSource, OrphanedModifier, PendingChoice, PowerPointPool, PowerSlots,
+    PowerView, RequirementContext, RequirementTree, Severity,
 };
 
 /// Complete view of a character with all related data resolved.
@@ -15,6 +24,9 @@ pub struct CharacterView {
     pub id: i64,
     pub is_wild_card: bool,
     pub name: String,
+    /// How many times the saved character has been written, 0 for an unsaved
+    /// draft. Saving compares it against the database to catch stale drafts.
+    pub revision: i64,
 
     // Ancestry
     pub ancestry: Option<AncestryView>,
@@ -22,7 +34,10 @@ pub struct CharacterView {
 
     // Rank and Advances
     pub rank: Rank,
+    /// Advances taken, or earned from experience when the config tracks XP.
     pub current_advances: i64,
+    /// Total XP earned. Only drives advances when the config tracks XP.
+    pub experience_points: i64,
 
     // Attributes and Skills
     pub attributes: Vec<CharacterAttributeValue>,
@@ -36,16 +51,33 @@ pub struct CharacterView {
     pub arcane_backgrounds: Vec<CharacterArcaneBackgroundValue>,
     pub arcane_background_choices: Vec<CharacterArcaneBackgroundChoiceValue>,
     pub powers: Vec<CharacterPowerValue>,
+    /// Combined power points across all arcane backgrounds.
     pub power_points: i64,
+    /// Power points spent by a character without arcane backgrounds. With
+    /// arcane backgrounds, each pool's `used` is what counts; see
+    /// `total_power_points_used`.
     pub power_points_used: i64,
+    /// Power points from the arcane backgrounds' starting pools.
+    pub power_points_base: i64,
+    /// Power points added by modifiers such as the Power Points edge.
+    pub power_points_bonus: i64,
+    /// Separate power point pool for each arcane background.
+    pub power_point_pools: Vec<PowerPointPool>,
+    /// Starting powers allowed and chosen.
+    pub power_slots: PowerSlots,
 
     // Status Tracking
     pub wounds: i64,
     pub fatigue: i64,
+    /// Wounds or Fatigue past the character's maximum.
+    pub incapacitated: bool,
 
     // Notes
     pub notes: Vec<CharacterNoteValue>,
 
+    // Linked companions (sidekicks, animal companions, etc.)
+    pub companions: Vec<CompanionSummary>,
+
     // Gear and Encumbrance
     pub gear: Vec<CharacterGearValue>,
     pub encumbrance: EncumbranceInfo,
@@ -55,6 +87,9 @@ pub struct CharacterView {
 
     // Derived Stats (computed from attributes, skills, and modifiers)
     pub derived_stats: DerivedStatsView,
+    /// Situational modifiers (e.g. +1 Fighting when unarmed), listed separately
+    /// since they are left out of effective dice and derived stats.
+    pub situational_modifiers: Vec<ModifierSource>,
 
     // Character Creation Tracking
     pub attribute_points_spent: i64,
@@ -72,54 +107,66 @@ pub struct CharacterView {
 
     // Additional Character Info
     pub wealth: i64,
+    /// Wealth plus the value of all owned gear.
+    pub net_worth: i64,
     pub background: Option<String>,
     pub description: Option<String>,
+    /// Campaign or table the character belongs to.
+    pub campaign: Option<String>,
 
     // Portrait (as data URL for frontend display)
     pub portrait_data_url: Option<String>,
+
+    // House rule configuration
+    pub config: CharacterConfig,
+
+    /// Stored values that were out of range and corrected on load, e.g.
+    /// "Agility stored as d12+2, clamped to d12". Saving keeps the corrections.
+    pub data_warnings: Vec<String>,
 }
 
 impl CharacterView {
-    /// Aggregate modifier values from all sources (character, ancestry, edges, hindrances).
+    /// Collect every active modifier along with its source type and label.
     ///
-    /// Takes a predicate to filter which modifiers to include.
-    fn aggregate_modifiers<P>(&self, predicate: P) -> i64
-    where
-        P: Fn(&Modifier) -> bool,
-    {
-        let mut total = 0i64;
+    /// Walks direct character modifiers, ancestry (including attribute bonuses picked
+    /// through ancestry choices), edges, hindrances, and equipped gear.
+    fn sourced_modifiers(&self) -> Vec<(&'static str, &str, Cow<'_, Modifier>)> {
+        let mut sourced = Vec::new();
 
         // Direct character modifiers
         for m in &self.modifiers {
-            if predicate(m) {
-                total += m.value.unwrap_or(0);
-            }
+            sourced.push(("direct", "Direct", Cow::Borrowed(m)));
         }
 
         // Ancestry modifiers
         if let Some(ancestry) = &self.ancestry {
             for m in &ancestry.modifiers {
-                if predicate(m) {
-                    total += m.value.unwrap_or(0);
-                }
+                sourced.push(("ancestry", ancestry.name.as_str(), Cow::Borrowed(m)));
+            }
+            for m in self
+                .ancestry_attribute_increases()
+                .into_iter()
+                .chain(self.ancestry_skill_grants())
+            {
+                sourced.push(("ancestry", ancestry.name.as_str(), Cow::Owned(m)));
             }
         }
 
         // Edge modifiers
         for edge_value in &self.edges {
             for m in &edge_value.edge.modifiers {
-                if predicate(m) {
-                    total += m.value.unwrap_or(0);
-                }
+                sourced.push(("edge", edge_value.edge.name.as_str(), Cow::Borrowed(m)));
             }
         }
 
         // Hindrance modifiers
         for hindrance_value in &self.hindrances {
             for m in &hindrance_value.hindrance.modifiers {
-                if predicate(m) {
-                    total += m.value.unwrap_or(0);
-                }
+                sourced.push((
+                    "hindrance",
+                    hindrance_value.hindrance.name.as_str(),
+                    Cow::Borrowed(m),
+                ));
             }
         }
 
@@ -127,35 +174,213 @@ impl CharacterView {
         for gear_value in &self.gear {
             if gear_value.is_equipped {
                 for m in &gear_value.gear.modifiers {
-                    if predicate(m) {
-                        total += m.value.unwrap_or(0);
-                    }
+                    sourced.push(("gear", gear_value.gear.name.as_str(), Cow::Borrowed(m)));
                 }
             }
         }
 
-        total
+        sourced
+    }
+
+    /// Die increment modifiers for attributes picked through ancestry choices.
+    fn ancestry_attribute_increases(&self) -> Vec<Modifier> {
+        self.ancestry_choices
+            .iter()
+            .filter_map(|c| c.selected_option.as_ref())
+            .filter(|opt| opt.option_type == AncestryOptionType::Attribute)
+            .filter_map(|opt| {
+                let attr = self
+                    .attributes
+                    .iter()
+                    .find(|a| Some(a.attribute.id) == opt.option_id)?;
+                Some(Modifier {
+                    id: 0,
+                    target_type: Some("attribute".to_string()),
+                    target_identifier: Some(attr.attribute.name.clone()),
+                    value_type: "die_increment".to_string(),
+                    value: Some(1),
+                    description: format!("+1 die type to {}", attr.attribute.name),
+                    condition: None,
+                    created_at: String::new(),
+                    updated_at: String::new(),
+                })
+            })
+            .collect()
+    }
+
+    /// Modifiers for skills picked through ancestry choices.
+    ///
+    /// The pick is one free die step: an untrained skill is granted at d4, a
+    /// trained one goes up a die type. Either way no skill points are spent,
+    /// and deselecting the option takes the step away.
+    fn ancestry_skill_grants(&self) -> Vec<Modifier> {
+        self.ancestry_choices
+            .iter()
+            .filter_map(|c| c.selected_option.as_ref())
+            .filter(|opt| opt.option_type == AncestryOptionType::Skill)
+            .filter_map(|opt| {
+                let skill = self.skills.iter().find(|s| Some(s.skill.id) == opt.option_id)?;
+                let name = &skill.skill.name;
+                let (value_type, value, description) = match skill.die {
+                    None => ("grant_skill", 4, format!("Free d4 in {}", name)),
+                    Some(_) => ("die_increment", 1, format!("+1 die type to {}", name)),
+                };
+                Some(Modifier {
+                    id: 0,
+                    target_type: Some("skill".to_string()),
+                    target_identifier: Some(name.clone()),
+                    value_type: value_type.to_string(),
+                    value: Some(value),
+                    description,
+                    condition: None,
+                    created_at: String::new(),
+                    updated_at: String::new(),
+                })
+            })
+            .collect()
+    }
+
+    /// List active modifiers whose target names no attribute, skill, or derived
+    /// stat on this character.
+    ///
+    /// Modifiers match their targets by name, so renaming a skill in a data
+    /// update leaves its modifiers applying to nothing. Other target types
+    /// (skill points, power slots, ...) have no name to check and are skipped.
+    pub fn orphaned_modifiers(&self) -> Vec<OrphanedModifier> {
+        self.sourced_modifiers()
+            .into_iter()
+            .filter(|(_, _, m)| {
+                let Some(target) = m.target_identifier.as_deref() else {
+                    return false;
+                };
+                match m.target_type.as_deref() {
+                    Some("attribute") => !self.attributes.iter().any(|a| a.attribute.name == target),
+                    Some("skill") => !self.skills.iter().any(|s| s.skill.name == target),
+                    Some("derived_stat") => !DERIVED_STAT_NAMES.contains(&target),
+                    _ => false,
+                }
+            })
+            .map(|(source_type, source_name, m)| OrphanedModifier::new(source_type, source_name, &m))
+            .collect()
+    }
+
+    /// Aggregate modifier values from all sources (character, ancestry, edges, hindrances, gear).
+    ///
+    /// Takes a predicate to filter which modifiers to include. Values are signed, so
+    /// penalties (usually from hindrances) subtract from the total. Conditional
+    /// modifiers are only counted if the predicate lets them through; the
+    /// always-on totals below exclude them.
+    fn aggregate_modifiers<P>(&self, predicate: P) -> i64
+    where
+        P: Fn(&Modifier) -> bool,
+    {
+        self.sourced_modifiers()
+            .into_iter()
+            .filter(|(_, _, m)| predicate(m))
+            .map(|(_, _, m)| m.value.unwrap_or(0))
+            .sum()
+    }
+
+    /// List each modifier targeting `target_identifier` with the source it came from.
+    ///
+    /// Covers the same modifiers `aggregate_modifiers` sums, so the UI can explain
+    /// where a bonus or penalty comes from.
+    pub fn modifier_breakdown(&self, target_identifier: &str) -> Vec<ModifierSource> {
+        self.sourced_modifiers()
+            .into_iter()
+            .filter(|(_, _, m)| {
+                !m.is_conditional() && m.target_identifier.as_deref() == Some(target_identifier)
+            })
+            .map(|(source_type, source_name, m)| ModifierSource::new(source_type, source_name, &m))
+            .collect()
+    }
+
+    /// List every active conditional modifier with the source it came from.
+    ///
+    /// These only apply in certain situations, so they are not folded into
+    /// effective dice or derived stats.
+    pub fn situational_modifiers(&self) -> Vec<ModifierSource> {
+        self.sourced_modifiers()
+            .into_iter()
+            .filter(|(_, _, m)| m.is_conditional())
+            .map(|(source_type, source_name, m)| ModifierSource::new(source_type, source_name, &m))
+            .collect()
     }
 
     /// Sum all die_increment modifiers for a specific target (attribute or skill).
     fn sum_die_increments(&self, target_type: &str, target_identifier: &str) -> i64 {
         self.aggregate_modifiers(|m| {
-            m.value_type == "die_increment"
+            !m.is_conditional()
+                && m.value_type == "die_increment"
+                && m.target_type.as_deref() == Some(target_type)
+                && m.target_identifier.as_deref() == Some(target_identifier)
+        })
+    }
+
+    /// Sum all max_die_increase modifiers for a specific target (attribute or skill).
+    fn sum_max_die_increases(&self, target_type: &str, target_identifier: &str) -> i64 {
+        self.aggregate_modifiers(|m| {
+            !m.is_conditional()
+                && m.value_type == "max_die_increase"
                 && m.target_type.as_deref() == Some(target_type)
                 && m.target_identifier.as_deref() == Some(target_identifier)
         })
     }
 
+    /// The highest die granted to a skill by grant_skill modifiers, if any.
+    ///
+    /// Granted dice cost no skill points and disappear with the edge (or other
+    /// source) that carries the modifier.
+    fn granted_skill_die(&self, skill_name: &str) -> Option<Die> {
+        self.sourced_modifiers()
+            .into_iter()
+            .filter(|(_, _, m)| {
+                !m.is_conditional()
+                    && m.value_type == "grant_skill"
+                    && m.target_type.as_deref() == Some("skill")
+                    && m.target_identifier.as_deref() == Some(skill_name)
+            })
+            .filter_map(|(_, _, m)| m.value.and_then(|v| u8::try_from(v).ok()).and_then(Die::new))
+            .max()
+    }
+
     /// Sum all flat_bonus modifiers for a derived stat (pace, parry, toughness, size).
     fn sum_flat_bonuses(&self, target_identifier: &str) -> i64 {
         self.aggregate_modifiers(|m| {
-            m.value_type == "flat_bonus"
+            !m.is_conditional()
+                && m.value_type == "flat_bonus"
                 && m.target_type.as_deref() == Some("derived_stat")
                 && m.target_identifier.as_deref() == Some(target_identifier)
         })
     }
 
+    /// Name of the skill Parry is derived from.
+    ///
+    /// A parry_skill modifier (e.g. from an edge) names the skill in its target
+    /// and wins over the character's config, which falls back to Fighting.
+    pub fn parry_skill(&self) -> String {
+        self.sourced_modifiers()
+            .into_iter()
+            .filter(|(_, _, m)| {
+                !m.is_conditional()
+                    && m.value_type == "parry_skill"
+                    && m.target_type.as_deref() == Some("skill")
+            })
+            .find_map(|(_, _, m)| m.target_identifier.clone())
+            .unwrap_or_else(|| self.config.parry_skill().to_string())
+    }
+
+    /// Flat bonus modifiers contributing to a derived stat, with their sources.
+    fn derived_stat_breakdown(&self, stat: &str) -> Vec<ModifierSource> {
+        self.modifier_breakdown(stat)
+            .into_iter()
+            .filter(|source| source.value_type == "flat_bonus")
+            .collect()
+    }
+
     /// Apply die increments (positive or negative) to a base die.
+    ///
+    /// Negative increments step the die down, stopping at d4.
     fn apply_die_increments(base: Die, increments: i64) -> Die {
         let mut die = base;
         if increments > 0 {
@@ -194,28 +419,105 @@ impl CharacterView {
         Some(Self::apply_die_increments(attr.attribute.base_die, increments))
     }
 
-    /// Get the effective max die for an attribute (d12 + modifiers).
+    /// Get the effective max die for an attribute (attribute cap + modifiers).
     ///
-    /// This is the ceiling for a character with this character's modifiers.
+    /// The cap is d12 unless the character's config raises it.
     /// Returns None if the attribute is not found.
     pub fn get_attribute_max_die(&self, attribute_id: i64) -> Option<Die> {
         let attr = self.attributes.iter().find(|a| a.attribute.id == attribute_id)?;
         let increments = self.sum_die_increments("attribute", &attr.attribute.name);
-        Some(Self::apply_die_increments(Die::d12(), increments))
+        Some(Self::apply_die_increments(
+            self.attribute_die_cap(attribute_id)?,
+            increments,
+        ))
+    }
+
+    /// Get the highest die an attribute can be raised to, before die increments.
+    ///
+    /// This is the configured cap (d12 unless a house rule raises it) plus any
+    /// max_die_increase modifiers, such as the Professional edge.
+    /// Returns None if the attribute is not found.
+    pub fn attribute_die_cap(&self, attribute_id: i64) -> Option<Die> {
+        let attr = self.attributes.iter().find(|a| a.attribute.id == attribute_id)?;
+        let increases = self.sum_max_die_increases("attribute", &attr.attribute.name);
+        Some(Self::apply_die_increments(
+            self.config.attribute_die_cap(),
+            increases,
+        ))
+    }
+
+    /// Get the highest die a skill can be raised to: its max die plus any
+    /// max_die_increase modifiers.
+    ///
+    /// Returns None if the skill is not found.
+    pub fn skill_die_cap(&self, skill_id: i64) -> Option<Die> {
+        let skill_value = self.skills.iter().find(|s| s.skill.id == skill_id)?;
+        let increases = self.sum_max_die_increases("skill", &skill_value.skill.name);
+        Some(Self::apply_die_increments(skill_value.skill.max_die, increases))
+    }
+
+    /// Lower any attribute or skill die no rules could produce back to a
+    /// legal one.
+    ///
+    /// Dice may sit above their cap (imports, house rules that were later
+    /// removed), but nothing legitimately goes past d12+`MAX_DIE_MODIFIER`
+    /// or the cap, whichever is higher. Stored data only gets there through
+    /// corruption or a manual database edit; each correction is noted in
+    /// `data_warnings`.
+    pub fn clamp_dice_to_caps(&mut self) {
+        let ceiling = Die::with_modifier(12, MAX_DIE_MODIFIER).unwrap_or(Die::d12());
+
+        let attribute_limits: Vec<Die> = self
+            .attributes
+            .iter()
+            .map(|a| {
+                self.attribute_die_cap(a.attribute.id)
+                    .map_or(ceiling, |cap| cap.max(ceiling))
+            })
+            .collect();
+        for (attr_value, limit) in self.attributes.iter_mut().zip(attribute_limits) {
+            if attr_value.die > limit {
+                self.data_warnings.push(format!(
+                    "{} stored as {}, clamped to {}",
+                    attr_value.attribute.name, attr_value.die, limit
+                ));
+                attr_value.die = limit;
+            }
+        }
+
+        let skill_limits: Vec<Die> = self
+            .skills
+            .iter()
+            .map(|s| self.skill_die_cap(s.skill.id).map_or(ceiling, |cap| cap.max(ceiling)))
+            .collect();
+        for (skill_value, limit) in self.skills.iter_mut().zip(skill_limits) {
+            if let Some(die) = skill_value.die
+                && die > limit
+            {
+                self.data_warnings.push(format!(
+                    "{} stored as {}, clamped to {}",
+                    skill_value.skill.name, die, limit
+                ));
+                skill_value.die = Some(limit);
+            }
+        }
     }
 
-    /// Get the effective die for a skill (purchased die + modifiers).
+    /// Get the effective die for a skill (purchased or granted die + modifiers).
     ///
-    /// For untrained skills (die is None), returns None - the UI should display "d4-2".
+    /// A granted die replaces a lower purchased one. For untrained skills with no
+    /// grant, returns None - the UI should display "d4-2".
     /// Returns None if the skill is not found.
     pub fn get_effective_skill_die(&self, skill_id: i64) -> Option<Die> {
         let skill_value = self.skills.iter().find(|s| s.skill.id == skill_id)?;
 
-        // If untrained, return None (UI displays d4-2)
-        let purchased_die = skill_value.die?;
+        // If untrained and not granted, return None (UI displays d4-2)
+        let base_die = skill_value
+            .die
+            .max(self.granted_skill_die(&skill_value.skill.name))?;
 
         let increments = self.sum_die_increments("skill", &skill_value.skill.name);
-        Some(Self::apply_die_increments(purchased_die, increments))
+        Some(Self::apply_die_increments(base_die, increments))
     }
 
     /// Get the effective die for a skill's linked attribute.
@@ -237,29 +539,43 @@ impl CharacterView {
             + self.hindrance_points_to_attributes
             - self.attribute_points_spent;
 
-        // First, collect all attribute increments (to avoid borrow issues)
-        let attr_increments: Vec<(usize, i64)> = self
+        let attribute_cap = self.config.attribute_die_cap();
+
+        // First, collect all attribute increments and cap increases (to avoid borrow issues)
+        let attr_increments: Vec<(usize, i64, i64)> = self
             .attributes
             .iter()
             .enumerate()
             .map(|(i, attr_value)| {
-                let increments = self.sum_die_increments("attribute", &attr_value.attribute.name);
-                (i, increments)
+                let name = &attr_value.attribute.name;
+                let increments = self.sum_die_increments("attribute", name);
+                let cap_increases = self.sum_max_die_increases("attribute", name);
+                (i, increments, cap_increases)
             })
             .collect();
 
         // Apply attribute increments and compute action states
-        for (i, increments) in attr_increments {
+        for (i, increments, cap_increases) in attr_increments {
             let attr_value = &mut self.attributes[i];
             attr_value.effective_die = Self::apply_die_increments(attr_value.die, increments);
             attr_value.base_die =
                 Self::apply_die_increments(attr_value.attribute.base_die, increments);
-            attr_value.max_die = Self::apply_die_increments(Die::d12(), increments);
+            attr_value.max_die =
+                Self::apply_die_increments(attribute_cap, increments + cap_increases);
 
             // Action states for attributes
             attr_value.can_increment =
                 attr_points_remaining > 0 && attr_value.effective_die < attr_value.max_die;
             attr_value.can_decrement = attr_value.die > attr_value.attribute.base_die;
+
+            // Point previews, matching what the increment/decrement commands do
+            attr_value.increment_cost = ATTRIBUTE_STEP_COST;
+            attr_value.points_if_incremented = attr_points_remaining - ATTRIBUTE_STEP_COST;
+            attr_value.points_if_decremented = if attr_value.can_decrement {
+                attr_points_remaining + ATTRIBUTE_STEP_COST
+            } else {
+                attr_points_remaining
+            };
         }
 
         // Build a map of attribute_id -> effective_die for skill cost calculation
@@ -274,36 +590,34 @@ impl CharacterView {
             + self.hindrance_points_to_skills
             - self.skill_points_spent;
 
-        // Collect skill increments and linked attribute info
-        let skill_data: Vec<(usize, Option<i64>, Die)> = self
+        // Collect skill dice (purchased or granted, whichever is higher), increments,
+        // caps and linked attribute info
+        let skill_data: Vec<(usize, Option<Die>, i64, Die, Die)> = self
             .skills
             .iter()
             .enumerate()
             .map(|(i, skill_value)| {
-                let increments = if skill_value.die.is_some() {
-                    Some(self.sum_die_increments("skill", &skill_value.skill.name))
-                } else {
-                    None
-                };
+                let base_die = skill_value
+                    .die
+                    .max(self.granted_skill_die(&skill_value.skill.name));
+                let increments = self.sum_die_increments("skill", &skill_value.skill.name);
+                let max_die = self
+                    .skill_die_cap(skill_value.skill.id)
+                    .unwrap_or(skill_value.skill.max_die);
                 let linked_attr_die = attr_effective_dies
                     .get(&skill_value.skill.linked_attribute_id)
                     .copied()
                     .unwrap_or(Die::d4());
-                (i, increments, linked_attr_die)
+                (i, base_die, increments, max_die, linked_attr_die)
             })
             .collect();
 
         // Apply skill increments and compute action states
-        for (i, maybe_increments, linked_attr_die) in skill_data {
+        for (i, base_die, increments, max_die, linked_attr_die) in skill_data {
             let skill_value = &mut self.skills[i];
 
             // Compute effective die
-            if let (Some(purchased_die), Some(increments)) = (skill_value.die, maybe_increments) {
-                skill_value.effective_die =
-                    Some(Self::apply_die_increments(purchased_die, increments));
-            } else {
-                skill_value.effective_die = None;
-            }
+            skill_value.effective_die = base_die.map(|die| Self::apply_die_increments(die, increments));
 
             // Compute is_above_attribute
             skill_value.is_above_attribute = skill_value
@@ -330,7 +644,7 @@ impl CharacterView {
             // Has enough points and not at max die
             let at_max = skill_value
                 .effective_die
-                .map(|d| d >= skill_value.skill.max_die)
+                .map(|d| d >= max_die)
                 .unwrap_or(false);
             skill_value.can_increment =
                 skill_points_remaining >= skill_value.increment_cost && !at_max;
@@ -345,8 +659,116 @@ impl CharacterView {
         // Compute derived stats (must come after attributes/skills are computed)
         self.derived_stats = self.compute_derived_stats();
 
-        // Compute encumbrance
+        // Compute encumbrance, which slows the character and hampers physical traits
         self.encumbrance = self.compute_encumbrance();
+        if self.encumbrance.is_encumbered {
+            self.derived_stats.pace =
+                (self.derived_stats.pace - self.encumbrance.pace_penalty as i32).max(1);
+        }
+        self.derived_stats.encumbrance_penalty = self.encumbrance.encumbrance_penalty as i32;
+
+        // Show weights in the character's chosen unit; encumbrance was worked out in pounds
+        let unit = self.config.weight_unit;
+        self.encumbrance = self.encumbrance.in_unit(unit);
+        for gear_value in &mut self.gear {
+            gear_value.weight_label = gear_value.display_weight(unit);
+        }
+        self.net_worth = self.wealth + self.total_gear_value();
+
+        // Compute power point pools and keep the combined totals in sync
+        self.power_point_pools = self.compute_power_point_pools();
+        if self.power_point_pools.is_empty() {
+            self.power_points_base = self.power_points;
+            self.power_points_bonus = 0;
+        } else {
+            self.power_points_base = self
+                .arcane_backgrounds
+                .iter()
+                .map(|ab| ab.arcane_background.starting_power_points)
+                .sum();
+            self.power_points_bonus = self.power_point_bonus();
+            self.power_points = self.power_points_base + self.power_points_bonus;
+        }
+
+        self.power_slots = self.power_slots();
+        self.situational_modifiers = self.situational_modifiers();
+        self.incapacitated = self.wounds > self.max_wounds() || self.fatigue > MAX_FATIGUE;
+    }
+
+    /// Wounds this character can take before Incapacitation: the configured
+    /// maximum (3 by default) for Wild Cards, none for Extras.
+    pub fn max_wounds(&self) -> i64 {
+        if self.is_wild_card {
+            self.config.max_wounds()
+        } else {
+            0
+        }
+    }
+
+    /// Starting power slots: the arcane backgrounds' starting powers plus bonus
+    /// slots from edges like New Powers, against the powers already chosen.
+    pub fn power_slots(&self) -> PowerSlots {
+        let base: i64 = self
+            .arcane_backgrounds
+            .iter()
+            .map(|ab| ab.arcane_background.starting_powers)
+            .sum();
+
+        let bonus: i64 = self
+            .edges
+            .iter()
+            .flat_map(|e| &e.edge.modifiers)
+            .filter(|m| m.target_type.as_deref() == Some("power_slots"))
+            .filter_map(|m| m.value)
+            .sum();
+
+        PowerSlots::new(base + bonus, self.powers.len() as i64)
+    }
+
+    /// Sum of power_points modifiers, e.g. +5 for each Power Points edge taken.
+    fn power_point_bonus(&self) -> i64 {
+        self.aggregate_modifiers(|m| {
+            !m.is_conditional()
+                && m.value_type == "flat_bonus"
+                && m.target_type.as_deref() == Some("power_points")
+        })
+    }
+
+    /// Build one power point pool per arcane background.
+    ///
+    /// Bonus power points from edges go to the first arcane background's pool.
+    fn compute_power_point_pools(&self) -> Vec<PowerPointPool> {
+        let bonus = self.power_point_bonus();
+        self.arcane_backgrounds
+            .iter()
+            .enumerate()
+            .map(|(i, ab)| {
+                let max = ab.arcane_background.starting_power_points + if i == 0 { bonus } else { 0 };
+                PowerPointPool::new(
+                    ab.arcane_background.id,
+                    ab.arcane_background.name.clone(),
+                    max,
+                    ab.power_points_used,
+                )
+            })
+            .collect()
+    }
+
+    /// Get the power point pool for a specific arcane background.
+    pub fn get_power_point_pool(&self, arcane_background_id: i64) -> Option<&PowerPointPool> {
+        self.power_point_pools
+            .iter()
+            .find(|p| p.arcane_background_id == arcane_background_id)
+    }
+
+    /// Power points spent across every pool, or the character's own count
+    /// when it has no arcane backgrounds.
+    pub fn total_power_points_used(&self) -> i64 {
+        if self.power_point_pools.is_empty() {
+            self.power_points_used
+        } else {
+            self.power_point_pools.iter().map(|p| p.used).sum()
+        }
     }
 
     /// Compute encumbrance based on carried gear weight and Strength die.
@@ -361,7 +783,398 @@ impl CharacterView {
             .map(|a| a.effective_die.size())
             .unwrap_or(4);
 
-        EncumbranceInfo::from_weight_and_strength(total_weight, strength_die_size)
+        EncumbranceInfo::with_load_multiplier(
+            total_weight,
+            strength_die_size,
+            self.config.load_limit_multiplier(),
+        )
+    }
+
+    /// Total purchase value of all owned gear (cost * quantity), equipped or not.
+    pub fn total_gear_value(&self) -> i64 {
+        self.gear.iter().map(|g| g.total_cost()).sum()
+    }
+
+    /// Ancestry choices that still need selections to reach their minimum.
+    pub fn pending_ancestry_choices(&self) -> Vec<PendingChoice> {
+        let Some(ancestry) = &self.ancestry else {
+            return vec![];
+        };
+
+        ancestry
+            .choices
+            .iter()
+            .filter_map(|choice| {
+                let selected = self
+                    .ancestry_choices
+                    .iter()
+                    .filter(|c| c.choice.id == choice.id && c.selected_option.is_some())
+                    .count() as i64;
+                PendingChoice::from_counts(
+                    choice.id,
+                    choice.description.clone(),
+                    choice.min_selections,
+                    choice.max_selections,
+                    selected,
+                )
+            })
+            .collect()
+    }
+
+    /// Explain why an edge can't be taken again, or None if it can.
+    ///
+    /// A held edge blocks a second copy unless it's repeatable. Copies of a
+    /// repeatable edge are told apart by their notes (Scholar (Occult),
+    /// Scholar (History)), so a copy with the same notes as one already held is
+    /// blocked too.
+    pub fn edge_conflict(&self, edge: &EdgeView, notes: Option<&str>) -> Option<String> {
+        let held: Vec<&CharacterEdgeValue> =
+            self.edges.iter().filter(|e| e.edge.id == edge.id).collect();
+        if held.is_empty() {
+            return None;
+        }
+        if !edge.can_take_multiple_times {
+            return Some(format!(
+                "{} is already taken and can't be taken more than once",
+                edge.name
+            ));
+        }
+
+        let normalize = |n: Option<&str>| {
+            n.map(str::trim)
+                .filter(|n| !n.is_empty())
+                .map(str::to_lowercase)
+        };
+        let notes = normalize(notes);
+        if held.iter().any(|e| normalize(e.notes.as_deref()) == notes) {
+            return Some(match notes {
+                Some(_) => format!(
+                    "{} is already taken with those notes; use different notes to tell the \
+                     copies apart",
+                    edge.name
+                ),
+                None => format!(
+                    "{} is already taken; add notes to tell the copies apart",
+                    edge.name
+                ),
+            });
+        }
+        None
+    }
+
+    /// Explain why a hindrance can't be added, or None if it can.
+    ///
+    /// Blocks taking the same hindrance twice (unless it's repeatable) and holding
+    /// both the minor and major version of a hindrance.
+    pub fn hindrance_conflict(&self, hindrance: &HindranceView) -> Option<String> {
+        let already_taken = self.hindrances.iter().any(|h| h.hindrance.id == hindrance.id);
+        if already_taken && !hindrance.can_take_multiple_times {
+            return Some(format!("{} is already taken", hindrance.name));
+        }
+
+        let has_companion = self.hindrances.iter().any(|h| {
+            Some(h.hindrance.id) == hindrance.companion_hindrance_id
+                || h.hindrance.companion_hindrance_id == Some(hindrance.id)
+        });
+        if has_companion {
+            return Some(format!(
+                "Cannot take both the minor and major versions of {}",
+                hindrance.name
+            ));
+        }
+
+        None
+    }
+
+    /// Explain why taking a hindrance would go past the hindrance point cap, or
+    /// None if it fits.
+    ///
+    /// Always None when the config allows hindrances past the cap; those
+    /// hindrances are taken for free instead.
+    pub fn hindrance_point_cap_exceeded(&self, hindrance: &HindranceView) -> Option<String> {
+        let new_total = self.hindrance_points_earned + hindrance.severity.point_value();
+        if new_total <= MAX_HINDRANCE_POINTS || self.config.allow_hindrances_past_cap {
+            return None;
+        }
+
+        Some(format!(
+            "Hindrance points exceed maximum ({}/{})",
+            new_total, MAX_HINDRANCE_POINTS
+        ))
+    }
+
+    /// Hindrance points earned from chosen and ancestry hindrances.
+    ///
+    /// When the config allows hindrances past the cap, the total stops at the cap.
+    pub fn earned_hindrance_points(&self) -> i64 {
+        let total: i64 = self
+            .hindrances
+            .iter()
+            .filter(|h| h.source == SOURCE_CHOSEN || h.source == SOURCE_ANCESTRY)
+            .map(|h| h.hindrance.severity.point_value())
+            .sum();
+
+        if self.config.allow_hindrances_past_cap {
+            total.min(MAX_HINDRANCE_POINTS)
+        } else {
+            total
+        }
+    }
+
+    /// Check whether a power is on the power list of the character's arcane backgrounds.
+    ///
+    /// Arcane backgrounds without a power list allow any power. When every arcane
+    /// background has a power list, the power must appear in at least one of them.
+    pub fn is_power_on_list(&self, power_id: i64) -> bool {
+        self.arcane_backgrounds.iter().any(|ab| {
+            let ab = &ab.arcane_background;
+            !ab.has_power_list
+                || ab
+                    .choices
+                    .iter()
+                    .filter(|c| c.choice_type == ArcaneBackgroundChoiceType::AvailablePower)
+                    .flat_map(|c| &c.options)
+                    .any(|opt| {
+                        opt.option_type == ArcaneBackgroundOptionType::Power
+                            && opt.option_id == Some(power_id)
+                    })
+        })
+    }
+
+    /// Check whether a power should be offered to the character.
+    ///
+    /// The power must be on the character's power list and its rank requirements
+    /// must be met. Other requirements are left to `requirement_statuses` so
+    /// partially-available powers can still be shown.
+    pub fn can_list_power(&self, power: &PowerView) -> bool {
+        self.is_power_on_list(power.id)
+            && power
+                .requirements
+                .evaluate_rank(&self.to_requirement_context())
+    }
+
+    /// Preview what decrementing an attribute by one step would change.
+    ///
+    /// Returns an empty impact if the attribute is already at its base die,
+    /// or None if the attribute is not found.
+    pub fn attribute_decrement_impact(&self, attribute_id: i64) -> Option<DecrementImpact> {
+        let attr = self.attributes.iter().find(|a| a.attribute.id == attribute_id)?;
+        if attr.die == attr.attribute.base_die {
+            return Some(DecrementImpact::default());
+        }
+
+        Some(self.decrement_impact(|character| {
+            if let Some(attr) = character
+                .attributes
+                .iter_mut()
+                .find(|a| a.attribute.id == attribute_id)
+            {
+                attr.die = attr.die.decrement().unwrap_or(attr.die);
+            }
+        }))
+    }
+
+    /// Preview what decrementing a skill by one step would change.
+    ///
+    /// Returns an empty impact if the skill is untrained, or None if the
+    /// skill is not found.
+    pub fn skill_decrement_impact(&self, skill_id: i64) -> Option<DecrementImpact> {
+        let skill = self.skills.iter().find(|s| s.skill.id == skill_id)?;
+        if skill.die.is_none() {
+            return Some(DecrementImpact::default());
+        }
+
+        Some(self.decrement_impact(|character| {
+            if let Some(skill) = character.skills.iter_mut().find(|s| s.skill.id == skill_id) {
+                skill.die = skill.die.and_then(|d| d.decrement());
+            }
+        }))
+    }
+
+    /// Apply a hypothetical change to a copy of this character and diff the results.
+    fn decrement_impact(&self, apply: impl FnOnce(&mut CharacterView)) -> DecrementImpact {
+        let mut before = self.clone();
+        before.compute_effective_values();
+        let mut after = before.clone();
+        apply(&mut after);
+        after.compute_effective_values();
+
+        let before_ctx = before.to_requirement_context();
+        let after_ctx = after.to_requirement_context();
+        let broken = |requirements: &RequirementTree| {
+            requirements.evaluate(&before_ctx) && !requirements.evaluate(&after_ctx)
+        };
+
+        let broken_requirements = self
+            .edges
+            .iter()
+            .filter(|e| broken(&e.edge.requirements))
+            .map(|e| e.edge.name.clone())
+            .chain(
+                self.powers
+                    .iter()
+                    .filter(|p| broken(&p.power.requirements))
+                    .map(|p| p.power.name.clone()),
+            )
+            .collect();
+
+        DecrementImpact {
+            derived_stat_changes: DerivedStatChange::diff(
+                &before.derived_stats,
+                &after.derived_stats,
+            ),
+            broken_requirements,
+        }
+    }
+
+    /// Describe held edges whose requirements this character no longer meets.
+    ///
+    /// Edges granted by the ancestry are skipped since they are taken without
+    /// requirements. Each entry names the edge and its unmet requirements.
+    pub fn unmet_edge_requirements(&self) -> Vec<String> {
+        let ctx = self.to_requirement_context();
+
+        self.edges
+            .iter()
+            .filter(|e| e.source != SOURCE_ANCESTRY && !e.edge.requirements.evaluate(&ctx))
+            .map(|e| {
+                let unmet: Vec<String> = e
+                    .edge
+                    .requirements
+                    .evaluate_detailed(&ctx)
+                    .into_iter()
+                    .filter(|status| !status.is_met)
+                    .map(|status| status.description)
+                    .collect();
+
+                if unmet.is_empty() {
+                    format!("{} no longer meets its requirements", e.edge.name)
+                } else {
+                    format!("{} no longer meets its requirements: {}", e.edge.name, unmet.join(", "))
+                }
+            })
+            .collect()
+    }
+
+    /// Compact plain-text stat block for pasting into VTT chat.
+    ///
+    /// Lists effective attribute dice, trained skills, derived stats (with
+    /// armor shown in parentheses after Toughness), edges, and hindrances,
+    /// followed by an "Incapacitated" line when the character is down.
+    pub fn to_stat_block(&self) -> String {
+        let join_or_none = |items: Vec<String>| {
+            if items.is_empty() {
+                "None".to_string()
+            } else {
+                items.join(", ")
+            }
+        };
+
+        let attributes: Vec<String> = self
+            .attributes
+            .iter()
+            .map(|a| format!("{} {}", a.attribute.name, a.effective_die))
+            .collect();
+
+        let mut skills: Vec<(&str, Die)> = self
+            .skills
+            .iter()
+            .filter_map(|s| s.effective_die.map(|die| (s.skill.name.as_str(), die)))
+            .collect();
+        skills.sort_by(|a, b| a.0.cmp(b.0));
+        let skills = skills
+            .into_iter()
+            .map(|(name, die)| format!("{} {}", name, die))
+            .collect();
+
+        let armor: i64 = self
+            .modifier_breakdown("toughness")
+            .iter()
+            .filter(|source| source.source_type == "gear")
+            .map(|source| source.value)
+            .sum();
+        let toughness = if armor > 0 {
+            format!("{}({})", self.derived_stats.toughness, armor)
+        } else {
+            self.derived_stats.toughness.to_string()
+        };
+
+        let edges = self.edges.iter().map(|e| e.edge.name.clone()).collect();
+        let hindrances = self
+            .hindrances
+            .iter()
+            .map(|h| match h.hindrance.severity {
+                Severity::Major => format!("{} (Major)", h.hindrance.name),
+                Severity::Minor => h.hindrance.name.clone(),
+            })
+            .collect();
+
+        let mut stat_block = format!(
+            "{} ({}) \u{2014} {}\nSkills: {}\nPace {}, Parry {}, Toughness {}\nEdges: {}\nHindrances: {}",
+            self.name,
+            self.rank.name,
+            attributes.join(", "),
+            join_or_none(skills),
+            self.derived_stats.pace,
+            self.derived_stats.parry,
+            toughness,
+            join_or_none(edges),
+            join_or_none(hindrances),
+        );
+        if self.incapacitated {
+            stat_block.push_str("\nIncapacitated");
+        }
+        stat_block
+    }
+
+    /// Canonical snapshot of effective dice, derived stats, point pools, and
+    /// edge/hindrance ids, sorted so snapshots compare equal with `==`.
+    pub fn effective_snapshot(&self) -> EffectiveSheet {
+        let mut attributes: Vec<(i64, Die)> = self
+            .attributes
+            .iter()
+            .map(|a| (a.attribute.id, a.effective_die))
+            .collect();
+        attributes.sort_by_key(|(id, _)| *id);
+
+        let mut skills: Vec<(i64, Option<Die>)> = self
+            .skills
+            .iter()
+            .map(|s| (s.skill.id, s.effective_die))
+            .collect();
+        skills.sort_by_key(|(id, _)| *id);
+
+        let mut edge_ids: Vec<i64> = self.edges.iter().map(|e| e.edge.id).collect();
+        edge_ids.sort_unstable();
+        let mut hindrance_ids: Vec<i64> = self.hindrances.iter().map(|h| h.hindrance.id).collect();
+        hindrance_ids.sort_unstable();
+
+        EffectiveSheet {
+            attributes,
+            skills,
+            pace: self.derived_stats.pace,
+            parry: self.derived_stats.parry,
+            toughness: self.derived_stats.toughness,
+            size: self.derived_stats.size,
+            wound_penalty: self.derived_stats.wound_penalty,
+            fatigue_penalty: self.derived_stats.fatigue_penalty,
+            encumbrance_penalty: self.derived_stats.encumbrance_penalty,
+            attribute_points_spent: self.attribute_points_spent,
+            attribute_points_earned: self.attribute_points_earned,
+            skill_points_spent: self.skill_points_spent,
+            skill_points_earned: self.skill_points_earned,
+            hindrance_points_spent: self.hindrance_points_spent,
+            hindrance_points_earned: self.hindrance_points_earned,
+            hindrance_points_to_edges: self.hindrance_points_to_edges,
+            hindrance_points_to_attributes: self.hindrance_points_to_attributes,
+            hindrance_points_to_skills: self.hindrance_points_to_skills,
+            hindrance_points_to_wealth: self.hindrance_points_to_wealth,
+            power_points: self.power_points,
+            power_points_used: self.total_power_points_used(),
+            wealth: self.wealth,
+            edge_ids,
+            hindrance_ids,
+        }
     }
 
     /// Create a RequirementContext for evaluating edge/power requirements.
@@ -407,8 +1220,9 @@ impl CharacterView {
     ///
     /// SWADE formulas:
     /// - Pace: 6 + flat_bonus modifiers
-    /// - Parry: 2 + (Fighting die / 2) + flat_bonus modifiers
-    /// - Toughness: 2 + (Vigor die / 2) + Size + flat_bonus modifiers
+    /// - Parry: 2 + (parry skill die / 2) + flat_bonus modifiers; the parry
+    ///   skill is Fighting unless configured or swapped by a modifier
+    /// - Toughness: 2 + (Vigor die / 2) + Size + flat_bonus modifiers, at least 1
     /// - Size: sum of flat_bonus modifiers (normal is 0)
     fn compute_derived_stats(&self) -> DerivedStatsView {
         // Size (affects toughness, must be computed first)
@@ -417,15 +1231,16 @@ impl CharacterView {
         // Pace: base + modifiers
         let pace = BASE_PACE + self.sum_flat_bonuses("pace") as i32;
 
-        // Parry: base + (Fighting die / 2) + modifiers
-        let fighting_bonus = self
+        // Parry: base + (parry skill die / 2) + modifiers
+        let parry_skill = self.parry_skill();
+        let parry_skill_bonus = self
             .skills
             .iter()
-            .find(|s| s.skill.name == "Fighting")
+            .find(|s| s.skill.name == parry_skill)
             .and_then(|s| s.effective_die)
             .map(|d| d.size() as i32 / 2)
             .unwrap_or(0);
-        let parry = BASE_PARRY + fighting_bonus + self.sum_flat_bonuses("parry") as i32;
+        let parry = BASE_PARRY + parry_skill_bonus + self.sum_flat_bonuses("parry") as i32;
 
         // Toughness: base + (Vigor die / 2) + size + modifiers
         let vigor_bonus = self
@@ -434,13 +1249,29 @@ impl CharacterView {
             .find(|a| a.attribute.name == "Vigor")
             .map(|a| a.effective_die.size() as i32 / 2)
             .unwrap_or(0);
-        let toughness = BASE_TOUGHNESS + vigor_bonus + size + self.sum_flat_bonuses("toughness") as i32;
+        // Negative Size (small characters) lowers Toughness, but never below the minimum
+        let toughness = (BASE_TOUGHNESS + vigor_bonus + size + self.sum_flat_bonuses("toughness") as i32)
+            .max(MIN_TOUGHNESS);
+
+        let modifier_breakdown = ["pace", "parry", "toughness", "size"]
+            .into_iter()
+            .map(|stat| (stat.to_string(), self.derived_stat_breakdown(stat)))
+            .collect();
+
+        // Wound penalty: -1 per wound, capped
+        let wound_penalty = self.wounds.clamp(0, MAX_WOUND_PENALTY) as i32;
+        // Fatigue penalty: -1 when Fatigued, -2 when Exhausted
+        let fatigue_penalty = self.fatigue.clamp(0, MAX_FATIGUE) as i32;
 
         DerivedStatsView {
             pace,
             parry,
             toughness,
             size,
+            wound_penalty,
+            fatigue_penalty,
+            encumbrance_penalty: 0, // Set once encumbrance is computed
+            modifier_breakdown,
         }
     }
 }
@@ -449,7 +1280,7 @@ impl CharacterView {
 mod tests {
     use super::*;
     use crate::models::Modifier;
-    use crate::views::{AttributeView, SkillView};
+    use crate::views::{AttributeView, SkillView, WeightUnit};
 
     fn create_test_rank() -> Rank {
         Rank {
@@ -486,6 +1317,9 @@ mod tests {
                     max_die: Die::d12(),
                     can_increment: false,
                     can_decrement: false,
+                    increment_cost: 1,
+                    points_if_incremented: 0,
+                    points_if_decremented: 0,
                 },
                 CharacterAttributeValue {
                     attribute: AttributeView {
@@ -500,6 +1334,9 @@ mod tests {
                     max_die: Die::d12(),
                     can_increment: false,
                     can_decrement: false,
+                    increment_cost: 1,
+                    points_if_incremented: 0,
+                    points_if_decremented: 0,
                 },
             ],
             skills: vec![
@@ -509,6 +1346,7 @@ mod tests {
                         name: "Fighting".to_string(),
                         description: "".to_string(),
                         linked_attribute_id: 1,
+                        linked_attribute_name: "Agility".to_string(),
                         is_core_skill: true,
                         default_die: Some(Die::d4()),
                         max_die: Die::d12(),
@@ -527,6 +1365,7 @@ mod tests {
                         name: "Notice".to_string(),
                         description: "".to_string(),
                         linked_attribute_id: 2,
+                        linked_attribute_name: "Smarts".to_string(),
                         is_core_skill: true,
                         default_die: Some(Die::d4()),
                         max_die: Die::d12(),
@@ -547,13 +1386,22 @@ mod tests {
             powers: vec![],
             power_points: 0,
             power_points_used: 0,
+            power_points_base: 0,
+            power_points_bonus: 0,
+            power_point_pools: vec![],
+            power_slots: PowerSlots::default(),
             wounds: 0,
             fatigue: 0,
+            incapacitated: false,
+            experience_points: 0,
+            revision: 0,
             notes: vec![],
+            companions: vec![],
             gear: vec![],
             encumbrance: EncumbranceInfo::empty(4),
             modifiers: vec![],
             derived_stats: DerivedStatsView::default(),
+            situational_modifiers: vec![],
             attribute_points_spent: 1,
             attribute_points_earned: 5,
             skill_points_spent: 0,
@@ -565,9 +1413,13 @@ mod tests {
             hindrance_points_to_skills: 0,
             hindrance_points_to_wealth: 0,
             wealth: 500,
+            net_worth: 500,
             background: None,
             description: None,
+            campaign: None,
             portrait_data_url: None,
+            config: CharacterConfig::default(),
+            data_warnings: vec![],
         }
     }
 
@@ -579,6 +1431,7 @@ mod tests {
             value_type: "die_increment".to_string(),
             value: Some(value),
             description: format!("+{} die to {}", value, target_name),
+            condition: None,
             created_at: "2024-01-01".to_string(),
             updated_at: "2024-01-01".to_string(),
         }
@@ -623,6 +1476,48 @@ mod tests {
         assert_eq!(max, Die::with_modifier(12, 1).unwrap());
     }
 
+    #[test]
+    fn test_get_attribute_max_die_with_raised_cap() {
+        let mut character = create_test_character();
+        character.config.max_attribute_die = Die::with_modifier(12, 2);
+
+        let max = character.get_attribute_max_die(5).unwrap();
+        assert_eq!(max, Die::with_modifier(12, 2).unwrap());
+    }
+
+    #[test]
+    fn test_compute_effective_values_previews_attribute_point_costs() {
+        let mut character = create_test_character();
+
+        character.compute_effective_values();
+
+        // Agility is at d6 with 4 of 5 attribute points left
+        let agility = &character.attributes[0];
+        assert_eq!(agility.die, Die::d6());
+        assert_eq!(agility.increment_cost, 1);
+        assert_eq!(agility.points_if_incremented, 3);
+        assert_eq!(agility.points_if_decremented, 5);
+
+        // Vigor is at base, so lowering it refunds nothing
+        let vigor = &character.attributes[1];
+        assert_eq!(vigor.points_if_incremented, 3);
+        assert_eq!(vigor.points_if_decremented, 4);
+    }
+
+    #[test]
+    fn test_compute_effective_values_raised_cap_allows_increment_past_d12() {
+        let mut character = create_test_character();
+        character.attributes[0].die = Die::d12();
+
+        character.compute_effective_values();
+        assert!(!character.attributes[0].can_increment);
+
+        character.config.max_attribute_die = Die::with_modifier(12, 2);
+        character.compute_effective_values();
+        assert!(character.attributes[0].can_increment);
+        assert_eq!(character.attributes[0].max_die, Die::with_modifier(12, 2).unwrap());
+    }
+
     #[test]
     fn test_get_effective_skill_die_trained() {
         let character = create_test_character();
@@ -701,14 +1596,119 @@ mod tests {
         assert!(result.is_none());
     }
 
-    fn create_flat_bonus_modifier(target_identifier: &str, value: i64) -> Modifier {
-        Modifier {
-            id: 1,
-            target_type: Some("derived_stat".to_string()),
-            target_identifier: Some(target_identifier.to_string()),
+    fn create_skill_grant_edge(skill_name: &str, die_size: i64) -> CharacterEdgeValue {
+        use crate::views::{EdgeCategory, EdgeView, RequirementTree};
+        let edge = EdgeView {
+            id: 10,
+            name: "Trained".to_string(),
+            category: EdgeCategory::Background,
+            source: "core".to_string(),
+            description: "".to_string(),
+            can_take_multiple_times: false,
+            modifiers: vec![Modifier {
+                id: 10,
+                target_type: Some("skill".to_string()),
+                target_identifier: Some(skill_name.to_string()),
+                value_type: "grant_skill".to_string(),
+                value: Some(die_size),
+                description: format!("{} d{}", skill_name, die_size),
+                condition: None,
+                created_at: "2024-01-01".to_string(),
+                updated_at: "2024-01-01".to_string(),
+            }],
+            requirements: RequirementTree::none(),
+        };
+        CharacterEdgeValue::new(edge, 0, None, "chosen".to_string())
+    }
+
+    #[test]
+    fn test_grant_skill_edge_trains_untrained_skill_for_free() {
+        let mut character = create_test_character();
+        character.edges.push(create_skill_grant_edge("Notice", 6));
+
+        character.compute_effective_values();
+
+        assert_eq!(character.skills[1].effective_die, Some(Die::d6()));
+        assert_eq!(character.skills[1].die, None);
+        assert_eq!(character.get_effective_skill_die(2), Some(Die::d6()));
+        assert_eq!(character.skill_points_spent, 0);
+
+        // Removing the edge removes the grant
+        character.edges.clear();
+        character.compute_effective_values();
+        assert_eq!(character.skills[1].effective_die, None);
+    }
+
+    #[test]
+    fn test_grant_skill_raises_lower_trained_skill() {
+        let mut character = create_test_character();
+        character.skills[0].die = Some(Die::d4());
+        character.edges.push(create_skill_grant_edge("Fighting", 6));
+
+        character.compute_effective_values();
+
+        assert_eq!(character.skills[0].effective_die, Some(Die::d6()));
+        assert_eq!(character.skills[0].die, Some(Die::d4()));
+    }
+
+    #[test]
+    fn test_grant_skill_does_not_lower_higher_trained_skill() {
+        let mut character = create_test_character();
+        character.skills[0].die = Some(Die::d8());
+        character.edges.push(create_skill_grant_edge("Fighting", 6));
+        character.modifiers.push(create_die_increment_modifier("skill", "Fighting", 1));
+
+        character.compute_effective_values();
+
+        assert_eq!(character.skills[0].effective_die, Some(Die::d10()));
+    }
+
+    fn create_max_die_increase_modifier(target_type: &str, target_name: &str) -> Modifier {
+        Modifier {
+            value_type: "max_die_increase".to_string(),
+            description: format!("{} max +1 die", target_name),
+            ..create_die_increment_modifier(target_type, target_name, 1)
+        }
+    }
+
+    #[test]
+    fn test_max_die_increase_raises_attribute_cap() {
+        let mut character = create_test_character();
+        character.attributes[0].die = Die::d12();
+        character.modifiers.push(create_max_die_increase_modifier("attribute", "Agility"));
+
+        character.compute_effective_values();
+
+        assert_eq!(character.attribute_die_cap(1), Die::with_modifier(12, 1));
+        assert_eq!(character.attributes[0].max_die, Die::with_modifier(12, 1).unwrap());
+        assert_eq!(character.attributes[0].effective_die, Die::d12());
+        assert!(character.attributes[0].can_increment);
+    }
+
+    #[test]
+    fn test_max_die_increase_raises_skill_cap() {
+        let mut character = create_test_character();
+        character.skill_points_spent = 0;
+        character.skills[0].die = Some(Die::d12());
+
+        character.compute_effective_values();
+        assert!(!character.skills[0].can_increment);
+
+        character.modifiers.push(create_max_die_increase_modifier("skill", "Fighting"));
+        character.compute_effective_values();
+        assert_eq!(character.skill_die_cap(1), Die::with_modifier(12, 1));
+        assert!(character.skills[0].can_increment);
+    }
+
+    fn create_flat_bonus_modifier(target_identifier: &str, value: i64) -> Modifier {
+        Modifier {
+            id: 1,
+            target_type: Some("derived_stat".to_string()),
+            target_identifier: Some(target_identifier.to_string()),
             value_type: "flat_bonus".to_string(),
             value: Some(value),
             description: format!("+{} to {}", value, target_identifier),
+            condition: None,
             created_at: "2024-01-01".to_string(),
             updated_at: "2024-01-01".to_string(),
         }
@@ -795,4 +1795,1133 @@ mod tests {
         // Parry: 2 + (10/2) = 7
         assert_eq!(character.derived_stats.parry, 7);
     }
+
+    fn create_test_arcane_background(
+        id: i64,
+        name: &str,
+        starting_power_points: i64,
+        power_points_used: i64,
+    ) -> CharacterArcaneBackgroundValue {
+        let arcane_background = crate::views::ArcaneBackgroundView {
+            id,
+            name: name.to_string(),
+            arcane_skill_id: 10 + id,
+            arcane_skill_name: None,
+            starting_powers: 3,
+            starting_power_points,
+            has_power_list: false,
+            source: "core".to_string(),
+            description: "".to_string(),
+            edge_id: None,
+            requirements: crate::views::RequirementTree::none(),
+            choices: vec![],
+        };
+        CharacterArcaneBackgroundValue::new(arcane_background, None, power_points_used)
+    }
+
+    #[test]
+    fn test_power_point_pools_are_separate_per_arcane_background() {
+        let mut character = create_test_character();
+        character.arcane_backgrounds = vec![
+            create_test_arcane_background(1, "Magic", 10, 3),
+            create_test_arcane_background(2, "Miracles", 15, 0),
+        ];
+        character.power_points = 25;
+        character.compute_effective_values();
+
+        assert_eq!(character.power_point_pools.len(), 2);
+
+        let magic = character.get_power_point_pool(1).unwrap();
+        assert_eq!(magic.max, 10);
+        assert_eq!(magic.used, 3);
+        assert_eq!(magic.remaining(), 7);
+
+        let miracles = character.get_power_point_pool(2).unwrap();
+        assert_eq!(miracles.max, 15);
+        assert_eq!(miracles.used, 0);
+        assert_eq!(miracles.remaining(), 15);
+
+        // Combined totals are kept for display
+        assert_eq!(character.power_points, 25);
+        assert_eq!(character.total_power_points_used(), 3);
+    }
+
+    #[test]
+    fn test_power_point_pools_leave_stored_power_points_used_alone() {
+        let mut character = create_test_character();
+        character.arcane_backgrounds = vec![create_test_arcane_background(1, "Magic", 10, 3)];
+        character.power_points_used = 7;
+        character.compute_effective_values();
+
+        assert_eq!(character.power_points_used, 7);
+        assert_eq!(character.total_power_points_used(), 3);
+    }
+
+    #[test]
+    fn test_power_point_pools_empty_without_arcane_background() {
+        let mut character = create_test_character();
+        character.power_points_used = 2;
+        character.compute_effective_values();
+
+        assert!(character.power_point_pools.is_empty());
+        assert!(character.get_power_point_pool(1).is_none());
+        assert_eq!(character.power_points_used, 2);
+        assert_eq!(character.total_power_points_used(), 2);
+    }
+
+    fn create_test_power(id: i64, required_rank_id: Option<i64>) -> PowerView {
+        let requirements = match required_rank_id {
+            Some(rank_id) => crate::views::RequirementTree::leaf(crate::models::Requirement {
+                id: 1,
+                requirement_type: "rank".to_string(),
+                target_id: Some(rank_id),
+                target_name: None,
+                value: Some(rank_id),
+                description: "Rank".to_string(),
+                created_at: "2024-01-01".to_string(),
+                updated_at: "2024-01-01".to_string(),
+            }),
+            None => crate::views::RequirementTree::none(),
+        };
+        PowerView {
+            id,
+            name: format!("Power {}", id),
+            power_points: 1,
+            maintenance_power_points: None,
+            range: "Smarts".to_string(),
+            duration: "Instant".to_string(),
+            source: "core".to_string(),
+            description: "".to_string(),
+            modifiers: vec![],
+            requirements,
+        }
+    }
+
+    fn create_power_list_choice(power_ids: &[i64]) -> crate::views::ArcaneBackgroundChoiceView {
+        crate::views::ArcaneBackgroundChoiceView {
+            id: 1,
+            choice_type: ArcaneBackgroundChoiceType::AvailablePower,
+            choice_category: None,
+            min_selections: 0,
+            max_selections: power_ids.len() as i64,
+            description: "Power list".to_string(),
+            position: 0,
+            options: power_ids
+                .iter()
+                .enumerate()
+                .map(|(i, &power_id)| crate::views::ArcaneBackgroundChoiceOptionView {
+                    id: i as i64 + 1,
+                    option_type: ArcaneBackgroundOptionType::Power,
+                    option_id: Some(power_id),
+                    description: None,
+                    position: i as i64,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_can_list_power_filters_by_rank() {
+        let mut character = create_test_character();
+        character.arcane_backgrounds = vec![create_test_arcane_background(1, "Magic", 10, 0)];
+
+        // Character is Novice (rank 1)
+        assert!(character.can_list_power(&create_test_power(1, None)));
+        assert!(character.can_list_power(&create_test_power(2, Some(1))));
+        assert!(!character.can_list_power(&create_test_power(3, Some(2))));
+    }
+
+    #[test]
+    fn test_can_list_power_filters_by_power_list() {
+        let mut character = create_test_character();
+        let mut ab = create_test_arcane_background(1, "Gifted", 15, 0);
+        ab.arcane_background.has_power_list = true;
+        ab.arcane_background.choices = vec![create_power_list_choice(&[1, 3])];
+        character.arcane_backgrounds = vec![ab];
+
+        assert!(character.can_list_power(&create_test_power(1, None)));
+        assert!(!character.can_list_power(&create_test_power(2, None)));
+        // On the list but above the character's rank
+        assert!(!character.can_list_power(&create_test_power(3, Some(2))));
+    }
+
+    #[test]
+    fn test_is_power_on_list_unrestricted_background_allows_any_power() {
+        let mut character = create_test_character();
+        let mut gifted = create_test_arcane_background(1, "Gifted", 15, 0);
+        gifted.arcane_background.has_power_list = true;
+        gifted.arcane_background.choices = vec![create_power_list_choice(&[1])];
+        character.arcane_backgrounds = vec![gifted];
+
+        assert!(!character.is_power_on_list(2));
+
+        character
+            .arcane_backgrounds
+            .push(create_test_arcane_background(2, "Magic", 10, 0));
+
+        assert!(character.is_power_on_list(2));
+    }
+
+    #[test]
+    fn test_is_power_on_list_without_arcane_background() {
+        let character = create_test_character();
+
+        assert!(!character.is_power_on_list(1));
+    }
+
+    #[test]
+    fn test_power_slots_include_new_powers_edge() {
+        use crate::views::{EdgeCategory, EdgeView, RequirementTree};
+        let mut character = create_test_character();
+        character.arcane_backgrounds = vec![create_test_arcane_background(1, "Magic", 10, 0)];
+        character.powers = vec![CharacterPowerValue::new(create_test_power(1, None), None)];
+        character.compute_effective_values();
+
+        assert_eq!(character.power_slots, PowerSlots::new(3, 1));
+
+        let new_powers = EdgeView {
+            id: 2,
+            name: "New Powers".to_string(),
+            category: EdgeCategory::Power,
+            source: "core".to_string(),
+            description: "".to_string(),
+            can_take_multiple_times: true,
+            modifiers: vec![Modifier {
+                id: 1,
+                target_type: Some("power_slots".to_string()),
+                target_identifier: None,
+                value_type: "flat_bonus".to_string(),
+                value: Some(2),
+                description: "+2 powers".to_string(),
+                condition: None,
+                created_at: "2024-01-01".to_string(),
+                updated_at: "2024-01-01".to_string(),
+            }],
+            requirements: RequirementTree::none(),
+        };
+        character
+            .edges
+            .push(CharacterEdgeValue::new(new_powers, 0, None, "advancement".to_string()));
+        character.compute_effective_values();
+
+        assert_eq!(character.power_slots.total, 5);
+        assert_eq!(character.power_slots.used, 1);
+        assert_eq!(character.power_slots.remaining, 4);
+    }
+
+    fn create_power_points_edge() -> CharacterEdgeValue {
+        use crate::views::{EdgeCategory, EdgeView, RequirementTree};
+        let edge = EdgeView {
+            id: 86,
+            name: "Power Points".to_string(),
+            category: EdgeCategory::Power,
+            source: "core".to_string(),
+            description: "".to_string(),
+            can_take_multiple_times: true,
+            modifiers: vec![Modifier {
+                id: 525,
+                target_type: Some("power_points".to_string()),
+                target_identifier: None,
+                value_type: "flat_bonus".to_string(),
+                value: Some(5),
+                description: "+5 power points".to_string(),
+                condition: None,
+                created_at: "2024-01-01".to_string(),
+                updated_at: "2024-01-01".to_string(),
+            }],
+            requirements: RequirementTree::none(),
+        };
+        CharacterEdgeValue::new(edge, 0, None, "advancement".to_string())
+    }
+
+    #[test]
+    fn test_power_points_edge_adds_five_per_copy() {
+        let mut character = create_test_character();
+        character.arcane_backgrounds = vec![create_test_arcane_background(1, "Magic", 10, 0)];
+        character.compute_effective_values();
+        assert_eq!(character.power_points, 10);
+
+        character.edges.push(create_power_points_edge());
+        character.compute_effective_values();
+        assert_eq!(character.power_points, 15);
+        assert_eq!(character.get_power_point_pool(1).unwrap().max, 15);
+
+        // The edge is repeatable and each copy stacks
+        character.edges.push(create_power_points_edge());
+        character.compute_effective_values();
+        assert_eq!(character.power_points, 20);
+        assert_eq!(character.get_power_point_pool(1).unwrap().remaining(), 20);
+    }
+
+    #[test]
+    fn test_power_points_split_into_base_and_bonus() {
+        let mut character = create_test_character();
+        character.arcane_backgrounds = vec![create_test_arcane_background(1, "Magic", 10, 0)];
+        character.edges.push(create_power_points_edge());
+        character.compute_effective_values();
+
+        assert_eq!(character.power_points_base, 10);
+        assert_eq!(character.power_points_bonus, 5);
+        assert_eq!(character.power_points, 15);
+    }
+
+    fn create_skill_requirement_edge(name: &str, skill_id: i64, die_size: i64) -> CharacterEdgeValue {
+        use crate::views::{EdgeCategory, EdgeView, RequirementTree};
+        let edge = EdgeView {
+            id: 1,
+            name: name.to_string(),
+            category: EdgeCategory::Combat,
+            source: "core".to_string(),
+            description: "".to_string(),
+            can_take_multiple_times: false,
+            modifiers: vec![],
+            requirements: RequirementTree::leaf(crate::models::Requirement {
+                id: 1,
+                requirement_type: "skill".to_string(),
+                target_id: Some(skill_id),
+                target_name: None,
+                value: Some(die_size),
+                description: format!("Skill d{}+", die_size),
+                created_at: "2024-01-01".to_string(),
+                updated_at: "2024-01-01".to_string(),
+            }),
+        };
+        CharacterEdgeValue::new(edge, 0, None, "advancement".to_string())
+    }
+
+    #[test]
+    fn test_attribute_decrement_impact_vigor_lowers_toughness() {
+        let mut character = create_test_character();
+        character.attributes[1].die = Die::d6();
+        character.compute_effective_values();
+
+        let impact = character.attribute_decrement_impact(5).unwrap();
+
+        // Toughness: 2 + (6/2) = 5 -> 2 + (4/2) = 4
+        assert_eq!(
+            impact.derived_stat_changes,
+            vec![DerivedStatChange::new("toughness", 5, 4)]
+        );
+        assert!(impact.broken_requirements.is_empty());
+    }
+
+    #[test]
+    fn test_attribute_decrement_impact_at_base_is_empty() {
+        let character = create_test_character();
+
+        // Vigor is already at its base d4
+        let impact = character.attribute_decrement_impact(5).unwrap();
+        assert!(impact.is_empty());
+        assert!(character.attribute_decrement_impact(999).is_none());
+    }
+
+    #[test]
+    fn test_skill_decrement_impact_breaks_edge_requirement() {
+        let mut character = create_test_character();
+        character.edges.push(create_skill_requirement_edge("Block", 1, 6));
+        character.compute_effective_values();
+
+        let impact = character.skill_decrement_impact(1).unwrap();
+
+        // Fighting d6 -> d4: Parry 5 -> 4 and Block (Fighting d6+) is lost
+        assert_eq!(
+            impact.derived_stat_changes,
+            vec![DerivedStatChange::new("parry", 5, 4)]
+        );
+        assert_eq!(impact.broken_requirements, vec!["Block".to_string()]);
+    }
+
+    #[test]
+    fn test_skill_decrement_impact_keeps_edge_still_met() {
+        let mut character = create_test_character();
+        character.edges.push(create_skill_requirement_edge("Trademark", 1, 4));
+
+        let impact = character.skill_decrement_impact(1).unwrap();
+
+        assert!(impact.broken_requirements.is_empty());
+    }
+
+    fn create_armor_gear(name: &str, toughness: i64, is_equipped: bool) -> CharacterGearValue {
+        use crate::views::{GearCategoryView, GearView, RequirementTree};
+        let gear = GearView {
+            id: 1,
+            name: name.to_string(),
+            category: GearCategoryView {
+                id: 1,
+                name: "Armor".to_string(),
+                description: None,
+            },
+            era: "medieval".to_string(),
+            cost: 20,
+            weight: 5.0,
+            source: "core".to_string(),
+            notes: None,
+            description: None,
+            modifiers: vec![create_flat_bonus_modifier("toughness", toughness)],
+            requirements: RequirementTree::none(),
+            weapon_stats: None,
+            armor_stats: None,
+            shield_stats: None,
+            ammunition_stats: None,
+            poison_stats: None,
+            pack_contents: None,
+        };
+        CharacterGearValue::new(1, gear, 1, is_equipped, None)
+    }
+
+    #[test]
+    fn test_modifier_breakdown_attributes_armor_and_edge() {
+        let mut character = create_test_character();
+        character.gear.push(create_armor_gear("Leather Jacket", 1, true));
+        let mut brawny = create_skill_requirement_edge("Brawny", 1, 4);
+        brawny.edge.modifiers.push(create_flat_bonus_modifier("toughness", 1));
+        character.edges.push(brawny);
+        character.compute_effective_values();
+
+        let breakdown = character.modifier_breakdown("toughness");
+        let sources: Vec<(&str, &str, i64)> = breakdown
+            .iter()
+            .map(|s| (s.source_type.as_str(), s.source_name.as_str(), s.value))
+            .collect();
+        assert_eq!(
+            sources,
+            vec![("edge", "Brawny", 1), ("gear", "Leather Jacket", 1)]
+        );
+
+        // Toughness: 2 + (4/2) + 1 + 1 = 6, and the sidecar explains the +2
+        assert_eq!(character.derived_stats.toughness, 6);
+        assert_eq!(character.derived_stats.modifier_breakdown["toughness"], breakdown);
+        assert!(character.derived_stats.modifier_breakdown["pace"].is_empty());
+    }
+
+    #[test]
+    fn test_modifier_breakdown_skips_unequipped_gear() {
+        let mut character = create_test_character();
+        character.gear.push(create_armor_gear("Leather Jacket", 1, false));
+        character.modifiers.push(create_flat_bonus_modifier("toughness", 1));
+
+        let breakdown = character.modifier_breakdown("toughness");
+
+        assert_eq!(breakdown.len(), 1);
+        assert_eq!(breakdown[0].source_type, "direct");
+        assert_eq!(breakdown[0].source_name, "Direct");
+    }
+
+    #[test]
+    fn test_total_gear_value_multiplies_cost_by_quantity() {
+        let mut character = create_test_character();
+        character.gear.push(create_armor_gear("Leather Jacket", 1, true));
+        let rope = create_armor_gear("Rope", 0, false).gear;
+        character.gear.push(CharacterGearValue::new(2, rope, 3, false, None));
+        character.compute_effective_values();
+
+        // Jacket 20 x 1 + rope 20 x 3
+        assert_eq!(character.total_gear_value(), 80);
+        assert_eq!(character.net_worth, 580);
+    }
+
+    #[test]
+    fn test_load_limit_multiplier_changes_encumbrance() {
+        let mut character = create_test_character();
+        // Three 5 lb jackets fit a d4 Strength's core 20 lb limit but not a 10 lb one
+        let jacket = create_armor_gear("Leather Jacket", 1, false).gear;
+        character.gear.push(CharacterGearValue::new(1, jacket, 3, false, None));
+        character.compute_effective_values();
+
+        assert_eq!(character.encumbrance.load_limit, 20.0);
+        assert!(!character.encumbrance.is_encumbered);
+
+        character.config.load_limit_multiplier = Some(10);
+        character.compute_effective_values();
+
+        assert_eq!(character.encumbrance.load_limit, 10.0);
+        assert!(character.encumbrance.is_encumbered);
+    }
+
+    #[test]
+    fn test_weight_unit_converts_displayed_weights_only() {
+        let mut character = create_test_character();
+        let jacket = create_armor_gear("Leather Jacket", 1, false).gear;
+        character.gear.push(CharacterGearValue::new(1, jacket, 5, false, None));
+        character.compute_effective_values();
+        let in_pounds = character.encumbrance.clone();
+
+        character.config.weight_unit = WeightUnit::Kg;
+        character.compute_effective_values();
+
+        assert_eq!(character.encumbrance.unit, WeightUnit::Kg);
+        assert!((character.encumbrance.load_limit - 9.07).abs() < 0.01);
+        assert_eq!(character.encumbrance.is_encumbered, in_pounds.is_encumbered);
+        assert_eq!(character.encumbrance.pace_penalty, in_pounds.pace_penalty);
+        assert_eq!(character.gear[0].weight_label, "11.3 kg");
+    }
+
+    #[test]
+    fn test_encumbrance_reduces_pace_and_reports_trait_penalty() {
+        let mut character = create_test_character();
+        // Five 5 lb jackets are over a d4 Strength's 20 lb limit
+        let jacket = create_armor_gear("Leather Jacket", 1, false).gear;
+        character.gear.push(CharacterGearValue::new(1, jacket, 5, false, None));
+        character.wounds = 1;
+        character.compute_effective_values();
+
+        assert!(character.encumbrance.is_encumbered);
+        assert_eq!(character.derived_stats.pace, 4);
+        assert_eq!(character.derived_stats.encumbrance_penalty, 2);
+        assert_eq!(character.derived_stats.wound_penalty, 1);
+    }
+
+    #[test]
+    fn test_encumbrance_pace_penalty_leaves_at_least_one() {
+        let mut character = create_test_character();
+        character.modifiers.push(create_flat_bonus_modifier("pace", -5));
+        let jacket = create_armor_gear("Leather Jacket", 1, false).gear;
+        character.gear.push(CharacterGearValue::new(1, jacket, 5, false, None));
+        character.compute_effective_values();
+
+        assert_eq!(character.derived_stats.pace, 1);
+    }
+
+    #[test]
+    fn test_unencumbered_character_keeps_full_pace() {
+        let mut character = create_test_character();
+        character.gear.push(create_armor_gear("Leather Jacket", 1, false));
+        character.wounds = 5;
+        character.compute_effective_values();
+
+        assert_eq!(character.derived_stats.pace, 6);
+        assert_eq!(character.derived_stats.encumbrance_penalty, 0);
+        assert_eq!(character.derived_stats.wound_penalty, 3);
+    }
+
+    #[test]
+    fn test_stat_block_lists_traits_stats_edges_and_hindrances() {
+        let mut character = create_test_character();
+        character.gear.push(create_armor_gear("Leather Jacket", 2, true));
+        // Only the armor counts toward the parenthesized value
+        character.modifiers.push(create_flat_bonus_modifier("toughness", 1));
+        character.edges.push(create_skill_requirement_edge("Block", 1, 4));
+        character.hindrances.push(create_hindrance_with_modifier(
+            "Loyal",
+            create_flat_bonus_modifier("pace", 0),
+        ));
+        let mut wanted = create_hindrance_with_modifier("Wanted", create_flat_bonus_modifier("pace", 0));
+        wanted.hindrance.severity = Severity::Major;
+        character.hindrances.push(wanted);
+        character.compute_effective_values();
+
+        assert_eq!(
+            character.to_stat_block(),
+            "Test Character (Novice) \u{2014} Agility d6, Vigor d4\n\
+             Skills: Fighting d6\n\
+             Pace 6, Parry 5, Toughness 7(2)\n\
+             Edges: Block\n\
+             Hindrances: Loyal, Wanted (Major)"
+        );
+    }
+
+    #[test]
+    fn test_stat_block_without_armor_or_edges() {
+        let mut character = create_test_character();
+        character.compute_effective_values();
+
+        let stat_block = character.to_stat_block();
+
+        assert!(stat_block.contains("Toughness 4\n"));
+        assert!(stat_block.contains("Edges: None\n"));
+        assert!(stat_block.ends_with("Hindrances: None"));
+    }
+
+    #[test]
+    fn test_wild_card_incapacitated_at_four_wounds() {
+        let mut character = create_test_character();
+        character.wounds = 3;
+        character.compute_effective_values();
+        assert!(!character.incapacitated);
+
+        character.wounds = 4;
+        character.compute_effective_values();
+
+        assert!(character.incapacitated);
+        assert!(character.to_stat_block().ends_with("\nIncapacitated"));
+    }
+
+    #[test]
+    fn test_extra_incapacitated_at_one_wound() {
+        let mut character = create_test_character();
+        character.is_wild_card = false;
+        character.compute_effective_values();
+        assert!(!character.incapacitated);
+
+        character.wounds = 1;
+        character.compute_effective_values();
+
+        assert!(character.incapacitated);
+    }
+
+    #[test]
+    fn test_incapacitated_by_fatigue() {
+        let mut character = create_test_character();
+        character.fatigue = MAX_FATIGUE + 1;
+        character.compute_effective_values();
+
+        assert!(character.incapacitated);
+        assert!(!create_test_character().to_stat_block().contains("Incapacitated"));
+    }
+
+    #[test]
+    fn test_total_gear_value_is_zero_without_gear() {
+        let mut character = create_test_character();
+        character.compute_effective_values();
+
+        assert_eq!(character.total_gear_value(), 0);
+        assert_eq!(character.net_worth, character.wealth);
+    }
+
+    fn create_hindrance_with_modifier(name: &str, modifier: Modifier) -> CharacterHindranceValue {
+        use crate::views::{RequirementTree, Severity};
+        let hindrance = HindranceView {
+            id: 1,
+            name: name.to_string(),
+            severity: Severity::Minor,
+            point_value: 1,
+            companion_hindrance_id: None,
+            can_take_multiple_times: false,
+            source: "core".to_string(),
+            description: "".to_string(),
+            modifiers: vec![modifier],
+            requirements: RequirementTree::none(),
+        };
+        CharacterHindranceValue::new(hindrance, "chosen".to_string())
+    }
+
+    #[test]
+    fn test_hindrance_negative_pace_lowers_derived_pace() {
+        let mut character = create_test_character();
+        character
+            .hindrances
+            .push(create_hindrance_with_modifier("Slow", create_flat_bonus_modifier("pace", -1)));
+        character.compute_effective_values();
+
+        assert_eq!(character.derived_stats.pace, 5);
+    }
+
+    #[test]
+    fn test_hindrance_negative_toughness_and_size() {
+        let mut character = create_test_character();
+        character
+            .hindrances
+            .push(create_hindrance_with_modifier("Small", create_flat_bonus_modifier("size", -1)));
+        character.hindrances.push(create_hindrance_with_modifier(
+            "Small",
+            create_flat_bonus_modifier("toughness", -1),
+        ));
+        character.compute_effective_values();
+
+        // Toughness: 2 + (4/2) + (-1 size) + (-1) = 2
+        assert_eq!(character.derived_stats.size, -1);
+        assert_eq!(character.derived_stats.toughness, 2);
+    }
+
+    #[test]
+    fn test_negative_size_ancestry_lowers_toughness() {
+        use crate::views::RequirementTree;
+        let mut character = create_test_character();
+        character.attributes[1].die = Die::d6();
+        character.ancestry = Some(AncestryView {
+            id: 1,
+            name: "Pixie".to_string(),
+            source: "core".to_string(),
+            description: "".to_string(),
+            choices: vec![],
+            modifiers: vec![create_flat_bonus_modifier("size", -2)],
+            requirements: RequirementTree::none(),
+        });
+        character.compute_effective_values();
+
+        // Toughness: 2 + (6/2) + (-2 size) = 3
+        assert_eq!(character.derived_stats.size, -2);
+        assert_eq!(character.derived_stats.toughness, 3);
+        assert_eq!(character.derived_stats.modifier_breakdown["size"].len(), 1);
+    }
+
+    #[test]
+    fn test_toughness_never_drops_below_minimum() {
+        let mut character = create_test_character();
+        character
+            .hindrances
+            .push(create_hindrance_with_modifier("Tiny", create_flat_bonus_modifier("size", -4)));
+        character.compute_effective_values();
+
+        // 2 + (4/2) + (-4 size) = 0, floored at 1
+        assert_eq!(character.derived_stats.size, -4);
+        assert_eq!(character.derived_stats.toughness, MIN_TOUGHNESS);
+    }
+
+    #[test]
+    fn test_hindrance_negative_die_increment_lowers_attribute() {
+        let mut character = create_test_character();
+        character.hindrances.push(create_hindrance_with_modifier(
+            "Frail",
+            create_die_increment_modifier("attribute", "Agility", -1),
+        ));
+        character.compute_effective_values();
+
+        // Agility d6 purchased, -1 step = d4 effective
+        let agility = &character.attributes[0];
+        assert_eq!(agility.effective_die, Die::d4());
+        assert_eq!(agility.max_die, Die::d10());
+        // Effective base can't drop below d4
+        assert_eq!(agility.base_die, Die::d4());
+    }
+
+    fn create_ancestry_with_free_edge_choice(min_selections: i64) -> AncestryView {
+        use crate::views::{
+            AncestryChoiceOptionView, AncestryChoiceType, AncestryChoiceView, AncestryOptionType,
+            RequirementTree,
+        };
+        AncestryView {
+            id: 1,
+            name: "Human".to_string(),
+            source: "core".to_string(),
+            description: "".to_string(),
+            choices: vec![AncestryChoiceView {
+                id: 7,
+                choice_type: AncestryChoiceType::FreeEdge,
+                choice_category: None,
+                min_selections,
+                max_selections: 1,
+                description: "Choose one free Novice Edge".to_string(),
+                options: vec![AncestryChoiceOptionView {
+                    id: 70,
+                    option_type: AncestryOptionType::Edge,
+                    option_id: Some(10),
+                    description: Some("Alertness".to_string()),
+                }],
+            }],
+            modifiers: vec![],
+            requirements: RequirementTree::none(),
+        }
+    }
+
+    fn select_ancestry(character: &mut CharacterView, ancestry: AncestryView) {
+        character.ancestry_choices = ancestry
+            .choices
+            .iter()
+            .map(|choice| CharacterAncestryChoiceValue::new(choice.clone(), None))
+            .collect();
+        character.ancestry = Some(ancestry);
+    }
+
+    #[test]
+    fn test_pending_ancestry_choices_reports_unmade_free_edge() {
+        let mut character = create_test_character();
+        select_ancestry(&mut character, create_ancestry_with_free_edge_choice(1));
+
+        let pending = character.pending_ancestry_choices();
+
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].choice_id, 7);
+        assert_eq!(pending[0].min_selections, 1);
+        assert_eq!(pending[0].max_selections, 1);
+        assert_eq!(pending[0].selected, 0);
+        assert_eq!(pending[0].remaining, 1);
+    }
+
+    #[test]
+    fn test_pending_ancestry_choices_clears_once_selected() {
+        let mut character = create_test_character();
+        select_ancestry(&mut character, create_ancestry_with_free_edge_choice(1));
+        let option = character.ancestry_choices[0].choice.options[0].clone();
+        character.ancestry_choices[0].selected_option = Some(option);
+
+        assert!(character.pending_ancestry_choices().is_empty());
+    }
+
+    #[test]
+    fn test_pending_ancestry_choices_ignores_optional_choices() {
+        let mut character = create_test_character();
+        select_ancestry(&mut character, create_ancestry_with_free_edge_choice(0));
+
+        assert!(character.pending_ancestry_choices().is_empty());
+        assert!(create_test_character().pending_ancestry_choices().is_empty());
+    }
+
+    fn create_ancestry_with_attribute_choice() -> AncestryView {
+        use crate::views::{
+            AncestryChoiceOptionView, AncestryChoiceType, AncestryChoiceView, RequirementTree,
+        };
+        let option = |id: i64, attribute_id: i64, name: &str| AncestryChoiceOptionView {
+            id,
+            option_type: AncestryOptionType::Attribute,
+            option_id: Some(attribute_id),
+            description: Some(name.to_string()),
+        };
+        AncestryView {
+            id: 2,
+            name: "Half-Folk".to_string(),
+            source: "core".to_string(),
+            description: "".to_string(),
+            choices: vec![AncestryChoiceView {
+                id: 8,
+                choice_type: AncestryChoiceType::AttributeIncrease,
+                choice_category: None,
+                min_selections: 1,
+                max_selections: 1,
+                description: "Raise one attribute by a die type".to_string(),
+                options: vec![option(80, 1, "Agility"), option(81, 5, "Vigor")],
+            }],
+            modifiers: vec![],
+            requirements: RequirementTree::none(),
+        }
+    }
+
+    fn create_ancestry_with_skill_choice() -> AncestryView {
+        use crate::views::{
+            AncestryChoiceOptionView, AncestryChoiceType, AncestryChoiceView, RequirementTree,
+        };
+        let option = |id: i64, skill_id: i64, name: &str| AncestryChoiceOptionView {
+            id,
+            option_type: AncestryOptionType::Skill,
+            option_id: Some(skill_id),
+            description: Some(name.to_string()),
+        };
+        AncestryView {
+            id: 3,
+            name: "Scholar-Folk".to_string(),
+            source: "core".to_string(),
+            description: "".to_string(),
+            choices: vec![AncestryChoiceView {
+                id: 9,
+                choice_type: AncestryChoiceType::FreeSkill,
+                choice_category: None,
+                min_selections: 1,
+                max_selections: 1,
+                description: "Raise one skill by a die type".to_string(),
+                options: vec![option(90, 1, "Fighting"), option(91, 2, "Notice")],
+            }],
+            modifiers: vec![],
+            requirements: RequirementTree::none(),
+        }
+    }
+
+    #[test]
+    fn test_ancestry_free_skill_costs_no_skill_points() {
+        let mut character = create_test_character();
+        character.compute_effective_values();
+        let skill_points_spent = character.skill_points_spent;
+        select_ancestry(&mut character, create_ancestry_with_skill_choice());
+
+        // Untrained Notice is granted at d4
+        let notice_option = character.ancestry_choices[0].choice.options[1].clone();
+        character.ancestry_choices[0].selected_option = Some(notice_option);
+        character.compute_effective_values();
+        assert_eq!(character.skills[1].die, None);
+        assert_eq!(character.skills[1].effective_die, Some(Die::d4()));
+        assert_eq!(character.skill_points_spent, skill_points_spent);
+        // Nothing was bought, so there's nothing to refund
+        assert!(!character.skills[1].can_decrement);
+        let breakdown = character.modifier_breakdown("Notice");
+        assert_eq!(breakdown.len(), 1);
+        assert_eq!(breakdown[0].source_type, "ancestry");
+
+        // Trained Fighting goes up a die type instead
+        let fighting_option = character.ancestry_choices[0].choice.options[0].clone();
+        character.ancestry_choices[0].selected_option = Some(fighting_option);
+        character.compute_effective_values();
+        assert_eq!(character.skills[0].effective_die, Some(Die::d8()));
+        assert_eq!(character.skills[1].effective_die, None);
+        assert_eq!(character.skill_points_spent, skill_points_spent);
+
+        // Deselecting takes the step away
+        character.ancestry_choices[0].selected_option = None;
+        character.compute_effective_values();
+        assert_eq!(character.skills[0].effective_die, Some(Die::d6()));
+    }
+
+    #[test]
+    fn test_ancestry_attribute_increase_selection_and_change() {
+        let mut character = create_test_character();
+        select_ancestry(&mut character, create_ancestry_with_attribute_choice());
+
+        // Pick Agility: d6 purchased + 1 step = d8
+        let agility_option = character.ancestry_choices[0].choice.options[0].clone();
+        character.ancestry_choices[0].selected_option = Some(agility_option);
+        character.compute_effective_values();
+        assert_eq!(character.attributes[0].effective_die, Die::d8());
+        assert_eq!(character.attributes[1].effective_die, Die::d4());
+
+        let breakdown = character.modifier_breakdown("Agility");
+        assert_eq!(breakdown.len(), 1);
+        assert_eq!(breakdown[0].source_type, "ancestry");
+        assert_eq!(breakdown[0].source_name, "Half-Folk");
+
+        // Switch to Vigor: Agility loses the bonus
+        let vigor_option = character.ancestry_choices[0].choice.options[1].clone();
+        character.ancestry_choices[0].selected_option = Some(vigor_option);
+        character.compute_effective_values();
+        assert_eq!(character.attributes[0].effective_die, Die::d6());
+        assert_eq!(character.attributes[1].effective_die, Die::d6());
+
+        // Changing ancestry clears the bonus
+        select_ancestry(&mut character, create_ancestry_with_free_edge_choice(1));
+        character.compute_effective_values();
+        assert_eq!(character.attributes[1].effective_die, Die::d4());
+    }
+
+    fn create_hindrance_variant(
+        id: i64,
+        severity: crate::views::Severity,
+        companion: Option<i64>,
+    ) -> HindranceView {
+        let mut hindrance =
+            create_hindrance_with_modifier("Slow", create_flat_bonus_modifier("pace", -1)).hindrance;
+        hindrance.id = id;
+        hindrance.severity = severity;
+        hindrance.companion_hindrance_id = companion;
+        hindrance
+    }
+
+    #[test]
+    fn test_hindrance_conflict_blocks_duplicate() {
+        let mut character = create_test_character();
+        let slow = create_hindrance_variant(51, crate::views::Severity::Minor, None);
+        assert!(character.hindrance_conflict(&slow).is_none());
+
+        character
+            .hindrances
+            .push(CharacterHindranceValue::new(slow.clone(), "chosen".to_string()));
+
+        let reason = character.hindrance_conflict(&slow).unwrap();
+        assert!(reason.contains("already taken"));
+    }
+
+    #[test]
+    fn test_hindrance_conflict_allows_repeatable_duplicate() {
+        let mut character = create_test_character();
+        let mut quirk = create_hindrance_variant(60, crate::views::Severity::Minor, None);
+        quirk.can_take_multiple_times = true;
+        character
+            .hindrances
+            .push(CharacterHindranceValue::new(quirk.clone(), "chosen".to_string()));
+
+        assert!(character.hindrance_conflict(&quirk).is_none());
+    }
+
+    #[test]
+    fn test_hindrance_conflict_blocks_minor_and_major() {
+        use crate::views::Severity;
+        let mut character = create_test_character();
+        let minor = create_hindrance_variant(51, Severity::Minor, Some(52));
+        let major = create_hindrance_variant(52, Severity::Major, Some(51));
+        character
+            .hindrances
+            .push(CharacterHindranceValue::new(minor, "chosen".to_string()));
+
+        let reason = character.hindrance_conflict(&major).unwrap();
+        assert!(reason.contains("minor and major"));
+    }
+
+    fn take_hindrance(character: &mut CharacterView, hindrance: HindranceView) {
+        character
+            .hindrances
+            .push(CharacterHindranceValue::new(hindrance, "chosen".to_string()));
+        character.hindrance_points_earned = character.earned_hindrance_points();
+    }
+
+    #[test]
+    fn test_hindrance_point_cap_allows_exactly_four() {
+        use crate::views::Severity;
+        let mut character = create_test_character();
+        take_hindrance(&mut character, create_hindrance_variant(51, Severity::Major, None));
+
+        let second_major = create_hindrance_variant(52, Severity::Major, None);
+        assert!(character.hindrance_point_cap_exceeded(&second_major).is_none());
+        take_hindrance(&mut character, second_major);
+
+        assert_eq!(character.hindrance_points_earned, 4);
+    }
+
+    #[test]
+    fn test_hindrance_point_cap_blocks_fifth_point() {
+        use crate::views::Severity;
+        let mut character = create_test_character();
+        take_hindrance(&mut character, create_hindrance_variant(51, Severity::Major, None));
+        take_hindrance(&mut character, create_hindrance_variant(52, Severity::Major, None));
+
+        let minor = create_hindrance_variant(53, Severity::Minor, None);
+        let reason = character.hindrance_point_cap_exceeded(&minor).unwrap();
+        assert!(reason.contains("5/4"));
+    }
+
+    #[test]
+    fn test_hindrance_point_cap_override_takes_hindrance_for_free() {
+        use crate::views::Severity;
+        let mut character = create_test_character();
+        character.config.allow_hindrances_past_cap = true;
+        take_hindrance(&mut character, create_hindrance_variant(51, Severity::Major, None));
+        take_hindrance(&mut character, create_hindrance_variant(52, Severity::Major, None));
+
+        let minor = create_hindrance_variant(53, Severity::Minor, None);
+        assert!(character.hindrance_point_cap_exceeded(&minor).is_none());
+        take_hindrance(&mut character, minor);
+
+        assert_eq!(character.hindrances.len(), 3);
+        assert_eq!(character.hindrance_points_earned, 4);
+    }
+
+    #[test]
+    fn test_effective_snapshot_ignores_build_order() {
+        use crate::views::Severity;
+        let mut first = create_test_character();
+        first.attributes[1].die = Die::d8();
+        take_hindrance(&mut first, create_hindrance_variant(51, Severity::Minor, None));
+        take_hindrance(&mut first, create_hindrance_variant(52, Severity::Major, None));
+        first.edges.push(create_skill_requirement_edge("Block", 1, 6));
+        first.compute_effective_values();
+
+        // Same end state reached in a different order, via an intermediate die
+        let mut second = create_test_character();
+        second.attributes.reverse();
+        second.skills.reverse();
+        second.edges.push(create_skill_requirement_edge("Block", 1, 6));
+        take_hindrance(&mut second, create_hindrance_variant(52, Severity::Major, None));
+        take_hindrance(&mut second, create_hindrance_variant(51, Severity::Minor, None));
+        second.attributes[0].die = Die::d10();
+        second.compute_effective_values();
+        second.attributes[0].die = Die::d8();
+        second.compute_effective_values();
+
+        assert_eq!(first.effective_snapshot(), second.effective_snapshot());
+        assert_eq!(first.effective_snapshot().hindrance_ids, vec![51, 52]);
+    }
+
+    #[test]
+    fn test_effective_snapshot_differs_when_dice_differ() {
+        let mut first = create_test_character();
+        let mut second = create_test_character();
+        second.attributes[1].die = Die::d6();
+        first.compute_effective_values();
+        second.compute_effective_values();
+
+        let (a, b) = (first.effective_snapshot(), second.effective_snapshot());
+        assert_ne!(a, b);
+        assert_eq!(b.toughness, a.toughness + 1);
+    }
+
+    #[test]
+    fn test_orphaned_modifiers_flags_unknown_skill() {
+        let mut character = create_test_character();
+        character.hindrances.push(create_hindrance_with_modifier(
+            "Clumsy",
+            create_die_increment_modifier("skill", "Lockpicking", -1),
+        ));
+        character.hindrances.push(create_hindrance_with_modifier(
+            "Slow",
+            create_flat_bonus_modifier("pace", -1),
+        ));
+        character.edges.push(create_skill_grant_edge("Fighting", 8));
+
+        let orphans = character.orphaned_modifiers();
+
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].source_type, "hindrance");
+        assert_eq!(orphans[0].source_name, "Clumsy");
+        assert_eq!(orphans[0].target_type, "skill");
+        assert_eq!(orphans[0].target_identifier, "Lockpicking");
+    }
+
+    #[test]
+    fn test_orphaned_modifiers_flags_unknown_attribute_and_stat() {
+        let mut character = create_test_character();
+        character.modifiers.push(create_die_increment_modifier("attribute", "Luck", 1));
+        character.modifiers.push(create_flat_bonus_modifier("armor", 2));
+        character.modifiers.push(create_die_increment_modifier("attribute", "Agility", 1));
+
+        let targets: Vec<String> = character
+            .orphaned_modifiers()
+            .into_iter()
+            .map(|o| o.target_identifier)
+            .collect();
+
+        assert_eq!(targets, vec!["Luck".to_string(), "armor".to_string()]);
+    }
+
+    #[test]
+    fn test_conditional_modifiers_excluded_from_derived_stats() {
+        let mut character = create_test_character();
+        character.compute_effective_values();
+        let base_parry = character.derived_stats.parry;
+        character.hindrances.push(create_hindrance_with_modifier(
+            "Brawler",
+            Modifier {
+                condition: Some("unarmed".to_string()),
+                ..create_flat_bonus_modifier("parry", 1)
+            },
+        ));
+        character.compute_effective_values();
+
+        assert_eq!(character.derived_stats.parry, base_parry);
+        assert!(character.modifier_breakdown("parry").is_empty());
+        assert_eq!(character.situational_modifiers.len(), 1);
+        let situational = &character.situational_modifiers[0];
+        assert_eq!(situational.source_name, "Brawler");
+        assert_eq!(situational.value, 1);
+        assert_eq!(situational.condition.as_deref(), Some("unarmed"));
+    }
+
+    #[test]
+    fn test_conditional_die_increment_leaves_skill_die_unchanged() {
+        let mut character = create_test_character();
+        character.modifiers.push(Modifier {
+            condition: Some("unarmed".to_string()),
+            ..create_die_increment_modifier("skill", "Fighting", 1)
+        });
+        character.modifiers.push(create_die_increment_modifier("attribute", "Vigor", 1));
+        character.compute_effective_values();
+
+        assert_eq!(character.get_effective_skill_die(1), Some(Die::d6()));
+        // Always-on modifiers still apply
+        assert_eq!(character.get_effective_attribute_die(5), Some(Die::d6()));
+        assert_eq!(character.situational_modifiers.len(), 1);
+    }
+
+    fn add_martial_arts(character: &mut CharacterView) {
+        let mut martial_arts = character.skills[0].clone();
+        martial_arts.skill.id = 10;
+        martial_arts.skill.name = "Martial Arts".to_string();
+        martial_arts.die = Some(Die::d10());
+        character.skills.push(martial_arts);
+    }
+
+    #[test]
+    fn test_parry_from_configured_skill() {
+        let mut character = create_test_character();
+        add_martial_arts(&mut character);
+        character.config.parry_skill = Some("Martial Arts".to_string());
+        character.compute_effective_values();
+
+        // Parry: 2 + (Martial Arts d10 / 2) = 7, ignoring Fighting d6
+        assert_eq!(character.derived_stats.parry, 7);
+    }
+
+    #[test]
+    fn test_parry_skill_modifier_overrides_config() {
+        let mut character = create_test_character();
+        add_martial_arts(&mut character);
+        character.compute_effective_values();
+        assert_eq!(character.derived_stats.parry, 5);
+
+        character.edges.push(create_skill_grant_edge("Martial Arts", 4));
+        character.edges[0].edge.modifiers = vec![Modifier {
+            value_type: "parry_skill".to_string(),
+            value: None,
+            description: "Parry uses Martial Arts".to_string(),
+            ..create_die_increment_modifier("skill", "Martial Arts", 0)
+        }];
+        character.compute_effective_values();
+
+        assert_eq!(character.parry_skill(), "Martial Arts");
+        assert_eq!(character.derived_stats.parry, 7);
+    }
 }
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// A companion (sidekick, animal companion, etc.) linked to a character.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct CompanionSummary {
+    pub link_id: i64,
+    /// Id of the companion's own character record.
+    pub character_id: i64,
+    pub name: String,
+    pub is_wild_card: bool,
+    /// How the companion relates to the parent ("Sidekick", "Animal Companion", etc.).
+    pub relationship: String,
+}
+
+impl CompanionSummary {
+    pub fn new(
+        link_id: i64,
+        character_id: i64,
+        name: String,
+        is_wild_card: bool,
+        relationship: String,
+    ) -> Self {
+        Self {
+            link_id,
+            character_id,
+            name,
+            is_wild_card,
+            relationship,
+        }
+    }
+}
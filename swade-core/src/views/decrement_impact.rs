@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+use crate::views::DerivedStatsView;
+
+/// A derived stat that would change as the result of a hypothetical edit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct DerivedStatChange {
+    /// Name of the stat (pace, parry, toughness, size).
+    pub stat: String,
+    pub before: i32,
+    pub after: i32,
+}
+
+impl DerivedStatChange {
+    pub fn new(stat: impl Into<String>, before: i32, after: i32) -> Self {
+        Self {
+            stat: stat.into(),
+            before,
+            after,
+        }
+    }
+
+    /// List the stats that differ between two sets of derived stats.
+    pub fn diff(before: &DerivedStatsView, after: &DerivedStatsView) -> Vec<Self> {
+        [
+            ("pace", before.pace, after.pace),
+            ("parry", before.parry, after.parry),
+            ("toughness", before.toughness, after.toughness),
+            ("size", before.size, after.size),
+        ]
+        .into_iter()
+        .filter(|(_, b, a)| b != a)
+        .map(|(stat, b, a)| Self::new(stat, b, a))
+        .collect()
+    }
+}
+
+/// What decrementing an attribute or skill would do to the rest of the character.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct DecrementImpact {
+    /// Derived stats whose values would change.
+    pub derived_stat_changes: Vec<DerivedStatChange>,
+    /// Names of edges and powers whose requirements would no longer be met.
+    pub broken_requirements: Vec<String>,
+}
+
+impl DecrementImpact {
+    /// True if the decrement would change nothing outside the die itself.
+    pub fn is_empty(&self) -> bool {
+        self.derived_stat_changes.is_empty() && self.broken_requirements.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_reports_only_changed_stats() {
+        let before = DerivedStatsView::new();
+        let after = DerivedStatsView {
+            toughness: 4,
+            ..before.clone()
+        };
+
+        let changes = DerivedStatChange::diff(&before, &after);
+
+        assert_eq!(changes, vec![DerivedStatChange::new("toughness", 2, 4)]);
+    }
+
+    #[test]
+    fn diff_identical_stats_is_empty() {
+        let stats = DerivedStatsView::new();
+
+        assert!(DerivedStatChange::diff(&stats, &stats).is_empty());
+    }
+
+    #[test]
+    fn default_impact_is_empty() {
+        assert!(DecrementImpact::default().is_empty());
+    }
+}
@@ -1,11 +1,14 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::views::ModifierSource;
 
 /// Derived characteristics calculated from a character's attributes, skills, and modifiers.
 ///
 /// These values are computed by CharacterView::compute_effective_values() and represent
 /// the final calculated stats after all modifiers from edges, hindrances, and ancestries
 /// are applied.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
 pub struct DerivedStatsView {
     /// Movement rate in tabletop inches per round. Base is 6.
     pub pace: i32,
@@ -15,6 +18,16 @@ pub struct DerivedStatsView {
     pub toughness: i32,
     /// Size modifier. Normal humans are 0. Affects Toughness.
     pub size: i32,
+    /// Penalty to all trait rolls from wounds, up to 3.
+    pub wound_penalty: i32,
+    /// Penalty to all trait rolls from Fatigue, up to 2.
+    pub fatigue_penalty: i32,
+    /// Penalty to Agility and Strength rolls (and their linked skills) from
+    /// carrying more than the load limit.
+    pub encumbrance_penalty: i32,
+    /// Flat bonus modifiers behind each stat, keyed by stat name (pace, parry,
+    /// toughness, size).
+    pub modifier_breakdown: HashMap<String, Vec<ModifierSource>>,
 }
 
 impl DerivedStatsView {
@@ -25,8 +38,17 @@ impl DerivedStatsView {
             parry: 2,
             toughness: 2,
             size: 0,
+            wound_penalty: 0,
+            fatigue_penalty: 0,
+            encumbrance_penalty: 0,
+            modifier_breakdown: HashMap::new(),
         }
     }
+
+    /// Combined wound and Fatigue penalty applied to every trait roll.
+    pub fn status_penalty(&self) -> i32 {
+        self.wound_penalty + self.fatigue_penalty
+    }
 }
 
 impl Default for DerivedStatsView {
@@ -46,6 +68,8 @@ mod tests {
         assert_eq!(stats.parry, 2);
         assert_eq!(stats.toughness, 2);
         assert_eq!(stats.size, 0);
+        assert_eq!(stats.wound_penalty, 0);
+        assert_eq!(stats.encumbrance_penalty, 0);
     }
 
     #[test]
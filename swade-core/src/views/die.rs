@@ -5,10 +5,13 @@ use std::fmt;
 ///
 /// Represents die sizes from d4 to d12, and beyond d12 with modifiers (d12+1, d12+2, etc.).
 /// The progression is: d4 → d6 → d8 → d10 → d12 → d12+1 → d12+2 → ...
+///
+/// A d4 may also carry a negative modifier to express a roll below the base
+/// die, such as the d4-2 rolled for an untrained skill.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, specta::Type)]
 pub struct Die {
     size: u8,
-    modifier: u8,
+    modifier: i8,
 }
 
 impl Die {
@@ -33,7 +36,32 @@ impl Die {
         if size < 12 && modifier > 0 {
             return None;
         }
-        Some(Self { size, modifier })
+        Some(Self {
+            size,
+            modifier: i8::try_from(modifier).ok()?,
+        })
+    }
+
+    /// Create a die from a size and a signed modifier.
+    /// Positive modifiers are only valid on a d12 (d12+1), negative ones only on a d4 (d4-2).
+    pub fn from_size_modifier(size: u8, modifier: i8) -> Option<Self> {
+        if !Self::VALID_SIZES.contains(&size) {
+            return None;
+        }
+        let valid = match modifier {
+            0 => true,
+            m if m > 0 => size == 12,
+            _ => size == 4,
+        };
+        valid.then_some(Self { size, modifier })
+    }
+
+    /// The d4-2 rolled for a skill the character has no dice in.
+    pub fn untrained() -> Self {
+        Self {
+            size: 4,
+            modifier: -2,
+        }
     }
 
     pub fn d4() -> Self {
@@ -76,15 +104,22 @@ impl Die {
         self.size
     }
 
-    /// Get the modifier (0 for normal dice, 1+ for d12+N).
-    pub fn modifier(&self) -> u8 {
+    /// Get the modifier (0 for normal dice, 1+ for d12+N, negative for d4-N).
+    pub fn modifier(&self) -> i8 {
         self.modifier
     }
 
+    /// Format the die for display, e.g. "d8", "d12+1", or "d4-2".
+    pub fn to_display_string(&self) -> String {
+        self.to_string()
+    }
+
     /// Increment to the next die value.
-    /// d4 → d6 → d8 → d10 → d12 → d12+1 → d12+2 → ...
+    /// d4-2 → d4 → d6 → d8 → d10 → d12 → d12+1 → d12+2 → ...
     pub fn increment(&self) -> Self {
-        if self.size == 12 {
+        if self.modifier < 0 {
+            Self::d4()
+        } else if self.size == 12 {
             Self {
                 size: 12,
                 modifier: self.modifier + 1,
@@ -105,39 +140,33 @@ impl Die {
     /// Returns how many increments it takes to go from `from` to `self`.
     /// Returns 0 if `from` is greater than or equal to `self`.
     pub fn steps_from(&self, from: Die) -> u8 {
-        let self_index = Self::VALID_SIZES
-            .iter()
-            .position(|&s| s == self.size)
-            .unwrap_or(0);
-        let from_index = Self::VALID_SIZES
-            .iter()
-            .position(|&s| s == from.size)
-            .unwrap_or(0);
-
-        if from_index > self_index {
-            return 0;
-        }
+        from.steps_to(*self).max(0) as u8
+    }
 
-        let base_steps = (self_index - from_index) as u8;
+    /// Signed number of steps from `self` to `other`.
+    /// Positive when `other` is higher (d6 → d10 is 2), negative when lower.
+    pub fn steps_to(&self, other: Die) -> i32 {
+        other.step_index() - self.step_index()
+    }
 
-        // Add modifier difference if both are d12
-        if self.size == 12 && from.size == 12 {
-            if self.modifier >= from.modifier {
-                base_steps + (self.modifier - from.modifier)
-            } else {
-                0
-            }
-        } else if self.size == 12 {
-            base_steps + self.modifier
-        } else {
-            base_steps
+    /// Position in the die progression: d4-N is -1, d4 is 0, d12+N is 4 + N.
+    fn step_index(&self) -> i32 {
+        if self.modifier < 0 {
+            return -1;
         }
+        let index = Self::VALID_SIZES
+            .iter()
+            .position(|&s| s == self.size)
+            .unwrap_or(0) as i32;
+        index + self.modifier as i32
     }
 
     /// Decrement to the previous die value, returning None if already at d4.
     /// d12+2 → d12+1 → d12 → d10 → d8 → d6 → d4 → None
     pub fn decrement(&self) -> Option<Self> {
-        if self.modifier > 0 {
+        if self.modifier < 0 {
+            None
+        } else if self.modifier > 0 {
             Some(Self {
                 size: 12,
                 modifier: self.modifier - 1,
@@ -159,10 +188,10 @@ impl Die {
 
 impl fmt::Display for Die {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.modifier == 0 {
-            write!(f, "d{}", self.size)
-        } else {
-            write!(f, "d{}+{}", self.size, self.modifier)
+        match self.modifier {
+            0 => write!(f, "d{}", self.size),
+            m if m > 0 => write!(f, "d{}+{}", self.size, m),
+            m => write!(f, "d{}{}", self.size, m),
         }
     }
 }
@@ -271,6 +300,51 @@ mod tests {
         assert_eq!(Die::with_modifier(12, 3).unwrap().to_string(), "d12+3");
     }
 
+    #[test]
+    fn to_display_string_with_modifiers() {
+        assert_eq!(Die::d8().to_display_string(), "d8");
+        assert_eq!(Die::with_modifier(12, 1).unwrap().to_display_string(), "d12+1");
+        assert_eq!(Die::from_size_modifier(12, 2).unwrap().to_display_string(), "d12+2");
+    }
+
+    #[test]
+    fn untrained_displays_d4_minus_2() {
+        assert_eq!(Die::untrained().to_display_string(), "d4-2");
+        assert_eq!(Die::from_size_modifier(4, -2), Some(Die::untrained()));
+    }
+
+    #[test]
+    fn from_size_modifier_rejects_invalid_combinations() {
+        assert!(Die::from_size_modifier(7, 0).is_none());
+        assert!(Die::from_size_modifier(8, 1).is_none());
+        assert!(Die::from_size_modifier(8, -1).is_none());
+        assert!(Die::from_size_modifier(12, -1).is_none());
+        assert_eq!(Die::from_size_modifier(10, 0), Some(Die::d10()));
+    }
+
+    #[test]
+    fn untrained_steps_and_bounds() {
+        assert!(Die::untrained() < Die::d4());
+        assert_eq!(Die::untrained().increment(), Die::d4());
+        assert_eq!(Die::untrained().decrement(), None);
+        assert_eq!(Die::d6().steps_from(Die::untrained()), 2);
+    }
+
+    #[test]
+    fn steps_to_is_signed() {
+        assert_eq!(Die::d6().steps_to(Die::d10()), 2);
+        assert_eq!(Die::d10().steps_to(Die::d6()), -2);
+        assert_eq!(Die::d10().steps_to(Die::with_modifier(12, 2).unwrap()), 3);
+        assert_eq!(Die::d8().steps_to(Die::d8()), 0);
+    }
+
+    #[test]
+    fn steps_from_matches_previous_behaviour() {
+        assert_eq!(Die::d12().steps_from(Die::d4()), 4);
+        assert_eq!(Die::with_modifier(12, 1).unwrap().steps_from(Die::d10()), 2);
+        assert_eq!(Die::d6().steps_from(Die::d10()), 0);
+    }
+
     #[test]
     fn ordering() {
         assert!(Die::d4() < Die::d6());
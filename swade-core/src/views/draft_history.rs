@@ -0,0 +1,268 @@
+use std::collections::VecDeque;
+
+use crate::views::CharacterView;
+
+/// Undo/redo stacks of draft character snapshots.
+///
+/// A snapshot of the draft is recorded before each edit. Undo swaps the current
+/// draft for the most recent snapshot, redo swaps it back. Recording a new edit
+/// clears the redo stack.
+#[derive(Debug, Clone)]
+pub struct DraftHistory {
+    undo: VecDeque<CharacterView>,
+    redo: Vec<CharacterView>,
+    limit: usize,
+}
+
+impl DraftHistory {
+    /// Number of snapshots kept when no explicit limit is given.
+    pub const DEFAULT_LIMIT: usize = 50;
+
+    pub fn new(limit: usize) -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            limit,
+        }
+    }
+
+    /// Record the draft as it was before an edit, dropping the oldest snapshot
+    /// once the limit is reached.
+    pub fn record(&mut self, snapshot: CharacterView) {
+        if self.limit == 0 {
+            return;
+        }
+        if self.undo.len() == self.limit {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(snapshot);
+        self.redo.clear();
+    }
+
+    /// Run an edit on the draft, recording its prior state only if the edit
+    /// succeeds. On failure the draft is put back as it was and the history
+    /// is left alone, so a rejected edit can't be undone or clear the redo stack.
+    pub fn edit<T, E>(
+        &mut self,
+        draft: &mut CharacterView,
+        edit: impl FnOnce(&mut CharacterView) -> Result<T, E>,
+    ) -> Result<T, E> {
+        let before = draft.clone();
+        match edit(draft) {
+            Ok(value) => {
+                self.record(before);
+                Ok(value)
+            }
+            Err(e) => {
+                *draft = before;
+                Err(e)
+            }
+        }
+    }
+
+    /// Step back one edit. Returns the draft to restore, or None if there is
+    /// nothing to undo.
+    pub fn undo(&mut self, current: &CharacterView) -> Option<CharacterView> {
+        let mut previous = self.undo.pop_back()?;
+        self.redo.push(current.clone());
+        // The stored revision isn't an edit, so keep the one last loaded or saved
+        previous.revision = current.revision;
+        Some(previous)
+    }
+
+    /// Reapply the most recently undone edit. Returns the draft to restore, or
+    /// None if there is nothing to redo.
+    pub fn redo(&mut self, current: &CharacterView) -> Option<CharacterView> {
+        let mut next = self.redo.pop()?;
+        self.undo.push_back(current.clone());
+        next.revision = current.revision;
+        Some(next)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forget all snapshots, e.g. when a different character is loaded.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+impl Default for DraftHistory {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_LIMIT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Rank;
+    use crate::views::{CharacterConfig, DerivedStatsView, EncumbranceInfo, PowerSlots};
+
+    fn draft(name: &str) -> CharacterView {
+        CharacterView {
+            id: 0,
+            is_wild_card: true,
+            name: name.to_string(),
+            ancestry: None,
+            ancestry_choices: vec![],
+            rank: Rank {
+                id: 1,
+                name: "Novice".to_string(),
+                min_advances: 0,
+                max_advances: Some(3),
+                description: "".to_string(),
+                created_at: "2024-01-01".to_string(),
+                updated_at: "2024-01-01".to_string(),
+            },
+            current_advances: 0,
+            attributes: vec![],
+            skills: vec![],
+            edges: vec![],
+            hindrances: vec![],
+            arcane_backgrounds: vec![],
+            arcane_background_choices: vec![],
+            powers: vec![],
+            power_points: 0,
+            power_points_used: 0,
+            power_points_base: 0,
+            power_points_bonus: 0,
+            power_point_pools: vec![],
+            power_slots: PowerSlots::default(),
+            wounds: 0,
+            fatigue: 0,
+            incapacitated: false,
+            experience_points: 0,
+            revision: 0,
+            notes: vec![],
+            companions: vec![],
+            gear: vec![],
+            encumbrance: EncumbranceInfo::empty(4),
+            modifiers: vec![],
+            derived_stats: DerivedStatsView::default(),
+            situational_modifiers: vec![],
+            attribute_points_spent: 0,
+            attribute_points_earned: 5,
+            skill_points_spent: 0,
+            skill_points_earned: 12,
+            hindrance_points_spent: 0,
+            hindrance_points_earned: 0,
+            hindrance_points_to_edges: 0,
+            hindrance_points_to_attributes: 0,
+            hindrance_points_to_skills: 0,
+            hindrance_points_to_wealth: 0,
+            wealth: 500,
+            net_worth: 500,
+            background: None,
+            description: None,
+            campaign: None,
+            portrait_data_url: None,
+            config: CharacterConfig::default(),
+            data_warnings: vec![],
+        }
+    }
+
+    #[test]
+    fn undo_and_redo_walk_through_edits() {
+        let mut history = DraftHistory::default();
+        let mut current = draft("v1");
+
+        for name in ["v2", "v3", "v4"] {
+            history.record(current.clone());
+            current = draft(name);
+        }
+
+        current = history.undo(&current).unwrap();
+        assert_eq!(current.name, "v3");
+        current = history.undo(&current).unwrap();
+        assert_eq!(current.name, "v2");
+
+        current = history.redo(&current).unwrap();
+        assert_eq!(current.name, "v3");
+        assert!(history.can_redo());
+    }
+
+    #[test]
+    fn new_edit_clears_redo() {
+        let mut history = DraftHistory::default();
+        let mut current = draft("v1");
+
+        history.record(current.clone());
+        current = draft("v2");
+        current = history.undo(&current).unwrap();
+        assert!(history.can_redo());
+
+        history.record(current.clone());
+        assert!(!history.can_redo());
+        assert!(history.redo(&draft("v3")).is_none());
+    }
+
+    #[test]
+    fn failed_edit_leaves_draft_and_history_unchanged() {
+        let mut history = DraftHistory::default();
+        let mut current = draft("v1");
+        history
+            .edit(&mut current, |d| {
+                d.name = "v2".to_string();
+                Ok::<_, ()>(())
+            })
+            .unwrap();
+        current = history.undo(&current).unwrap();
+        assert!(history.can_redo());
+
+        let result = history.edit(&mut current, |d| {
+            d.name = "rejected".to_string();
+            Err::<(), _>(())
+        });
+
+        assert!(result.is_err());
+        assert_eq!(current.name, "v1");
+        assert!(!history.can_undo());
+        assert!(history.can_redo());
+    }
+
+    #[test]
+    fn undo_with_empty_history_returns_none() {
+        let mut history = DraftHistory::default();
+
+        assert!(!history.can_undo());
+        assert!(history.undo(&draft("v1")).is_none());
+    }
+
+    #[test]
+    fn record_drops_oldest_past_limit() {
+        let mut history = DraftHistory::new(2);
+        let mut current = draft("v1");
+
+        for name in ["v2", "v3", "v4"] {
+            history.record(current.clone());
+            current = draft(name);
+        }
+
+        current = history.undo(&current).unwrap();
+        assert_eq!(current.name, "v3");
+        current = history.undo(&current).unwrap();
+        assert_eq!(current.name, "v2");
+        assert!(history.undo(&current).is_none());
+    }
+
+    #[test]
+    fn undo_keeps_latest_saved_revision() {
+        let mut history = DraftHistory::default();
+        history.record(draft("v1"));
+        let mut saved = draft("v2");
+        saved.revision = 3;
+
+        let restored = history.undo(&saved).unwrap();
+
+        assert_eq!(restored.name, "v1");
+        assert_eq!(restored.revision, 3);
+    }
+}
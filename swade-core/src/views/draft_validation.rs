@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// Problems that keep a draft from being a legal starting character.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct DraftValidation {
+    /// Rule violations, each a sentence naming what is wrong.
+    pub errors: Vec<String>,
+    /// Things worth a second look that don't make the draft illegal, such as
+    /// points left unspent.
+    pub warnings: Vec<String>,
+}
+
+impl DraftValidation {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
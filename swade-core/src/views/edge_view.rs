@@ -59,6 +59,7 @@ mod tests {
             value_type: "roll_bonus".to_string(),
             value: Some(2),
             description: "+2 to Notice rolls".to_string(),
+            condition: None,
             created_at: "2024-01-01".to_string(),
             updated_at: "2024-01-01".to_string(),
         }
@@ -69,6 +70,7 @@ mod tests {
             id: 1,
             requirement_type: "rank".to_string(),
             target_id: Some(1),
+            target_name: None,
             value: Some(1),
             description: "Novice".to_string(),
             created_at: "2024-01-01".to_string(),
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::views::Die;
+
+/// Order-independent snapshot of a character's effective values.
+///
+/// Built by `CharacterView::effective_snapshot()`. Everything is sorted by id, so
+/// two characters that reach the same end state compare equal with `==` no matter
+/// what order their attributes, skills, edges, or hindrances were loaded or added in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct EffectiveSheet {
+    /// Effective die for each attribute, as `(attribute_id, die)` sorted by id.
+    pub attributes: Vec<(i64, Die)>,
+    /// Effective die for each skill, as `(skill_id, die)` sorted by id. Untrained
+    /// skills have no die.
+    pub skills: Vec<(i64, Option<Die>)>,
+
+    // Derived stats
+    pub pace: i32,
+    pub parry: i32,
+    pub toughness: i32,
+    pub size: i32,
+    pub wound_penalty: i32,
+    pub fatigue_penalty: i32,
+    pub encumbrance_penalty: i32,
+
+    // Point pools
+    pub attribute_points_spent: i64,
+    pub attribute_points_earned: i64,
+    pub skill_points_spent: i64,
+    pub skill_points_earned: i64,
+    pub hindrance_points_spent: i64,
+    pub hindrance_points_earned: i64,
+    pub hindrance_points_to_edges: i64,
+    pub hindrance_points_to_attributes: i64,
+    pub hindrance_points_to_skills: i64,
+    pub hindrance_points_to_wealth: i64,
+    pub power_points: i64,
+    pub power_points_used: i64,
+    pub wealth: i64,
+
+    /// Ids of every edge held, sorted. Repeatable edges appear once per copy.
+    pub edge_ids: Vec<i64>,
+    /// Ids of every hindrance held, sorted.
+    pub hindrance_ids: Vec<i64>,
+}
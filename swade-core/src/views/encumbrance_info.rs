@@ -1,5 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+use crate::constants::{
+    ENCUMBRANCE_PACE_PENALTY, ENCUMBRANCE_TRAIT_PENALTY, LOAD_LIMIT_PER_STRENGTH_STEP,
+};
+use crate::views::WeightUnit;
+
 /// Information about a character's encumbrance status
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
 pub struct EncumbranceInfo {
@@ -14,30 +19,61 @@ pub struct EncumbranceInfo {
 
     /// Penalty applied if encumbered (-2 to all physical tasks)
     pub encumbrance_penalty: i64,
+
+    /// Pace lost if encumbered
+    pub pace_penalty: i64,
+
+    /// Unit `current_weight` and `load_limit` are in
+    pub unit: WeightUnit,
 }
 
 impl EncumbranceInfo {
     /// Create encumbrance info from current weight and strength die size
     pub fn from_weight_and_strength(current_weight: f64, strength_die_size: u8) -> Self {
-        // SWADE encumbrance table based on Strength die
-        let load_limit = match strength_die_size {
-            4 => 20.0,
-            6 => 40.0,
-            8 => 60.0,
-            10 => 80.0,
-            12 => 100.0,
-            // d12+N (die size stored as 12, modifier adds 20 per step beyond)
-            _ => 100.0 + ((strength_die_size.saturating_sub(12)) as f64 * 20.0),
+        Self::with_load_multiplier(current_weight, strength_die_size, LOAD_LIMIT_PER_STRENGTH_STEP)
+    }
+
+    /// Create encumbrance info using a custom number of pounds per Strength die step.
+    pub fn with_load_multiplier(
+        current_weight: f64,
+        strength_die_size: u8,
+        pounds_per_step: i64,
+    ) -> Self {
+        // SWADE encumbrance table: one load step per Strength die step
+        let strength_steps = match strength_die_size {
+            4 => 1,
+            6 => 2,
+            8 => 3,
+            10 => 4,
+            12 => 5,
+            // d12+N (die size stored as 12, modifier adds a step beyond)
+            _ => 5 + strength_die_size.saturating_sub(12) as i64,
         };
+        let load_limit = (strength_steps * pounds_per_step) as f64;
 
         let is_encumbered = current_weight > load_limit;
-        let encumbrance_penalty = if is_encumbered { 2 } else { 0 };
+        let encumbrance_penalty = if is_encumbered { ENCUMBRANCE_TRAIT_PENALTY } else { 0 };
+        let pace_penalty = if is_encumbered { ENCUMBRANCE_PACE_PENALTY } else { 0 };
 
         Self {
             current_weight,
             load_limit,
             is_encumbered,
             encumbrance_penalty,
+            pace_penalty,
+            unit: WeightUnit::Lb,
+        }
+    }
+
+    /// The same encumbrance with weights converted from pounds to `unit` for
+    /// display. Encumbrance is always worked out in pounds, so the level and
+    /// penalties don't change.
+    pub fn in_unit(&self, unit: WeightUnit) -> Self {
+        Self {
+            current_weight: unit.from_pounds(self.current_weight),
+            load_limit: unit.from_pounds(self.load_limit),
+            unit,
+            ..self.clone()
         }
     }
 
@@ -46,3 +82,48 @@ impl EncumbranceInfo {
         Self::from_weight_and_strength(0.0, strength_die_size)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_load_limit_is_twenty_pounds_per_strength_step() {
+        assert_eq!(EncumbranceInfo::empty(4).load_limit, 20.0);
+        assert_eq!(EncumbranceInfo::empty(8).load_limit, 60.0);
+        assert_eq!(EncumbranceInfo::empty(12).load_limit, 100.0);
+    }
+
+    #[test]
+    fn custom_multiplier_changes_encumbrance_at_same_weight() {
+        let core = EncumbranceInfo::from_weight_and_strength(50.0, 6);
+        let generous = EncumbranceInfo::with_load_multiplier(50.0, 6, 30);
+
+        assert_eq!(core.load_limit, 40.0);
+        assert!(core.is_encumbered);
+        assert_eq!(core.encumbrance_penalty, 2);
+        assert_eq!(core.pace_penalty, 2);
+        assert_eq!(generous.load_limit, 60.0);
+        assert!(!generous.is_encumbered);
+        assert_eq!(generous.encumbrance_penalty, 0);
+        assert_eq!(generous.pace_penalty, 0);
+    }
+
+    #[test]
+    fn switching_units_keeps_encumbrance_level() {
+        for weight in [30.0, 40.0, 41.0] {
+            let pounds = EncumbranceInfo::from_weight_and_strength(weight, 6);
+            let kilograms = pounds.in_unit(WeightUnit::Kg);
+
+            assert_eq!(kilograms.is_encumbered, pounds.is_encumbered);
+            assert_eq!(kilograms.encumbrance_penalty, pounds.encumbrance_penalty);
+            assert_eq!(kilograms.pace_penalty, pounds.pace_penalty);
+            assert_eq!(
+                kilograms.current_weight > kilograms.load_limit,
+                pounds.is_encumbered
+            );
+        }
+        let kilograms = EncumbranceInfo::empty(6).in_unit(WeightUnit::Kg);
+        assert!((kilograms.load_limit - 18.143_694_8).abs() < 1e-6);
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::constants::MAX_HINDRANCE_POINTS;
+
 /// Static game configuration constants for SWADE.
 /// Provides a single source of truth for game rules that the frontend can query.
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
@@ -26,7 +28,7 @@ impl GameConfig {
     /// Returns the default SWADE game configuration.
     pub fn swade_defaults() -> Self {
         Self {
-            max_hindrance_points: 4,
+            max_hindrance_points: MAX_HINDRANCE_POINTS,
             base_attribute_points: 5,
             base_skill_points: 12,
             hindrance_points_per_attribute: 2,
@@ -11,6 +11,7 @@ pub struct HindranceView {
     pub severity: Severity,
     pub point_value: i64,
     pub companion_hindrance_id: Option<i64>,
+    pub can_take_multiple_times: bool,
     pub source: String,
     pub description: String,
     pub modifiers: Vec<Modifier>,
@@ -31,6 +32,7 @@ impl HindranceView {
             severity,
             point_value: hindrance.point_value,
             companion_hindrance_id: hindrance.companion_hindrance_id,
+            can_take_multiple_times: hindrance.can_take_multiple_times,
             source: hindrance.source,
             description: hindrance.description,
             modifiers,
@@ -51,6 +53,7 @@ mod tests {
             severity: "Major".to_string(),
             point_value: 2,
             companion_hindrance_id: None,
+            can_take_multiple_times: false,
             source: "core".to_string(),
             description: "The character is completely blind.".to_string(),
             created_at: "2024-01-01".to_string(),
@@ -66,6 +69,7 @@ mod tests {
             value_type: "roll_bonus".to_string(),
             value: Some(-2),
             description: "-2 to Notice rolls".to_string(),
+            condition: None,
             created_at: "2024-01-01".to_string(),
             updated_at: "2024-01-01".to_string(),
         }
@@ -76,6 +80,7 @@ mod tests {
             id: 1,
             requirement_type: "rank".to_string(),
             target_id: Some(1),
+            target_name: None,
             value: Some(1),
             description: "Novice".to_string(),
             created_at: "2024-01-01".to_string(),
@@ -9,6 +9,7 @@ mod arcane_background_choice_type;
 mod arcane_background_choice_view;
 mod arcane_background_option_type;
 mod arcane_background_view;
+mod archetype_view;
 mod armor_stats_view;
 mod attribute_view;
 mod character_advance_value;
@@ -16,27 +17,40 @@ mod character_ancestry_choice_value;
 mod character_arcane_background_choice_value;
 mod character_arcane_background_value;
 mod character_attribute_value;
+mod character_config;
+mod character_diff;
 mod character_edge_value;
 mod character_gear_value;
 mod character_hindrance_value;
 mod character_note_value;
 mod character_power_value;
 mod character_skill_value;
+mod character_snapshot_value;
 mod character_view;
+mod companion_summary;
+mod decrement_impact;
 mod derived_stats_view;
 mod die;
+mod draft_history;
+mod draft_validation;
 mod edge_category;
 mod edge_view;
+mod effective_sheet;
 mod encumbrance_info;
 mod game_config;
 mod gear_category_view;
 mod gear_view;
 mod hindrance_view;
+mod modifier_source;
 mod modifier_target_type;
 mod modifier_value_type;
 mod node_type;
+mod orphaned_modifier;
 mod pack_contents_view;
+mod pending_choice;
 mod poison_stats_view;
+mod power_point_pool;
+mod power_slots;
 mod power_view;
 mod requirement_tree;
 mod requirement_type;
@@ -44,6 +58,7 @@ mod severity;
 mod shield_stats_view;
 mod skill_view;
 mod weapon_stats_view;
+mod weight_unit;
 
 pub use ammunition_stats_view::*;
 pub use ancestry_choice_option_view::*;
@@ -56,6 +71,7 @@ pub use arcane_background_choice_type::*;
 pub use arcane_background_choice_view::*;
 pub use arcane_background_option_type::*;
 pub use arcane_background_view::*;
+pub use archetype_view::*;
 pub use armor_stats_view::*;
 pub use attribute_view::*;
 pub use character_advance_value::*;
@@ -63,27 +79,40 @@ pub use character_ancestry_choice_value::*;
 pub use character_arcane_background_choice_value::*;
 pub use character_arcane_background_value::*;
 pub use character_attribute_value::*;
+pub use character_config::*;
+pub use character_diff::*;
 pub use character_edge_value::*;
 pub use character_gear_value::*;
 pub use character_hindrance_value::*;
 pub use character_note_value::*;
 pub use character_power_value::*;
 pub use character_skill_value::*;
+pub use character_snapshot_value::*;
 pub use character_view::*;
+pub use companion_summary::*;
+pub use decrement_impact::*;
 pub use derived_stats_view::*;
 pub use die::*;
+pub use draft_history::*;
+pub use draft_validation::*;
 pub use edge_category::*;
 pub use edge_view::*;
+pub use effective_sheet::*;
 pub use encumbrance_info::*;
 pub use game_config::*;
 pub use gear_category_view::*;
 pub use gear_view::*;
 pub use hindrance_view::*;
+pub use modifier_source::*;
 pub use modifier_target_type::*;
 pub use modifier_value_type::*;
 pub use node_type::*;
+pub use orphaned_modifier::*;
 pub use pack_contents_view::*;
+pub use pending_choice::*;
 pub use poison_stats_view::*;
+pub use power_point_pool::*;
+pub use power_slots::*;
 pub use power_view::*;
 pub use requirement_tree::*;
 pub use requirement_type::*;
@@ -91,3 +120,4 @@ pub use severity::*;
 pub use shield_stats_view::*;
 pub use skill_view::*;
 pub use weapon_stats_view::*;
+pub use weight_unit::*;
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::Modifier;
+
+/// A single modifier contributing to a character value, labelled with where it came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ModifierSource {
+    /// Kind of source: "direct", "ancestry", "edge", "hindrance", or "gear".
+    pub source_type: String,
+    /// Display label for the source (edge name, gear name, etc.). "Direct" for
+    /// modifiers applied to the character itself.
+    pub source_name: String,
+    pub value_type: String,
+    pub value: i64,
+    pub description: String,
+    /// When the modifier applies, for situational modifiers.
+    pub condition: Option<String>,
+}
+
+impl ModifierSource {
+    pub fn new(source_type: &str, source_name: &str, modifier: &Modifier) -> Self {
+        Self {
+            source_type: source_type.to_string(),
+            source_name: source_name.to_string(),
+            value_type: modifier.value_type.clone(),
+            value: modifier.value.unwrap_or(0),
+            description: modifier.description.clone(),
+            condition: modifier.condition.clone(),
+        }
+    }
+}
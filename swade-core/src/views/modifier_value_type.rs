@@ -12,6 +12,12 @@ pub enum ModifierValueType {
     Description,
     BonusSelection,
     MandatorySelection,
+    /// Grants a skill at the die size in `value` (e.g. 6 for d6) at no point cost.
+    GrantSkill,
+    /// Raises a trait's maximum die by `value` steps (e.g. Professional: d12 → d12+1).
+    MaxDieIncrease,
+    /// Derives Parry from the skill named in `target_identifier` instead of Fighting.
+    ParrySkill,
 }
 
 impl FromStr for ModifierValueType {
@@ -25,6 +31,9 @@ impl FromStr for ModifierValueType {
             "description" => Ok(ModifierValueType::Description),
             "bonus_selection" => Ok(ModifierValueType::BonusSelection),
             "mandatory_selection" => Ok(ModifierValueType::MandatorySelection),
+            "grant_skill" => Ok(ModifierValueType::GrantSkill),
+            "max_die_increase" => Ok(ModifierValueType::MaxDieIncrease),
+            "parry_skill" => Ok(ModifierValueType::ParrySkill),
             _ => Err(format!("Invalid modifier value type: {}", s)),
         }
     }
@@ -39,6 +48,9 @@ impl fmt::Display for ModifierValueType {
             ModifierValueType::Description => write!(f, "description"),
             ModifierValueType::BonusSelection => write!(f, "bonus_selection"),
             ModifierValueType::MandatorySelection => write!(f, "mandatory_selection"),
+            ModifierValueType::GrantSkill => write!(f, "grant_skill"),
+            ModifierValueType::MaxDieIncrease => write!(f, "max_die_increase"),
+            ModifierValueType::ParrySkill => write!(f, "parry_skill"),
         }
     }
 }
@@ -73,6 +85,18 @@ mod tests {
             ModifierValueType::from_str("mandatory_selection").unwrap(),
             ModifierValueType::MandatorySelection
         );
+        assert_eq!(
+            ModifierValueType::from_str("grant_skill").unwrap(),
+            ModifierValueType::GrantSkill
+        );
+        assert_eq!(
+            ModifierValueType::from_str("max_die_increase").unwrap(),
+            ModifierValueType::MaxDieIncrease
+        );
+        assert_eq!(
+            ModifierValueType::from_str("parry_skill").unwrap(),
+            ModifierValueType::ParrySkill
+        );
     }
 
     #[test]
@@ -97,6 +121,9 @@ mod tests {
         assert_eq!(ModifierValueType::DieIncrement.to_string(), "die_increment");
         assert_eq!(ModifierValueType::RollBonus.to_string(), "roll_bonus");
         assert_eq!(ModifierValueType::FlatBonus.to_string(), "flat_bonus");
+        assert_eq!(ModifierValueType::GrantSkill.to_string(), "grant_skill");
+        assert_eq!(ModifierValueType::MaxDieIncrease.to_string(), "max_die_increase");
+        assert_eq!(ModifierValueType::ParrySkill.to_string(), "parry_skill");
     }
 
     #[test]
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::Modifier;
+
+/// A modifier whose target no longer matches any attribute, skill, or derived
+/// stat, so it silently applies to nothing (e.g. after a skill was renamed).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct OrphanedModifier {
+    /// Kind of source: "direct", "ancestry", "edge", "hindrance", or "gear".
+    pub source_type: String,
+    /// Display label for the source (edge name, gear name, etc.).
+    pub source_name: String,
+    pub target_type: String,
+    pub target_identifier: String,
+    pub description: String,
+}
+
+impl OrphanedModifier {
+    pub fn new(source_type: &str, source_name: &str, modifier: &Modifier) -> Self {
+        Self {
+            source_type: source_type.to_string(),
+            source_name: source_name.to_string(),
+            target_type: modifier.target_type.clone().unwrap_or_default(),
+            target_identifier: modifier.target_identifier.clone().unwrap_or_default(),
+            description: modifier.description.clone(),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+/// A choice that still needs selections before the character is complete.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct PendingChoice {
+    pub choice_id: i64,
+    pub description: String,
+    pub min_selections: i64,
+    pub max_selections: i64,
+    /// Selections made so far.
+    pub selected: i64,
+    /// Selections still required to reach `min_selections`.
+    pub remaining: i64,
+}
+
+impl PendingChoice {
+    /// Build a pending choice, or None if `selected` already meets `min_selections`.
+    pub fn from_counts(
+        choice_id: i64,
+        description: String,
+        min_selections: i64,
+        max_selections: i64,
+        selected: i64,
+    ) -> Option<Self> {
+        let remaining = min_selections - selected;
+        (remaining > 0).then_some(Self {
+            choice_id,
+            description,
+            min_selections,
+            max_selections,
+            selected,
+            remaining,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_counts_reports_remaining() {
+        let pending = PendingChoice::from_counts(1, "Pick two".to_string(), 2, 3, 1).unwrap();
+
+        assert_eq!(pending.remaining, 1);
+        assert_eq!(pending.max_selections, 3);
+    }
+
+    #[test]
+    fn from_counts_satisfied_is_none() {
+        assert!(PendingChoice::from_counts(1, "Pick one".to_string(), 1, 1, 1).is_none());
+        assert!(PendingChoice::from_counts(1, "Optional".to_string(), 0, 1, 0).is_none());
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+/// A separate power point pool belonging to one of a character's arcane backgrounds.
+///
+/// Characters with more than one arcane background track power points per
+/// background rather than in a single shared pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct PowerPointPool {
+    pub arcane_background_id: i64,
+    pub arcane_background_name: String,
+    /// Maximum power points in this pool.
+    pub max: i64,
+    /// Power points currently spent from this pool.
+    pub used: i64,
+}
+
+impl PowerPointPool {
+    pub fn new(
+        arcane_background_id: i64,
+        arcane_background_name: String,
+        max: i64,
+        used: i64,
+    ) -> Self {
+        Self {
+            arcane_background_id,
+            arcane_background_name,
+            max,
+            used,
+        }
+    }
+
+    /// Power points still available to spend from this pool.
+    pub fn remaining(&self) -> i64 {
+        (self.max - self.used).max(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remaining() {
+        let pool = PowerPointPool::new(1, "Magic".to_string(), 10, 4);
+        assert_eq!(pool.remaining(), 6);
+    }
+
+    #[test]
+    fn test_remaining_never_negative() {
+        let pool = PowerPointPool::new(1, "Magic".to_string(), 10, 12);
+        assert_eq!(pool.remaining(), 0);
+    }
+}
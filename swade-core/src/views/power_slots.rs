@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// Starting power slots: how many powers a character may choose and how many
+/// are already taken.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct PowerSlots {
+    /// Slots from arcane backgrounds plus bonus slots from edges like New Powers.
+    pub total: i64,
+    pub used: i64,
+    pub remaining: i64,
+}
+
+impl PowerSlots {
+    pub fn new(total: i64, used: i64) -> Self {
+        Self {
+            total,
+            used,
+            remaining: (total - used).max(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remaining() {
+        let slots = PowerSlots::new(3, 2);
+        assert_eq!(slots.remaining, 1);
+    }
+
+    #[test]
+    fn test_remaining_never_negative() {
+        let slots = PowerSlots::new(2, 3);
+        assert_eq!(slots.remaining, 0);
+    }
+}
@@ -7,7 +7,11 @@ use crate::views::RequirementTree;
 pub struct PowerView {
     pub id: i64,
     pub name: String,
+    /// Power points to cast.
     pub power_points: i64,
+    /// Power points to extend the duration. None when the cost isn't recorded
+    /// for this power, which the UI shows as unknown.
+    pub maintenance_power_points: Option<i64>,
     pub range: String,
     pub duration: String,
     pub source: String,
@@ -23,6 +23,10 @@ pub struct RequirementStatus {
     pub description: String,
     /// Whether this requirement is met
     pub is_met: bool,
+    /// Character's current value (die size or rank ID) for trait and rank requirements
+    pub current_value: Option<i64>,
+    /// Value the requirement asks for (die size or rank ID)
+    pub required_value: Option<i64>,
 }
 
 /// Context for evaluating requirements against a character.
@@ -110,6 +114,65 @@ impl RequirementTree {
         }
     }
 
+    /// Get the minimum rank ID a character needs to satisfy this tree.
+    ///
+    /// Takes the highest rank across AND branches and the lowest across OR
+    /// branches. Returns None when no rank requirement applies. Rank
+    /// requirements inside a NOT node are ignored.
+    pub fn min_rank(&self) -> Option<i64> {
+        match &self.node {
+            RequirementNode::And(children) => children.iter().filter_map(|c| c.min_rank()).max(),
+            RequirementNode::Or(children) => {
+                let ranks: Vec<Option<i64>> = children.iter().map(|c| c.min_rank()).collect();
+                // Any branch without a rank gate means no minimum rank
+                if ranks.is_empty() || ranks.iter().any(|r| r.is_none()) {
+                    None
+                } else {
+                    ranks.into_iter().flatten().min()
+                }
+            }
+            RequirementNode::Not(_) => None,
+            RequirementNode::Leaf(req) if req.requirement_type == "rank" => req.target_id,
+            RequirementNode::Leaf(_) => None,
+        }
+    }
+
+    /// Evaluate only the rank requirements in this tree.
+    ///
+    /// All other requirement types are ignored, so a tree with no rank
+    /// requirements is always satisfied.
+    pub fn evaluate_rank(&self, ctx: &RequirementContext) -> bool {
+        self.evaluate_rank_node(ctx).unwrap_or(true)
+    }
+
+    /// Evaluate rank requirements, returning None for subtrees without any.
+    fn evaluate_rank_node(&self, ctx: &RequirementContext) -> Option<bool> {
+        match &self.node {
+            RequirementNode::And(children) => {
+                let results: Vec<bool> = children
+                    .iter()
+                    .filter_map(|c| c.evaluate_rank_node(ctx))
+                    .collect();
+                (!results.is_empty()).then(|| results.iter().all(|&r| r))
+            }
+            RequirementNode::Or(children) => {
+                let results: Vec<Option<bool>> =
+                    children.iter().map(|c| c.evaluate_rank_node(ctx)).collect();
+                if results.iter().all(|r| r.is_none()) {
+                    None
+                } else {
+                    // A branch without rank requirements can always satisfy the OR
+                    Some(results.iter().any(|r| r.unwrap_or(true)))
+                }
+            }
+            RequirementNode::Not(child) => child.evaluate_rank_node(ctx).map(|r| !r),
+            RequirementNode::Leaf(req) if req.requirement_type == "rank" => {
+                Some(Self::evaluate_requirement(req, ctx))
+            }
+            RequirementNode::Leaf(_) => None,
+        }
+    }
+
     /// Evaluate and return detailed status of all leaf requirements.
     /// For AND nodes, returns all children's statuses.
     /// For OR nodes, returns all children's statuses.
@@ -143,14 +206,52 @@ impl RequirementTree {
                 if negated {
                     is_met = !is_met;
                 }
+                let (current_value, required_value) = Self::requirement_values(req, ctx);
                 statuses.push(RequirementStatus {
                     description: req.description.clone(),
                     is_met,
+                    current_value,
+                    required_value,
                 });
             }
         }
     }
 
+    /// Get the character's current value and the required value for a requirement.
+    ///
+    /// Only attribute, skill, arcane skill, and rank requirements have numeric
+    /// values; an untrained skill has no current value.
+    fn requirement_values(
+        req: &Requirement,
+        ctx: &RequirementContext,
+    ) -> (Option<i64>, Option<i64>) {
+        match req.requirement_type.as_str() {
+            "rank" => (Some(ctx.rank_id), req.target_id),
+            "attribute" => {
+                let current = req
+                    .target_id
+                    .and_then(|id| ctx.attribute_dies.get(&id))
+                    .map(|&die| die as i64);
+                (current, req.value)
+            }
+            "skill" => {
+                let current = req
+                    .target_id
+                    .and_then(|id| ctx.skill_dies.get(&id).copied().flatten())
+                    .map(|die| die as i64);
+                (current, req.value)
+            }
+            "arcane_skill" => {
+                let current = req
+                    .target_id
+                    .and_then(|id| ctx.arcane_skill_dies.get(&id).copied().flatten())
+                    .map(|die| die as i64);
+                (current, req.value)
+            }
+            _ => (None, None),
+        }
+    }
+
     /// Evaluate a single requirement against the context.
     fn evaluate_requirement(req: &Requirement, ctx: &RequirementContext) -> bool {
         match req.requirement_type.as_str() {
@@ -243,6 +344,7 @@ mod tests {
             id: 1,
             requirement_type: "rank".to_string(),
             target_id: Some(1),
+            target_name: None,
             value: Some(1),
             description: description.to_string(),
             created_at: "2024-01-01".to_string(),
@@ -297,4 +399,223 @@ mod tests {
 
         assert!(!tree.is_empty());
     }
+
+    fn create_requirement(requirement_type: &str, target_id: i64, value: i64) -> Requirement {
+        Requirement {
+            id: 1,
+            requirement_type: requirement_type.to_string(),
+            target_id: Some(target_id),
+            target_name: None,
+            value: Some(value),
+            description: format!("{} {}", requirement_type, target_id),
+            created_at: "2024-01-01".to_string(),
+            updated_at: "2024-01-01".to_string(),
+        }
+    }
+
+    fn create_context(rank_id: i64) -> RequirementContext {
+        RequirementContext {
+            rank_id,
+            is_wild_card: true,
+            attribute_dies: std::collections::HashMap::new(),
+            skill_dies: std::collections::HashMap::new(),
+            edge_ids: std::collections::HashSet::new(),
+            arcane_background_ids: std::collections::HashSet::new(),
+            arcane_skill_dies: std::collections::HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn evaluate_rank_ignores_other_requirements() {
+        // Seasoned + Agility d8 (character has no Agility at all)
+        let tree = RequirementTree::and(vec![
+            RequirementTree::leaf(create_requirement("rank", 2, 2)),
+            RequirementTree::leaf(create_requirement("attribute", 1, 8)),
+        ]);
+
+        assert!(tree.evaluate_rank(&create_context(2)));
+        assert!(!tree.evaluate(&create_context(2)));
+        assert!(!tree.evaluate_rank(&create_context(1)));
+    }
+
+    #[test]
+    fn evaluate_rank_without_rank_requirements_is_met() {
+        let tree = RequirementTree::leaf(create_requirement("edge", 5, 1));
+
+        assert!(tree.evaluate_rank(&create_context(1)));
+        assert!(RequirementTree::none().evaluate_rank(&create_context(1)));
+    }
+
+    #[test]
+    fn evaluate_rank_or_branch_without_rank_is_met() {
+        // Veteran OR some edge: the edge branch has no rank gate
+        let tree = RequirementTree::or(vec![
+            RequirementTree::leaf(create_requirement("rank", 3, 3)),
+            RequirementTree::leaf(create_requirement("edge", 5, 1)),
+        ]);
+
+        assert!(tree.evaluate_rank(&create_context(1)));
+    }
+
+    #[test]
+    fn evaluate_rank_or_of_ranks_uses_lowest() {
+        let tree = RequirementTree::or(vec![
+            RequirementTree::leaf(create_requirement("rank", 3, 3)),
+            RequirementTree::leaf(create_requirement("rank", 2, 2)),
+        ]);
+
+        assert!(!tree.evaluate_rank(&create_context(1)));
+        assert!(tree.evaluate_rank(&create_context(2)));
+    }
+
+    #[test]
+    fn min_rank_single_leaf() {
+        let tree = RequirementTree::leaf(create_requirement("rank", 2, 2));
+
+        assert_eq!(tree.min_rank(), Some(2));
+    }
+
+    #[test]
+    fn min_rank_none_without_rank_requirements() {
+        let tree = RequirementTree::and(vec![
+            RequirementTree::leaf(create_requirement("attribute", 1, 8)),
+            RequirementTree::leaf(create_requirement("edge", 5, 1)),
+        ]);
+
+        assert_eq!(tree.min_rank(), None);
+        assert_eq!(RequirementTree::none().min_rank(), None);
+    }
+
+    #[test]
+    fn min_rank_takes_highest_across_and() {
+        let tree = RequirementTree::and(vec![
+            RequirementTree::leaf(create_requirement("rank", 2, 2)),
+            RequirementTree::leaf(create_requirement("attribute", 1, 8)),
+            RequirementTree::leaf(create_requirement("rank", 3, 3)),
+        ]);
+
+        assert_eq!(tree.min_rank(), Some(3));
+    }
+
+    #[test]
+    fn min_rank_takes_lowest_across_or() {
+        let tree = RequirementTree::or(vec![
+            RequirementTree::leaf(create_requirement("rank", 4, 4)),
+            RequirementTree::leaf(create_requirement("rank", 2, 2)),
+        ]);
+
+        assert_eq!(tree.min_rank(), Some(2));
+    }
+
+    #[test]
+    fn min_rank_or_with_ungated_branch_is_none() {
+        let tree = RequirementTree::or(vec![
+            RequirementTree::leaf(create_requirement("rank", 3, 3)),
+            RequirementTree::leaf(create_requirement("edge", 5, 1)),
+        ]);
+
+        assert_eq!(tree.min_rank(), None);
+    }
+
+    #[test]
+    fn min_rank_nested_and_or() {
+        // Seasoned AND (Veteran OR (Heroic AND Fighting d8))
+        let tree = RequirementTree::and(vec![
+            RequirementTree::leaf(create_requirement("rank", 2, 2)),
+            RequirementTree::or(vec![
+                RequirementTree::leaf(create_requirement("rank", 3, 3)),
+                RequirementTree::and(vec![
+                    RequirementTree::leaf(create_requirement("rank", 4, 4)),
+                    RequirementTree::leaf(create_requirement("skill", 1, 8)),
+                ]),
+            ]),
+        ]);
+
+        assert_eq!(tree.min_rank(), Some(3));
+    }
+
+    #[test]
+    fn min_rank_nested_or_of_ands() {
+        // (Veteran AND Novice) OR (Heroic AND Seasoned)
+        let tree = RequirementTree::or(vec![
+            RequirementTree::and(vec![
+                RequirementTree::leaf(create_requirement("rank", 3, 3)),
+                RequirementTree::leaf(create_requirement("rank", 1, 1)),
+            ]),
+            RequirementTree::and(vec![
+                RequirementTree::leaf(create_requirement("rank", 4, 4)),
+                RequirementTree::leaf(create_requirement("rank", 2, 2)),
+            ]),
+        ]);
+
+        assert_eq!(tree.min_rank(), Some(3));
+    }
+
+    #[test]
+    fn min_rank_ignores_negated_rank() {
+        let tree = RequirementTree::and(vec![
+            RequirementTree::leaf(create_requirement("rank", 2, 2)),
+            RequirementTree::negate(RequirementTree::leaf(create_requirement("rank", 4, 4))),
+        ]);
+
+        assert_eq!(tree.min_rank(), Some(2));
+    }
+
+    #[test]
+    fn evaluate_detailed_fills_values_for_unmet_attribute() {
+        let tree = RequirementTree::leaf(create_requirement("attribute", 1, 8));
+        let mut ctx = create_context(1);
+        ctx.attribute_dies.insert(1, 6);
+
+        let statuses = tree.evaluate_detailed(&ctx);
+
+        assert_eq!(statuses.len(), 1);
+        assert!(!statuses[0].is_met);
+        assert_eq!(statuses[0].current_value, Some(6));
+        assert_eq!(statuses[0].required_value, Some(8));
+    }
+
+    #[test]
+    fn evaluate_detailed_fills_values_for_unmet_skill() {
+        let tree = RequirementTree::and(vec![
+            RequirementTree::leaf(create_requirement("skill", 1, 8)),
+            RequirementTree::leaf(create_requirement("skill", 2, 6)),
+        ]);
+        let mut ctx = create_context(1);
+        ctx.skill_dies.insert(1, Some(4));
+        ctx.skill_dies.insert(2, None);
+
+        let statuses = tree.evaluate_detailed(&ctx);
+
+        assert!(!statuses[0].is_met);
+        assert_eq!(statuses[0].current_value, Some(4));
+        assert_eq!(statuses[0].required_value, Some(8));
+        // Untrained skill has no current die
+        assert!(!statuses[1].is_met);
+        assert_eq!(statuses[1].current_value, None);
+        assert_eq!(statuses[1].required_value, Some(6));
+    }
+
+    #[test]
+    fn evaluate_detailed_fills_values_for_rank() {
+        let tree = RequirementTree::leaf(create_requirement("rank", 3, 3));
+
+        let statuses = tree.evaluate_detailed(&create_context(2));
+
+        assert!(!statuses[0].is_met);
+        assert_eq!(statuses[0].current_value, Some(2));
+        assert_eq!(statuses[0].required_value, Some(3));
+    }
+
+    #[test]
+    fn evaluate_detailed_no_values_for_edge() {
+        let tree = RequirementTree::leaf(create_requirement("edge", 5, 1));
+
+        let statuses = tree.evaluate_detailed(&create_context(1));
+
+        assert!(!statuses[0].is_met);
+        assert_eq!(statuses[0].description, "edge 5");
+        assert_eq!(statuses[0].current_value, None);
+        assert_eq!(statuses[0].required_value, None);
+    }
 }
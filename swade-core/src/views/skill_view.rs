@@ -9,6 +9,7 @@ pub struct SkillView {
     pub name: String,
     pub description: String,
     pub linked_attribute_id: i64,
+    pub linked_attribute_name: String,
     pub is_core_skill: bool,
     pub default_die: Option<Die>,
     pub max_die: Die,
@@ -16,10 +17,18 @@ pub struct SkillView {
 }
 
 impl SkillView {
-    pub fn new(skill: Skill) -> Self {
-        let default_die = skill.default_die_size.and_then(|size| Die::new(size as u8));
-
-        let max_die = Die::with_modifier(skill.max_die_size as u8, skill.max_die_modifier as u8)
+    /// Build a view of `skill`. The caller supplies the linked attribute's
+    /// name so a list of skills can share one attribute lookup.
+    pub fn new(skill: Skill, linked_attribute_name: String) -> Self {
+        let default_die = skill
+            .default_die_size
+            .and_then(|size| u8::try_from(size).ok())
+            .and_then(Die::new);
+
+        let max_die = u8::try_from(skill.max_die_size)
+            .ok()
+            .zip(u8::try_from(skill.max_die_modifier).ok())
+            .and_then(|(size, modifier)| Die::with_modifier(size, modifier))
             .unwrap_or(Die::d12());
 
         Self {
@@ -27,6 +36,7 @@ impl SkillView {
             name: skill.name,
             description: skill.description,
             linked_attribute_id: skill.linked_attribute_id,
+            linked_attribute_name,
             is_core_skill: skill.is_core_skill,
             default_die,
             max_die,
@@ -58,7 +68,7 @@ mod tests {
     #[test]
     fn new_core_skill_has_default_die() {
         let skill = create_test_skill(true, Some(4));
-        let view = SkillView::new(skill);
+        let view = SkillView::new(skill, "Agility".to_string());
 
         assert_eq!(view.id, 1);
         assert_eq!(view.name, "Athletics");
@@ -69,7 +79,7 @@ mod tests {
     #[test]
     fn new_non_core_skill_has_no_default_die() {
         let skill = create_test_skill(false, None);
-        let view = SkillView::new(skill);
+        let view = SkillView::new(skill, "Agility".to_string());
 
         assert_eq!(view.is_core_skill, false);
         assert_eq!(view.default_die, None);
@@ -78,7 +88,7 @@ mod tests {
     #[test]
     fn new_converts_max_die() {
         let skill = create_test_skill(true, Some(4));
-        let view = SkillView::new(skill);
+        let view = SkillView::new(skill, "Agility".to_string());
 
         assert_eq!(view.max_die, Die::d12());
     }
@@ -89,7 +99,7 @@ mod tests {
         skill.max_die_size = 12;
         skill.max_die_modifier = 2;
 
-        let view = SkillView::new(skill);
+        let view = SkillView::new(skill, "Agility".to_string());
 
         assert_eq!(view.max_die, Die::with_modifier(12, 2).unwrap());
     }
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::constants::KILOGRAMS_PER_POUND;
+
+/// Unit gear weights are shown in. Weights are stored in pounds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "lowercase")]
+pub enum WeightUnit {
+    #[default]
+    Lb,
+    Kg,
+}
+
+impl WeightUnit {
+    /// Convert a weight in pounds to this unit.
+    pub fn from_pounds(&self, pounds: f64) -> f64 {
+        match self {
+            WeightUnit::Lb => pounds,
+            WeightUnit::Kg => pounds * KILOGRAMS_PER_POUND,
+        }
+    }
+
+    /// Format a weight in pounds for display in this unit, e.g. "6 lb" or
+    /// "2.7 kg". Shows at most one decimal place.
+    pub fn format(&self, pounds: f64) -> String {
+        let value = format!("{:.1}", self.from_pounds(pounds));
+        let value = value.strip_suffix(".0").unwrap_or(&value);
+        format!("{} {}", value, self)
+    }
+}
+
+impl FromStr for WeightUnit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "lb" => Ok(WeightUnit::Lb),
+            "kg" => Ok(WeightUnit::Kg),
+            _ => Err(format!("Invalid weight unit: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for WeightUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightUnit::Lb => write!(f, "lb"),
+            WeightUnit::Kg => write!(f, "kg"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_drops_trailing_zero() {
+        assert_eq!(WeightUnit::Lb.format(6.0), "6 lb");
+        assert_eq!(WeightUnit::Lb.format(0.5), "0.5 lb");
+    }
+
+    #[test]
+    fn format_converts_to_kilograms() {
+        assert_eq!(WeightUnit::Kg.format(6.0), "2.7 kg");
+        assert_eq!(WeightUnit::Kg.format(0.0), "0 kg");
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let json = serde_json::to_string(&WeightUnit::Kg).unwrap();
+        assert_eq!(json, "\"kg\"");
+        let parsed: WeightUnit = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, WeightUnit::Kg);
+    }
+}
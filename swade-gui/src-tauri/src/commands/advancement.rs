@@ -2,7 +2,10 @@
 
 use std::sync::Mutex;
 use swade_core::services::AdvancementService;
-use swade_core::views::{AdvancementOptions, CharacterAdvanceValue};
+use swade_core::views::{
+    AdvancePlanStep, AdvancementOptions, AdvancementTimelineEntry, AppliedAdvance, CharacterAdvanceValue,
+    CharacterView, EdgeView, PlannedAdvance,
+};
 use tauri::State;
 
 use crate::error::CommandResult;
@@ -20,12 +23,28 @@ pub fn get_advancement_options(
     Ok(AdvancementService::get_advancement_options(&conn, character_id)?)
 }
 
+/// Get the edges a saved character can take as their next advance.
+///
+/// Unlike `get_edges`, which checks against the draft, this uses the
+/// persisted character and leaves out edges that can't be taken.
+#[tauri::command]
+#[specta::specta]
+pub fn get_takeable_edges(
+    character_id: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<EdgeView>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(AdvancementService::get_takeable_edges(&conn, character_id)?)
+}
+
 /// Take an edge as an advancement.
 #[tauri::command]
 #[specta::specta]
 pub fn take_edge_advance(
     character_id: i64,
     edge_id: i64,
+    arcane_background_id: Option<i64>,
     notes: Option<String>,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterAdvanceValue> {
@@ -35,6 +54,7 @@ pub fn take_edge_advance(
         &conn,
         character_id,
         edge_id,
+        arcane_background_id,
         notes,
     )?)
 }
@@ -45,6 +65,7 @@ pub fn take_edge_advance(
 pub fn take_attribute_advance(
     character_id: i64,
     attribute_id: i64,
+    notes: Option<String>,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterAdvanceValue> {
     let state = lock_state(&state)?;
@@ -53,6 +74,7 @@ pub fn take_attribute_advance(
         &conn,
         character_id,
         attribute_id,
+        notes,
     )?)
 }
 
@@ -62,6 +84,7 @@ pub fn take_attribute_advance(
 pub fn take_expensive_skill_advance(
     character_id: i64,
     skill_id: i64,
+    notes: Option<String>,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterAdvanceValue> {
     let state = lock_state(&state)?;
@@ -70,6 +93,7 @@ pub fn take_expensive_skill_advance(
         &conn,
         character_id,
         skill_id,
+        notes,
     )?)
 }
 
@@ -80,6 +104,7 @@ pub fn take_cheap_skill_advance(
     character_id: i64,
     skill_id_1: i64,
     skill_id_2: i64,
+    notes: Option<String>,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterAdvanceValue> {
     let state = lock_state(&state)?;
@@ -89,17 +114,19 @@ pub fn take_cheap_skill_advance(
         character_id,
         skill_id_1,
         skill_id_2,
+        notes,
     )?)
 }
 
 /// Take a hindrance modification as an advancement.
-/// Actions: "remove_minor", "reduce_major", "remove_major_half"
+/// Actions: "remove_minor", "reduce_major", "remove_major_half" (the completing half is detected automatically)
 #[tauri::command]
 #[specta::specta]
 pub fn take_hindrance_advance(
     character_id: i64,
     hindrance_id: i64,
     action: String,
+    notes: Option<String>,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterAdvanceValue> {
     let state = lock_state(&state)?;
@@ -109,6 +136,103 @@ pub fn take_hindrance_advance(
         character_id,
         hindrance_id,
         &action,
+        notes,
+    )?)
+}
+
+/// Take any kind of advance and return it with the updated character, so the
+/// new dice and derived stats arrive without another fetch.
+#[tauri::command]
+#[specta::specta]
+pub fn take_advance(
+    character_id: i64,
+    advance: PlannedAdvance,
+    notes: Option<String>,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<AppliedAdvance> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(AdvancementService::take_advance(&conn, character_id, &advance, notes)?)
+}
+
+/// Validate a single advance and describe it without saving it.
+#[tauri::command]
+#[specta::specta]
+pub fn check_advance(
+    character_id: i64,
+    advance: PlannedAdvance,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterAdvanceValue> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(AdvancementService::check_advance(&conn, character_id, &advance)?)
+}
+
+/// Add experience points to a saved character.
+#[tauri::command]
+#[specta::specta]
+pub fn award_xp(
+    character_id: i64,
+    amount: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+    let character = AdvancementService::award_xp(&conn, character_id, amount)?;
+    state.sync_draft(&character, |draft, saved| {
+        draft.experience_points = saved.experience_points;
+        draft.current_advances = saved.current_advances;
+    });
+    Ok(character)
+}
+
+/// Take experience points back off a saved character.
+#[tauri::command]
+#[specta::specta]
+pub fn spend_xp(
+    character_id: i64,
+    amount: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+    let character = AdvancementService::spend_xp(&conn, character_id, amount)?;
+    state.sync_draft(&character, |draft, saved| {
+        draft.experience_points = saved.experience_points;
+        draft.current_advances = saved.current_advances;
+    });
+    Ok(character)
+}
+
+/// How many more advances until an edge can be taken, when only rank is
+/// holding it back. Returns None if other requirements are unmet.
+#[tauri::command]
+#[specta::specta]
+pub fn advances_until_available(
+    character_id: i64,
+    edge_id: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Option<i64>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(AdvancementService::advances_until_available(&conn, character_id, edge_id)?)
+}
+
+/// Check a sequence of planned advances without saving them.
+/// Returns the outcome and resulting rank of each step.
+#[tauri::command]
+#[specta::specta]
+pub fn preview_advancement_plan(
+    character_id: i64,
+    plan: Vec<PlannedAdvance>,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<AdvancePlanStep>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(AdvancementService::preview_advancement_plan(
+        &conn,
+        character_id,
+        &plan,
     )?)
 }
 
@@ -136,3 +260,35 @@ pub fn get_advancement_history(
     let conn = state.connection()?;
     Ok(AdvancementService::get_advancement_history(&conn, character_id)?)
 }
+
+/// Get a character's advances in order, labelled with their rank, for a
+/// progression timeline.
+#[tauri::command]
+#[specta::specta]
+pub fn export_advancement_timeline(
+    character_id: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<AdvancementTimelineEntry>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(AdvancementService::export_advancement_timeline(&conn, character_id)?)
+}
+
+/// Replace the notes on an advance the character has already taken.
+#[tauri::command]
+#[specta::specta]
+pub fn update_advance_notes(
+    character_id: i64,
+    advance_id: i64,
+    notes: Option<String>,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterAdvanceValue> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(AdvancementService::update_advance_notes(
+        &conn,
+        character_id,
+        advance_id,
+        notes,
+    )?)
+}
@@ -3,13 +3,20 @@
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use std::sync::Mutex;
 use swade_core::repositories::CharacterRepository;
-use swade_core::services::CharacterService;
-use swade_core::views::CharacterView;
+use swade_core::services::{
+    ArchetypeService, CharacterService, FoundryImportService, QuickBuildService, SnapshotService,
+};
+use swade_core::views::{
+    ArchetypeView, CharacterConfig, CharacterDiff, CharacterSnapshotValue, CharacterView,
+    DraftValidation, OrphanedModifier,
+};
 use tauri::State;
 
 use crate::error::{CommandError, CommandResult};
 use crate::state::{lock_state, AppState};
 
+use super::types::{DraftResult, SkillRepairResult, ValidationWarning};
+
 #[tauri::command]
 #[specta::specta]
 pub fn get_characters(state: State<Mutex<AppState>>) -> CommandResult<Vec<CharacterView>> {
@@ -26,16 +33,68 @@ pub fn get_character(id: i64, state: State<Mutex<AppState>>) -> CommandResult<Op
     Ok(CharacterService::get_by_id(&conn, id)?)
 }
 
+/// Compare two saved characters, reporting what `after_id` has added, removed,
+/// or changed relative to `before_id`.
+#[tauri::command]
+#[specta::specta]
+pub fn diff_characters(
+    before_id: i64,
+    after_id: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterDiff> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(CharacterService::diff(&conn, before_id, after_id)?)
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn create_character(name: String, state: State<Mutex<AppState>>) -> CommandResult<CharacterView> {
     let mut state = lock_state(&state)?;
     let conn = state.connection()?;
     let character = CharacterService::build_new(&conn, name)?;
-    state.draft_character = Some(character.clone());
+    state.set_draft(Some(character.clone()));
     Ok(character)
 }
 
+/// Get the archetype templates quick build can start from.
+#[tauri::command]
+#[specta::specta]
+pub fn get_archetypes(state: State<Mutex<AppState>>) -> CommandResult<Vec<ArchetypeView>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(ArchetypeService::get_all(&conn)?)
+}
+
+/// Start a draft with a legal starting character built from an archetype
+/// template (the first archetype when no key is given).
+#[tauri::command]
+#[specta::specta]
+pub fn quick_build_character(
+    name: String,
+    archetype_key: Option<String>,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+    let character = QuickBuildService::build(&conn, name, archetype_key.as_deref())?;
+    state.set_draft(Some(character.clone()));
+    Ok(character)
+}
+
+/// Create a new character from a Foundry VTT `swade` actor JSON export.
+/// Items that can't be matched are listed in a note on the new character.
+#[tauri::command]
+#[specta::specta]
+pub fn import_character_foundry(
+    json: String,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(FoundryImportService::import(&conn, &json)?)
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn get_draft_character(state: State<Mutex<AppState>>) -> CommandResult<Option<CharacterView>> {
@@ -43,15 +102,36 @@ pub fn get_draft_character(state: State<Mutex<AppState>>) -> CommandResult<Optio
     Ok(state.draft_character.clone())
 }
 
+/// Check the draft against the character creation rules.
+///
+/// Unspent attribute and skill points come back as warnings; they don't
+/// block `save_character`.
+#[tauri::command]
+#[specta::specta]
+pub fn validate_draft(state: State<Mutex<AppState>>) -> CommandResult<DraftValidation> {
+    let state = lock_state(&state)?;
+    Ok(CharacterService::validate_draft(state.draft()?))
+}
+
 #[tauri::command]
 #[specta::specta]
-pub fn save_character(state: State<Mutex<AppState>>) -> CommandResult<CharacterView> {
+pub fn save_character(force: bool, state: State<Mutex<AppState>>) -> CommandResult<CharacterView> {
     let mut state = lock_state(&state)?;
     let draft = state.draft()?;
     let conn = state.connection()?;
+    let was_new = draft.id == 0;
+    // Refuse to overwrite a save made after this draft was loaded, unless forced
+    if !force {
+        CharacterService::check_not_stale(&conn, draft)?;
+    }
     let saved = CharacterService::save(&conn, draft)?;
-    // Update draft with saved version (now has an ID if it was new)
-    state.draft_character = Some(saved.clone());
+    // Update draft with saved version (now has an ID if it was new).
+    // Snapshots of a new character predate its ID, so they can't be restored.
+    if was_new {
+        state.set_draft(Some(saved.clone()));
+    } else {
+        state.draft_character = Some(saved.clone());
+    }
     Ok(saved)
 }
 
@@ -59,10 +139,56 @@ pub fn save_character(state: State<Mutex<AppState>>) -> CommandResult<CharacterV
 #[specta::specta]
 pub fn discard_draft(state: State<Mutex<AppState>>) -> CommandResult<()> {
     let mut state = lock_state(&state)?;
-    state.draft_character = None;
+    state.set_draft(None);
     Ok(())
 }
 
+/// Reset the draft to a fresh character, keeping its id and name.
+#[tauri::command]
+#[specta::specta]
+pub fn reset_draft(state: State<Mutex<AppState>>) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+    state.edit_draft(|draft| {
+        *draft = CharacterService::reset(&conn, draft)?;
+        Ok(draft.clone())
+    })
+}
+
+/// Revert the draft to how it was before the most recent edit.
+#[tauri::command]
+#[specta::specta]
+pub fn undo_draft_edit(state: State<Mutex<AppState>>) -> CommandResult<CharacterView> {
+    let mut guard = lock_state(&state)?;
+    let state = &mut *guard;
+    let draft = state
+        .draft_character
+        .as_mut()
+        .ok_or_else(|| CommandError::no_draft("No draft character".to_string()))?;
+    *draft = state
+        .draft_history
+        .undo(draft)
+        .ok_or_else(|| CommandError::validation("Nothing to undo".to_string()))?;
+    Ok(draft.clone())
+}
+
+/// Reapply the most recently undone draft edit.
+#[tauri::command]
+#[specta::specta]
+pub fn redo_draft_edit(state: State<Mutex<AppState>>) -> CommandResult<CharacterView> {
+    let mut guard = lock_state(&state)?;
+    let state = &mut *guard;
+    let draft = state
+        .draft_character
+        .as_mut()
+        .ok_or_else(|| CommandError::no_draft("No draft character".to_string()))?;
+    *draft = state
+        .draft_history
+        .redo(draft)
+        .ok_or_else(|| CommandError::validation("Nothing to redo".to_string()))?;
+    Ok(draft.clone())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn load_character_into_draft(
@@ -72,11 +198,15 @@ pub fn load_character_into_draft(
     let mut state = lock_state(&state)?;
     let conn = state.connection()?;
     let character = CharacterService::get_by_id(&conn, id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))?;
-    state.draft_character = Some(character.clone());
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
+    state.set_draft(Some(character.clone()));
     Ok(character)
 }
 
+/// Update the draft's name, Wild Card status, background and description.
+///
+/// The name is trimmed and must be non-blank and not overly long; background
+/// and description may be empty.
 #[tauri::command]
 #[specta::specta]
 pub fn update_draft_basic_info(
@@ -86,15 +216,200 @@ pub fn update_draft_basic_info(
     description: Option<String>,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterView> {
+    let name = CharacterService::normalize_name(&name)?;
     let mut state = lock_state(&state)?;
-    let draft = state.draft_mut()?;
+    state.edit_draft(|draft| {
+        draft.name = name;
+        draft.is_wild_card = is_wild_card;
+        draft.background = background;
+        draft.description = description;
 
-    draft.name = name;
-    draft.is_wild_card = is_wild_card;
-    draft.background = background;
-    draft.description = description;
+        Ok(draft.clone())
+    })
+}
 
-    Ok(draft.clone())
+/// Make a saved character a Wild Card or an Extra.
+///
+/// Warns about any held edges whose requirements are no longer met.
+#[tauri::command]
+#[specta::specta]
+pub fn set_wild_card(
+    id: i64,
+    is_wild_card: bool,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<DraftResult> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+    let (character, warnings) = CharacterService::set_wild_card(&conn, id, is_wild_card)?;
+
+    state.sync_draft(&character, |draft, saved| draft.is_wild_card = saved.is_wild_card);
+
+    let warnings = warnings
+        .into_iter()
+        .map(ValidationWarning::requirement_not_met)
+        .collect();
+    Ok(DraftResult::with_warnings(character, warnings))
+}
+
+/// Set a saved character's wounds, clamped to one past their maximum.
+#[tauri::command]
+#[specta::specta]
+pub fn set_wounds(
+    id: i64,
+    wounds: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+    let character = CharacterService::set_wounds(&conn, id, wounds)?;
+    state.sync_draft(&character, |draft, saved| draft.wounds = saved.wounds);
+    Ok(character)
+}
+
+/// Add (or heal, with a negative amount) wounds on a saved character.
+#[tauri::command]
+#[specta::specta]
+pub fn increment_wounds(
+    id: i64,
+    amount: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+    let character = CharacterService::increment_wounds(&conn, id, amount)?;
+    state.sync_draft(&character, |draft, saved| draft.wounds = saved.wounds);
+    Ok(character)
+}
+
+/// Set a saved character's Fatigue, clamped to one past Exhausted.
+#[tauri::command]
+#[specta::specta]
+pub fn set_fatigue(
+    id: i64,
+    fatigue: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+    let character = CharacterService::set_fatigue(&conn, id, fatigue)?;
+    state.sync_draft(&character, |draft, saved| draft.fatigue = saved.fatigue);
+    Ok(character)
+}
+
+/// Add (or recover, with a negative amount) Fatigue on a saved character.
+#[tauri::command]
+#[specta::specta]
+pub fn increment_fatigue(
+    id: i64,
+    amount: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+    let character = CharacterService::increment_fatigue(&conn, id, amount)?;
+    state.sync_draft(&character, |draft, saved| draft.fatigue = saved.fatigue);
+    Ok(character)
+}
+
+/// Add any current core skills a saved character is missing, e.g. after a
+/// data update added a new core skill.
+#[tauri::command]
+#[specta::specta]
+pub fn repair_character_skills(
+    id: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<SkillRepairResult> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    let (character, added_skills) = CharacterService::repair_character_skills(&conn, id)?;
+    Ok(SkillRepairResult {
+        character,
+        added_skills,
+    })
+}
+
+/// List a saved character's modifiers whose target attribute, skill, or
+/// derived stat no longer exists.
+#[tauri::command]
+#[specta::specta]
+pub fn audit_character_modifiers(
+    id: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<OrphanedModifier>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    let character = CharacterService::get_by_id(&conn, id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
+    Ok(character.orphaned_modifiers())
+}
+
+/// Replace the draft's house rule configuration.
+#[tauri::command]
+#[specta::specta]
+pub fn update_draft_config(
+    config: CharacterConfig,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    state.edit_draft(|draft| {
+        draft.config = config;
+        draft.compute_effective_values();
+
+        Ok(draft.clone())
+    })
+}
+
+/// Get all saved characters in a campaign.
+#[tauri::command]
+#[specta::specta]
+pub fn get_characters_by_campaign(
+    campaign: String,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<CharacterView>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(CharacterService::get_by_campaign(&conn, &campaign)?)
+}
+
+/// Rename a saved character without loading it into the draft.
+#[tauri::command]
+#[specta::specta]
+pub fn rename_character(
+    id: i64,
+    name: String,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+
+    let character = CharacterService::rename(&conn, id, &name)?;
+
+    // Keep an open draft of this character in sync so saving it doesn't
+    // trip the stale-draft check or undo the rename
+    if let Some(draft) = state.draft_character.as_mut().filter(|d| d.id == id) {
+        draft.name = character.name.clone();
+        draft.revision = character.revision;
+    }
+
+    Ok(character)
+}
+
+/// Set or clear a saved character's campaign.
+#[tauri::command]
+#[specta::specta]
+pub fn set_character_campaign(
+    id: i64,
+    campaign: Option<String>,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+
+    let character = CharacterService::set_campaign(&conn, id, campaign.as_deref())?;
+
+    state.sync_draft(&character, |draft, saved| draft.campaign = saved.campaign.clone());
+
+    Ok(character)
 }
 
 #[tauri::command]
@@ -106,28 +421,76 @@ pub fn update_character_status(
     power_points_used: i64,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterView> {
-    let state = lock_state(&state)?;
+    let mut state = lock_state(&state)?;
     let conn = state.connection()?;
 
-    // Get the character from the database
-    let mut character = CharacterRepository::get_by_id(&conn, id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))?;
+    // Wounds and fatigue go through the service so they're clamped
+    CharacterService::set_wounds(&conn, id, wounds)?;
+    CharacterService::set_fatigue(&conn, id, fatigue)?;
 
-    // Update the status fields
-    character.wounds = wounds;
-    character.fatigue = fatigue;
+    let mut character = CharacterRepository::get_by_id(&conn, id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
     character.power_points_used = power_points_used;
 
     // Save back to database
     CharacterRepository::update(&conn, &character)?;
 
-    // Return the full character view
-    CharacterService::get_by_id(&conn, id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found after update".to_string()))
+    let character = CharacterService::get_by_id(&conn, id)?
+        .ok_or_else(|| CommandError::not_found("Character not found after update".to_string()))?;
+
+    // Keep an open edit of this character in step, so saving it neither
+    // reverts the status nor trips the stale-save check
+    if let Some(draft) = state.draft_character.as_mut().filter(|d| d.id == id) {
+        draft.wounds = character.wounds;
+        draft.fatigue = character.fatigue;
+        draft.power_points_used = character.power_points_used;
+        draft.revision = character.revision;
+        draft.compute_effective_values();
+    }
+
+    Ok(character)
+}
+
+/// Update the power points spent from one arcane background's pool.
+#[tauri::command]
+#[specta::specta]
+pub fn update_power_point_pool(
+    id: i64,
+    arcane_background_id: i64,
+    power_points_used: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+
+    let character = CharacterService::update_power_points_used(
+        &conn,
+        id,
+        arcane_background_id,
+        power_points_used,
+    )?;
+    state.sync_draft(&character, |draft, saved| {
+        draft.arcane_backgrounds = saved.arcane_backgrounds.clone();
+    });
+    Ok(character)
+}
+
+/// Get a character's portrait as a data URL.
+/// Characters are loaded without their portraits, so views fetch it on demand.
+#[tauri::command]
+#[specta::specta]
+pub fn get_character_portrait(
+    id: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Option<String>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(CharacterService::get_portrait(&conn, id)?)
 }
 
 /// Update a character's portrait.
 /// Accepts base64-encoded image data and mime type (e.g., "image/png" or "image/jpeg").
+/// The image is downscaled and re-encoded as PNG before it is stored.
 #[tauri::command]
 #[specta::specta]
 pub fn update_character_portrait(
@@ -136,12 +499,12 @@ pub fn update_character_portrait(
     mime_type: String,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterView> {
-    let state = lock_state(&state)?;
+    let mut state = lock_state(&state)?;
     let conn = state.connection()?;
 
     // Validate mime type
     if mime_type != "image/png" && mime_type != "image/jpeg" {
-        return Err(CommandError::Validation(
+        return Err(CommandError::validation(
             "Portrait must be PNG or JPEG format".to_string(),
         ));
     }
@@ -149,21 +512,21 @@ pub fn update_character_portrait(
     // Decode base64 to bytes
     let portrait_bytes = BASE64
         .decode(&image_base64)
-        .map_err(|e| CommandError::Validation(format!("Invalid base64 image data: {}", e)))?;
+        .map_err(|e| CommandError::validation(format!("Invalid base64 image data: {}", e)))?;
 
     // Validate size (max ~2MB for a 1024x1024 image is reasonable)
     if portrait_bytes.len() > 2 * 1024 * 1024 {
-        return Err(CommandError::Validation(
+        return Err(CommandError::validation(
             "Portrait image is too large (max 2MB)".to_string(),
         ));
     }
 
-    // Update the portrait
-    CharacterRepository::update_portrait(&conn, id, Some(&portrait_bytes), Some(&mime_type))?;
-
-    // Return the updated character
-    CharacterService::get_by_id(&conn, id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))
+    // Normalize and store the portrait, returning the updated character
+    let character = CharacterService::update_portrait(&conn, id, &portrait_bytes)?;
+    state.sync_draft(&character, |draft, saved| {
+        draft.portrait_data_url = saved.portrait_data_url.clone();
+    });
+    Ok(character)
 }
 
 /// Clear a character's portrait.
@@ -173,22 +536,26 @@ pub fn clear_character_portrait(
     id: i64,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterView> {
-    let state = lock_state(&state)?;
+    let mut state = lock_state(&state)?;
     let conn = state.connection()?;
 
     // Clear the portrait
     CharacterRepository::update_portrait(&conn, id, None, None)?;
 
     // Return the updated character
-    CharacterService::get_by_id(&conn, id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))
+    let character = CharacterService::get_by_id(&conn, id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
+    state.sync_draft(&character, |draft, _| draft.portrait_data_url = None);
+    Ok(character)
 }
 
-/// Delete a character permanently.
+/// Delete a character. By default the character is archived so it can be
+/// restored later; pass `permanent` to delete it for good.
 #[tauri::command]
 #[specta::specta]
 pub fn delete_character(
     id: i64,
+    permanent: bool,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<()> {
     let mut state = lock_state(&state)?;
@@ -196,17 +563,82 @@ pub fn delete_character(
 
     // Check if character exists
     CharacterRepository::get_by_id(&conn, id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))?;
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
 
     // Clear draft if it's this character
     if let Some(ref draft) = state.draft_character {
         if draft.id == id {
-            state.draft_character = None;
+            state.set_draft(None);
         }
     }
 
-    // Delete the character (cascade deletes related records)
-    CharacterRepository::delete(&conn, id)?;
+    if permanent {
+        // Delete the character (cascade deletes related records, companions are kept)
+        CharacterService::delete(&conn, id)?;
+    } else {
+        CharacterService::archive(&conn, id)?;
+    }
 
     Ok(())
 }
+
+/// Get all archived characters.
+#[tauri::command]
+#[specta::specta]
+pub fn get_archived_characters(
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<CharacterView>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(CharacterService::get_archived(&conn)?)
+}
+
+/// Restore an archived character to the character list.
+#[tauri::command]
+#[specta::specta]
+pub fn restore_character(id: i64, state: State<Mutex<AppState>>) -> CommandResult<CharacterView> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(CharacterService::restore(&conn, id)?)
+}
+
+/// Save a labelled checkpoint of a character and its advance history.
+#[tauri::command]
+#[specta::specta]
+pub fn snapshot_character(
+    character_id: i64,
+    label: String,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterSnapshotValue> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(SnapshotService::snapshot(&conn, character_id, &label)?)
+}
+
+/// List a character's snapshots, newest first.
+#[tauri::command]
+#[specta::specta]
+pub fn list_snapshots(
+    character_id: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<CharacterSnapshotValue>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(SnapshotService::list(&conn, character_id)?)
+}
+
+/// Roll a character back to a snapshot, replacing the draft if it's the same
+/// character so it isn't left stale.
+#[tauri::command]
+#[specta::specta]
+pub fn restore_snapshot(snapshot_id: i64, state: State<Mutex<AppState>>) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+    let character = SnapshotService::restore(&conn, snapshot_id)?;
+
+    if state.draft_character.as_ref().is_some_and(|d| d.id == character.id) {
+        state.set_draft(Some(character.clone()));
+    }
+
+    Ok(character)
+}
@@ -0,0 +1,65 @@
+//! Companion linking commands.
+//!
+//! Links operate directly on saved characters, not drafts. The draft's companion
+//! list is refreshed when the draft is the parent.
+
+use std::sync::Mutex;
+use swade_core::services::CharacterService;
+use swade_core::views::CompanionSummary;
+use tauri::State;
+
+use crate::error::CommandResult;
+use crate::state::{lock_state, AppState};
+
+/// Keep the draft's companion list in sync after a link changes.
+fn refresh_draft_companions(state: &mut AppState, parent_id: i64, companions: &[CompanionSummary]) {
+    if let Some(draft) = state.draft_character.as_mut() {
+        if draft.id == parent_id {
+            draft.companions = companions.to_vec();
+        }
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_companions(
+    character_id: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<CompanionSummary>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(CharacterService::get_companions(&conn, character_id)?)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn link_companion(
+    parent_id: i64,
+    companion_id: i64,
+    relationship: String,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<CompanionSummary>> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+
+    let companions = CharacterService::link_companion(&conn, parent_id, companion_id, &relationship)?;
+    refresh_draft_companions(&mut state, parent_id, &companions);
+
+    Ok(companions)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn unlink_companion(
+    parent_id: i64,
+    companion_id: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<CompanionSummary>> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
+
+    let companions = CharacterService::unlink_companion(&conn, parent_id, companion_id)?;
+    refresh_draft_companions(&mut state, parent_id, &companions);
+
+    Ok(companions)
+}
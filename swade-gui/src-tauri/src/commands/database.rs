@@ -0,0 +1,51 @@
+//! Database maintenance commands.
+
+use std::sync::Mutex;
+use swade_core::db::{self, BackupInfo, CompactReport, IntegrityIssue};
+use tauri::State;
+
+use crate::error::CommandResult;
+use crate::state::{lock_state, AppState};
+
+/// List backups of the database, newest first.
+#[tauri::command]
+#[specta::specta]
+pub fn list_backups() -> CommandResult<Vec<BackupInfo>> {
+    Ok(db::list_backups()?)
+}
+
+/// Replace the database with a backup. Any open draft is discarded and
+/// cached reference data reloaded.
+#[tauri::command]
+#[specta::specta]
+pub fn restore_backup(file_name: String, state: State<Mutex<AppState>>) -> CommandResult<()> {
+    let mut state = lock_state(&state)?;
+
+    db::restore_backup(&file_name)?;
+    state.set_draft(None);
+    state.static_data.invalidate();
+
+    Ok(())
+}
+
+/// Run SQLite's integrity and foreign key checks. Returns an empty list when
+/// the database is clean.
+#[tauri::command]
+#[specta::specta]
+pub fn check_database_integrity(
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<IntegrityIssue>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(db::check_integrity(&conn)?)
+}
+
+/// Reclaim unused space in the database file, e.g. after deleting characters.
+/// Returns the size before and after.
+#[tauri::command]
+#[specta::specta]
+pub fn compact_database(state: State<Mutex<AppState>>) -> CommandResult<CompactReport> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    Ok(db::compact(&conn)?)
+}
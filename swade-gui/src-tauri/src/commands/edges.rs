@@ -2,12 +2,12 @@
 
 use std::sync::Mutex;
 use swade_core::constants::{EDGE_HINDRANCE_POINT_COST, SOURCE_HINDRANCE_POINTS};
-use swade_core::services::EdgeService;
+use swade_core::services::{CharacterService, EdgeService};
 use swade_core::views::{CharacterEdgeValue, CharacterView, EdgeView, RequirementStatus};
 use tauri::State;
 
 use crate::error::{CommandError, CommandResult};
-use crate::state::{available_hindrance_points, clear_arcane_backgrounds, lock_state, AppState};
+use crate::state::{clear_arcane_backgrounds, lock_state, AppState};
 
 use super::types::{DraftResult, ValidationWarning};
 
@@ -22,12 +22,33 @@ pub struct EdgeWithAvailability {
 #[tauri::command]
 #[specta::specta]
 pub fn get_edges(state: State<Mutex<AppState>>) -> CommandResult<Vec<EdgeWithAvailability>> {
+    let mut state = lock_state(&state)?;
+    let edges = state.static_data()?.edges.clone();
+
+    Ok(with_availability(edges, state.draft_character.as_ref()))
+}
+
+/// Search edges by name or description, optionally filtered by category.
+#[tauri::command]
+#[specta::specta]
+pub fn search_edges(
+    query: String,
+    category: Option<String>,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<EdgeWithAvailability>> {
     let state = lock_state(&state)?;
     let conn = state.connection()?;
-    let edges = EdgeService::get_all(&conn)?;
+    let edges = EdgeService::search(&conn, &query, category.as_deref())?;
+
+    Ok(with_availability(edges, state.draft_character.as_ref()))
+}
 
-    // If there's a draft character, evaluate requirements against it
-    let results = match &state.draft_character {
+/// Pair each edge with its availability for the draft character, if any.
+fn with_availability(
+    edges: Vec<EdgeView>,
+    draft: Option<&CharacterView>,
+) -> Vec<EdgeWithAvailability> {
+    match draft {
         Some(draft) => {
             let ctx = draft.to_requirement_context();
             edges
@@ -54,9 +75,7 @@ pub fn get_edges(state: State<Mutex<AppState>>) -> CommandResult<Vec<EdgeWithAva
                 })
                 .collect()
         }
-    };
-
-    Ok(results)
+    }
 }
 
 #[tauri::command]
@@ -72,87 +91,86 @@ pub fn add_draft_edge(
     let bypass = bypass_validation.unwrap_or(false);
     let mut warnings = Vec::new();
 
-    let draft = state.draft_mut()?;
-
-    // Load the edge
-    let edge = EdgeService::get_by_id(&conn, edge_id)?
-        .ok_or_else(|| CommandError::NotFound("Edge not found".to_string()))?;
+    state.edit_draft(|draft| {
+        // Load the edge
+        let edge = EdgeService::get_by_id(&conn, edge_id)?
+            .ok_or_else(|| CommandError::not_found("Edge not found".to_string()))?;
 
-    // Check if edge can be taken multiple times, or if already taken
-    if !edge.can_take_multiple_times
-        && draft.edges.iter().any(|e| e.edge.id == edge_id) {
-            return Err(CommandError::Validation("Edge already taken and cannot be taken multiple times".to_string()));
+        // Only repeatable edges can be taken again, each copy with its own notes
+        if let Some(reason) = draft.edge_conflict(&edge, notes.as_deref()) {
+            return Err(CommandError::conflict(reason));
         }
 
-    // Check requirements
-    let ctx = draft.to_requirement_context();
-    if !edge.requirements.evaluate(&ctx) {
-        if bypass {
-            warnings.push(ValidationWarning::requirement_not_met(format!(
-                "Edge '{}' requirements not met",
-                edge.name
-            )));
-        } else {
-            return Err(CommandError::Validation("Character does not meet edge requirements".to_string()));
+        // Check requirements
+        let ctx = draft.to_requirement_context();
+        if !edge.requirements.evaluate(&ctx) {
+            if bypass {
+                warnings.push(ValidationWarning::requirement_not_met(format!(
+                    "Edge '{}' requirements not met",
+                    edge.name
+                )));
+            } else {
+                return Err(CommandError::validation("Character does not meet edge requirements".to_string()));
+            }
         }
-    }
 
-    // If edge can be taken multiple times, notes are required
-    if edge.can_take_multiple_times && notes.is_none() {
-        return Err(CommandError::Validation("This edge requires notes (e.g., specify the skill or weapon)".to_string()));
-    }
+        // If edge can be taken multiple times, notes are required
+        if edge.can_take_multiple_times && notes.is_none() {
+            return Err(CommandError::validation("This edge requires notes (e.g., specify the skill or weapon)".to_string()));
+        }
 
-    // Check if we have enough allocated hindrance points for edges
-    // Count current edges from hindrance_points source
-    let edges_from_hindrance_points = draft
-        .edges
-        .iter()
-        .filter(|e| e.source == SOURCE_HINDRANCE_POINTS)
-        .count() as i64;
-    let points_spent_on_edges = edges_from_hindrance_points * EDGE_HINDRANCE_POINT_COST;
-    let points_available_for_edges = draft.hindrance_points_to_edges - points_spent_on_edges;
-
-    if points_available_for_edges < 2 {
-        if bypass {
-            warnings.push(ValidationWarning::point_limit_exceeded(
-                "Not enough hindrance points allocated to edges".to_string(),
-            ));
-        } else {
-            return Err(CommandError::Validation(
-                "Not enough hindrance points allocated to edges. Allocate more points first."
-                    .to_string(),
-            ));
+        // Check if we have enough allocated hindrance points for edges
+        // Count current edges from hindrance_points source
+        let edges_from_hindrance_points = draft
+            .edges
+            .iter()
+            .filter(|e| e.source == SOURCE_HINDRANCE_POINTS)
+            .count() as i64;
+        let points_spent_on_edges = edges_from_hindrance_points * EDGE_HINDRANCE_POINT_COST;
+        let points_available_for_edges = draft.hindrance_points_to_edges - points_spent_on_edges;
+
+        if points_available_for_edges < 2 {
+            if bypass {
+                warnings.push(ValidationWarning::point_limit_exceeded(
+                    "Not enough hindrance points allocated to edges".to_string(),
+                ));
+            } else {
+                return Err(CommandError::validation(
+                    "Not enough hindrance points allocated to edges. Allocate more points first."
+                        .to_string(),
+                ));
+            }
         }
-    }
 
-    // Apply wealth modifiers from the edge (e.g., Rich, Filthy Rich)
-    let wealth_bonus: i64 = edge
-        .modifiers
-        .iter()
-        .filter(|m| m.target_type.as_deref() == Some("wealth"))
-        .filter_map(|m| m.value)
-        .sum();
-    if wealth_bonus > 0 {
-        draft.wealth += wealth_bonus;
-    }
+        // Apply wealth modifiers from the edge (e.g., Rich, Filthy Rich)
+        let wealth_bonus: i64 = edge
+            .modifiers
+            .iter()
+            .filter(|m| m.target_type.as_deref() == Some("wealth"))
+            .filter_map(|m| m.value)
+            .sum();
+        if wealth_bonus > 0 {
+            draft.wealth += wealth_bonus;
+        }
 
-    // Add the edge
-    // Source is "hindrance_points" for character creation
-    // advance_taken is 0 for character creation (not an advancement)
-    draft.edges.push(CharacterEdgeValue::new(
-        edge,
-        0, // advance_taken
-        notes,
-        SOURCE_HINDRANCE_POINTS.to_string(),
-    ));
+        // Add the edge
+        // Source is "hindrance_points" for character creation
+        // advance_taken is 0 for character creation (not an advancement)
+        draft.edges.push(CharacterEdgeValue::new(
+            edge,
+            0, // advance_taken
+            notes,
+            SOURCE_HINDRANCE_POINTS.to_string(),
+        ));
 
-    // Note: hindrance_points_to_edges is not modified here - it tracks allocated points,
-    // and edge count * 2 tracks spent points
+        // Note: hindrance_points_to_edges is not modified here - it tracks allocated points,
+        // and edge count * 2 tracks spent points
 
-    // Recompute effective values (edges can have die modifiers)
-    draft.compute_effective_values();
+        // Recompute effective values (edges can have die modifiers)
+        draft.compute_effective_values();
 
-    Ok(DraftResult::with_warnings(draft.clone(), warnings))
+        Ok(DraftResult::with_warnings(draft.clone(), warnings))
+    })
 }
 
 #[tauri::command]
@@ -163,48 +181,48 @@ pub fn remove_draft_edge(
 ) -> CommandResult<CharacterView> {
     let mut state = lock_state(&state)?;
 
-    let draft = state.draft_mut()?;
+    state.edit_draft(|draft| {
+        // Find and remove the edge (only edges from hindrance_points can be removed during creation)
+        let position = draft
+            .edges
+            .iter()
+            .position(|e| e.edge.id == edge_id && e.source == SOURCE_HINDRANCE_POINTS);
 
-    // Find and remove the edge (only edges from hindrance_points can be removed during creation)
-    let position = draft
-        .edges
-        .iter()
-        .position(|e| e.edge.id == edge_id && e.source == SOURCE_HINDRANCE_POINTS);
+        match position {
+            Some(idx) => {
+                let removed_edge = draft.edges.remove(idx);
 
-    match position {
-        Some(idx) => {
-            let removed_edge = draft.edges.remove(idx);
-
-            // If the removed edge is "Arcane Background", clear arcane backgrounds and powers
-            if removed_edge.edge.name == "Arcane Background" {
-                clear_arcane_backgrounds(draft);
-            }
+                // If the removed edge is "Arcane Background", clear arcane backgrounds and powers
+                if removed_edge.edge.name == "Arcane Background" {
+                    clear_arcane_backgrounds(draft);
+                }
 
-            // Remove wealth modifiers from the edge (e.g., Rich, Filthy Rich)
-            let wealth_bonus: i64 = removed_edge
-                .edge
-                .modifiers
-                .iter()
-                .filter(|m| m.target_type.as_deref() == Some("wealth"))
-                .filter_map(|m| m.value)
-                .sum();
-            if wealth_bonus > 0 {
-                draft.wealth -= wealth_bonus;
-                if draft.wealth < 0 {
-                    draft.wealth = 0;
+                // Remove wealth modifiers from the edge (e.g., Rich, Filthy Rich)
+                let wealth_bonus: i64 = removed_edge
+                    .edge
+                    .modifiers
+                    .iter()
+                    .filter(|m| m.target_type.as_deref() == Some("wealth"))
+                    .filter_map(|m| m.value)
+                    .sum();
+                if wealth_bonus > 0 {
+                    draft.wealth -= wealth_bonus;
+                    if draft.wealth < 0 {
+                        draft.wealth = 0;
+                    }
                 }
-            }
 
-            // Note: hindrance_points_to_edges is not modified here - it tracks allocated points,
-            // removing an edge just frees up those allocated points for another edge
+                // Note: hindrance_points_to_edges is not modified here - it tracks allocated points,
+                // removing an edge just frees up those allocated points for another edge
 
-            // Recompute effective values
-            draft.compute_effective_values();
+                // Recompute effective values
+                draft.compute_effective_values();
 
-            Ok(draft.clone())
+                Ok(draft.clone())
+            }
+            None => Err(CommandError::not_found("Edge not found or cannot be removed".to_string())),
         }
-        None => Err(CommandError::NotFound("Edge not found or cannot be removed".to_string())),
-    }
+    })
 }
 
 #[tauri::command]
@@ -215,43 +233,9 @@ pub fn allocate_hindrance_points_to_edges(
 ) -> CommandResult<CharacterView> {
     let mut state = lock_state(&state)?;
 
-    let draft = state.draft_mut()?;
-    let available = available_hindrance_points(draft);
-
-    // Count edges from hindrance_points to track what's been "spent"
-    let edges_from_hindrance_points = draft
-        .edges
-        .iter()
-        .filter(|e| e.source == SOURCE_HINDRANCE_POINTS)
-        .count() as i64;
-    let points_spent_on_edges = edges_from_hindrance_points * EDGE_HINDRANCE_POINT_COST;
-
-    if points < 0 {
-        // Deallocating
-        let new_total = draft.hindrance_points_to_edges + points;
-        if new_total < 0 {
-            return Err(CommandError::Validation("Cannot deallocate more points than allocated".to_string()));
-        }
-        // Check if removing these points would leave us with not enough for current edges
-        if new_total < points_spent_on_edges {
-            return Err(CommandError::Validation(
-                "Cannot deallocate: points already spent on edges. Remove edges first.".to_string(),
-            ));
-        }
-        draft.hindrance_points_to_edges = new_total;
-    } else {
-        // Allocating - must allocate in increments of 2 (edge cost)
-        if points % EDGE_HINDRANCE_POINT_COST != 0 {
-            return Err(CommandError::Validation("Must allocate hindrance points to edges in multiples of 2".to_string()));
-        }
-        if points > available {
-            return Err(CommandError::Validation(format!(
-                "Not enough hindrance points. Need {}, have {}",
-                points, available
-            )));
-        }
-        draft.hindrance_points_to_edges += points;
-    }
+    state.edit_draft(|draft| {
+        CharacterService::allocate_hindrance_points_to_edges(draft, points)?;
 
-    Ok(draft.clone())
+        Ok(draft.clone())
+    })
 }
@@ -2,7 +2,7 @@
 
 use std::sync::Mutex;
 use swade_core::services::CharacterService;
-use swade_core::views::CharacterView;
+use swade_core::views::{CharacterView, Die};
 use tauri::State;
 use tauri_plugin_dialog::{DialogExt, FilePath};
 
@@ -25,7 +25,7 @@ pub async fn export_character_markdown(
         let conn = state.connection()?;
 
         let character = CharacterService::get_by_id(&conn, id)?
-            .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))?;
+            .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
 
         let markdown = generate_markdown(&character);
         // Sanitize filename: replace invalid characters
@@ -51,7 +51,7 @@ pub async fn export_character_markdown(
             };
 
             std::fs::write(&path_str, &markdown)
-                .map_err(|e| CommandError::State(format!("Failed to write file: {}", e)))?;
+                .map_err(|e| CommandError::state(format!("Failed to write file: {}", e)))?;
 
             Ok(true)
         }
@@ -62,6 +62,57 @@ pub async fn export_character_markdown(
     }
 }
 
+/// Export every active character as a CSV roster.
+/// Shows a save dialog and writes the file to the chosen location.
+/// Returns true if the file was saved, false if the user cancelled.
+#[tauri::command]
+#[specta::specta]
+pub async fn export_roster_csv(
+    app: tauri::AppHandle,
+    state: State<'_, Mutex<AppState>>,
+) -> CommandResult<bool> {
+    let csv = {
+        let state = lock_state(&state)?;
+        let conn = state.connection()?;
+        CharacterService::export_roster_csv(&conn)?
+    };
+
+    let file_path = app
+        .dialog()
+        .file()
+        .set_file_name("roster.csv")
+        .add_filter("CSV", &["csv"])
+        .blocking_save_file();
+
+    match file_path {
+        Some(path) => {
+            let path_str = match &path {
+                FilePath::Path(p) => p.to_string_lossy().to_string(),
+                FilePath::Url(u) => u.path().to_string(),
+            };
+
+            std::fs::write(&path_str, &csv)
+                .map_err(|e| CommandError::state(format!("Failed to write file: {}", e)))?;
+
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Get a compact plain-text stat block for pasting into VTT chat.
+#[tauri::command]
+#[specta::specta]
+pub fn export_character_statblock(id: i64, state: State<Mutex<AppState>>) -> CommandResult<String> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+
+    let character = CharacterService::get_by_id(&conn, id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
+
+    Ok(character.to_stat_block())
+}
+
 /// Generate a markdown string from a character view.
 fn generate_markdown(character: &CharacterView) -> String {
     let mut md = String::new();
@@ -99,7 +150,7 @@ fn generate_markdown(character: &CharacterView) -> String {
     md.push_str("| Attribute | Die |\n");
     md.push_str("|-----------|-----|\n");
     for attr in &character.attributes {
-        let die_str = format!("{}", attr.effective_die);
+        let die_str = attr.effective_die.to_display_string();
         md.push_str(&format!("| {} | {} |\n", attr.attribute.name, die_str));
     }
     md.push_str("\n---\n\n");
@@ -138,10 +189,10 @@ fn generate_markdown(character: &CharacterView) -> String {
     skills_to_show.sort_by(|a, b| a.skill.name.cmp(&b.skill.name));
 
     for skill in skills_to_show {
-        let die_str = match &skill.effective_die {
-            Some(d) => format!("{}", d),
-            None => "d4-2".to_string(), // Untrained core skill
-        };
+        let die_str = skill
+            .effective_die
+            .unwrap_or_else(Die::untrained) // Untrained core skill
+            .to_display_string();
         let attr_name = attr_map
             .get(&skill.skill.linked_attribute_id)
             .unwrap_or(&"?");
@@ -56,13 +56,17 @@ fn add_gear_item(
 }
 
 /// Get all available gear items.
+/// With `affordable_only` and an active draft, only items the draft can afford are returned.
 #[tauri::command]
 #[specta::specta]
-pub fn get_all_gear(state: State<Mutex<AppState>>) -> CommandResult<Vec<GearView>> {
+pub fn get_all_gear(
+    affordable_only: bool,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<GearView>> {
     let state = lock_state(&state)?;
     let conn = state.connection()?;
     let gear = GearService::get_all(&conn)?;
-    Ok(gear)
+    Ok(affordable_for_draft(&state, gear, affordable_only))
 }
 
 /// Get all gear categories.
@@ -76,16 +80,32 @@ pub fn get_gear_categories(state: State<Mutex<AppState>>) -> CommandResult<Vec<G
 }
 
 /// Get gear items by category.
+/// With `affordable_only` and an active draft, only items the draft can afford are returned.
 #[tauri::command]
 #[specta::specta]
 pub fn get_gear_by_category(
     category_id: i64,
+    affordable_only: bool,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<Vec<GearView>> {
     let state = lock_state(&state)?;
     let conn = state.connection()?;
     let gear = GearService::get_by_category_id(&conn, category_id)?;
-    Ok(gear)
+    Ok(affordable_for_draft(&state, gear, affordable_only))
+}
+
+/// Copy a saved character's gear and wealth into an open draft of it.
+fn sync_gear(draft: &mut CharacterView, saved: &CharacterView) {
+    draft.gear = saved.gear.clone();
+    draft.wealth = saved.wealth;
+}
+
+/// Filter gear down to what the draft can afford, when asked and a draft is active.
+fn affordable_for_draft(state: &AppState, gear: Vec<GearView>, affordable_only: bool) -> Vec<GearView> {
+    match &state.draft_character {
+        Some(draft) if affordable_only => GearService::filter_affordable(gear, draft.wealth),
+        _ => gear,
+    }
 }
 
 /// Get a character's gear inventory.
@@ -126,16 +146,16 @@ pub fn add_gear(
     quantity: i64,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterView> {
-    let state = lock_state(&state)?;
+    let mut state = lock_state(&state)?;
     let conn = state.connection()?;
 
     // Verify the gear exists
     let gear = GearService::get_by_id(&conn, gear_id)?
-        .ok_or_else(|| CommandError::NotFound("Gear not found".to_string()))?;
+        .ok_or_else(|| CommandError::not_found("Gear not found".to_string()))?;
 
     // Verify the character exists
     let _character = CharacterService::get_by_id(&conn, character_id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))?;
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
 
     // If this is a pack, add individual contents instead of the pack itself
     if let Some(ref pack_contents) = gear.pack_contents {
@@ -149,8 +169,10 @@ pub fn add_gear(
     }
 
     // Reload the character with updated gear
-    CharacterService::get_by_id(&conn, character_id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))
+    let character = CharacterService::get_by_id(&conn, character_id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
+    state.sync_draft(&character, sync_gear);
+    Ok(character)
 }
 
 /// Purchase gear for a character (deducts from wealth).
@@ -163,33 +185,28 @@ pub fn purchase_gear(
     quantity: i64,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterView> {
-    let state = lock_state(&state)?;
+    let mut state = lock_state(&state)?;
     let conn = state.connection()?;
 
     // Verify the gear exists and get its cost
     let gear = GearService::get_by_id(&conn, gear_id)?
-        .ok_or_else(|| CommandError::NotFound("Gear not found".to_string()))?;
+        .ok_or_else(|| CommandError::not_found("Gear not found".to_string()))?;
 
     // Verify the character exists
     let character = CharacterService::get_by_id(&conn, character_id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))?;
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
 
     // Calculate total cost (pack price, not sum of individual items)
     let total_cost = gear.cost * quantity;
 
     // Check if character has enough wealth
-    if character.wealth < total_cost {
-        return Err(CommandError::Validation(format!(
-            "Insufficient funds. Need ${}, have ${}",
-            total_cost, character.wealth
-        )));
-    }
+    GearService::check_affordable(character.wealth, total_cost)?;
 
     // Deduct wealth
     conn.execute(
-        "UPDATE characters SET wealth = wealth - ?, updated_at = ? WHERE id = ?",
+        "UPDATE characters SET wealth = wealth - ?, updated_at = ?, revision = revision + 1 WHERE id = ?",
         rusqlite::params![total_cost, chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(), character_id],
-    ).map_err(|e| CommandError::Database(e.to_string()))?;
+    ).map_err(|e| CommandError::database(e.to_string()))?;
 
     // If this is a pack, add individual contents instead of the pack itself
     if let Some(ref pack_contents) = gear.pack_contents {
@@ -203,11 +220,13 @@ pub fn purchase_gear(
     }
 
     // Reload the character with updated gear and wealth
-    CharacterService::get_by_id(&conn, character_id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))
+    let character = CharacterService::get_by_id(&conn, character_id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
+    state.sync_draft(&character, sync_gear);
+    Ok(character)
 }
 
-/// Sell gear from a character (at 50% value).
+/// Sell gear from a character (at their configured sell-back rate).
 #[tauri::command]
 #[specta::specta]
 pub fn sell_gear(
@@ -215,58 +234,47 @@ pub fn sell_gear(
     quantity: i64,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterView> {
-    let state = lock_state(&state)?;
+    let mut state = lock_state(&state)?;
     let conn = state.connection()?;
 
-    // Get the character gear record
     let character_gear = CharacterGearRepository::get_by_id(&conn, character_gear_id)?
-        .ok_or_else(|| CommandError::NotFound("Character gear not found".to_string()))?;
+        .ok_or_else(|| CommandError::not_found("Character gear not found".to_string()))?;
+    let character = CharacterService::get_by_id(&conn, character_gear.character_id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
 
-    // Validate quantity
-    if quantity > character_gear.quantity {
-        return Err(CommandError::Validation(format!(
-            "Cannot sell {} items, only have {}",
-            quantity, character_gear.quantity
-        )));
-    }
-
-    // Get the gear to calculate sale price
-    let gear = GearService::get_by_id(&conn, character_gear.gear_id)?
-        .ok_or_else(|| CommandError::NotFound("Gear not found".to_string()))?;
+    GearService::sell(
+        &conn,
+        character_gear_id,
+        quantity,
+        character.config.sell_back_percent(),
+    )?;
 
-    // Calculate sale value (50% of cost)
-    let sale_value = (gear.cost * quantity) / 2;
+    // Reload the character
+    let character = CharacterService::get_by_id(&conn, character_gear.character_id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
+    state.sync_draft(&character, sync_gear);
+    Ok(character)
+}
 
-    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+/// Sell everything the character doesn't have equipped (at their configured sell-back rate).
+#[tauri::command]
+#[specta::specta]
+pub fn sell_all_unequipped_gear(
+    character_id: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+    let conn = state.connection()?;
 
-    // Add wealth
-    conn.execute(
-        "UPDATE characters SET wealth = wealth + ?, updated_at = ? WHERE id = ?",
-        rusqlite::params![sale_value, &now, character_gear.character_id],
-    ).map_err(|e| CommandError::Database(e.to_string()))?;
+    let character = CharacterService::get_by_id(&conn, character_id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
 
-    // Update or delete gear
-    if quantity >= character_gear.quantity {
-        // Remove all
-        CharacterGearRepository::delete(&conn, character_gear_id)?;
-    } else {
-        // Reduce quantity
-        let updated = CharacterGear {
-            id: character_gear.id,
-            character_id: character_gear.character_id,
-            gear_id: character_gear.gear_id,
-            quantity: character_gear.quantity - quantity,
-            is_equipped: character_gear.is_equipped,
-            custom_notes: character_gear.custom_notes,
-            created_at: character_gear.created_at,
-            updated_at: now,
-        };
-        CharacterGearRepository::update(&conn, &updated)?;
-    }
+    GearService::sell_all_unequipped(&conn, character_id, character.config.sell_back_percent())?;
 
-    // Reload the character
-    CharacterService::get_by_id(&conn, character_gear.character_id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))
+    let character = CharacterService::get_by_id(&conn, character_id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
+    state.sync_draft(&character, sync_gear);
+    Ok(character)
 }
 
 /// Remove gear from a character without selling (lost, destroyed, given away).
@@ -277,16 +285,16 @@ pub fn remove_gear(
     quantity: i64,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterView> {
-    let state = lock_state(&state)?;
+    let mut state = lock_state(&state)?;
     let conn = state.connection()?;
 
     // Get the character gear record
     let character_gear = CharacterGearRepository::get_by_id(&conn, character_gear_id)?
-        .ok_or_else(|| CommandError::NotFound("Character gear not found".to_string()))?;
+        .ok_or_else(|| CommandError::not_found("Character gear not found".to_string()))?;
 
     // Validate quantity
     if quantity > character_gear.quantity {
-        return Err(CommandError::Validation(format!(
+        return Err(CommandError::validation(format!(
             "Cannot remove {} items, only have {}",
             quantity, character_gear.quantity
         )));
@@ -314,8 +322,10 @@ pub fn remove_gear(
     }
 
     // Reload the character
-    CharacterService::get_by_id(&conn, character_gear.character_id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))
+    let character = CharacterService::get_by_id(&conn, character_gear.character_id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
+    state.sync_draft(&character, sync_gear);
+    Ok(character)
 }
 
 /// Toggle whether gear is equipped.
@@ -325,12 +335,12 @@ pub fn toggle_gear_equipped(
     character_gear_id: i64,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterView> {
-    let state = lock_state(&state)?;
+    let mut state = lock_state(&state)?;
     let conn = state.connection()?;
 
     // Get the character gear record
     let character_gear = CharacterGearRepository::get_by_id(&conn, character_gear_id)?
-        .ok_or_else(|| CommandError::NotFound("Character gear not found".to_string()))?;
+        .ok_or_else(|| CommandError::not_found("Character gear not found".to_string()))?;
 
     let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
@@ -348,8 +358,10 @@ pub fn toggle_gear_equipped(
     CharacterGearRepository::update(&conn, &updated)?;
 
     // Reload the character (equipped gear affects modifiers)
-    CharacterService::get_by_id(&conn, character_gear.character_id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))
+    let character = CharacterService::get_by_id(&conn, character_gear.character_id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
+    state.sync_draft(&character, sync_gear);
+    Ok(character)
 }
 
 /// Update custom notes on a gear item.
@@ -360,12 +372,12 @@ pub fn update_gear_notes(
     notes: Option<String>,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterView> {
-    let state = lock_state(&state)?;
+    let mut state = lock_state(&state)?;
     let conn = state.connection()?;
 
     // Get the character gear record
     let character_gear = CharacterGearRepository::get_by_id(&conn, character_gear_id)?
-        .ok_or_else(|| CommandError::NotFound("Character gear not found".to_string()))?;
+        .ok_or_else(|| CommandError::not_found("Character gear not found".to_string()))?;
 
     let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
@@ -383,8 +395,10 @@ pub fn update_gear_notes(
     CharacterGearRepository::update(&conn, &updated)?;
 
     // Reload the character
-    CharacterService::get_by_id(&conn, character_gear.character_id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))
+    let character = CharacterService::get_by_id(&conn, character_gear.character_id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
+    state.sync_draft(&character, sync_gear);
+    Ok(character)
 }
 
 /// Update a character's wealth directly.
@@ -395,21 +409,23 @@ pub fn update_character_wealth(
     wealth: i64,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterView> {
-    let state = lock_state(&state)?;
+    let mut state = lock_state(&state)?;
     let conn = state.connection()?;
 
     // Validate wealth is non-negative
     if wealth < 0 {
-        return Err(CommandError::Validation("Wealth cannot be negative".to_string()));
+        return Err(CommandError::validation("Wealth cannot be negative".to_string()));
     }
 
     let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
     conn.execute(
-        "UPDATE characters SET wealth = ?, updated_at = ? WHERE id = ?",
+        "UPDATE characters SET wealth = ?, updated_at = ?, revision = revision + 1 WHERE id = ?",
         rusqlite::params![wealth, now, character_id],
-    ).map_err(|e| CommandError::Database(e.to_string()))?;
+    ).map_err(|e| CommandError::database(e.to_string()))?;
 
-    CharacterService::get_by_id(&conn, character_id)?
-        .ok_or_else(|| CommandError::NotFound("Character not found".to_string()))
+    let character = CharacterService::get_by_id(&conn, character_id)?
+        .ok_or_else(|| CommandError::not_found("Character not found".to_string()))?;
+    state.sync_draft(&character, sync_gear);
+    Ok(character)
 }
@@ -2,7 +2,7 @@
 
 use std::sync::Mutex;
 use swade_core::constants::SOURCE_CHOSEN;
-use swade_core::services::HindranceService;
+use swade_core::services::{CharacterService, HindranceService};
 use swade_core::views::{CharacterHindranceValue, CharacterView, HindranceView};
 use tauri::State;
 
@@ -31,45 +31,35 @@ pub fn add_draft_hindrance(
     let bypass = bypass_validation.unwrap_or(false);
     let mut warnings = Vec::new();
 
-    let draft = state.draft_mut()?;
-
-    // Check if hindrance is already added
-    if draft
-        .hindrances
-        .iter()
-        .any(|h| h.hindrance.id == hindrance_id)
-    {
-        return Err(CommandError::Validation("Hindrance already added".to_string()));
-    }
-
-    // Load the hindrance
-    let hindrance = HindranceService::get_by_id(&conn, hindrance_id)?
-        .ok_or_else(|| CommandError::NotFound("Hindrance not found".to_string()))?;
-
-    // Check point limit (max 4 hindrance points typically)
-    let max_points = 4i64; // Could come from GameConfig
-    let new_total = draft.hindrance_points_earned + hindrance.point_value;
-    if new_total > max_points {
-        if bypass {
-            warnings.push(ValidationWarning::point_limit_exceeded(format!(
-                "Hindrance points exceed maximum ({}/{})",
-                new_total, max_points
-            )));
+    state.edit_draft(|draft| {
+        // Load the hindrance
+        let hindrance = HindranceService::get_by_id(&conn, hindrance_id)?
+            .ok_or_else(|| CommandError::not_found("Hindrance not found".to_string()))?;
+
+        // Block duplicates and holding both the minor and major version
+        if let Some(reason) = draft.hindrance_conflict(&hindrance) {
+            return Err(CommandError::validation(reason));
+        }
+
+        // Enforce the starting hindrance point cap
+        if let Some(reason) = draft.hindrance_point_cap_exceeded(&hindrance) {
+            if !bypass {
+                return Err(CommandError::validation(reason));
+            }
+            warnings.push(ValidationWarning::point_limit_exceeded(reason));
         }
-        // Note: We allow this even without bypass since it was previously allowed
-    }
 
-    // Add to draft and update points
-    let point_value = hindrance.point_value;
-    draft
-        .hindrances
-        .push(CharacterHindranceValue::new(hindrance, SOURCE_CHOSEN.to_string()));
-    draft.hindrance_points_earned += point_value;
+        // Add to draft and update points
+        draft
+            .hindrances
+            .push(CharacterHindranceValue::new(hindrance, SOURCE_CHOSEN.to_string()));
+        draft.hindrance_points_earned = draft.earned_hindrance_points();
 
-    // Recompute effective values (hindrances can have die modifiers)
-    draft.compute_effective_values();
+        // Recompute effective values (hindrances can have die modifiers)
+        draft.compute_effective_values();
 
-    Ok(DraftResult::with_warnings(draft.clone(), warnings))
+        Ok(DraftResult::with_warnings(draft.clone(), warnings))
+    })
 }
 
 #[tauri::command]
@@ -80,24 +70,40 @@ pub fn remove_draft_hindrance(
 ) -> CommandResult<CharacterView> {
     let mut state = lock_state(&state)?;
 
-    let draft = state.draft_mut()?;
-
-    // Find and remove the hindrance
-    let position = draft
-        .hindrances
-        .iter()
-        .position(|h| h.hindrance.id == hindrance_id && h.source == SOURCE_CHOSEN);
+    state.edit_draft(|draft| {
+        // Find and remove the hindrance
+        let position = draft
+            .hindrances
+            .iter()
+            .position(|h| h.hindrance.id == hindrance_id && h.source == SOURCE_CHOSEN);
 
-    match position {
-        Some(idx) => {
-            let removed = draft.hindrances.remove(idx);
-            draft.hindrance_points_earned -= removed.hindrance.point_value;
+        match position {
+            Some(idx) => {
+                draft.hindrances.remove(idx);
+                draft.hindrance_points_earned = draft.earned_hindrance_points();
 
-            // Recompute effective values (hindrances can have die modifiers)
-            draft.compute_effective_values();
+                // Recompute effective values (hindrances can have die modifiers)
+                draft.compute_effective_values();
 
-            Ok(draft.clone())
+                Ok(draft.clone())
+            }
+            None => Err(CommandError::not_found("Hindrance not found or cannot be removed".to_string())),
         }
-        None => Err(CommandError::NotFound("Hindrance not found or cannot be removed".to_string())),
-    }
+    })
+}
+
+/// Convert hindrance points into starting funds (negative points give them back).
+#[tauri::command]
+#[specta::specta]
+pub fn allocate_hindrance_points_to_wealth(
+    points: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterView> {
+    let mut state = lock_state(&state)?;
+
+    state.edit_draft(|draft| {
+        CharacterService::allocate_hindrance_points_to_wealth(draft, points)?;
+
+        Ok(draft.clone())
+    })
 }
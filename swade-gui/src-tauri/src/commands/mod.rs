@@ -4,6 +4,8 @@ pub mod advancement;
 pub mod ancestry;
 pub mod attributes;
 pub mod character;
+pub mod companions;
+pub mod database;
 pub mod edges;
 pub mod export;
 pub mod gear;
@@ -18,6 +20,8 @@ pub use advancement::*;
 pub use ancestry::*;
 pub use attributes::*;
 pub use character::*;
+pub use companions::*;
+pub use database::*;
 pub use edges::*;
 pub use export::*;
 pub use gear::*;
@@ -3,7 +3,7 @@
 //! Notes operate directly on saved characters, not drafts.
 
 use std::sync::Mutex;
-use swade_core::models::CharacterNote;
+use swade_core::models::{CharacterNote, NoteOrder};
 use swade_core::repositories::CharacterNoteRepository;
 use swade_core::views::CharacterNoteValue;
 use tauri::State;
@@ -11,21 +11,44 @@ use tauri::State;
 use crate::error::{CommandError, CommandResult};
 use crate::state::{lock_state, AppState};
 
+/// Get a character's notes, optionally limited to one category. Pinned notes
+/// come first, then the newest by `order_by` (last updated by default).
 #[tauri::command]
 #[specta::specta]
 pub fn get_character_notes(
     character_id: i64,
+    category: Option<String>,
+    order_by: Option<NoteOrder>,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<Vec<CharacterNoteValue>> {
     let state = lock_state(&state)?;
     let conn = state.connection()?;
 
-    let notes = CharacterNoteRepository::get_by_character_id(&conn, character_id)?;
+    let order = order_by.unwrap_or_default();
+    let notes = match normalize_category(category) {
+        Some(category) => {
+            CharacterNoteRepository::get_by_category(&conn, character_id, &category, order)?
+        }
+        None => CharacterNoteRepository::get_by_character_id(&conn, character_id, order)?,
+    };
+
+    Ok(notes.into_iter().map(to_value).collect())
+}
+
+/// Search a character's notes by title or body, most recently updated first.
+#[tauri::command]
+#[specta::specta]
+pub fn search_notes(
+    character_id: i64,
+    query: String,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<CharacterNoteValue>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+
+    let notes = CharacterNoteRepository::search(&conn, character_id, query.trim())?;
 
-    Ok(notes
-        .into_iter()
-        .map(|n| CharacterNoteValue::new(n.id, n.title, n.body, n.created_at, n.updated_at))
-        .collect())
+    Ok(notes.into_iter().map(to_value).collect())
 }
 
 #[tauri::command]
@@ -34,6 +57,7 @@ pub fn create_character_note(
     character_id: i64,
     title: String,
     body: String,
+    category: Option<String>,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterNoteValue> {
     let state = lock_state(&state)?;
@@ -44,6 +68,8 @@ pub fn create_character_note(
         character_id,
         title,
         body,
+        pinned: false,
+        category: normalize_category(category),
         created_at: String::new(),
         updated_at: String::new(),
     };
@@ -52,15 +78,9 @@ pub fn create_character_note(
 
     // Return the newly created note
     let created = CharacterNoteRepository::get_by_id(&conn, id)?
-        .ok_or_else(|| CommandError::NotFound("Note not found after creation".to_string()))?;
-
-    Ok(CharacterNoteValue::new(
-        created.id,
-        created.title,
-        created.body,
-        created.created_at,
-        created.updated_at,
-    ))
+        .ok_or_else(|| CommandError::not_found("Note not found after creation".to_string()))?;
+
+    Ok(to_value(created))
 }
 
 #[tauri::command]
@@ -69,6 +89,7 @@ pub fn update_character_note(
     note_id: i64,
     title: String,
     body: String,
+    category: Option<String>,
     state: State<Mutex<AppState>>,
 ) -> CommandResult<CharacterNoteValue> {
     let state = lock_state(&state)?;
@@ -76,24 +97,40 @@ pub fn update_character_note(
 
     // Get existing note to update
     let mut note = CharacterNoteRepository::get_by_id(&conn, note_id)?
-        .ok_or_else(|| CommandError::NotFound("Note not found".to_string()))?;
+        .ok_or_else(|| CommandError::not_found("Note not found".to_string()))?;
 
     note.title = title;
     note.body = body;
+    note.category = normalize_category(category);
 
     CharacterNoteRepository::update(&conn, &note)?;
 
     // Return the updated note
     let updated = CharacterNoteRepository::get_by_id(&conn, note_id)?
-        .ok_or_else(|| CommandError::NotFound("Note not found after update".to_string()))?;
-
-    Ok(CharacterNoteValue::new(
-        updated.id,
-        updated.title,
-        updated.body,
-        updated.created_at,
-        updated.updated_at,
-    ))
+        .ok_or_else(|| CommandError::not_found("Note not found after update".to_string()))?;
+
+    Ok(to_value(updated))
+}
+
+/// Pin an unpinned note or unpin a pinned one. Pinned notes are listed first.
+#[tauri::command]
+#[specta::specta]
+pub fn toggle_note_pinned(
+    note_id: i64,
+    state: State<Mutex<AppState>>,
+) -> CommandResult<CharacterNoteValue> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+
+    let note = CharacterNoteRepository::get_by_id(&conn, note_id)?
+        .ok_or_else(|| CommandError::not_found("Note not found".to_string()))?;
+
+    CharacterNoteRepository::set_pinned(&conn, note_id, !note.pinned)?;
+
+    let updated = CharacterNoteRepository::get_by_id(&conn, note_id)?
+        .ok_or_else(|| CommandError::not_found("Note not found after update".to_string()))?;
+
+    Ok(to_value(updated))
 }
 
 #[tauri::command]
@@ -109,3 +146,22 @@ pub fn delete_character_note(
 
     Ok(())
 }
+
+/// Trim a category name, treating blank names as no category.
+fn normalize_category(category: Option<String>) -> Option<String> {
+    category
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty())
+}
+
+fn to_value(note: CharacterNote) -> CharacterNoteValue {
+    CharacterNoteValue::new(
+        note.id,
+        note.title,
+        note.body,
+        note.pinned,
+        note.category,
+        note.created_at,
+        note.updated_at,
+    )
+}
@@ -74,97 +74,99 @@ pub fn add_draft_arcane_background(
     let bypass = bypass_validation.unwrap_or(false);
     let mut warnings = Vec::new();
 
-    let draft = state.draft_mut()?;
-
-    // Check if arcane background is already added
-    if draft
-        .arcane_backgrounds
-        .iter()
-        .any(|ab| ab.arcane_background.id == arcane_background_id)
-    {
-        return Err(CommandError::Validation("Arcane background already added".to_string()));
-    }
+    state.edit_draft(|draft| {
+        // Check if arcane background is already added
+        if draft
+            .arcane_backgrounds
+            .iter()
+            .any(|ab| ab.arcane_background.id == arcane_background_id)
+        {
+            return Err(CommandError::conflict("Arcane background already added".to_string()));
+        }
 
-    // Load the arcane background
-    let arcane_background = ArcaneBackgroundService::get_by_id(&conn, arcane_background_id)?
-        .ok_or_else(|| CommandError::NotFound("Arcane background not found".to_string()))?;
-
-    // Check requirements
-    let ctx = draft.to_requirement_context();
-    if !arcane_background.requirements.evaluate(&ctx) {
-        if bypass {
-            warnings.push(ValidationWarning::requirement_not_met(format!(
-                "Arcane Background '{}' requirements not met",
-                arcane_background.name
-            )));
-        } else {
-            return Err(CommandError::Validation("Character does not meet arcane background requirements".to_string()));
+        // Load the arcane background
+        let arcane_background = ArcaneBackgroundService::get_by_id(&conn, arcane_background_id)?
+            .ok_or_else(|| CommandError::not_found("Arcane background not found".to_string()))?;
+
+        // Check requirements
+        let ctx = draft.to_requirement_context();
+        if !arcane_background.requirements.evaluate(&ctx) {
+            if bypass {
+                warnings.push(ValidationWarning::requirement_not_met(format!(
+                    "Arcane Background '{}' requirements not met",
+                    arcane_background.name
+                )));
+            } else {
+                return Err(CommandError::validation("Character does not meet arcane background requirements".to_string()));
+            }
         }
-    }
 
-    // Initialize arcane_background_choices from the AB's choices
-    // Auto-select required options (built_in_hindrance, required_starting_power)
-    for choice in &arcane_background.choices {
-        let mut selected_options = Vec::new();
-
-        match choice.choice_type {
-            // Built-in hindrances are auto-selected and added to draft.hindrances
-            ArcaneBackgroundChoiceType::BuiltInHindrance => {
-                for option in &choice.options {
-                    if option.option_type == ArcaneBackgroundOptionType::Hindrance {
-                        if let Some(hindrance_id) = option.option_id {
-                            // Load and add the hindrance
-                            if let Ok(Some(hindrance)) = HindranceService::get_by_id(&conn, hindrance_id) {
-                                // Don't add hindrance points for built-in hindrances
-                                // (they're part of the AB, not chosen by player)
-                                draft.hindrances.push(CharacterHindranceValue::new(
-                                    hindrance,
-                                    SOURCE_ARCANE_BACKGROUND.to_string(),
-                                ));
+        // Initialize arcane_background_choices from the AB's choices
+        // Auto-select required options (built_in_hindrance, required_starting_power)
+        for choice in &arcane_background.choices {
+            let mut selected_options = Vec::new();
+
+            match choice.choice_type {
+                // Built-in hindrances are auto-selected and added to draft.hindrances
+                ArcaneBackgroundChoiceType::BuiltInHindrance => {
+                    for option in &choice.options {
+                        if option.option_type == ArcaneBackgroundOptionType::Hindrance {
+                            if let Some(hindrance_id) = option.option_id {
+                                // Load and add the hindrance
+                                if let Ok(Some(hindrance)) = HindranceService::get_by_id(&conn, hindrance_id) {
+                                    // Don't add hindrance points for built-in hindrances
+                                    // (they're part of the AB, not chosen by player)
+                                    draft.hindrances.push(CharacterHindranceValue::new(
+                                        hindrance,
+                                        SOURCE_ARCANE_BACKGROUND.to_string(),
+                                    ));
+                                }
                             }
+                            selected_options.push(option.clone());
                         }
-                        selected_options.push(option.clone());
                     }
                 }
-            }
-            // Required starting powers are auto-selected and added to draft.powers
-            ArcaneBackgroundChoiceType::RequiredStartingPower => {
-                for option in &choice.options {
-                    if option.option_type == ArcaneBackgroundOptionType::Power {
-                        if let Some(power_id) = option.option_id {
-                            // Load and add the power
-                            if let Ok(Some(power)) = PowerService::get_by_id(&conn, power_id) {
-                                // Mark as locked (required power can't be removed)
-                                let mut power_value = CharacterPowerValue::new(power, None);
-                                power_value.is_locked = true;
-                                draft.powers.push(power_value);
+                // Required starting powers are auto-selected and added to draft.powers
+                ArcaneBackgroundChoiceType::RequiredStartingPower => {
+                    for option in &choice.options {
+                        if option.option_type == ArcaneBackgroundOptionType::Power {
+                            if let Some(power_id) = option.option_id {
+                                // Load and add the power
+                                if let Ok(Some(power)) = PowerService::get_by_id(&conn, power_id) {
+                                    // Mark as locked (required power can't be removed)
+                                    let mut power_value = CharacterPowerValue::new(power, None);
+                                    power_value.is_locked = true;
+                                    draft.powers.push(power_value);
+                                }
                             }
+                            selected_options.push(option.clone());
                         }
-                        selected_options.push(option.clone());
                     }
                 }
+                // Other choice types start with no selection
+                _ => {}
             }
-            // Other choice types start with no selection
-            _ => {}
-        }
 
-        draft.arcane_background_choices.push(
-            CharacterArcaneBackgroundChoiceValue::new(choice.clone(), selected_options),
-        );
-    }
+            draft.arcane_background_choices.push(
+                CharacterArcaneBackgroundChoiceValue::new(choice.clone(), selected_options),
+            );
+        }
 
-    // Add the arcane background (advance_taken is None for character creation)
-    draft
-        .arcane_backgrounds
-        .push(CharacterArcaneBackgroundValue::new(
-            arcane_background.clone(),
-            None,
-        ));
+        // Add the arcane background (advance_taken is None for character creation)
+        draft
+            .arcane_backgrounds
+            .push(CharacterArcaneBackgroundValue::new(
+                arcane_background.clone(),
+                None,
+                0,
+            ));
 
-    // Add the starting power points
-    draft.power_points += arcane_background.starting_power_points;
+        // Add the starting power points
+        draft.power_points += arcane_background.starting_power_points;
+        draft.compute_effective_values();
 
-    Ok(DraftResult::with_warnings(draft.clone(), warnings))
+        Ok(DraftResult::with_warnings(draft.clone(), warnings))
+    })
 }
 
 #[tauri::command]
@@ -175,47 +177,48 @@ pub fn remove_draft_arcane_background(
 ) -> CommandResult<CharacterView> {
     let mut state = lock_state(&state)?;
 
-    let draft = state.draft_mut()?;
+    state.edit_draft(|draft| {
+        // Find the arcane background
+        let position = draft
+            .arcane_backgrounds
+            .iter()
+            .position(|ab| ab.arcane_background.id == arcane_background_id);
 
-    // Find the arcane background
-    let position = draft
-        .arcane_backgrounds
-        .iter()
-        .position(|ab| ab.arcane_background.id == arcane_background_id);
+        match position {
+            Some(idx) => {
+                let removed = draft.arcane_backgrounds.remove(idx);
 
-    match position {
-        Some(idx) => {
-            let removed = draft.arcane_backgrounds.remove(idx);
+                // Remove power points from this arcane background
+                draft.power_points -= removed.arcane_background.starting_power_points;
+                if draft.power_points < 0 {
+                    draft.power_points = 0;
+                }
 
-            // Remove power points from this arcane background
-            draft.power_points -= removed.arcane_background.starting_power_points;
-            if draft.power_points < 0 {
-                draft.power_points = 0;
+                // Remove all powers (since we no longer have the arcane background)
+                draft.powers.clear();
+
+                // Remove hindrances that came from this arcane background
+                draft
+                    .hindrances
+                    .retain(|h| h.source != SOURCE_ARCANE_BACKGROUND);
+
+                // Remove arcane background choices for this AB
+                let choice_ids: Vec<i64> = removed
+                    .arcane_background
+                    .choices
+                    .iter()
+                    .map(|c| c.id)
+                    .collect();
+                draft
+                    .arcane_background_choices
+                    .retain(|c| !choice_ids.contains(&c.choice.id));
+
+                draft.compute_effective_values();
+                Ok(draft.clone())
             }
-
-            // Remove all powers (since we no longer have the arcane background)
-            draft.powers.clear();
-
-            // Remove hindrances that came from this arcane background
-            draft
-                .hindrances
-                .retain(|h| h.source != SOURCE_ARCANE_BACKGROUND);
-
-            // Remove arcane background choices for this AB
-            let choice_ids: Vec<i64> = removed
-                .arcane_background
-                .choices
-                .iter()
-                .map(|c| c.id)
-                .collect();
-            draft
-                .arcane_background_choices
-                .retain(|c| !choice_ids.contains(&c.choice.id));
-
-            Ok(draft.clone())
+            None => Err(CommandError::not_found("Arcane background not found".to_string())),
         }
-        None => Err(CommandError::NotFound("Arcane background not found".to_string())),
-    }
+    })
 }
 
 /// Update a character's arcane background choice selection.
@@ -233,130 +236,130 @@ pub fn update_draft_arcane_background_choice(
     let mut state = lock_state(&state)?;
     let conn = state.connection()?;
 
-    let draft = state.draft_mut()?;
-
-    // Find the choice
-    let choice_value = draft
-        .arcane_background_choices
-        .iter_mut()
-        .find(|c| c.choice.id == choice_id)
-        .ok_or_else(|| CommandError::NotFound("Choice not found".to_string()))?;
-
-    // Prevent modifying built-in hindrances and required starting powers
-    match choice_value.choice.choice_type {
-        ArcaneBackgroundChoiceType::BuiltInHindrance
-        | ArcaneBackgroundChoiceType::RequiredStartingPower => {
-            return Err(CommandError::Validation(
-                "Cannot modify built-in or required choices".to_string(),
-            ));
+    state.edit_draft(|draft| {
+        // Find the choice
+        let choice_value = draft
+            .arcane_background_choices
+            .iter_mut()
+            .find(|c| c.choice.id == choice_id)
+            .ok_or_else(|| CommandError::not_found("Choice not found".to_string()))?;
+
+        // Prevent modifying built-in hindrances and required starting powers
+        match choice_value.choice.choice_type {
+            ArcaneBackgroundChoiceType::BuiltInHindrance
+            | ArcaneBackgroundChoiceType::RequiredStartingPower => {
+                return Err(CommandError::validation(
+                    "Cannot modify built-in or required choices".to_string(),
+                ));
+            }
+            _ => {}
         }
-        _ => {}
-    }
 
-    // Find the option in the choice
-    let option = choice_value
-        .choice
-        .options
-        .iter()
-        .find(|o| o.id == selected_option_id)
-        .ok_or_else(|| CommandError::NotFound("Option not found in choice".to_string()))?
-        .clone();
-
-    if is_selecting {
-        // Check if already selected
-        if choice_value
-            .selected_options
+        // Find the option in the choice
+        let option = choice_value
+            .choice
+            .options
             .iter()
-            .any(|o| o.id == selected_option_id)
-        {
-            return Err(CommandError::Validation("Option already selected".to_string()));
-        }
+            .find(|o| o.id == selected_option_id)
+            .ok_or_else(|| CommandError::not_found("Option not found in choice".to_string()))?
+            .clone();
+
+        if is_selecting {
+            // Check if already selected
+            if choice_value
+                .selected_options
+                .iter()
+                .any(|o| o.id == selected_option_id)
+            {
+                return Err(CommandError::conflict("Option already selected".to_string()));
+            }
 
-        // Check max_selections limit
-        if choice_value.selected_options.len() as i64 >= choice_value.choice.max_selections {
-            return Err(CommandError::Validation(format!(
-                "Maximum {} selections allowed for this choice",
-                choice_value.choice.max_selections
-            )));
-        }
+            // Check max_selections limit
+            if choice_value.selected_options.len() as i64 >= choice_value.choice.max_selections {
+                return Err(CommandError::validation(format!(
+                    "Maximum {} selections allowed for this choice",
+                    choice_value.choice.max_selections
+                )));
+            }
 
-        // Handle the option based on type
-        match option.option_type {
-            ArcaneBackgroundOptionType::Power => {
-                if let Some(power_id) = option.option_id {
-                    // Load and add the power
-                    let power = PowerService::get_by_id(&conn, power_id)?
-                        .ok_or_else(|| CommandError::NotFound("Power not found".to_string()))?;
-
-                    // Check if power is already in draft.powers
-                    if draft.powers.iter().any(|p| p.power.id == power_id) {
-                        return Err(CommandError::Validation("Power already added".to_string()));
-                    }
+            // Handle the option based on type
+            match option.option_type {
+                ArcaneBackgroundOptionType::Power => {
+                    if let Some(power_id) = option.option_id {
+                        // Load and add the power
+                        let power = PowerService::get_by_id(&conn, power_id)?
+                            .ok_or_else(|| CommandError::not_found("Power not found".to_string()))?;
+
+                        // Check if power is already in draft.powers
+                        if draft.powers.iter().any(|p| p.power.id == power_id) {
+                            return Err(CommandError::conflict("Power already added".to_string()));
+                        }
 
-                    draft.powers.push(CharacterPowerValue::new(power, None));
+                        draft.powers.push(CharacterPowerValue::new(power, None));
+                    }
                 }
-            }
-            ArcaneBackgroundOptionType::Hindrance => {
-                if let Some(hindrance_id) = option.option_id {
-                    // Load and add the hindrance
-                    let hindrance = HindranceService::get_by_id(&conn, hindrance_id)?
-                        .ok_or_else(|| CommandError::NotFound("Hindrance not found".to_string()))?;
-
-                    draft.hindrances.push(CharacterHindranceValue::new(
-                        hindrance,
-                        SOURCE_ARCANE_BACKGROUND.to_string(),
-                    ));
+                ArcaneBackgroundOptionType::Hindrance => {
+                    if let Some(hindrance_id) = option.option_id {
+                        // Load and add the hindrance
+                        let hindrance = HindranceService::get_by_id(&conn, hindrance_id)?
+                            .ok_or_else(|| CommandError::not_found("Hindrance not found".to_string()))?;
+
+                        draft.hindrances.push(CharacterHindranceValue::new(
+                            hindrance,
+                            SOURCE_ARCANE_BACKGROUND.to_string(),
+                        ));
+                    }
                 }
+                // Ability and EdgeCategory types are informational, no side effects
+                _ => {}
             }
-            // Ability and EdgeCategory types are informational, no side effects
-            _ => {}
-        }
-
-        choice_value.selected_options.push(option);
-    } else {
-        // Deselecting
-        let option_position = choice_value
-            .selected_options
-            .iter()
-            .position(|o| o.id == selected_option_id);
 
-        match option_position {
-            Some(idx) => {
-                let removed_option = choice_value.selected_options.remove(idx);
-
-                // Handle side effects based on option type
-                match removed_option.option_type {
-                    ArcaneBackgroundOptionType::Power => {
-                        if let Some(power_id) = removed_option.option_id {
-                            // Remove the power from draft.powers (only non-locked)
-                            if let Some(pos) = draft.powers.iter().position(|p| {
-                                p.power.id == power_id && !p.is_locked
-                            }) {
-                                draft.powers.remove(pos);
+            choice_value.selected_options.push(option);
+        } else {
+            // Deselecting
+            let option_position = choice_value
+                .selected_options
+                .iter()
+                .position(|o| o.id == selected_option_id);
+
+            match option_position {
+                Some(idx) => {
+                    let removed_option = choice_value.selected_options.remove(idx);
+
+                    // Handle side effects based on option type
+                    match removed_option.option_type {
+                        ArcaneBackgroundOptionType::Power => {
+                            if let Some(power_id) = removed_option.option_id {
+                                // Remove the power from draft.powers (only non-locked)
+                                if let Some(pos) = draft.powers.iter().position(|p| {
+                                    p.power.id == power_id && !p.is_locked
+                                }) {
+                                    draft.powers.remove(pos);
+                                }
                             }
                         }
-                    }
-                    ArcaneBackgroundOptionType::Hindrance => {
-                        if let Some(hindrance_id) = removed_option.option_id {
-                            // Remove the hindrance from draft.hindrances
-                            if let Some(pos) = draft.hindrances.iter().position(|h| {
-                                h.hindrance.id == hindrance_id
-                                    && h.source == SOURCE_ARCANE_BACKGROUND
-                            }) {
-                                draft.hindrances.remove(pos);
+                        ArcaneBackgroundOptionType::Hindrance => {
+                            if let Some(hindrance_id) = removed_option.option_id {
+                                // Remove the hindrance from draft.hindrances
+                                if let Some(pos) = draft.hindrances.iter().position(|h| {
+                                    h.hindrance.id == hindrance_id
+                                        && h.source == SOURCE_ARCANE_BACKGROUND
+                                }) {
+                                    draft.hindrances.remove(pos);
+                                }
                             }
                         }
+                        _ => {}
                     }
-                    _ => {}
                 }
-            }
-            None => {
-                return Err(CommandError::Validation("Option not currently selected".to_string()));
+                None => {
+                    return Err(CommandError::validation("Option not currently selected".to_string()));
+                }
             }
         }
-    }
 
-    Ok(draft.clone())
+        Ok(draft.clone())
+    })
 }
 
 /// Power with its availability status for the current character.
@@ -374,8 +377,33 @@ pub fn get_powers(state: State<Mutex<AppState>>) -> CommandResult<Vec<PowerWithA
     let conn = state.connection()?;
     let powers = PowerService::get_all(&conn)?;
 
-    // If there's a draft character, evaluate requirements against it
-    let results = match &state.draft_character {
+    Ok(with_availability(powers, state.draft_character.as_ref()))
+}
+
+/// Get only the powers the draft character could take: those at or below the
+/// character's rank and, for arcane backgrounds with a power list, on that list.
+#[tauri::command]
+#[specta::specta]
+pub fn get_available_powers(
+    state: State<Mutex<AppState>>,
+) -> CommandResult<Vec<PowerWithAvailability>> {
+    let state = lock_state(&state)?;
+    let conn = state.connection()?;
+    let draft = state.draft()?;
+    let powers = PowerService::get_all(&conn)?
+        .into_iter()
+        .filter(|power| draft.can_list_power(power))
+        .collect();
+
+    Ok(with_availability(powers, Some(draft)))
+}
+
+/// Pair each power with its availability for the draft character, if any.
+fn with_availability(
+    powers: Vec<PowerView>,
+    draft: Option<&CharacterView>,
+) -> Vec<PowerWithAvailability> {
+    match draft {
         Some(draft) => {
             let ctx = draft.to_requirement_context();
             powers
@@ -399,9 +427,7 @@ pub fn get_powers(state: State<Mutex<AppState>>) -> CommandResult<Vec<PowerWithA
                 power,
             })
             .collect(),
-    };
-
-    Ok(results)
+    }
 }
 
 #[tauri::command]
@@ -416,104 +442,69 @@ pub fn add_draft_power(
     let bypass = bypass_validation.unwrap_or(false);
     let mut warnings = Vec::new();
 
-    let draft = state.draft_mut()?;
-
-    // Check if power is already added
-    if draft.powers.iter().any(|p| p.power.id == power_id) {
-        return Err(CommandError::Validation("Power already added".to_string()));
-    }
-
-    // Check if character has an arcane background
-    if draft.arcane_backgrounds.is_empty() {
-        if bypass {
-            warnings.push(ValidationWarning::requirement_not_met(
-                "Character has no Arcane Background".to_string(),
-            ));
-        } else {
-            return Err(CommandError::Validation("Character must have an Arcane Background to select powers".to_string()));
+    state.edit_draft(|draft| {
+        // Check if power is already added
+        if draft.powers.iter().any(|p| p.power.id == power_id) {
+            return Err(CommandError::conflict("Power already added".to_string()));
         }
-    }
 
-    // Check if the arcane background has a power list restriction
-    let has_power_list = draft
-        .arcane_backgrounds
-        .iter()
-        .any(|ab| ab.arcane_background.has_power_list);
+        // Check if character has an arcane background
+        if draft.arcane_backgrounds.is_empty() {
+            if bypass {
+                warnings.push(ValidationWarning::requirement_not_met(
+                    "Character has no Arcane Background".to_string(),
+                ));
+            } else {
+                return Err(CommandError::validation("Character must have an Arcane Background to select powers".to_string()));
+            }
+        }
 
-    if has_power_list && !bypass {
-        // Validate that the power is in the available_power choices
-        let available_power_ids: Vec<i64> = draft
-            .arcane_background_choices
-            .iter()
-            .filter(|c| c.choice.choice_type == ArcaneBackgroundChoiceType::AvailablePower)
-            .flat_map(|c| &c.choice.options)
-            .filter(|opt| opt.option_type == ArcaneBackgroundOptionType::Power)
-            .filter_map(|opt| opt.option_id)
-            .collect();
-
-        if !available_power_ids.contains(&power_id) {
-            return Err(CommandError::Validation(
+        // Check if the power is on the arcane background's power list
+        if !bypass && !draft.arcane_backgrounds.is_empty() && !draft.is_power_on_list(power_id) {
+            return Err(CommandError::validation(
                 "This power is not available for your Arcane Background".to_string(),
             ));
         }
-    }
 
-    // Check if character has room for more starting powers
-    // Base slots from arcane backgrounds
-    let base_power_slots: i64 = draft
-        .arcane_backgrounds
-        .iter()
-        .map(|ab| ab.arcane_background.starting_powers)
-        .sum();
-
-    // Bonus slots from edges like "New Powers"
-    let bonus_power_slots: i64 = draft
-        .edges
-        .iter()
-        .flat_map(|e| &e.edge.modifiers)
-        .filter(|m| m.target_type.as_deref() == Some("power_slots"))
-        .filter_map(|m| m.value)
-        .sum();
-
-    let total_starting_powers = base_power_slots + bonus_power_slots;
-    let current_power_count = draft.powers.len() as i64;
-
-    if current_power_count >= total_starting_powers {
-        if bypass {
-            warnings.push(ValidationWarning::slot_limit_exceeded(format!(
-                "Power slots exceeded ({}/{})",
-                current_power_count + 1,
-                total_starting_powers
-            )));
-        } else {
-            return Err(CommandError::Validation(format!(
-                "Cannot add more powers. You have {} of {} starting powers.",
-                current_power_count, total_starting_powers
-            )));
+        // Check if character has room for more starting powers
+        let slots = draft.power_slots();
+        if slots.remaining == 0 {
+            if bypass {
+                warnings.push(ValidationWarning::slot_limit_exceeded(format!(
+                    "Power slots exceeded ({}/{})",
+                    slots.used + 1,
+                    slots.total
+                )));
+            } else {
+                return Err(CommandError::validation(format!(
+                    "Cannot add more powers. You have {} of {} starting powers.",
+                    slots.used, slots.total
+                )));
+            }
         }
-    }
 
-    // Load the power
-    let power = PowerService::get_by_id(&conn, power_id)?
-        .ok_or_else(|| CommandError::NotFound("Power not found".to_string()))?;
-
-    // Check requirements
-    let ctx = draft.to_requirement_context();
-    if !power.requirements.evaluate(&ctx) {
-        if bypass {
-            warnings.push(ValidationWarning::requirement_not_met(format!(
-                "Power '{}' requirements not met",
-                power.name
-            )));
-        } else {
-            return Err(CommandError::Validation("Character does not meet power requirements".to_string()));
+        // Load the power
+        let power = PowerService::get_by_id(&conn, power_id)?
+            .ok_or_else(|| CommandError::not_found("Power not found".to_string()))?;
+
+        // Check requirements
+        let ctx = draft.to_requirement_context();
+        if !power.requirements.evaluate(&ctx) {
+            if bypass {
+                warnings.push(ValidationWarning::requirement_not_met(format!(
+                    "Power '{}' requirements not met",
+                    power.name
+                )));
+            } else {
+                return Err(CommandError::validation("Character does not meet power requirements".to_string()));
+            }
         }
-    }
 
-    // Add the power (advance_taken is None for character creation/starting powers)
-    draft.powers.push(CharacterPowerValue::new(power, None));
+        // Add the power (advance_taken is None for character creation/starting powers)
+        draft.powers.push(CharacterPowerValue::new(power, None));
 
-    Ok(DraftResult::with_warnings(draft.clone(), warnings))
+        Ok(DraftResult::with_warnings(draft.clone(), warnings))
+    })
 }
 
 #[tauri::command]
@@ -524,23 +515,23 @@ pub fn remove_draft_power(
 ) -> CommandResult<CharacterView> {
     let mut state = lock_state(&state)?;
 
-    let draft = state.draft_mut()?;
+    state.edit_draft(|draft| {
+        // Find the power
+        let position = draft.powers.iter().position(|p| p.power.id == power_id);
 
-    // Find the power
-    let position = draft.powers.iter().position(|p| p.power.id == power_id);
+        match position {
+            Some(idx) => {
+                // Check if the power is locked (required starting power)
+                if draft.powers[idx].is_locked {
+                    return Err(CommandError::validation(
+                        "Cannot remove a required starting power".to_string(),
+                    ));
+                }
 
-    match position {
-        Some(idx) => {
-            // Check if the power is locked (required starting power)
-            if draft.powers[idx].is_locked {
-                return Err(CommandError::Validation(
-                    "Cannot remove a required starting power".to_string(),
-                ));
+                draft.powers.remove(idx);
+                Ok(draft.clone())
             }
-
-            draft.powers.remove(idx);
-            Ok(draft.clone())
+            None => Err(CommandError::not_found("Power not found".to_string())),
         }
-        None => Err(CommandError::NotFound("Power not found".to_string())),
-    }
+    })
 }
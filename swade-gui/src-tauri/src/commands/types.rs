@@ -49,3 +49,11 @@ impl DraftResult {
         Self { character, warnings }
     }
 }
+
+/// Result of repairing a saved character's core skills.
+#[derive(serde::Serialize, specta::Type)]
+pub struct SkillRepairResult {
+    pub character: CharacterView,
+    /// Names of the core skills that were added at d4.
+    pub added_skills: Vec<String>,
+}
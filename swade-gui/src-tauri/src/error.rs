@@ -4,34 +4,78 @@ use serde::Serialize;
 use specta::Type;
 use swade_core::error::SwadeError;
 
-/// Error type for Tauri commands, providing structured error information to the frontend.
-#[derive(Debug, Clone, Serialize, Type)]
-#[serde(tag = "kind", content = "message")]
-pub enum CommandError {
+/// Machine-readable error category, so the frontend can branch on the kind of
+/// failure instead of matching message text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Type)]
+pub enum ErrorCode {
     /// Database operation failed
-    Database(String),
+    Database,
 
     /// Requested entity was not found
-    NotFound(String),
+    NotFound,
 
     /// Validation constraint was violated
-    Validation(String),
+    Validation,
+
+    /// The change clashes with the stored data, e.g. it was edited elsewhere
+    Conflict,
+
+    /// The character can't afford a purchase
+    InsufficientFunds,
 
     /// No draft character in progress
-    NoDraft(String),
+    NoDraft,
 
     /// Internal state error (mutex, etc.)
-    State(String),
+    State,
+}
+
+/// Error type for Tauri commands, providing structured error information to the frontend.
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct CommandError {
+    pub code: ErrorCode,
+    pub message: String,
+}
+
+impl CommandError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn database(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Database, message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::NotFound, message)
+    }
+
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Validation, message)
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Conflict, message)
+    }
+
+    pub fn no_draft(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::NoDraft, message)
+    }
+
+    pub fn state(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::State, message)
+    }
 }
 
 impl std::fmt::Display for CommandError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            CommandError::Database(msg) => write!(f, "Database error: {}", msg),
-            CommandError::NotFound(msg) => write!(f, "{}", msg),
-            CommandError::Validation(msg) => write!(f, "{}", msg),
-            CommandError::NoDraft(msg) => write!(f, "{}", msg),
-            CommandError::State(msg) => write!(f, "State error: {}", msg),
+        match self.code {
+            ErrorCode::Database => write!(f, "Database error: {}", self.message),
+            ErrorCode::State => write!(f, "State error: {}", self.message),
+            _ => write!(f, "{}", self.message),
         }
     }
 }
@@ -41,16 +85,38 @@ impl std::error::Error for CommandError {}
 impl From<SwadeError> for CommandError {
     fn from(err: SwadeError) -> Self {
         match err {
-            SwadeError::Database(e) => CommandError::Database(e.to_string()),
-            SwadeError::NotFound(msg) => CommandError::NotFound(msg),
-            SwadeError::Validation(msg) => CommandError::Validation(msg),
-            SwadeError::UserDataDir => CommandError::State("Failed to determine user data directory".to_string()),
-            SwadeError::Extraction(e) => CommandError::State(format!("Failed to extract database: {}", e)),
-            SwadeError::Migration(msg) => CommandError::State(format!("Migration failed: {}", msg)),
-            SwadeError::Io(e) => CommandError::State(format!("IO error: {}", e)),
+            SwadeError::Database(e) => CommandError::database(e.to_string()),
+            SwadeError::NotFound(msg) => CommandError::not_found(msg),
+            SwadeError::Validation(msg) => CommandError::validation(msg),
+            SwadeError::Conflict(msg) => CommandError::conflict(msg),
+            err @ SwadeError::InsufficientFunds { .. } => {
+                CommandError::new(ErrorCode::InsufficientFunds, err.to_string())
+            }
+            SwadeError::UserDataDir => CommandError::state("Failed to determine user data directory"),
+            SwadeError::Extraction(e) => CommandError::state(format!("Failed to extract database: {}", e)),
+            SwadeError::Migration(msg) => CommandError::state(format!("Migration failed: {}", msg)),
+            SwadeError::Io(e) => CommandError::state(format!("IO error: {}", e)),
         }
     }
 }
 
 /// Shorthand result type for Tauri commands.
 pub type CommandResult<T> = Result<T, CommandError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn funds_shortfall_carries_insufficient_funds_code() {
+        let err = CommandError::from(SwadeError::InsufficientFunds {
+            needed: 300,
+            available: 100,
+        });
+
+        assert_eq!(err.code, ErrorCode::InsufficientFunds);
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["code"], "InsufficientFunds");
+        assert_eq!(json["message"], "Insufficient funds. Need $300, have $100");
+    }
+}
@@ -6,32 +6,72 @@ mod state;
 
 use std::sync::Mutex;
 use swade_core::db::Database;
+use swade_core::services::StaticDataCache;
+use swade_core::views::DraftHistory;
 use tauri_specta::{collect_commands, Builder};
 
 use commands::{
     // Character
     get_characters,
     get_character,
+    diff_characters,
     create_character,
+    get_archetypes,
+    quick_build_character,
+    import_character_foundry,
     delete_character,
+    get_archived_characters,
+    restore_character,
+    snapshot_character,
+    list_snapshots,
+    restore_snapshot,
+    set_wild_card,
+    set_wounds,
+    increment_wounds,
+    set_fatigue,
+    increment_fatigue,
+    repair_character_skills,
+    audit_character_modifiers,
     get_draft_character,
+    validate_draft,
     save_character,
     discard_draft,
+    reset_draft,
+    undo_draft_edit,
+    redo_draft_edit,
     load_character_into_draft,
     update_draft_basic_info,
+    update_draft_config,
+    get_characters_by_campaign,
+    set_character_campaign,
+    rename_character,
     update_character_status,
+    update_power_point_pool,
+    get_character_portrait,
     update_character_portrait,
     clear_character_portrait,
+    // Companions
+    get_companions,
+    link_companion,
+    unlink_companion,
+    // Database
+    list_backups,
+    restore_backup,
+    check_database_integrity,
+    compact_database,
     // Ancestry
     get_ancestries,
     update_draft_ancestry,
     update_draft_ancestry_choice,
+    get_pending_ancestry_choices,
     // Hindrances
     get_hindrances,
     add_draft_hindrance,
     remove_draft_hindrance,
+    allocate_hindrance_points_to_wealth,
     // Edges
     get_edges,
+    search_edges,
     add_draft_edge,
     remove_draft_edge,
     allocate_hindrance_points_to_edges,
@@ -41,22 +81,30 @@ use commands::{
     remove_draft_arcane_background,
     update_draft_arcane_background_choice,
     get_powers,
+    get_available_powers,
     add_draft_power,
     remove_draft_power,
     // Skills
     get_skills,
+    get_skills_grouped,
     get_game_config,
     update_draft_skill,
+    set_draft_skills,
+    reset_draft_skills,
     allocate_hindrance_points_to_skills,
     check_skill_decrement_impact,
     // Attributes
     update_draft_attribute,
+    set_draft_attributes,
+    reset_draft_attributes,
     allocate_hindrance_points_to_attributes,
     check_attribute_decrement_impact,
     // Notes
     get_character_notes,
+    search_notes,
     create_character_note,
     update_character_note,
+    toggle_note_pinned,
     delete_character_note,
     // Gear
     get_all_gear,
@@ -66,21 +114,33 @@ use commands::{
     add_gear,
     purchase_gear,
     sell_gear,
+    sell_all_unequipped_gear,
     remove_gear,
     toggle_gear_equipped,
     update_gear_notes,
     update_character_wealth,
     // Advancement
     get_advancement_options,
+    get_takeable_edges,
     take_edge_advance,
     take_attribute_advance,
     take_expensive_skill_advance,
     take_cheap_skill_advance,
     take_hindrance_advance,
+    take_advance,
+    check_advance,
+    award_xp,
+    spend_xp,
+    advances_until_available,
+    preview_advancement_plan,
     undo_last_advance,
     get_advancement_history,
+    export_advancement_timeline,
+    update_advance_notes,
     // Export
     export_character_markdown,
+    export_character_statblock,
+    export_roster_csv,
     // Types (for TypeScript bindings)
     DraftResult,
     ValidationWarning,
@@ -94,26 +154,64 @@ pub fn run() {
             // Character
             get_characters,
             get_character,
+            diff_characters,
             create_character,
+            get_archetypes,
+            quick_build_character,
+            import_character_foundry,
             delete_character,
+            get_archived_characters,
+            restore_character,
+            snapshot_character,
+            list_snapshots,
+            restore_snapshot,
+            set_wild_card,
+            set_wounds,
+            increment_wounds,
+            set_fatigue,
+            increment_fatigue,
+            repair_character_skills,
+            audit_character_modifiers,
             get_draft_character,
+            validate_draft,
             save_character,
             discard_draft,
+            reset_draft,
+            undo_draft_edit,
+            redo_draft_edit,
             load_character_into_draft,
             update_draft_basic_info,
+            update_draft_config,
+            get_characters_by_campaign,
+            set_character_campaign,
+            rename_character,
             update_character_status,
+            update_power_point_pool,
+            get_character_portrait,
             update_character_portrait,
             clear_character_portrait,
+            // Companions
+            get_companions,
+            link_companion,
+            unlink_companion,
+            // Database
+            list_backups,
+            restore_backup,
+            check_database_integrity,
+            compact_database,
             // Ancestry
             get_ancestries,
             update_draft_ancestry,
             update_draft_ancestry_choice,
+            get_pending_ancestry_choices,
             // Hindrances
             get_hindrances,
             add_draft_hindrance,
             remove_draft_hindrance,
+            allocate_hindrance_points_to_wealth,
             // Edges
             get_edges,
+            search_edges,
             add_draft_edge,
             remove_draft_edge,
             allocate_hindrance_points_to_edges,
@@ -123,22 +221,30 @@ pub fn run() {
             remove_draft_arcane_background,
             update_draft_arcane_background_choice,
             get_powers,
+            get_available_powers,
             add_draft_power,
             remove_draft_power,
             // Skills
             get_skills,
+            get_skills_grouped,
             get_game_config,
             update_draft_skill,
+            set_draft_skills,
+            reset_draft_skills,
             allocate_hindrance_points_to_skills,
             check_skill_decrement_impact,
             // Attributes
             update_draft_attribute,
+            set_draft_attributes,
+            reset_draft_attributes,
             allocate_hindrance_points_to_attributes,
             check_attribute_decrement_impact,
             // Notes
             get_character_notes,
+            search_notes,
             create_character_note,
             update_character_note,
+            toggle_note_pinned,
             delete_character_note,
             // Gear
             get_all_gear,
@@ -148,21 +254,33 @@ pub fn run() {
             add_gear,
             purchase_gear,
             sell_gear,
+            sell_all_unequipped_gear,
             remove_gear,
             toggle_gear_equipped,
             update_gear_notes,
     update_character_wealth,
             // Advancement
             get_advancement_options,
+            get_takeable_edges,
             take_edge_advance,
             take_attribute_advance,
             take_expensive_skill_advance,
             take_cheap_skill_advance,
             take_hindrance_advance,
+            take_advance,
+            check_advance,
+            award_xp,
+            spend_xp,
+            advances_until_available,
+            preview_advancement_plan,
             undo_last_advance,
             get_advancement_history,
+            export_advancement_timeline,
+            update_advance_notes,
             // Export
-            export_character_markdown
+            export_character_markdown,
+            export_character_statblock,
+            export_roster_csv
         ]);
 
     #[cfg(debug_assertions)]
@@ -175,10 +293,17 @@ pub fn run() {
         .expect("Failed to export TypeScript bindings");
 
     let db = Database::init().expect("Failed to initialize database");
-    let app_state = Mutex::new(AppState {
+    let mut app_state = AppState {
         db,
         draft_character: None,
-    });
+        draft_history: DraftHistory::default(),
+        static_data: StaticDataCache::default(),
+    };
+    // Warm the reference data cache; if this fails it's retried on first use
+    if let Err(e) = app_state.static_data() {
+        eprintln!("Failed to load reference data at startup: {}", e.message);
+    }
+    let app_state = Mutex::new(app_state);
 
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
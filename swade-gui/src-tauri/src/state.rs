@@ -1,11 +1,10 @@
 //! Application state management for Tauri commands.
 
 use std::sync::Mutex;
-use swade_core::constants::{
-    ATTRIBUTE_HINDRANCE_POINT_COST, EDGE_HINDRANCE_POINT_COST, SOURCE_HINDRANCE_POINTS,
-};
+use swade_core::constants::{EDGE_HINDRANCE_POINT_COST, SOURCE_HINDRANCE_POINTS};
 use swade_core::db::Database;
-use swade_core::views::CharacterView;
+use swade_core::services::{StaticData, StaticDataCache};
+use swade_core::views::{CharacterView, DraftHistory};
 use tauri::State;
 
 use crate::error::CommandError;
@@ -13,24 +12,61 @@ use crate::error::CommandError;
 pub struct AppState {
     pub db: Database,
     pub draft_character: Option<CharacterView>,
+    pub draft_history: DraftHistory,
+    pub static_data: StaticDataCache,
 }
 
 impl AppState {
     pub fn draft(&self) -> Result<&CharacterView, CommandError> {
         self.draft_character
             .as_ref()
-            .ok_or_else(|| CommandError::NoDraft("No draft character".to_string()))
+            .ok_or_else(|| CommandError::no_draft("No draft character".to_string()))
     }
 
-    pub fn draft_mut(&mut self) -> Result<&mut CharacterView, CommandError> {
-        self.draft_character
+    /// Edit the draft, recording an undo snapshot only if the edit succeeds.
+    ///
+    /// A failed edit leaves the draft and its undo/redo history untouched.
+    pub fn edit_draft<T>(
+        &mut self,
+        edit: impl FnOnce(&mut CharacterView) -> Result<T, CommandError>,
+    ) -> Result<T, CommandError> {
+        let draft = self
+            .draft_character
             .as_mut()
-            .ok_or_else(|| CommandError::NoDraft("No draft character to update".to_string()))
+            .ok_or_else(|| CommandError::no_draft("No draft character to update".to_string()))?;
+        self.draft_history.edit(draft, edit)
+    }
+
+    /// Replace the draft with a different character, forgetting its edit history.
+    pub fn set_draft(&mut self, character: Option<CharacterView>) {
+        self.draft_character = character;
+        self.draft_history.clear();
+    }
+
+    /// Bring an open draft of a saved character in step with a change written
+    /// straight to the database, so saving the draft neither reverts the change
+    /// nor trips the stale-draft check.
+    pub fn sync_draft(
+        &mut self,
+        saved: &CharacterView,
+        sync: impl FnOnce(&mut CharacterView, &CharacterView),
+    ) {
+        if let Some(draft) = self.draft_character.as_mut().filter(|d| d.id == saved.id) {
+            sync(draft, saved);
+            draft.revision = saved.revision;
+            draft.compute_effective_values();
+        }
     }
 
     pub fn connection(&self) -> Result<rusqlite::Connection, CommandError> {
         self.db.connection().map_err(CommandError::from)
     }
+
+    /// Skills, edges, and ancestries, queried once and reused.
+    pub fn static_data(&mut self) -> Result<&StaticData, CommandError> {
+        let conn = self.connection()?;
+        self.static_data.get(&conn).map_err(CommandError::from)
+    }
 }
 
 /// Helper to lock the app state mutex with consistent error handling.
@@ -39,7 +75,7 @@ pub fn lock_state<'a>(
 ) -> Result<std::sync::MutexGuard<'a, AppState>, CommandError> {
     state
         .lock()
-        .map_err(|e| CommandError::State(format!("Lock poisoned: {}", e)))
+        .map_err(|e| CommandError::state(format!("Lock poisoned: {}", e)))
 }
 
 /// Clear all arcane backgrounds and powers, refunding power points.
@@ -54,15 +90,6 @@ pub fn clear_arcane_backgrounds(draft: &mut CharacterView) {
     draft.powers.clear();
 }
 
-/// Calculate currently available (unallocated) hindrance points.
-pub fn available_hindrance_points(draft: &CharacterView) -> i64 {
-    let already_allocated = draft.hindrance_points_to_edges
-        + (draft.hindrance_points_to_attributes * ATTRIBUTE_HINDRANCE_POINT_COST)
-        + draft.hindrance_points_to_skills
-        + draft.hindrance_points_to_wealth;
-    draft.hindrance_points_earned - already_allocated
-}
-
 /// Remove edges from hindrance_points that no longer meet requirements.
 /// Refunds the hindrance points for each removed edge.
 pub fn remove_invalid_edges(draft: &mut CharacterView) {
@@ -21,6 +21,18 @@ async getCharacter(id: number) : Promise<Result<CharacterView | null, CommandErr
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Compare two saved characters, reporting what `after_id` has added, removed,
+ * or changed relative to `before_id`.
+ */
+async diffCharacters(beforeId: number, afterId: number) : Promise<Result<CharacterDiff, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("diff_characters", { beforeId, afterId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async createCharacter(name: string) : Promise<Result<CharacterView, CommandError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("create_character", { name }) };
@@ -30,11 +42,184 @@ async createCharacter(name: string) : Promise<Result<CharacterView, CommandError
 }
 },
 /**
- * Delete a character permanently.
+ * Get the archetype templates quick build can start from.
+ */
+async getArchetypes() : Promise<Result<ArchetypeView[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_archetypes") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Start a draft with a legal starting character built from an archetype
+ * template (the first archetype when no key is given).
+ */
+async quickBuildCharacter(name: string, archetypeKey: string | null) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("quick_build_character", { name, archetypeKey }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Create a new character from a Foundry VTT `swade` actor JSON export.
+ * Items that can't be matched are listed in a note on the new character.
+ */
+async importCharacterFoundry(json: string) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_character_foundry", { json }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Delete a character. By default the character is archived so it can be
+ * restored later; pass `permanent` to delete it for good.
+ */
+async deleteCharacter(id: number, permanent: boolean) : Promise<Result<null, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_character", { id, permanent }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get all archived characters.
+ */
+async getArchivedCharacters() : Promise<Result<CharacterView[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_archived_characters") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Restore an archived character to the character list.
+ */
+async restoreCharacter(id: number) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("restore_character", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Save a labelled checkpoint of a character and its advance history.
+ */
+async snapshotCharacter(characterId: number, label: string) : Promise<Result<CharacterSnapshotValue, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("snapshot_character", { characterId, label }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * List a character's snapshots, newest first.
+ */
+async listSnapshots(characterId: number) : Promise<Result<CharacterSnapshotValue[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_snapshots", { characterId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Roll a character back to a snapshot, replacing the draft if it's the same
+ * character so it isn't left stale.
+ */
+async restoreSnapshot(snapshotId: number) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("restore_snapshot", { snapshotId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Make a saved character a Wild Card or an Extra.
+ * 
+ * Warns about any held edges whose requirements are no longer met.
+ */
+async setWildCard(id: number, isWildCard: boolean) : Promise<Result<DraftResult, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_wild_card", { id, isWildCard }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set a saved character's wounds, clamped to one past their maximum.
  */
-async deleteCharacter(id: number) : Promise<Result<null, CommandError>> {
+async setWounds(id: number, wounds: number) : Promise<Result<CharacterView, CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_character", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_wounds", { id, wounds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Add (or heal, with a negative amount) wounds on a saved character.
+ */
+async incrementWounds(id: number, amount: number) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("increment_wounds", { id, amount }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set a saved character's Fatigue, clamped to one past Exhausted.
+ */
+async setFatigue(id: number, fatigue: number) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_fatigue", { id, fatigue }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Add (or recover, with a negative amount) Fatigue on a saved character.
+ */
+async incrementFatigue(id: number, amount: number) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("increment_fatigue", { id, amount }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Add any current core skills a saved character is missing, e.g. after a
+ * data update added a new core skill.
+ */
+async repairCharacterSkills(id: number) : Promise<Result<SkillRepairResult, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("repair_character_skills", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * List a saved character's modifiers whose target attribute, skill, or
+ * derived stat no longer exists.
+ */
+async auditCharacterModifiers(id: number) : Promise<Result<OrphanedModifier[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("audit_character_modifiers", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -48,9 +233,23 @@ async getDraftCharacter() : Promise<Result<CharacterView | null, CommandError>>
     else return { status: "error", error: e  as any };
 }
 },
-async saveCharacter() : Promise<Result<CharacterView, CommandError>> {
+/**
+ * Check the draft against the character creation rules.
+ * 
+ * Unspent attribute and skill points come back as warnings; they don't
+ * block `save_character`.
+ */
+async validateDraft() : Promise<Result<DraftValidation, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("validate_draft") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async saveCharacter(force: boolean) : Promise<Result<CharacterView, CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("save_character") };
+    return { status: "ok", data: await TAURI_INVOKE("save_character", { force }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -64,6 +263,39 @@ async discardDraft() : Promise<Result<null, CommandError>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Reset the draft to a fresh character, keeping its id and name.
+ */
+async resetDraft() : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("reset_draft") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Revert the draft to how it was before the most recent edit.
+ */
+async undoDraftEdit() : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("undo_draft_edit") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Reapply the most recently undone draft edit.
+ */
+async redoDraftEdit() : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("redo_draft_edit") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async loadCharacterIntoDraft(id: number) : Promise<Result<CharacterView, CommandError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("load_character_into_draft", { id }) };
@@ -72,6 +304,12 @@ async loadCharacterIntoDraft(id: number) : Promise<Result<CharacterView, Command
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Update the draft's name, Wild Card status, background and description.
+ * 
+ * The name is trimmed and must be non-blank and not overly long; background
+ * and description may be empty.
+ */
 async updateDraftBasicInfo(name: string, isWildCard: boolean, background: string | null, description: string | null) : Promise<Result<CharacterView, CommandError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("update_draft_basic_info", { name, isWildCard, background, description }) };
@@ -80,6 +318,50 @@ async updateDraftBasicInfo(name: string, isWildCard: boolean, background: string
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Replace the draft's house rule configuration.
+ */
+async updateDraftConfig(config: CharacterConfig) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_draft_config", { config }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get all saved characters in a campaign.
+ */
+async getCharactersByCampaign(campaign: string) : Promise<Result<CharacterView[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_characters_by_campaign", { campaign }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set or clear a saved character's campaign.
+ */
+async setCharacterCampaign(id: number, campaign: string | null) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_character_campaign", { id, campaign }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Rename a saved character without loading it into the draft.
+ */
+async renameCharacter(id: number, name: string) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("rename_character", { id, name }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async updateCharacterStatus(id: number, wounds: number, fatigue: number, powerPointsUsed: number) : Promise<Result<CharacterView, CommandError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("update_character_status", { id, wounds, fatigue, powerPointsUsed }) };
@@ -88,9 +370,33 @@ async updateCharacterStatus(id: number, wounds: number, fatigue: number, powerPo
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Update the power points spent from one arcane background's pool.
+ */
+async updatePowerPointPool(id: number, arcaneBackgroundId: number, powerPointsUsed: number) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_power_point_pool", { id, arcaneBackgroundId, powerPointsUsed }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get a character's portrait as a data URL.
+ * Characters are loaded without their portraits, so views fetch it on demand.
+ */
+async getCharacterPortrait(id: number) : Promise<Result<string | null, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_character_portrait", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Update a character's portrait.
  * Accepts base64-encoded image data and mime type (e.g., "image/png" or "image/jpeg").
+ * The image is downscaled and re-encoded as PNG before it is stored.
  */
 async updateCharacterPortrait(id: number, imageBase64: string, mimeType: string) : Promise<Result<CharacterView, CommandError>> {
     try {
@@ -111,6 +417,77 @@ async clearCharacterPortrait(id: number) : Promise<Result<CharacterView, Command
     else return { status: "error", error: e  as any };
 }
 },
+async getCompanions(characterId: number) : Promise<Result<CompanionSummary[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_companions", { characterId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async linkCompanion(parentId: number, companionId: number, relationship: string) : Promise<Result<CompanionSummary[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("link_companion", { parentId, companionId, relationship }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async unlinkCompanion(parentId: number, companionId: number) : Promise<Result<CompanionSummary[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("unlink_companion", { parentId, companionId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * List backups of the database, newest first.
+ */
+async listBackups() : Promise<Result<BackupInfo[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_backups") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replace the database with a backup. Any open draft is discarded and
+ * cached reference data reloaded.
+ */
+async restoreBackup(fileName: string) : Promise<Result<null, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("restore_backup", { fileName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Run SQLite's integrity and foreign key checks. Returns an empty list when
+ * the database is clean.
+ */
+async checkDatabaseIntegrity() : Promise<Result<IntegrityIssue[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_database_integrity") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Reclaim unused space in the database file, e.g. after deleting characters.
+ * Returns the size before and after.
+ */
+async compactDatabase() : Promise<Result<CompactReport, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("compact_database") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async getAncestries() : Promise<Result<AncestryView[], CommandError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_ancestries") };
@@ -135,6 +512,17 @@ async updateDraftAncestryChoice(choiceId: number, selectedOptionId: number | nul
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * List the draft's ancestry choices that still need selections.
+ */
+async getPendingAncestryChoices() : Promise<Result<PendingChoice[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_pending_ancestry_choices") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async getHindrances() : Promise<Result<HindranceView[], CommandError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_hindrances") };
@@ -159,6 +547,17 @@ async removeDraftHindrance(hindranceId: number) : Promise<Result<CharacterView,
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Convert hindrance points into starting funds (negative points give them back).
+ */
+async allocateHindrancePointsToWealth(points: number) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("allocate_hindrance_points_to_wealth", { points }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async getEdges() : Promise<Result<EdgeWithAvailability[], CommandError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_edges") };
@@ -167,6 +566,17 @@ async getEdges() : Promise<Result<EdgeWithAvailability[], CommandError>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Search edges by name or description, optionally filtered by category.
+ */
+async searchEdges(query: string, category: string | null) : Promise<Result<EdgeWithAvailability[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("search_edges", { query, category }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async addDraftEdge(edgeId: number, notes: string | null, bypassValidation: boolean | null) : Promise<Result<DraftResult, CommandError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("add_draft_edge", { edgeId, notes, bypassValidation }) };
@@ -237,6 +647,18 @@ async getPowers() : Promise<Result<PowerWithAvailability[], CommandError>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Get only the powers the draft character could take: those at or below the
+ * character's rank and, for arcane backgrounds with a power list, on that list.
+ */
+async getAvailablePowers() : Promise<Result<PowerWithAvailability[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_available_powers") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async addDraftPower(powerId: number, bypassValidation: boolean | null) : Promise<Result<DraftResult, CommandError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("add_draft_power", { powerId, bypassValidation }) };
@@ -261,6 +683,20 @@ async getSkills() : Promise<Result<SkillView[], CommandError>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Skills grouped by linked attribute name.
+ * 
+ * Uses the draft's skills (with its dice) when there is a draft, otherwise
+ * every skill untrained.
+ */
+async getSkillsGrouped() : Promise<Result<Partial<{ [key in string]: CharacterSkillValue[] }>, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_skills_grouped") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async getGameConfig() : Promise<GameConfig> {
     return await TAURI_INVOKE("get_game_config");
 },
@@ -272,6 +708,34 @@ async updateDraftSkill(skillId: number, increment: boolean, bypassValidation: bo
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Set several draft skills to target dice in one step.
+ * 
+ * Takes (skill id, target die) pairs; a None die leaves the skill untrained.
+ * Only the final point total is checked, so points can be moved between
+ * skills without a transient overspend.
+ */
+async setDraftSkills(skills: ([number, Die | null])[]) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_draft_skills", { skills }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Return core skills to d4 and untrain the rest, refunding the points.
+ * 
+ * Hindrance points converted to skills stay available to spend.
+ */
+async resetDraftSkills() : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("reset_draft_skills") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async allocateHindrancePointsToSkills(points: number) : Promise<Result<CharacterView, CommandError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("allocate_hindrance_points_to_skills", { points }) };
@@ -281,10 +745,11 @@ async allocateHindrancePointsToSkills(points: number) : Promise<Result<Character
 }
 },
 /**
- * Check which edges would be invalidated if a skill is decremented.
- * Returns the list of edge names that would lose their requirements.
+ * Check what decrementing a skill would change.
+ * Returns the derived stats that would change and the edges/powers that would
+ * lose their requirements.
  */
-async checkSkillDecrementImpact(skillId: number) : Promise<Result<string[], CommandError>> {
+async checkSkillDecrementImpact(skillId: number) : Promise<Result<DecrementImpact, CommandError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("check_skill_decrement_impact", { skillId }) };
 } catch (e) {
@@ -300,6 +765,34 @@ async updateDraftAttribute(attributeId: number, increment: boolean, bypassValida
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Set several draft attributes to target dice in one step.
+ * 
+ * Takes (attribute id, target die) pairs. Only the final point total is
+ * checked, so a step can be moved between attributes without a transient
+ * overspend.
+ */
+async setDraftAttributes(attributes: ([number, Die])[]) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_draft_attributes", { attributes }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Return every draft attribute to its base die, refunding the points.
+ * 
+ * Hindrance points converted to attributes stay available to spend.
+ */
+async resetDraftAttributes() : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("reset_draft_attributes") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async allocateHindrancePointsToAttributes(points: number) : Promise<Result<CharacterView, CommandError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("allocate_hindrance_points_to_attributes", { points }) };
@@ -309,10 +802,11 @@ async allocateHindrancePointsToAttributes(points: number) : Promise<Result<Chara
 }
 },
 /**
- * Check which edges would be invalidated if an attribute is decremented.
- * Returns the list of edge names that would lose their requirements.
+ * Check what decrementing an attribute would change.
+ * Returns the derived stats that would change and the edges/powers that would
+ * lose their requirements.
  */
-async checkAttributeDecrementImpact(attributeId: number) : Promise<Result<string[], CommandError>> {
+async checkAttributeDecrementImpact(attributeId: number) : Promise<Result<DecrementImpact, CommandError>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("check_attribute_decrement_impact", { attributeId }) };
 } catch (e) {
@@ -320,25 +814,51 @@ async checkAttributeDecrementImpact(attributeId: number) : Promise<Result<string
     else return { status: "error", error: e  as any };
 }
 },
-async getCharacterNotes(characterId: number) : Promise<Result<CharacterNoteValue[], CommandError>> {
+/**
+ * Get a character's notes, optionally limited to one category. Pinned notes
+ * come first, then the newest by `order_by` (last updated by default).
+ */
+async getCharacterNotes(characterId: number, category: string | null, orderBy: NoteOrder | null) : Promise<Result<CharacterNoteValue[], CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_character_notes", { characterId }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_character_notes", { characterId, category, orderBy }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async createCharacterNote(characterId: number, title: string, body: string) : Promise<Result<CharacterNoteValue, CommandError>> {
+/**
+ * Search a character's notes by title or body, most recently updated first.
+ */
+async searchNotes(characterId: number, query: string) : Promise<Result<CharacterNoteValue[], CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("create_character_note", { characterId, title, body }) };
+    return { status: "ok", data: await TAURI_INVOKE("search_notes", { characterId, query }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateCharacterNote(noteId: number, title: string, body: string) : Promise<Result<CharacterNoteValue, CommandError>> {
+async createCharacterNote(characterId: number, title: string, body: string, category: string | null) : Promise<Result<CharacterNoteValue, CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_character_note", { noteId, title, body }) };
+    return { status: "ok", data: await TAURI_INVOKE("create_character_note", { characterId, title, body, category }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateCharacterNote(noteId: number, title: string, body: string, category: string | null) : Promise<Result<CharacterNoteValue, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_character_note", { noteId, title, body, category }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Pin an unpinned note or unpin a pinned one. Pinned notes are listed first.
+ */
+async toggleNotePinned(noteId: number) : Promise<Result<CharacterNoteValue, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("toggle_note_pinned", { noteId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -354,10 +874,11 @@ async deleteCharacterNote(noteId: number) : Promise<Result<null, CommandError>>
 },
 /**
  * Get all available gear items.
+ * With `affordable_only` and an active draft, only items the draft can afford are returned.
  */
-async getAllGear() : Promise<Result<GearView[], CommandError>> {
+async getAllGear(affordableOnly: boolean) : Promise<Result<GearView[], CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_all_gear") };
+    return { status: "ok", data: await TAURI_INVOKE("get_all_gear", { affordableOnly }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -376,10 +897,11 @@ async getGearCategories() : Promise<Result<GearCategoryView[], CommandError>> {
 },
 /**
  * Get gear items by category.
+ * With `affordable_only` and an active draft, only items the draft can afford are returned.
  */
-async getGearByCategory(categoryId: number) : Promise<Result<GearView[], CommandError>> {
+async getGearByCategory(categoryId: number, affordableOnly: boolean) : Promise<Result<GearView[], CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_gear_by_category", { categoryId }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_gear_by_category", { categoryId, affordableOnly }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -421,7 +943,7 @@ async purchaseGear(characterId: number, gearId: number, quantity: number) : Prom
 }
 },
 /**
- * Sell gear from a character (at 50% value).
+ * Sell gear from a character (at their configured sell-back rate).
  */
 async sellGear(characterGearId: number, quantity: number) : Promise<Result<CharacterView, CommandError>> {
     try {
@@ -431,6 +953,17 @@ async sellGear(characterGearId: number, quantity: number) : Promise<Result<Chara
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Sell everything the character doesn't have equipped (at their configured sell-back rate).
+ */
+async sellAllUnequippedGear(characterId: number) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("sell_all_unequipped_gear", { characterId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Remove gear from a character without selling (lost, destroyed, given away).
  */
@@ -486,12 +1019,26 @@ async getAdvancementOptions(characterId: number) : Promise<Result<AdvancementOpt
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Get the edges a saved character can take as their next advance.
+ * 
+ * Unlike `get_edges`, which checks against the draft, this uses the
+ * persisted character and leaves out edges that can't be taken.
+ */
+async getTakeableEdges(characterId: number) : Promise<Result<EdgeView[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_takeable_edges", { characterId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Take an edge as an advancement.
  */
-async takeEdgeAdvance(characterId: number, edgeId: number, notes: string | null) : Promise<Result<CharacterAdvanceValue, CommandError>> {
+async takeEdgeAdvance(characterId: number, edgeId: number, arcaneBackgroundId: number | null, notes: string | null) : Promise<Result<CharacterAdvanceValue, CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("take_edge_advance", { characterId, edgeId, notes }) };
+    return { status: "ok", data: await TAURI_INVOKE("take_edge_advance", { characterId, edgeId, arcaneBackgroundId, notes }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -500,9 +1047,9 @@ async takeEdgeAdvance(characterId: number, edgeId: number, notes: string | null)
 /**
  * Take an attribute increase as an advancement.
  */
-async takeAttributeAdvance(characterId: number, attributeId: number) : Promise<Result<CharacterAdvanceValue, CommandError>> {
+async takeAttributeAdvance(characterId: number, attributeId: number, notes: string | null) : Promise<Result<CharacterAdvanceValue, CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("take_attribute_advance", { characterId, attributeId }) };
+    return { status: "ok", data: await TAURI_INVOKE("take_attribute_advance", { characterId, attributeId, notes }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -511,9 +1058,9 @@ async takeAttributeAdvance(characterId: number, attributeId: number) : Promise<R
 /**
  * Take an expensive skill increase (one skill at or above linked attribute) as an advancement.
  */
-async takeExpensiveSkillAdvance(characterId: number, skillId: number) : Promise<Result<CharacterAdvanceValue, CommandError>> {
+async takeExpensiveSkillAdvance(characterId: number, skillId: number, notes: string | null) : Promise<Result<CharacterAdvanceValue, CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("take_expensive_skill_advance", { characterId, skillId }) };
+    return { status: "ok", data: await TAURI_INVOKE("take_expensive_skill_advance", { characterId, skillId, notes }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -522,9 +1069,9 @@ async takeExpensiveSkillAdvance(characterId: number, skillId: number) : Promise<
 /**
  * Take two cheap skill increases (skills below linked attribute) as an advancement.
  */
-async takeCheapSkillAdvance(characterId: number, skillId1: number, skillId2: number) : Promise<Result<CharacterAdvanceValue, CommandError>> {
+async takeCheapSkillAdvance(characterId: number, skillId1: number, skillId2: number, notes: string | null) : Promise<Result<CharacterAdvanceValue, CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("take_cheap_skill_advance", { characterId, skillId1, skillId2 }) };
+    return { status: "ok", data: await TAURI_INVOKE("take_cheap_skill_advance", { characterId, skillId1, skillId2, notes }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -532,11 +1079,80 @@ async takeCheapSkillAdvance(characterId: number, skillId1: number, skillId2: num
 },
 /**
  * Take a hindrance modification as an advancement.
- * Actions: "remove_minor", "reduce_major", "remove_major_half"
+ * Actions: "remove_minor", "reduce_major", "remove_major_half" (the completing half is detected automatically)
+ */
+async takeHindranceAdvance(characterId: number, hindranceId: number, action: string, notes: string | null) : Promise<Result<CharacterAdvanceValue, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("take_hindrance_advance", { characterId, hindranceId, action, notes }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Take any kind of advance and return it with the updated character, so the
+ * new dice and derived stats arrive without another fetch.
+ */
+async takeAdvance(characterId: number, advance: PlannedAdvance, notes: string | null) : Promise<Result<AppliedAdvance, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("take_advance", { characterId, advance, notes }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Validate a single advance and describe it without saving it.
  */
-async takeHindranceAdvance(characterId: number, hindranceId: number, action: string) : Promise<Result<CharacterAdvanceValue, CommandError>> {
+async checkAdvance(characterId: number, advance: PlannedAdvance) : Promise<Result<CharacterAdvanceValue, CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("take_hindrance_advance", { characterId, hindranceId, action }) };
+    return { status: "ok", data: await TAURI_INVOKE("check_advance", { characterId, advance }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Add experience points to a saved character.
+ */
+async awardXp(characterId: number, amount: number) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("award_xp", { characterId, amount }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Take experience points back off a saved character.
+ */
+async spendXp(characterId: number, amount: number) : Promise<Result<CharacterView, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("spend_xp", { characterId, amount }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * How many more advances until an edge can be taken, when only rank is
+ * holding it back. Returns None if other requirements are unmet.
+ */
+async advancesUntilAvailable(characterId: number, edgeId: number) : Promise<Result<number | null, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("advances_until_available", { characterId, edgeId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Check a sequence of planned advances without saving them.
+ * Returns the outcome and resulting rank of each step.
+ */
+async previewAdvancementPlan(characterId: number, plan: PlannedAdvance[]) : Promise<Result<AdvancePlanStep[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("preview_advancement_plan", { characterId, plan }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -546,33 +1162,80 @@ async takeHindranceAdvance(characterId: number, hindranceId: number, action: str
  * Undo the most recent advancement for a character.
  * Returns true if an advance was successfully undone.
  */
-async undoLastAdvance(characterId: number) : Promise<Result<boolean, CommandError>> {
+async undoLastAdvance(characterId: number) : Promise<Result<boolean, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("undo_last_advance", { characterId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get the advancement history for a character.
+ */
+async getAdvancementHistory(characterId: number) : Promise<Result<CharacterAdvanceValue[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_advancement_history", { characterId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Get a character's advances in order, labelled with their rank, for a
+ * progression timeline.
+ */
+async exportAdvancementTimeline(characterId: number) : Promise<Result<AdvancementTimelineEntry[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_advancement_timeline", { characterId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Replace the notes on an advance the character has already taken.
+ */
+async updateAdvanceNotes(characterId: number, advanceId: number, notes: string | null) : Promise<Result<CharacterAdvanceValue, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_advance_notes", { characterId, advanceId, notes }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Export a character as a markdown file.
+ * Shows a save dialog and writes the file to the chosen location.
+ * Returns true if the file was saved, false if the user cancelled.
+ */
+async exportCharacterMarkdown(id: number) : Promise<Result<boolean, CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("undo_last_advance", { characterId }) };
+    return { status: "ok", data: await TAURI_INVOKE("export_character_markdown", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Get the advancement history for a character.
+ * Get a compact plain-text stat block for pasting into VTT chat.
  */
-async getAdvancementHistory(characterId: number) : Promise<Result<CharacterAdvanceValue[], CommandError>> {
+async exportCharacterStatblock(id: number) : Promise<Result<string, CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_advancement_history", { characterId }) };
+    return { status: "ok", data: await TAURI_INVOKE("export_character_statblock", { id }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Export a character as a markdown file.
+ * Export every active character as a CSV roster.
  * Shows a save dialog and writes the file to the chosen location.
  * Returns true if the file was saved, false if the user cancelled.
  */
-async exportCharacterMarkdown(id: number) : Promise<Result<boolean, CommandError>> {
+async exportRosterCsv() : Promise<Result<boolean, CommandError>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("export_character_markdown", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("export_roster_csv") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -590,6 +1253,34 @@ async exportCharacterMarkdown(id: number) : Promise<Result<boolean, CommandError
 
 /** user-defined types **/
 
+/**
+ * The outcome of one step of a previewed advancement plan.
+ */
+export type AdvancePlanStep = { 
+/**
+ * Position of the step in the plan, starting at 1
+ */
+step: number; 
+/**
+ * Whether the advance could be taken at this point in the plan
+ */
+success: boolean; 
+/**
+ * What the advance does, if it succeeded
+ */
+description: string | null; 
+/**
+ * Why the advance was rejected, if it failed
+ */
+error: string | null; 
+/**
+ * Advances taken after this step
+ */
+advances: number; 
+/**
+ * Rank after this step
+ */
+rank: string }
 /**
  * The type of advancement taken.
  */
@@ -642,6 +1333,11 @@ hindrance_options: HindranceAdvanceOption[];
  * Current advance number (what number this advance would be)
  */
 next_advance_number: number; 
+/**
+ * Advances earned from XP but not yet taken. None unless the character
+ * tracks XP.
+ */
+unspent_advances: number | null; 
 /**
  * Current rank name
  */
@@ -649,7 +1345,21 @@ current_rank: string;
 /**
  * Rank after taking this advance
  */
-rank_after_advance: string }
+rank_after_advance: string; 
+/**
+ * Advances since the character reached the current rank
+ */
+advances_into_rank: number; 
+/**
+ * Advances left until the next rank (the last one enters it). None in
+ * the final, open-ended rank.
+ */
+advances_remaining_in_rank: number | null }
+/**
+ * One advance on a character's progression timeline, labelled with the rank
+ * the character held once it was taken.
+ */
+export type AdvancementTimelineEntry = { advance_number: number; rank: string; description: string; created_at: string }
 /**
  * View model for ammunition statistics
  */
@@ -661,7 +1371,15 @@ export type AncestryChoiceOptionView = { id: number; option_type: AncestryOption
 /**
  * Types of ancestry choices.
  */
-export type AncestryChoiceType = "free_edge" | "mandatory_hindrance" | "ancestral_enemy"
+export type AncestryChoiceType = "free_edge" | "mandatory_hindrance" | "ancestral_enemy" | 
+/**
+ * Player picks an attribute to raise one die step.
+ */
+"attribute_increase" | 
+/**
+ * Player picks a skill to raise one die step (or take at d4 if untrained).
+ */
+"free_skill"
 /**
  * View model for an ancestry choice with its options.
  */
@@ -669,11 +1387,25 @@ export type AncestryChoiceView = { id: number; choice_type: AncestryChoiceType;
 /**
  * Types of ancestry choice options.
  */
-export type AncestryOptionType = "edge" | "hindrance" | "ancestry"
+export type AncestryOptionType = "edge" | "hindrance" | "ancestry" | 
+/**
+ * A free die step in an attribute; `option_id` is the attribute id.
+ */
+"attribute" | 
+/**
+ * A free die step in a skill (untrained skills start at d4); `option_id`
+ * is the skill id.
+ */
+"skill"
 /**
  * View model for an ancestry with all its related data.
  */
 export type AncestryView = { id: number; name: string; source: string; description: string; choices: AncestryChoiceView[]; modifiers: Modifier[]; requirements: RequirementTree }
+/**
+ * A freshly applied advance together with the character as it stands afterwards,
+ * so the UI gets the new dice and derived stats without fetching again.
+ */
+export type AppliedAdvance = { advance: CharacterAdvanceValue; character: CharacterView }
 /**
  * View model for an arcane background choice option.
  */
@@ -693,11 +1425,24 @@ export type ArcaneBackgroundOptionType = "power" | "hindrance" | "ability" | "ed
 /**
  * View model for an arcane background with its requirements and choices.
  */
-export type ArcaneBackgroundView = { id: number; name: string; arcane_skill_id: number; arcane_skill_name: string | null; starting_powers: number; starting_power_points: number; has_power_list: boolean; source: string; description: string; requirements: RequirementTree; choices: ArcaneBackgroundChoiceView[] }
+export type ArcaneBackgroundView = { id: number; name: string; arcane_skill_id: number; arcane_skill_name: string | null; starting_powers: number; starting_power_points: number; has_power_list: boolean; source: string; description: string; 
+/**
+ * Edge taken as an advance to gain this arcane background
+ */
+edge_id: number | null; requirements: RequirementTree; choices: ArcaneBackgroundChoiceView[] }
 /**
  * Arcane background with its availability status for the current character.
  */
 export type ArcaneBackgroundWithAvailability = { arcane_background: ArcaneBackgroundView; is_available: boolean; requirement_statuses: RequirementStatus[] }
+/**
+ * An attribute or skill an archetype raises, and the die it aims for.
+ */
+export type ArchetypeTraitView = { id: number; name: string; die: Die }
+/**
+ * A quick build template with its suggestions resolved, each list in the
+ * order it is applied.
+ */
+export type ArchetypeView = { id: number; key: string; name: string; description: string; attributes: ArchetypeTraitView[]; skills: ArchetypeTraitView[]; edges: EdgeView[]; hindrances: HindranceView[] }
 /**
  * View model for armor statistics
  */
@@ -707,26 +1452,47 @@ export type ArmorStatsView = { armor_value: number; coverage: string; min_streng
  */
 export type AttributeAdvanceOption = { id: number; name: string; 
 /**
- * Base die size (without modifiers)
+ * Base die (without modifiers)
  */
-current_die: number; 
+current_die: Die; 
 /**
- * Effective die size (with modifiers applied) - for display
+ * Effective die (with modifiers applied) - for display
  */
-effective_die: number; 
+effective_die: Die; 
 /**
- * Base die size after advancement
+ * Base die after advancement
  */
-next_die: number; 
+next_die: Die; 
 /**
- * Effective die size after advancement (with modifiers) - for display
+ * Effective die after advancement (with modifiers) - for display
  */
-effective_next_die: number; is_maxed: boolean }
+effective_next_die: Die; is_maxed: boolean }
 export type AttributeView = { id: number; name: string; description: string; base_die: Die }
+/**
+ * A backup of the user database.
+ */
+export type BackupInfo = { 
+/**
+ * File name inside the backup directory, used to restore it.
+ */
+file_name: string; 
+/**
+ * When the backup was taken (UTC).
+ */
+created_at: string; size_bytes: number }
 /**
  * Represents an advance that a character has taken, with resolved names for display.
  */
-export type CharacterAdvanceValue = { id: number; advance_number: number; advance_type: AdvanceType; description: string; created_at: string }
+export type CharacterAdvanceValue = { id: number; advance_number: number; advance_type: AdvanceType; description: string; 
+/**
+ * The player's own annotation for this advance, if any.
+ */
+notes: string | null; created_at: string; 
+/**
+ * The rank the character entered by taking this advance, if it crossed a rank boundary.
+ * Only set on freshly applied advances, not on history entries.
+ */
+rank_changed: Rank | null }
 /**
  * Represents a character's selection for an ancestry choice.
  */
@@ -738,7 +1504,11 @@ export type CharacterArcaneBackgroundChoiceValue = { choice: ArcaneBackgroundCho
 /**
  * Represents an arcane background that a character has, along with when it was acquired.
  */
-export type CharacterArcaneBackgroundValue = { arcane_background: ArcaneBackgroundView; advance_taken: number | null }
+export type CharacterArcaneBackgroundValue = { arcane_background: ArcaneBackgroundView; advance_taken: number | null; 
+/**
+ * Power points spent from this background's pool.
+ */
+power_points_used: number }
 export type CharacterAttributeValue = { attribute: AttributeView; 
 /**
  * The purchased die value (what the player spent points on).
@@ -763,7 +1533,70 @@ can_increment: boolean;
 /**
  * Whether this attribute can be decremented (not at base).
  */
-can_decrement: boolean }
+can_decrement: boolean; 
+/**
+ * The attribute points it costs to raise this attribute one step.
+ */
+increment_cost: number; 
+/**
+ * Attribute points that would remain after raising this attribute.
+ */
+points_if_incremented: number; 
+/**
+ * Attribute points that would remain after lowering this attribute (the
+ * current remainder when it is already at base).
+ */
+points_if_decremented: number }
+/**
+ * Per-character house rule settings.
+ * 
+ * Every option is unset by default, which keeps the core SWADE rules.
+ */
+export type CharacterConfig = { 
+/**
+ * Highest die attributes can be raised to. Unset means d12.
+ */
+max_attribute_die: Die | null; 
+/**
+ * Let hindrances be taken past the starting point cap. Points past the cap
+ * are not earned.
+ */
+allow_hindrances_past_cap: boolean; 
+/**
+ * Pounds of load limit per Strength die step. Unset means the core 20.
+ */
+load_limit_multiplier: number | null; 
+/**
+ * Skill Parry is derived from, for settings that don't use Fighting.
+ * Unset means Fighting.
+ */
+parry_skill: string | null; 
+/**
+ * Wounds a Wild Card can take before Incapacitation. Unset means 3.
+ */
+max_wounds: number | null; 
+/**
+ * Earn advances from experience points (one per 5 XP) instead of
+ * counting advances taken.
+ */
+track_experience: boolean; 
+/**
+ * Percent of an item's cost refunded when selling it. Unset means 50.
+ */
+sell_back_percent: number | null; 
+/**
+ * Unit gear weights and encumbrance are shown in. Weights are always
+ * stored and compared in pounds.
+ */
+weight_unit: WeightUnit }
+/**
+ * Differences between two characters' effective sheets.
+ * 
+ * Built by `CharacterDiff::between()` from each side's `EffectiveSheet`, so
+ * only effective values are compared. Every list is sorted by id (stats keep
+ * sheet order) and leaves out entries that are the same on both sides.
+ */
+export type CharacterDiff = { attributes: TraitDiff[]; skills: TraitDiff[]; edges: FeatureDiff[]; hindrances: FeatureDiff[]; derived_stats: StatDiff[] }
 /**
  * Represents an edge that a character has taken, along with metadata about when/how it was acquired.
  */
@@ -775,7 +1608,19 @@ export type CharacterGearValue = { id: number; gear: GearView; quantity: number;
 /**
  * Total weight of this gear stack (weight * quantity)
  */
-total_weight: number }
+total_weight: number; 
+/**
+ * Total weight formatted in the owner's weight unit, e.g. "6 lb"
+ */
+weight_label: string; 
+/**
+ * Damage as written, e.g. "Str+d6" or "2d6".
+ */
+damage: string | null; 
+/**
+ * Range as "short/medium/long" in inches.
+ */
+range: string | null; reach: number | null; rof: number | null }
 /**
  * Represents a hindrance that a character has taken, along with metadata about how it was acquired.
  */
@@ -783,7 +1628,7 @@ export type CharacterHindranceValue = { hindrance: HindranceView; source: string
 /**
  * Represents a note attached to a character.
  */
-export type CharacterNoteValue = { id: number; title: string; body: string; created_at: string; updated_at: string }
+export type CharacterNoteValue = { id: number; title: string; body: string; pinned: boolean; category: string | null; created_at: string; updated_at: string }
 /**
  * Represents a power that a character has learned, along with when it was acquired.
  */
@@ -818,34 +1663,115 @@ can_increment: boolean;
  * Whether this skill can be decremented (has die, and not a core skill at d4).
  */
 can_decrement: boolean }
+/**
+ * A saved checkpoint of a character, without the snapshot data itself.
+ */
+export type CharacterSnapshotValue = { id: number; character_id: number; label: string; created_at: string }
 /**
  * Complete view of a character with all related data resolved.
  */
-export type CharacterView = { id: number; is_wild_card: boolean; name: string; ancestry: AncestryView | null; ancestry_choices: CharacterAncestryChoiceValue[]; rank: Rank; current_advances: number; attributes: CharacterAttributeValue[]; skills: CharacterSkillValue[]; edges: CharacterEdgeValue[]; hindrances: CharacterHindranceValue[]; arcane_backgrounds: CharacterArcaneBackgroundValue[]; arcane_background_choices: CharacterArcaneBackgroundChoiceValue[]; powers: CharacterPowerValue[]; power_points: number; power_points_used: number; wounds: number; fatigue: number; notes: CharacterNoteValue[]; gear: CharacterGearValue[]; encumbrance: EncumbranceInfo; modifiers: Modifier[]; derived_stats: DerivedStatsView; attribute_points_spent: number; attribute_points_earned: number; skill_points_spent: number; skill_points_earned: number; hindrance_points_spent: number; hindrance_points_earned: number; hindrance_points_to_edges: number; hindrance_points_to_attributes: number; hindrance_points_to_skills: number; hindrance_points_to_wealth: number; wealth: number; background: string | null; description: string | null; portrait_data_url: string | null }
+export type CharacterView = { id: number; is_wild_card: boolean; name: string; 
+/**
+ * How many times the saved character has been written, 0 for an unsaved
+ * draft. Saving compares it against the database to catch stale drafts.
+ */
+revision: number; ancestry: AncestryView | null; ancestry_choices: CharacterAncestryChoiceValue[]; rank: Rank; 
+/**
+ * Advances taken, or earned from experience when the config tracks XP.
+ */
+current_advances: number; 
+/**
+ * Total XP earned. Only drives advances when the config tracks XP.
+ */
+experience_points: number; attributes: CharacterAttributeValue[]; skills: CharacterSkillValue[]; edges: CharacterEdgeValue[]; hindrances: CharacterHindranceValue[]; arcane_backgrounds: CharacterArcaneBackgroundValue[]; arcane_background_choices: CharacterArcaneBackgroundChoiceValue[]; powers: CharacterPowerValue[]; 
+/**
+ * Combined power points across all arcane backgrounds.
+ */
+power_points: number; 
+/**
+ * Power points spent by a character without arcane backgrounds. With
+ * arcane backgrounds, each pool's `used` is what counts; see
+ * `total_power_points_used`.
+ */
+power_points_used: number; 
+/**
+ * Power points from the arcane backgrounds' starting pools.
+ */
+power_points_base: number; 
+/**
+ * Power points added by modifiers such as the Power Points edge.
+ */
+power_points_bonus: number; 
+/**
+ * Separate power point pool for each arcane background.
+ */
+power_point_pools: PowerPointPool[]; 
+/**
+ * Starting powers allowed and chosen.
+ */
+power_slots: PowerSlots; wounds: number; fatigue: number; 
+/**
+ * Wounds or Fatigue past the character's maximum.
+ */
+incapacitated: boolean; notes: CharacterNoteValue[]; companions: CompanionSummary[]; gear: CharacterGearValue[]; encumbrance: EncumbranceInfo; modifiers: Modifier[]; derived_stats: DerivedStatsView; 
+/**
+ * Situational modifiers (e.g. +1 Fighting when unarmed), listed separately
+ * since they are left out of effective dice and derived stats.
+ */
+situational_modifiers: ModifierSource[]; attribute_points_spent: number; attribute_points_earned: number; skill_points_spent: number; skill_points_earned: number; hindrance_points_spent: number; hindrance_points_earned: number; hindrance_points_to_edges: number; hindrance_points_to_attributes: number; hindrance_points_to_skills: number; hindrance_points_to_wealth: number; wealth: number; 
+/**
+ * Wealth plus the value of all owned gear.
+ */
+net_worth: number; background: string | null; description: string | null; 
+/**
+ * Campaign or table the character belongs to.
+ */
+campaign: string | null; portrait_data_url: string | null; config: CharacterConfig; 
+/**
+ * Stored values that were out of range and corrected on load, e.g.
+ * "Agility stored as d12+2, clamped to d12". Saving keeps the corrections.
+ */
+data_warnings: string[] }
 /**
  * Error type for Tauri commands, providing structured error information to the frontend.
  */
-export type CommandError = 
+export type CommandError = { code: ErrorCode; message: string }
 /**
- * Database operation failed
+ * Database size before and after `compact`.
  */
-{ kind: "Database"; message: string } | 
+export type CompactReport = { before_bytes: number; after_bytes: number }
 /**
- * Requested entity was not found
+ * A companion (sidekick, animal companion, etc.) linked to a character.
  */
-{ kind: "NotFound"; message: string } | 
+export type CompanionSummary = { link_id: number; 
 /**
- * Validation constraint was violated
+ * Id of the companion's own character record.
  */
-{ kind: "Validation"; message: string } | 
+character_id: number; name: string; is_wild_card: boolean; 
 /**
- * No draft character in progress
+ * How the companion relates to the parent ("Sidekick", "Animal Companion", etc.).
  */
-{ kind: "NoDraft"; message: string } | 
+relationship: string }
 /**
- * Internal state error (mutex, etc.)
+ * What decrementing an attribute or skill would do to the rest of the character.
+ */
+export type DecrementImpact = { 
+/**
+ * Derived stats whose values would change.
+ */
+derived_stat_changes: DerivedStatChange[]; 
+/**
+ * Names of edges and powers whose requirements would no longer be met.
+ */
+broken_requirements: string[] }
+/**
+ * A derived stat that would change as the result of a hypothetical edit.
+ */
+export type DerivedStatChange = { 
+/**
+ * Name of the stat (pace, parry, toughness, size).
  */
-{ kind: "State"; message: string }
+stat: string; before: number; after: number }
 /**
  * Derived characteristics calculated from a character's attributes, skills, and modifiers.
  * 
@@ -869,18 +1795,56 @@ toughness: number;
 /**
  * Size modifier. Normal humans are 0. Affects Toughness.
  */
-size: number }
+size: number; 
+/**
+ * Penalty to all trait rolls from wounds, up to 3.
+ */
+wound_penalty: number; 
+/**
+ * Penalty to all trait rolls from Fatigue, up to 2.
+ */
+fatigue_penalty: number; 
+/**
+ * Penalty to Agility and Strength rolls (and their linked skills) from
+ * carrying more than the load limit.
+ */
+encumbrance_penalty: number; 
+/**
+ * Flat bonus modifiers behind each stat, keyed by stat name (pace, parry,
+ * toughness, size).
+ */
+modifier_breakdown: Partial<{ [key in string]: ModifierSource[] }> }
 /**
  * A SWADE die value.
  * 
  * Represents die sizes from d4 to d12, and beyond d12 with modifiers (d12+1, d12+2, etc.).
  * The progression is: d4 → d6 → d8 → d10 → d12 → d12+1 → d12+2 → ...
+ * 
+ * A d4 may also carry a negative modifier to express a roll below the base
+ * die, such as the d4-2 rolled for an untrained skill.
  */
 export type Die = { size: number; modifier: number }
+/**
+ * How an entry differs between the two characters being compared.
+ */
+export type DiffKind = "added" | "removed" | "changed"
 /**
  * Result returned by draft commands that support bypass_validation.
  */
 export type DraftResult = { character: CharacterView; warnings: ValidationWarning[] }
+/**
+ * Problems that keep a draft from being a legal starting character.
+ */
+export type DraftValidation = { 
+/**
+ * Rule violations, each a sentence naming what is wrong.
+ */
+errors: string[]; 
+/**
+ * Things worth a second look that don't make the draft illegal, such as
+ * points left unspent.
+ */
+warnings: string[] }
 /**
  * Edge category types in SWADE.
  */
@@ -909,7 +1873,53 @@ is_encumbered: boolean;
 /**
  * Penalty applied if encumbered (-2 to all physical tasks)
  */
-encumbrance_penalty: number }
+encumbrance_penalty: number; 
+/**
+ * Pace lost if encumbered
+ */
+pace_penalty: number; 
+/**
+ * Unit `current_weight` and `load_limit` are in
+ */
+unit: WeightUnit }
+/**
+ * Machine-readable error category, so the frontend can branch on the kind of
+ * failure instead of matching message text.
+ */
+export type ErrorCode = 
+/**
+ * Database operation failed
+ */
+"Database" | 
+/**
+ * Requested entity was not found
+ */
+"NotFound" | 
+/**
+ * Validation constraint was violated
+ */
+"Validation" | 
+/**
+ * The change clashes with the stored data, e.g. it was edited elsewhere
+ */
+"Conflict" | 
+/**
+ * The character can't afford a purchase
+ */
+"InsufficientFunds" | 
+/**
+ * No draft character in progress
+ */
+"NoDraft" | 
+/**
+ * Internal state error (mutex, etc.)
+ */
+"State"
+/**
+ * An edge or hindrance held by only one side, or held a different number of
+ * times (repeatable edges).
+ */
+export type FeatureDiff = { id: number; name: string; kind: DiffKind; old_count: number; new_count: number }
 /**
  * Static game configuration constants for SWADE.
  * Provides a single source of truth for game rules that the frontend can query.
@@ -1008,8 +2018,69 @@ action_label: string;
  * Whether this hindrance has a banked advance
  */
 is_banked: boolean }
-export type HindranceView = { id: number; name: string; severity: Severity; point_value: number; companion_hindrance_id: number | null; source: string; description: string; modifiers: Modifier[]; requirements: RequirementTree }
-export type Modifier = { id: number; target_type: string | null; target_identifier: string | null; value_type: string; value: number | null; description: string; created_at: string; updated_at: string }
+export type HindranceView = { id: number; name: string; severity: Severity; point_value: number; companion_hindrance_id: number | null; can_take_multiple_times: boolean; source: string; description: string; modifiers: Modifier[]; requirements: RequirementTree }
+/**
+ * A problem found by `check_integrity`.
+ */
+export type IntegrityIssue = { 
+/**
+ * "integrity" for file corruption, "foreign_key" for orphaned rows.
+ */
+kind: string; 
+/**
+ * Table holding the bad row, for foreign key issues.
+ */
+table: string | null; 
+/**
+ * Row id of the bad row, for foreign key issues.
+ */
+row_id: number | null; message: string }
+export type Modifier = { id: number; target_type: string | null; target_identifier: string | null; value_type: string; 
+/**
+ * Signed amount. Positive values are bonuses and negative values are penalties
+ * (e.g. a hindrance's Pace −1 is a `flat_bonus` of -1). For `die_increment`,
+ * each point is one die step, so -1 lowers d6 to d4.
+ */
+value: number | null; description: string; 
+/**
+ * When a situational bonus applies (e.g. "unarmed"). `None` for modifiers
+ * that are always on.
+ */
+condition: string | null; created_at: string; updated_at: string }
+/**
+ * A single modifier contributing to a character value, labelled with where it came from.
+ */
+export type ModifierSource = { 
+/**
+ * Kind of source: "direct", "ancestry", "edge", "hindrance", or "gear".
+ */
+source_type: string; 
+/**
+ * Display label for the source (edge name, gear name, etc.). "Direct" for
+ * modifiers applied to the character itself.
+ */
+source_name: string; value_type: string; value: number; description: string; 
+/**
+ * When the modifier applies, for situational modifiers.
+ */
+condition: string | null }
+/**
+ * Which timestamp to sort a character's notes by. Newest come first either way.
+ */
+export type NoteOrder = "updated_at" | "created_at"
+/**
+ * A modifier whose target no longer matches any attribute, skill, or derived
+ * stat, so it silently applies to nothing (e.g. after a skill was renamed).
+ */
+export type OrphanedModifier = { 
+/**
+ * Kind of source: "direct", "ancestry", "edge", "hindrance", or "gear".
+ */
+source_type: string; 
+/**
+ * Display label for the source (edge name, gear name, etc.).
+ */
+source_name: string; target_type: string; target_identifier: string; description: string }
 /**
  * View model for a single item within a pack
  */
@@ -1026,17 +2097,76 @@ quantity: number;
  * Optional notes about this item in the pack context
  */
 notes: string | null }
+/**
+ * A choice that still needs selections before the character is complete.
+ */
+export type PendingChoice = { choice_id: number; description: string; min_selections: number; max_selections: number; 
+/**
+ * Selections made so far.
+ */
+selected: number; 
+/**
+ * Selections still required to reach `min_selections`.
+ */
+remaining: number }
+/**
+ * One advance in a proposed advancement plan.
+ */
+export type PlannedAdvance = 
+/**
+ * `arcane_background_id` picks the arcane background the edge grants, if
+ * it grants more than one.
+ */
+{ type: "edge"; edge_id: number; arcane_background_id: number | null } | { type: "attribute"; attribute_id: number } | { type: "skill_expensive"; skill_id: number } | { type: "skill_cheap"; skill_id_1: number; skill_id_2: number } | { type: "hindrance"; hindrance_id: number; action: HindranceAction }
 /**
  * View model for poison statistics
  */
 export type PoisonStatsView = { poison_type: string; delivery_method: string; affected_attribute: string | null; notes: string | null }
-export type PowerView = { id: number; name: string; power_points: number; range: string; duration: string; source: string; description: string; modifiers: Modifier[]; requirements: RequirementTree }
+/**
+ * A separate power point pool belonging to one of a character's arcane backgrounds.
+ * 
+ * Characters with more than one arcane background track power points per
+ * background rather than in a single shared pool.
+ */
+export type PowerPointPool = { arcane_background_id: number; arcane_background_name: string; 
+/**
+ * Maximum power points in this pool.
+ */
+max: number; 
+/**
+ * Power points currently spent from this pool.
+ */
+used: number }
+/**
+ * Starting power slots: how many powers a character may choose and how many
+ * are already taken.
+ */
+export type PowerSlots = { 
+/**
+ * Slots from arcane backgrounds plus bonus slots from edges like New Powers.
+ */
+total: number; used: number; remaining: number }
+export type PowerView = { id: number; name: string; 
+/**
+ * Power points to cast.
+ */
+power_points: number; 
+/**
+ * Power points to extend the duration. None when the cost isn't recorded
+ * for this power, which the UI shows as unknown.
+ */
+maintenance_power_points: number | null; range: string; duration: string; source: string; description: string; modifiers: Modifier[]; requirements: RequirementTree }
 /**
  * Power with its availability status for the current character.
  */
 export type PowerWithAvailability = { power: PowerView; is_available: boolean; requirement_statuses: RequirementStatus[] }
 export type Rank = { id: number; name: string; min_advances: number; max_advances: number | null; description: string; created_at: string; updated_at: string }
-export type Requirement = { id: number; requirement_type: string; target_id: number | null; value: number | null; description: string; created_at: string; updated_at: string }
+export type Requirement = { id: number; requirement_type: string; target_id: number | null; 
+/**
+ * Target referenced by name when there's no id, e.g. a rank in imported
+ * content. Resolved to `target_id` when the requirement is loaded.
+ */
+target_name: string | null; value: number | null; description: string; created_at: string; updated_at: string }
 /**
  * A node in a requirement expression tree.
  * Represents boolean logic (AND, OR, NOT) or a leaf requirement.
@@ -1069,7 +2199,15 @@ description: string;
 /**
  * Whether this requirement is met
  */
-is_met: boolean }
+is_met: boolean; 
+/**
+ * Character's current value (die size or rank ID) for trait and rank requirements
+ */
+current_value: number | null; 
+/**
+ * Value the requirement asks for (die size or rank ID)
+ */
+required_value: number | null }
 /**
  * A tree structure representing requirements with boolean logic.
  * Built from requirement_expressions and requirements tables.
@@ -1088,22 +2226,41 @@ export type ShieldStatsView = { parry_bonus: number; cover_penalty: number; min_
  */
 export type SkillAdvanceOption = { id: number; name: string; 
 /**
- * Base die size (0 if untrained)
+ * Base die (None if untrained)
+ */
+current_die: Die | null; 
+/**
+ * Effective die (with modifiers applied) - for display
+ */
+effective_die: Die | null; 
+/**
+ * Base die after advancement
+ */
+next_die: Die; 
+/**
+ * Effective die after advancement (with modifiers) - for display
+ */
+effective_next_die: Die; is_maxed: boolean }
+/**
+ * Result of repairing a saved character's core skills.
  */
-current_die: number; 
+export type SkillRepairResult = { character: CharacterView; 
 /**
- * Effective die size (with modifiers applied) - for display
+ * Names of the core skills that were added at d4.
  */
-effective_die: number; 
+added_skills: string[] }
+export type SkillView = { id: number; name: string; description: string; linked_attribute_id: number; linked_attribute_name: string; is_core_skill: boolean; default_die: Die | null; max_die: Die; source: string }
 /**
- * Base die size after advancement
+ * A derived stat whose value differs, keyed by stat name (pace, parry, ...).
  */
-next_die: number; 
+export type StatDiff = { stat: string; old_value: number; new_value: number }
 /**
- * Effective die size after advancement (with modifiers) - for display
+ * An attribute or skill whose effective die differs.
+ * 
+ * A skill going from untrained to trained is `Added` with no old die, and the
+ * reverse is `Removed` with no new die.
  */
-effective_next_die: number; is_maxed: boolean }
-export type SkillView = { id: number; name: string; description: string; linked_attribute_id: number; is_core_skill: boolean; default_die: Die | null; max_die: Die; source: string }
+export type TraitDiff = { id: number; name: string; kind: DiffKind; old_die: Die | null; new_die: Die | null }
 /**
  * A warning generated when validation is bypassed.
  */
@@ -1112,6 +2269,10 @@ export type ValidationWarning = { warning_type: string; message: string }
  * View model for weapon statistics
  */
 export type WeaponStatsView = { damage: string; ap: number; range_short: number | null; range_medium: number | null; range_long: number | null; rof: number | null; shots: number | null; min_strength: number | null; is_two_handed: boolean; reach: number | null; blast_template: string | null; notes: string | null }
+/**
+ * Unit gear weights are shown in. Weights are stored in pounds.
+ */
+export type WeightUnit = "lb" | "kg"
 
 /** tauri-specta globals **/
 
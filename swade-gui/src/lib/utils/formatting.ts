@@ -2,6 +2,8 @@
  * Utility functions for formatting display strings.
  */
 
+import type { WeightUnit } from "$lib/bindings";
+
 /**
  * Format a source string from snake_case to Title Case.
  * Examples:
@@ -15,3 +17,26 @@ export function formatSource(source: string): string {
     .map((word) => word.charAt(0).toUpperCase() + word.slice(1).toLowerCase())
     .join(" ");
 }
+
+/**
+ * Format a power's maintenance cost. Powers without a recorded cost show as unknown.
+ * Examples:
+ *   1 -> "1 PP"
+ *   null -> "Unknown"
+ */
+export function formatMaintenanceCost(powerPoints: number | null): string {
+  return powerPoints === null ? "Unknown" : `${powerPoints} PP`;
+}
+
+const KILOGRAMS_PER_POUND = 0.45359237;
+
+/**
+ * Format a weight stored in pounds in the given unit, with at most one decimal place.
+ * Examples:
+ *   (6, "lb") -> "6 lb"
+ *   (6, "kg") -> "2.7 kg"
+ */
+export function formatWeight(pounds: number, unit: WeightUnit): string {
+  const value = unit === "kg" ? pounds * KILOGRAMS_PER_POUND : pounds;
+  return `${value.toFixed(1).replace(/\.0$/, "")} ${unit}`;
+}
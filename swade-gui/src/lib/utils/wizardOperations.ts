@@ -59,7 +59,7 @@ export async function discardDraftAndNavigate(): Promise<string | null> {
  * Returns a WizardResult with the updated character or an error.
  */
 export async function saveCharacter(): Promise<WizardResult<CharacterView>> {
-  const result = await commands.saveCharacter();
+  const result = await commands.saveCharacter(false);
   if (result.status === "ok") {
     return { ok: true, data: result.data };
   }
@@ -72,7 +72,7 @@ export async function saveCharacter(): Promise<WizardResult<CharacterView>> {
  * Returns the character ID on success, or null with error message on failure.
  */
 export async function saveCharacterAndGetId(): Promise<{ id: number | null; error: string | null }> {
-  const result = await commands.saveCharacter();
+  const result = await commands.saveCharacter(false);
   if (result.status === "ok") {
     return { id: result.data.id, error: null };
   }